@@ -0,0 +1,315 @@
+use crate::optimize::{
+    decode_raw, decode_residuals, dequantize, pseudo_series, Basis, Coefficient, Dag, Dictionary,
+    Node, Patch,
+};
+use crate::totals::{read_totals, to_relative};
+use hashbrown::HashMap;
+use polars::prelude::*;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "decompress",
+    about = "Decompress an optimized output directory"
+)]
+pub struct Decompress {
+    #[structopt(short = "i", parse(from_os_str))]
+    pub input: PathBuf,
+    #[structopt(short = "o", parse(from_os_str))]
+    pub output: PathBuf,
+    #[structopt(short = "r", long = "relative")]
+    pub relative: bool,
+    #[structopt(long = "clamp")]
+    pub clamp: bool,
+}
+
+pub fn decompress(input: PathBuf, output: PathBuf, relative: bool, clamp: bool) {
+    let metadata = read_metadata(&input.join("metadata.parquet"));
+    let dictionary = Dictionary::read(&input.join("dictionary.parquet"));
+    let clamp = clamp || metadata.get("clamp").is_some_and(|x| x == "true");
+
+    let totals = match relative {
+        true => Some(read_totals(&input.join("totals.parquet")).unwrap()),
+        false => None,
+    };
+
+    let mut raw = read_uncompressed(&input.join("uncompressed"));
+    let basis = Basis::read(&input.join("basis.parquet"));
+    raw.extend(basis.atoms.iter().cloned());
+
+    let mut dag = Dag::new();
+    for (ngram, node) in read_compressed(&input.join("compressed"), &metadata, &dictionary) {
+        if !raw.contains_key(&ngram) {
+            dag.insert(ngram, node);
+        }
+    }
+
+    let mut decoded = dag.decode(&raw, clamp).unwrap();
+    for (token, _) in basis.atoms.iter() {
+        decoded.remove(token);
+    }
+
+    let by_n = decoded
+        .into_iter()
+        .fold(HashMap::new(), |mut acc, (ngram, frequency)| {
+            let n = ngram.split_ascii_whitespace().count();
+            acc.entry(n)
+                .or_insert_with(Vec::new)
+                .push((ngram, frequency));
+            return acc;
+        });
+
+    for (n, rows) in by_n {
+        let outdir = output.join(format!("n={}", n));
+        fs::create_dir_all(&outdir).unwrap();
+
+        let (ngrams, frequencies): (Vec<String>, Vec<Series>) = rows
+            .into_iter()
+            .map(|(ngram, frequency)| match &totals {
+                Some(totals) => (
+                    ngram,
+                    Series::new("frequency", to_relative(&frequency, totals)),
+                ),
+                None => {
+                    let counts = frequency
+                        .iter()
+                        .map(|x| x.round().max(0.) as u64)
+                        .collect::<Vec<_>>();
+                    (ngram, Series::new("frequency", counts))
+                }
+            })
+            .unzip();
+
+        let ngrams = Series::new("ngram", ngrams);
+        let frequencies = Series::new("frequency", frequencies);
+        let mut df = DataFrame::new(vec![ngrams, frequencies]).unwrap();
+
+        let mut f = fs::File::create(outdir.join("0.parquet")).unwrap();
+        ParquetWriter::new(&mut f)
+            .with_compression(ParquetCompression::Uncompressed)
+            .finish(&mut df)
+            .expect("writing parquet file");
+    }
+}
+
+pub fn reconstruct(
+    coefficients: &[Coefficient],
+    frequencies: &HashMap<String, [f64; 201]>,
+) -> [f64; 201] {
+    let mut reconstructed = [0.; 201];
+
+    for coefficient in coefficients {
+        let Some(frequency) = frequencies
+            .get(&coefficient.token)
+            .copied()
+            .or_else(|| pseudo_series(&coefficient.token))
+        else {
+            continue;
+        };
+
+        for (value, x) in reconstructed.iter_mut().zip(frequency.iter()) {
+            *value += coefficient.coefficient * x;
+        }
+    }
+
+    return reconstructed;
+}
+
+pub fn read_metadata(path: &Path) -> HashMap<String, String> {
+    if !path.exists() {
+        return HashMap::new();
+    }
+
+    let df = ParquetReader::new(fs::File::open(path).unwrap())
+        .finish()
+        .unwrap();
+    let keys = df.column("key").unwrap().str().unwrap().clone();
+    let values = df.column("value").unwrap().str().unwrap().clone();
+
+    return keys
+        .into_iter()
+        .zip(values.into_iter())
+        .filter_map(|(key, value)| Some((key?.to_string(), value?.to_string())))
+        .collect();
+}
+
+pub fn read_compressed(
+    dir: &Path,
+    metadata: &HashMap<String, String>,
+    dictionary: &Dictionary,
+) -> HashMap<String, Node> {
+    let mut compressed = HashMap::new();
+
+    for path in parquet_files(dir) {
+        let key = format!("scale:{}", path.strip_prefix(dir).unwrap().display());
+        let scale = metadata
+            .get(&key)
+            .and_then(|x| x.parse::<f64>().ok())
+            .unwrap_or(1.);
+
+        let df = ParquetReader::new(fs::File::open(&path).unwrap())
+            .finish()
+            .unwrap();
+        let ngrams = df.column("ngram").unwrap().str().unwrap().clone();
+        let coefficients = df.column("coefficients").unwrap().list().unwrap().clone();
+        let patches = match df.column("patches") {
+            Ok(patches) => patches
+                .list()
+                .unwrap()
+                .into_iter()
+                .map(|patches| patches.map(|x| read_patches(&x)).unwrap_or_default())
+                .collect::<Vec<_>>(),
+            Err(_) => vec![Vec::new(); df.height()],
+        };
+        let residuals = match df.column("residuals") {
+            Ok(residuals) => residuals
+                .binary()
+                .unwrap()
+                .into_iter()
+                .map(|residuals| residuals.map(|x| decode_residuals(x).unwrap()))
+                .collect::<Vec<_>>(),
+            Err(_) => vec![None; df.height()],
+        };
+
+        for (((ngram, coefs), patches), residuals) in ngrams
+            .into_iter()
+            .zip(coefficients.into_iter())
+            .zip(patches)
+            .zip(residuals)
+        {
+            let (Some(ngram), Some(coefs)) = (ngram, coefs) else {
+                continue;
+            };
+
+            let coefs = coefs.struct_().unwrap();
+            let values = coefs.field_by_name("coefficient").unwrap();
+
+            // Older outputs store the child tokens inline instead of referencing the dictionary.
+            let tokens = match coefs.field_by_name("child_id") {
+                Ok(ids) => ids
+                    .u32()
+                    .unwrap()
+                    .into_no_null_iter()
+                    .map(|id| dictionary.token(id).unwrap().to_string())
+                    .collect::<Vec<_>>(),
+                Err(_) => coefs
+                    .field_by_name("token")
+                    .unwrap()
+                    .str()
+                    .unwrap()
+                    .into_no_null_iter()
+                    .map(|token| token.to_string())
+                    .collect::<Vec<_>>(),
+            };
+
+            let coefficients = tokens
+                .into_iter()
+                .zip(dequantize(&values, scale))
+                .map(|(token, coefficient)| Coefficient { token, coefficient })
+                .collect();
+
+            compressed.insert(
+                ngram.to_string(),
+                Node {
+                    coefficients,
+                    patches,
+                    reconstructed: None,
+                    deviation: None,
+                    residuals,
+                },
+            );
+        }
+    }
+
+    return compressed;
+}
+
+fn read_patches(patches: &Series) -> Vec<Patch> {
+    let patches = patches.struct_().unwrap();
+    let years = patches.field_by_name("year").unwrap();
+    let corrections = patches.field_by_name("correction").unwrap();
+
+    return years
+        .u16()
+        .unwrap()
+        .into_no_null_iter()
+        .zip(corrections.f64().unwrap().into_no_null_iter())
+        .map(|(year, correction)| Patch { year, correction })
+        .collect();
+}
+
+pub fn read_uncompressed(dir: &Path) -> HashMap<String, [f64; 201]> {
+    let mut uncompressed = HashMap::new();
+
+    for path in parquet_files(dir) {
+        let df = ParquetReader::new(fs::File::open(&path).unwrap())
+            .finish()
+            .unwrap();
+        let ngrams = df.column("ngram").unwrap().str().unwrap().clone();
+
+        if let (Ok(encodings), Ok(data)) = (df.column("encoding"), df.column("data")) {
+            for ((ngram, encoding), data) in ngrams
+                .into_iter()
+                .zip(encodings.str().unwrap().into_iter())
+                .zip(data.binary().unwrap().into_iter())
+            {
+                let (Some(ngram), Some(encoding), Some(data)) = (ngram, encoding, data) else {
+                    continue;
+                };
+
+                uncompressed.insert(ngram.to_string(), decode_raw(encoding, data).unwrap());
+            }
+
+            continue;
+        }
+
+        let frequencies = df
+            .column("frequency")
+            .unwrap()
+            .cast(&DataType::List(Box::new(DataType::Float64)))
+            .unwrap();
+
+        for (ngram, frequency) in ngrams
+            .into_iter()
+            .zip(frequencies.list().unwrap().into_iter())
+        {
+            let (Some(ngram), Some(frequency)) = (ngram, frequency) else {
+                continue;
+            };
+
+            let frequency: [f64; 201] = frequency
+                .f64()
+                .unwrap()
+                .into_no_null_iter()
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+
+            uncompressed.insert(ngram.to_string(), frequency);
+        }
+    }
+
+    return uncompressed;
+}
+
+fn parquet_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut files = entries
+        .map(|entry| entry.unwrap().path())
+        .flat_map(|path| match path.is_dir() {
+            true => parquet_files(&path),
+            false => vec![path],
+        })
+        .filter(|path| path.extension().is_some_and(|ext| ext == "parquet"))
+        .collect::<Vec<_>>();
+
+    files.sort();
+    return files;
+}
@@ -0,0 +1,15 @@
+pub mod decompress;
+pub mod optimize;
+pub mod preprocessing;
+pub mod report;
+pub mod totals;
+
+pub use decompress::reconstruct;
+pub use optimize::{
+    Basis, BasisSource, CandidateProvider, CandidateSource, ChildCombination, ChildStrategy,
+    Coefficient, CoefficientBounds, Compressor, Dag, Dct, Dictionary, DuckDBLoader, LinearProgram,
+    Load, Loader, LpDump, Normalization, Optimizer, OptimizerBuilder, ParquetLoader, Patch,
+    PiecewiseLinear, Quantization, QuantizationFallback, RawEncoding, Solution, SolveStatus,
+    Solver, SolverBackend, SolverOption, Strategy, Substitution, SvdBasis, YearWeights,
+};
+pub use preprocessing::Preprocessor;
@@ -1,36 +1,38 @@
-mod optimize;
-mod preprocessing;
-
-use crate::optimize::Optimize;
-use preprocessing::Preprocess;
+use nghc_rs::decompress::{self, Decompress};
+use nghc_rs::optimize::Optimize;
+use nghc_rs::preprocessing::Preprocess;
+use nghc_rs::report::{self, Report};
+use nghc_rs::{Optimizer, Preprocessor};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
 enum Opt {
     Preprocess(Preprocess),
     Optimize(Optimize),
+    Decompress(Decompress),
+    Report(Report),
 }
 fn main() {
     match Opt::from_args() {
         Opt::Preprocess(preprocess) => {
-            preprocessing::preprocess(
-                preprocess.input,
-                preprocess.output,
-                preprocess.gzip,
-                preprocess.cont,
-                preprocess.duckdb,
-            );
+            Preprocessor::from(preprocess).run();
         }
         Opt::Optimize(optimize) => {
-            optimize::optimize(
-                optimize.input,
-                optimize.output,
-                optimize.chunk_size,
-                optimize.error_bound,
-                optimize.verbose_output,
-                optimize.output_all,
-                optimize.core_count,
+            if let Err(e) = Optimizer::from(optimize).run() {
+                eprintln!("error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Opt::Decompress(decompress) => {
+            decompress::decompress(
+                decompress.input,
+                decompress.output,
+                decompress.relative,
+                decompress.clamp,
             );
         }
+        Opt::Report(report) => {
+            report::report(report);
+        }
     }
 }
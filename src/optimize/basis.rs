@@ -0,0 +1,199 @@
+use hashbrown::HashMap;
+use polars::prelude::*;
+use std::{fmt, fs, path::Path, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BasisSource {
+    #[default]
+    Frequent,
+    Centroids,
+}
+
+/// Shared series every n-gram may draw from in addition to its own children. Atoms are stored
+/// once per output under pseudo tokens, so coefficients reference them like any other child.
+#[derive(Debug, Clone, Default)]
+pub struct Basis {
+    pub atoms: Vec<(String, [f64; 201])>,
+}
+
+impl Basis {
+    pub fn token(i: usize) -> String {
+        return format!("__atom_{}__", i);
+    }
+
+    pub fn new(series: Vec<[f64; 201]>) -> Self {
+        return Basis {
+            atoms: series
+                .into_iter()
+                .enumerate()
+                .map(|(i, series)| (Basis::token(i), series))
+                .collect(),
+        };
+    }
+
+    /// Either the `size` most frequent series of the sample, or k-means centroids of their
+    /// directions seeded with them.
+    pub fn learn(sample: &HashMap<String, [f64; 201]>, size: usize, source: BasisSource) -> Self {
+        let mut series = sample.values().copied().collect::<Vec<_>>();
+        series.sort_by(|a, b| b.iter().sum::<f64>().total_cmp(&a.iter().sum::<f64>()));
+
+        let frequent = series.iter().take(size).copied().collect::<Vec<_>>();
+
+        return match source {
+            BasisSource::Frequent => Basis::new(frequent),
+            BasisSource::Centroids => Basis::new(centroids(&series, frequent, 20)),
+        };
+    }
+
+    pub fn len(&self) -> usize {
+        return self.atoms.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.atoms.is_empty();
+    }
+
+    pub fn read(path: &Path) -> Self {
+        if !path.exists() {
+            return Basis::default();
+        }
+
+        let df = ParquetReader::new(fs::File::open(path).unwrap())
+            .finish()
+            .unwrap();
+        let tokens = df.column("token").unwrap().str().unwrap().clone();
+        let series = df
+            .column("series")
+            .unwrap()
+            .cast(&DataType::List(Box::new(DataType::Float64)))
+            .unwrap();
+
+        return Basis {
+            atoms: tokens
+                .into_no_null_iter()
+                .zip(series.list().unwrap().into_no_null_iter())
+                .map(|(token, series)| {
+                    let series: [f64; 201] = series
+                        .f64()
+                        .unwrap()
+                        .into_no_null_iter()
+                        .collect::<Vec<_>>()
+                        .try_into()
+                        .unwrap();
+                    (token.to_string(), series)
+                })
+                .collect(),
+        };
+    }
+
+    pub fn write(&self, path: &Path) {
+        let mut f = fs::File::create(path).unwrap();
+        let mut df = df![
+            "token" => self.atoms.iter().map(|(token, _)| token.clone()).collect::<Vec<_>>(),
+            "series" => self
+                .atoms
+                .iter()
+                .map(|(_, series)| Series::new("series", series))
+                .collect::<Vec<_>>(),
+        ]
+        .unwrap();
+        ParquetWriter::new(&mut f)
+            .with_compression(ParquetCompression::Uncompressed)
+            .finish(&mut df)
+            .expect("writing parquet file");
+    }
+}
+
+fn norm(x: &[f64; 201]) -> f64 {
+    return x.iter().map(|v| v * v).sum::<f64>().sqrt();
+}
+
+/// Spherical k-means: series are clustered by direction, and each centroid is scaled back to the
+/// mean magnitude of its members so the LP sees values of the usual order.
+fn centroids(series: &[[f64; 201]], seeds: Vec<[f64; 201]>, iterations: usize) -> Vec<[f64; 201]> {
+    let directions = series
+        .iter()
+        .filter(|x| norm(x) > 0.)
+        .map(|x| (x.map(|v| v / norm(x)), norm(x)))
+        .collect::<Vec<_>>();
+    let seeds = seeds
+        .into_iter()
+        .filter(|x| norm(x) > 0.)
+        .collect::<Vec<_>>();
+    let mut centroids = seeds
+        .iter()
+        .map(|x| x.map(|v| v / norm(x)))
+        .collect::<Vec<_>>();
+    let mut magnitudes = seeds.iter().map(norm).collect::<Vec<_>>();
+
+    for _ in 0..iterations {
+        let mut sums = vec![[0.; 201]; centroids.len()];
+        let mut totals = vec![(0., 0usize); centroids.len()];
+
+        for (direction, magnitude) in directions.iter() {
+            let Some((closest, _)) = centroids
+                .iter()
+                .map(|c| {
+                    c.iter()
+                        .zip(direction.iter())
+                        .map(|(a, b)| a * b)
+                        .sum::<f64>()
+                })
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+            else {
+                break;
+            };
+
+            for (sum, x) in sums[closest].iter_mut().zip(direction.iter()) {
+                *sum += x;
+            }
+            totals[closest].0 += magnitude;
+            totals[closest].1 += 1;
+        }
+
+        for ((centroid, sum), (magnitude, (total, count))) in centroids
+            .iter_mut()
+            .zip(sums.iter())
+            .zip(magnitudes.iter_mut().zip(totals.iter()))
+        {
+            if *count == 0 || norm(sum) == 0. {
+                continue;
+            }
+
+            *centroid = sum.map(|v| v / norm(sum));
+            *magnitude = total / *count as f64;
+        }
+    }
+
+    return centroids
+        .into_iter()
+        .zip(magnitudes)
+        .map(|(centroid, magnitude)| centroid.map(|v| v * magnitude))
+        .collect();
+}
+
+impl BasisSource {
+    pub const VARIANTS: &'static [&'static str] = &["frequent", "centroids"];
+}
+
+impl FromStr for BasisSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s {
+            "frequent" => Ok(BasisSource::Frequent),
+            "centroids" => Ok(BasisSource::Centroids),
+            _ => Err(format!("unknown basis source: {}", s)),
+        };
+    }
+}
+
+impl fmt::Display for BasisSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            BasisSource::Frequent => write!(f, "frequent"),
+            BasisSource::Centroids => write!(f, "centroids"),
+        };
+    }
+}
@@ -0,0 +1,266 @@
+use super::dag::Dag;
+use super::strategy::ChildStrategy;
+use super::util::ChildCache;
+use hashbrown::{HashMap, HashSet};
+use std::{fmt, str::FromStr};
+
+/// N-grams of the current chunk that same-level candidates may be drawn from. Only these have
+/// their series loaded, and only these can be checked for cycles before anything is written.
+#[derive(Debug, Default)]
+pub struct Pool {
+    ngrams: HashSet<String>,
+    by_prefix: HashMap<String, Vec<String>>,
+    by_suffix: HashMap<String, Vec<String>>,
+    normalized: Vec<(String, [f64; 201])>,
+}
+
+/// Size of the most frequent part of the chunk that correlated neighbors are searched in.
+const CORRELATION_POOL: usize = 1000;
+const MAX_SIBLINGS: usize = 10;
+
+impl Pool {
+    pub fn new() -> Self {
+        return Pool::default();
+    }
+
+    pub fn from_chunk(chunk: &HashMap<String, [f64; 201]>, sources: &[CandidateSource]) -> Self {
+        let mut pool = Pool::new();
+        if sources.iter().all(|x| *x == CandidateSource::Children) {
+            return pool;
+        }
+
+        let mut by_frequency = chunk
+            .iter()
+            .map(|(ngram, series)| (ngram, series, series.iter().sum::<f64>()))
+            .collect::<Vec<_>>();
+        by_frequency.sort_by(|a, b| b.2.total_cmp(&a.2));
+
+        pool.ngrams = chunk.keys().cloned().collect();
+
+        for (ngram, _, _) in by_frequency.iter() {
+            let tokens = ngram.split_ascii_whitespace().collect::<Vec<_>>();
+            if tokens.len() < 2 {
+                continue;
+            }
+
+            pool.by_prefix
+                .entry(tokens[..tokens.len() - 1].join(" "))
+                .or_default()
+                .push(ngram.to_string());
+            pool.by_suffix
+                .entry(tokens[1..].join(" "))
+                .or_default()
+                .push(ngram.to_string());
+        }
+
+        if sources
+            .iter()
+            .any(|x| matches!(x, CandidateSource::Correlated(_)))
+        {
+            pool.normalized = by_frequency
+                .iter()
+                .take(CORRELATION_POOL)
+                .filter_map(|(ngram, series, _)| Some((ngram.to_string(), standardize(series)?)))
+                .collect();
+        }
+
+        return pool;
+    }
+
+    pub fn contains(&self, ngram: &str) -> bool {
+        return self.ngrams.contains(ngram);
+    }
+}
+
+/// Zero mean, unit length, so the dot product of two series is their correlation.
+fn standardize(series: &[f64; 201]) -> Option<[f64; 201]> {
+    let mean = series.iter().sum::<f64>() / 201.;
+    let centered = series.map(|x| x - mean);
+    let norm = centered.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+    if norm == 0. {
+        return None;
+    }
+
+    return Some(centered.map(|x| x / norm));
+}
+
+pub struct CandidateContext<'a> {
+    pub strategy: &'a dyn ChildStrategy,
+    pub dag: &'a Dag,
+    pub cache: &'a ChildCache,
+    pub pool: &'a Pool,
+    pub frequencies: &'a HashMap<String, [f64; 201]>,
+}
+
+pub trait CandidateProvider: Send + Sync {
+    fn candidates(&self, ngram: &str, context: &CandidateContext) -> Vec<String>;
+}
+
+pub struct Children;
+
+impl CandidateProvider for Children {
+    fn candidates(&self, ngram: &str, context: &CandidateContext) -> Vec<String> {
+        return context.strategy.children(ngram, context.dag, context.cache);
+    }
+}
+
+/// Same-level n-grams sharing every token but the last, or every token but the first.
+pub struct Siblings;
+
+impl CandidateProvider for Siblings {
+    fn candidates(&self, ngram: &str, context: &CandidateContext) -> Vec<String> {
+        let tokens = ngram.split_ascii_whitespace().collect::<Vec<_>>();
+        if tokens.len() < 2 {
+            return Vec::new();
+        }
+
+        let prefix = context
+            .pool
+            .by_prefix
+            .get(&tokens[..tokens.len() - 1].join(" "));
+        let suffix = context.pool.by_suffix.get(&tokens[1..].join(" "));
+
+        return [prefix, suffix]
+            .into_iter()
+            .flatten()
+            .flat_map(|siblings| {
+                siblings
+                    .iter()
+                    .filter(|x| x.as_str() != ngram)
+                    .take(MAX_SIBLINGS)
+            })
+            .cloned()
+            .collect();
+    }
+}
+
+/// Same-level n-grams that differ in one token by casing or a plural `s`.
+pub struct Variants;
+
+impl CandidateProvider for Variants {
+    fn candidates(&self, ngram: &str, context: &CandidateContext) -> Vec<String> {
+        let tokens = ngram.split_ascii_whitespace().collect::<Vec<_>>();
+        let mut variants = Vec::new();
+
+        for (i, token) in tokens.iter().enumerate() {
+            let mut capitalized = token.chars();
+            let capitalized = match capitalized.next() {
+                Some(first) => first.to_uppercase().chain(capitalized).collect::<String>(),
+                None => continue,
+            };
+
+            for variant in [
+                token.to_lowercase(),
+                token.to_uppercase(),
+                capitalized,
+                format!("{}s", token),
+                token.strip_suffix('s').unwrap_or(token).to_string(),
+            ] {
+                if variant == *token || variant.is_empty() {
+                    continue;
+                }
+
+                let mut replaced = tokens.clone();
+                replaced[i] = &variant;
+                let replaced = replaced.join(" ");
+
+                if context.pool.contains(&replaced) {
+                    variants.push(replaced);
+                }
+            }
+        }
+
+        return variants;
+    }
+}
+
+/// The `k` n-grams of the chunk whose series correlate best with this one.
+pub struct Correlated(pub usize);
+
+impl CandidateProvider for Correlated {
+    fn candidates(&self, ngram: &str, context: &CandidateContext) -> Vec<String> {
+        let Some(y) = context.frequencies.get(ngram).and_then(standardize) else {
+            return Vec::new();
+        };
+
+        let mut correlations = context
+            .pool
+            .normalized
+            .iter()
+            .filter(|(other, _)| other != ngram)
+            .map(|(other, x)| {
+                (
+                    other,
+                    x.iter().zip(y.iter()).map(|(a, b)| a * b).sum::<f64>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        correlations.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        return correlations
+            .into_iter()
+            .take(self.0)
+            .map(|(other, _)| other.to_string())
+            .collect();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CandidateSource {
+    Children,
+    Siblings,
+    Variants,
+    Correlated(usize),
+}
+
+impl CandidateSource {
+    pub fn provider(&self) -> Box<dyn CandidateProvider> {
+        return match self {
+            CandidateSource::Children => Box::new(Children),
+            CandidateSource::Siblings => Box::new(Siblings),
+            CandidateSource::Variants => Box::new(Variants),
+            CandidateSource::Correlated(k) => Box::new(Correlated(*k)),
+        };
+    }
+
+    /// Whether the candidates are n-grams of the same level, which may depend on each other.
+    pub fn same_level(&self) -> bool {
+        return *self != CandidateSource::Children;
+    }
+}
+
+impl FromStr for CandidateSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, k) = match s.split_once(':') {
+            Some((name, k)) => (
+                name,
+                k.parse::<usize>()
+                    .map_err(|_| format!("invalid neighbor count: {}", k))?,
+            ),
+            None => (s, 5),
+        };
+
+        return match name {
+            "children" => Ok(CandidateSource::Children),
+            "siblings" => Ok(CandidateSource::Siblings),
+            "variants" => Ok(CandidateSource::Variants),
+            "correlated" if k > 0 => Ok(CandidateSource::Correlated(k)),
+            "correlated" => Err(format!("invalid neighbor count: {}", k)),
+            _ => Err(format!("unknown candidate source: {}", s)),
+        };
+    }
+}
+
+impl fmt::Display for CandidateSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            CandidateSource::Children => write!(f, "children"),
+            CandidateSource::Siblings => write!(f, "siblings"),
+            CandidateSource::Variants => write!(f, "variants"),
+            CandidateSource::Correlated(k) => write!(f, "correlated:{}", k),
+        };
+    }
+}
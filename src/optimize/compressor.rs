@@ -0,0 +1,357 @@
+use super::candidates::Pool;
+use super::dag::Dag;
+use super::optimize::{evaluate, minimize_abs_error};
+use super::optimizer::Optimizer;
+use super::solution::{Coefficient, Solution};
+use super::strategy::ChildStrategy;
+use super::util::ChildCache;
+use hashbrown::HashMap;
+
+/// Anything that turns a single series into a `Solution`, so the child-combination approach
+/// can be compared against generic series compression on the same metric.
+pub trait Compressor: Send + Sync {
+    fn name(&self) -> String;
+
+    fn compress(
+        &self,
+        ngram: &str,
+        frequencies: &HashMap<String, [f64; 201]>,
+        optimizer: &Optimizer,
+    ) -> Solution;
+
+    /// Parameters shared by every n-gram, e.g. a learned basis.
+    fn overhead(&self) -> usize {
+        return 0;
+    }
+}
+
+pub struct ChildCombination {
+    strategy: Box<dyn ChildStrategy>,
+    cache: ChildCache,
+}
+
+impl ChildCombination {
+    pub fn new(strategy: Box<dyn ChildStrategy>) -> Self {
+        return ChildCombination {
+            strategy,
+            cache: ChildCache::new(),
+        };
+    }
+}
+
+impl Compressor for ChildCombination {
+    fn name(&self) -> String {
+        return "child-combination".to_string();
+    }
+
+    fn compress(
+        &self,
+        ngram: &str,
+        frequencies: &HashMap<String, [f64; 201]>,
+        optimizer: &Optimizer,
+    ) -> Solution {
+        return minimize_abs_error(
+            ngram,
+            frequencies,
+            &Dag::new(),
+            self.strategy.as_ref(),
+            &self.cache,
+            &Pool::new(),
+            optimizer,
+        );
+    }
+}
+
+/// Orthonormal DCT-II keeping only the largest coefficients.
+pub struct Dct {
+    cosines: Vec<[f64; 201]>,
+}
+
+impl Dct {
+    pub fn new() -> Self {
+        let n: f64 = 201.;
+        let cosines = (0..201)
+            .map(|k| {
+                let scale = match k {
+                    0 => (1. / n).sqrt(),
+                    _ => (2. / n).sqrt(),
+                };
+                std::array::from_fn(|i| {
+                    scale * (std::f64::consts::PI / n * (i as f64 + 0.5) * k as f64).cos()
+                })
+            })
+            .collect();
+
+        return Dct { cosines };
+    }
+
+    fn truncated(&self, ngram: &str, y: &[f64; 201], k: usize, optimizer: &Optimizer) -> Solution {
+        let mut transformed = self
+            .cosines
+            .iter()
+            .enumerate()
+            .map(|(i, basis)| {
+                (
+                    i,
+                    basis.iter().zip(y.iter()).map(|(b, x)| b * x).sum::<f64>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        transformed.sort_by(|a, b| b.1.abs().total_cmp(&a.1.abs()));
+        transformed.truncate(k);
+
+        let mut y_pred = [0.; 201];
+        for (i, coefficient) in transformed.iter() {
+            for (value, b) in y_pred.iter_mut().zip(self.cosines[*i].iter()) {
+                *value += coefficient * b;
+            }
+        }
+
+        let coefficients = transformed
+            .into_iter()
+            .map(|(i, coefficient)| Coefficient {
+                token: format!("__dct_{}__", i),
+                coefficient,
+            })
+            .collect();
+
+        return evaluate(ngram, coefficients, y, y_pred, optimizer);
+    }
+}
+
+impl Default for Dct {
+    fn default() -> Self {
+        return Dct::new();
+    }
+}
+
+impl Compressor for Dct {
+    fn name(&self) -> String {
+        return "dct".to_string();
+    }
+
+    fn compress(
+        &self,
+        ngram: &str,
+        frequencies: &HashMap<String, [f64; 201]>,
+        optimizer: &Optimizer,
+    ) -> Solution {
+        let y = frequencies.get(ngram).unwrap();
+        return smallest(201, optimizer, |k| self.truncated(ngram, y, k, optimizer));
+    }
+}
+
+/// Linear interpolation between a subset of the original points. Segments are grown greedily
+/// while every skipped year stays within a tolerance, which is searched for the largest value
+/// that still meets the error bound.
+pub struct PiecewiseLinear;
+
+impl PiecewiseLinear {
+    fn knots(y: &[f64; 201], tolerance: f64) -> Vec<usize> {
+        let mut knots = vec![0];
+        let mut start = 0;
+
+        while start < 200 {
+            let (mut lo, mut hi) = (f64::NEG_INFINITY, f64::INFINITY);
+            let mut end = start + 1;
+
+            for (j, y_j) in y.iter().enumerate().skip(start + 1) {
+                let dx = (j - start) as f64;
+                let slope = (y_j - y[start]) / dx;
+                if slope < lo || slope > hi {
+                    break;
+                }
+
+                end = j;
+                lo = lo.max((y_j - tolerance - y[start]) / dx);
+                hi = hi.min((y_j + tolerance - y[start]) / dx);
+            }
+
+            knots.push(end);
+            start = end;
+        }
+
+        return knots;
+    }
+
+    fn fit(ngram: &str, y: &[f64; 201], tolerance: f64, optimizer: &Optimizer) -> Solution {
+        let knots = PiecewiseLinear::knots(y, tolerance);
+
+        let mut y_pred = [0.; 201];
+        for segment in knots.windows(2) {
+            let (start, end) = (segment[0], segment[1]);
+            for (i, value) in y_pred.iter_mut().enumerate().take(end + 1).skip(start) {
+                let t = (i - start) as f64 / (end - start) as f64;
+                *value = y[start] + t * (y[end] - y[start]);
+            }
+        }
+
+        let coefficients = knots
+            .into_iter()
+            .map(|i| Coefficient {
+                token: format!("__knot_{}__", 1800 + i),
+                coefficient: y[i],
+            })
+            .collect();
+
+        return evaluate(ngram, coefficients, y, y_pred, optimizer);
+    }
+}
+
+impl Compressor for PiecewiseLinear {
+    fn name(&self) -> String {
+        return "piecewise-linear".to_string();
+    }
+
+    fn compress(
+        &self,
+        ngram: &str,
+        frequencies: &HashMap<String, [f64; 201]>,
+        optimizer: &Optimizer,
+    ) -> Solution {
+        let y = frequencies.get(ngram).unwrap();
+        let accepted = |sol: &Solution| sol.error <= optimizer.error_bound;
+
+        let mut best = PiecewiseLinear::fit(ngram, y, 0., optimizer);
+        let (mut lo, mut hi) = (0., y.iter().fold(0., |acc: f64, x| acc.max(x.abs())));
+
+        for _ in 0..32 {
+            let tolerance = (lo + hi) / 2.;
+            let sol = PiecewiseLinear::fit(ngram, y, tolerance, optimizer);
+
+            if accepted(&sol) {
+                lo = tolerance;
+                best = sol;
+            } else {
+                hi = tolerance;
+            }
+        }
+
+        return best;
+    }
+}
+
+/// Orthonormal basis of the dominant directions of a sample of series, computed with power
+/// iteration on their uncentered covariance.
+pub struct SvdBasis {
+    basis: Vec<[f64; 201]>,
+}
+
+impl SvdBasis {
+    pub fn learn<'a>(
+        series: impl Iterator<Item = &'a [f64; 201]>,
+        rank: usize,
+        optimizer: &Optimizer,
+    ) -> Self {
+        let scale = optimizer.scale();
+        let mut covariance = vec![[0.; 201]; 201];
+
+        for x in series {
+            let x: [f64; 201] = std::array::from_fn(|i| x[i] * scale[i]);
+            let norm = x.iter().map(|v| v * v).sum::<f64>().sqrt();
+            if norm == 0. {
+                continue;
+            }
+
+            for (row, x_i) in covariance.iter_mut().zip(x.iter()) {
+                for (value, x_j) in row.iter_mut().zip(x.iter()) {
+                    *value += x_i * x_j / (norm * norm);
+                }
+            }
+        }
+
+        let mut basis: Vec<[f64; 201]> = Vec::with_capacity(rank);
+        for _ in 0..rank.min(201) {
+            let mut v = [1. / (201f64).sqrt(); 201];
+
+            for _ in 0..100 {
+                let mut next: [f64; 201] = std::array::from_fn(|i| {
+                    covariance[i].iter().zip(v.iter()).map(|(c, x)| c * x).sum()
+                });
+
+                // Keep the iterate orthogonal to the vectors already found.
+                for b in basis.iter() {
+                    let dot = b.iter().zip(next.iter()).map(|(b, x)| b * x).sum::<f64>();
+                    for (x, b) in next.iter_mut().zip(b.iter()) {
+                        *x -= dot * b;
+                    }
+                }
+
+                let norm = next.iter().map(|x| x * x).sum::<f64>().sqrt();
+                if norm == 0. {
+                    break;
+                }
+                v = next.map(|x| x / norm);
+            }
+
+            basis.push(v);
+        }
+
+        return SvdBasis { basis };
+    }
+
+    fn truncated(&self, ngram: &str, y: &[f64; 201], k: usize, optimizer: &Optimizer) -> Solution {
+        let mut y_pred = [0.; 201];
+        let mut coefficients = Vec::with_capacity(k);
+
+        for (i, b) in self.basis.iter().take(k).enumerate() {
+            let coefficient = b.iter().zip(y.iter()).map(|(b, x)| b * x).sum::<f64>();
+            for (value, b) in y_pred.iter_mut().zip(b.iter()) {
+                *value += coefficient * b;
+            }
+
+            coefficients.push(Coefficient {
+                token: format!("__svd_{}__", i),
+                coefficient,
+            });
+        }
+
+        return evaluate(ngram, coefficients, y, y_pred, optimizer);
+    }
+}
+
+impl Compressor for SvdBasis {
+    fn name(&self) -> String {
+        return format!("svd:{}", self.basis.len());
+    }
+
+    fn compress(
+        &self,
+        ngram: &str,
+        frequencies: &HashMap<String, [f64; 201]>,
+        optimizer: &Optimizer,
+    ) -> Solution {
+        let y = frequencies.get(ngram).unwrap();
+        return smallest(self.basis.len(), optimizer, |k| {
+            self.truncated(ngram, y, k, optimizer)
+        });
+    }
+
+    fn overhead(&self) -> usize {
+        return self.basis.len() * 201;
+    }
+}
+
+/// Binary search for the fewest parameters that meet the error bound, assuming the error only
+/// shrinks as parameters are added. Falls back to the largest fit.
+fn smallest(max: usize, optimizer: &Optimizer, fit: impl Fn(usize) -> Solution) -> Solution {
+    let mut best = fit(max);
+    if best.error > optimizer.error_bound {
+        return best;
+    }
+
+    let (mut lo, mut hi) = (1, max);
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        let sol = fit(mid);
+
+        if sol.error <= optimizer.error_bound {
+            hi = mid;
+            best = sol;
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    return best;
+}
@@ -0,0 +1,139 @@
+use super::solution::{Coefficient, Patch};
+use crate::decompress::reconstruct;
+use hashbrown::{HashMap, HashSet};
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub coefficients: Vec<Coefficient>,
+    pub patches: Vec<Patch>,
+    pub reconstructed: Option<[f64; 201]>,
+    pub deviation: Option<[f64; 201]>,
+    pub residuals: Option<[i64; 201]>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Dag {
+    nodes: HashMap<String, Node>,
+}
+
+impl Dag {
+    pub fn new() -> Self {
+        return Dag::default();
+    }
+
+    pub fn insert(&mut self, ngram: String, node: Node) {
+        self.nodes.insert(ngram, node);
+    }
+
+    pub fn contains(&self, ngram: &str) -> bool {
+        return self.nodes.contains_key(ngram);
+    }
+
+    pub fn get(&self, ngram: &str) -> Option<&Node> {
+        return self.nodes.get(ngram);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.nodes.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.nodes.is_empty();
+    }
+
+    pub fn ngrams(&self) -> impl Iterator<Item = &String> {
+        return self.nodes.keys();
+    }
+
+    pub fn reconstructions(&self) -> impl Iterator<Item = (&String, &[f64; 201])> {
+        return self
+            .nodes
+            .iter()
+            .filter_map(|(ngram, node)| node.reconstructed.as_ref().map(|x| (ngram, x)));
+    }
+
+    pub fn parents<'a>(&'a self, ngram: &'a str) -> impl Iterator<Item = &'a String> {
+        return self
+            .nodes
+            .get(ngram)
+            .into_iter()
+            .flat_map(|node| node.coefficients.iter())
+            .map(|coefficient| &coefficient.token)
+            .filter(move |token| token.as_str() != ngram && self.nodes.contains_key(*token));
+    }
+
+    pub fn decoding_order(&self) -> Result<Vec<String>, String> {
+        let mut pending: HashMap<&String, usize> = HashMap::with_capacity(self.nodes.len());
+        let mut dependents: HashMap<&String, Vec<&String>> = HashMap::new();
+
+        for ngram in self.nodes.keys() {
+            let parents = self.parents(ngram).collect::<HashSet<_>>();
+            pending.insert(ngram, parents.len());
+
+            for parent in parents {
+                dependents.entry(parent).or_default().push(ngram);
+            }
+        }
+
+        let mut queue = pending
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(ngram, _)| *ngram)
+            .collect::<VecDeque<_>>();
+        let mut order: Vec<String> = Vec::with_capacity(self.nodes.len());
+
+        while let Some(ngram) = queue.pop_front() {
+            order.push(ngram.to_string());
+
+            for dependent in dependents.get(ngram).into_iter().flatten() {
+                let count = pending.get_mut(dependent).unwrap();
+                *count -= 1;
+
+                if *count == 0 {
+                    queue.push_back(*dependent);
+                }
+            }
+        }
+
+        if order.len() != self.nodes.len() {
+            return Err(format!(
+                "compressed n-grams contain a cycle: {} of {} n-grams cannot be decoded",
+                self.nodes.len() - order.len(),
+                self.nodes.len()
+            ));
+        }
+
+        return Ok(order);
+    }
+
+    pub fn decode(
+        &self,
+        raw: &HashMap<String, [f64; 201]>,
+        clamp: bool,
+    ) -> Result<HashMap<String, [f64; 201]>, String> {
+        let mut frequencies = raw.clone();
+
+        for ngram in self.decoding_order()? {
+            let node = &self.nodes[&ngram];
+            let mut reconstructed = reconstruct(&node.coefficients, &frequencies);
+            if clamp {
+                reconstructed = reconstructed.map(|x| x.max(0.));
+            }
+
+            for patch in node.patches.iter() {
+                reconstructed[patch.index()] += patch.correction;
+            }
+
+            if let Some(residuals) = &node.residuals {
+                for (value, residual) in reconstructed.iter_mut().zip(residuals.iter()) {
+                    *value = value.round() + *residual as f64;
+                }
+            }
+
+            frequencies.insert(ngram, reconstructed);
+        }
+
+        return Ok(frequencies);
+    }
+}
@@ -0,0 +1,77 @@
+use hashbrown::HashMap;
+use polars::prelude::*;
+use std::{fs, path::Path};
+
+/// Maps every child token referenced by a compressed row to a compact integer id, so
+/// frequent children are stored once per run instead of once per row.
+#[derive(Debug, Clone, Default)]
+pub struct Dictionary {
+    ids: HashMap<String, u32>,
+    tokens: Vec<String>,
+}
+
+impl Dictionary {
+    pub fn new() -> Self {
+        return Dictionary::default();
+    }
+
+    pub fn insert(&mut self, token: &str) -> u32 {
+        if let Some(id) = self.ids.get(token) {
+            return *id;
+        }
+
+        let id = self.tokens.len() as u32;
+        self.ids.insert(token.to_string(), id);
+        self.tokens.push(token.to_string());
+        return id;
+    }
+
+    pub fn id(&self, token: &str) -> Option<u32> {
+        return self.ids.get(token).copied();
+    }
+
+    pub fn token(&self, id: u32) -> Option<&str> {
+        return self.tokens.get(id as usize).map(|x| x.as_str());
+    }
+
+    pub fn len(&self) -> usize {
+        return self.tokens.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.tokens.is_empty();
+    }
+
+    pub fn read(path: &Path) -> Self {
+        let mut dictionary = Dictionary::new();
+        if !path.exists() {
+            return dictionary;
+        }
+
+        let df = ParquetReader::new(fs::File::open(path).unwrap())
+            .finish()
+            .unwrap()
+            .sort(["id"], Default::default())
+            .unwrap();
+        let tokens = df.column("token").unwrap().str().unwrap().clone();
+
+        for token in tokens.into_no_null_iter() {
+            dictionary.insert(token);
+        }
+
+        return dictionary;
+    }
+
+    pub fn write(&self, path: &Path) {
+        let mut f = fs::File::create(path).unwrap();
+        let mut df = df![
+            "id" => (0..self.tokens.len() as u32).collect::<Vec<_>>(),
+            "token" => self.tokens.clone(),
+        ]
+        .unwrap();
+        ParquetWriter::new(&mut f)
+            .with_compression(ParquetCompression::Uncompressed)
+            .finish(&mut df)
+            .expect("writing parquet file");
+    }
+}
@@ -0,0 +1,194 @@
+use super::solver::LinearProgram;
+use hashbrown::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Write as _;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+
+/// Writes the LP of every n-gram matching `pattern` to `dir`, so it can be reproduced with a
+/// standalone solver.
+#[derive(Debug, Clone)]
+pub struct LpDump {
+    pattern: String,
+    dir: PathBuf,
+}
+
+impl LpDump {
+    /// `pattern` matches whole n-grams, with `*` for any run of characters and `?` for one.
+    pub fn new(pattern: &str, dir: PathBuf) -> Self {
+        return LpDump {
+            pattern: pattern.to_string(),
+            dir,
+        };
+    }
+
+    pub fn matches(&self, ngram: &str) -> bool {
+        return glob(
+            &self.pattern.chars().collect::<Vec<_>>(),
+            &ngram.chars().collect::<Vec<_>>(),
+        );
+    }
+
+    /// Writes `{ngram}.mps` with one column per token, and `{ngram}.csv` with the weight of
+    /// every year, the series of the n-gram and those of the tokens. Failures are reported
+    /// and otherwise ignored, so a dump never aborts the run.
+    pub fn write(&self, ngram: &str, tokens: &[String], lp: &LinearProgram) {
+        let name = file_name(ngram);
+        let files = [
+            (
+                self.dir.join(format!("{}.mps", name)),
+                mps(ngram, tokens, lp),
+            ),
+            (
+                self.dir.join(format!("{}.csv", name)),
+                csv(ngram, tokens, lp),
+            ),
+        ];
+
+        if let Err(e) = fs::create_dir_all(&self.dir) {
+            eprintln!("could not dump LP of {:?}: {}", ngram, e);
+            return;
+        }
+        for (path, contents) in files {
+            if let Err(e) = fs::write(&path, contents) {
+                eprintln!("could not write {}: {}", path.display(), e);
+            }
+        }
+    }
+}
+
+fn glob(pattern: &[char], text: &[char]) -> bool {
+    return match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len()).any(|i| glob(rest, &text[i..])),
+        Some((p, rest)) => match text.split_first() {
+            Some((t, text)) => (*p == '?' || p == t) && glob(rest, text),
+            None => false,
+        },
+    };
+}
+
+/// Replaces characters that are unsafe in file names by `_`. Where that changes the n-gram, a
+/// hash of it is appended, so e.g. `a b` and `a_b` do not overwrite each other.
+fn file_name(ngram: &str) -> String {
+    let name = ngram
+        .chars()
+        .map(|c| match c.is_alphanumeric() || c == '-' || c == '.' {
+            true => c,
+            false => '_',
+        })
+        .collect::<String>();
+    if name == ngram {
+        return name;
+    }
+
+    let mut hasher = DefaultHasher::new();
+    ngram.hash(&mut hasher);
+    return format!("{}-{:016x}", name, hasher.finish());
+}
+
+/// MPS names cannot contain whitespace, so spaces between tokens become underscores, with the
+/// column index appended where that makes two names equal. The bound on the residual is named
+/// `__t__`, like the other pseudo tokens.
+fn mps(ngram: &str, tokens: &[String], lp: &LinearProgram) -> String {
+    let mut seen = HashSet::new();
+    let columns = tokens
+        .iter()
+        .map(|token| token.split_whitespace().collect::<Vec<_>>().join("_"))
+        .chain(["__t__".to_string()])
+        .enumerate()
+        .map(|(j, column)| match seen.insert(column.clone()) {
+            true => column,
+            false => format!("{}_{}", column, j),
+        })
+        .collect::<Vec<_>>();
+
+    let rows = lp.rows();
+    let years = (0..201)
+        .filter(|i| lp.weights[*i] != 0.)
+        .map(|i| 1800 + i)
+        .collect::<Vec<_>>();
+    let row_names = years
+        .iter()
+        .map(|year| format!("above_{}", year))
+        .chain(years.iter().map(|year| format!("below_{}", year)))
+        .collect::<Vec<_>>();
+
+    let mut mps = String::new();
+    writeln!(mps, "NAME {}", file_name(ngram)).unwrap();
+    writeln!(mps, "ROWS").unwrap();
+    writeln!(mps, " N obj").unwrap();
+    for name in row_names.iter() {
+        writeln!(mps, " L {}", name).unwrap();
+    }
+
+    // MPS lists the matrix by column.
+    let mut entries: Vec<Vec<(&String, f64)>> = vec![Vec::new(); columns.len()];
+    for (name, (row, _)) in row_names.iter().zip(rows.iter()) {
+        for (j, a) in row.iter() {
+            entries[*j].push((name, *a));
+        }
+    }
+
+    writeln!(mps, "COLUMNS").unwrap();
+    for ((column, cost), entries) in columns.iter().zip(lp.costs()).zip(entries) {
+        if cost != 0. {
+            writeln!(mps, "    {} obj {}", column, cost).unwrap();
+        }
+        for (name, a) in entries {
+            writeln!(mps, "    {} {} {}", column, name, a).unwrap();
+        }
+    }
+
+    writeln!(mps, "RHS").unwrap();
+    for (name, (_, upper)) in row_names.iter().zip(rows.iter()) {
+        if *upper != 0. {
+            writeln!(mps, "    RHS {} {}", name, upper).unwrap();
+        }
+    }
+
+    writeln!(mps, "BOUNDS").unwrap();
+    for (column, (lo, hi)) in columns.iter().zip(lp.column_bounds()) {
+        match (lo.is_finite(), hi.is_finite()) {
+            (false, false) => writeln!(mps, " FR BND {}", column).unwrap(),
+            (false, true) => {
+                writeln!(mps, " MI BND {}", column).unwrap();
+                writeln!(mps, " UP BND {} {}", column, hi).unwrap();
+            }
+            (true, _) => {
+                if lo != 0. {
+                    writeln!(mps, " LO BND {} {}", column, lo).unwrap();
+                }
+                if hi.is_finite() {
+                    writeln!(mps, " UP BND {} {}", column, hi).unwrap();
+                }
+            }
+        }
+    }
+    writeln!(mps, "ENDATA").unwrap();
+
+    return mps;
+}
+
+fn csv(ngram: &str, tokens: &[String], lp: &LinearProgram) -> String {
+    let quote = |s: &str| format!("\"{}\"", s.replace('"', "\"\""));
+
+    let mut csv = String::new();
+    let header = ["year".to_string(), "weight".to_string(), quote(ngram)]
+        .into_iter()
+        .chain(tokens.iter().map(|token| quote(token)))
+        .collect::<Vec<_>>();
+    writeln!(csv, "{}", header.join(",")).unwrap();
+
+    for i in 0..201 {
+        let values = [(1800 + i) as f64, lp.weights[i], lp.y[i]]
+            .into_iter()
+            .chain(lp.series.iter().map(|x| x[i]))
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
+        writeln!(csv, "{}", values.join(",")).unwrap();
+    }
+
+    return csv;
+}
@@ -0,0 +1,311 @@
+use std::{fmt, str::FromStr};
+
+/// How the series of rejected n-grams are written to `uncompressed/`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RawEncoding {
+    Array,
+    Delta,
+    Sparse,
+    #[default]
+    Auto,
+}
+
+impl RawEncoding {
+    pub const VARIANTS: &'static [&'static str] = &["array", "delta", "sparse", "auto"];
+
+    /// Returns the name of the encoding that was actually used together with the bytes.
+    /// Series that are not non-negative integers are always stored as plain `f64`, since
+    /// the integer encodings would not round-trip them.
+    pub fn encode(&self, frequency: &[f64; 201]) -> (&'static str, Vec<u8>) {
+        if !frequency
+            .iter()
+            .all(|x| x.is_finite() && *x >= 0. && x.fract() == 0. && *x <= u64::MAX as f64)
+        {
+            return ("f64", encode_f64(frequency));
+        }
+
+        return match self {
+            RawEncoding::Array => ("f64", encode_f64(frequency)),
+            RawEncoding::Delta => ("delta", encode_delta(frequency)),
+            RawEncoding::Sparse => ("sparse", encode_sparse(frequency)),
+            RawEncoding::Auto => {
+                let delta = encode_delta(frequency);
+                let sparse = encode_sparse(frequency);
+                match sparse.len() < delta.len() {
+                    true => ("sparse", sparse),
+                    false => ("delta", delta),
+                }
+            }
+        };
+    }
+}
+
+pub fn decode_raw(encoding: &str, data: &[u8]) -> Result<[f64; 201], String> {
+    return match encoding {
+        "f64" => decode_f64(data),
+        "delta" => decode_delta(data),
+        "sparse" => decode_sparse(data),
+        _ => Err(format!("unknown raw encoding: {}", encoding)),
+    };
+}
+
+/// Zigzag varints of the per-year residuals of a lossless reconstruction.
+pub fn encode_residuals(residuals: &[i64; 201]) -> Vec<u8> {
+    let mut data = Vec::new();
+    for x in residuals.iter() {
+        write_varint(&mut data, zigzag(*x as i128));
+    }
+
+    return data;
+}
+
+pub fn decode_residuals(data: &[u8]) -> Result<[i64; 201], String> {
+    let mut residuals = [0; 201];
+    let mut offset = 0;
+
+    for value in residuals.iter_mut() {
+        *value = unzigzag(read_varint(data, &mut offset)?) as i64;
+    }
+
+    return Ok(residuals);
+}
+
+fn encode_f64(frequency: &[f64; 201]) -> Vec<u8> {
+    return frequency.iter().flat_map(|x| x.to_le_bytes()).collect();
+}
+
+fn decode_f64(data: &[u8]) -> Result<[f64; 201], String> {
+    if data.len() != 201 * 8 {
+        return Err(format!("expected {} bytes, got {}", 201 * 8, data.len()));
+    }
+
+    let mut frequency = [0.; 201];
+    for (value, bytes) in frequency.iter_mut().zip(data.chunks_exact(8)) {
+        *value = f64::from_le_bytes(bytes.try_into().unwrap());
+    }
+
+    return Ok(frequency);
+}
+
+/// Zigzag varints of the year-over-year differences, starting from zero.
+fn encode_delta(frequency: &[f64; 201]) -> Vec<u8> {
+    let mut data = Vec::new();
+    let mut previous = 0i128;
+
+    for x in frequency.iter() {
+        let current = *x as u64 as i128;
+        write_varint(&mut data, zigzag(current - previous));
+        previous = current;
+    }
+
+    return data;
+}
+
+fn decode_delta(data: &[u8]) -> Result<[f64; 201], String> {
+    let mut frequency = [0.; 201];
+    let mut offset = 0;
+    let mut previous = 0i128;
+
+    for value in frequency.iter_mut() {
+        previous += unzigzag(read_varint(data, &mut offset)?);
+        *value = previous as f64;
+    }
+
+    return Ok(frequency);
+}
+
+/// Number of non-zero years followed by `(year gap, count)` varint pairs.
+fn encode_sparse(frequency: &[f64; 201]) -> Vec<u8> {
+    let nonzero = frequency
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| **x != 0.)
+        .collect::<Vec<_>>();
+
+    let mut data = Vec::new();
+    write_varint(&mut data, nonzero.len() as u128);
+
+    let mut previous = 0;
+    for (year, x) in nonzero {
+        write_varint(&mut data, (year - previous) as u128);
+        write_varint(&mut data, *x as u64 as u128);
+        previous = year;
+    }
+
+    return data;
+}
+
+fn decode_sparse(data: &[u8]) -> Result<[f64; 201], String> {
+    let mut frequency = [0.; 201];
+    let mut offset = 0;
+    let mut year = 0;
+
+    for _ in 0..read_varint(data, &mut offset)? {
+        year += read_varint(data, &mut offset)? as usize;
+        let value = read_varint(data, &mut offset)?;
+        *frequency
+            .get_mut(year)
+            .ok_or_else(|| format!("year index out of range: {}", year))? = value as f64;
+    }
+
+    return Ok(frequency);
+}
+
+fn zigzag(x: i128) -> u128 {
+    return ((x << 1) ^ (x >> 127)) as u128;
+}
+
+fn unzigzag(x: u128) -> i128 {
+    return (x >> 1) as i128 ^ -((x & 1) as i128);
+}
+
+fn write_varint(data: &mut Vec<u8>, mut x: u128) {
+    while x >= 0x80 {
+        data.push((x as u8) | 0x80);
+        x >>= 7;
+    }
+    data.push(x as u8);
+}
+
+fn read_varint(data: &[u8], offset: &mut usize) -> Result<u128, String> {
+    let mut x = 0u128;
+    let mut shift = 0;
+
+    loop {
+        let byte = *data
+            .get(*offset)
+            .ok_or_else(|| "truncated varint".to_string())?;
+        *offset += 1;
+
+        if shift >= 128 {
+            return Err("varint overflow".to_string());
+        }
+        x |= ((byte & 0x7f) as u128) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(x);
+        }
+        shift += 7;
+    }
+}
+
+impl FromStr for RawEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s {
+            "array" => Ok(RawEncoding::Array),
+            "delta" => Ok(RawEncoding::Delta),
+            "sparse" => Ok(RawEncoding::Sparse),
+            "auto" => Ok(RawEncoding::Auto),
+            _ => Err(format!("unknown raw encoding: {}", s)),
+        };
+    }
+}
+
+impl fmt::Display for RawEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            RawEncoding::Array => write!(f, "array"),
+            RawEncoding::Delta => write!(f, "delta"),
+            RawEncoding::Sparse => write!(f, "sparse"),
+            RawEncoding::Auto => write!(f, "auto"),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENCODINGS: [RawEncoding; 4] = [
+        RawEncoding::Array,
+        RawEncoding::Delta,
+        RawEncoding::Sparse,
+        RawEncoding::Auto,
+    ];
+
+    fn round_trip(frequency: &[f64; 201]) {
+        for raw_encoding in ENCODINGS {
+            let (encoding, data) = raw_encoding.encode(frequency);
+            let decoded = decode_raw(encoding, &data).unwrap();
+
+            // Compare the bits so NaN counts as well.
+            assert_eq!(
+                decoded.map(f64::to_bits),
+                frequency.map(f64::to_bits),
+                "{} stored as {}",
+                raw_encoding,
+                encoding
+            );
+        }
+    }
+
+    #[test]
+    fn empty_data_is_rejected() {
+        for encoding in ["f64", "delta", "sparse"] {
+            assert!(decode_raw(encoding, &[]).is_err(), "{}", encoding);
+        }
+        assert!(decode_residuals(&[]).is_err());
+        assert!(decode_raw("gzip", &[]).is_err());
+    }
+
+    #[test]
+    fn all_zero_series() {
+        let zero = [0.; 201];
+        round_trip(&zero);
+
+        assert_eq!(RawEncoding::Auto.encode(&zero), ("sparse", vec![0]));
+        assert_eq!(RawEncoding::Delta.encode(&zero).1, vec![0; 201]);
+        assert_eq!(decode_residuals(&encode_residuals(&[0; 201])), Ok([0; 201]));
+    }
+
+    #[test]
+    fn max_u64_deltas() {
+        // The largest counts an f64 holds below and at u64::MAX, alternating with zero so every
+        // delta spans the whole u64 range in one direction or the other.
+        let below = (u64::MAX - 2047) as f64;
+        let max = u64::MAX as f64;
+        let frequency: [f64; 201] = std::array::from_fn(|i| match i % 4 {
+            1 => below,
+            3 => max,
+            _ => 0.,
+        });
+        round_trip(&frequency);
+
+        let (encoding, data) = RawEncoding::Delta.encode(&frequency);
+        assert_eq!(encoding, "delta");
+        assert_eq!(decode_delta(&data).unwrap()[3], max);
+
+        let mut sparse = [0.; 201];
+        sparse[200] = max;
+        assert_eq!(RawEncoding::Auto.encode(&sparse).0, "sparse");
+        round_trip(&sparse);
+    }
+
+    #[test]
+    fn non_integer_series_fall_back_to_f64() {
+        let mut frequency = [3.; 201];
+        for x in [-1., 0.5, f64::INFINITY, f64::NAN, 1e20] {
+            frequency[7] = x;
+            for raw_encoding in ENCODINGS {
+                assert_eq!(raw_encoding.encode(&frequency).0, "f64", "{}", x);
+            }
+            round_trip(&frequency);
+        }
+    }
+
+    #[test]
+    fn negative_residuals() {
+        let residuals: [i64; 201] = std::array::from_fn(|i| match i {
+            0 => i64::MIN,
+            1 => i64::MAX,
+            2 => -1,
+            _ => (i as i64 - 100) * 1_000_003,
+        });
+        let data = encode_residuals(&residuals);
+
+        assert_eq!(decode_residuals(&data), Ok(residuals));
+        assert_eq!(encode_residuals(&[-1; 201]), vec![1; 201]);
+        assert!(decode_residuals(&data[..data.len() - 1]).is_err());
+    }
+}
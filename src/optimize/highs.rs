@@ -0,0 +1,83 @@
+use super::solver::{LinearProgram, SolveStatus, Solver, SolverOption};
+use super::warm;
+use highs::{RowProblem, Sense};
+
+/// HiGHS, either on a model kept per worker thread or on a fresh `RowProblem` for every LP.
+pub struct Highs {
+    warm: bool,
+}
+
+impl Highs {
+    pub fn new(warm: bool) -> Self {
+        return Highs { warm };
+    }
+
+    /// Defaults that have worked best on n-gram series, followed by the given options so they
+    /// can be overridden.
+    fn options(options: &[SolverOption]) -> Vec<SolverOption> {
+        return [
+            SolverOption::new("presolve", "off"),
+            SolverOption::new("simplex_scale_strategy", "4"),
+        ]
+        .into_iter()
+        .chain(options.iter().cloned())
+        .collect();
+    }
+}
+
+impl Solver for Highs {
+    fn name(&self) -> String {
+        return "highs".to_string();
+    }
+
+    fn solve(&self, lp: &LinearProgram, options: &[SolverOption]) -> Result<Vec<f64>, SolveStatus> {
+        let options = Highs::options(options);
+        return match self.warm {
+            true => warm::solve(lp, &options),
+            false => solve_cold(lp, &options),
+        };
+    }
+
+    fn check_options(&self, options: &[SolverOption]) -> Result<(), String> {
+        return warm::check_options(&Highs::options(options));
+    }
+}
+
+fn solve_cold(lp: &LinearProgram, options: &[SolverOption]) -> Result<Vec<f64>, SolveStatus> {
+    // The highs crate panics on options HiGHS rejects, so fail like the warm model instead.
+    warm::check_options(options).map_err(|_| SolveStatus::NumericalError)?;
+
+    let mut pb = RowProblem::new();
+    let c = lp
+        .costs()
+        .into_iter()
+        .zip(lp.column_bounds())
+        .map(|(cost, (lo, hi))| pb.add_column(cost, lo..=hi))
+        .collect::<Vec<_>>();
+
+    for (entries, upper) in lp.rows() {
+        pb.add_row(
+            ..upper,
+            entries
+                .into_iter()
+                .map(|(j, a)| (c[j], a))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    let mut model = pb.optimise(Sense::Minimise);
+    for option in options {
+        model.set_option(option.key.as_str(), option.value.as_str());
+    }
+
+    let solved = model.try_solve().map_err(|_| SolveStatus::NumericalError)?;
+    let status = SolveStatus::from_highs(solved.status() as i32);
+    if status != SolveStatus::Optimal {
+        return Err(status);
+    }
+
+    let sol = solved.get_solution();
+    let columns = sol.columns();
+
+    return Ok(columns[..columns.len() - 1].to_vec());
+}
@@ -1,34 +1,70 @@
-use super::util::get_children;
+use super::strategy::ChildStrategy;
+use super::util::ChildCache;
 use cached::proc_macro::cached;
 use duckdb::{params, params_from_iter, types::Value, Config, Connection, Rows, Statement};
 use hashbrown::{HashMap, HashSet};
 use polars::prelude::*;
-use std::path::PathBuf;
-
-pub trait Load {
+use std::{
+    ffi::OsStr,
+    ops::Deref,
+    path::PathBuf,
+    sync::{Mutex, MutexGuard},
+};
+
+pub trait Load: Send + Sync {
     fn get_count(&self, n: usize) -> usize;
     fn get_slice(&self, limit: usize, offset: usize, n: u8) -> HashMap<String, [f64; 201]>;
-    fn get_frequencies(&self, ngrams: &HashMap<String, [f64; 201]>) -> HashMap<String, [f64; 201]>;
+    fn get_frequencies(
+        &self,
+        ngrams: &HashMap<String, [f64; 201]>,
+        strategy: &dyn ChildStrategy,
+        cache: &ChildCache,
+    ) -> HashMap<String, [f64; 201]>;
 }
 
-pub(crate) struct ParquetLoader {
+pub struct ParquetLoader {
     pub input: PathBuf,
+    connections: Connections,
 }
 
-pub(crate) struct DuckDBLoader {
+pub struct DuckDBLoader {
     pub input: PathBuf,
+    connections: Connections,
+}
+
+impl ParquetLoader {
+    pub fn new(input: PathBuf) -> Self {
+        ParquetLoader {
+            input,
+            connections: Connections::new(None),
+        }
+    }
+}
+
+impl DuckDBLoader {
+    pub fn new(input: PathBuf) -> Self {
+        DuckDBLoader {
+            connections: Connections::new(Some(input.clone())),
+            input,
+        }
+    }
 }
 
 pub struct Loader {
     pub loader: Box<dyn Load>,
 }
 
-unsafe impl Sync for Loader {}
-
 impl Loader {
     pub fn new(loader: Box<dyn Load>) -> Self {
         Loader { loader }
     }
+
+    pub fn from_path(input: PathBuf) -> Self {
+        Loader::new(match input.extension().and_then(OsStr::to_str) {
+            Some("db") => Box::new(DuckDBLoader::new(input)),
+            _ => Box::new(ParquetLoader::new(input)),
+        })
+    }
 }
 
 impl Load for Loader {
@@ -40,23 +76,83 @@ impl Load for Loader {
         self.loader.get_slice(limit, offset, n)
     }
 
-    fn get_frequencies(&self, ngrams: &HashMap<String, [f64; 201]>) -> HashMap<String, [f64; 201]> {
-        self.loader.get_frequencies(ngrams)
+    fn get_frequencies(
+        &self,
+        ngrams: &HashMap<String, [f64; 201]>,
+        strategy: &dyn ChildStrategy,
+        cache: &ChildCache,
+    ) -> HashMap<String, [f64; 201]> {
+        self.loader.get_frequencies(ngrams, strategy, cache)
+    }
+}
+
+/// Idle connections of a loader. A query takes one out (or opens a new one) and puts it back
+/// when done, so there are never more connections than threads querying at once, and all of
+/// them are closed when the loader is dropped.
+struct Connections {
+    input: Option<PathBuf>,
+    idle: Mutex<Vec<Connection>>,
+}
+
+impl Connections {
+    fn new(input: Option<PathBuf>) -> Self {
+        Connections {
+            input,
+            idle: Mutex::new(vec![]),
+        }
+    }
+
+    fn idle(&self) -> MutexGuard<'_, Vec<Connection>> {
+        // A panicking query cannot leave the list itself inconsistent.
+        return self.idle.lock().unwrap_or_else(|e| e.into_inner());
+    }
+
+    fn get(&self) -> PooledConnection<'_> {
+        let conn = self.idle().pop().unwrap_or_else(|| match &self.input {
+            Some(path) => Connection::open_with_flags(
+                path.to_str().unwrap(),
+                Config::default()
+                    .access_mode(duckdb::AccessMode::ReadOnly)
+                    .unwrap(),
+            )
+            .unwrap(),
+            None => Connection::open_in_memory().unwrap(),
+        });
+
+        return PooledConnection {
+            pool: self,
+            conn: Some(conn),
+        };
+    }
+}
+
+struct PooledConnection<'a> {
+    pool: &'a Connections,
+    conn: Option<Connection>,
+}
+
+impl Deref for PooledConnection<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.conn.as_ref().unwrap()
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            self.pool.idle().push(conn);
+        }
     }
 }
 
 impl Load for DuckDBLoader {
     fn get_slice(&self, limit: usize, offset: usize, n: u8) -> HashMap<String, [f64; 201]> {
-        let conn: Connection;
+        let conn: PooledConnection;
         let mut query: Statement;
 
-        conn = Connection::open_with_flags(
-            &self.input.to_str().unwrap(),
-            Config::default()
-                .access_mode(duckdb::AccessMode::ReadOnly)
-                .unwrap(),
-        )
-        .unwrap();
+        conn = self.connections.get();
 
         query = conn
             .prepare(&format!(
@@ -73,17 +169,11 @@ impl Load for DuckDBLoader {
     }
 
     fn get_count(&self, n: usize) -> usize {
-        let conn: Connection;
+        let conn: PooledConnection;
         let mut query: Statement;
         let mut result: Rows;
 
-        conn = Connection::open_with_flags(
-            &self.input.to_str().unwrap(),
-            Config::default()
-                .access_mode(duckdb::AccessMode::ReadOnly)
-                .unwrap(),
-        )
-        .unwrap();
+        conn = self.connections.get();
 
         query = conn
             .prepare(&format!("SELECT count(*) FROM ngrams WHERE n == ?",))
@@ -93,20 +183,19 @@ impl Load for DuckDBLoader {
         result.next().unwrap().unwrap().get(0).unwrap()
     }
 
-    fn get_frequencies(&self, ngrams: &HashMap<String, [f64; 201]>) -> HashMap<String, [f64; 201]> {
+    fn get_frequencies(
+        &self,
+        ngrams: &HashMap<String, [f64; 201]>,
+        strategy: &dyn ChildStrategy,
+        cache: &ChildCache,
+    ) -> HashMap<String, [f64; 201]> {
         let wanted = ngrams
             .iter()
-            .map(|(ngram, _)| get_children(ngram, false, &HashSet::new()))
+            .map(|(ngram, _)| strategy.wanted(ngram, cache))
             .flatten()
             .collect::<HashSet<String>>();
 
-        let conn = Connection::open_with_flags(
-            &self.input.to_str().unwrap(),
-            Config::default()
-                .access_mode(duckdb::AccessMode::ReadOnly)
-                .unwrap(),
-        )
-        .unwrap();
+        let conn = self.connections.get();
 
         let mut map = wanted
             .iter()
@@ -147,11 +236,11 @@ impl Load for DuckDBLoader {
 
 impl Load for ParquetLoader {
     fn get_count(&self, n: usize) -> usize {
-        let conn: Connection;
+        let conn: PooledConnection;
         let mut query: Statement;
         let mut result: Rows;
 
-        conn = Connection::open_in_memory().unwrap();
+        conn = self.connections.get();
 
         query = conn
             .prepare(&format!(
@@ -167,10 +256,10 @@ impl Load for ParquetLoader {
     }
 
     fn get_slice(&self, limit: usize, offset: usize, n: u8) -> HashMap<String, [f64; 201]> {
-        let conn: Connection;
+        let conn: PooledConnection;
         let mut query: Statement;
 
-        conn = Connection::open_in_memory().unwrap();
+        conn = self.connections.get();
 
         query = conn
                 .prepare(&format!(
@@ -189,15 +278,21 @@ impl Load for ParquetLoader {
             .collect::<HashMap<_, _>>()
     }
 
-    fn get_frequencies(&self, ngrams: &HashMap<String, [f64; 201]>) -> HashMap<String, [f64; 201]> {
+    fn get_frequencies(
+        &self,
+        ngrams: &HashMap<String, [f64; 201]>,
+        strategy: &dyn ChildStrategy,
+        cache: &ChildCache,
+    ) -> HashMap<String, [f64; 201]> {
         #[cached]
         fn start_values(input: PathBuf) -> HashMap<String, Vec<(String, PathBuf)>> {
             println!("Scanning input directory");
             input
                 .read_dir()
                 .unwrap()
-                .map(|e| {
-                    let entry = e.unwrap();
+                .map(|e| e.unwrap())
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| {
                     let dir = entry.path();
                     let mut firsts = dir
                         .read_dir()
@@ -234,34 +329,33 @@ impl Load for ParquetLoader {
 
         let wanted = ngrams
             .iter()
-            .map(|(ngram, _)| get_children(ngram, false, &HashSet::new()))
+            .map(|(ngram, _)| strategy.wanted(ngram, cache))
             .flatten()
             .collect::<HashSet<String>>();
 
-        let conn = Connection::open_in_memory().unwrap();
+        let conn = self.connections.get();
 
         let file_ranges = start_values(self.input.clone());
 
+        // Skip-grams and other non-contiguous children need not occur in the corpus, so missing
+        // levels, files and n-grams are dropped like the DuckDB loader does.
         let files = wanted
             .iter()
-            .map(|ngram| {
+            .filter_map(|ngram| {
                 let n = ngram.split_ascii_whitespace().count();
-                let range = &file_ranges[format!("n={}", n).as_str()];
+                let range = file_ranges.get(format!("n={}", n).as_str())?;
                 let idx = match range.binary_search_by_key(&ngram, |(ngram, _)| ngram) {
                     Ok(i) => i,
-                    Err(i) => match range.len() - 1 {
-                        0 => 0,
-                        _ => i - 1,
-                    },
+                    Err(i) => i.saturating_sub(1),
                 };
 
-                return (ngram.to_string(), range[idx].1.clone());
+                return Some((ngram.to_string(), range.get(idx)?.1.clone()));
             })
             .collect::<Vec<_>>();
 
         let mut map = files
             .iter()
-            .map(|(ngram, file)| {
+            .filter_map(|(ngram, file)| {
                 let mut query = conn
                     .prepare(&format!(
                         "SELECT ngram, frequency FROM read_parquet('{}') WHERE ngram == ?",
@@ -272,8 +366,8 @@ impl Load for ParquetLoader {
                 query
                     .query_map(params![&ngram], row_map)
                     .unwrap()
-                    .map(|x| x.unwrap())
                     .next()
+                    .transpose()
                     .unwrap()
             })
             .collect::<HashMap<String, [f64; 201]>>();
@@ -299,3 +393,152 @@ fn row_map(row: &duckdb::Row) -> Result<(String, [f64; 201]), duckdb::Error> {
     let frequency: [f64; 201] = freq.try_into().unwrap();
     Ok((ngram, frequency))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::strategy::Strategy;
+    use super::*;
+    use rayon::prelude::*;
+    use std::fs;
+    use std::path::Path;
+
+    const THREADS: usize = 16;
+    const ROUNDS: usize = 20;
+
+    /// Thirty unigrams and every bigram whose word indices sum to a multiple of three, as a
+    /// DuckDB database and as a parquet directory with the bigrams split over two files.
+    fn fixture() -> (PathBuf, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("nghc-rs-load-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("parquet").join("n=1")).unwrap();
+        fs::create_dir_all(dir.join("parquet").join("n=2")).unwrap();
+
+        let db = dir.join("ngrams.db");
+        let parquet = dir.join("parquet");
+        let conn = Connection::open(&db).unwrap();
+        conn.execute_batch(&format!(
+            "
+            CREATE TABLE words AS SELECT 'w' || i AS word, i FROM range(30) t(i);
+            CREATE TABLE ngrams AS
+                SELECT word AS ngram, 1 AS n,
+                    list_transform(range(201), y -> ((i + 1) * y % 97)::UBIGINT) AS frequency
+                FROM words
+                UNION ALL
+                SELECT a.word || ' ' || b.word, 2,
+                    list_transform(range(201), y -> ((a.i + b.i + 1) * y % 89)::UBIGINT)
+                FROM words a, words b
+                WHERE (a.i + b.i) % 3 == 0;
+            COPY (SELECT ngram, frequency FROM ngrams WHERE n == 1 ORDER BY ngram)
+                TO '{0}/n=1/0.parquet';
+            COPY (SELECT ngram, frequency FROM ngrams WHERE n == 2 AND ngram < 'w2' ORDER BY ngram)
+                TO '{0}/n=2/0.parquet';
+            COPY (SELECT ngram, frequency FROM ngrams WHERE n == 2 AND ngram >= 'w2' ORDER BY ngram)
+                TO '{0}/n=2/1.parquet';
+            ",
+            parquet.to_str().unwrap()
+        ))
+        .unwrap();
+        drop(conn);
+
+        return (db, parquet);
+    }
+
+    fn chunks(loader: &dyn Load) -> Vec<HashMap<String, [f64; 201]>> {
+        let bigrams = loader.get_slice(loader.get_count(2), 0, 2);
+        let mut ngrams = bigrams.keys().cloned().collect::<Vec<_>>();
+        ngrams.sort();
+
+        return ngrams
+            .chunks(7)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .map(|ngram| (ngram.clone(), bigrams[ngram]))
+                    .collect()
+            })
+            .collect();
+    }
+
+    /// Runs `get_frequencies` on every chunk from many threads at once, interleaving both
+    /// loaders so each thread holds connections to several databases, and compares the results
+    /// with a sequential run.
+    fn stress(loaders: &[&dyn Load], dir: &Path) {
+        let strategy = "direct-children"
+            .parse::<Strategy>()
+            .unwrap()
+            .child_strategy();
+        let cache = ChildCache::new();
+        let chunks = chunks(loaders[0]);
+
+        let expected = loaders
+            .iter()
+            .map(|loader| {
+                chunks
+                    .iter()
+                    .map(|chunk| loader.get_frequencies(chunk, strategy.as_ref(), &cache))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        for (chunk, frequencies) in chunks.iter().zip(expected[0].iter()) {
+            assert!(frequencies.len() > chunk.len(), "children were not loaded");
+        }
+        for other in expected.iter().skip(1) {
+            assert_eq!(other, &expected[0], "loaders disagree");
+        }
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(THREADS)
+            .build()
+            .unwrap();
+        pool.install(|| {
+            for round in 0..ROUNDS {
+                let found = (0..loaders.len() * chunks.len())
+                    .into_par_iter()
+                    .map(|i| {
+                        let (loader, chunk) = (i % loaders.len(), i / loaders.len());
+                        return (
+                            loader,
+                            chunk,
+                            loaders[loader].get_frequencies(
+                                &chunks[chunk],
+                                strategy.as_ref(),
+                                &cache,
+                            ),
+                        );
+                    })
+                    .collect::<Vec<_>>();
+
+                for (loader, chunk, frequencies) in found {
+                    assert_eq!(
+                        frequencies,
+                        expected[loader][chunk],
+                        "round {} differs for chunk {} in {}",
+                        round,
+                        chunk,
+                        dir.display()
+                    );
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn concurrent_get_frequencies() {
+        let (db, parquet) = fixture();
+        let duckdb = DuckDBLoader::new(db.clone());
+        let parquet = ParquetLoader::new(parquet);
+
+        stress(&[&duckdb], &db);
+        stress(&[&parquet], &parquet.input);
+        stress(&[&duckdb, &parquet], &db);
+
+        for connections in [&duckdb.connections, &parquet.connections] {
+            let idle = connections.idle().len();
+            assert!(idle > 0 && idle <= THREADS + 1, "{} idle connections", idle);
+        }
+
+        drop(duckdb);
+        drop(parquet);
+        fs::remove_dir_all(db.parent().unwrap()).unwrap();
+    }
+}
@@ -1,22 +1,140 @@
 use ndarray::Array1;
+use std::{fmt, str::FromStr};
 
-pub(crate) fn z_normalize(x: &Array1<f64>, y: &Array1<f64>) -> (Array1<f64>, Array1<f64>) {
-    let x_mean = x.mean().unwrap();
-    let x_std = *x.std_axis(ndarray::Axis(0), 0.).iter().next().unwrap();
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Normalization {
+    #[default]
+    Z,
+    MinMax,
+    Relative,
+    Log1p,
+    Raw,
+}
+
+impl Normalization {
+    pub const VARIANTS: &'static [&'static str] = &["z", "minmax", "relative", "log1p", "raw"];
+
+    pub fn normalize(
+        &self,
+        x: &Array1<f64>,
+        y: &Array1<f64>,
+        w: &Array1<f64>,
+    ) -> (Array1<f64>, Array1<f64>) {
+        return match self {
+            Normalization::Z => z_normalize(x, y, w),
+            Normalization::MinMax => minmax_normalize(x, y, w),
+            Normalization::Relative => relative_normalize(x, y, w),
+            Normalization::Log1p => (x.mapv(|v| v.max(0.).ln_1p()), y.mapv(|v| v.max(0.).ln_1p())),
+            Normalization::Raw => (x.to_owned(), y.to_owned()),
+        };
+    }
+}
+
+impl FromStr for Normalization {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s {
+            "z" => Ok(Normalization::Z),
+            "minmax" => Ok(Normalization::MinMax),
+            "relative" => Ok(Normalization::Relative),
+            "log1p" => Ok(Normalization::Log1p),
+            "raw" => Ok(Normalization::Raw),
+            _ => Err(format!("unknown normalization: {}", s)),
+        };
+    }
+}
+
+impl fmt::Display for Normalization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(
+            f,
+            "{}",
+            match self {
+                Normalization::Z => "z",
+                Normalization::MinMax => "minmax",
+                Normalization::Relative => "relative",
+                Normalization::Log1p => "log1p",
+                Normalization::Raw => "raw",
+            }
+        );
+    }
+}
+
+fn scale_or_one(scale: f64) -> f64 {
+    if scale.is_finite() && scale > 0. {
+        return scale;
+    }
+
+    return 1.;
+}
+
+fn masked(x: &Array1<f64>, w: &Array1<f64>) -> Array1<f64> {
+    let masked = x
+        .iter()
+        .zip(w.iter())
+        .filter(|(_, w)| **w > 0.)
+        .map(|(x, _)| *x)
+        .collect::<Array1<f64>>();
+
+    if masked.is_empty() {
+        return x.to_owned();
+    }
+
+    return masked;
+}
+
+pub(crate) fn z_normalize(
+    x: &Array1<f64>,
+    y: &Array1<f64>,
+    w: &Array1<f64>,
+) -> (Array1<f64>, Array1<f64>) {
+    let x_masked = masked(x, w);
+    let x_mean = x_masked.mean().unwrap();
+    let x_std = scale_or_one(
+        *x_masked
+            .std_axis(ndarray::Axis(0), 0.)
+            .iter()
+            .next()
+            .unwrap(),
+    );
 
     return ((x - x_mean) / x_std, (y - x_mean) / x_std);
 }
 
-pub(crate) fn linf_dist(x: &Array1<f64>, y: &Array1<f64>) -> f64 {
-    let v = (x - y).mapv(f64::abs);
+pub(crate) fn minmax_normalize(
+    x: &Array1<f64>,
+    y: &Array1<f64>,
+    w: &Array1<f64>,
+) -> (Array1<f64>, Array1<f64>) {
+    let x_masked = masked(x, w);
+    let x_min = x_masked.fold(f64::INFINITY, |acc, elem| acc.min(*elem));
+    let x_max = x_masked.fold(f64::NEG_INFINITY, |acc, elem| acc.max(*elem));
+    let range = scale_or_one(x_max - x_min);
+
+    return ((x - x_min) / range, (y - x_min) / range);
+}
+
+pub(crate) fn relative_normalize(
+    x: &Array1<f64>,
+    y: &Array1<f64>,
+    w: &Array1<f64>,
+) -> (Array1<f64>, Array1<f64>) {
+    let x_mean = scale_or_one(masked(x, w).mapv(f64::abs).mean().unwrap());
+
+    return (x / x_mean, y / x_mean);
+}
+
+pub(crate) fn linf_dist(x: &Array1<f64>, y: &Array1<f64>, w: &Array1<f64>) -> f64 {
+    let v = (x - y).mapv(f64::abs) * w;
     return v.fold(f64::NEG_INFINITY, |acc, elem| acc.max(*elem));
 }
 
-pub(crate) fn l1_dist(x: &Array1<f64>, y: &Array1<f64>) -> f64 {
-    return (x - y).mapv(f64::abs).sum();
+pub(crate) fn l1_dist(x: &Array1<f64>, y: &Array1<f64>, w: &Array1<f64>) -> f64 {
+    return ((x - y).mapv(f64::abs) * w).sum();
 }
 
-pub(crate) fn rmse(x: &Array1<f64>, y: &Array1<f64>) -> f64 {
-    let v = (x - y).mapv(|x| x * x);
-    return (v.sum() / v.len() as f64).sqrt();
+pub(crate) fn rmse(x: &Array1<f64>, y: &Array1<f64>, w: &Array1<f64>) -> f64 {
+    let v = (x - y).mapv(|x| x * x) * w;
+    return (v.sum() / scale_or_one(w.sum())).sqrt();
 }
@@ -1,7 +1,45 @@
 pub mod optimize;
+pub use basis::{Basis, BasisSource};
+pub use candidates::{
+    CandidateContext, CandidateProvider, CandidateSource, Children, Correlated, Pool, Siblings,
+    Variants,
+};
+pub use compressor::{ChildCombination, Compressor, Dct, PiecewiseLinear, SvdBasis};
+pub use dag::{Dag, Node};
+pub use dictionary::Dictionary;
+pub use dump::LpDump;
+pub use encoding::{decode_raw, decode_residuals, encode_residuals, RawEncoding};
+pub use load::{DuckDBLoader, Load, Loader, ParquetLoader};
+pub use math::Normalization;
 pub use optimize::{optimize, Optimize};
+pub use optimizer::{Optimizer, OptimizerBuilder};
+pub use quantize::{dequantize, Quantization, QuantizationFallback};
+pub use solution::{
+    pseudo_series, Coefficient, CoefficientBounds, Patch, Solution, INTERCEPT, TREND,
+};
+pub use solver::{LinearProgram, SolveStatus, Solver, SolverBackend, SolverOption, DEFAULT_SOLVER};
+pub use strategy::{ChildStrategy, Strategy, Substitution, DEFAULT_STRATEGY};
+pub use util::ChildCache;
+pub use weights::YearWeights;
 
+mod basis;
+mod candidates;
+mod compressor;
+mod dag;
+mod dictionary;
+mod dump;
+mod encoding;
+#[cfg(feature = "highs")]
+mod highs;
 mod load;
 mod math;
+mod optimizer;
+mod quantize;
+mod simplex;
 mod solution;
+mod solver;
+mod strategy;
 mod util;
+#[cfg(feature = "highs")]
+mod warm;
+mod weights;
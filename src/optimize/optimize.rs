@@ -1,15 +1,26 @@
-use super::load::{DuckDBLoader, Load, Loader, ParquetLoader};
-use super::math::{l1_dist, linf_dist, rmse, z_normalize};
-use super::solution::{Coefficient, Solution};
-use super::util::get_children;
-use cfg_if::cfg_if;
+use super::basis::{Basis, BasisSource};
+use super::candidates::{CandidateContext, CandidateSource, Pool};
+use super::dag::{Dag, Node};
+use super::dictionary::Dictionary;
+use super::encoding::{encode_residuals, RawEncoding};
+use super::load::Load;
+use super::math::{l1_dist, linf_dist, rmse, Normalization};
+use super::optimizer::Optimizer;
+use super::quantize::{Quantization, QuantizationFallback};
+use super::solution::{
+    pseudo_series, Coefficient, CoefficientBounds, Patch, Solution, INTERCEPT, TREND,
+};
+use super::solver::{LinearProgram, SolveStatus, SolverBackend, SolverOption, DEFAULT_SOLVER};
+use super::strategy::{ChildStrategy, Strategy, Substitution, DEFAULT_STRATEGY};
+use super::util::{dedup, ChildCache, SolveStats};
+use super::weights::YearWeights;
+use crate::decompress::reconstruct;
+use crate::totals::{parse_totals, write_totals};
 use hashbrown::{HashMap, HashSet};
-use highs::{RowProblem, Sense};
 use ndarray::{arr1, arr2};
 use polars::prelude::*;
 use rayon::prelude::*;
-use std::ffi::OsStr;
-use std::{fs, path::PathBuf};
+use std::{fs, path::PathBuf, sync::Arc, time::Instant};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
@@ -29,57 +40,362 @@ pub struct Optimize {
     pub output_all: bool,
     #[structopt(short = "C", long = "cores")]
     pub core_count: Option<usize>,
+    #[structopt(
+        long = "strategy",
+        default_value = DEFAULT_STRATEGY,
+        help = "recursive, highly-selective, direct-children, non-selective, contiguous[:k], skip-grams[:k] or unigrams"
+    )]
+    pub strategy: Strategy,
+    #[structopt(short = "n", long = "max-n", default_value = "5")]
+    pub max_n: usize,
+    #[structopt(short = "e", long = "reject-end-to-end")]
+    pub reject_end_to_end: bool,
+    #[structopt(
+        long = "normalization",
+        default_value = "z",
+        possible_values = Normalization::VARIANTS
+    )]
+    pub normalization: Normalization,
+    #[structopt(
+        long = "year-weights",
+        default_value = "uniform",
+        help = "uniform, linear-ramp or a csv file of year,weight lines"
+    )]
+    pub year_weights: YearWeights,
+    #[structopt(long = "totals", parse(try_from_str = parse_totals))]
+    pub totals: Option<[f64; 201]>,
+    #[structopt(long = "intercept")]
+    pub intercept: bool,
+    #[structopt(long = "trend")]
+    pub trend: bool,
+    #[structopt(long = "coef-bounds", default_value = "0:")]
+    pub coef_bounds: CoefficientBounds,
+    #[structopt(
+        long = "signed",
+        conflicts_with = "coef-bounds",
+        help = "allow coefficients of any sign, same as --coef-bounds :"
+    )]
+    pub signed: bool,
+    #[structopt(long = "clamp")]
+    pub clamp: bool,
+    #[structopt(
+        long = "quantize",
+        default_value = "f64",
+        help = "f64, f32, f16 or fixed[:bits]"
+    )]
+    pub quantization: Quantization,
+    #[structopt(
+        long = "quantize-fallback",
+        default_value = "full",
+        possible_values = QuantizationFallback::VARIANTS
+    )]
+    pub quantization_fallback: QuantizationFallback,
+    #[structopt(
+        long = "raw-encoding",
+        default_value = "auto",
+        possible_values = RawEncoding::VARIANTS
+    )]
+    pub raw_encoding: RawEncoding,
+    #[structopt(
+        long = "patch-threshold",
+        help = "store rejected n-grams as coefficients plus corrections for every year whose residual exceeds this many counts"
+    )]
+    pub patch_threshold: Option<f64>,
+    #[structopt(long = "max-patches", default_value = "20")]
+    pub max_patches: usize,
+    #[structopt(
+        long = "lossless",
+        help = "store integer residuals so every count is reconstructed exactly; ignores -b, --quantize and --patch-threshold"
+    )]
+    pub lossless: bool,
+    #[structopt(
+        long = "basis-size",
+        default_value = "0",
+        help = "number of shared series every n-gram may use in addition to its children"
+    )]
+    pub basis_size: usize,
+    #[structopt(
+        long = "basis-source",
+        default_value = "frequent",
+        possible_values = BasisSource::VARIANTS
+    )]
+    pub basis_source: BasisSource,
+    #[structopt(
+        long = "candidates",
+        default_value = "children",
+        use_delimiter = true,
+        help = "comma separated list of children, siblings, variants and correlated[:k]"
+    )]
+    pub candidates: Vec<CandidateSource>,
+    #[structopt(
+        long = "max-candidates",
+        help = "only pass the candidates that correlate best with the n-gram to the LP"
+    )]
+    pub max_candidates: Option<usize>,
+    #[structopt(
+        long = "solver",
+        default_value = DEFAULT_SOLVER,
+        possible_values = SolverBackend::VARIANTS
+    )]
+    pub solver: SolverBackend,
+    #[structopt(
+        long = "cold-start",
+        help = "build every HiGHS model from scratch instead of reusing the one of the worker thread"
+    )]
+    pub cold_start: bool,
+    #[structopt(
+        long = "solver-opt",
+        number_of_values = 1,
+        help = "key=value option passed to the solver, may be repeated"
+    )]
+    pub solver_options: Vec<SolverOption>,
+    #[structopt(long = "time-limit", help = "seconds each LP may take")]
+    pub time_limit: Option<f64>,
+    #[structopt(
+        long = "dump-lp",
+        number_of_values = 2,
+        max_values = 2,
+        value_names = &["pattern", "dir"],
+        help = "write the LP of every n-gram matching the pattern (* and ? wildcards) to dir as MPS, with its series as CSV"
+    )]
+    pub dump_lp: Option<Vec<String>>,
 }
 
-pub fn optimize(
-    input: PathBuf,
-    output: PathBuf,
-    chunk_size: u64,
-    error_bound: f64,
-    verbose_output: bool,
-    output_all: bool,
-    core_count: Option<usize>,
-) {
-    cfg_if! {
-        if #[cfg(any(feature = "non-selective", feature = "direct-children"))] {
-            let mut compressed_frequencies_map: HashMap<String, [f64; 201]> = HashMap::new();
-            let compressed_frequencies: HashSet<String> = HashSet::new();
-        } else {
-            let mut compressed_frequencies: HashSet<String> = HashSet::new();
+/// Compresses every n-gram the loader provides into the output directory of the optimizer.
+/// Fails before anything is loaded if the solver rejects the options.
+pub fn optimize(optimizer: &Optimizer, loader: &dyn Load) -> Result<(), String> {
+    optimizer
+        .solver
+        .solver(!optimizer.cold_start)
+        .check_options(&optimizer.lp_options())?;
+
+    let mut optimizer = optimizer.clone();
+    if optimizer.basis.is_none() && optimizer.basis_size > 0 {
+        let sample = loader.get_slice(
+            (optimizer.chunk_size as usize).min(loader.get_count(1)),
+            0,
+            1,
+        );
+        optimizer.basis = Some(Basis::learn(
+            &sample,
+            optimizer.basis_size,
+            optimizer.basis_source,
+        ));
+    }
+    // A rejected solution is only written with -a, and patches and residuals are built on top
+    // of rejected fits, so only otherwise can hopeless LPs be skipped.
+    optimizer.prescreen =
+        !optimizer.output_all && !optimizer.lossless && optimizer.patch_threshold.is_none();
+    optimizer.stats = Arc::new(SolveStats::new());
+    let optimizer = &optimizer;
+
+    let Optimizer {
+        output,
+        chunk_size,
+        error_bound,
+        verbose_output,
+        output_all,
+        core_count,
+        strategy,
+        max_n,
+        reject_end_to_end,
+        normalization,
+        quantization,
+        quantization_fallback,
+        raw_encoding,
+        patch_threshold,
+        max_patches,
+        lossless,
+        ..
+    } = optimizer.clone();
+
+    // Residuals are computed from the final coefficients and make every stored n-gram exact,
+    // so the lossy refinements of the coefficients have nothing left to do.
+    let (quantization, patch_threshold) = match lossless {
+        true => (Quantization::F64, None),
+        false => (quantization, patch_threshold),
+    };
+
+    let strategy = strategy.child_strategy();
+    let substitution = strategy.substitution();
+    let track = verbose_output || reject_end_to_end;
+    let accepted = |sol: &Solution| match lossless {
+        true => sol.residuals.is_some(),
+        false => {
+            sol.error <= error_bound && (!reject_end_to_end || sol.end_to_end_error <= error_bound)
         }
+    };
+
+    let mut dag = Dag::new();
+    let mut dictionary = Dictionary::new();
+
+    let metadata: Vec<(&str, String)> = vec![
+        ("strategy", optimizer.strategy.to_string()),
+        ("normalization", normalization.to_string()),
+        ("error_bound", error_bound.to_string()),
+        ("year_weights", optimizer.year_weights.to_string()),
+        (
+            "frequencies",
+            match optimizer.totals {
+                Some(_) => "per-million".to_string(),
+                None => "absolute".to_string(),
+            },
+        ),
+        ("intercept", optimizer.intercept.to_string()),
+        ("trend", optimizer.trend.to_string()),
+        ("coef_bounds", optimizer.coef_bounds.to_string()),
+        ("clamp", optimizer.clamp.to_string()),
+        ("quantization", quantization.to_string()),
+        ("quantization_fallback", quantization_fallback.to_string()),
+        ("raw_encoding", raw_encoding.to_string()),
+        (
+            "patch_threshold",
+            patch_threshold.map_or("none".to_string(), |x| x.to_string()),
+        ),
+        ("max_patches", max_patches.to_string()),
+        ("lossless", lossless.to_string()),
+        (
+            "basis_size",
+            optimizer.basis.as_ref().map_or(0, |x| x.len()).to_string(),
+        ),
+        ("basis_source", optimizer.basis_source.to_string()),
+        (
+            "candidates",
+            optimizer
+                .candidates
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        ("solver", optimizer.solver.to_string()),
+        (
+            "solver_options",
+            optimizer
+                .lp_options()
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        (
+            "max_candidates",
+            optimizer
+                .max_candidates
+                .map_or("none".to_string(), |x| x.to_string()),
+        ),
+    ];
+    let mut scales: Vec<(String, String)> = Vec::new();
+
+    if let Some(basis) = optimizer.basis.as_ref().filter(|x| !x.is_empty()) {
+        fs::create_dir_all(&output).unwrap();
+        basis.write(&output.join("basis.parquet"));
+    }
+
+    if let Some(totals) = &optimizer.totals {
+        fs::create_dir_all(&output).unwrap();
+        write_totals(totals, &output.join("totals.parquet"));
     }
 
-    let loader = Loader::new(match input.extension().and_then(OsStr::to_str) {
-        Some("db") => Box::new(DuckDBLoader { input }),
-        _ => Box::new(ParquetLoader { input }),
-    });
+    let compressed_schema = |quantization: Quantization| {
+        Schema::from_iter(
+            vec![
+                Field::new("ngram", DataType::String),
+                Field::new(
+                    "coefficients",
+                    DataType::List(Box::new(DataType::Struct(vec![
+                        Field::new("child_id", DataType::UInt32),
+                        Field::new("coefficient", quantization.data_type()),
+                    ]))),
+                ),
+                Field::new(
+                    "patches",
+                    DataType::List(Box::new(DataType::Struct(vec![
+                        Field::new("year", DataType::UInt16),
+                        Field::new("correction", DataType::Float64),
+                    ]))),
+                ),
+                Field::new("residuals", DataType::Binary),
+                Field::new("error", DataType::Float64),
+                Field::new("rmse", DataType::Float64),
+                Field::new("summed_error", DataType::Float64),
+                Field::new("end_to_end_error", DataType::Float64),
+                Field::new("worst_case_error", DataType::Float64),
+                Field::new("status", DataType::String),
+            ][..(if verbose_output { 10 } else { 4 })]
+                .to_vec(),
+        )
+    };
 
-    let compressed_schema = Schema::from_iter(
-        vec![
+    let compressed_row = |sol: &Solution,
+                          dictionary: &Dictionary,
+                          quantization: Quantization,
+                          scale: f64| {
+        polars::frame::row::Row::new(
+            vec![
+                AnyValue::StringOwned(sol.ngram.clone().into()),
+                AnyValue::List(
+                    DataFrame::new(vec![
+                        Series::new(
+                            "child_id",
+                            sol.coefficients
+                                .iter()
+                                .map(|x| dictionary.id(&x.token).unwrap())
+                                .collect::<Vec<_>>(),
+                        ),
+                        quantization.encode(
+                            "coefficient",
+                            &sol.coefficients
+                                .iter()
+                                .map(|x| x.coefficient)
+                                .collect::<Vec<_>>(),
+                            scale,
+                        ),
+                    ])
+                    .unwrap()
+                    .into_struct("coefficients")
+                    .into_series(),
+                ),
+                AnyValue::List(
+                    df![
+                        "year" => sol.patches.iter().map(|x| x.year).collect::<Vec<_>>(),
+                        "correction" => sol.patches.iter().map(|x| x.correction).collect::<Vec<_>>(),
+                    ]
+                    .unwrap()
+                    .into_struct("patches")
+                    .into_series(),
+                ),
+                match &sol.residuals {
+                    Some(residuals) => AnyValue::BinaryOwned(encode_residuals(residuals)),
+                    None => AnyValue::Null,
+                },
+                AnyValue::Float64(sol.error),
+                AnyValue::Float64(sol.rmse),
+                AnyValue::Float64(sol.summed_error),
+                AnyValue::Float64(sol.end_to_end_error),
+                AnyValue::Float64(sol.worst_case_error),
+                AnyValue::StringOwned(sol.status.to_string().into()),
+            ][..(if verbose_output { 10 } else { 4 })]
+                .to_vec(),
+        )
+    };
+
+    let uncompressed_schema = match raw_encoding {
+        RawEncoding::Array => Schema::from_iter(vec![
             Field::new("ngram", DataType::String),
             Field::new(
-                "coefficients",
-                DataType::List(Box::new(DataType::Struct(vec![
-                    Field::new("token", DataType::String),
-                    Field::new("coefficient", DataType::Float64),
-                ]))),
+                "frequency",
+                DataType::Array(Box::new(DataType::Float64), 201),
             ),
-            Field::new("error", DataType::Float64),
-            Field::new("rmse", DataType::Float64),
-            Field::new("summed_error", DataType::Float64),
-        ][..(if verbose_output { 5 } else { 2 })]
-            .to_vec(),
-    );
-
-    let uncompressed_schema = Schema::from_iter(vec![
-        Field::new("ngram", DataType::String),
-        Field::new(
-            "frequency",
-            DataType::Array(Box::new(DataType::Float64), 201),
-        ),
-    ]);
+        ]),
+        _ => Schema::from_iter(vec![
+            Field::new("ngram", DataType::String),
+            Field::new("encoding", DataType::String),
+            Field::new("data", DataType::Binary),
+        ]),
+    };
 
-    for n in 1..6 {
+    for n in 1..=max_n {
         let count = loader.get_count(n);
         let cpu_count = core_count.unwrap_or_else(|| num_cpus::get() / 2);
 
@@ -91,7 +407,8 @@ pub fn optimize(
         std::fs::create_dir_all(&outdir_uncompressed).unwrap();
 
         for i in (0..count).step_by(chunk_size as usize) {
-            let solutions = (0..=cpu_count)
+            let cache = ChildCache::new();
+            let results = (0..=cpu_count)
                 .into_par_iter()
                 .map(|j| {
                     let chunk = loader.get_slice(
@@ -100,88 +417,225 @@ pub fn optimize(
                         n as u8,
                     );
 
-                    cfg_if! {
-                        if #[cfg(any(feature = "non-selective", feature = "direct-children"))] {
-                            let mut frequencies = loader.get_frequencies(&chunk);
-                            frequencies.extend(compressed_frequencies_map.clone());
-                        } else if #[cfg(feature = "highly-selective")] {
-                            let frequencies = loader.get_frequencies(&chunk);
-                        } else {
-                            let mut frequencies = loader.get_frequencies(&chunk);
-                            frequencies.extend(compressed_frequencies.clone().iter()
-                                .map(|x| (x.to_string(), [0.; 201]))
-                                .collect::<HashMap<_, _>>());
+                    let mut frequencies = loader.get_frequencies(&chunk, strategy.as_ref(), &cache);
+                    match substitution {
+                        Substitution::Approximated => {
+                            frequencies.extend(
+                                dag.reconstructions()
+                                    .map(|(x, reconstructed)| (x.to_string(), *reconstructed))
+                                    .collect::<HashMap<_, _>>(),
+                            );
                         }
+                        Substitution::Zeroed => {
+                            frequencies.extend(
+                                dag.ngrams()
+                                    .map(|x| (x.to_string(), [0.; 201]))
+                                    .collect::<HashMap<_, _>>(),
+                            );
+                        }
+                        Substitution::Original => {}
+                    }
+
+                    if let Some(basis) = &optimizer.basis {
+                        frequencies.extend(basis.atoms.iter().cloned());
                     }
 
-                    return chunk
+                    let pool = Pool::from_chunk(&chunk, &optimizer.candidates);
+
+                    let solutions = chunk
                         .into_par_iter()
                         .map(|(ngram, _)| {
-                            minimize_abs_error(&ngram, &frequencies, &compressed_frequencies)
+                            let mut sol = minimize_abs_error(
+                                &ngram,
+                                &frequencies,
+                                &dag,
+                                strategy.as_ref(),
+                                &cache,
+                                &pool,
+                                optimizer,
+                            );
+
+                            if track {
+                                propagate_error(&mut sol, &frequencies, &dag, optimizer);
+                            }
+
+                            return sol;
                         })
                         .collect::<Vec<_>>();
+
+                    return (solutions, frequencies);
                 })
-                .flatten()
                 .collect::<Vec<_>>();
 
-            cfg_if! {
-                if #[cfg(any(feature = "non-selective", feature = "direct-children"))] {
-                    compressed_frequencies_map.extend(
-                        solutions
-                            .clone()
-                            .into_par_iter()
-                            .map(|sol| match sol.error <= error_bound {
-                                true => Some((sol.ngram.clone(), sol.calculated)),
-                                false => None,
-                            })
-                            .filter_map(|x| x)
-                            .collect::<HashMap<_, _>>(),
-                    );
-                } else {
-                    compressed_frequencies.extend(
+            // One fixed-point scale per file, so it can only be chosen once the whole chunk is solved.
+            let scale = quantization.scale(
+                results
+                    .iter()
+                    .flat_map(|(solutions, _)| solutions.iter())
+                    .filter(|sol| accepted(sol))
+                    .flat_map(|sol| sol.coefficients.iter().map(|x| x.coefficient)),
+            );
+
+            let (solutions, quantized): (Vec<Solution>, Vec<bool>) = results
+                .into_par_iter()
+                .flat_map(|(solutions, frequencies)| {
+                    solutions
+                        .into_iter()
+                        .map(|sol| {
+                            if quantization == Quantization::F64 || !accepted(&sol) {
+                                return (sol, true);
+                            }
+
+                            return requantize(
+                                sol,
+                                &frequencies,
+                                &dag,
+                                scale,
+                                track,
+                                optimizer,
+                                &accepted,
+                            );
+                        })
+                        .map(|(sol, quantized)| match lossless {
+                            true => (
+                                with_residuals(sol, &frequencies, raw_encoding, optimizer),
+                                quantized,
+                            ),
+                            false => (sol, quantized),
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unzip();
+
+            let solutions = match patch_threshold {
+                Some(threshold) => solutions
+                    .into_par_iter()
+                    .map(|sol| {
+                        if accepted(&sol) || sol.coefficients.is_empty() {
+                            return sol;
+                        }
+
+                        let patched = patch(&sol, threshold, track, optimizer);
+                        return match accepted(&patched) && patched.patches.len() <= max_patches {
+                            true => patched,
+                            false => sol,
+                        };
+                    })
+                    .collect::<Vec<_>>(),
+                None => solutions,
+            };
+
+            // Same-level candidates may point at each other within a chunk. Only keep references
+            // to n-grams that end up stored raw, so the chunk cannot introduce a cycle.
+            let (solutions, quantized): (Vec<Solution>, Vec<bool>) =
+                match optimizer.candidates.iter().any(|x| x.same_level()) {
+                    true => {
+                        let chunk_accepted = solutions
+                            .iter()
+                            .filter(|sol| accepted(sol))
+                            .map(|sol| sol.ngram.clone())
+                            .collect::<HashSet<_>>();
+
                         solutions
-                            .clone()
-                            .into_par_iter()
-                            .map(|sol| match sol.error <= error_bound {
-                                true => Some(sol.ngram),
-                                false => None,
+                            .into_iter()
+                            .zip(quantized)
+                            .map(|(sol, quantized)| {
+                                match accepted(&sol)
+                                    && sol
+                                        .coefficients
+                                        .iter()
+                                        .any(|x| chunk_accepted.contains(&x.token))
+                                {
+                                    true => (
+                                        Solution {
+                                            status: sol.status,
+                                            ..Solution::unsolved(&sol.ngram, &sol.original)
+                                        },
+                                        true,
+                                    ),
+                                    false => (sol, quantized),
+                                }
                             })
-                            .filter_map(|x| x)
-                            .collect::<Vec<_>>(),
-                    );
+                            .unzip()
+                    }
+                    false => (solutions, quantized),
+                };
+
+            for sol in solutions.iter().filter(|sol| accepted(sol)) {
+                dag.insert(
+                    sol.ngram.clone(),
+                    Node {
+                        coefficients: sol.coefficients.clone(),
+                        patches: sol.patches.clone(),
+                        reconstructed: match track || substitution == Substitution::Approximated {
+                            true if lossless => Some(sol.original),
+                            true => Some(sol.calculated),
+                            false => None,
+                        },
+                        deviation: match track {
+                            true if lossless => Some([0.; 201]),
+                            true => Some(sol.deviation),
+                            false => None,
+                        },
+                        residuals: sol.residuals,
+                    },
+                );
+            }
+
+            for sol in solutions.iter().filter(|sol| output_all || accepted(sol)) {
+                for coefficient in sol.coefficients.iter() {
+                    dictionary.insert(&coefficient.token);
                 }
             }
 
-            let compressed = solutions.clone().into_par_iter()
-                .filter(|sol| output_all || sol.error <= error_bound)
-                .map(|sol|polars::frame::row::Row::new(vec![
-                    AnyValue::StringOwned(sol.ngram.clone().into()),
-                    AnyValue::List(
-                        df![
-                            "token" => sol.coefficients.iter().map(|x| x.token.clone()).collect::<Vec<_>>(),
-                            "coefficient" => sol.coefficients.iter().map(|x| x.coefficient).collect::<Vec<_>>(),
-                            ].unwrap().into_struct("coefficients").into_series(),
-                        ),
-                    AnyValue::Float64(sol.error),
-                    AnyValue::Float64(sol.rmse),
-                    AnyValue::Float64(sol.summed_error),
-                ][..(if verbose_output { 5 } else { 2 })].to_vec()))
+            let compressed = solutions
+                .par_iter()
+                .zip(quantized.par_iter())
+                .filter(|(sol, quantized)| **quantized && (output_all || accepted(sol)))
+                .map(|(sol, _)| compressed_row(sol, &dictionary, quantization, scale))
                 .collect::<Vec<_>>();
 
             write(
                 &compressed,
-                &compressed_schema,
+                &compressed_schema(quantization),
                 outdir_compressed.join(format!("{}.parquet", i)),
             );
 
+            if let Quantization::Fixed(_) = quantization {
+                scales.push((format!("scale:n={}/{}.parquet", n, i), scale.to_string()));
+            }
+
+            let full = solutions
+                .par_iter()
+                .zip(quantized.par_iter())
+                .filter(|(_, quantized)| !**quantized)
+                .map(|(sol, _)| compressed_row(sol, &dictionary, Quantization::F64, 1.))
+                .collect::<Vec<_>>();
+
+            if !full.is_empty() {
+                write(
+                    &full,
+                    &compressed_schema(Quantization::F64),
+                    outdir_compressed.join(format!("{}-full.parquet", i)),
+                );
+            }
+
             let uncompressed = solutions
                 .into_par_iter()
-                .filter(|sol| sol.error > error_bound)
-                .map(|sol| {
-                    polars::frame::row::Row::new(vec![
+                .filter(|sol| !accepted(sol))
+                .map(|sol| match raw_encoding {
+                    RawEncoding::Array => polars::frame::row::Row::new(vec![
                         AnyValue::StringOwned(sol.ngram.clone().into()),
                         AnyValue::Array(sol.original.iter().collect(), 201),
-                    ])
+                    ]),
+                    _ => {
+                        let (encoding, data) = raw_encoding.encode(&sol.original);
+                        polars::frame::row::Row::new(vec![
+                            AnyValue::StringOwned(sol.ngram.clone().into()),
+                            AnyValue::StringOwned(encoding.into()),
+                            AnyValue::BinaryOwned(data),
+                        ])
+                    }
                 })
                 .collect::<Vec<_>>();
 
@@ -192,6 +646,19 @@ pub fn optimize(
             );
         }
     }
+
+    println!("{}", optimizer.stats);
+
+    dictionary.write(&output.join("dictionary.parquet"));
+    write_metadata(
+        metadata
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .chain(scales),
+        output.join("metadata.parquet"),
+    );
+
+    return Ok(());
 }
 
 fn write(rows: &Vec<polars::frame::row::Row>, schema: &Schema, path: PathBuf) {
@@ -203,43 +670,81 @@ fn write(rows: &Vec<polars::frame::row::Row>, schema: &Schema, path: PathBuf) {
         .expect("writing parquet file");
 }
 
-fn minimize_abs_error(
+fn write_metadata(entries: impl Iterator<Item = (String, String)>, path: PathBuf) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+    let (keys, values): (Vec<String>, Vec<String>) = entries.unzip();
+    let mut f = fs::File::create(path).unwrap();
+    let mut df = df![
+        "key" => keys,
+        "value" => values,
+    ]
+    .unwrap();
+    ParquetWriter::new(&mut f)
+        .with_compression(ParquetCompression::Uncompressed)
+        .finish(&mut df)
+        .expect("writing parquet file");
+}
+
+pub(crate) fn minimize_abs_error(
     ngram: &str,
     frequencies: &HashMap<String, [f64; 201]>,
-    compressed_frequencies: &HashSet<String>,
+    dag: &Dag,
+    strategy: &dyn ChildStrategy,
+    cache: &ChildCache,
+    pool: &Pool,
+    optimizer: &Optimizer,
 ) -> Solution {
-    let children: Vec<String>;
-
-    cfg_if! {
-        if #[cfg(feature = "highly-selective")] {
-            let _children: HashSet<_> =
-            HashSet::from_iter(get_children(ngram, false, &HashSet::new()).clone());
-            let filtered_compressed = compressed_frequencies
-                .intersection(&_children)
-                .map(|x| x.to_string())
-                .collect::<HashSet<_>>();
-            children = get_children(ngram, false, &filtered_compressed);
-        } else {
-            children = get_children(ngram, false, &HashSet::new());
-        }
-    }
+    let context = CandidateContext {
+        strategy,
+        dag,
+        cache,
+        pool,
+        frequencies,
+    };
+    let children = dedup(
+        optimizer
+            .candidates
+            .iter()
+            .flat_map(|source| source.provider().candidates(ngram, &context))
+            .collect(),
+    );
+    let weights = &optimizer.year_weights.weights;
+    let scale = optimizer.scale();
 
     let y: &[f64; 201] = frequencies.get(ngram).unwrap();
+    let atoms = optimizer
+        .basis
+        .as_ref()
+        .map_or(&[][..], |basis| &basis.atoms[..]);
 
-    if children.len() < 2 {
+    // A unigram is its own child. Without atoms it stays unsolved, but with them it would be
+    // fitted against itself and could not be decoded.
+    let children = children
+        .into_iter()
+        .filter(|child| child.as_str() != ngram)
+        .collect::<Vec<_>>();
+
+    if children.len() < 2 && atoms.is_empty() {
         return Solution::unsolved(ngram, y);
     }
 
     let child_freqs = children
         .iter()
         .map(|child| frequencies.get(child).unwrap_or_else(|| &[0.; 201]))
+        .chain(atoms.iter().map(|(_, series)| series))
         .collect::<Vec<_>>();
 
     let (children, child_freqs): (Vec<String>, Vec<&[f64; 201]>) = children
         .iter()
+        .chain(atoms.iter().map(|(token, _)| token))
         .zip(child_freqs.iter())
         .filter_map(|(child, freq)| {
-            if freq.iter().all(|x| *x == 0.) {
+            if freq
+                .iter()
+                .enumerate()
+                .all(|(i, x)| *x * scale[i] == 0. || weights[i] == 0.)
+            {
                 return None;
             }
             return Some((child.to_owned(), freq));
@@ -250,47 +755,81 @@ fn minimize_abs_error(
         return Solution::unsolved(ngram, y);
     }
 
-    let mut pb = RowProblem::new();
-    let mut c = child_freqs
+    let (children, child_freqs) = match optimizer.max_candidates {
+        Some(k) if child_freqs.len() > k => {
+            optimizer.stats.dropped(child_freqs.len() - k);
+            most_correlated(y, children, child_freqs, k, optimizer)
+        }
+        _ => (children, child_freqs),
+    };
+
+    let pseudo = [(optimizer.intercept, INTERCEPT), (optimizer.trend, TREND)]
         .iter()
-        .map(|_| pb.add_column(0., 0..))
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, token)| (token.to_string(), pseudo_series(token).unwrap()))
         .collect::<Vec<_>>();
 
-    c.push(pb.add_column(1., 0..));
-
-    for (idx, y_i) in y.iter().enumerate() {
-        let mut a_i = child_freqs
-            .iter()
-            .enumerate()
-            .map(|(i, x)| (c[i], x[idx] as f64))
-            .collect::<Vec<_>>();
-        a_i.push((*c.last().unwrap(), -1.));
-        pb.add_row(..(*y_i as f64), a_i);
+    if optimizer.prescreen
+        && pseudo.is_empty()
+        && zero_bound(y, &child_freqs, optimizer) > optimizer.error_bound
+    {
+        optimizer.stats.skipped();
+        return Solution::unsolved(ngram, y);
     }
 
-    for (idx, y_i) in y.iter().enumerate() {
-        let mut a_i = child_freqs
-            .iter()
-            .enumerate()
-            .map(|(i, x)| (c[i], x[idx] as f64 * -1.))
-            .collect::<Vec<_>>();
-        a_i.push((*c.last().unwrap(), -1.));
-        pb.add_row(..(*y_i as f64 * -1.), a_i);
-    }
+    let (children, child_freqs): (Vec<String>, Vec<&[f64; 201]>) = children
+        .into_iter()
+        .zip(child_freqs)
+        .chain(
+            pseudo
+                .iter()
+                .map(|(token, series)| (token.to_string(), series)),
+        )
+        .unzip();
 
-    let mut model = pb.optimise(Sense::Minimise);
-    model.set_option("presolve", "off");
-    model.set_option("simplex_scale_strategy", "4");
+    let w: [f64; 201] = std::array::from_fn(|i| weights[i] * scale[i]);
+    let bounds = children
+        .iter()
+        .map(|child| match pseudo_series(child) {
+            Some(_) => (f64::NEG_INFINITY, f64::INFINITY),
+            None => (optimizer.coef_bounds.lo, optimizer.coef_bounds.hi),
+        })
+        .collect::<Vec<_>>();
 
-    let model = model.try_solve();
-    if model.is_err() {
-        return Solution::unsolved(ngram, y);
+    let lp = LinearProgram {
+        y,
+        series: &child_freqs,
+        bounds: &bounds,
+        weights: &w,
+    };
+    if let Some(dump) = optimizer.dump_lp.as_ref().filter(|x| x.matches(ngram)) {
+        dump.write(ngram, &children, &lp);
     }
 
-    let sol = model.unwrap().get_solution();
+    let start = Instant::now();
+    let columns = optimizer
+        .solver
+        .solver(!optimizer.cold_start)
+        .solve(&lp, &optimizer.lp_options());
+    optimizer.stats.solved(
+        start.elapsed(),
+        columns
+            .as_ref()
+            .err()
+            .copied()
+            .unwrap_or(SolveStatus::Optimal),
+    );
+    let columns = match columns {
+        Ok(columns) => columns,
+        Err(status) => {
+            return Solution {
+                status,
+                ..Solution::unsolved(ngram, y)
+            }
+        }
+    };
 
-    let coefs = sol
-        .columns()
+    let coefs = columns
         .iter()
         .map(|x| x.to_owned())
         .zip(children)
@@ -302,26 +841,415 @@ fn minimize_abs_error(
         .collect::<Vec<_>>();
 
     if coefs.is_empty() {
-        return Solution::unsolved(ngram, y);
+        return Solution {
+            status: SolveStatus::Optimal,
+            ..Solution::unsolved(ngram, y)
+        };
     }
 
     let x: Vec<[f64; 201]> = child_freqs
         .iter()
         .map(|x| x.to_owned().to_owned())
         .collect::<Vec<_>>();
-    let c = arr1(&sol.columns()[..sol.columns().len() - 1]);
+    let c = arr1(&columns);
     let y_pred = c.dot(&arr2(&x));
+
+    return Solution {
+        status: SolveStatus::Optimal,
+        ..evaluate(
+            ngram,
+            coefs,
+            y,
+            y_pred.to_vec().try_into().unwrap(),
+            optimizer,
+        )
+    };
+}
+
+/// The `k` candidates whose weighted series correlate best with `y`, by absolute value when
+/// coefficients may be negative.
+fn most_correlated<'a>(
+    y: &[f64; 201],
+    children: Vec<String>,
+    child_freqs: Vec<&'a [f64; 201]>,
+    k: usize,
+    optimizer: &Optimizer,
+) -> (Vec<String>, Vec<&'a [f64; 201]>) {
+    let weights = &optimizer.year_weights.weights;
+    let scale = optimizer.scale();
+    let standardized = |x: &[f64; 201]| {
+        let x: [f64; 201] = std::array::from_fn(|i| x[i] * scale[i] * weights[i]);
+        let mean = x.iter().sum::<f64>() / 201.;
+        let centered = x.map(|v| v - mean);
+        let norm = centered.iter().map(|v| v * v).sum::<f64>().sqrt();
+        if norm == 0. {
+            return [0.; 201];
+        }
+        return centered.map(|v| v / norm);
+    };
+
+    let y = standardized(y);
+    let signed = optimizer.coef_bounds.lo < 0.;
+    let mut ranked = children
+        .into_iter()
+        .zip(child_freqs)
+        .map(|(child, x)| {
+            let r = standardized(x)
+                .iter()
+                .zip(y.iter())
+                .map(|(a, b)| a * b)
+                .sum::<f64>();
+            return (child, x, if signed { r.abs() } else { r });
+        })
+        .collect::<Vec<_>>();
+    ranked.sort_by(|a, b| b.2.total_cmp(&a.2));
+
+    return ranked
+        .into_iter()
+        .take(k)
+        .map(|(child, x, _)| (child, x))
+        .unzip();
+}
+
+/// Error over the years in which every candidate is zero. Any combination of the candidates
+/// predicts zero there, so no LP over them can do better.
+fn zero_bound(y: &[f64; 201], child_freqs: &[&[f64; 201]], optimizer: &Optimizer) -> f64 {
+    let zero: [bool; 201] = std::array::from_fn(|i| child_freqs.iter().all(|x| x[i] == 0.));
+    if !zero.iter().any(|x| *x) {
+        return 0.;
+    }
+
+    let y_pred: [f64; 201] = std::array::from_fn(|i| if zero[i] { 0. } else { y[i] });
+    return evaluate("", Vec::new(), y, y_pred, optimizer).error;
+}
+
+pub(crate) fn evaluate(
+    ngram: &str,
+    coefficients: Vec<Coefficient>,
+    y: &[f64; 201],
+    y_pred: [f64; 201],
+    optimizer: &Optimizer,
+) -> Solution {
+    let mut y_pred = arr1(&y_pred);
+    if optimizer.clamp {
+        y_pred.mapv_inplace(|x| x.max(0.));
+    }
     let y = arr1(y);
 
-    let (y_norm, y_pred_norm) = z_normalize(&y, &y_pred);
+    let w = arr1(&optimizer.year_weights.weights);
+    let s = arr1(&optimizer.scale());
+
+    let (y_norm, y_pred_norm) = optimizer
+        .normalization
+        .normalize(&(&y * &s), &(&y_pred * &s), &w);
+    let error = linf_dist(&y_norm, &y_pred_norm, &w);
 
     return Solution {
         ngram: ngram.to_string(),
-        coefficients: coefs,
-        error: linf_dist(&y_norm, &y_pred_norm),
-        summed_error: l1_dist(&y_norm, &y_pred_norm),
-        rmse: rmse(&y_norm, &y_pred_norm),
+        coefficients,
+        error,
+        summed_error: l1_dist(&y_norm, &y_pred_norm, &w),
+        rmse: rmse(&y_norm, &y_pred_norm, &w),
+        end_to_end_error: error,
+        worst_case_error: error,
+        deviation: (&y - &y_pred).mapv(f64::abs).to_vec().try_into().unwrap(),
         original: y.to_vec().try_into().unwrap(),
         calculated: y_pred.to_vec().try_into().unwrap(),
+        patches: Vec::new(),
+        residuals: None,
+        status: SolveStatus::NotSolved,
     };
 }
+
+/// Re-fits `sol` with its coefficients rounded to the configured storage format, so the
+/// error bound is checked against what the decompressor will actually reconstruct.
+fn quantize(
+    sol: &Solution,
+    frequencies: &HashMap<String, [f64; 201]>,
+    dag: &Dag,
+    scale: f64,
+    track: bool,
+    optimizer: &Optimizer,
+) -> Solution {
+    let coefficients = sol
+        .coefficients
+        .iter()
+        .map(|x| Coefficient {
+            token: x.token.clone(),
+            coefficient: optimizer.quantization.round_trip(x.coefficient, scale),
+        })
+        .filter(|x| x.coefficient != 0.)
+        .collect::<Vec<_>>();
+
+    if coefficients.is_empty() {
+        return Solution {
+            status: sol.status,
+            ..Solution::unsolved(&sol.ngram, &sol.original)
+        };
+    }
+
+    let y_pred = reconstruct(&coefficients, frequencies);
+    let mut quantized = evaluate(&sol.ngram, coefficients, &sol.original, y_pred, optimizer);
+    quantized.status = sol.status;
+
+    if track {
+        propagate_error(&mut quantized, frequencies, dag, optimizer);
+    }
+
+    return quantized;
+}
+
+/// Quantizes an accepted solution and re-checks it. Returns what to store and whether it goes
+/// into the quantized file; when the error bound no longer holds, the fallback decides between
+/// keeping the full-precision solution for `{i}-full.parquet` and the rejected quantized one,
+/// which is then stored raw.
+fn requantize(
+    sol: Solution,
+    frequencies: &HashMap<String, [f64; 201]>,
+    dag: &Dag,
+    scale: f64,
+    track: bool,
+    optimizer: &Optimizer,
+    accepted: &dyn Fn(&Solution) -> bool,
+) -> (Solution, bool) {
+    let requantized = quantize(&sol, frequencies, dag, scale, track, optimizer);
+
+    return match (accepted(&requantized), optimizer.quantization_fallback) {
+        (true, _) | (false, QuantizationFallback::Uncompressed) => (requantized, true),
+        (false, QuantizationFallback::Full) => (sol, false),
+    };
+}
+
+/// Replaces the years whose residual exceeds `threshold` with exact corrections. The corrections
+/// are relative to `sol.calculated`, so they are exact whenever that is what the decompressor
+/// rebuilds, i.e. with approximated substitution or end-to-end tracking.
+fn patch(sol: &Solution, threshold: f64, track: bool, optimizer: &Optimizer) -> Solution {
+    let patches = sol
+        .original
+        .iter()
+        .zip(sol.calculated.iter())
+        .enumerate()
+        .filter(|(_, (y, y_pred))| (*y - *y_pred).abs() > threshold)
+        .map(|(i, (y, y_pred))| Patch {
+            year: 1800 + i as u16,
+            correction: y - y_pred,
+        })
+        .collect::<Vec<_>>();
+
+    let mut calculated = sol.calculated;
+    let mut deviation = sol.deviation;
+    for patch in patches.iter() {
+        calculated[patch.index()] = sol.original[patch.index()];
+        deviation[patch.index()] = 0.;
+    }
+
+    let mut patched = evaluate(
+        &sol.ngram,
+        sol.coefficients.clone(),
+        &sol.original,
+        calculated,
+        optimizer,
+    );
+    patched.patches = patches;
+    patched.status = sol.status;
+
+    if track {
+        patched.worst_case_error = worst_case_error(&sol.original, &deviation, optimizer);
+        patched.deviation = deviation;
+    }
+
+    return patched;
+}
+
+/// Attaches the integer residuals between the original counts and the rounded reconstruction the
+/// decompressor will compute, as long as they are cheaper to store than the raw series.
+fn with_residuals(
+    mut sol: Solution,
+    frequencies: &HashMap<String, [f64; 201]>,
+    raw_encoding: RawEncoding,
+    optimizer: &Optimizer,
+) -> Solution {
+    if sol.coefficients.is_empty() {
+        return sol;
+    }
+
+    let mut calculated = reconstruct(&sol.coefficients, frequencies);
+    if optimizer.clamp {
+        calculated = calculated.map(|x| x.max(0.));
+    }
+
+    let residuals: [i64; 201] =
+        std::array::from_fn(|i| (sol.original[i] - calculated[i].round()) as i64);
+
+    let stored = encode_residuals(&residuals).len()
+        + sol.coefficients.len() * (std::mem::size_of::<u32>() + std::mem::size_of::<f64>());
+    if stored < raw_encoding.encode(&sol.original).1.len() {
+        sol.residuals = Some(residuals);
+    }
+
+    return sol;
+}
+
+fn propagate_error(
+    sol: &mut Solution,
+    frequencies: &HashMap<String, [f64; 201]>,
+    dag: &Dag,
+    optimizer: &Optimizer,
+) {
+    if sol.coefficients.is_empty() {
+        return;
+    }
+
+    let mut decoded = [0.; 201];
+    let mut deviation = sol.deviation;
+
+    for coefficient in sol.coefficients.iter() {
+        let node = dag.get(&coefficient.token);
+        let series = node
+            .and_then(|node| node.reconstructed)
+            .or_else(|| frequencies.get(&coefficient.token).copied())
+            .or_else(|| pseudo_series(&coefficient.token));
+
+        if let Some(series) = series {
+            for (value, x) in decoded.iter_mut().zip(series.iter()) {
+                *value += coefficient.coefficient * x;
+            }
+        }
+
+        if let Some(parent_deviation) = node.and_then(|node| node.deviation.as_ref()) {
+            for (value, x) in deviation.iter_mut().zip(parent_deviation.iter()) {
+                *value += coefficient.coefficient.abs() * x;
+            }
+        }
+    }
+
+    if optimizer.clamp {
+        decoded = decoded.map(|x| x.max(0.));
+    }
+
+    let normalization = optimizer.normalization;
+    let w = arr1(&optimizer.year_weights.weights);
+    let s = arr1(&optimizer.scale());
+    let y = arr1(&sol.original) * &s;
+    let decoded_fit = arr1(&decoded) * &s;
+    let (y_norm, decoded_norm) = normalization.normalize(&y, &decoded_fit, &w);
+
+    sol.end_to_end_error = linf_dist(&y_norm, &decoded_norm, &w);
+    sol.worst_case_error = worst_case_error(&sol.original, &deviation, optimizer);
+    sol.calculated = decoded;
+    sol.deviation = deviation;
+}
+
+fn worst_case_error(original: &[f64; 201], deviation: &[f64; 201], optimizer: &Optimizer) -> f64 {
+    let normalization = optimizer.normalization;
+    let w = arr1(&optimizer.year_weights.weights);
+    let s = arr1(&optimizer.scale());
+    let y = arr1(original) * &s;
+    let deviation_fit = arr1(deviation) * &s;
+    let (y_norm, upper_norm) = normalization.normalize(&y, &(&y + &deviation_fit), &w);
+    let (_, lower_norm) = normalization.normalize(&y, &(&y - &deviation_fit), &w);
+
+    return linf_dist(&y_norm, &upper_norm, &w).max(linf_dist(&y_norm, &lower_norm, &w));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts around 1e10, so a coefficient off by a relative 1e-10 already breaks a bound of 0.5.
+    fn child() -> [f64; 201] {
+        return std::array::from_fn(|i| 1e10 + (i * 1000) as f64);
+    }
+
+    fn optimizer(quantization: Quantization, fallback: QuantizationFallback) -> Optimizer {
+        return Optimizer::builder(PathBuf::new(), PathBuf::new())
+            .normalization(Normalization::Raw)
+            .error_bound(0.5)
+            .quantization(quantization)
+            .quantization_fallback(fallback)
+            .build();
+    }
+
+    /// Exact fit of an n-gram that is `coefficient` times the child.
+    fn fit(coefficient: f64, optimizer: &Optimizer) -> (Solution, HashMap<String, [f64; 201]>) {
+        let frequencies = HashMap::from([("a".to_string(), child())]);
+        let coefficients = vec![Coefficient {
+            token: "a".to_string(),
+            coefficient,
+        }];
+        let y = reconstruct(&coefficients, &frequencies);
+        let sol = evaluate("a b", coefficients, &y, y, optimizer);
+        assert!(sol.error <= optimizer.error_bound);
+
+        return (sol, frequencies);
+    }
+
+    fn store(coefficient: f64, file: &[f64], optimizer: &Optimizer) -> (Solution, bool) {
+        let (sol, frequencies) = fit(coefficient, optimizer);
+        let scale = optimizer.quantization.scale(file.iter().cloned());
+        let accepted = |sol: &Solution| sol.error <= optimizer.error_bound;
+
+        return requantize(
+            sol,
+            &frequencies,
+            &Dag::new(),
+            scale,
+            false,
+            optimizer,
+            &accepted,
+        );
+    }
+
+    #[test]
+    fn quantized_coefficients_within_bound_are_kept() {
+        for (quantization, coefficient) in [
+            (Quantization::F32, 1.5),
+            (Quantization::F16, 1.5),
+            (Quantization::Fixed(8), 2.),
+        ] {
+            let optimizer = optimizer(quantization, QuantizationFallback::Full);
+            let (sol, quantized) = store(coefficient, &[coefficient, 127.], &optimizer);
+
+            assert!(quantized, "{}", quantization);
+            assert!(sol.error <= 0.5, "{}: {}", quantization, sol.error);
+        }
+    }
+
+    #[test]
+    fn quantization_errors_fall_back_to_full() {
+        for (quantization, coefficient) in [
+            (Quantization::F32, 1. + 1e-9),
+            (Quantization::F16, 1.0003),
+            (Quantization::Fixed(8), 1.4),
+        ] {
+            let optimizer = optimizer(quantization, QuantizationFallback::Full);
+            let (sol, quantized) = store(coefficient, &[coefficient, 127.], &optimizer);
+
+            assert!(!quantized, "{} was stored quantized", quantization);
+            assert_eq!(sol.coefficients[0].coefficient, coefficient);
+            assert!(sol.error <= 0.5, "{}: {}", quantization, sol.error);
+
+            let optimizer = Optimizer {
+                quantization_fallback: QuantizationFallback::Uncompressed,
+                ..optimizer
+            };
+            let (sol, quantized) = store(coefficient, &[coefficient, 127.], &optimizer);
+
+            assert!(quantized, "{}", quantization);
+            assert!(sol.error > 0.5, "{}: {}", quantization, sol.error);
+        }
+    }
+
+    #[test]
+    fn fixed_point_scale_is_per_file() {
+        let optimizer = optimizer(Quantization::Fixed(8), QuantizationFallback::Full);
+
+        // Next to 127 the grid has a step of 1 and 1.4 cannot be stored, on its own it can.
+        let (_, quantized) = store(1.4, &[1.4, 127.], &optimizer);
+        assert!(!quantized);
+
+        let (sol, quantized) = store(1.4, &[1.4], &optimizer);
+        assert!(quantized);
+        assert!(sol.error <= 0.5, "{}", sol.error);
+    }
+}
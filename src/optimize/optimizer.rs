@@ -0,0 +1,381 @@
+use super::basis::{Basis, BasisSource};
+use super::candidates::{CandidateSource, Pool};
+use super::dag::Dag;
+use super::dump::LpDump;
+use super::encoding::RawEncoding;
+use super::load::{Load, Loader};
+use super::math::Normalization;
+use super::optimize::{minimize_abs_error, optimize, Optimize};
+use super::quantize::{Quantization, QuantizationFallback};
+use super::solution::{CoefficientBounds, Solution};
+use super::solver::{SolverBackend, SolverOption};
+use super::strategy::Strategy;
+use super::util::{ChildCache, SolveStats};
+use super::weights::YearWeights;
+use crate::totals::per_million;
+use hashbrown::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct Optimizer {
+    pub(crate) input: PathBuf,
+    pub(crate) output: PathBuf,
+    pub(crate) chunk_size: u64,
+    pub(crate) error_bound: f64,
+    pub(crate) verbose_output: bool,
+    pub(crate) output_all: bool,
+    pub(crate) core_count: Option<usize>,
+    pub(crate) strategy: Strategy,
+    pub(crate) max_n: usize,
+    pub(crate) reject_end_to_end: bool,
+    pub(crate) normalization: Normalization,
+    pub(crate) year_weights: YearWeights,
+    pub(crate) totals: Option<[f64; 201]>,
+    pub(crate) intercept: bool,
+    pub(crate) trend: bool,
+    pub(crate) coef_bounds: CoefficientBounds,
+    pub(crate) clamp: bool,
+    pub(crate) quantization: Quantization,
+    pub(crate) quantization_fallback: QuantizationFallback,
+    pub(crate) raw_encoding: RawEncoding,
+    pub(crate) patch_threshold: Option<f64>,
+    pub(crate) max_patches: usize,
+    pub(crate) lossless: bool,
+    pub(crate) basis_size: usize,
+    pub(crate) basis_source: BasisSource,
+    pub(crate) basis: Option<Basis>,
+    pub(crate) candidates: Vec<CandidateSource>,
+    pub(crate) max_candidates: Option<usize>,
+    /// Skip LPs that provably cannot meet the error bound. Only set when a rejected solution
+    /// is never written, as it then carries no coefficients.
+    pub(crate) prescreen: bool,
+    pub(crate) stats: Arc<SolveStats>,
+    pub(crate) solver: SolverBackend,
+    pub(crate) cold_start: bool,
+    pub(crate) solver_options: Vec<SolverOption>,
+    pub(crate) time_limit: Option<f64>,
+    pub(crate) dump_lp: Option<LpDump>,
+}
+
+impl Optimizer {
+    pub fn builder(input: PathBuf, output: PathBuf) -> OptimizerBuilder {
+        return OptimizerBuilder {
+            optimizer: Optimizer {
+                input,
+                output,
+                chunk_size: 2500000,
+                error_bound: 0.5,
+                verbose_output: false,
+                output_all: false,
+                core_count: None,
+                strategy: Strategy::default(),
+                max_n: 5,
+                reject_end_to_end: false,
+                normalization: Normalization::default(),
+                year_weights: YearWeights::default(),
+                totals: None,
+                intercept: false,
+                trend: false,
+                coef_bounds: CoefficientBounds::default(),
+                clamp: false,
+                quantization: Quantization::default(),
+                quantization_fallback: QuantizationFallback::default(),
+                raw_encoding: RawEncoding::default(),
+                patch_threshold: None,
+                max_patches: 20,
+                lossless: false,
+                basis_size: 0,
+                basis_source: BasisSource::default(),
+                basis: None,
+                candidates: vec![CandidateSource::Children],
+                max_candidates: None,
+                prescreen: false,
+                stats: Arc::new(SolveStats::new()),
+                solver: SolverBackend::default(),
+                cold_start: false,
+                solver_options: Vec::new(),
+                time_limit: None,
+                dump_lp: None,
+            },
+        };
+    }
+
+    pub fn run(&self) -> Result<(), String> {
+        return self.run_with_loader(&Loader::from_path(self.input.clone()));
+    }
+
+    pub fn run_with_loader(&self, loader: &dyn Load) -> Result<(), String> {
+        return optimize(self, loader);
+    }
+
+    /// Options every LP is solved with, on top of the defaults of the backend.
+    pub(crate) fn lp_options(&self) -> Vec<SolverOption> {
+        let mut options = Vec::new();
+        if let Some(time_limit) = self.time_limit {
+            options.push(SolverOption::new("time_limit", &time_limit.to_string()));
+        }
+        options.extend(self.solver_options.iter().cloned());
+
+        return options;
+    }
+
+    pub(crate) fn scale(&self) -> [f64; 201] {
+        return match &self.totals {
+            Some(totals) => per_million(totals),
+            None => [1.; 201],
+        };
+    }
+
+    pub fn solve(&self, ngram: &str, frequencies: &HashMap<String, [f64; 201]>) -> Solution {
+        return minimize_abs_error(
+            ngram,
+            frequencies,
+            &Dag::new(),
+            self.strategy.child_strategy().as_ref(),
+            &ChildCache::new(),
+            &Pool::new(),
+            self,
+        );
+    }
+}
+
+impl From<Optimize> for Optimizer {
+    fn from(optimize: Optimize) -> Self {
+        let mut builder = Optimizer::builder(optimize.input, optimize.output)
+            .chunk_size(optimize.chunk_size)
+            .error_bound(optimize.error_bound)
+            .verbose_output(optimize.verbose_output)
+            .output_all(optimize.output_all)
+            .strategy(optimize.strategy)
+            .max_n(optimize.max_n)
+            .reject_end_to_end(optimize.reject_end_to_end)
+            .normalization(optimize.normalization)
+            .year_weights(optimize.year_weights)
+            .intercept(optimize.intercept)
+            .trend(optimize.trend)
+            .coef_bounds(match optimize.signed {
+                true => CoefficientBounds::signed(),
+                false => optimize.coef_bounds,
+            })
+            .clamp(optimize.clamp)
+            .quantization(optimize.quantization)
+            .quantization_fallback(optimize.quantization_fallback)
+            .raw_encoding(optimize.raw_encoding)
+            .max_patches(optimize.max_patches)
+            .lossless(optimize.lossless)
+            .basis_size(optimize.basis_size)
+            .basis_source(optimize.basis_source)
+            .candidates(optimize.candidates)
+            .solver(optimize.solver)
+            .cold_start(optimize.cold_start)
+            .solver_options(optimize.solver_options);
+
+        if let Some(core_count) = optimize.core_count {
+            builder = builder.cores(core_count);
+        }
+
+        if let Some(max_candidates) = optimize.max_candidates {
+            builder = builder.max_candidates(max_candidates);
+        }
+
+        if let Some(time_limit) = optimize.time_limit {
+            builder = builder.time_limit(time_limit);
+        }
+
+        if let Some(dump_lp) = optimize.dump_lp {
+            builder = builder.dump_lp(&dump_lp[0], PathBuf::from(&dump_lp[1]));
+        }
+
+        if let Some(patch_threshold) = optimize.patch_threshold {
+            builder = builder.patch_threshold(patch_threshold);
+        }
+
+        if let Some(totals) = optimize.totals {
+            builder = builder.totals(totals);
+        }
+
+        return builder.build();
+    }
+}
+
+pub struct OptimizerBuilder {
+    optimizer: Optimizer,
+}
+
+impl OptimizerBuilder {
+    pub fn chunk_size(mut self, chunk_size: u64) -> Self {
+        self.optimizer.chunk_size = chunk_size;
+        return self;
+    }
+
+    pub fn error_bound(mut self, error_bound: f64) -> Self {
+        self.optimizer.error_bound = error_bound;
+        return self;
+    }
+
+    pub fn cores(mut self, core_count: usize) -> Self {
+        self.optimizer.core_count = Some(core_count);
+        return self;
+    }
+
+    pub fn verbose_output(mut self, verbose_output: bool) -> Self {
+        self.optimizer.verbose_output = verbose_output;
+        return self;
+    }
+
+    pub fn output_all(mut self, output_all: bool) -> Self {
+        self.optimizer.output_all = output_all;
+        return self;
+    }
+
+    pub fn strategy(mut self, strategy: Strategy) -> Self {
+        self.optimizer.strategy = strategy;
+        return self;
+    }
+
+    pub fn max_n(mut self, max_n: usize) -> Self {
+        self.optimizer.max_n = max_n;
+        return self;
+    }
+
+    pub fn reject_end_to_end(mut self, reject_end_to_end: bool) -> Self {
+        self.optimizer.reject_end_to_end = reject_end_to_end;
+        return self;
+    }
+
+    pub fn normalization(mut self, normalization: Normalization) -> Self {
+        self.optimizer.normalization = normalization;
+        return self;
+    }
+
+    pub fn year_weights(mut self, year_weights: YearWeights) -> Self {
+        self.optimizer.year_weights = year_weights;
+        return self;
+    }
+
+    pub fn totals(mut self, totals: [f64; 201]) -> Self {
+        self.optimizer.totals = Some(totals);
+        return self;
+    }
+
+    pub fn intercept(mut self, intercept: bool) -> Self {
+        self.optimizer.intercept = intercept;
+        return self;
+    }
+
+    pub fn trend(mut self, trend: bool) -> Self {
+        self.optimizer.trend = trend;
+        return self;
+    }
+
+    pub fn coef_bounds(mut self, coef_bounds: CoefficientBounds) -> Self {
+        self.optimizer.coef_bounds = coef_bounds;
+        return self;
+    }
+
+    pub fn signed(self) -> Self {
+        return self.coef_bounds(CoefficientBounds::signed());
+    }
+
+    pub fn clamp(mut self, clamp: bool) -> Self {
+        self.optimizer.clamp = clamp;
+        return self;
+    }
+
+    pub fn quantization(mut self, quantization: Quantization) -> Self {
+        self.optimizer.quantization = quantization;
+        return self;
+    }
+
+    pub fn quantization_fallback(mut self, quantization_fallback: QuantizationFallback) -> Self {
+        self.optimizer.quantization_fallback = quantization_fallback;
+        return self;
+    }
+
+    pub fn raw_encoding(mut self, raw_encoding: RawEncoding) -> Self {
+        self.optimizer.raw_encoding = raw_encoding;
+        return self;
+    }
+
+    pub fn patch_threshold(mut self, patch_threshold: f64) -> Self {
+        self.optimizer.patch_threshold = Some(patch_threshold);
+        return self;
+    }
+
+    pub fn max_patches(mut self, max_patches: usize) -> Self {
+        self.optimizer.max_patches = max_patches;
+        return self;
+    }
+
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.optimizer.lossless = lossless;
+        return self;
+    }
+
+    pub fn basis_size(mut self, basis_size: usize) -> Self {
+        self.optimizer.basis_size = basis_size;
+        return self;
+    }
+
+    pub fn basis_source(mut self, basis_source: BasisSource) -> Self {
+        self.optimizer.basis_source = basis_source;
+        return self;
+    }
+
+    /// Uses a fixed basis instead of learning one from the unigrams of the input.
+    pub fn basis(mut self, basis: Basis) -> Self {
+        self.optimizer.basis = Some(basis);
+        return self;
+    }
+
+    pub fn candidates(mut self, candidates: Vec<CandidateSource>) -> Self {
+        self.optimizer.candidates = candidates;
+        return self;
+    }
+
+    /// Keeps only the candidates whose series correlate best with the n-gram in each LP.
+    pub fn max_candidates(mut self, max_candidates: usize) -> Self {
+        self.optimizer.max_candidates = Some(max_candidates);
+        return self;
+    }
+
+    pub fn solver(mut self, solver: SolverBackend) -> Self {
+        self.optimizer.solver = solver;
+        return self;
+    }
+
+    /// Builds every LP from scratch instead of updating the model of the worker thread.
+    pub fn cold_start(mut self, cold_start: bool) -> Self {
+        self.optimizer.cold_start = cold_start;
+        return self;
+    }
+
+    pub fn solver_options(mut self, solver_options: Vec<SolverOption>) -> Self {
+        self.optimizer.solver_options = solver_options;
+        return self;
+    }
+
+    pub fn solver_option(mut self, key: &str, value: &str) -> Self {
+        self.optimizer
+            .solver_options
+            .push(SolverOption::new(key, value));
+        return self;
+    }
+
+    /// Seconds each LP may take before it is given up and the n-gram stored uncompressed.
+    pub fn time_limit(mut self, time_limit: f64) -> Self {
+        self.optimizer.time_limit = Some(time_limit);
+        return self;
+    }
+
+    /// Writes the LP of every n-gram matching `pattern` to `dir` before it is solved.
+    pub fn dump_lp(mut self, pattern: &str, dir: PathBuf) -> Self {
+        self.optimizer.dump_lp = Some(LpDump::new(pattern, dir));
+        return self;
+    }
+
+    pub fn build(self) -> Optimizer {
+        return self.optimizer;
+    }
+}
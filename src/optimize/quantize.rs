@@ -0,0 +1,211 @@
+use half::f16;
+use polars::prelude::*;
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Quantization {
+    #[default]
+    F64,
+    F32,
+    F16,
+    Fixed(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuantizationFallback {
+    #[default]
+    Full,
+    Uncompressed,
+}
+
+impl Quantization {
+    pub fn data_type(&self) -> DataType {
+        return match self {
+            Quantization::F64 => DataType::Float64,
+            Quantization::F32 => DataType::Float32,
+            Quantization::F16 => DataType::UInt16,
+            Quantization::Fixed(_) => DataType::Int32,
+        };
+    }
+
+    /// Step size of the fixed-point grid, chosen so the largest coefficient of the file
+    /// still fits into the available bits. Float formats do not need a scale.
+    pub fn scale(&self, coefficients: impl Iterator<Item = f64>) -> f64 {
+        let Quantization::Fixed(bits) = self else {
+            return 1.;
+        };
+
+        let max = coefficients
+            .map(f64::abs)
+            .filter(|x| x.is_finite())
+            .fold(0., f64::max);
+        let steps = ((1u64 << (bits - 1)) - 1) as f64;
+
+        return match max > 0. {
+            true => max / steps,
+            false => 1.,
+        };
+    }
+
+    /// Value the decompressor will see after storing `x` in this format.
+    pub fn round_trip(&self, x: f64, scale: f64) -> f64 {
+        return match self {
+            Quantization::F64 => x,
+            Quantization::F32 => x as f32 as f64,
+            Quantization::F16 => f16::from_f64(x).to_f64(),
+            Quantization::Fixed(_) => (x / scale).round() as i32 as f64 * scale,
+        };
+    }
+
+    pub fn encode(&self, name: &str, values: &[f64], scale: f64) -> Series {
+        return match self {
+            Quantization::F64 => Series::new(name, values),
+            Quantization::F32 => {
+                Series::new(name, values.iter().map(|x| *x as f32).collect::<Vec<_>>())
+            }
+            Quantization::F16 => Series::new(
+                name,
+                values
+                    .iter()
+                    .map(|x| f16::from_f64(*x).to_bits())
+                    .collect::<Vec<_>>(),
+            ),
+            Quantization::Fixed(_) => Series::new(
+                name,
+                values
+                    .iter()
+                    .map(|x| (x / scale).round() as i32)
+                    .collect::<Vec<_>>(),
+            ),
+        };
+    }
+}
+
+/// Inverse of `Quantization::encode`, dispatching on the stored data type.
+pub fn dequantize(series: &Series, scale: f64) -> Vec<f64> {
+    return match series.dtype() {
+        DataType::UInt16 => series
+            .u16()
+            .unwrap()
+            .into_no_null_iter()
+            .map(|x| f16::from_bits(x).to_f64())
+            .collect(),
+        DataType::Int32 => series
+            .i32()
+            .unwrap()
+            .into_no_null_iter()
+            .map(|x| x as f64 * scale)
+            .collect(),
+        _ => series
+            .cast(&DataType::Float64)
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect(),
+    };
+}
+
+impl FromStr for Quantization {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s.split_once(':') {
+            Some(("fixed", bits)) => match bits.parse::<u8>() {
+                Ok(bits) if (2..=32).contains(&bits) => Ok(Quantization::Fixed(bits)),
+                _ => Err(format!("invalid fixed-point width: {}", bits)),
+            },
+            Some(_) => Err(format!("unknown quantization: {}", s)),
+            None => match s {
+                "f64" => Ok(Quantization::F64),
+                "f32" => Ok(Quantization::F32),
+                "f16" => Ok(Quantization::F16),
+                "fixed" => Ok(Quantization::Fixed(16)),
+                _ => Err(format!("unknown quantization: {}", s)),
+            },
+        };
+    }
+}
+
+impl fmt::Display for Quantization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Quantization::F64 => write!(f, "f64"),
+            Quantization::F32 => write!(f, "f32"),
+            Quantization::F16 => write!(f, "f16"),
+            Quantization::Fixed(bits) => write!(f, "fixed:{}", bits),
+        };
+    }
+}
+
+impl QuantizationFallback {
+    pub const VARIANTS: &'static [&'static str] = &["full", "uncompressed"];
+}
+
+impl FromStr for QuantizationFallback {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s {
+            "full" => Ok(QuantizationFallback::Full),
+            "uncompressed" => Ok(QuantizationFallback::Uncompressed),
+            _ => Err(format!("unknown quantization fallback: {}", s)),
+        };
+    }
+}
+
+impl fmt::Display for QuantizationFallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            QuantizationFallback::Full => write!(f, "full"),
+            QuantizationFallback::Uncompressed => write!(f, "uncompressed"),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_scale_fits_the_largest_coefficient() {
+        let fixed = Quantization::Fixed(8);
+        let scale = fixed.scale([0.5, -12.7, f64::INFINITY, 3.].into_iter());
+
+        assert!((scale - 0.1).abs() < 1e-12, "{}", scale);
+        assert!((fixed.round_trip(-12.7, scale) + 12.7).abs() < 1e-12);
+        assert_eq!(fixed.scale([0., 0.].into_iter()), 1.);
+        assert_eq!(fixed.scale(std::iter::empty()), 1.);
+
+        for float in [Quantization::F64, Quantization::F32, Quantization::F16] {
+            assert_eq!(float.scale([1e6].into_iter()), 1.);
+        }
+    }
+
+    #[test]
+    fn encode_matches_round_trip() {
+        let values = [0., 1., -1.5, 1.0003, 1. + 1e-9, 2.71, -120.];
+
+        for quantization in [
+            Quantization::F64,
+            Quantization::F32,
+            Quantization::F16,
+            Quantization::Fixed(8),
+            Quantization::Fixed(32),
+        ] {
+            let scale = quantization.scale(values.iter().cloned());
+            let series = quantization.encode("coefficient", &values, scale);
+
+            assert_eq!(series.dtype(), &quantization.data_type());
+            assert_eq!(
+                dequantize(&series, scale),
+                values
+                    .iter()
+                    .map(|x| quantization.round_trip(*x, scale))
+                    .collect::<Vec<_>>(),
+                "{}",
+                quantization
+            );
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use super::solver::{LinearProgram, SolveStatus, Solver, SolverOption};
+use std::time::{Duration, Instant};
+
+const EPSILON: f64 = 1e-9;
+const ITERATION_LIMIT: usize = 100000;
+/// Consecutive pivots without progress after which Bland's rule takes over to avoid cycling.
+const DEGENERATE_PIVOTS: usize = 50;
+
+/// Dense simplex in pure Rust, so the binary can be built without the HiGHS toolchain.
+///
+/// The LP has a few columns and two rows per year, so the simplex runs on its dual, whose
+/// tableau has one row per column. The coefficients are the simplex multipliers of the
+/// optimal dual basis.
+pub struct Simplex;
+
+struct Limits {
+    time: Option<Duration>,
+    iterations: usize,
+}
+
+impl Limits {
+    fn from_options(options: &[SolverOption]) -> Result<Self, String> {
+        let mut limits = Limits {
+            time: None,
+            iterations: ITERATION_LIMIT,
+        };
+
+        for option in options {
+            let invalid = || format!("invalid solver option: {}", option);
+            match option.key.as_str() {
+                "time_limit" => {
+                    let seconds = option.value.parse::<f64>().map_err(|_| invalid())?;
+                    limits.time =
+                        Some(Duration::try_from_secs_f64(seconds).map_err(|_| invalid())?);
+                }
+                "simplex_iteration_limit" => {
+                    limits.iterations = option.value.parse().map_err(|_| invalid())?;
+                }
+                _ => {
+                    return Err(format!(
+                        "unsupported option for the simplex solver: {}",
+                        option
+                    ))
+                }
+            }
+        }
+
+        return Ok(limits);
+    }
+}
+
+impl Solver for Simplex {
+    fn name(&self) -> String {
+        return "simplex".to_string();
+    }
+
+    fn solve(&self, lp: &LinearProgram, options: &[SolverOption]) -> Result<Vec<f64>, SolveStatus> {
+        let limits = Limits::from_options(options).map_err(|_| SolveStatus::NumericalError)?;
+        let n = lp.columns();
+
+        let mut g = Vec::new();
+        let mut h = Vec::new();
+        for (entries, upper) in lp.rows() {
+            let mut row = vec![0.; n];
+            for (j, a) in entries {
+                row[j] += a;
+            }
+            g.push(row);
+            h.push(upper);
+        }
+
+        for (j, (lo, hi)) in lp.column_bounds().into_iter().enumerate() {
+            let mut row = vec![0.; n];
+            if lo.is_finite() {
+                row[j] = -1.;
+                g.push(row.clone());
+                h.push(-lo);
+            }
+            if hi.is_finite() {
+                row[j] = 1.;
+                g.push(row);
+                h.push(hi);
+            }
+        }
+
+        let mut z = minimize(g, h, lp.costs(), &limits)?;
+        z.pop();
+
+        return Ok(z);
+    }
+
+    fn check_options(&self, options: &[SolverOption]) -> Result<(), String> {
+        return Limits::from_options(options).map(|_| ());
+    }
+}
+
+/// Solves `min c·z` subject to `G z <= h` with `z` free, through its dual
+/// `min h·u` subject to `G^T u = -c`, `u >= 0`.
+fn minimize(
+    mut g: Vec<Vec<f64>>,
+    mut h: Vec<f64>,
+    c: Vec<f64>,
+    limits: &Limits,
+) -> Result<Vec<f64>, SolveStatus> {
+    let n = c.len();
+    let r = g.len();
+
+    // Scaling a column of G rescales its variable, scaling a row of G rescales a dual
+    // variable, and neither changes the solution.
+    let column_scale = (0..n)
+        .map(|j| {
+            let max = g.iter().fold(0., |acc: f64, row| acc.max(row[j].abs()));
+            return if max > 0. { max } else { 1. };
+        })
+        .collect::<Vec<_>>();
+    let c = c
+        .iter()
+        .zip(column_scale.iter())
+        .map(|(c, s)| c / s)
+        .collect::<Vec<_>>();
+    for (row, h) in g.iter_mut().zip(h.iter_mut()) {
+        for (a, s) in row.iter_mut().zip(column_scale.iter()) {
+            *a /= s;
+        }
+
+        let max = row.iter().fold(0., |acc: f64, a| acc.max(a.abs()));
+        if max > 0. {
+            row.iter_mut().for_each(|a| *a /= max);
+            *h /= max;
+        }
+    }
+
+    // One equality per primal column, flipped so the right-hand side is non-negative, with an
+    // artificial variable as the starting basis.
+    let width = r + n + 1;
+    let signs = c
+        .iter()
+        .map(|c| if -c < 0. { -1. } else { 1. })
+        .collect::<Vec<_>>();
+    let mut tableau = (0..n)
+        .map(|j| {
+            let mut row = vec![0.; width];
+            for (i, g_i) in g.iter().enumerate() {
+                row[i] = signs[j] * g_i[j];
+            }
+            row[r + j] = 1.;
+            row[width - 1] = signs[j] * -c[j];
+            return row;
+        })
+        .collect::<Vec<_>>();
+    let mut basis = (r..r + n).collect::<Vec<_>>();
+
+    let mut tableau = Tableau {
+        rows: &mut tableau,
+        basis: &mut basis,
+        entering: r,
+        start: Instant::now(),
+        iterations: 0,
+    };
+
+    let phase_one = (0..width - 1)
+        .map(|i| if i < r { 0. } else { 1. })
+        .collect::<Vec<_>>();
+    let infeasibility = -tableau.optimize(&phase_one, limits)?;
+    let rhs_scale = tableau
+        .rows
+        .iter()
+        .fold(1., |acc: f64, row| acc.max(row[width - 1].abs()));
+    if infeasibility > 1e-7 * rhs_scale {
+        // The dual is infeasible, so the LP has no finite optimum.
+        return Err(SolveStatus::Unbounded);
+    }
+    tableau.drive_out_artificials();
+
+    let phase_two = h
+        .iter()
+        .cloned()
+        .chain(std::iter::repeat_n(0., n))
+        .collect::<Vec<_>>();
+    let reduced = match tableau.optimize(&phase_two, limits) {
+        Ok(_) => tableau.reduced_costs(&phase_two),
+        // An unbounded dual means no coefficients satisfy the constraints.
+        Err(SolveStatus::Unbounded) => return Err(SolveStatus::Infeasible),
+        Err(status) => return Err(status),
+    };
+
+    return Ok((0..n)
+        .map(|j| -signs[j] * reduced[r + j] / column_scale[j])
+        .collect());
+}
+
+struct Tableau<'a> {
+    rows: &'a mut Vec<Vec<f64>>,
+    basis: &'a mut Vec<usize>,
+    /// Columns from here on are artificial and may only leave the basis.
+    entering: usize,
+    start: Instant,
+    iterations: usize,
+}
+
+impl Tableau<'_> {
+    fn reduced_costs(&self, costs: &[f64]) -> Vec<f64> {
+        let width = self.rows[0].len();
+        let mut reduced = costs.to_vec();
+        reduced.push(0.);
+
+        for (row, b) in self.rows.iter().zip(self.basis.iter()) {
+            let cost = costs[*b];
+            if cost == 0. {
+                continue;
+            }
+            for (d, a) in reduced.iter_mut().zip(row.iter()).take(width) {
+                *d -= cost * a;
+            }
+        }
+
+        return reduced;
+    }
+
+    /// Runs the simplex on `costs` from the current basis and returns the negated objective.
+    fn optimize(&mut self, costs: &[f64], limits: &Limits) -> Result<f64, SolveStatus> {
+        let width = self.rows[0].len();
+        let mut reduced = self.reduced_costs(costs);
+        let mut degenerate = 0;
+
+        loop {
+            self.iterations += 1;
+            if self.iterations > limits.iterations {
+                return Err(SolveStatus::IterationLimit);
+            }
+            if self.iterations.is_multiple_of(100)
+                && limits
+                    .time
+                    .is_some_and(|limit| self.start.elapsed() > limit)
+            {
+                return Err(SolveStatus::TimeLimit);
+            }
+
+            let candidates = reduced[..self.entering]
+                .iter()
+                .enumerate()
+                .filter(|(_, d)| **d < -EPSILON);
+            let column = match degenerate < DEGENERATE_PIVOTS {
+                true => candidates.min_by(|a, b| a.1.total_cmp(b.1)).map(|(i, _)| i),
+                false => candidates.map(|(i, _)| i).next(),
+            };
+            let Some(column) = column else {
+                return Ok(reduced[width - 1]);
+            };
+
+            let mut pivot: Option<(usize, f64)> = None;
+            for (i, row) in self.rows.iter().enumerate() {
+                if row[column] <= EPSILON {
+                    continue;
+                }
+
+                let ratio = row[width - 1] / row[column];
+                let better = match pivot {
+                    None => true,
+                    Some((p, best)) => {
+                        ratio < best - EPSILON
+                            || (ratio <= best + EPSILON && self.basis[i] < self.basis[p])
+                    }
+                };
+                if better {
+                    pivot = Some((i, ratio));
+                }
+            }
+
+            let Some((row, ratio)) = pivot else {
+                return Err(SolveStatus::Unbounded);
+            };
+
+            degenerate = match ratio.abs() <= EPSILON {
+                true => degenerate + 1,
+                false => 0,
+            };
+            self.pivot(row, column, &mut reduced);
+        }
+    }
+
+    fn pivot(&mut self, row: usize, column: usize, reduced: &mut [f64]) {
+        let value = self.rows[row][column];
+        self.rows[row].iter_mut().for_each(|a| *a /= value);
+        let pivot_row = self.rows[row].clone();
+
+        for (i, other) in self.rows.iter_mut().enumerate() {
+            let factor = other[column];
+            if i == row || factor == 0. {
+                continue;
+            }
+            for (a, p) in other.iter_mut().zip(pivot_row.iter()) {
+                *a -= factor * p;
+            }
+        }
+
+        let factor = reduced[column];
+        for (d, p) in reduced.iter_mut().zip(pivot_row.iter()) {
+            *d -= factor * p;
+        }
+
+        self.basis[row] = column;
+    }
+
+    /// Replaces artificial variables left in the basis at zero by real ones where possible.
+    /// Rows where that fails are redundant and stay as they are.
+    fn drive_out_artificials(&mut self) {
+        let width = self.rows[0].len();
+        let mut unused = vec![0.; width];
+
+        for row in 0..self.rows.len() {
+            if self.basis[row] < self.entering {
+                continue;
+            }
+
+            let column = (0..self.entering)
+                .filter(|i| !self.basis.contains(i))
+                .max_by(|a, b| {
+                    self.rows[row][*a]
+                        .abs()
+                        .total_cmp(&self.rows[row][*b].abs())
+                })
+                .filter(|i| self.rows[row][*i].abs() > EPSILON);
+            if let Some(column) = column {
+                self.pivot(row, column, &mut unused);
+            }
+        }
+    }
+}
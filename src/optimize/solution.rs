@@ -1,7 +1,20 @@
+use super::solver::SolveStatus;
 use core::f64;
+use std::{fmt, str::FromStr};
+
+pub const INTERCEPT: &str = "__intercept__";
+pub const TREND: &str = "__trend__";
+
+pub fn pseudo_series(token: &str) -> Option<[f64; 201]> {
+    return match token {
+        INTERCEPT => Some([1.; 201]),
+        TREND => Some(std::array::from_fn(|i| i as f64)),
+        _ => None,
+    };
+}
 
 #[derive(Debug)]
-pub(crate) struct Solution {
+pub struct Solution {
     pub ngram: String,
     pub coefficients: Vec<Coefficient>,
     pub original: [f64; 201],
@@ -9,6 +22,12 @@ pub(crate) struct Solution {
     pub error: f64,
     pub rmse: f64,
     pub summed_error: f64,
+    pub end_to_end_error: f64,
+    pub worst_case_error: f64,
+    pub deviation: [f64; 201],
+    pub patches: Vec<Patch>,
+    pub residuals: Option<[i64; 201]>,
+    pub status: SolveStatus,
 }
 
 impl Solution {
@@ -21,6 +40,12 @@ impl Solution {
             error: f64::INFINITY,
             rmse: f64::INFINITY,
             summed_error: f64::INFINITY,
+            end_to_end_error: f64::INFINITY,
+            worst_case_error: f64::INFINITY,
+            deviation: [f64::INFINITY; 201],
+            patches: Vec::new(),
+            residuals: None,
+            status: SolveStatus::NotSolved,
         };
     }
 }
@@ -35,12 +60,18 @@ impl Clone for Solution {
             error: self.error,
             rmse: self.rmse,
             summed_error: self.summed_error,
+            end_to_end_error: self.end_to_end_error,
+            worst_case_error: self.worst_case_error,
+            deviation: self.deviation,
+            patches: self.patches.clone(),
+            residuals: self.residuals,
+            status: self.status,
         };
     }
 }
 
 #[derive(Debug)]
-pub(crate) struct Coefficient {
+pub struct Coefficient {
     pub token: String,
     pub coefficient: f64,
 }
@@ -53,3 +84,79 @@ impl Clone for Coefficient {
         };
     }
 }
+
+/// Exact correction for a single year whose residual was too large to leave to the coefficients.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Patch {
+    pub year: u16,
+    pub correction: f64,
+}
+
+impl Patch {
+    pub fn index(&self) -> usize {
+        return (self.year - 1800) as usize;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoefficientBounds {
+    pub lo: f64,
+    pub hi: f64,
+}
+
+impl CoefficientBounds {
+    pub fn non_negative() -> Self {
+        return CoefficientBounds {
+            lo: 0.,
+            hi: f64::INFINITY,
+        };
+    }
+
+    pub fn signed() -> Self {
+        return CoefficientBounds {
+            lo: f64::NEG_INFINITY,
+            hi: f64::INFINITY,
+        };
+    }
+}
+
+impl Default for CoefficientBounds {
+    fn default() -> Self {
+        return CoefficientBounds::non_negative();
+    }
+}
+
+impl FromStr for CoefficientBounds {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (lo, hi) = s
+            .split_once(':')
+            .ok_or_else(|| format!("expected lo:hi, got {}", s))?;
+
+        let lo = match lo.trim() {
+            "" => f64::NEG_INFINITY,
+            lo => lo
+                .parse::<f64>()
+                .map_err(|_| format!("invalid lower bound: {}", lo))?,
+        };
+        let hi = match hi.trim() {
+            "" => f64::INFINITY,
+            hi => hi
+                .parse::<f64>()
+                .map_err(|_| format!("invalid upper bound: {}", hi))?,
+        };
+
+        if lo.is_nan() || hi.is_nan() || lo > hi {
+            return Err(format!("invalid coefficient bounds: {}", s));
+        }
+
+        return Ok(CoefficientBounds { lo, hi });
+    }
+}
+
+impl fmt::Display for CoefficientBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}:{}", self.lo, self.hi);
+    }
+}
@@ -0,0 +1,254 @@
+#[cfg(feature = "highs")]
+use super::highs::Highs;
+use super::simplex::Simplex;
+use cfg_if::cfg_if;
+use std::{fmt, str::FromStr};
+
+cfg_if! {
+    if #[cfg(feature = "highs")] {
+        pub const DEFAULT_SOLVER: &str = "highs";
+    } else {
+        pub const DEFAULT_SOLVER: &str = "simplex";
+    }
+}
+
+/// The LP behind every fit: find coefficients `c` within `bounds` and the smallest `t` with
+/// `|weights_i * (y_i - sum_j c_j * series_j_i)| <= t` for every year of non-zero weight.
+pub struct LinearProgram<'a> {
+    pub y: &'a [f64; 201],
+    pub series: &'a [&'a [f64; 201]],
+    pub bounds: &'a [(f64, f64)],
+    pub weights: &'a [f64; 201],
+}
+
+impl LinearProgram<'_> {
+    /// Number of columns, the coefficients followed by `t`.
+    pub fn columns(&self) -> usize {
+        return self.series.len() + 1;
+    }
+
+    pub fn costs(&self) -> Vec<f64> {
+        let mut costs = vec![0.; self.series.len()];
+        costs.push(1.);
+
+        return costs;
+    }
+
+    pub fn column_bounds(&self) -> Vec<(f64, f64)> {
+        let mut bounds = self.bounds.to_vec();
+        bounds.push((0., f64::INFINITY));
+
+        return bounds;
+    }
+
+    /// Sparse rows `(entries, upper)`, each meaning `sum(value * column) <= upper`.
+    pub fn rows(&self) -> Vec<(Vec<(usize, f64)>, f64)> {
+        let t = self.series.len();
+        let mut rows = Vec::with_capacity(402);
+
+        for sign in [1., -1.] {
+            for (i, y_i) in self.y.iter().enumerate() {
+                let w_i = self.weights[i] * sign;
+                if w_i == 0. {
+                    continue;
+                }
+
+                let mut entries = self
+                    .series
+                    .iter()
+                    .enumerate()
+                    .map(|(j, x)| (j, x[i] * w_i))
+                    .filter(|(_, a)| *a != 0.)
+                    .collect::<Vec<_>>();
+                entries.push((t, -1.));
+                rows.push((entries, y_i * w_i));
+            }
+        }
+
+        return rows;
+    }
+}
+
+/// An LP backend. Returns the values of the coefficient columns, without `t`, or why there are
+/// none.
+pub trait Solver: Send + Sync {
+    fn name(&self) -> String;
+
+    fn solve(&self, lp: &LinearProgram, options: &[SolverOption]) -> Result<Vec<f64>, SolveStatus>;
+
+    /// Rejects options the backend does not know, before any LP is solved.
+    fn check_options(&self, options: &[SolverOption]) -> Result<(), String>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverBackend {
+    #[cfg(feature = "highs")]
+    Highs,
+    Simplex,
+}
+
+impl SolverBackend {
+    pub const VARIANTS: &'static [&'static str] = &["highs", "simplex"];
+
+    /// `warm` lets backends that support it reuse a model across the LPs of a thread.
+    pub fn solver(&self, warm: bool) -> Box<dyn Solver> {
+        return match self {
+            #[cfg(feature = "highs")]
+            SolverBackend::Highs => Box::new(Highs::new(warm)),
+            SolverBackend::Simplex => {
+                let _ = warm;
+                Box::new(Simplex)
+            }
+        };
+    }
+}
+
+impl Default for SolverBackend {
+    fn default() -> Self {
+        return DEFAULT_SOLVER.parse().unwrap();
+    }
+}
+
+impl FromStr for SolverBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s {
+            #[cfg(feature = "highs")]
+            "highs" => Ok(SolverBackend::Highs),
+            #[cfg(not(feature = "highs"))]
+            "highs" => Err("built without the highs feature".to_string()),
+            "simplex" => Ok(SolverBackend::Simplex),
+            _ => Err(format!("unknown solver: {}", s)),
+        };
+    }
+}
+
+impl fmt::Display for SolverBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            #[cfg(feature = "highs")]
+            SolverBackend::Highs => write!(f, "highs"),
+            SolverBackend::Simplex => write!(f, "simplex"),
+        };
+    }
+}
+
+/// Outcome of the LP behind a solution, so pathological n-grams can be told apart from ones
+/// that simply had no good combination of candidates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SolveStatus {
+    /// No LP was run, e.g. because there were too few candidates.
+    #[default]
+    NotSolved,
+    Optimal,
+    Infeasible,
+    Unbounded,
+    TimeLimit,
+    IterationLimit,
+    NumericalError,
+    /// Any other HiGHS model status, by its code.
+    Other(i32),
+}
+
+impl SolveStatus {
+    pub const VARIANTS: &'static [&'static str] = &[
+        "not-solved",
+        "optimal",
+        "infeasible",
+        "unbounded",
+        "time-limit",
+        "iteration-limit",
+        "numerical-error",
+        "other",
+    ];
+
+    /// Maps a HiGHS model status code. Load, model, presolve, solve and postsolve errors count
+    /// as numerical errors, anything without a variant of its own is kept as `Other`.
+    pub fn from_highs(status: i32) -> Self {
+        return match status {
+            1..=5 => SolveStatus::NumericalError,
+            7 => SolveStatus::Optimal,
+            8 => SolveStatus::Infeasible,
+            9 | 10 => SolveStatus::Unbounded,
+            13 => SolveStatus::TimeLimit,
+            14 => SolveStatus::IterationLimit,
+            _ => SolveStatus::Other(status),
+        };
+    }
+
+    pub fn index(&self) -> usize {
+        return match self {
+            SolveStatus::NotSolved => 0,
+            SolveStatus::Optimal => 1,
+            SolveStatus::Infeasible => 2,
+            SolveStatus::Unbounded => 3,
+            SolveStatus::TimeLimit => 4,
+            SolveStatus::IterationLimit => 5,
+            SolveStatus::NumericalError => 6,
+            SolveStatus::Other(_) => 7,
+        };
+    }
+}
+
+impl FromStr for SolveStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s {
+            "not-solved" => Ok(SolveStatus::NotSolved),
+            "optimal" => Ok(SolveStatus::Optimal),
+            "infeasible" => Ok(SolveStatus::Infeasible),
+            "unbounded" => Ok(SolveStatus::Unbounded),
+            "time-limit" => Ok(SolveStatus::TimeLimit),
+            "iteration-limit" => Ok(SolveStatus::IterationLimit),
+            "numerical-error" => Ok(SolveStatus::NumericalError),
+            _ => match s.strip_prefix("other:").map(|code| code.parse()) {
+                Some(Ok(code)) => Ok(SolveStatus::Other(code)),
+                _ => Err(format!("unknown solve status: {}", s)),
+            },
+        };
+    }
+}
+
+impl fmt::Display for SolveStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            SolveStatus::Other(code) => write!(f, "other:{}", code),
+            _ => write!(f, "{}", SolveStatus::VARIANTS[self.index()]),
+        };
+    }
+}
+
+/// A `key=value` option passed to the LP solver as is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolverOption {
+    pub key: String,
+    pub value: String,
+}
+
+impl SolverOption {
+    pub fn new(key: &str, value: &str) -> Self {
+        return SolverOption {
+            key: key.to_string(),
+            value: value.to_string(),
+        };
+    }
+}
+
+impl FromStr for SolverOption {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s.split_once('=') {
+            Some((key, value)) if !key.is_empty() => Ok(SolverOption::new(key, value)),
+            _ => Err(format!("expected key=value: {}", s)),
+        };
+    }
+}
+
+impl fmt::Display for SolverOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}={}", self.key, self.value);
+    }
+}
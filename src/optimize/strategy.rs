@@ -0,0 +1,209 @@
+use super::dag::Dag;
+use super::util::{
+    dedup, expand, get_children, get_contiguous, get_direct_children, get_skip_grams, get_unigrams,
+    ChildCache,
+};
+use cfg_if::cfg_if;
+use hashbrown::HashSet;
+use std::{fmt, str::FromStr};
+
+cfg_if! {
+    if #[cfg(feature = "direct-children")] {
+        pub const DEFAULT_STRATEGY: &str = "direct-children";
+    } else if #[cfg(feature = "non-selective")] {
+        pub const DEFAULT_STRATEGY: &str = "non-selective";
+    } else if #[cfg(feature = "highly-selective")] {
+        pub const DEFAULT_STRATEGY: &str = "highly-selective";
+    } else {
+        pub const DEFAULT_STRATEGY: &str = "recursive";
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Substitution {
+    Zeroed,
+    Approximated,
+    Original,
+}
+
+pub trait ChildStrategy: Send + Sync {
+    fn children(&self, ngram: &str, compressed: &Dag, cache: &ChildCache) -> Vec<String>;
+
+    fn wanted(&self, ngram: &str, cache: &ChildCache) -> Vec<String> {
+        return self.children(ngram, &Dag::new(), cache);
+    }
+
+    fn substitution(&self) -> Substitution;
+}
+
+pub struct Recursive;
+
+impl ChildStrategy for Recursive {
+    fn children(&self, ngram: &str, _compressed: &Dag, cache: &ChildCache) -> Vec<String> {
+        return get_children(ngram, false, cache);
+    }
+
+    fn substitution(&self) -> Substitution {
+        return Substitution::Zeroed;
+    }
+}
+
+pub struct HighlySelective;
+
+impl ChildStrategy for HighlySelective {
+    fn children(&self, ngram: &str, compressed: &Dag, cache: &ChildCache) -> Vec<String> {
+        let children = get_children(ngram, false, cache);
+        let filtered_compressed = children
+            .iter()
+            .filter(|child| compressed.contains(child))
+            .map(|x| x.to_string())
+            .collect::<HashSet<_>>();
+
+        if filtered_compressed.is_empty() {
+            return children;
+        }
+
+        let mut expanded: Vec<String> = Vec::new();
+        expand(ngram, &filtered_compressed, &mut expanded);
+        return dedup(expanded);
+    }
+
+    fn substitution(&self) -> Substitution {
+        return Substitution::Original;
+    }
+}
+
+pub struct DirectChildren;
+
+impl ChildStrategy for DirectChildren {
+    fn children(&self, ngram: &str, _compressed: &Dag, _cache: &ChildCache) -> Vec<String> {
+        return dedup(get_direct_children(ngram));
+    }
+
+    fn substitution(&self) -> Substitution {
+        return Substitution::Approximated;
+    }
+}
+
+pub struct NonSelective;
+
+impl ChildStrategy for NonSelective {
+    fn children(&self, ngram: &str, _compressed: &Dag, cache: &ChildCache) -> Vec<String> {
+        return get_children(ngram, false, cache);
+    }
+
+    fn substitution(&self) -> Substitution {
+        return Substitution::Approximated;
+    }
+}
+
+pub struct Contiguous(pub usize);
+
+impl ChildStrategy for Contiguous {
+    fn children(&self, ngram: &str, _compressed: &Dag, _cache: &ChildCache) -> Vec<String> {
+        return dedup(get_contiguous(ngram, self.0));
+    }
+
+    fn substitution(&self) -> Substitution {
+        return Substitution::Approximated;
+    }
+}
+
+pub struct SkipGrams(pub usize);
+
+impl ChildStrategy for SkipGrams {
+    fn children(&self, ngram: &str, _compressed: &Dag, _cache: &ChildCache) -> Vec<String> {
+        return dedup(get_skip_grams(ngram, self.0));
+    }
+
+    fn substitution(&self) -> Substitution {
+        return Substitution::Approximated;
+    }
+}
+
+pub struct Unigrams;
+
+impl ChildStrategy for Unigrams {
+    fn children(&self, ngram: &str, _compressed: &Dag, _cache: &ChildCache) -> Vec<String> {
+        return dedup(get_unigrams(ngram));
+    }
+
+    fn substitution(&self) -> Substitution {
+        return Substitution::Original;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    Recursive,
+    HighlySelective,
+    DirectChildren,
+    NonSelective,
+    Contiguous(usize),
+    SkipGrams(usize),
+    Unigrams,
+}
+
+impl Strategy {
+    pub fn child_strategy(&self) -> Box<dyn ChildStrategy> {
+        return match self {
+            Strategy::Recursive => Box::new(Recursive),
+            Strategy::HighlySelective => Box::new(HighlySelective),
+            Strategy::DirectChildren => Box::new(DirectChildren),
+            Strategy::NonSelective => Box::new(NonSelective),
+            Strategy::Contiguous(k) => Box::new(Contiguous(*k)),
+            Strategy::SkipGrams(k) => Box::new(SkipGrams(*k)),
+            Strategy::Unigrams => Box::new(Unigrams),
+        };
+    }
+}
+
+impl Default for Strategy {
+    fn default() -> Self {
+        return DEFAULT_STRATEGY.parse().unwrap();
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, k) = match s.split_once(':') {
+            Some((name, k)) => (
+                name,
+                k.parse::<usize>()
+                    .map_err(|_| format!("invalid child length: {}", k))?,
+            ),
+            None => (s, 2),
+        };
+
+        if k == 0 {
+            return Err(format!("invalid child length: {}", k));
+        }
+
+        return match name {
+            "recursive" => Ok(Strategy::Recursive),
+            "highly-selective" => Ok(Strategy::HighlySelective),
+            "direct-children" => Ok(Strategy::DirectChildren),
+            "non-selective" => Ok(Strategy::NonSelective),
+            "contiguous" => Ok(Strategy::Contiguous(k)),
+            "skip-grams" => Ok(Strategy::SkipGrams(k)),
+            "unigrams" => Ok(Strategy::Unigrams),
+            _ => Err(format!("unknown strategy: {}", s)),
+        };
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Strategy::Recursive => write!(f, "recursive"),
+            Strategy::HighlySelective => write!(f, "highly-selective"),
+            Strategy::DirectChildren => write!(f, "direct-children"),
+            Strategy::NonSelective => write!(f, "non-selective"),
+            Strategy::Contiguous(k) => write!(f, "contiguous:{}", k),
+            Strategy::SkipGrams(k) => write!(f, "skip-grams:{}", k),
+            Strategy::Unigrams => write!(f, "unigrams"),
+        };
+    }
+}
@@ -1,63 +1,221 @@
-use cfg_if::cfg_if;
-use hashbrown::HashSet;
+use super::solver::SolveStatus;
+use hashbrown::{HashMap, HashSet};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+use std::time::Duration;
 
-pub fn get_children(ngram: &str, _child: bool, _compressed: &HashSet<String>) -> Vec<String> {
+#[derive(Default)]
+pub struct ChildCache {
+    children: RwLock<HashMap<String, Vec<String>>>,
+}
+
+impl ChildCache {
+    pub fn new() -> Self {
+        return ChildCache::default();
+    }
+
+    pub fn get_or_insert_with(&self, ngram: &str, f: impl FnOnce() -> Vec<String>) -> Vec<String> {
+        if let Some(children) = self.children.read().unwrap().get(ngram) {
+            return children.clone();
+        }
+
+        let children = f();
+        self.children
+            .write()
+            .unwrap()
+            .insert(ngram.to_string(), children.clone());
+
+        return children;
+    }
+}
+
+pub fn dedup(children: Vec<String>) -> Vec<String> {
+    let mut seen: HashSet<String> = HashSet::with_capacity(children.len());
+    return children
+        .into_iter()
+        .filter(|child| seen.insert(child.clone()))
+        .collect();
+}
+
+pub fn get_children(ngram: &str, child: bool, cache: &ChildCache) -> Vec<String> {
     if ngram.split_ascii_whitespace().count() == 1 {
         return vec![ngram.to_string()];
     }
 
-    cfg_if! {
-        if #[cfg(feature = "direct-children")] {
-            return vec![
-                ngram.rsplit_once(' ').unwrap().0.to_string(),
-                ngram.split_once(' ').unwrap().1.to_string(),
-            ];
-        }
+    if !child {
+        return dedup(
+            [
+                get_children(ngram.rsplit_once(' ').unwrap().0, true, cache),
+                get_children(ngram.split_once(' ').unwrap().1, true, cache),
+            ]
+            .concat(),
+        );
     }
 
-    cfg_if! {
-        if #[cfg(feature = "highly-selective")] {
-            fn expand(ngram: &str, compressed: &HashSet<String>, expanded: &mut Vec<String>) {
-                if ngram.split_ascii_whitespace().count() == 1 {
-                    expanded.push(ngram.to_string());
-                    return;
-                }
-
-                let right = ngram.split_once(' ').unwrap().1;
-                if !compressed.contains(&right.to_string()) {
-                    expanded.push(right.to_string());
-                } else {
-                    expand(right, compressed, expanded);
-                }
-
-                let left = ngram.rsplit_once(' ').unwrap().0;
-                if !compressed.contains(&left.to_string()) {
-                    expanded.push(left.to_string());
-                } else {
-                    expand(left, compressed, expanded);
-                }
-            }
+    return cache.get_or_insert_with(ngram, || {
+        dedup(
+            [
+                get_children(ngram.rsplit_once(' ').unwrap().0, true, cache),
+                get_children(ngram.split_once(' ').unwrap().1, true, cache),
+                vec![ngram.to_string()],
+            ]
+            .concat(),
+        )
+    });
+}
 
-            if _compressed.len() != 0 {
-                let mut expanded: Vec<String> = Vec::new();
-                expand(ngram, _compressed, &mut expanded);
-                return expanded;
-            }
+pub fn get_direct_children(ngram: &str) -> Vec<String> {
+    if ngram.split_ascii_whitespace().count() == 1 {
+        return vec![ngram.to_string()];
+    }
+
+    return vec![
+        ngram.rsplit_once(' ').unwrap().0.to_string(),
+        ngram.split_once(' ').unwrap().1.to_string(),
+    ];
+}
+
+pub fn expand(ngram: &str, compressed: &HashSet<String>, expanded: &mut Vec<String>) {
+    if ngram.split_ascii_whitespace().count() == 1 {
+        expanded.push(ngram.to_string());
+        return;
+    }
+
+    let right = ngram.split_once(' ').unwrap().1;
+    if !compressed.contains(&right.to_string()) {
+        expanded.push(right.to_string());
+    } else {
+        expand(right, compressed, expanded);
+    }
+
+    let left = ngram.rsplit_once(' ').unwrap().0;
+    if !compressed.contains(&left.to_string()) {
+        expanded.push(left.to_string());
+    } else {
+        expand(left, compressed, expanded);
+    }
+}
+
+pub fn get_contiguous(ngram: &str, k: usize) -> Vec<String> {
+    let tokens = ngram.split_ascii_whitespace().collect::<Vec<_>>();
+    if tokens.len() == 1 {
+        return vec![ngram.to_string()];
+    }
+
+    return tokens
+        .windows(k.clamp(1, tokens.len() - 1))
+        .map(|window| window.join(" "))
+        .collect();
+}
+
+pub fn get_skip_grams(ngram: &str, k: usize) -> Vec<String> {
+    fn combine(
+        tokens: &[&str],
+        k: usize,
+        start: usize,
+        current: &mut Vec<usize>,
+        out: &mut Vec<String>,
+    ) {
+        if current.len() == k {
+            out.push(
+                current
+                    .iter()
+                    .map(|i| tokens[*i])
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            );
+            return;
+        }
+
+        for i in start..=(tokens.len() - (k - current.len())) {
+            current.push(i);
+            combine(tokens, k, i + 1, current, out);
+            current.pop();
         }
     }
 
-    if !_child {
-        return [
-            get_children(ngram.rsplit_once(' ').unwrap().0, true, _compressed),
-            get_children(ngram.split_once(' ').unwrap().1, true, _compressed),
-        ]
-        .concat();
+    let tokens = ngram.split_ascii_whitespace().collect::<Vec<_>>();
+    if tokens.len() == 1 {
+        return vec![ngram.to_string()];
     }
 
-    return [
-        get_children(ngram.rsplit_once(' ').unwrap().0, true, _compressed),
-        get_children(ngram.split_once(' ').unwrap().1, true, _compressed),
-        vec![ngram.to_string()],
-    ]
-    .concat();
+    let mut skip_grams: Vec<String> = Vec::new();
+    combine(
+        &tokens,
+        k.clamp(1, tokens.len() - 1),
+        0,
+        &mut Vec::new(),
+        &mut skip_grams,
+    );
+    return skip_grams;
+}
+
+pub fn get_unigrams(ngram: &str) -> Vec<String> {
+    return ngram
+        .split_ascii_whitespace()
+        .map(|token| token.to_string())
+        .collect();
+}
+
+/// Counters shared by every worker, reported once the run is done.
+#[derive(Debug, Default)]
+pub struct SolveStats {
+    solved: AtomicU64,
+    skipped: AtomicU64,
+    dropped: AtomicU64,
+    nanos: AtomicU64,
+    statuses: [AtomicU64; SolveStatus::VARIANTS.len()],
+}
+
+impl SolveStats {
+    pub fn new() -> Self {
+        return SolveStats::default();
+    }
+
+    pub fn solved(&self, elapsed: Duration, status: SolveStatus) {
+        self.solved.fetch_add(1, Ordering::Relaxed);
+        self.statuses[status.index()].fetch_add(1, Ordering::Relaxed);
+        self.nanos
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    pub fn skipped(&self) {
+        self.skipped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn dropped(&self, count: usize) {
+        self.dropped.fetch_add(count as u64, Ordering::Relaxed);
+    }
+}
+
+impl fmt::Display for SolveStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let solved = self.solved.load(Ordering::Relaxed);
+        let skipped = self.skipped.load(Ordering::Relaxed);
+        let seconds = self.nanos.load(Ordering::Relaxed) as f64 / 1e9;
+        let per_solve = match solved {
+            0 => 0.,
+            _ => seconds / solved as f64,
+        };
+
+        write!(
+            f,
+            "solved {} LPs in {:.2}s, skipped {} that could not meet the error bound (~{:.2}s saved), dropped {} weakly correlated candidates",
+            solved,
+            seconds,
+            skipped,
+            skipped as f64 * per_solve,
+            self.dropped.load(Ordering::Relaxed)
+        )?;
+
+        for (name, count) in SolveStatus::VARIANTS.iter().zip(self.statuses.iter()) {
+            let count = count.load(Ordering::Relaxed);
+            if count > 0 && *name != "optimal" {
+                write!(f, ", {}: {}", name, count)?;
+            }
+        }
+
+        return Ok(());
+    }
 }
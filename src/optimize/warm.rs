@@ -0,0 +1,261 @@
+use super::solver::{LinearProgram, SolveStatus, SolverOption};
+use highs_sys::*;
+use std::cell::RefCell;
+use std::ffi::{c_void, CString};
+use std::ptr;
+
+const STATUS_ERROR: HighsInt = -1;
+const MATRIX_FORMAT_COLWISE: HighsInt = 1;
+const SENSE_MINIMIZE: HighsInt = 1;
+
+thread_local! {
+    static MODEL: RefCell<Option<WarmModel>> = const { RefCell::new(None) };
+}
+
+/// A HiGHS instance kept alive between the n-grams a worker solves. Column 0 is the bound `t`
+/// on the weighted residual, followed by one column per candidate. Every weighted year has
+/// two rows, `a·c - t <= y` and `-a·c - t <= -y`. Only the candidate columns, their
+/// coefficients and the row bounds change between solves, so HiGHS keeps its basis and starts
+/// the simplex from the previous optimum.
+struct WarmModel {
+    highs: *mut c_void,
+    /// Weighted years, in row order.
+    years: Vec<usize>,
+    options: Vec<SolverOption>,
+    candidates: usize,
+}
+
+impl WarmModel {
+    fn new(w: &[f64; 201], options: &[SolverOption]) -> Result<Self, SolveStatus> {
+        let years = (0..201).filter(|i| w[*i] != 0.).collect::<Vec<_>>();
+        let rows = 2 * years.len();
+
+        unsafe {
+            let highs = Highs_create();
+            Highs_setBoolOptionValue(highs, c"output_flag".as_ptr(), 0);
+            // Options are checked before any LP is solved, so this only fails for callers that
+            // skip `check_options`, and is then treated like any other failed call.
+            if set_options(highs, options).is_err() {
+                Highs_destroy(highs);
+                return Err(SolveStatus::NumericalError);
+            }
+
+            // Dropping the model on error destroys the instance.
+            let model = WarmModel {
+                highs,
+                years,
+                options: options.to_vec(),
+                candidates: 0,
+            };
+
+            let row_lower = vec![f64::NEG_INFINITY; rows];
+            let row_upper = vec![0.; rows];
+            let index = (0..rows as HighsInt).collect::<Vec<_>>();
+            let value = vec![-1.; rows];
+            check(Highs_passLp(
+                highs,
+                1,
+                rows as HighsInt,
+                rows as HighsInt,
+                MATRIX_FORMAT_COLWISE,
+                SENSE_MINIMIZE,
+                0.,
+                [1.].as_ptr(),
+                [0.].as_ptr(),
+                [f64::INFINITY].as_ptr(),
+                row_lower.as_ptr(),
+                row_upper.as_ptr(),
+                [0].as_ptr(),
+                index.as_ptr(),
+                value.as_ptr(),
+            ))?;
+
+            return Ok(model);
+        }
+    }
+
+    fn matches(&self, w: &[f64; 201], options: &[SolverOption]) -> bool {
+        return self.years.len() == w.iter().filter(|x| **x != 0.).count()
+            && self.years.iter().all(|i| w[*i] != 0.)
+            && self.options == options;
+    }
+
+    fn resize(&mut self, candidates: usize) -> Result<(), SolveStatus> {
+        unsafe {
+            if candidates > self.candidates {
+                let added = candidates - self.candidates;
+                let zeros = vec![0.; added];
+                let starts = vec![0; added];
+                check(Highs_addCols(
+                    self.highs,
+                    added as HighsInt,
+                    zeros.as_ptr(),
+                    zeros.as_ptr(),
+                    zeros.as_ptr(),
+                    0,
+                    starts.as_ptr(),
+                    ptr::null(),
+                    ptr::null(),
+                ))?;
+            } else if candidates < self.candidates {
+                check(Highs_deleteColsByRange(
+                    self.highs,
+                    candidates as HighsInt + 1,
+                    self.candidates as HighsInt,
+                ))?;
+            }
+        }
+
+        self.candidates = candidates;
+        return Ok(());
+    }
+
+    fn solve(
+        &mut self,
+        y: &[f64; 201],
+        child_freqs: &[&[f64; 201]],
+        bounds: &[(f64, f64)],
+        w: &[f64; 201],
+    ) -> Result<Vec<f64>, SolveStatus> {
+        self.resize(child_freqs.len())?;
+        let rows = 2 * self.years.len();
+
+        unsafe {
+            if !bounds.is_empty() {
+                let (lower, upper): (Vec<f64>, Vec<f64>) = bounds.iter().cloned().unzip();
+                check(Highs_changeColsBoundsByRange(
+                    self.highs,
+                    1,
+                    bounds.len() as HighsInt,
+                    lower.as_ptr(),
+                    upper.as_ptr(),
+                ))?;
+            }
+
+            for (col, x) in child_freqs.iter().enumerate() {
+                for (row, year) in self.years.iter().enumerate() {
+                    let a = x[*year] * w[*year];
+                    check(Highs_changeCoeff(
+                        self.highs,
+                        row as HighsInt,
+                        col as HighsInt + 1,
+                        a,
+                    ))?;
+                    check(Highs_changeCoeff(
+                        self.highs,
+                        (row + self.years.len()) as HighsInt,
+                        col as HighsInt + 1,
+                        -a,
+                    ))?;
+                }
+            }
+
+            let row_lower = vec![f64::NEG_INFINITY; rows];
+            let row_upper = self
+                .years
+                .iter()
+                .map(|i| y[*i] * w[*i])
+                .chain(self.years.iter().map(|i| -y[*i] * w[*i]))
+                .collect::<Vec<_>>();
+            if rows > 0 {
+                let set = (0..rows as HighsInt).collect::<Vec<_>>();
+                check(Highs_changeRowsBoundsBySet(
+                    self.highs,
+                    rows as HighsInt,
+                    set.as_ptr(),
+                    row_lower.as_ptr(),
+                    row_upper.as_ptr(),
+                ))?;
+            }
+
+            // The run clock keeps counting across runs, so without this the time limit would
+            // apply to all solves of the model together instead of to each.
+            check(Highs_zeroAllClocks(self.highs))?;
+            check(Highs_run(self.highs))?;
+
+            let status = SolveStatus::from_highs(Highs_getModelStatus(self.highs) as i32);
+            if status != SolveStatus::Optimal {
+                return Err(status);
+            }
+
+            let mut col_value = vec![0.; self.candidates + 1];
+            let mut col_dual = vec![0.; self.candidates + 1];
+            let mut row_value = vec![0.; rows];
+            let mut row_dual = vec![0.; rows];
+            check(Highs_getSolution(
+                self.highs,
+                col_value.as_mut_ptr(),
+                col_dual.as_mut_ptr(),
+                row_value.as_mut_ptr(),
+                row_dual.as_mut_ptr(),
+            ))?;
+
+            return Ok(col_value.split_off(1));
+        }
+    }
+}
+
+impl Drop for WarmModel {
+    fn drop(&mut self) {
+        unsafe {
+            Highs_destroy(self.highs);
+        }
+    }
+}
+
+/// Turns a failed HiGHS call into an error, so the caller discards the model.
+fn check(status: HighsInt) -> Result<(), SolveStatus> {
+    return match status == STATUS_ERROR {
+        true => Err(SolveStatus::NumericalError),
+        false => Ok(()),
+    };
+}
+
+unsafe fn set_options(highs: *mut c_void, options: &[SolverOption]) -> Result<(), String> {
+    for option in options {
+        let key = CString::new(option.key.as_str()).map_err(|e| e.to_string())?;
+        let value = CString::new(option.value.as_str()).map_err(|e| e.to_string())?;
+        if Highs_setStringOptionValue(highs, key.as_ptr(), value.as_ptr()) == STATUS_ERROR {
+            return Err(format!("invalid solver option: {}", option));
+        }
+    }
+
+    return Ok(());
+}
+
+/// Checks the options against a throwaway instance, so a typo fails before any work is done.
+pub(crate) fn check_options(options: &[SolverOption]) -> Result<(), String> {
+    unsafe {
+        let highs = Highs_create();
+        Highs_setBoolOptionValue(highs, c"output_flag".as_ptr(), 0);
+        let result = set_options(highs, options);
+        Highs_destroy(highs);
+        return result;
+    }
+}
+
+/// Solves `lp` on this thread's reused model. A failed solve discards the model so the next
+/// n-gram starts from scratch.
+pub(crate) fn solve(lp: &LinearProgram, options: &[SolverOption]) -> Result<Vec<f64>, SolveStatus> {
+    let LinearProgram {
+        y,
+        series: child_freqs,
+        bounds,
+        weights: w,
+    } = *lp;
+
+    return MODEL.with(|model| {
+        let mut model = model.borrow_mut();
+        if !model.as_ref().is_some_and(|m| m.matches(w, options)) {
+            *model = None;
+            *model = Some(WarmModel::new(w, options)?);
+        }
+
+        let columns = model.as_mut().unwrap().solve(y, child_freqs, bounds, w);
+        if columns.is_err() {
+            *model = None;
+        }
+
+        return columns;
+    });
+}
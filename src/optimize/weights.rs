@@ -0,0 +1,89 @@
+use std::{fmt, fs, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct YearWeights {
+    pub name: String,
+    pub weights: [f64; 201],
+}
+
+impl YearWeights {
+    pub fn uniform() -> Self {
+        return YearWeights {
+            name: "uniform".to_string(),
+            weights: [1.; 201],
+        };
+    }
+
+    pub fn linear_ramp() -> Self {
+        let mut weights = [0.; 201];
+        for (i, weight) in weights.iter_mut().enumerate() {
+            *weight = (i + 1) as f64 / 201.;
+        }
+
+        return YearWeights {
+            name: "linear-ramp".to_string(),
+            weights,
+        };
+    }
+
+    pub fn from_csv(path: &str) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        let mut weights = [1.; 201];
+
+        for (i, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            // Only the first line may be a header, recognized by not starting with a year.
+            let first = line.split(',').next().unwrap_or_default();
+            if i == 0 && first.trim().parse::<usize>().is_err() {
+                continue;
+            }
+
+            let invalid = || format!("{}:{}: expected year,weight: {}", path, i + 1, line);
+            let (year, weight) = line.split_once(',').ok_or_else(invalid)?;
+            let year = year.trim().parse::<usize>().map_err(|_| invalid())?;
+            let weight = weight.trim().parse::<f64>().map_err(|_| invalid())?;
+
+            if !(1800..=2000).contains(&year) {
+                return Err(format!("{}:{}: year out of range: {}", path, i + 1, year));
+            }
+
+            if !weight.is_finite() || weight < 0. {
+                return Err(format!("{}: invalid weight for {}: {}", path, year, weight));
+            }
+
+            weights[year - 1800] = weight;
+        }
+
+        return Ok(YearWeights {
+            name: path.to_string(),
+            weights,
+        });
+    }
+}
+
+impl Default for YearWeights {
+    fn default() -> Self {
+        return YearWeights::uniform();
+    }
+}
+
+impl FromStr for YearWeights {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s {
+            "uniform" => Ok(YearWeights::uniform()),
+            "linear-ramp" => Ok(YearWeights::linear_ramp()),
+            path => YearWeights::from_csv(path),
+        };
+    }
+}
+
+impl fmt::Display for YearWeights {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.name);
+    }
+}
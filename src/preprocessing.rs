@@ -1,3 +1,4 @@
+use crate::totals::{parse_totals, write_totals};
 use polars::prelude::*;
 use std::{collections::HashMap, fs, io::Read, path::PathBuf};
 use structopt::StructOpt;
@@ -15,10 +16,99 @@ pub struct Preprocess {
     pub cont: bool,
     #[structopt(name = "duckdb", short = "d", long = "duckdb")]
     pub duckdb: bool,
+    #[structopt(short = "n", long = "max-n", default_value = "5")]
+    pub max_n: usize,
+    #[structopt(long = "totals", parse(try_from_str = parse_totals))]
+    pub totals: Option<[f64; 201]>,
 }
 
-pub fn preprocess(input: PathBuf, output: PathBuf, gzip: bool, cont: bool, duckdb: bool) {
-    for n in 1..6 {
+#[derive(Debug, Clone)]
+pub struct Preprocessor {
+    input: PathBuf,
+    output: PathBuf,
+    gzip: bool,
+    cont: bool,
+    duckdb: bool,
+    max_n: usize,
+    totals: Option<[f64; 201]>,
+}
+
+impl Preprocessor {
+    pub fn new(input: PathBuf, output: PathBuf) -> Self {
+        return Preprocessor {
+            input,
+            output,
+            gzip: false,
+            cont: false,
+            duckdb: false,
+            max_n: 5,
+            totals: None,
+        };
+    }
+
+    pub fn gzip(mut self, gzip: bool) -> Self {
+        self.gzip = gzip;
+        return self;
+    }
+
+    pub fn cont(mut self, cont: bool) -> Self {
+        self.cont = cont;
+        return self;
+    }
+
+    pub fn duckdb(mut self, duckdb: bool) -> Self {
+        self.duckdb = duckdb;
+        return self;
+    }
+
+    pub fn max_n(mut self, max_n: usize) -> Self {
+        self.max_n = max_n;
+        return self;
+    }
+
+    pub fn totals(mut self, totals: [f64; 201]) -> Self {
+        self.totals = Some(totals);
+        return self;
+    }
+
+    pub fn run(&self) {
+        preprocess(
+            self.input.clone(),
+            self.output.clone(),
+            self.gzip,
+            self.cont,
+            self.duckdb,
+            self.max_n,
+            self.totals,
+        );
+    }
+}
+
+impl From<Preprocess> for Preprocessor {
+    fn from(preprocess: Preprocess) -> Self {
+        let preprocessor = Preprocessor::new(preprocess.input, preprocess.output)
+            .gzip(preprocess.gzip)
+            .cont(preprocess.cont)
+            .duckdb(preprocess.duckdb)
+            .max_n(preprocess.max_n);
+
+        return match preprocess.totals {
+            Some(totals) => preprocessor.totals(totals),
+            None => preprocessor,
+        };
+    }
+}
+
+pub fn preprocess(
+    input: PathBuf,
+    output: PathBuf,
+    gzip: bool,
+    cont: bool,
+    duckdb: bool,
+    max_n: usize,
+    totals: Option<[f64; 201]>,
+) {
+    for n in 1..=max_n {
         let files = fs::read_dir(input.join(n.to_string())).unwrap();
 
         let outdir = output.join(format!("n={}", n));
@@ -57,6 +147,10 @@ pub fn preprocess(input: PathBuf, output: PathBuf, gzip: bool, cont: bool, duckd
         }
     }
 
+    if let Some(totals) = totals {
+        write_totals(&totals, &output.join("totals.parquet"));
+    }
+
     if duckdb {
         let conn = duckdb::Connection::open(&output.with_extension("db")).unwrap();
 
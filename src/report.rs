@@ -0,0 +1,107 @@
+use crate::optimize::{
+    ChildCache, ChildCombination, Compressor, Dct, Load, Loader, Normalization, Optimizer,
+    PiecewiseLinear, Strategy, SvdBasis, DEFAULT_STRATEGY,
+};
+use rayon::prelude::*;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "report",
+    about = "Compare child combinations against generic series compressors"
+)]
+pub struct Report {
+    #[structopt(short = "i", parse(from_os_str))]
+    pub input: PathBuf,
+    #[structopt(short = "b", default_value = "0.5")]
+    pub error_bound: f64,
+    #[structopt(short = "n", long = "max-n", default_value = "5")]
+    pub max_n: usize,
+    #[structopt(short = "l", long = "limit", default_value = "10000")]
+    pub limit: usize,
+    #[structopt(long = "strategy", default_value = DEFAULT_STRATEGY)]
+    pub strategy: Strategy,
+    #[structopt(
+        long = "normalization",
+        default_value = "z",
+        possible_values = Normalization::VARIANTS
+    )]
+    pub normalization: Normalization,
+    #[structopt(long = "svd-rank", default_value = "20")]
+    pub svd_rank: usize,
+}
+
+pub fn report(report: Report) {
+    let optimizer = Optimizer::builder(report.input.clone(), PathBuf::new())
+        .error_bound(report.error_bound)
+        .strategy(report.strategy)
+        .normalization(report.normalization)
+        .build();
+    let loader = Loader::from_path(report.input);
+
+    for n in 1..=report.max_n {
+        let slice = loader.get_slice(report.limit, 0, n as u8);
+        if slice.is_empty() {
+            continue;
+        }
+
+        let strategy = report.strategy.child_strategy();
+        let frequencies = loader.get_frequencies(&slice, strategy.as_ref(), &ChildCache::new());
+
+        let compressors: Vec<Box<dyn Compressor>> = vec![
+            Box::new(ChildCombination::new(strategy)),
+            Box::new(Dct::new()),
+            Box::new(PiecewiseLinear),
+            Box::new(SvdBasis::learn(slice.values(), report.svd_rank, &optimizer)),
+        ];
+
+        println!(
+            "n={} ({} n-grams, error bound {})",
+            n,
+            slice.len(),
+            report.error_bound
+        );
+        println!(
+            "{:<20} {:>10} {:>12} {:>12} {:>12} {:>12}",
+            "compressor", "accepted", "parameters", "overhead", "mean error", "mean rmse"
+        );
+
+        for compressor in compressors.iter() {
+            let solutions = slice
+                .par_iter()
+                .map(|(ngram, _)| compressor.compress(ngram, &frequencies, &optimizer))
+                .collect::<Vec<_>>();
+
+            let accepted = solutions
+                .iter()
+                .filter(|sol| sol.error <= report.error_bound)
+                .collect::<Vec<_>>();
+            let solved = solutions
+                .iter()
+                .filter(|sol| sol.error.is_finite())
+                .collect::<Vec<_>>();
+
+            let parameters = accepted
+                .iter()
+                .map(|sol| sol.coefficients.len())
+                .sum::<usize>() as f64
+                / accepted.len().max(1) as f64;
+            let error =
+                solved.iter().map(|sol| sol.error).sum::<f64>() / solved.len().max(1) as f64;
+            let rmse = solved.iter().map(|sol| sol.rmse).sum::<f64>() / solved.len().max(1) as f64;
+
+            println!(
+                "{:<20} {:>9.2}% {:>12.2} {:>12} {:>12.4} {:>12.4}",
+                compressor.name(),
+                100. * accepted.len() as f64 / solutions.len() as f64,
+                parameters,
+                compressor.overhead(),
+                error,
+                rmse
+            );
+        }
+
+        println!();
+    }
+}
@@ -0,0 +1,163 @@
+use polars::prelude::*;
+use std::{fs, path::Path};
+
+/// Reads the per-year totals for 1800 to 2000 from a parquet file with `year` and `total`
+/// columns, or from a Google Books `total_counts` file of whitespace-separated
+/// `year,match_count,...` records. Years outside the range are ignored, but every year in it
+/// must be present with a non-zero total, since it would otherwise scale its frequencies to 0.
+pub fn read_totals(path: &Path) -> Result<[f64; 201], String> {
+    let name = path.display();
+    let mut totals = [f64::NAN; 201];
+
+    if path.extension().is_some_and(|ext| ext == "parquet") {
+        let df = fs::File::open(path)
+            .map_err(|e| e.to_string())
+            .and_then(|f| ParquetReader::new(f).finish().map_err(|e| e.to_string()))
+            .map_err(|e| format!("{}: {}", name, e))?;
+        let column = |column: &str| {
+            return df
+                .column(column)
+                .and_then(|c| c.cast(&DataType::Float64))
+                .map_err(|e| format!("{}: {}", name, e));
+        };
+        let (years, counts) = (column("year")?, column("total")?);
+
+        for (year, total) in years
+            .f64()
+            .unwrap()
+            .iter()
+            .zip(counts.f64().unwrap().iter())
+        {
+            let (Some(year), Some(total)) = (year, total) else {
+                return Err(format!("{}: null year or total", name));
+            };
+
+            if (1800.0..=2000.0).contains(&year) {
+                totals[year as usize - 1800] = total;
+            }
+        }
+    } else {
+        let content = fs::read_to_string(path).map_err(|e| format!("{}: {}", name, e))?;
+
+        for record in content.split_ascii_whitespace() {
+            let invalid = || format!("{}: expected year,match_count,...: {}", name, record);
+            let mut fields = record.split(',');
+            let year = fields.next().ok_or_else(invalid)?;
+            let count = fields.next().ok_or_else(invalid)?;
+            let year = year.parse::<usize>().map_err(|_| invalid())?;
+            let count = count.parse::<u64>().map_err(|_| invalid())?;
+
+            if (1800..=2000).contains(&year) {
+                totals[year - 1800] = count as f64;
+            }
+        }
+    }
+
+    for (i, total) in totals.iter().enumerate() {
+        if total.is_nan() {
+            return Err(format!("{}: missing total for {}", name, 1800 + i));
+        }
+
+        if !total.is_finite() || *total <= 0. {
+            return Err(format!(
+                "{}: invalid total for {}: {}",
+                name,
+                1800 + i,
+                total
+            ));
+        }
+    }
+
+    return Ok(totals);
+}
+
+/// Reads the totals named on the command line, so bad files are rejected while parsing it.
+pub fn parse_totals(path: &str) -> Result<[f64; 201], String> {
+    return read_totals(Path::new(path));
+}
+
+pub fn write_totals(totals: &[f64; 201], path: &Path) {
+    let mut f = fs::File::create(path).unwrap();
+    let mut df = df![
+        "year" => (1800..=2000u32).collect::<Vec<_>>(),
+        "total" => totals.iter().map(|x| *x as u64).collect::<Vec<_>>(),
+    ]
+    .unwrap();
+    ParquetWriter::new(&mut f)
+        .with_compression(ParquetCompression::Uncompressed)
+        .finish(&mut df)
+        .expect("writing parquet file");
+}
+
+pub fn per_million(totals: &[f64; 201]) -> [f64; 201] {
+    return totals.map(|total| match total > 0. {
+        true => 1e6 / total,
+        false => 0.,
+    });
+}
+
+pub fn to_relative(frequency: &[f64; 201], totals: &[f64; 201]) -> [f64; 201] {
+    let scale = per_million(totals);
+    let mut relative = [0.; 201];
+
+    for (i, value) in relative.iter_mut().enumerate() {
+        *value = frequency[i] * scale[i];
+    }
+
+    return relative;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(name: &str, content: &str) -> Result<[f64; 201], String> {
+        let path = std::env::temp_dir().join(format!("nghc-rs-{}-{}", std::process::id(), name));
+        fs::write(&path, content).unwrap();
+        let totals = read_totals(&path);
+        fs::remove_file(&path).unwrap();
+
+        return totals;
+    }
+
+    fn records(skip: usize, zero: usize) -> String {
+        return (1790..=2005)
+            .filter(|year| *year != skip)
+            .map(|year| format!("{},{},1,1", year, if year == zero { 0 } else { year }))
+            .collect::<Vec<_>>()
+            .join("\t");
+    }
+
+    #[test]
+    fn reads_every_year() {
+        let totals = read("all", &records(0, 0)).unwrap();
+        assert_eq!(totals[0], 1800.);
+        assert_eq!(totals[200], 2000.);
+
+        let path = std::env::temp_dir().join(format!("nghc-rs-{}.parquet", std::process::id()));
+        write_totals(&totals, &path);
+        assert_eq!(read_totals(&path), Ok(totals));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn rejects_malformed_records() {
+        let err = read("malformed", &format!("{}\t1850;12", records(0, 0))).unwrap_err();
+        assert!(err.contains("1850;12"), "{}", err);
+
+        let err = read("count", &format!("{}\t1850,x,1,1", records(0, 0))).unwrap_err();
+        assert!(err.contains("1850,x"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_missing_and_zero_totals() {
+        let err = read("missing", &records(1900, 0)).unwrap_err();
+        assert!(err.contains("missing total for 1900"), "{}", err);
+
+        let err = read("zero", &records(0, 1950)).unwrap_err();
+        assert!(err.contains("invalid total for 1950"), "{}", err);
+
+        // Years outside 1800 to 2000 are not needed and may be anything.
+        assert!(read("outside", &records(1795, 2003)).is_ok());
+    }
+}
@@ -0,0 +1,29 @@
+#!/bin/zsh
+
+# Round-trips a preprocessed directory through `optimize --lossless --basis-size` and
+# `decompress`, including unigrams, and fails unless every decompressed count matches the
+# preprocessed input exactly.
+
+input=${1:-50_000}
+size=${2:-16}
+
+rm -rf basis
+mkdir -p basis
+
+./nghc-rs optimize -i $input -o basis/optimized --lossless --basis-size $size || exit 1
+[[ -f basis/optimized/basis.parquet ]] || { echo "no basis written"; exit 1 }
+./nghc-rs decompress -i basis/optimized -o basis/decompressed || exit 1
+
+mismatches=$(duckdb -noheader -csv -c "
+    SELECT count(*)
+    FROM read_parquet('$input/*/*.parquet') AS original
+    FULL OUTER JOIN read_parquet('basis/decompressed/*/*.parquet') AS decompressed
+    USING (ngram)
+    WHERE original.frequency IS DISTINCT FROM decompressed.frequency
+")
+unigrams=$(duckdb -noheader -csv -c "
+    SELECT count(*) FROM read_parquet('basis/decompressed/n=1/*.parquet')
+")
+
+echo "mismatches: $mismatches, unigrams: $unigrams"
+[[ $mismatches == 0 && $unigrams -gt 0 ]]
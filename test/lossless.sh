@@ -0,0 +1,23 @@
+#!/bin/zsh
+
+# Round-trips a preprocessed directory through `optimize --lossless` and `decompress`
+# and fails unless every decompressed count matches the preprocessed input exactly.
+
+input=${1:-50_000}
+
+rm -rf lossless
+mkdir -p lossless
+
+./nghc-rs optimize -i $input -o lossless/optimized --lossless || exit 1
+./nghc-rs decompress -i lossless/optimized -o lossless/decompressed || exit 1
+
+mismatches=$(duckdb -noheader -csv -c "
+    SELECT count(*)
+    FROM read_parquet('$input/*/*.parquet') AS original
+    FULL OUTER JOIN read_parquet('lossless/decompressed/*/*.parquet') AS decompressed
+    USING (ngram)
+    WHERE original.frequency IS DISTINCT FROM decompressed.frequency
+")
+
+echo "mismatches: $mismatches"
+[[ $mismatches == 0 ]]
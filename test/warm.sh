@@ -0,0 +1,22 @@
+#!/bin/zsh
+
+# Compares the reused, warm-started LP models against building every LP from scratch.
+# Both runs must accept the same n-grams; the LP time of each is printed at the end.
+
+input=${1:-50_000.db}
+
+rm -rf warm
+mkdir -p warm
+
+for mode in warm cold ; do
+    flags=()
+    [[ $mode == cold ]] && flags=(--cold-start)
+
+    echo "$mode:"
+    (time ./nghc-rs optimize -i $input -o warm/$mode $flags) 2>&1 | tee warm/$mode.log
+done
+
+for mode in warm cold ; do
+    echo -n "$mode compressed: "
+    duckdb -noheader -csv -c "SELECT count(*) FROM read_parquet('warm/$mode/compressed/*/*.parquet')"
+done
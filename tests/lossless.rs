@@ -0,0 +1,179 @@
+use duckdb::Connection;
+use nghc_rs::decompress::decompress;
+use nghc_rs::{Optimizer, Preprocessor, Strategy};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Ten unigrams, the first four sparse enough to stay raw, bigrams built from two unigrams and
+/// trigrams built from two bigrams, each with a little noise so the residuals are not all zero.
+/// Written as Google Books files for `preprocess`.
+fn fixture() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("nghc-rs-lossless-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+
+    let mut seed = 12345u64;
+    let mut random = |max: u64| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        return (seed >> 33) % max;
+    };
+
+    let unigrams = (0..10)
+        .map(|i| {
+            let series: [u64; 201] = std::array::from_fn(|y| match i < 4 {
+                true if y % 20 == i => 50 + i as u64,
+                true => 0,
+                false => 5000 * i as u64 + random(3000),
+            });
+            return (format!("w{}", i), series);
+        })
+        .collect::<Vec<_>>();
+
+    let mut bigrams = Vec::new();
+    for (a, b) in [
+        (0, 5),
+        (1, 6),
+        (2, 7),
+        (3, 8),
+        (5, 6),
+        (6, 7),
+        (7, 8),
+        (8, 9),
+        (9, 4),
+    ] {
+        let series: [u64; 201] =
+            std::array::from_fn(|y| 2 * unigrams[a].1[y] + unigrams[b].1[y] + random(5));
+        bigrams.push((format!("w{} w{}", a, b), series));
+    }
+
+    let mut trigrams = Vec::new();
+    for (ab, bc) in [(4, 5), (5, 6), (6, 7), (7, 8)] {
+        let (first, second) = (&bigrams[ab], &bigrams[bc]);
+        let series: [u64; 201] = std::array::from_fn(|y| first.1[y] + 3 * second.1[y] + random(2));
+        let last = second.0.split(' ').next_back().unwrap();
+        trigrams.push((format!("{} {}", first.0, last), series));
+    }
+
+    for (n, ngrams) in [(1, unigrams), (2, bigrams), (3, trigrams)] {
+        let lines = ngrams
+            .iter()
+            .map(|(ngram, series)| {
+                let years = series
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, count)| **count > 0)
+                    .map(|(y, count)| format!("{},{},1", 1800 + y, count))
+                    .collect::<Vec<_>>();
+                return format!("{}\t{}", ngram, years.join("\t"));
+            })
+            .collect::<Vec<_>>();
+
+        fs::create_dir_all(dir.join("raw").join(n.to_string())).unwrap();
+        fs::write(
+            dir.join("raw").join(n.to_string()).join("0"),
+            lines.join("\n"),
+        )
+        .unwrap();
+    }
+
+    return dir;
+}
+
+fn count(conn: &Connection, query: &str) -> usize {
+    return conn.query_row(query, [], |row| row.get(0)).unwrap();
+}
+
+/// Counts the stored coefficients by what their child is: an atom of the basis, another
+/// compressed n-gram or an n-gram stored raw.
+fn children(conn: &Connection, optimized: &Path) -> (usize, usize, usize) {
+    conn.execute_batch(&format!(
+        "
+        CREATE TABLE children AS
+            SELECT token
+            FROM (
+                SELECT unnest(coefficients).child_id AS id
+                FROM read_parquet('{0}/compressed/*/*.parquet')
+            )
+            JOIN read_parquet('{0}/dictionary.parquet') USING (id);
+        CREATE TABLE compressed AS SELECT ngram FROM read_parquet('{0}/compressed/*/*.parquet');
+        CREATE TABLE uncompressed AS SELECT ngram FROM read_parquet('{0}/uncompressed/*/*.parquet');
+        ",
+        optimized.display()
+    ))
+    .unwrap();
+
+    return (
+        count(
+            conn,
+            "SELECT count(*) FROM children WHERE token LIKE '\\_\\_atom\\_%' ESCAPE '\\'",
+        ),
+        count(
+            conn,
+            "SELECT count(*) FROM children WHERE token IN (SELECT ngram FROM compressed)",
+        ),
+        count(
+            conn,
+            "SELECT count(*) FROM children WHERE token IN (SELECT ngram FROM uncompressed)",
+        ),
+    );
+}
+
+#[test]
+fn lossless_round_trip() {
+    let dir = fixture();
+    let (preprocessed, optimized, decompressed) = (
+        dir.join("preprocessed"),
+        dir.join("optimized"),
+        dir.join("decompressed"),
+    );
+
+    Preprocessor::new(dir.join("raw"), preprocessed.clone())
+        .max_n(3)
+        .run();
+    Optimizer::builder(preprocessed.clone(), optimized.clone())
+        .lossless(true)
+        .cores(1)
+        .max_n(3)
+        .strategy("direct-children".parse::<Strategy>().unwrap())
+        .basis_size(2)
+        .build()
+        .run()
+        .unwrap();
+    decompress(optimized.clone(), decompressed.clone(), false, false);
+
+    let conn = Connection::open_in_memory().unwrap();
+
+    let (atoms, compressed, raw) = children(&conn, &optimized);
+    assert!(atoms > 0, "no n-gram uses a basis atom");
+    assert!(compressed > 0, "no n-gram uses a compressed child");
+    assert!(raw > 0, "no n-gram uses a raw child");
+
+    let ngrams = count(
+        &conn,
+        &format!(
+            "SELECT count(*) FROM read_parquet('{}/*/*.parquet')",
+            decompressed.display()
+        ),
+    );
+    assert_eq!(ngrams, 10 + 9 + 4);
+
+    let mismatches = count(
+        &conn,
+        &format!(
+            "
+            SELECT count(*)
+            FROM read_parquet('{}/*/*.parquet') AS original
+            FULL OUTER JOIN read_parquet('{}/*/*.parquet') AS decompressed
+            USING (ngram)
+            WHERE original.frequency IS DISTINCT FROM decompressed.frequency
+            ",
+            preprocessed.display(),
+            decompressed.display()
+        ),
+    );
+    assert_eq!(mismatches, 0);
+
+    drop(conn);
+    fs::remove_dir_all(&dir).unwrap();
+}
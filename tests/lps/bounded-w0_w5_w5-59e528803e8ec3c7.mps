@@ -0,0 +1,2826 @@
+NAME w0_w5_w5-59e528803e8ec3c7
+ROWS
+ N obj
+ L above_1800
+ L above_1801
+ L above_1802
+ L above_1803
+ L above_1804
+ L above_1805
+ L above_1806
+ L above_1807
+ L above_1808
+ L above_1809
+ L above_1810
+ L above_1811
+ L above_1812
+ L above_1813
+ L above_1814
+ L above_1815
+ L above_1816
+ L above_1817
+ L above_1818
+ L above_1819
+ L above_1820
+ L above_1821
+ L above_1822
+ L above_1823
+ L above_1824
+ L above_1825
+ L above_1826
+ L above_1827
+ L above_1828
+ L above_1829
+ L above_1830
+ L above_1831
+ L above_1832
+ L above_1833
+ L above_1834
+ L above_1835
+ L above_1836
+ L above_1837
+ L above_1838
+ L above_1839
+ L above_1840
+ L above_1841
+ L above_1842
+ L above_1843
+ L above_1844
+ L above_1845
+ L above_1846
+ L above_1847
+ L above_1848
+ L above_1849
+ L above_1850
+ L above_1851
+ L above_1852
+ L above_1853
+ L above_1854
+ L above_1855
+ L above_1856
+ L above_1857
+ L above_1858
+ L above_1859
+ L above_1860
+ L above_1861
+ L above_1862
+ L above_1863
+ L above_1864
+ L above_1865
+ L above_1866
+ L above_1867
+ L above_1868
+ L above_1869
+ L above_1870
+ L above_1871
+ L above_1872
+ L above_1873
+ L above_1874
+ L above_1875
+ L above_1876
+ L above_1877
+ L above_1878
+ L above_1879
+ L above_1880
+ L above_1881
+ L above_1882
+ L above_1883
+ L above_1884
+ L above_1885
+ L above_1886
+ L above_1887
+ L above_1888
+ L above_1889
+ L above_1890
+ L above_1891
+ L above_1892
+ L above_1893
+ L above_1894
+ L above_1895
+ L above_1896
+ L above_1897
+ L above_1898
+ L above_1899
+ L above_1900
+ L above_1901
+ L above_1902
+ L above_1903
+ L above_1904
+ L above_1905
+ L above_1906
+ L above_1907
+ L above_1908
+ L above_1909
+ L above_1910
+ L above_1911
+ L above_1912
+ L above_1913
+ L above_1914
+ L above_1915
+ L above_1916
+ L above_1917
+ L above_1918
+ L above_1919
+ L above_1920
+ L above_1921
+ L above_1922
+ L above_1923
+ L above_1924
+ L above_1925
+ L above_1926
+ L above_1927
+ L above_1928
+ L above_1929
+ L above_1930
+ L above_1931
+ L above_1932
+ L above_1933
+ L above_1934
+ L above_1935
+ L above_1936
+ L above_1937
+ L above_1938
+ L above_1939
+ L above_1940
+ L above_1941
+ L above_1942
+ L above_1943
+ L above_1944
+ L above_1945
+ L above_1946
+ L above_1947
+ L above_1948
+ L above_1949
+ L above_1950
+ L above_1951
+ L above_1952
+ L above_1953
+ L above_1954
+ L above_1955
+ L above_1956
+ L above_1957
+ L above_1958
+ L above_1959
+ L above_1960
+ L above_1961
+ L above_1962
+ L above_1963
+ L above_1964
+ L above_1965
+ L above_1966
+ L above_1967
+ L above_1968
+ L above_1969
+ L above_1970
+ L above_1971
+ L above_1972
+ L above_1973
+ L above_1974
+ L above_1975
+ L above_1976
+ L above_1977
+ L above_1978
+ L above_1979
+ L above_1980
+ L above_1981
+ L above_1982
+ L above_1983
+ L above_1984
+ L above_1985
+ L above_1986
+ L above_1987
+ L above_1988
+ L above_1989
+ L above_1990
+ L above_1991
+ L above_1992
+ L above_1993
+ L above_1994
+ L above_1995
+ L above_1996
+ L above_1997
+ L above_1998
+ L above_1999
+ L above_2000
+ L below_1800
+ L below_1801
+ L below_1802
+ L below_1803
+ L below_1804
+ L below_1805
+ L below_1806
+ L below_1807
+ L below_1808
+ L below_1809
+ L below_1810
+ L below_1811
+ L below_1812
+ L below_1813
+ L below_1814
+ L below_1815
+ L below_1816
+ L below_1817
+ L below_1818
+ L below_1819
+ L below_1820
+ L below_1821
+ L below_1822
+ L below_1823
+ L below_1824
+ L below_1825
+ L below_1826
+ L below_1827
+ L below_1828
+ L below_1829
+ L below_1830
+ L below_1831
+ L below_1832
+ L below_1833
+ L below_1834
+ L below_1835
+ L below_1836
+ L below_1837
+ L below_1838
+ L below_1839
+ L below_1840
+ L below_1841
+ L below_1842
+ L below_1843
+ L below_1844
+ L below_1845
+ L below_1846
+ L below_1847
+ L below_1848
+ L below_1849
+ L below_1850
+ L below_1851
+ L below_1852
+ L below_1853
+ L below_1854
+ L below_1855
+ L below_1856
+ L below_1857
+ L below_1858
+ L below_1859
+ L below_1860
+ L below_1861
+ L below_1862
+ L below_1863
+ L below_1864
+ L below_1865
+ L below_1866
+ L below_1867
+ L below_1868
+ L below_1869
+ L below_1870
+ L below_1871
+ L below_1872
+ L below_1873
+ L below_1874
+ L below_1875
+ L below_1876
+ L below_1877
+ L below_1878
+ L below_1879
+ L below_1880
+ L below_1881
+ L below_1882
+ L below_1883
+ L below_1884
+ L below_1885
+ L below_1886
+ L below_1887
+ L below_1888
+ L below_1889
+ L below_1890
+ L below_1891
+ L below_1892
+ L below_1893
+ L below_1894
+ L below_1895
+ L below_1896
+ L below_1897
+ L below_1898
+ L below_1899
+ L below_1900
+ L below_1901
+ L below_1902
+ L below_1903
+ L below_1904
+ L below_1905
+ L below_1906
+ L below_1907
+ L below_1908
+ L below_1909
+ L below_1910
+ L below_1911
+ L below_1912
+ L below_1913
+ L below_1914
+ L below_1915
+ L below_1916
+ L below_1917
+ L below_1918
+ L below_1919
+ L below_1920
+ L below_1921
+ L below_1922
+ L below_1923
+ L below_1924
+ L below_1925
+ L below_1926
+ L below_1927
+ L below_1928
+ L below_1929
+ L below_1930
+ L below_1931
+ L below_1932
+ L below_1933
+ L below_1934
+ L below_1935
+ L below_1936
+ L below_1937
+ L below_1938
+ L below_1939
+ L below_1940
+ L below_1941
+ L below_1942
+ L below_1943
+ L below_1944
+ L below_1945
+ L below_1946
+ L below_1947
+ L below_1948
+ L below_1949
+ L below_1950
+ L below_1951
+ L below_1952
+ L below_1953
+ L below_1954
+ L below_1955
+ L below_1956
+ L below_1957
+ L below_1958
+ L below_1959
+ L below_1960
+ L below_1961
+ L below_1962
+ L below_1963
+ L below_1964
+ L below_1965
+ L below_1966
+ L below_1967
+ L below_1968
+ L below_1969
+ L below_1970
+ L below_1971
+ L below_1972
+ L below_1973
+ L below_1974
+ L below_1975
+ L below_1976
+ L below_1977
+ L below_1978
+ L below_1979
+ L below_1980
+ L below_1981
+ L below_1982
+ L below_1983
+ L below_1984
+ L below_1985
+ L below_1986
+ L below_1987
+ L below_1988
+ L below_1989
+ L below_1990
+ L below_1991
+ L below_1992
+ L below_1993
+ L below_1994
+ L below_1995
+ L below_1996
+ L below_1997
+ L below_1998
+ L below_1999
+ L below_2000
+COLUMNS
+    w0 above_1800 51422
+    w0 above_1801 49243
+    w0 above_1802 51632
+    w0 above_1803 56497
+    w0 above_1804 62163
+    w0 above_1805 54862
+    w0 above_1806 60946
+    w0 above_1807 57189
+    w0 above_1808 62041
+    w0 above_1809 60099
+    w0 above_1810 67048
+    w0 above_1811 66630
+    w0 above_1812 68430
+    w0 above_1813 66835
+    w0 above_1814 68792
+    w0 above_1815 73721
+    w0 above_1816 72991
+    w0 above_1817 74461
+    w0 above_1818 70588
+    w0 above_1819 72598
+    w0 above_1820 70313
+    w0 above_1821 80058
+    w0 above_1822 79982
+    w0 above_1823 83562
+    w0 above_1824 78857
+    w0 above_1825 84733
+    w0 above_1826 84533
+    w0 above_1827 86684
+    w0 above_1828 92440
+    w0 above_1829 90404
+    w0 above_1830 99023
+    w0 above_1831 95010
+    w0 above_1832 89504
+    w0 above_1833 96055
+    w0 above_1834 101847
+    w0 above_1835 99440
+    w0 above_1836 97118
+    w0 above_1837 98861
+    w0 above_1838 107170
+    w0 above_1839 117222
+    w0 above_1840 100027
+    w0 above_1841 104022
+    w0 above_1842 110583
+    w0 above_1843 120174
+    w0 above_1844 123051
+    w0 above_1845 114845
+    w0 above_1846 115882
+    w0 above_1847 118138
+    w0 above_1848 126206
+    w0 above_1849 132716
+    w0 above_1850 126038
+    w0 above_1851 125001
+    w0 above_1852 119535
+    w0 above_1853 136287
+    w0 above_1854 123481
+    w0 above_1855 129220
+    w0 above_1856 132467
+    w0 above_1857 129788
+    w0 above_1858 137362
+    w0 above_1859 142732
+    w0 above_1860 138160
+    w0 above_1861 131516
+    w0 above_1862 150514
+    w0 above_1863 143334
+    w0 above_1864 143985
+    w0 above_1865 141525
+    w0 above_1866 167136
+    w0 above_1867 147658
+    w0 above_1868 156346
+    w0 above_1869 160966
+    w0 above_1870 146669
+    w0 above_1871 159061
+    w0 above_1872 160795
+    w0 above_1873 157895
+    w0 above_1874 167156
+    w0 above_1875 177177
+    w0 above_1876 168586
+    w0 above_1877 161651
+    w0 above_1878 153783
+    w0 above_1879 164740
+    w0 above_1880 170504
+    w0 above_1881 193250
+    w0 above_1882 168093
+    w0 above_1883 175926
+    w0 above_1884 170076
+    w0 above_1885 174629
+    w0 above_1886 173507
+    w0 above_1887 190112
+    w0 above_1888 200848
+    w0 above_1889 185130
+    w0 above_1890 187402
+    w0 above_1891 199916
+    w0 above_1892 197980
+    w0 above_1893 186324
+    w0 above_1894 189516
+    w0 above_1895 208523
+    w0 above_1896 207543
+    w0 above_1897 208976
+    w0 above_1898 199778
+    w0 above_1899 199030
+    w0 above_1900 220860
+    w0 above_1901 208072
+    w0 above_1902 232937
+    w0 above_1903 212698
+    w0 above_1904 219674
+    w0 above_1905 197633
+    w0 above_1906 220700
+    w0 above_1907 202257
+    w0 above_1908 210581
+    w0 above_1909 212066
+    w0 above_1910 219758
+    w0 above_1911 203996
+    w0 above_1912 214462
+    w0 above_1913 235181
+    w0 above_1914 234427
+    w0 above_1915 216230
+    w0 above_1916 209333
+    w0 above_1917 242237
+    w0 above_1918 250679
+    w0 above_1919 232106
+    w0 above_1920 221059
+    w0 above_1921 240767
+    w0 above_1922 224484
+    w0 above_1923 227616
+    w0 above_1924 243338
+    w0 above_1925 243747
+    w0 above_1926 247575
+    w0 above_1927 246773
+    w0 above_1928 224087
+    w0 above_1929 253617
+    w0 above_1930 249345
+    w0 above_1931 254756
+    w0 above_1932 246453
+    w0 above_1933 285284
+    w0 above_1934 262248
+    w0 above_1935 246708
+    w0 above_1936 257176
+    w0 above_1937 269376
+    w0 above_1938 251680
+    w0 above_1939 254913
+    w0 above_1940 253498
+    w0 above_1941 280108
+    w0 above_1942 264013
+    w0 above_1943 260556
+    w0 above_1944 237400
+    w0 above_1945 268362
+    w0 above_1946 256755
+    w0 above_1947 266028
+    w0 above_1948 277912
+    w0 above_1949 292117
+    w0 above_1950 268199
+    w0 above_1951 261424
+    w0 above_1952 259563
+    w0 above_1953 256153
+    w0 above_1954 283437
+    w0 above_1955 285567
+    w0 above_1956 289467
+    w0 above_1957 298888
+    w0 above_1958 301542
+    w0 above_1959 306129
+    w0 above_1960 290193
+    w0 above_1961 258337
+    w0 above_1962 289324
+    w0 above_1963 243394
+    w0 above_1964 291174
+    w0 above_1965 297056
+    w0 above_1966 281350
+    w0 above_1967 297824
+    w0 above_1968 292376
+    w0 above_1969 284537
+    w0 above_1970 279026
+    w0 above_1971 306382
+    w0 above_1972 290453
+    w0 above_1973 291454
+    w0 above_1974 315356
+    w0 above_1975 319830
+    w0 above_1976 308533
+    w0 above_1977 303503
+    w0 above_1978 293547
+    w0 above_1979 296333
+    w0 above_1980 305086
+    w0 above_1981 279551
+    w0 above_1982 296835
+    w0 above_1983 319476
+    w0 above_1984 286300
+    w0 above_1985 324819
+    w0 above_1986 331124
+    w0 above_1987 318146
+    w0 above_1988 277741
+    w0 above_1989 302499
+    w0 above_1990 294102
+    w0 above_1991 298467
+    w0 above_1992 320441
+    w0 above_1993 336698
+    w0 above_1994 317784
+    w0 above_1995 320671
+    w0 above_1996 348847
+    w0 above_1997 292322
+    w0 above_1998 308121
+    w0 above_1999 325227
+    w0 above_2000 298136
+    w0 below_1800 -51422
+    w0 below_1801 -49243
+    w0 below_1802 -51632
+    w0 below_1803 -56497
+    w0 below_1804 -62163
+    w0 below_1805 -54862
+    w0 below_1806 -60946
+    w0 below_1807 -57189
+    w0 below_1808 -62041
+    w0 below_1809 -60099
+    w0 below_1810 -67048
+    w0 below_1811 -66630
+    w0 below_1812 -68430
+    w0 below_1813 -66835
+    w0 below_1814 -68792
+    w0 below_1815 -73721
+    w0 below_1816 -72991
+    w0 below_1817 -74461
+    w0 below_1818 -70588
+    w0 below_1819 -72598
+    w0 below_1820 -70313
+    w0 below_1821 -80058
+    w0 below_1822 -79982
+    w0 below_1823 -83562
+    w0 below_1824 -78857
+    w0 below_1825 -84733
+    w0 below_1826 -84533
+    w0 below_1827 -86684
+    w0 below_1828 -92440
+    w0 below_1829 -90404
+    w0 below_1830 -99023
+    w0 below_1831 -95010
+    w0 below_1832 -89504
+    w0 below_1833 -96055
+    w0 below_1834 -101847
+    w0 below_1835 -99440
+    w0 below_1836 -97118
+    w0 below_1837 -98861
+    w0 below_1838 -107170
+    w0 below_1839 -117222
+    w0 below_1840 -100027
+    w0 below_1841 -104022
+    w0 below_1842 -110583
+    w0 below_1843 -120174
+    w0 below_1844 -123051
+    w0 below_1845 -114845
+    w0 below_1846 -115882
+    w0 below_1847 -118138
+    w0 below_1848 -126206
+    w0 below_1849 -132716
+    w0 below_1850 -126038
+    w0 below_1851 -125001
+    w0 below_1852 -119535
+    w0 below_1853 -136287
+    w0 below_1854 -123481
+    w0 below_1855 -129220
+    w0 below_1856 -132467
+    w0 below_1857 -129788
+    w0 below_1858 -137362
+    w0 below_1859 -142732
+    w0 below_1860 -138160
+    w0 below_1861 -131516
+    w0 below_1862 -150514
+    w0 below_1863 -143334
+    w0 below_1864 -143985
+    w0 below_1865 -141525
+    w0 below_1866 -167136
+    w0 below_1867 -147658
+    w0 below_1868 -156346
+    w0 below_1869 -160966
+    w0 below_1870 -146669
+    w0 below_1871 -159061
+    w0 below_1872 -160795
+    w0 below_1873 -157895
+    w0 below_1874 -167156
+    w0 below_1875 -177177
+    w0 below_1876 -168586
+    w0 below_1877 -161651
+    w0 below_1878 -153783
+    w0 below_1879 -164740
+    w0 below_1880 -170504
+    w0 below_1881 -193250
+    w0 below_1882 -168093
+    w0 below_1883 -175926
+    w0 below_1884 -170076
+    w0 below_1885 -174629
+    w0 below_1886 -173507
+    w0 below_1887 -190112
+    w0 below_1888 -200848
+    w0 below_1889 -185130
+    w0 below_1890 -187402
+    w0 below_1891 -199916
+    w0 below_1892 -197980
+    w0 below_1893 -186324
+    w0 below_1894 -189516
+    w0 below_1895 -208523
+    w0 below_1896 -207543
+    w0 below_1897 -208976
+    w0 below_1898 -199778
+    w0 below_1899 -199030
+    w0 below_1900 -220860
+    w0 below_1901 -208072
+    w0 below_1902 -232937
+    w0 below_1903 -212698
+    w0 below_1904 -219674
+    w0 below_1905 -197633
+    w0 below_1906 -220700
+    w0 below_1907 -202257
+    w0 below_1908 -210581
+    w0 below_1909 -212066
+    w0 below_1910 -219758
+    w0 below_1911 -203996
+    w0 below_1912 -214462
+    w0 below_1913 -235181
+    w0 below_1914 -234427
+    w0 below_1915 -216230
+    w0 below_1916 -209333
+    w0 below_1917 -242237
+    w0 below_1918 -250679
+    w0 below_1919 -232106
+    w0 below_1920 -221059
+    w0 below_1921 -240767
+    w0 below_1922 -224484
+    w0 below_1923 -227616
+    w0 below_1924 -243338
+    w0 below_1925 -243747
+    w0 below_1926 -247575
+    w0 below_1927 -246773
+    w0 below_1928 -224087
+    w0 below_1929 -253617
+    w0 below_1930 -249345
+    w0 below_1931 -254756
+    w0 below_1932 -246453
+    w0 below_1933 -285284
+    w0 below_1934 -262248
+    w0 below_1935 -246708
+    w0 below_1936 -257176
+    w0 below_1937 -269376
+    w0 below_1938 -251680
+    w0 below_1939 -254913
+    w0 below_1940 -253498
+    w0 below_1941 -280108
+    w0 below_1942 -264013
+    w0 below_1943 -260556
+    w0 below_1944 -237400
+    w0 below_1945 -268362
+    w0 below_1946 -256755
+    w0 below_1947 -266028
+    w0 below_1948 -277912
+    w0 below_1949 -292117
+    w0 below_1950 -268199
+    w0 below_1951 -261424
+    w0 below_1952 -259563
+    w0 below_1953 -256153
+    w0 below_1954 -283437
+    w0 below_1955 -285567
+    w0 below_1956 -289467
+    w0 below_1957 -298888
+    w0 below_1958 -301542
+    w0 below_1959 -306129
+    w0 below_1960 -290193
+    w0 below_1961 -258337
+    w0 below_1962 -289324
+    w0 below_1963 -243394
+    w0 below_1964 -291174
+    w0 below_1965 -297056
+    w0 below_1966 -281350
+    w0 below_1967 -297824
+    w0 below_1968 -292376
+    w0 below_1969 -284537
+    w0 below_1970 -279026
+    w0 below_1971 -306382
+    w0 below_1972 -290453
+    w0 below_1973 -291454
+    w0 below_1974 -315356
+    w0 below_1975 -319830
+    w0 below_1976 -308533
+    w0 below_1977 -303503
+    w0 below_1978 -293547
+    w0 below_1979 -296333
+    w0 below_1980 -305086
+    w0 below_1981 -279551
+    w0 below_1982 -296835
+    w0 below_1983 -319476
+    w0 below_1984 -286300
+    w0 below_1985 -324819
+    w0 below_1986 -331124
+    w0 below_1987 -318146
+    w0 below_1988 -277741
+    w0 below_1989 -302499
+    w0 below_1990 -294102
+    w0 below_1991 -298467
+    w0 below_1992 -320441
+    w0 below_1993 -336698
+    w0 below_1994 -317784
+    w0 below_1995 -320671
+    w0 below_1996 -348847
+    w0 below_1997 -292322
+    w0 below_1998 -308121
+    w0 below_1999 -325227
+    w0 below_2000 -298136
+    w5 above_1800 7898
+    w5 above_1801 8629
+    w5 above_1802 9067
+    w5 above_1803 9448
+    w5 above_1804 10028
+    w5 above_1805 9775
+    w5 above_1806 9521
+    w5 above_1807 10661
+    w5 above_1808 10472
+    w5 above_1809 10898
+    w5 above_1810 10542
+    w5 above_1811 10604
+    w5 above_1812 11253
+    w5 above_1813 11842
+    w5 above_1814 12646
+    w5 above_1815 11797
+    w5 above_1816 13170
+    w5 above_1817 13665
+    w5 above_1818 12223
+    w5 above_1819 12032
+    w5 above_1820 13587
+    w5 above_1821 14939
+    w5 above_1822 13760
+    w5 above_1823 15864
+    w5 above_1824 15262
+    w5 above_1825 14389
+    w5 above_1826 14660
+    w5 above_1827 15797
+    w5 above_1828 14648
+    w5 above_1829 16163
+    w5 above_1830 16381
+    w5 above_1831 14725
+    w5 above_1832 15833
+    w5 above_1833 15621
+    w5 above_1834 16088
+    w5 above_1835 16894
+    w5 above_1836 17922
+    w5 above_1837 17848
+    w5 above_1838 17800
+    w5 above_1839 19757
+    w5 above_1840 17069
+    w5 above_1841 20385
+    w5 above_1842 19418
+    w5 above_1843 18196
+    w5 above_1844 20308
+    w5 above_1845 20297
+    w5 above_1846 20878
+    w5 above_1847 19430
+    w5 above_1848 22484
+    w5 above_1849 22706
+    w5 above_1850 21871
+    w5 above_1851 18569
+    w5 above_1852 21916
+    w5 above_1853 21184
+    w5 above_1854 22776
+    w5 above_1855 23431
+    w5 above_1856 22574
+    w5 above_1857 24968
+    w5 above_1858 23706
+    w5 above_1859 25552
+    w5 above_1860 23998
+    w5 above_1861 24119
+    w5 above_1862 23167
+    w5 above_1863 26157
+    w5 above_1864 27128
+    w5 above_1865 23443
+    w5 above_1866 25757
+    w5 above_1867 27590
+    w5 above_1868 27597
+    w5 above_1869 27558
+    w5 above_1870 25825
+    w5 above_1871 28003
+    w5 above_1872 29642
+    w5 above_1873 29670
+    w5 above_1874 27074
+    w5 above_1875 30937
+    w5 above_1876 30027
+    w5 above_1877 30221
+    w5 above_1878 28321
+    w5 above_1879 29481
+    w5 above_1880 30756
+    w5 above_1881 31075
+    w5 above_1882 32020
+    w5 above_1883 30204
+    w5 above_1884 31219
+    w5 above_1885 29287
+    w5 above_1886 30716
+    w5 above_1887 31101
+    w5 above_1888 31965
+    w5 above_1889 34218
+    w5 above_1890 36425
+    w5 above_1891 34387
+    w5 above_1892 30961
+    w5 above_1893 35146
+    w5 above_1894 34037
+    w5 above_1895 32975
+    w5 above_1896 33420
+    w5 above_1897 35674
+    w5 above_1898 36627
+    w5 above_1899 33774
+    w5 above_1900 36461
+    w5 above_1901 34730
+    w5 above_1902 38078
+    w5 above_1903 39505
+    w5 above_1904 38882
+    w5 above_1905 33827
+    w5 above_1906 37485
+    w5 above_1907 37799
+    w5 above_1908 39916
+    w5 above_1909 38221
+    w5 above_1910 39877
+    w5 above_1911 38141
+    w5 above_1912 37797
+    w5 above_1913 40011
+    w5 above_1914 41407
+    w5 above_1915 37106
+    w5 above_1916 40235
+    w5 above_1917 41940
+    w5 above_1918 42436
+    w5 above_1919 44349
+    w5 above_1920 43076
+    w5 above_1921 44352
+    w5 above_1922 42078
+    w5 above_1923 43319
+    w5 above_1924 45094
+    w5 above_1925 47150
+    w5 above_1926 45054
+    w5 above_1927 45940
+    w5 above_1928 43223
+    w5 above_1929 44790
+    w5 above_1930 44615
+    w5 above_1931 47744
+    w5 above_1932 42181
+    w5 above_1933 44754
+    w5 above_1934 43875
+    w5 above_1935 45829
+    w5 above_1936 48173
+    w5 above_1937 41497
+    w5 above_1938 39752
+    w5 above_1939 45388
+    w5 above_1940 45247
+    w5 above_1941 48892
+    w5 above_1942 47388
+    w5 above_1943 50575
+    w5 above_1944 48671
+    w5 above_1945 49827
+    w5 above_1946 48752
+    w5 above_1947 48789
+    w5 above_1948 46674
+    w5 above_1949 50021
+    w5 above_1950 45839
+    w5 above_1951 49176
+    w5 above_1952 49878
+    w5 above_1953 44103
+    w5 above_1954 48753
+    w5 above_1955 47626
+    w5 above_1956 46911
+    w5 above_1957 48653
+    w5 above_1958 45471
+    w5 above_1959 46503
+    w5 above_1960 45025
+    w5 above_1961 47147
+    w5 above_1962 50115
+    w5 above_1963 54632
+    w5 above_1964 46904
+    w5 above_1965 50416
+    w5 above_1966 50645
+    w5 above_1967 53113
+    w5 above_1968 53099
+    w5 above_1969 48470
+    w5 above_1970 56627
+    w5 above_1971 55369
+    w5 above_1972 49381
+    w5 above_1973 52914
+    w5 above_1974 52055
+    w5 above_1975 51548
+    w5 above_1976 52220
+    w5 above_1977 51639
+    w5 above_1978 55322
+    w5 above_1979 54740
+    w5 above_1980 55308
+    w5 above_1981 54425
+    w5 above_1982 56569
+    w5 above_1983 49823
+    w5 above_1984 52409
+    w5 above_1985 53403
+    w5 above_1986 48569
+    w5 above_1987 55010
+    w5 above_1988 54510
+    w5 above_1989 52030
+    w5 above_1990 54518
+    w5 above_1991 55548
+    w5 above_1992 58214
+    w5 above_1993 54514
+    w5 above_1994 59734
+    w5 above_1995 56138
+    w5 above_1996 56156
+    w5 above_1997 57661
+    w5 above_1998 58083
+    w5 above_1999 58711
+    w5 above_2000 55370
+    w5 below_1800 -7898
+    w5 below_1801 -8629
+    w5 below_1802 -9067
+    w5 below_1803 -9448
+    w5 below_1804 -10028
+    w5 below_1805 -9775
+    w5 below_1806 -9521
+    w5 below_1807 -10661
+    w5 below_1808 -10472
+    w5 below_1809 -10898
+    w5 below_1810 -10542
+    w5 below_1811 -10604
+    w5 below_1812 -11253
+    w5 below_1813 -11842
+    w5 below_1814 -12646
+    w5 below_1815 -11797
+    w5 below_1816 -13170
+    w5 below_1817 -13665
+    w5 below_1818 -12223
+    w5 below_1819 -12032
+    w5 below_1820 -13587
+    w5 below_1821 -14939
+    w5 below_1822 -13760
+    w5 below_1823 -15864
+    w5 below_1824 -15262
+    w5 below_1825 -14389
+    w5 below_1826 -14660
+    w5 below_1827 -15797
+    w5 below_1828 -14648
+    w5 below_1829 -16163
+    w5 below_1830 -16381
+    w5 below_1831 -14725
+    w5 below_1832 -15833
+    w5 below_1833 -15621
+    w5 below_1834 -16088
+    w5 below_1835 -16894
+    w5 below_1836 -17922
+    w5 below_1837 -17848
+    w5 below_1838 -17800
+    w5 below_1839 -19757
+    w5 below_1840 -17069
+    w5 below_1841 -20385
+    w5 below_1842 -19418
+    w5 below_1843 -18196
+    w5 below_1844 -20308
+    w5 below_1845 -20297
+    w5 below_1846 -20878
+    w5 below_1847 -19430
+    w5 below_1848 -22484
+    w5 below_1849 -22706
+    w5 below_1850 -21871
+    w5 below_1851 -18569
+    w5 below_1852 -21916
+    w5 below_1853 -21184
+    w5 below_1854 -22776
+    w5 below_1855 -23431
+    w5 below_1856 -22574
+    w5 below_1857 -24968
+    w5 below_1858 -23706
+    w5 below_1859 -25552
+    w5 below_1860 -23998
+    w5 below_1861 -24119
+    w5 below_1862 -23167
+    w5 below_1863 -26157
+    w5 below_1864 -27128
+    w5 below_1865 -23443
+    w5 below_1866 -25757
+    w5 below_1867 -27590
+    w5 below_1868 -27597
+    w5 below_1869 -27558
+    w5 below_1870 -25825
+    w5 below_1871 -28003
+    w5 below_1872 -29642
+    w5 below_1873 -29670
+    w5 below_1874 -27074
+    w5 below_1875 -30937
+    w5 below_1876 -30027
+    w5 below_1877 -30221
+    w5 below_1878 -28321
+    w5 below_1879 -29481
+    w5 below_1880 -30756
+    w5 below_1881 -31075
+    w5 below_1882 -32020
+    w5 below_1883 -30204
+    w5 below_1884 -31219
+    w5 below_1885 -29287
+    w5 below_1886 -30716
+    w5 below_1887 -31101
+    w5 below_1888 -31965
+    w5 below_1889 -34218
+    w5 below_1890 -36425
+    w5 below_1891 -34387
+    w5 below_1892 -30961
+    w5 below_1893 -35146
+    w5 below_1894 -34037
+    w5 below_1895 -32975
+    w5 below_1896 -33420
+    w5 below_1897 -35674
+    w5 below_1898 -36627
+    w5 below_1899 -33774
+    w5 below_1900 -36461
+    w5 below_1901 -34730
+    w5 below_1902 -38078
+    w5 below_1903 -39505
+    w5 below_1904 -38882
+    w5 below_1905 -33827
+    w5 below_1906 -37485
+    w5 below_1907 -37799
+    w5 below_1908 -39916
+    w5 below_1909 -38221
+    w5 below_1910 -39877
+    w5 below_1911 -38141
+    w5 below_1912 -37797
+    w5 below_1913 -40011
+    w5 below_1914 -41407
+    w5 below_1915 -37106
+    w5 below_1916 -40235
+    w5 below_1917 -41940
+    w5 below_1918 -42436
+    w5 below_1919 -44349
+    w5 below_1920 -43076
+    w5 below_1921 -44352
+    w5 below_1922 -42078
+    w5 below_1923 -43319
+    w5 below_1924 -45094
+    w5 below_1925 -47150
+    w5 below_1926 -45054
+    w5 below_1927 -45940
+    w5 below_1928 -43223
+    w5 below_1929 -44790
+    w5 below_1930 -44615
+    w5 below_1931 -47744
+    w5 below_1932 -42181
+    w5 below_1933 -44754
+    w5 below_1934 -43875
+    w5 below_1935 -45829
+    w5 below_1936 -48173
+    w5 below_1937 -41497
+    w5 below_1938 -39752
+    w5 below_1939 -45388
+    w5 below_1940 -45247
+    w5 below_1941 -48892
+    w5 below_1942 -47388
+    w5 below_1943 -50575
+    w5 below_1944 -48671
+    w5 below_1945 -49827
+    w5 below_1946 -48752
+    w5 below_1947 -48789
+    w5 below_1948 -46674
+    w5 below_1949 -50021
+    w5 below_1950 -45839
+    w5 below_1951 -49176
+    w5 below_1952 -49878
+    w5 below_1953 -44103
+    w5 below_1954 -48753
+    w5 below_1955 -47626
+    w5 below_1956 -46911
+    w5 below_1957 -48653
+    w5 below_1958 -45471
+    w5 below_1959 -46503
+    w5 below_1960 -45025
+    w5 below_1961 -47147
+    w5 below_1962 -50115
+    w5 below_1963 -54632
+    w5 below_1964 -46904
+    w5 below_1965 -50416
+    w5 below_1966 -50645
+    w5 below_1967 -53113
+    w5 below_1968 -53099
+    w5 below_1969 -48470
+    w5 below_1970 -56627
+    w5 below_1971 -55369
+    w5 below_1972 -49381
+    w5 below_1973 -52914
+    w5 below_1974 -52055
+    w5 below_1975 -51548
+    w5 below_1976 -52220
+    w5 below_1977 -51639
+    w5 below_1978 -55322
+    w5 below_1979 -54740
+    w5 below_1980 -55308
+    w5 below_1981 -54425
+    w5 below_1982 -56569
+    w5 below_1983 -49823
+    w5 below_1984 -52409
+    w5 below_1985 -53403
+    w5 below_1986 -48569
+    w5 below_1987 -55010
+    w5 below_1988 -54510
+    w5 below_1989 -52030
+    w5 below_1990 -54518
+    w5 below_1991 -55548
+    w5 below_1992 -58214
+    w5 below_1993 -54514
+    w5 below_1994 -59734
+    w5 below_1995 -56138
+    w5 below_1996 -56156
+    w5 below_1997 -57661
+    w5 below_1998 -58083
+    w5 below_1999 -58711
+    w5 below_2000 -55370
+    w0_w5 above_1800 757
+    w0_w5 above_1801 795
+    w0_w5 above_1802 861
+    w0_w5 above_1803 769
+    w0_w5 above_1804 824
+    w0_w5 above_1805 831
+    w0_w5 above_1806 885
+    w0_w5 above_1807 873
+    w0_w5 above_1808 926
+    w0_w5 above_1809 849
+    w0_w5 above_1810 906
+    w0_w5 above_1811 929
+    w0_w5 above_1812 887
+    w0_w5 above_1813 940
+    w0_w5 above_1814 933
+    w0_w5 above_1815 1087
+    w0_w5 above_1816 1017
+    w0_w5 above_1817 956
+    w0_w5 above_1818 1025
+    w0_w5 above_1819 1131
+    w0_w5 above_1820 1013
+    w0_w5 above_1821 1114
+    w0_w5 above_1822 1052
+    w0_w5 above_1823 1072
+    w0_w5 above_1824 1234
+    w0_w5 above_1825 1106
+    w0_w5 above_1826 1177
+    w0_w5 above_1827 1155
+    w0_w5 above_1828 1239
+    w0_w5 above_1829 1266
+    w0_w5 above_1830 1299
+    w0_w5 above_1831 1088
+    w0_w5 above_1832 1313
+    w0_w5 above_1833 1253
+    w0_w5 above_1834 1288
+    w0_w5 above_1835 1221
+    w0_w5 above_1836 1285
+    w0_w5 above_1837 1438
+    w0_w5 above_1838 1405
+    w0_w5 above_1839 1335
+    w0_w5 above_1840 1351
+    w0_w5 above_1841 1286
+    w0_w5 above_1842 1397
+    w0_w5 above_1843 1423
+    w0_w5 above_1844 1285
+    w0_w5 above_1845 1499
+    w0_w5 above_1846 1566
+    w0_w5 above_1847 1490
+    w0_w5 above_1848 1490
+    w0_w5 above_1849 1647
+    w0_w5 above_1850 1514
+    w0_w5 above_1851 1632
+    w0_w5 above_1852 1634
+    w0_w5 above_1853 1608
+    w0_w5 above_1854 1505
+    w0_w5 above_1855 1766
+    w0_w5 above_1856 1645
+    w0_w5 above_1857 1882
+    w0_w5 above_1858 1845
+    w0_w5 above_1859 1660
+    w0_w5 above_1860 1857
+    w0_w5 above_1861 1722
+    w0_w5 above_1862 1854
+    w0_w5 above_1863 1963
+    w0_w5 above_1864 1763
+    w0_w5 above_1865 1960
+    w0_w5 above_1866 1843
+    w0_w5 above_1867 1884
+    w0_w5 above_1868 1945
+    w0_w5 above_1869 1911
+    w0_w5 above_1870 1781
+    w0_w5 above_1871 2031
+    w0_w5 above_1872 1986
+    w0_w5 above_1873 1891
+    w0_w5 above_1874 2131
+    w0_w5 above_1875 1874
+    w0_w5 above_1876 2132
+    w0_w5 above_1877 2132
+    w0_w5 above_1878 2107
+    w0_w5 above_1879 2126
+    w0_w5 above_1880 2128
+    w0_w5 above_1881 1974
+    w0_w5 above_1882 2110
+    w0_w5 above_1883 2140
+    w0_w5 above_1884 2175
+    w0_w5 above_1885 2209
+    w0_w5 above_1886 2347
+    w0_w5 above_1887 2150
+    w0_w5 above_1888 2074
+    w0_w5 above_1889 2124
+    w0_w5 above_1890 2308
+    w0_w5 above_1891 2142
+    w0_w5 above_1892 2321
+    w0_w5 above_1893 2456
+    w0_w5 above_1894 2388
+    w0_w5 above_1895 2273
+    w0_w5 above_1896 2481
+    w0_w5 above_1897 2382
+    w0_w5 above_1898 2516
+    w0_w5 above_1899 2492
+    w0_w5 above_1900 2471
+    w0_w5 above_1901 2719
+    w0_w5 above_1902 2563
+    w0_w5 above_1903 2867
+    w0_w5 above_1904 2517
+    w0_w5 above_1905 2880
+    w0_w5 above_1906 3139
+    w0_w5 above_1907 3125
+    w0_w5 above_1908 3097
+    w0_w5 above_1909 2947
+    w0_w5 above_1910 3405
+    w0_w5 above_1911 3178
+    w0_w5 above_1912 3456
+    w0_w5 above_1913 3266
+    w0_w5 above_1914 3470
+    w0_w5 above_1915 3233
+    w0_w5 above_1916 3181
+    w0_w5 above_1917 3026
+    w0_w5 above_1918 3534
+    w0_w5 above_1919 3536
+    w0_w5 above_1920 3756
+    w0_w5 above_1921 3737
+    w0_w5 above_1922 3312
+    w0_w5 above_1923 3424
+    w0_w5 above_1924 3430
+    w0_w5 above_1925 3426
+    w0_w5 above_1926 3795
+    w0_w5 above_1927 3602
+    w0_w5 above_1928 3872
+    w0_w5 above_1929 3526
+    w0_w5 above_1930 3695
+    w0_w5 above_1931 3748
+    w0_w5 above_1932 3656
+    w0_w5 above_1933 3212
+    w0_w5 above_1934 3953
+    w0_w5 above_1935 3575
+    w0_w5 above_1936 3571
+    w0_w5 above_1937 4050
+    w0_w5 above_1938 3980
+    w0_w5 above_1939 3615
+    w0_w5 above_1940 3571
+    w0_w5 above_1941 3637
+    w0_w5 above_1942 3792
+    w0_w5 above_1943 3779
+    w0_w5 above_1944 3961
+    w0_w5 above_1945 3716
+    w0_w5 above_1946 3644
+    w0_w5 above_1947 3896
+    w0_w5 above_1948 3507
+    w0_w5 above_1949 3681
+    w0_w5 above_1950 3812
+    w0_w5 above_1951 3493
+    w0_w5 above_1952 3891
+    w0_w5 above_1953 3594
+    w0_w5 above_1954 3633
+    w0_w5 above_1955 3914
+    w0_w5 above_1956 3661
+    w0_w5 above_1957 4116
+    w0_w5 above_1958 3494
+    w0_w5 above_1959 3552
+    w0_w5 above_1960 3642
+    w0_w5 above_1961 3988
+    w0_w5 above_1962 3780
+    w0_w5 above_1963 3993
+    w0_w5 above_1964 3759
+    w0_w5 above_1965 4114
+    w0_w5 above_1966 3809
+    w0_w5 above_1967 3704
+    w0_w5 above_1968 3959
+    w0_w5 above_1969 3856
+    w0_w5 above_1970 3994
+    w0_w5 above_1971 3495
+    w0_w5 above_1972 3775
+    w0_w5 above_1973 3846
+    w0_w5 above_1974 3895
+    w0_w5 above_1975 4171
+    w0_w5 above_1976 3957
+    w0_w5 above_1977 3878
+    w0_w5 above_1978 3661
+    w0_w5 above_1979 3997
+    w0_w5 above_1980 3967
+    w0_w5 above_1981 3794
+    w0_w5 above_1982 4021
+    w0_w5 above_1983 3875
+    w0_w5 above_1984 3680
+    w0_w5 above_1985 3466
+    w0_w5 above_1986 4081
+    w0_w5 above_1987 4155
+    w0_w5 above_1988 4111
+    w0_w5 above_1989 3828
+    w0_w5 above_1990 4244
+    w0_w5 above_1991 3886
+    w0_w5 above_1992 3604
+    w0_w5 above_1993 3791
+    w0_w5 above_1994 3944
+    w0_w5 above_1995 4072
+    w0_w5 above_1996 3794
+    w0_w5 above_1997 3617
+    w0_w5 above_1998 3823
+    w0_w5 above_1999 3995
+    w0_w5 above_2000 3835
+    w0_w5 below_1800 -757
+    w0_w5 below_1801 -795
+    w0_w5 below_1802 -861
+    w0_w5 below_1803 -769
+    w0_w5 below_1804 -824
+    w0_w5 below_1805 -831
+    w0_w5 below_1806 -885
+    w0_w5 below_1807 -873
+    w0_w5 below_1808 -926
+    w0_w5 below_1809 -849
+    w0_w5 below_1810 -906
+    w0_w5 below_1811 -929
+    w0_w5 below_1812 -887
+    w0_w5 below_1813 -940
+    w0_w5 below_1814 -933
+    w0_w5 below_1815 -1087
+    w0_w5 below_1816 -1017
+    w0_w5 below_1817 -956
+    w0_w5 below_1818 -1025
+    w0_w5 below_1819 -1131
+    w0_w5 below_1820 -1013
+    w0_w5 below_1821 -1114
+    w0_w5 below_1822 -1052
+    w0_w5 below_1823 -1072
+    w0_w5 below_1824 -1234
+    w0_w5 below_1825 -1106
+    w0_w5 below_1826 -1177
+    w0_w5 below_1827 -1155
+    w0_w5 below_1828 -1239
+    w0_w5 below_1829 -1266
+    w0_w5 below_1830 -1299
+    w0_w5 below_1831 -1088
+    w0_w5 below_1832 -1313
+    w0_w5 below_1833 -1253
+    w0_w5 below_1834 -1288
+    w0_w5 below_1835 -1221
+    w0_w5 below_1836 -1285
+    w0_w5 below_1837 -1438
+    w0_w5 below_1838 -1405
+    w0_w5 below_1839 -1335
+    w0_w5 below_1840 -1351
+    w0_w5 below_1841 -1286
+    w0_w5 below_1842 -1397
+    w0_w5 below_1843 -1423
+    w0_w5 below_1844 -1285
+    w0_w5 below_1845 -1499
+    w0_w5 below_1846 -1566
+    w0_w5 below_1847 -1490
+    w0_w5 below_1848 -1490
+    w0_w5 below_1849 -1647
+    w0_w5 below_1850 -1514
+    w0_w5 below_1851 -1632
+    w0_w5 below_1852 -1634
+    w0_w5 below_1853 -1608
+    w0_w5 below_1854 -1505
+    w0_w5 below_1855 -1766
+    w0_w5 below_1856 -1645
+    w0_w5 below_1857 -1882
+    w0_w5 below_1858 -1845
+    w0_w5 below_1859 -1660
+    w0_w5 below_1860 -1857
+    w0_w5 below_1861 -1722
+    w0_w5 below_1862 -1854
+    w0_w5 below_1863 -1963
+    w0_w5 below_1864 -1763
+    w0_w5 below_1865 -1960
+    w0_w5 below_1866 -1843
+    w0_w5 below_1867 -1884
+    w0_w5 below_1868 -1945
+    w0_w5 below_1869 -1911
+    w0_w5 below_1870 -1781
+    w0_w5 below_1871 -2031
+    w0_w5 below_1872 -1986
+    w0_w5 below_1873 -1891
+    w0_w5 below_1874 -2131
+    w0_w5 below_1875 -1874
+    w0_w5 below_1876 -2132
+    w0_w5 below_1877 -2132
+    w0_w5 below_1878 -2107
+    w0_w5 below_1879 -2126
+    w0_w5 below_1880 -2128
+    w0_w5 below_1881 -1974
+    w0_w5 below_1882 -2110
+    w0_w5 below_1883 -2140
+    w0_w5 below_1884 -2175
+    w0_w5 below_1885 -2209
+    w0_w5 below_1886 -2347
+    w0_w5 below_1887 -2150
+    w0_w5 below_1888 -2074
+    w0_w5 below_1889 -2124
+    w0_w5 below_1890 -2308
+    w0_w5 below_1891 -2142
+    w0_w5 below_1892 -2321
+    w0_w5 below_1893 -2456
+    w0_w5 below_1894 -2388
+    w0_w5 below_1895 -2273
+    w0_w5 below_1896 -2481
+    w0_w5 below_1897 -2382
+    w0_w5 below_1898 -2516
+    w0_w5 below_1899 -2492
+    w0_w5 below_1900 -2471
+    w0_w5 below_1901 -2719
+    w0_w5 below_1902 -2563
+    w0_w5 below_1903 -2867
+    w0_w5 below_1904 -2517
+    w0_w5 below_1905 -2880
+    w0_w5 below_1906 -3139
+    w0_w5 below_1907 -3125
+    w0_w5 below_1908 -3097
+    w0_w5 below_1909 -2947
+    w0_w5 below_1910 -3405
+    w0_w5 below_1911 -3178
+    w0_w5 below_1912 -3456
+    w0_w5 below_1913 -3266
+    w0_w5 below_1914 -3470
+    w0_w5 below_1915 -3233
+    w0_w5 below_1916 -3181
+    w0_w5 below_1917 -3026
+    w0_w5 below_1918 -3534
+    w0_w5 below_1919 -3536
+    w0_w5 below_1920 -3756
+    w0_w5 below_1921 -3737
+    w0_w5 below_1922 -3312
+    w0_w5 below_1923 -3424
+    w0_w5 below_1924 -3430
+    w0_w5 below_1925 -3426
+    w0_w5 below_1926 -3795
+    w0_w5 below_1927 -3602
+    w0_w5 below_1928 -3872
+    w0_w5 below_1929 -3526
+    w0_w5 below_1930 -3695
+    w0_w5 below_1931 -3748
+    w0_w5 below_1932 -3656
+    w0_w5 below_1933 -3212
+    w0_w5 below_1934 -3953
+    w0_w5 below_1935 -3575
+    w0_w5 below_1936 -3571
+    w0_w5 below_1937 -4050
+    w0_w5 below_1938 -3980
+    w0_w5 below_1939 -3615
+    w0_w5 below_1940 -3571
+    w0_w5 below_1941 -3637
+    w0_w5 below_1942 -3792
+    w0_w5 below_1943 -3779
+    w0_w5 below_1944 -3961
+    w0_w5 below_1945 -3716
+    w0_w5 below_1946 -3644
+    w0_w5 below_1947 -3896
+    w0_w5 below_1948 -3507
+    w0_w5 below_1949 -3681
+    w0_w5 below_1950 -3812
+    w0_w5 below_1951 -3493
+    w0_w5 below_1952 -3891
+    w0_w5 below_1953 -3594
+    w0_w5 below_1954 -3633
+    w0_w5 below_1955 -3914
+    w0_w5 below_1956 -3661
+    w0_w5 below_1957 -4116
+    w0_w5 below_1958 -3494
+    w0_w5 below_1959 -3552
+    w0_w5 below_1960 -3642
+    w0_w5 below_1961 -3988
+    w0_w5 below_1962 -3780
+    w0_w5 below_1963 -3993
+    w0_w5 below_1964 -3759
+    w0_w5 below_1965 -4114
+    w0_w5 below_1966 -3809
+    w0_w5 below_1967 -3704
+    w0_w5 below_1968 -3959
+    w0_w5 below_1969 -3856
+    w0_w5 below_1970 -3994
+    w0_w5 below_1971 -3495
+    w0_w5 below_1972 -3775
+    w0_w5 below_1973 -3846
+    w0_w5 below_1974 -3895
+    w0_w5 below_1975 -4171
+    w0_w5 below_1976 -3957
+    w0_w5 below_1977 -3878
+    w0_w5 below_1978 -3661
+    w0_w5 below_1979 -3997
+    w0_w5 below_1980 -3967
+    w0_w5 below_1981 -3794
+    w0_w5 below_1982 -4021
+    w0_w5 below_1983 -3875
+    w0_w5 below_1984 -3680
+    w0_w5 below_1985 -3466
+    w0_w5 below_1986 -4081
+    w0_w5 below_1987 -4155
+    w0_w5 below_1988 -4111
+    w0_w5 below_1989 -3828
+    w0_w5 below_1990 -4244
+    w0_w5 below_1991 -3886
+    w0_w5 below_1992 -3604
+    w0_w5 below_1993 -3791
+    w0_w5 below_1994 -3944
+    w0_w5 below_1995 -4072
+    w0_w5 below_1996 -3794
+    w0_w5 below_1997 -3617
+    w0_w5 below_1998 -3823
+    w0_w5 below_1999 -3995
+    w0_w5 below_2000 -3835
+    w5_w5 above_1800 104
+    w5_w5 above_1801 98
+    w5_w5 above_1802 114
+    w5_w5 above_1803 112
+    w5_w5 above_1804 109
+    w5_w5 above_1805 121
+    w5_w5 above_1806 110
+    w5_w5 above_1807 106
+    w5_w5 above_1808 115
+    w5_w5 above_1809 119
+    w5_w5 above_1810 112
+    w5_w5 above_1811 125
+    w5_w5 above_1812 131
+    w5_w5 above_1813 122
+    w5_w5 above_1814 119
+    w5_w5 above_1815 146
+    w5_w5 above_1816 127
+    w5_w5 above_1817 142
+    w5_w5 above_1818 147
+    w5_w5 above_1819 132
+    w5_w5 above_1820 146
+    w5_w5 above_1821 132
+    w5_w5 above_1822 140
+    w5_w5 above_1823 149
+    w5_w5 above_1824 149
+    w5_w5 above_1825 150
+    w5_w5 above_1826 152
+    w5_w5 above_1827 156
+    w5_w5 above_1828 150
+    w5_w5 above_1829 161
+    w5_w5 above_1830 145
+    w5_w5 above_1831 145
+    w5_w5 above_1832 160
+    w5_w5 above_1833 154
+    w5_w5 above_1834 169
+    w5_w5 above_1835 147
+    w5_w5 above_1836 167
+    w5_w5 above_1837 146
+    w5_w5 above_1838 174
+    w5_w5 above_1839 176
+    w5_w5 above_1840 154
+    w5_w5 above_1841 175
+    w5_w5 above_1842 166
+    w5_w5 above_1843 170
+    w5_w5 above_1844 190
+    w5_w5 above_1845 178
+    w5_w5 above_1846 168
+    w5_w5 above_1847 180
+    w5_w5 above_1848 178
+    w5_w5 above_1849 192
+    w5_w5 above_1850 190
+    w5_w5 above_1851 186
+    w5_w5 above_1852 201
+    w5_w5 above_1853 185
+    w5_w5 above_1854 210
+    w5_w5 above_1855 179
+    w5_w5 above_1856 176
+    w5_w5 above_1857 199
+    w5_w5 above_1858 189
+    w5_w5 above_1859 201
+    w5_w5 above_1860 197
+    w5_w5 above_1861 189
+    w5_w5 above_1862 212
+    w5_w5 above_1863 199
+    w5_w5 above_1864 197
+    w5_w5 above_1865 194
+    w5_w5 above_1866 193
+    w5_w5 above_1867 207
+    w5_w5 above_1868 186
+    w5_w5 above_1869 231
+    w5_w5 above_1870 217
+    w5_w5 above_1871 218
+    w5_w5 above_1872 210
+    w5_w5 above_1873 231
+    w5_w5 above_1874 228
+    w5_w5 above_1875 228
+    w5_w5 above_1876 239
+    w5_w5 above_1877 241
+    w5_w5 above_1878 239
+    w5_w5 above_1879 223
+    w5_w5 above_1880 238
+    w5_w5 above_1881 228
+    w5_w5 above_1882 235
+    w5_w5 above_1883 267
+    w5_w5 above_1884 258
+    w5_w5 above_1885 249
+    w5_w5 above_1886 268
+    w5_w5 above_1887 248
+    w5_w5 above_1888 242
+    w5_w5 above_1889 257
+    w5_w5 above_1890 247
+    w5_w5 above_1891 248
+    w5_w5 above_1892 266
+    w5_w5 above_1893 265
+    w5_w5 above_1894 292
+    w5_w5 above_1895 264
+    w5_w5 above_1896 291
+    w5_w5 above_1897 276
+    w5_w5 above_1898 259
+    w5_w5 above_1899 274
+    w5_w5 above_1900 265
+    w5_w5 above_1901 278
+    w5_w5 above_1902 316
+    w5_w5 above_1903 279
+    w5_w5 above_1904 295
+    w5_w5 above_1905 306
+    w5_w5 above_1906 284
+    w5_w5 above_1907 302
+    w5_w5 above_1908 291
+    w5_w5 above_1909 290
+    w5_w5 above_1910 315
+    w5_w5 above_1911 324
+    w5_w5 above_1912 315
+    w5_w5 above_1913 307
+    w5_w5 above_1914 317
+    w5_w5 above_1915 310
+    w5_w5 above_1916 312
+    w5_w5 above_1917 321
+    w5_w5 above_1918 318
+    w5_w5 above_1919 345
+    w5_w5 above_1920 304
+    w5_w5 above_1921 315
+    w5_w5 above_1922 353
+    w5_w5 above_1923 349
+    w5_w5 above_1924 347
+    w5_w5 above_1925 356
+    w5_w5 above_1926 324
+    w5_w5 above_1927 343
+    w5_w5 above_1928 355
+    w5_w5 above_1929 339
+    w5_w5 above_1930 295
+    w5_w5 above_1931 329
+    w5_w5 above_1932 334
+    w5_w5 above_1933 300
+    w5_w5 above_1934 349
+    w5_w5 above_1935 387
+    w5_w5 above_1936 364
+    w5_w5 above_1937 346
+    w5_w5 above_1938 367
+    w5_w5 above_1939 364
+    w5_w5 above_1940 370
+    w5_w5 above_1941 395
+    w5_w5 above_1942 380
+    w5_w5 above_1943 378
+    w5_w5 above_1944 370
+    w5_w5 above_1945 372
+    w5_w5 above_1946 385
+    w5_w5 above_1947 398
+    w5_w5 above_1948 394
+    w5_w5 above_1949 430
+    w5_w5 above_1950 431
+    w5_w5 above_1951 439
+    w5_w5 above_1952 400
+    w5_w5 above_1953 394
+    w5_w5 above_1954 431
+    w5_w5 above_1955 424
+    w5_w5 above_1956 381
+    w5_w5 above_1957 439
+    w5_w5 above_1958 395
+    w5_w5 above_1959 418
+    w5_w5 above_1960 387
+    w5_w5 above_1961 432
+    w5_w5 above_1962 427
+    w5_w5 above_1963 427
+    w5_w5 above_1964 420
+    w5_w5 above_1965 406
+    w5_w5 above_1966 384
+    w5_w5 above_1967 426
+    w5_w5 above_1968 387
+    w5_w5 above_1969 478
+    w5_w5 above_1970 447
+    w5_w5 above_1971 424
+    w5_w5 above_1972 436
+    w5_w5 above_1973 443
+    w5_w5 above_1974 406
+    w5_w5 above_1975 441
+    w5_w5 above_1976 421
+    w5_w5 above_1977 414
+    w5_w5 above_1978 434
+    w5_w5 above_1979 439
+    w5_w5 above_1980 455
+    w5_w5 above_1981 473
+    w5_w5 above_1982 430
+    w5_w5 above_1983 489
+    w5_w5 above_1984 427
+    w5_w5 above_1985 453
+    w5_w5 above_1986 450
+    w5_w5 above_1987 401
+    w5_w5 above_1988 388
+    w5_w5 above_1989 466
+    w5_w5 above_1990 459
+    w5_w5 above_1991 445
+    w5_w5 above_1992 450
+    w5_w5 above_1993 486
+    w5_w5 above_1994 437
+    w5_w5 above_1995 414
+    w5_w5 above_1996 448
+    w5_w5 above_1997 458
+    w5_w5 above_1998 467
+    w5_w5 above_1999 415
+    w5_w5 above_2000 434
+    w5_w5 below_1800 -104
+    w5_w5 below_1801 -98
+    w5_w5 below_1802 -114
+    w5_w5 below_1803 -112
+    w5_w5 below_1804 -109
+    w5_w5 below_1805 -121
+    w5_w5 below_1806 -110
+    w5_w5 below_1807 -106
+    w5_w5 below_1808 -115
+    w5_w5 below_1809 -119
+    w5_w5 below_1810 -112
+    w5_w5 below_1811 -125
+    w5_w5 below_1812 -131
+    w5_w5 below_1813 -122
+    w5_w5 below_1814 -119
+    w5_w5 below_1815 -146
+    w5_w5 below_1816 -127
+    w5_w5 below_1817 -142
+    w5_w5 below_1818 -147
+    w5_w5 below_1819 -132
+    w5_w5 below_1820 -146
+    w5_w5 below_1821 -132
+    w5_w5 below_1822 -140
+    w5_w5 below_1823 -149
+    w5_w5 below_1824 -149
+    w5_w5 below_1825 -150
+    w5_w5 below_1826 -152
+    w5_w5 below_1827 -156
+    w5_w5 below_1828 -150
+    w5_w5 below_1829 -161
+    w5_w5 below_1830 -145
+    w5_w5 below_1831 -145
+    w5_w5 below_1832 -160
+    w5_w5 below_1833 -154
+    w5_w5 below_1834 -169
+    w5_w5 below_1835 -147
+    w5_w5 below_1836 -167
+    w5_w5 below_1837 -146
+    w5_w5 below_1838 -174
+    w5_w5 below_1839 -176
+    w5_w5 below_1840 -154
+    w5_w5 below_1841 -175
+    w5_w5 below_1842 -166
+    w5_w5 below_1843 -170
+    w5_w5 below_1844 -190
+    w5_w5 below_1845 -178
+    w5_w5 below_1846 -168
+    w5_w5 below_1847 -180
+    w5_w5 below_1848 -178
+    w5_w5 below_1849 -192
+    w5_w5 below_1850 -190
+    w5_w5 below_1851 -186
+    w5_w5 below_1852 -201
+    w5_w5 below_1853 -185
+    w5_w5 below_1854 -210
+    w5_w5 below_1855 -179
+    w5_w5 below_1856 -176
+    w5_w5 below_1857 -199
+    w5_w5 below_1858 -189
+    w5_w5 below_1859 -201
+    w5_w5 below_1860 -197
+    w5_w5 below_1861 -189
+    w5_w5 below_1862 -212
+    w5_w5 below_1863 -199
+    w5_w5 below_1864 -197
+    w5_w5 below_1865 -194
+    w5_w5 below_1866 -193
+    w5_w5 below_1867 -207
+    w5_w5 below_1868 -186
+    w5_w5 below_1869 -231
+    w5_w5 below_1870 -217
+    w5_w5 below_1871 -218
+    w5_w5 below_1872 -210
+    w5_w5 below_1873 -231
+    w5_w5 below_1874 -228
+    w5_w5 below_1875 -228
+    w5_w5 below_1876 -239
+    w5_w5 below_1877 -241
+    w5_w5 below_1878 -239
+    w5_w5 below_1879 -223
+    w5_w5 below_1880 -238
+    w5_w5 below_1881 -228
+    w5_w5 below_1882 -235
+    w5_w5 below_1883 -267
+    w5_w5 below_1884 -258
+    w5_w5 below_1885 -249
+    w5_w5 below_1886 -268
+    w5_w5 below_1887 -248
+    w5_w5 below_1888 -242
+    w5_w5 below_1889 -257
+    w5_w5 below_1890 -247
+    w5_w5 below_1891 -248
+    w5_w5 below_1892 -266
+    w5_w5 below_1893 -265
+    w5_w5 below_1894 -292
+    w5_w5 below_1895 -264
+    w5_w5 below_1896 -291
+    w5_w5 below_1897 -276
+    w5_w5 below_1898 -259
+    w5_w5 below_1899 -274
+    w5_w5 below_1900 -265
+    w5_w5 below_1901 -278
+    w5_w5 below_1902 -316
+    w5_w5 below_1903 -279
+    w5_w5 below_1904 -295
+    w5_w5 below_1905 -306
+    w5_w5 below_1906 -284
+    w5_w5 below_1907 -302
+    w5_w5 below_1908 -291
+    w5_w5 below_1909 -290
+    w5_w5 below_1910 -315
+    w5_w5 below_1911 -324
+    w5_w5 below_1912 -315
+    w5_w5 below_1913 -307
+    w5_w5 below_1914 -317
+    w5_w5 below_1915 -310
+    w5_w5 below_1916 -312
+    w5_w5 below_1917 -321
+    w5_w5 below_1918 -318
+    w5_w5 below_1919 -345
+    w5_w5 below_1920 -304
+    w5_w5 below_1921 -315
+    w5_w5 below_1922 -353
+    w5_w5 below_1923 -349
+    w5_w5 below_1924 -347
+    w5_w5 below_1925 -356
+    w5_w5 below_1926 -324
+    w5_w5 below_1927 -343
+    w5_w5 below_1928 -355
+    w5_w5 below_1929 -339
+    w5_w5 below_1930 -295
+    w5_w5 below_1931 -329
+    w5_w5 below_1932 -334
+    w5_w5 below_1933 -300
+    w5_w5 below_1934 -349
+    w5_w5 below_1935 -387
+    w5_w5 below_1936 -364
+    w5_w5 below_1937 -346
+    w5_w5 below_1938 -367
+    w5_w5 below_1939 -364
+    w5_w5 below_1940 -370
+    w5_w5 below_1941 -395
+    w5_w5 below_1942 -380
+    w5_w5 below_1943 -378
+    w5_w5 below_1944 -370
+    w5_w5 below_1945 -372
+    w5_w5 below_1946 -385
+    w5_w5 below_1947 -398
+    w5_w5 below_1948 -394
+    w5_w5 below_1949 -430
+    w5_w5 below_1950 -431
+    w5_w5 below_1951 -439
+    w5_w5 below_1952 -400
+    w5_w5 below_1953 -394
+    w5_w5 below_1954 -431
+    w5_w5 below_1955 -424
+    w5_w5 below_1956 -381
+    w5_w5 below_1957 -439
+    w5_w5 below_1958 -395
+    w5_w5 below_1959 -418
+    w5_w5 below_1960 -387
+    w5_w5 below_1961 -432
+    w5_w5 below_1962 -427
+    w5_w5 below_1963 -427
+    w5_w5 below_1964 -420
+    w5_w5 below_1965 -406
+    w5_w5 below_1966 -384
+    w5_w5 below_1967 -426
+    w5_w5 below_1968 -387
+    w5_w5 below_1969 -478
+    w5_w5 below_1970 -447
+    w5_w5 below_1971 -424
+    w5_w5 below_1972 -436
+    w5_w5 below_1973 -443
+    w5_w5 below_1974 -406
+    w5_w5 below_1975 -441
+    w5_w5 below_1976 -421
+    w5_w5 below_1977 -414
+    w5_w5 below_1978 -434
+    w5_w5 below_1979 -439
+    w5_w5 below_1980 -455
+    w5_w5 below_1981 -473
+    w5_w5 below_1982 -430
+    w5_w5 below_1983 -489
+    w5_w5 below_1984 -427
+    w5_w5 below_1985 -453
+    w5_w5 below_1986 -450
+    w5_w5 below_1987 -401
+    w5_w5 below_1988 -388
+    w5_w5 below_1989 -466
+    w5_w5 below_1990 -459
+    w5_w5 below_1991 -445
+    w5_w5 below_1992 -450
+    w5_w5 below_1993 -486
+    w5_w5 below_1994 -437
+    w5_w5 below_1995 -414
+    w5_w5 below_1996 -448
+    w5_w5 below_1997 -458
+    w5_w5 below_1998 -467
+    w5_w5 below_1999 -415
+    w5_w5 below_2000 -434
+    __t__ obj 1
+    __t__ above_1800 -1
+    __t__ above_1801 -1
+    __t__ above_1802 -1
+    __t__ above_1803 -1
+    __t__ above_1804 -1
+    __t__ above_1805 -1
+    __t__ above_1806 -1
+    __t__ above_1807 -1
+    __t__ above_1808 -1
+    __t__ above_1809 -1
+    __t__ above_1810 -1
+    __t__ above_1811 -1
+    __t__ above_1812 -1
+    __t__ above_1813 -1
+    __t__ above_1814 -1
+    __t__ above_1815 -1
+    __t__ above_1816 -1
+    __t__ above_1817 -1
+    __t__ above_1818 -1
+    __t__ above_1819 -1
+    __t__ above_1820 -1
+    __t__ above_1821 -1
+    __t__ above_1822 -1
+    __t__ above_1823 -1
+    __t__ above_1824 -1
+    __t__ above_1825 -1
+    __t__ above_1826 -1
+    __t__ above_1827 -1
+    __t__ above_1828 -1
+    __t__ above_1829 -1
+    __t__ above_1830 -1
+    __t__ above_1831 -1
+    __t__ above_1832 -1
+    __t__ above_1833 -1
+    __t__ above_1834 -1
+    __t__ above_1835 -1
+    __t__ above_1836 -1
+    __t__ above_1837 -1
+    __t__ above_1838 -1
+    __t__ above_1839 -1
+    __t__ above_1840 -1
+    __t__ above_1841 -1
+    __t__ above_1842 -1
+    __t__ above_1843 -1
+    __t__ above_1844 -1
+    __t__ above_1845 -1
+    __t__ above_1846 -1
+    __t__ above_1847 -1
+    __t__ above_1848 -1
+    __t__ above_1849 -1
+    __t__ above_1850 -1
+    __t__ above_1851 -1
+    __t__ above_1852 -1
+    __t__ above_1853 -1
+    __t__ above_1854 -1
+    __t__ above_1855 -1
+    __t__ above_1856 -1
+    __t__ above_1857 -1
+    __t__ above_1858 -1
+    __t__ above_1859 -1
+    __t__ above_1860 -1
+    __t__ above_1861 -1
+    __t__ above_1862 -1
+    __t__ above_1863 -1
+    __t__ above_1864 -1
+    __t__ above_1865 -1
+    __t__ above_1866 -1
+    __t__ above_1867 -1
+    __t__ above_1868 -1
+    __t__ above_1869 -1
+    __t__ above_1870 -1
+    __t__ above_1871 -1
+    __t__ above_1872 -1
+    __t__ above_1873 -1
+    __t__ above_1874 -1
+    __t__ above_1875 -1
+    __t__ above_1876 -1
+    __t__ above_1877 -1
+    __t__ above_1878 -1
+    __t__ above_1879 -1
+    __t__ above_1880 -1
+    __t__ above_1881 -1
+    __t__ above_1882 -1
+    __t__ above_1883 -1
+    __t__ above_1884 -1
+    __t__ above_1885 -1
+    __t__ above_1886 -1
+    __t__ above_1887 -1
+    __t__ above_1888 -1
+    __t__ above_1889 -1
+    __t__ above_1890 -1
+    __t__ above_1891 -1
+    __t__ above_1892 -1
+    __t__ above_1893 -1
+    __t__ above_1894 -1
+    __t__ above_1895 -1
+    __t__ above_1896 -1
+    __t__ above_1897 -1
+    __t__ above_1898 -1
+    __t__ above_1899 -1
+    __t__ above_1900 -1
+    __t__ above_1901 -1
+    __t__ above_1902 -1
+    __t__ above_1903 -1
+    __t__ above_1904 -1
+    __t__ above_1905 -1
+    __t__ above_1906 -1
+    __t__ above_1907 -1
+    __t__ above_1908 -1
+    __t__ above_1909 -1
+    __t__ above_1910 -1
+    __t__ above_1911 -1
+    __t__ above_1912 -1
+    __t__ above_1913 -1
+    __t__ above_1914 -1
+    __t__ above_1915 -1
+    __t__ above_1916 -1
+    __t__ above_1917 -1
+    __t__ above_1918 -1
+    __t__ above_1919 -1
+    __t__ above_1920 -1
+    __t__ above_1921 -1
+    __t__ above_1922 -1
+    __t__ above_1923 -1
+    __t__ above_1924 -1
+    __t__ above_1925 -1
+    __t__ above_1926 -1
+    __t__ above_1927 -1
+    __t__ above_1928 -1
+    __t__ above_1929 -1
+    __t__ above_1930 -1
+    __t__ above_1931 -1
+    __t__ above_1932 -1
+    __t__ above_1933 -1
+    __t__ above_1934 -1
+    __t__ above_1935 -1
+    __t__ above_1936 -1
+    __t__ above_1937 -1
+    __t__ above_1938 -1
+    __t__ above_1939 -1
+    __t__ above_1940 -1
+    __t__ above_1941 -1
+    __t__ above_1942 -1
+    __t__ above_1943 -1
+    __t__ above_1944 -1
+    __t__ above_1945 -1
+    __t__ above_1946 -1
+    __t__ above_1947 -1
+    __t__ above_1948 -1
+    __t__ above_1949 -1
+    __t__ above_1950 -1
+    __t__ above_1951 -1
+    __t__ above_1952 -1
+    __t__ above_1953 -1
+    __t__ above_1954 -1
+    __t__ above_1955 -1
+    __t__ above_1956 -1
+    __t__ above_1957 -1
+    __t__ above_1958 -1
+    __t__ above_1959 -1
+    __t__ above_1960 -1
+    __t__ above_1961 -1
+    __t__ above_1962 -1
+    __t__ above_1963 -1
+    __t__ above_1964 -1
+    __t__ above_1965 -1
+    __t__ above_1966 -1
+    __t__ above_1967 -1
+    __t__ above_1968 -1
+    __t__ above_1969 -1
+    __t__ above_1970 -1
+    __t__ above_1971 -1
+    __t__ above_1972 -1
+    __t__ above_1973 -1
+    __t__ above_1974 -1
+    __t__ above_1975 -1
+    __t__ above_1976 -1
+    __t__ above_1977 -1
+    __t__ above_1978 -1
+    __t__ above_1979 -1
+    __t__ above_1980 -1
+    __t__ above_1981 -1
+    __t__ above_1982 -1
+    __t__ above_1983 -1
+    __t__ above_1984 -1
+    __t__ above_1985 -1
+    __t__ above_1986 -1
+    __t__ above_1987 -1
+    __t__ above_1988 -1
+    __t__ above_1989 -1
+    __t__ above_1990 -1
+    __t__ above_1991 -1
+    __t__ above_1992 -1
+    __t__ above_1993 -1
+    __t__ above_1994 -1
+    __t__ above_1995 -1
+    __t__ above_1996 -1
+    __t__ above_1997 -1
+    __t__ above_1998 -1
+    __t__ above_1999 -1
+    __t__ above_2000 -1
+    __t__ below_1800 -1
+    __t__ below_1801 -1
+    __t__ below_1802 -1
+    __t__ below_1803 -1
+    __t__ below_1804 -1
+    __t__ below_1805 -1
+    __t__ below_1806 -1
+    __t__ below_1807 -1
+    __t__ below_1808 -1
+    __t__ below_1809 -1
+    __t__ below_1810 -1
+    __t__ below_1811 -1
+    __t__ below_1812 -1
+    __t__ below_1813 -1
+    __t__ below_1814 -1
+    __t__ below_1815 -1
+    __t__ below_1816 -1
+    __t__ below_1817 -1
+    __t__ below_1818 -1
+    __t__ below_1819 -1
+    __t__ below_1820 -1
+    __t__ below_1821 -1
+    __t__ below_1822 -1
+    __t__ below_1823 -1
+    __t__ below_1824 -1
+    __t__ below_1825 -1
+    __t__ below_1826 -1
+    __t__ below_1827 -1
+    __t__ below_1828 -1
+    __t__ below_1829 -1
+    __t__ below_1830 -1
+    __t__ below_1831 -1
+    __t__ below_1832 -1
+    __t__ below_1833 -1
+    __t__ below_1834 -1
+    __t__ below_1835 -1
+    __t__ below_1836 -1
+    __t__ below_1837 -1
+    __t__ below_1838 -1
+    __t__ below_1839 -1
+    __t__ below_1840 -1
+    __t__ below_1841 -1
+    __t__ below_1842 -1
+    __t__ below_1843 -1
+    __t__ below_1844 -1
+    __t__ below_1845 -1
+    __t__ below_1846 -1
+    __t__ below_1847 -1
+    __t__ below_1848 -1
+    __t__ below_1849 -1
+    __t__ below_1850 -1
+    __t__ below_1851 -1
+    __t__ below_1852 -1
+    __t__ below_1853 -1
+    __t__ below_1854 -1
+    __t__ below_1855 -1
+    __t__ below_1856 -1
+    __t__ below_1857 -1
+    __t__ below_1858 -1
+    __t__ below_1859 -1
+    __t__ below_1860 -1
+    __t__ below_1861 -1
+    __t__ below_1862 -1
+    __t__ below_1863 -1
+    __t__ below_1864 -1
+    __t__ below_1865 -1
+    __t__ below_1866 -1
+    __t__ below_1867 -1
+    __t__ below_1868 -1
+    __t__ below_1869 -1
+    __t__ below_1870 -1
+    __t__ below_1871 -1
+    __t__ below_1872 -1
+    __t__ below_1873 -1
+    __t__ below_1874 -1
+    __t__ below_1875 -1
+    __t__ below_1876 -1
+    __t__ below_1877 -1
+    __t__ below_1878 -1
+    __t__ below_1879 -1
+    __t__ below_1880 -1
+    __t__ below_1881 -1
+    __t__ below_1882 -1
+    __t__ below_1883 -1
+    __t__ below_1884 -1
+    __t__ below_1885 -1
+    __t__ below_1886 -1
+    __t__ below_1887 -1
+    __t__ below_1888 -1
+    __t__ below_1889 -1
+    __t__ below_1890 -1
+    __t__ below_1891 -1
+    __t__ below_1892 -1
+    __t__ below_1893 -1
+    __t__ below_1894 -1
+    __t__ below_1895 -1
+    __t__ below_1896 -1
+    __t__ below_1897 -1
+    __t__ below_1898 -1
+    __t__ below_1899 -1
+    __t__ below_1900 -1
+    __t__ below_1901 -1
+    __t__ below_1902 -1
+    __t__ below_1903 -1
+    __t__ below_1904 -1
+    __t__ below_1905 -1
+    __t__ below_1906 -1
+    __t__ below_1907 -1
+    __t__ below_1908 -1
+    __t__ below_1909 -1
+    __t__ below_1910 -1
+    __t__ below_1911 -1
+    __t__ below_1912 -1
+    __t__ below_1913 -1
+    __t__ below_1914 -1
+    __t__ below_1915 -1
+    __t__ below_1916 -1
+    __t__ below_1917 -1
+    __t__ below_1918 -1
+    __t__ below_1919 -1
+    __t__ below_1920 -1
+    __t__ below_1921 -1
+    __t__ below_1922 -1
+    __t__ below_1923 -1
+    __t__ below_1924 -1
+    __t__ below_1925 -1
+    __t__ below_1926 -1
+    __t__ below_1927 -1
+    __t__ below_1928 -1
+    __t__ below_1929 -1
+    __t__ below_1930 -1
+    __t__ below_1931 -1
+    __t__ below_1932 -1
+    __t__ below_1933 -1
+    __t__ below_1934 -1
+    __t__ below_1935 -1
+    __t__ below_1936 -1
+    __t__ below_1937 -1
+    __t__ below_1938 -1
+    __t__ below_1939 -1
+    __t__ below_1940 -1
+    __t__ below_1941 -1
+    __t__ below_1942 -1
+    __t__ below_1943 -1
+    __t__ below_1944 -1
+    __t__ below_1945 -1
+    __t__ below_1946 -1
+    __t__ below_1947 -1
+    __t__ below_1948 -1
+    __t__ below_1949 -1
+    __t__ below_1950 -1
+    __t__ below_1951 -1
+    __t__ below_1952 -1
+    __t__ below_1953 -1
+    __t__ below_1954 -1
+    __t__ below_1955 -1
+    __t__ below_1956 -1
+    __t__ below_1957 -1
+    __t__ below_1958 -1
+    __t__ below_1959 -1
+    __t__ below_1960 -1
+    __t__ below_1961 -1
+    __t__ below_1962 -1
+    __t__ below_1963 -1
+    __t__ below_1964 -1
+    __t__ below_1965 -1
+    __t__ below_1966 -1
+    __t__ below_1967 -1
+    __t__ below_1968 -1
+    __t__ below_1969 -1
+    __t__ below_1970 -1
+    __t__ below_1971 -1
+    __t__ below_1972 -1
+    __t__ below_1973 -1
+    __t__ below_1974 -1
+    __t__ below_1975 -1
+    __t__ below_1976 -1
+    __t__ below_1977 -1
+    __t__ below_1978 -1
+    __t__ below_1979 -1
+    __t__ below_1980 -1
+    __t__ below_1981 -1
+    __t__ below_1982 -1
+    __t__ below_1983 -1
+    __t__ below_1984 -1
+    __t__ below_1985 -1
+    __t__ below_1986 -1
+    __t__ below_1987 -1
+    __t__ below_1988 -1
+    __t__ below_1989 -1
+    __t__ below_1990 -1
+    __t__ below_1991 -1
+    __t__ below_1992 -1
+    __t__ below_1993 -1
+    __t__ below_1994 -1
+    __t__ below_1995 -1
+    __t__ below_1996 -1
+    __t__ below_1997 -1
+    __t__ below_1998 -1
+    __t__ below_1999 -1
+    __t__ below_2000 -1
+RHS
+    RHS above_1800 39
+    RHS above_1801 38
+    RHS above_1802 40
+    RHS above_1803 42
+    RHS above_1804 43
+    RHS above_1805 44
+    RHS above_1806 42
+    RHS above_1807 43
+    RHS above_1808 45
+    RHS above_1809 46
+    RHS above_1810 44
+    RHS above_1811 44
+    RHS above_1812 44
+    RHS above_1813 46
+    RHS above_1814 44
+    RHS above_1815 45
+    RHS above_1816 47
+    RHS above_1817 51
+    RHS above_1818 46
+    RHS above_1819 44
+    RHS above_1820 47
+    RHS above_1821 47
+    RHS above_1822 47
+    RHS above_1823 50
+    RHS above_1824 50
+    RHS above_1825 50
+    RHS above_1826 49
+    RHS above_1827 52
+    RHS above_1828 53
+    RHS above_1829 52
+    RHS above_1830 52
+    RHS above_1831 53
+    RHS above_1832 54
+    RHS above_1833 50
+    RHS above_1834 52
+    RHS above_1835 55
+    RHS above_1836 52
+    RHS above_1837 54
+    RHS above_1838 53
+    RHS above_1839 54
+    RHS above_1840 58
+    RHS above_1841 52
+    RHS above_1842 55
+    RHS above_1843 55
+    RHS above_1844 54
+    RHS above_1845 55
+    RHS above_1846 55
+    RHS above_1847 60
+    RHS above_1848 51
+    RHS above_1849 57
+    RHS above_1850 57
+    RHS above_1851 49
+    RHS above_1852 58
+    RHS above_1853 63
+    RHS above_1854 54
+    RHS above_1855 57
+    RHS above_1856 58
+    RHS above_1857 54
+    RHS above_1858 56
+    RHS above_1859 56
+    RHS above_1860 60
+    RHS above_1861 60
+    RHS above_1862 58
+    RHS above_1863 60
+    RHS above_1864 63
+    RHS above_1865 65
+    RHS above_1866 54
+    RHS above_1867 64
+    RHS above_1868 66
+    RHS above_1869 66
+    RHS above_1870 66
+    RHS above_1871 60
+    RHS above_1872 61
+    RHS above_1873 68
+    RHS above_1874 66
+    RHS above_1875 62
+    RHS above_1876 57
+    RHS above_1877 62
+    RHS above_1878 62
+    RHS above_1879 65
+    RHS above_1880 65
+    RHS above_1881 63
+    RHS above_1882 65
+    RHS above_1883 64
+    RHS above_1884 63
+    RHS above_1885 68
+    RHS above_1886 65
+    RHS above_1887 65
+    RHS above_1888 71
+    RHS above_1889 62
+    RHS above_1890 72
+    RHS above_1891 70
+    RHS above_1892 71
+    RHS above_1893 63
+    RHS above_1894 66
+    RHS above_1895 66
+    RHS above_1896 66
+    RHS above_1897 68
+    RHS above_1898 68
+    RHS above_1899 68
+    RHS above_1900 69
+    RHS above_1901 72
+    RHS above_1902 73
+    RHS above_1903 67
+    RHS above_1904 67
+    RHS above_1905 65
+    RHS above_1906 69
+    RHS above_1907 74
+    RHS above_1908 68
+    RHS above_1909 69
+    RHS above_1910 69
+    RHS above_1911 70
+    RHS above_1912 67
+    RHS above_1913 71
+    RHS above_1914 68
+    RHS above_1915 63
+    RHS above_1916 70
+    RHS above_1917 71
+    RHS above_1918 75
+    RHS above_1919 70
+    RHS above_1920 67
+    RHS above_1921 65
+    RHS above_1922 68
+    RHS above_1923 70
+    RHS above_1924 69
+    RHS above_1925 69
+    RHS above_1926 69
+    RHS above_1927 68
+    RHS above_1928 66
+    RHS above_1929 70
+    RHS above_1930 65
+    RHS above_1931 73
+    RHS above_1932 68
+    RHS above_1933 66
+    RHS above_1934 74
+    RHS above_1935 71
+    RHS above_1936 72
+    RHS above_1937 74
+    RHS above_1938 70
+    RHS above_1939 73
+    RHS above_1940 70
+    RHS above_1941 72
+    RHS above_1942 75
+    RHS above_1943 71
+    RHS above_1944 78
+    RHS above_1945 72
+    RHS above_1946 70
+    RHS above_1947 70
+    RHS above_1948 76
+    RHS above_1949 74
+    RHS above_1950 79
+    RHS above_1951 69
+    RHS above_1952 71
+    RHS above_1953 75
+    RHS above_1954 69
+    RHS above_1955 63
+    RHS above_1956 67
+    RHS above_1957 69
+    RHS above_1958 74
+    RHS above_1959 70
+    RHS above_1960 77
+    RHS above_1961 66
+    RHS above_1962 71
+    RHS above_1963 74
+    RHS above_1964 68
+    RHS above_1965 71
+    RHS above_1966 75
+    RHS above_1967 69
+    RHS above_1968 79
+    RHS above_1969 73
+    RHS above_1970 72
+    RHS above_1971 78
+    RHS above_1972 71
+    RHS above_1973 67
+    RHS above_1974 71
+    RHS above_1975 79
+    RHS above_1976 67
+    RHS above_1977 74
+    RHS above_1978 72
+    RHS above_1979 68
+    RHS above_1980 70
+    RHS above_1981 72
+    RHS above_1982 72
+    RHS above_1983 69
+    RHS above_1984 71
+    RHS above_1985 79
+    RHS above_1986 72
+    RHS above_1987 79
+    RHS above_1988 76
+    RHS above_1989 74
+    RHS above_1990 70
+    RHS above_1991 78
+    RHS above_1992 81
+    RHS above_1993 70
+    RHS above_1994 78
+    RHS above_1995 85
+    RHS above_1996 77
+    RHS above_1997 74
+    RHS above_1998 77
+    RHS above_1999 76
+    RHS above_2000 78
+    RHS below_1800 -39
+    RHS below_1801 -38
+    RHS below_1802 -40
+    RHS below_1803 -42
+    RHS below_1804 -43
+    RHS below_1805 -44
+    RHS below_1806 -42
+    RHS below_1807 -43
+    RHS below_1808 -45
+    RHS below_1809 -46
+    RHS below_1810 -44
+    RHS below_1811 -44
+    RHS below_1812 -44
+    RHS below_1813 -46
+    RHS below_1814 -44
+    RHS below_1815 -45
+    RHS below_1816 -47
+    RHS below_1817 -51
+    RHS below_1818 -46
+    RHS below_1819 -44
+    RHS below_1820 -47
+    RHS below_1821 -47
+    RHS below_1822 -47
+    RHS below_1823 -50
+    RHS below_1824 -50
+    RHS below_1825 -50
+    RHS below_1826 -49
+    RHS below_1827 -52
+    RHS below_1828 -53
+    RHS below_1829 -52
+    RHS below_1830 -52
+    RHS below_1831 -53
+    RHS below_1832 -54
+    RHS below_1833 -50
+    RHS below_1834 -52
+    RHS below_1835 -55
+    RHS below_1836 -52
+    RHS below_1837 -54
+    RHS below_1838 -53
+    RHS below_1839 -54
+    RHS below_1840 -58
+    RHS below_1841 -52
+    RHS below_1842 -55
+    RHS below_1843 -55
+    RHS below_1844 -54
+    RHS below_1845 -55
+    RHS below_1846 -55
+    RHS below_1847 -60
+    RHS below_1848 -51
+    RHS below_1849 -57
+    RHS below_1850 -57
+    RHS below_1851 -49
+    RHS below_1852 -58
+    RHS below_1853 -63
+    RHS below_1854 -54
+    RHS below_1855 -57
+    RHS below_1856 -58
+    RHS below_1857 -54
+    RHS below_1858 -56
+    RHS below_1859 -56
+    RHS below_1860 -60
+    RHS below_1861 -60
+    RHS below_1862 -58
+    RHS below_1863 -60
+    RHS below_1864 -63
+    RHS below_1865 -65
+    RHS below_1866 -54
+    RHS below_1867 -64
+    RHS below_1868 -66
+    RHS below_1869 -66
+    RHS below_1870 -66
+    RHS below_1871 -60
+    RHS below_1872 -61
+    RHS below_1873 -68
+    RHS below_1874 -66
+    RHS below_1875 -62
+    RHS below_1876 -57
+    RHS below_1877 -62
+    RHS below_1878 -62
+    RHS below_1879 -65
+    RHS below_1880 -65
+    RHS below_1881 -63
+    RHS below_1882 -65
+    RHS below_1883 -64
+    RHS below_1884 -63
+    RHS below_1885 -68
+    RHS below_1886 -65
+    RHS below_1887 -65
+    RHS below_1888 -71
+    RHS below_1889 -62
+    RHS below_1890 -72
+    RHS below_1891 -70
+    RHS below_1892 -71
+    RHS below_1893 -63
+    RHS below_1894 -66
+    RHS below_1895 -66
+    RHS below_1896 -66
+    RHS below_1897 -68
+    RHS below_1898 -68
+    RHS below_1899 -68
+    RHS below_1900 -69
+    RHS below_1901 -72
+    RHS below_1902 -73
+    RHS below_1903 -67
+    RHS below_1904 -67
+    RHS below_1905 -65
+    RHS below_1906 -69
+    RHS below_1907 -74
+    RHS below_1908 -68
+    RHS below_1909 -69
+    RHS below_1910 -69
+    RHS below_1911 -70
+    RHS below_1912 -67
+    RHS below_1913 -71
+    RHS below_1914 -68
+    RHS below_1915 -63
+    RHS below_1916 -70
+    RHS below_1917 -71
+    RHS below_1918 -75
+    RHS below_1919 -70
+    RHS below_1920 -67
+    RHS below_1921 -65
+    RHS below_1922 -68
+    RHS below_1923 -70
+    RHS below_1924 -69
+    RHS below_1925 -69
+    RHS below_1926 -69
+    RHS below_1927 -68
+    RHS below_1928 -66
+    RHS below_1929 -70
+    RHS below_1930 -65
+    RHS below_1931 -73
+    RHS below_1932 -68
+    RHS below_1933 -66
+    RHS below_1934 -74
+    RHS below_1935 -71
+    RHS below_1936 -72
+    RHS below_1937 -74
+    RHS below_1938 -70
+    RHS below_1939 -73
+    RHS below_1940 -70
+    RHS below_1941 -72
+    RHS below_1942 -75
+    RHS below_1943 -71
+    RHS below_1944 -78
+    RHS below_1945 -72
+    RHS below_1946 -70
+    RHS below_1947 -70
+    RHS below_1948 -76
+    RHS below_1949 -74
+    RHS below_1950 -79
+    RHS below_1951 -69
+    RHS below_1952 -71
+    RHS below_1953 -75
+    RHS below_1954 -69
+    RHS below_1955 -63
+    RHS below_1956 -67
+    RHS below_1957 -69
+    RHS below_1958 -74
+    RHS below_1959 -70
+    RHS below_1960 -77
+    RHS below_1961 -66
+    RHS below_1962 -71
+    RHS below_1963 -74
+    RHS below_1964 -68
+    RHS below_1965 -71
+    RHS below_1966 -75
+    RHS below_1967 -69
+    RHS below_1968 -79
+    RHS below_1969 -73
+    RHS below_1970 -72
+    RHS below_1971 -78
+    RHS below_1972 -71
+    RHS below_1973 -67
+    RHS below_1974 -71
+    RHS below_1975 -79
+    RHS below_1976 -67
+    RHS below_1977 -74
+    RHS below_1978 -72
+    RHS below_1979 -68
+    RHS below_1980 -70
+    RHS below_1981 -72
+    RHS below_1982 -72
+    RHS below_1983 -69
+    RHS below_1984 -71
+    RHS below_1985 -79
+    RHS below_1986 -72
+    RHS below_1987 -79
+    RHS below_1988 -76
+    RHS below_1989 -74
+    RHS below_1990 -70
+    RHS below_1991 -78
+    RHS below_1992 -81
+    RHS below_1993 -70
+    RHS below_1994 -78
+    RHS below_1995 -85
+    RHS below_1996 -77
+    RHS below_1997 -74
+    RHS below_1998 -77
+    RHS below_1999 -76
+    RHS below_2000 -78
+BOUNDS
+ UP BND w0 1
+ UP BND w5 1
+ UP BND w0_w5 1
+ UP BND w5_w5 1
+ENDATA
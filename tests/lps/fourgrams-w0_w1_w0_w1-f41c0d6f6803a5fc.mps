@@ -0,0 +1,2796 @@
+NAME w0_w1_w0_w1-f41c0d6f6803a5fc
+ROWS
+ N obj
+ L above_1800
+ L above_1801
+ L above_1802
+ L above_1803
+ L above_1804
+ L above_1805
+ L above_1806
+ L above_1807
+ L above_1808
+ L above_1809
+ L above_1810
+ L above_1811
+ L above_1812
+ L above_1813
+ L above_1814
+ L above_1815
+ L above_1816
+ L above_1817
+ L above_1818
+ L above_1819
+ L above_1820
+ L above_1821
+ L above_1822
+ L above_1823
+ L above_1824
+ L above_1825
+ L above_1826
+ L above_1827
+ L above_1828
+ L above_1829
+ L above_1830
+ L above_1831
+ L above_1832
+ L above_1833
+ L above_1834
+ L above_1835
+ L above_1836
+ L above_1837
+ L above_1838
+ L above_1839
+ L above_1840
+ L above_1841
+ L above_1842
+ L above_1843
+ L above_1844
+ L above_1845
+ L above_1846
+ L above_1847
+ L above_1848
+ L above_1849
+ L above_1850
+ L above_1851
+ L above_1852
+ L above_1853
+ L above_1854
+ L above_1855
+ L above_1856
+ L above_1857
+ L above_1858
+ L above_1859
+ L above_1860
+ L above_1861
+ L above_1862
+ L above_1863
+ L above_1864
+ L above_1865
+ L above_1866
+ L above_1867
+ L above_1868
+ L above_1869
+ L above_1870
+ L above_1871
+ L above_1872
+ L above_1873
+ L above_1874
+ L above_1875
+ L above_1876
+ L above_1877
+ L above_1878
+ L above_1879
+ L above_1880
+ L above_1881
+ L above_1882
+ L above_1883
+ L above_1884
+ L above_1885
+ L above_1886
+ L above_1887
+ L above_1888
+ L above_1889
+ L above_1890
+ L above_1891
+ L above_1892
+ L above_1893
+ L above_1894
+ L above_1895
+ L above_1896
+ L above_1897
+ L above_1898
+ L above_1899
+ L above_1900
+ L above_1901
+ L above_1902
+ L above_1903
+ L above_1904
+ L above_1905
+ L above_1906
+ L above_1907
+ L above_1908
+ L above_1909
+ L above_1910
+ L above_1911
+ L above_1912
+ L above_1913
+ L above_1914
+ L above_1915
+ L above_1916
+ L above_1917
+ L above_1918
+ L above_1919
+ L above_1920
+ L above_1921
+ L above_1922
+ L above_1923
+ L above_1924
+ L above_1925
+ L above_1926
+ L above_1927
+ L above_1928
+ L above_1929
+ L above_1930
+ L above_1931
+ L above_1932
+ L above_1933
+ L above_1934
+ L above_1935
+ L above_1936
+ L above_1937
+ L above_1938
+ L above_1939
+ L above_1940
+ L above_1941
+ L above_1942
+ L above_1943
+ L above_1944
+ L above_1945
+ L above_1946
+ L above_1947
+ L above_1948
+ L above_1949
+ L above_1950
+ L above_1951
+ L above_1952
+ L above_1953
+ L above_1954
+ L above_1955
+ L above_1956
+ L above_1957
+ L above_1958
+ L above_1959
+ L above_1960
+ L above_1961
+ L above_1962
+ L above_1963
+ L above_1964
+ L above_1965
+ L above_1966
+ L above_1967
+ L above_1968
+ L above_1969
+ L above_1970
+ L above_1971
+ L above_1972
+ L above_1973
+ L above_1974
+ L above_1975
+ L above_1976
+ L above_1977
+ L above_1978
+ L above_1979
+ L above_1980
+ L above_1981
+ L above_1982
+ L above_1983
+ L above_1984
+ L above_1985
+ L above_1986
+ L above_1987
+ L above_1988
+ L above_1989
+ L above_1990
+ L above_1991
+ L above_1992
+ L above_1993
+ L above_1994
+ L above_1995
+ L above_1996
+ L above_1997
+ L above_1998
+ L above_1999
+ L above_2000
+ L below_1800
+ L below_1801
+ L below_1802
+ L below_1803
+ L below_1804
+ L below_1805
+ L below_1806
+ L below_1807
+ L below_1808
+ L below_1809
+ L below_1810
+ L below_1811
+ L below_1812
+ L below_1813
+ L below_1814
+ L below_1815
+ L below_1816
+ L below_1817
+ L below_1818
+ L below_1819
+ L below_1820
+ L below_1821
+ L below_1822
+ L below_1823
+ L below_1824
+ L below_1825
+ L below_1826
+ L below_1827
+ L below_1828
+ L below_1829
+ L below_1830
+ L below_1831
+ L below_1832
+ L below_1833
+ L below_1834
+ L below_1835
+ L below_1836
+ L below_1837
+ L below_1838
+ L below_1839
+ L below_1840
+ L below_1841
+ L below_1842
+ L below_1843
+ L below_1844
+ L below_1845
+ L below_1846
+ L below_1847
+ L below_1848
+ L below_1849
+ L below_1850
+ L below_1851
+ L below_1852
+ L below_1853
+ L below_1854
+ L below_1855
+ L below_1856
+ L below_1857
+ L below_1858
+ L below_1859
+ L below_1860
+ L below_1861
+ L below_1862
+ L below_1863
+ L below_1864
+ L below_1865
+ L below_1866
+ L below_1867
+ L below_1868
+ L below_1869
+ L below_1870
+ L below_1871
+ L below_1872
+ L below_1873
+ L below_1874
+ L below_1875
+ L below_1876
+ L below_1877
+ L below_1878
+ L below_1879
+ L below_1880
+ L below_1881
+ L below_1882
+ L below_1883
+ L below_1884
+ L below_1885
+ L below_1886
+ L below_1887
+ L below_1888
+ L below_1889
+ L below_1890
+ L below_1891
+ L below_1892
+ L below_1893
+ L below_1894
+ L below_1895
+ L below_1896
+ L below_1897
+ L below_1898
+ L below_1899
+ L below_1900
+ L below_1901
+ L below_1902
+ L below_1903
+ L below_1904
+ L below_1905
+ L below_1906
+ L below_1907
+ L below_1908
+ L below_1909
+ L below_1910
+ L below_1911
+ L below_1912
+ L below_1913
+ L below_1914
+ L below_1915
+ L below_1916
+ L below_1917
+ L below_1918
+ L below_1919
+ L below_1920
+ L below_1921
+ L below_1922
+ L below_1923
+ L below_1924
+ L below_1925
+ L below_1926
+ L below_1927
+ L below_1928
+ L below_1929
+ L below_1930
+ L below_1931
+ L below_1932
+ L below_1933
+ L below_1934
+ L below_1935
+ L below_1936
+ L below_1937
+ L below_1938
+ L below_1939
+ L below_1940
+ L below_1941
+ L below_1942
+ L below_1943
+ L below_1944
+ L below_1945
+ L below_1946
+ L below_1947
+ L below_1948
+ L below_1949
+ L below_1950
+ L below_1951
+ L below_1952
+ L below_1953
+ L below_1954
+ L below_1955
+ L below_1956
+ L below_1957
+ L below_1958
+ L below_1959
+ L below_1960
+ L below_1961
+ L below_1962
+ L below_1963
+ L below_1964
+ L below_1965
+ L below_1966
+ L below_1967
+ L below_1968
+ L below_1969
+ L below_1970
+ L below_1971
+ L below_1972
+ L below_1973
+ L below_1974
+ L below_1975
+ L below_1976
+ L below_1977
+ L below_1978
+ L below_1979
+ L below_1980
+ L below_1981
+ L below_1982
+ L below_1983
+ L below_1984
+ L below_1985
+ L below_1986
+ L below_1987
+ L below_1988
+ L below_1989
+ L below_1990
+ L below_1991
+ L below_1992
+ L below_1993
+ L below_1994
+ L below_1995
+ L below_1996
+ L below_1997
+ L below_1998
+ L below_1999
+ L below_2000
+COLUMNS
+    w0 above_1800 51422
+    w0 above_1801 49243
+    w0 above_1802 51632
+    w0 above_1803 56497
+    w0 above_1804 62163
+    w0 above_1805 54862
+    w0 above_1806 60946
+    w0 above_1807 57189
+    w0 above_1808 62041
+    w0 above_1809 60099
+    w0 above_1810 67048
+    w0 above_1811 66630
+    w0 above_1812 68430
+    w0 above_1813 66835
+    w0 above_1814 68792
+    w0 above_1815 73721
+    w0 above_1816 72991
+    w0 above_1817 74461
+    w0 above_1818 70588
+    w0 above_1819 72598
+    w0 above_1820 70313
+    w0 above_1821 80058
+    w0 above_1822 79982
+    w0 above_1823 83562
+    w0 above_1824 78857
+    w0 above_1825 84733
+    w0 above_1826 84533
+    w0 above_1827 86684
+    w0 above_1828 92440
+    w0 above_1829 90404
+    w0 above_1830 99023
+    w0 above_1831 95010
+    w0 above_1832 89504
+    w0 above_1833 96055
+    w0 above_1834 101847
+    w0 above_1835 99440
+    w0 above_1836 97118
+    w0 above_1837 98861
+    w0 above_1838 107170
+    w0 above_1839 117222
+    w0 above_1840 100027
+    w0 above_1841 104022
+    w0 above_1842 110583
+    w0 above_1843 120174
+    w0 above_1844 123051
+    w0 above_1845 114845
+    w0 above_1846 115882
+    w0 above_1847 118138
+    w0 above_1848 126206
+    w0 above_1849 132716
+    w0 above_1850 126038
+    w0 above_1851 125001
+    w0 above_1852 119535
+    w0 above_1853 136287
+    w0 above_1854 123481
+    w0 above_1855 129220
+    w0 above_1856 132467
+    w0 above_1857 129788
+    w0 above_1858 137362
+    w0 above_1859 142732
+    w0 above_1860 138160
+    w0 above_1861 131516
+    w0 above_1862 150514
+    w0 above_1863 143334
+    w0 above_1864 143985
+    w0 above_1865 141525
+    w0 above_1866 167136
+    w0 above_1867 147658
+    w0 above_1868 156346
+    w0 above_1869 160966
+    w0 above_1870 146669
+    w0 above_1871 159061
+    w0 above_1872 160795
+    w0 above_1873 157895
+    w0 above_1874 167156
+    w0 above_1875 177177
+    w0 above_1876 168586
+    w0 above_1877 161651
+    w0 above_1878 153783
+    w0 above_1879 164740
+    w0 above_1880 170504
+    w0 above_1881 193250
+    w0 above_1882 168093
+    w0 above_1883 175926
+    w0 above_1884 170076
+    w0 above_1885 174629
+    w0 above_1886 173507
+    w0 above_1887 190112
+    w0 above_1888 200848
+    w0 above_1889 185130
+    w0 above_1890 187402
+    w0 above_1891 199916
+    w0 above_1892 197980
+    w0 above_1893 186324
+    w0 above_1894 189516
+    w0 above_1895 208523
+    w0 above_1896 207543
+    w0 above_1897 208976
+    w0 above_1898 199778
+    w0 above_1899 199030
+    w0 above_1900 220860
+    w0 above_1901 208072
+    w0 above_1902 232937
+    w0 above_1903 212698
+    w0 above_1904 219674
+    w0 above_1905 197633
+    w0 above_1906 220700
+    w0 above_1907 202257
+    w0 above_1908 210581
+    w0 above_1909 212066
+    w0 above_1910 219758
+    w0 above_1911 203996
+    w0 above_1912 214462
+    w0 above_1913 235181
+    w0 above_1914 234427
+    w0 above_1915 216230
+    w0 above_1916 209333
+    w0 above_1917 242237
+    w0 above_1918 250679
+    w0 above_1919 232106
+    w0 above_1920 221059
+    w0 above_1921 240767
+    w0 above_1922 224484
+    w0 above_1923 227616
+    w0 above_1924 243338
+    w0 above_1925 243747
+    w0 above_1926 247575
+    w0 above_1927 246773
+    w0 above_1928 224087
+    w0 above_1929 253617
+    w0 above_1930 249345
+    w0 above_1931 254756
+    w0 above_1932 246453
+    w0 above_1933 285284
+    w0 above_1934 262248
+    w0 above_1935 246708
+    w0 above_1936 257176
+    w0 above_1937 269376
+    w0 above_1938 251680
+    w0 above_1939 254913
+    w0 above_1940 253498
+    w0 above_1941 280108
+    w0 above_1942 264013
+    w0 above_1943 260556
+    w0 above_1944 237400
+    w0 above_1945 268362
+    w0 above_1946 256755
+    w0 above_1947 266028
+    w0 above_1948 277912
+    w0 above_1949 292117
+    w0 above_1950 268199
+    w0 above_1951 261424
+    w0 above_1952 259563
+    w0 above_1953 256153
+    w0 above_1954 283437
+    w0 above_1955 285567
+    w0 above_1956 289467
+    w0 above_1957 298888
+    w0 above_1958 301542
+    w0 above_1959 306129
+    w0 above_1960 290193
+    w0 above_1961 258337
+    w0 above_1962 289324
+    w0 above_1963 243394
+    w0 above_1964 291174
+    w0 above_1965 297056
+    w0 above_1966 281350
+    w0 above_1967 297824
+    w0 above_1968 292376
+    w0 above_1969 284537
+    w0 above_1970 279026
+    w0 above_1971 306382
+    w0 above_1972 290453
+    w0 above_1973 291454
+    w0 above_1974 315356
+    w0 above_1975 319830
+    w0 above_1976 308533
+    w0 above_1977 303503
+    w0 above_1978 293547
+    w0 above_1979 296333
+    w0 above_1980 305086
+    w0 above_1981 279551
+    w0 above_1982 296835
+    w0 above_1983 319476
+    w0 above_1984 286300
+    w0 above_1985 324819
+    w0 above_1986 331124
+    w0 above_1987 318146
+    w0 above_1988 277741
+    w0 above_1989 302499
+    w0 above_1990 294102
+    w0 above_1991 298467
+    w0 above_1992 320441
+    w0 above_1993 336698
+    w0 above_1994 317784
+    w0 above_1995 320671
+    w0 above_1996 348847
+    w0 above_1997 292322
+    w0 above_1998 308121
+    w0 above_1999 325227
+    w0 above_2000 298136
+    w0 below_1800 -51422
+    w0 below_1801 -49243
+    w0 below_1802 -51632
+    w0 below_1803 -56497
+    w0 below_1804 -62163
+    w0 below_1805 -54862
+    w0 below_1806 -60946
+    w0 below_1807 -57189
+    w0 below_1808 -62041
+    w0 below_1809 -60099
+    w0 below_1810 -67048
+    w0 below_1811 -66630
+    w0 below_1812 -68430
+    w0 below_1813 -66835
+    w0 below_1814 -68792
+    w0 below_1815 -73721
+    w0 below_1816 -72991
+    w0 below_1817 -74461
+    w0 below_1818 -70588
+    w0 below_1819 -72598
+    w0 below_1820 -70313
+    w0 below_1821 -80058
+    w0 below_1822 -79982
+    w0 below_1823 -83562
+    w0 below_1824 -78857
+    w0 below_1825 -84733
+    w0 below_1826 -84533
+    w0 below_1827 -86684
+    w0 below_1828 -92440
+    w0 below_1829 -90404
+    w0 below_1830 -99023
+    w0 below_1831 -95010
+    w0 below_1832 -89504
+    w0 below_1833 -96055
+    w0 below_1834 -101847
+    w0 below_1835 -99440
+    w0 below_1836 -97118
+    w0 below_1837 -98861
+    w0 below_1838 -107170
+    w0 below_1839 -117222
+    w0 below_1840 -100027
+    w0 below_1841 -104022
+    w0 below_1842 -110583
+    w0 below_1843 -120174
+    w0 below_1844 -123051
+    w0 below_1845 -114845
+    w0 below_1846 -115882
+    w0 below_1847 -118138
+    w0 below_1848 -126206
+    w0 below_1849 -132716
+    w0 below_1850 -126038
+    w0 below_1851 -125001
+    w0 below_1852 -119535
+    w0 below_1853 -136287
+    w0 below_1854 -123481
+    w0 below_1855 -129220
+    w0 below_1856 -132467
+    w0 below_1857 -129788
+    w0 below_1858 -137362
+    w0 below_1859 -142732
+    w0 below_1860 -138160
+    w0 below_1861 -131516
+    w0 below_1862 -150514
+    w0 below_1863 -143334
+    w0 below_1864 -143985
+    w0 below_1865 -141525
+    w0 below_1866 -167136
+    w0 below_1867 -147658
+    w0 below_1868 -156346
+    w0 below_1869 -160966
+    w0 below_1870 -146669
+    w0 below_1871 -159061
+    w0 below_1872 -160795
+    w0 below_1873 -157895
+    w0 below_1874 -167156
+    w0 below_1875 -177177
+    w0 below_1876 -168586
+    w0 below_1877 -161651
+    w0 below_1878 -153783
+    w0 below_1879 -164740
+    w0 below_1880 -170504
+    w0 below_1881 -193250
+    w0 below_1882 -168093
+    w0 below_1883 -175926
+    w0 below_1884 -170076
+    w0 below_1885 -174629
+    w0 below_1886 -173507
+    w0 below_1887 -190112
+    w0 below_1888 -200848
+    w0 below_1889 -185130
+    w0 below_1890 -187402
+    w0 below_1891 -199916
+    w0 below_1892 -197980
+    w0 below_1893 -186324
+    w0 below_1894 -189516
+    w0 below_1895 -208523
+    w0 below_1896 -207543
+    w0 below_1897 -208976
+    w0 below_1898 -199778
+    w0 below_1899 -199030
+    w0 below_1900 -220860
+    w0 below_1901 -208072
+    w0 below_1902 -232937
+    w0 below_1903 -212698
+    w0 below_1904 -219674
+    w0 below_1905 -197633
+    w0 below_1906 -220700
+    w0 below_1907 -202257
+    w0 below_1908 -210581
+    w0 below_1909 -212066
+    w0 below_1910 -219758
+    w0 below_1911 -203996
+    w0 below_1912 -214462
+    w0 below_1913 -235181
+    w0 below_1914 -234427
+    w0 below_1915 -216230
+    w0 below_1916 -209333
+    w0 below_1917 -242237
+    w0 below_1918 -250679
+    w0 below_1919 -232106
+    w0 below_1920 -221059
+    w0 below_1921 -240767
+    w0 below_1922 -224484
+    w0 below_1923 -227616
+    w0 below_1924 -243338
+    w0 below_1925 -243747
+    w0 below_1926 -247575
+    w0 below_1927 -246773
+    w0 below_1928 -224087
+    w0 below_1929 -253617
+    w0 below_1930 -249345
+    w0 below_1931 -254756
+    w0 below_1932 -246453
+    w0 below_1933 -285284
+    w0 below_1934 -262248
+    w0 below_1935 -246708
+    w0 below_1936 -257176
+    w0 below_1937 -269376
+    w0 below_1938 -251680
+    w0 below_1939 -254913
+    w0 below_1940 -253498
+    w0 below_1941 -280108
+    w0 below_1942 -264013
+    w0 below_1943 -260556
+    w0 below_1944 -237400
+    w0 below_1945 -268362
+    w0 below_1946 -256755
+    w0 below_1947 -266028
+    w0 below_1948 -277912
+    w0 below_1949 -292117
+    w0 below_1950 -268199
+    w0 below_1951 -261424
+    w0 below_1952 -259563
+    w0 below_1953 -256153
+    w0 below_1954 -283437
+    w0 below_1955 -285567
+    w0 below_1956 -289467
+    w0 below_1957 -298888
+    w0 below_1958 -301542
+    w0 below_1959 -306129
+    w0 below_1960 -290193
+    w0 below_1961 -258337
+    w0 below_1962 -289324
+    w0 below_1963 -243394
+    w0 below_1964 -291174
+    w0 below_1965 -297056
+    w0 below_1966 -281350
+    w0 below_1967 -297824
+    w0 below_1968 -292376
+    w0 below_1969 -284537
+    w0 below_1970 -279026
+    w0 below_1971 -306382
+    w0 below_1972 -290453
+    w0 below_1973 -291454
+    w0 below_1974 -315356
+    w0 below_1975 -319830
+    w0 below_1976 -308533
+    w0 below_1977 -303503
+    w0 below_1978 -293547
+    w0 below_1979 -296333
+    w0 below_1980 -305086
+    w0 below_1981 -279551
+    w0 below_1982 -296835
+    w0 below_1983 -319476
+    w0 below_1984 -286300
+    w0 below_1985 -324819
+    w0 below_1986 -331124
+    w0 below_1987 -318146
+    w0 below_1988 -277741
+    w0 below_1989 -302499
+    w0 below_1990 -294102
+    w0 below_1991 -298467
+    w0 below_1992 -320441
+    w0 below_1993 -336698
+    w0 below_1994 -317784
+    w0 below_1995 -320671
+    w0 below_1996 -348847
+    w0 below_1997 -292322
+    w0 below_1998 -308121
+    w0 below_1999 -325227
+    w0 below_2000 -298136
+    w1 above_1800 18573
+    w1 above_1801 23843
+    w1 above_1802 23680
+    w1 above_1803 24216
+    w1 above_1804 26037
+    w1 above_1805 24163
+    w1 above_1806 25417
+    w1 above_1807 25789
+    w1 above_1808 26248
+    w1 above_1809 27208
+    w1 above_1810 28015
+    w1 above_1811 28483
+    w1 above_1812 30553
+    w1 above_1813 29107
+    w1 above_1814 31440
+    w1 above_1815 33059
+    w1 above_1816 32795
+    w1 above_1817 33165
+    w1 above_1818 34089
+    w1 above_1819 33683
+    w1 above_1820 33696
+    w1 above_1821 37417
+    w1 above_1822 37931
+    w1 above_1823 35437
+    w1 above_1824 36625
+    w1 above_1825 34328
+    w1 above_1826 39452
+    w1 above_1827 41328
+    w1 above_1828 41333
+    w1 above_1829 39587
+    w1 above_1830 45154
+    w1 above_1831 45095
+    w1 above_1832 44953
+    w1 above_1833 43461
+    w1 above_1834 43872
+    w1 above_1835 43903
+    w1 above_1836 44845
+    w1 above_1837 44906
+    w1 above_1838 46449
+    w1 above_1839 50510
+    w1 above_1840 47757
+    w1 above_1841 53188
+    w1 above_1842 51636
+    w1 above_1843 49766
+    w1 above_1844 51779
+    w1 above_1845 57332
+    w1 above_1846 53842
+    w1 above_1847 52609
+    w1 above_1848 56220
+    w1 above_1849 54749
+    w1 above_1850 60368
+    w1 above_1851 51947
+    w1 above_1852 57871
+    w1 above_1853 57712
+    w1 above_1854 58859
+    w1 above_1855 56566
+    w1 above_1856 62003
+    w1 above_1857 58965
+    w1 above_1858 56141
+    w1 above_1859 66735
+    w1 above_1860 69203
+    w1 above_1861 64844
+    w1 above_1862 68314
+    w1 above_1863 66125
+    w1 above_1864 65977
+    w1 above_1865 66237
+    w1 above_1866 66797
+    w1 above_1867 69841
+    w1 above_1868 72806
+    w1 above_1869 71832
+    w1 above_1870 70728
+    w1 above_1871 79707
+    w1 above_1872 71025
+    w1 above_1873 79031
+    w1 above_1874 73147
+    w1 above_1875 74002
+    w1 above_1876 72090
+    w1 above_1877 81331
+    w1 above_1878 85583
+    w1 above_1879 79118
+    w1 above_1880 81301
+    w1 above_1881 76450
+    w1 above_1882 86013
+    w1 above_1883 79334
+    w1 above_1884 84676
+    w1 above_1885 82792
+    w1 above_1886 88960
+    w1 above_1887 84220
+    w1 above_1888 89382
+    w1 above_1889 76778
+    w1 above_1890 85069
+    w1 above_1891 89501
+    w1 above_1892 95595
+    w1 above_1893 84795
+    w1 above_1894 87560
+    w1 above_1895 100089
+    w1 above_1896 90528
+    w1 above_1897 98766
+    w1 above_1898 98910
+    w1 above_1899 93928
+    w1 above_1900 96872
+    w1 above_1901 93455
+    w1 above_1902 102189
+    w1 above_1903 102786
+    w1 above_1904 92315
+    w1 above_1905 100359
+    w1 above_1906 102932
+    w1 above_1907 101873
+    w1 above_1908 103073
+    w1 above_1909 96325
+    w1 above_1910 106794
+    w1 above_1911 106138
+    w1 above_1912 97065
+    w1 above_1913 101876
+    w1 above_1914 107538
+    w1 above_1915 107953
+    w1 above_1916 107097
+    w1 above_1917 119403
+    w1 above_1918 111938
+    w1 above_1919 114722
+    w1 above_1920 110430
+    w1 above_1921 111681
+    w1 above_1922 110089
+    w1 above_1923 122225
+    w1 above_1924 107572
+    w1 above_1925 108554
+    w1 above_1926 123676
+    w1 above_1927 115506
+    w1 above_1928 107329
+    w1 above_1929 109388
+    w1 above_1930 108633
+    w1 above_1931 107658
+    w1 above_1932 124095
+    w1 above_1933 119031
+    w1 above_1934 123444
+    w1 above_1935 125005
+    w1 above_1936 129170
+    w1 above_1937 117234
+    w1 above_1938 126603
+    w1 above_1939 115190
+    w1 above_1940 128216
+    w1 above_1941 125533
+    w1 above_1942 127632
+    w1 above_1943 123244
+    w1 above_1944 143695
+    w1 above_1945 137559
+    w1 above_1946 126615
+    w1 above_1947 129508
+    w1 above_1948 132512
+    w1 above_1949 121267
+    w1 above_1950 124892
+    w1 above_1951 112248
+    w1 above_1952 133135
+    w1 above_1953 124883
+    w1 above_1954 130014
+    w1 above_1955 126647
+    w1 above_1956 125160
+    w1 above_1957 138794
+    w1 above_1958 125060
+    w1 above_1959 138959
+    w1 above_1960 149001
+    w1 above_1961 144451
+    w1 above_1962 134026
+    w1 above_1963 140081
+    w1 above_1964 141412
+    w1 above_1965 153613
+    w1 above_1966 142228
+    w1 above_1967 158361
+    w1 above_1968 137044
+    w1 above_1969 148331
+    w1 above_1970 141996
+    w1 above_1971 131085
+    w1 above_1972 130959
+    w1 above_1973 144780
+    w1 above_1974 144577
+    w1 above_1975 151063
+    w1 above_1976 148136
+    w1 above_1977 145868
+    w1 above_1978 142767
+    w1 above_1979 147677
+    w1 above_1980 146118
+    w1 above_1981 149150
+    w1 above_1982 155668
+    w1 above_1983 144145
+    w1 above_1984 137361
+    w1 above_1985 154411
+    w1 above_1986 159868
+    w1 above_1987 143458
+    w1 above_1988 126539
+    w1 above_1989 159581
+    w1 above_1990 149498
+    w1 above_1991 159957
+    w1 above_1992 156712
+    w1 above_1993 157550
+    w1 above_1994 160998
+    w1 above_1995 164759
+    w1 above_1996 139715
+    w1 above_1997 173516
+    w1 above_1998 158642
+    w1 above_1999 153635
+    w1 above_2000 143841
+    w1 below_1800 -18573
+    w1 below_1801 -23843
+    w1 below_1802 -23680
+    w1 below_1803 -24216
+    w1 below_1804 -26037
+    w1 below_1805 -24163
+    w1 below_1806 -25417
+    w1 below_1807 -25789
+    w1 below_1808 -26248
+    w1 below_1809 -27208
+    w1 below_1810 -28015
+    w1 below_1811 -28483
+    w1 below_1812 -30553
+    w1 below_1813 -29107
+    w1 below_1814 -31440
+    w1 below_1815 -33059
+    w1 below_1816 -32795
+    w1 below_1817 -33165
+    w1 below_1818 -34089
+    w1 below_1819 -33683
+    w1 below_1820 -33696
+    w1 below_1821 -37417
+    w1 below_1822 -37931
+    w1 below_1823 -35437
+    w1 below_1824 -36625
+    w1 below_1825 -34328
+    w1 below_1826 -39452
+    w1 below_1827 -41328
+    w1 below_1828 -41333
+    w1 below_1829 -39587
+    w1 below_1830 -45154
+    w1 below_1831 -45095
+    w1 below_1832 -44953
+    w1 below_1833 -43461
+    w1 below_1834 -43872
+    w1 below_1835 -43903
+    w1 below_1836 -44845
+    w1 below_1837 -44906
+    w1 below_1838 -46449
+    w1 below_1839 -50510
+    w1 below_1840 -47757
+    w1 below_1841 -53188
+    w1 below_1842 -51636
+    w1 below_1843 -49766
+    w1 below_1844 -51779
+    w1 below_1845 -57332
+    w1 below_1846 -53842
+    w1 below_1847 -52609
+    w1 below_1848 -56220
+    w1 below_1849 -54749
+    w1 below_1850 -60368
+    w1 below_1851 -51947
+    w1 below_1852 -57871
+    w1 below_1853 -57712
+    w1 below_1854 -58859
+    w1 below_1855 -56566
+    w1 below_1856 -62003
+    w1 below_1857 -58965
+    w1 below_1858 -56141
+    w1 below_1859 -66735
+    w1 below_1860 -69203
+    w1 below_1861 -64844
+    w1 below_1862 -68314
+    w1 below_1863 -66125
+    w1 below_1864 -65977
+    w1 below_1865 -66237
+    w1 below_1866 -66797
+    w1 below_1867 -69841
+    w1 below_1868 -72806
+    w1 below_1869 -71832
+    w1 below_1870 -70728
+    w1 below_1871 -79707
+    w1 below_1872 -71025
+    w1 below_1873 -79031
+    w1 below_1874 -73147
+    w1 below_1875 -74002
+    w1 below_1876 -72090
+    w1 below_1877 -81331
+    w1 below_1878 -85583
+    w1 below_1879 -79118
+    w1 below_1880 -81301
+    w1 below_1881 -76450
+    w1 below_1882 -86013
+    w1 below_1883 -79334
+    w1 below_1884 -84676
+    w1 below_1885 -82792
+    w1 below_1886 -88960
+    w1 below_1887 -84220
+    w1 below_1888 -89382
+    w1 below_1889 -76778
+    w1 below_1890 -85069
+    w1 below_1891 -89501
+    w1 below_1892 -95595
+    w1 below_1893 -84795
+    w1 below_1894 -87560
+    w1 below_1895 -100089
+    w1 below_1896 -90528
+    w1 below_1897 -98766
+    w1 below_1898 -98910
+    w1 below_1899 -93928
+    w1 below_1900 -96872
+    w1 below_1901 -93455
+    w1 below_1902 -102189
+    w1 below_1903 -102786
+    w1 below_1904 -92315
+    w1 below_1905 -100359
+    w1 below_1906 -102932
+    w1 below_1907 -101873
+    w1 below_1908 -103073
+    w1 below_1909 -96325
+    w1 below_1910 -106794
+    w1 below_1911 -106138
+    w1 below_1912 -97065
+    w1 below_1913 -101876
+    w1 below_1914 -107538
+    w1 below_1915 -107953
+    w1 below_1916 -107097
+    w1 below_1917 -119403
+    w1 below_1918 -111938
+    w1 below_1919 -114722
+    w1 below_1920 -110430
+    w1 below_1921 -111681
+    w1 below_1922 -110089
+    w1 below_1923 -122225
+    w1 below_1924 -107572
+    w1 below_1925 -108554
+    w1 below_1926 -123676
+    w1 below_1927 -115506
+    w1 below_1928 -107329
+    w1 below_1929 -109388
+    w1 below_1930 -108633
+    w1 below_1931 -107658
+    w1 below_1932 -124095
+    w1 below_1933 -119031
+    w1 below_1934 -123444
+    w1 below_1935 -125005
+    w1 below_1936 -129170
+    w1 below_1937 -117234
+    w1 below_1938 -126603
+    w1 below_1939 -115190
+    w1 below_1940 -128216
+    w1 below_1941 -125533
+    w1 below_1942 -127632
+    w1 below_1943 -123244
+    w1 below_1944 -143695
+    w1 below_1945 -137559
+    w1 below_1946 -126615
+    w1 below_1947 -129508
+    w1 below_1948 -132512
+    w1 below_1949 -121267
+    w1 below_1950 -124892
+    w1 below_1951 -112248
+    w1 below_1952 -133135
+    w1 below_1953 -124883
+    w1 below_1954 -130014
+    w1 below_1955 -126647
+    w1 below_1956 -125160
+    w1 below_1957 -138794
+    w1 below_1958 -125060
+    w1 below_1959 -138959
+    w1 below_1960 -149001
+    w1 below_1961 -144451
+    w1 below_1962 -134026
+    w1 below_1963 -140081
+    w1 below_1964 -141412
+    w1 below_1965 -153613
+    w1 below_1966 -142228
+    w1 below_1967 -158361
+    w1 below_1968 -137044
+    w1 below_1969 -148331
+    w1 below_1970 -141996
+    w1 below_1971 -131085
+    w1 below_1972 -130959
+    w1 below_1973 -144780
+    w1 below_1974 -144577
+    w1 below_1975 -151063
+    w1 below_1976 -148136
+    w1 below_1977 -145868
+    w1 below_1978 -142767
+    w1 below_1979 -147677
+    w1 below_1980 -146118
+    w1 below_1981 -149150
+    w1 below_1982 -155668
+    w1 below_1983 -144145
+    w1 below_1984 -137361
+    w1 below_1985 -154411
+    w1 below_1986 -159868
+    w1 below_1987 -143458
+    w1 below_1988 -126539
+    w1 below_1989 -159581
+    w1 below_1990 -149498
+    w1 below_1991 -159957
+    w1 below_1992 -156712
+    w1 below_1993 -157550
+    w1 below_1994 -160998
+    w1 below_1995 -164759
+    w1 below_1996 -139715
+    w1 below_1997 -173516
+    w1 below_1998 -158642
+    w1 below_1999 -153635
+    w1 below_2000 -143841
+    w1_w0 above_1800 2524
+    w1_w0 above_1801 2458
+    w1_w0 above_1802 2424
+    w1_w0 above_1803 2575
+    w1_w0 above_1804 2795
+    w1_w0 above_1805 2888
+    w1_w0 above_1806 2410
+    w1_w0 above_1807 2871
+    w1_w0 above_1808 2835
+    w1_w0 above_1809 3119
+    w1_w0 above_1810 3062
+    w1_w0 above_1811 3032
+    w1_w0 above_1812 2960
+    w1_w0 above_1813 3645
+    w1_w0 above_1814 3299
+    w1_w0 above_1815 3449
+    w1_w0 above_1816 3636
+    w1_w0 above_1817 3618
+    w1_w0 above_1818 3441
+    w1_w0 above_1819 3823
+    w1_w0 above_1820 3615
+    w1_w0 above_1821 4142
+    w1_w0 above_1822 3924
+    w1_w0 above_1823 4539
+    w1_w0 above_1824 4277
+    w1_w0 above_1825 4756
+    w1_w0 above_1826 4387
+    w1_w0 above_1827 4149
+    w1_w0 above_1828 4547
+    w1_w0 above_1829 4751
+    w1_w0 above_1830 4899
+    w1_w0 above_1831 4744
+    w1_w0 above_1832 4930
+    w1_w0 above_1833 5002
+    w1_w0 above_1834 5107
+    w1_w0 above_1835 5584
+    w1_w0 above_1836 5514
+    w1_w0 above_1837 5283
+    w1_w0 above_1838 5060
+    w1_w0 above_1839 5581
+    w1_w0 above_1840 5589
+    w1_w0 above_1841 5518
+    w1_w0 above_1842 5946
+    w1_w0 above_1843 6384
+    w1_w0 above_1844 6064
+    w1_w0 above_1845 6081
+    w1_w0 above_1846 5978
+    w1_w0 above_1847 6478
+    w1_w0 above_1848 6123
+    w1_w0 above_1849 6774
+    w1_w0 above_1850 6048
+    w1_w0 above_1851 6627
+    w1_w0 above_1852 6854
+    w1_w0 above_1853 7059
+    w1_w0 above_1854 6246
+    w1_w0 above_1855 6909
+    w1_w0 above_1856 6747
+    w1_w0 above_1857 7152
+    w1_w0 above_1858 6656
+    w1_w0 above_1859 6939
+    w1_w0 above_1860 7574
+    w1_w0 above_1861 7166
+    w1_w0 above_1862 7904
+    w1_w0 above_1863 7778
+    w1_w0 above_1864 7753
+    w1_w0 above_1865 6354
+    w1_w0 above_1866 8174
+    w1_w0 above_1867 7481
+    w1_w0 above_1868 8120
+    w1_w0 above_1869 7483
+    w1_w0 above_1870 8569
+    w1_w0 above_1871 7398
+    w1_w0 above_1872 8027
+    w1_w0 above_1873 8646
+    w1_w0 above_1874 8281
+    w1_w0 above_1875 9108
+    w1_w0 above_1876 9526
+    w1_w0 above_1877 8065
+    w1_w0 above_1878 8145
+    w1_w0 above_1879 8844
+    w1_w0 above_1880 8138
+    w1_w0 above_1881 8468
+    w1_w0 above_1882 9275
+    w1_w0 above_1883 9943
+    w1_w0 above_1884 8653
+    w1_w0 above_1885 8980
+    w1_w0 above_1886 9617
+    w1_w0 above_1887 9700
+    w1_w0 above_1888 8639
+    w1_w0 above_1889 9834
+    w1_w0 above_1890 8819
+    w1_w0 above_1891 10100
+    w1_w0 above_1892 10799
+    w1_w0 above_1893 9361
+    w1_w0 above_1894 9914
+    w1_w0 above_1895 9522
+    w1_w0 above_1896 9780
+    w1_w0 above_1897 9187
+    w1_w0 above_1898 10442
+    w1_w0 above_1899 11036
+    w1_w0 above_1900 10541
+    w1_w0 above_1901 9898
+    w1_w0 above_1902 10685
+    w1_w0 above_1903 10488
+    w1_w0 above_1904 10152
+    w1_w0 above_1905 10068
+    w1_w0 above_1906 11667
+    w1_w0 above_1907 11322
+    w1_w0 above_1908 10677
+    w1_w0 above_1909 11601
+    w1_w0 above_1910 10889
+    w1_w0 above_1911 10891
+    w1_w0 above_1912 10528
+    w1_w0 above_1913 10576
+    w1_w0 above_1914 10678
+    w1_w0 above_1915 10162
+    w1_w0 above_1916 11662
+    w1_w0 above_1917 10830
+    w1_w0 above_1918 11460
+    w1_w0 above_1919 11657
+    w1_w0 above_1920 11761
+    w1_w0 above_1921 11374
+    w1_w0 above_1922 11803
+    w1_w0 above_1923 12183
+    w1_w0 above_1924 11122
+    w1_w0 above_1925 12102
+    w1_w0 above_1926 10644
+    w1_w0 above_1927 13000
+    w1_w0 above_1928 12480
+    w1_w0 above_1929 11978
+    w1_w0 above_1930 12179
+    w1_w0 above_1931 12694
+    w1_w0 above_1932 12700
+    w1_w0 above_1933 12588
+    w1_w0 above_1934 12151
+    w1_w0 above_1935 11639
+    w1_w0 above_1936 12705
+    w1_w0 above_1937 12003
+    w1_w0 above_1938 12912
+    w1_w0 above_1939 13507
+    w1_w0 above_1940 12683
+    w1_w0 above_1941 14081
+    w1_w0 above_1942 12839
+    w1_w0 above_1943 12611
+    w1_w0 above_1944 13310
+    w1_w0 above_1945 13945
+    w1_w0 above_1946 14209
+    w1_w0 above_1947 12719
+    w1_w0 above_1948 13500
+    w1_w0 above_1949 14492
+    w1_w0 above_1950 13635
+    w1_w0 above_1951 12578
+    w1_w0 above_1952 13717
+    w1_w0 above_1953 15097
+    w1_w0 above_1954 15740
+    w1_w0 above_1955 12083
+    w1_w0 above_1956 15116
+    w1_w0 above_1957 14338
+    w1_w0 above_1958 14851
+    w1_w0 above_1959 14472
+    w1_w0 above_1960 13941
+    w1_w0 above_1961 14070
+    w1_w0 above_1962 15349
+    w1_w0 above_1963 14915
+    w1_w0 above_1964 13875
+    w1_w0 above_1965 14451
+    w1_w0 above_1966 14877
+    w1_w0 above_1967 14603
+    w1_w0 above_1968 14973
+    w1_w0 above_1969 15231
+    w1_w0 above_1970 14373
+    w1_w0 above_1971 15349
+    w1_w0 above_1972 15101
+    w1_w0 above_1973 15104
+    w1_w0 above_1974 14471
+    w1_w0 above_1975 15121
+    w1_w0 above_1976 14479
+    w1_w0 above_1977 14722
+    w1_w0 above_1978 14451
+    w1_w0 above_1979 16357
+    w1_w0 above_1980 14788
+    w1_w0 above_1981 16345
+    w1_w0 above_1982 13894
+    w1_w0 above_1983 14846
+    w1_w0 above_1984 14126
+    w1_w0 above_1985 14634
+    w1_w0 above_1986 17038
+    w1_w0 above_1987 14701
+    w1_w0 above_1988 14795
+    w1_w0 above_1989 14494
+    w1_w0 above_1990 16446
+    w1_w0 above_1991 16066
+    w1_w0 above_1992 14863
+    w1_w0 above_1993 16857
+    w1_w0 above_1994 15870
+    w1_w0 above_1995 14350
+    w1_w0 above_1996 16187
+    w1_w0 above_1997 17040
+    w1_w0 above_1998 15545
+    w1_w0 above_1999 15905
+    w1_w0 above_2000 18143
+    w1_w0 below_1800 -2524
+    w1_w0 below_1801 -2458
+    w1_w0 below_1802 -2424
+    w1_w0 below_1803 -2575
+    w1_w0 below_1804 -2795
+    w1_w0 below_1805 -2888
+    w1_w0 below_1806 -2410
+    w1_w0 below_1807 -2871
+    w1_w0 below_1808 -2835
+    w1_w0 below_1809 -3119
+    w1_w0 below_1810 -3062
+    w1_w0 below_1811 -3032
+    w1_w0 below_1812 -2960
+    w1_w0 below_1813 -3645
+    w1_w0 below_1814 -3299
+    w1_w0 below_1815 -3449
+    w1_w0 below_1816 -3636
+    w1_w0 below_1817 -3618
+    w1_w0 below_1818 -3441
+    w1_w0 below_1819 -3823
+    w1_w0 below_1820 -3615
+    w1_w0 below_1821 -4142
+    w1_w0 below_1822 -3924
+    w1_w0 below_1823 -4539
+    w1_w0 below_1824 -4277
+    w1_w0 below_1825 -4756
+    w1_w0 below_1826 -4387
+    w1_w0 below_1827 -4149
+    w1_w0 below_1828 -4547
+    w1_w0 below_1829 -4751
+    w1_w0 below_1830 -4899
+    w1_w0 below_1831 -4744
+    w1_w0 below_1832 -4930
+    w1_w0 below_1833 -5002
+    w1_w0 below_1834 -5107
+    w1_w0 below_1835 -5584
+    w1_w0 below_1836 -5514
+    w1_w0 below_1837 -5283
+    w1_w0 below_1838 -5060
+    w1_w0 below_1839 -5581
+    w1_w0 below_1840 -5589
+    w1_w0 below_1841 -5518
+    w1_w0 below_1842 -5946
+    w1_w0 below_1843 -6384
+    w1_w0 below_1844 -6064
+    w1_w0 below_1845 -6081
+    w1_w0 below_1846 -5978
+    w1_w0 below_1847 -6478
+    w1_w0 below_1848 -6123
+    w1_w0 below_1849 -6774
+    w1_w0 below_1850 -6048
+    w1_w0 below_1851 -6627
+    w1_w0 below_1852 -6854
+    w1_w0 below_1853 -7059
+    w1_w0 below_1854 -6246
+    w1_w0 below_1855 -6909
+    w1_w0 below_1856 -6747
+    w1_w0 below_1857 -7152
+    w1_w0 below_1858 -6656
+    w1_w0 below_1859 -6939
+    w1_w0 below_1860 -7574
+    w1_w0 below_1861 -7166
+    w1_w0 below_1862 -7904
+    w1_w0 below_1863 -7778
+    w1_w0 below_1864 -7753
+    w1_w0 below_1865 -6354
+    w1_w0 below_1866 -8174
+    w1_w0 below_1867 -7481
+    w1_w0 below_1868 -8120
+    w1_w0 below_1869 -7483
+    w1_w0 below_1870 -8569
+    w1_w0 below_1871 -7398
+    w1_w0 below_1872 -8027
+    w1_w0 below_1873 -8646
+    w1_w0 below_1874 -8281
+    w1_w0 below_1875 -9108
+    w1_w0 below_1876 -9526
+    w1_w0 below_1877 -8065
+    w1_w0 below_1878 -8145
+    w1_w0 below_1879 -8844
+    w1_w0 below_1880 -8138
+    w1_w0 below_1881 -8468
+    w1_w0 below_1882 -9275
+    w1_w0 below_1883 -9943
+    w1_w0 below_1884 -8653
+    w1_w0 below_1885 -8980
+    w1_w0 below_1886 -9617
+    w1_w0 below_1887 -9700
+    w1_w0 below_1888 -8639
+    w1_w0 below_1889 -9834
+    w1_w0 below_1890 -8819
+    w1_w0 below_1891 -10100
+    w1_w0 below_1892 -10799
+    w1_w0 below_1893 -9361
+    w1_w0 below_1894 -9914
+    w1_w0 below_1895 -9522
+    w1_w0 below_1896 -9780
+    w1_w0 below_1897 -9187
+    w1_w0 below_1898 -10442
+    w1_w0 below_1899 -11036
+    w1_w0 below_1900 -10541
+    w1_w0 below_1901 -9898
+    w1_w0 below_1902 -10685
+    w1_w0 below_1903 -10488
+    w1_w0 below_1904 -10152
+    w1_w0 below_1905 -10068
+    w1_w0 below_1906 -11667
+    w1_w0 below_1907 -11322
+    w1_w0 below_1908 -10677
+    w1_w0 below_1909 -11601
+    w1_w0 below_1910 -10889
+    w1_w0 below_1911 -10891
+    w1_w0 below_1912 -10528
+    w1_w0 below_1913 -10576
+    w1_w0 below_1914 -10678
+    w1_w0 below_1915 -10162
+    w1_w0 below_1916 -11662
+    w1_w0 below_1917 -10830
+    w1_w0 below_1918 -11460
+    w1_w0 below_1919 -11657
+    w1_w0 below_1920 -11761
+    w1_w0 below_1921 -11374
+    w1_w0 below_1922 -11803
+    w1_w0 below_1923 -12183
+    w1_w0 below_1924 -11122
+    w1_w0 below_1925 -12102
+    w1_w0 below_1926 -10644
+    w1_w0 below_1927 -13000
+    w1_w0 below_1928 -12480
+    w1_w0 below_1929 -11978
+    w1_w0 below_1930 -12179
+    w1_w0 below_1931 -12694
+    w1_w0 below_1932 -12700
+    w1_w0 below_1933 -12588
+    w1_w0 below_1934 -12151
+    w1_w0 below_1935 -11639
+    w1_w0 below_1936 -12705
+    w1_w0 below_1937 -12003
+    w1_w0 below_1938 -12912
+    w1_w0 below_1939 -13507
+    w1_w0 below_1940 -12683
+    w1_w0 below_1941 -14081
+    w1_w0 below_1942 -12839
+    w1_w0 below_1943 -12611
+    w1_w0 below_1944 -13310
+    w1_w0 below_1945 -13945
+    w1_w0 below_1946 -14209
+    w1_w0 below_1947 -12719
+    w1_w0 below_1948 -13500
+    w1_w0 below_1949 -14492
+    w1_w0 below_1950 -13635
+    w1_w0 below_1951 -12578
+    w1_w0 below_1952 -13717
+    w1_w0 below_1953 -15097
+    w1_w0 below_1954 -15740
+    w1_w0 below_1955 -12083
+    w1_w0 below_1956 -15116
+    w1_w0 below_1957 -14338
+    w1_w0 below_1958 -14851
+    w1_w0 below_1959 -14472
+    w1_w0 below_1960 -13941
+    w1_w0 below_1961 -14070
+    w1_w0 below_1962 -15349
+    w1_w0 below_1963 -14915
+    w1_w0 below_1964 -13875
+    w1_w0 below_1965 -14451
+    w1_w0 below_1966 -14877
+    w1_w0 below_1967 -14603
+    w1_w0 below_1968 -14973
+    w1_w0 below_1969 -15231
+    w1_w0 below_1970 -14373
+    w1_w0 below_1971 -15349
+    w1_w0 below_1972 -15101
+    w1_w0 below_1973 -15104
+    w1_w0 below_1974 -14471
+    w1_w0 below_1975 -15121
+    w1_w0 below_1976 -14479
+    w1_w0 below_1977 -14722
+    w1_w0 below_1978 -14451
+    w1_w0 below_1979 -16357
+    w1_w0 below_1980 -14788
+    w1_w0 below_1981 -16345
+    w1_w0 below_1982 -13894
+    w1_w0 below_1983 -14846
+    w1_w0 below_1984 -14126
+    w1_w0 below_1985 -14634
+    w1_w0 below_1986 -17038
+    w1_w0 below_1987 -14701
+    w1_w0 below_1988 -14795
+    w1_w0 below_1989 -14494
+    w1_w0 below_1990 -16446
+    w1_w0 below_1991 -16066
+    w1_w0 below_1992 -14863
+    w1_w0 below_1993 -16857
+    w1_w0 below_1994 -15870
+    w1_w0 below_1995 -14350
+    w1_w0 below_1996 -16187
+    w1_w0 below_1997 -17040
+    w1_w0 below_1998 -15545
+    w1_w0 below_1999 -15905
+    w1_w0 below_2000 -18143
+    w0_w1_w0 above_1800 310
+    w0_w1_w0 above_1801 364
+    w0_w1_w0 above_1802 416
+    w0_w1_w0 above_1803 377
+    w0_w1_w0 above_1804 424
+    w0_w1_w0 above_1805 414
+    w0_w1_w0 above_1806 390
+    w0_w1_w0 above_1807 416
+    w0_w1_w0 above_1808 460
+    w0_w1_w0 above_1809 464
+    w0_w1_w0 above_1810 479
+    w0_w1_w0 above_1811 468
+    w0_w1_w0 above_1812 512
+    w0_w1_w0 above_1813 514
+    w0_w1_w0 above_1814 495
+    w0_w1_w0 above_1815 527
+    w0_w1_w0 above_1816 529
+    w0_w1_w0 above_1817 525
+    w0_w1_w0 above_1818 550
+    w0_w1_w0 above_1819 605
+    w0_w1_w0 above_1820 561
+    w0_w1_w0 above_1821 573
+    w0_w1_w0 above_1822 605
+    w0_w1_w0 above_1823 585
+    w0_w1_w0 above_1824 627
+    w0_w1_w0 above_1825 677
+    w0_w1_w0 above_1826 621
+    w0_w1_w0 above_1827 680
+    w0_w1_w0 above_1828 747
+    w0_w1_w0 above_1829 694
+    w0_w1_w0 above_1830 735
+    w0_w1_w0 above_1831 698
+    w0_w1_w0 above_1832 763
+    w0_w1_w0 above_1833 733
+    w0_w1_w0 above_1834 738
+    w0_w1_w0 above_1835 833
+    w0_w1_w0 above_1836 770
+    w0_w1_w0 above_1837 729
+    w0_w1_w0 above_1838 835
+    w0_w1_w0 above_1839 841
+    w0_w1_w0 above_1840 840
+    w0_w1_w0 above_1841 809
+    w0_w1_w0 above_1842 891
+    w0_w1_w0 above_1843 901
+    w0_w1_w0 above_1844 915
+    w0_w1_w0 above_1845 965
+    w0_w1_w0 above_1846 933
+    w0_w1_w0 above_1847 928
+    w0_w1_w0 above_1848 969
+    w0_w1_w0 above_1849 950
+    w0_w1_w0 above_1850 969
+    w0_w1_w0 above_1851 997
+    w0_w1_w0 above_1852 1035
+    w0_w1_w0 above_1853 1071
+    w0_w1_w0 above_1854 965
+    w0_w1_w0 above_1855 1124
+    w0_w1_w0 above_1856 1054
+    w0_w1_w0 above_1857 1096
+    w0_w1_w0 above_1858 1237
+    w0_w1_w0 above_1859 1193
+    w0_w1_w0 above_1860 1209
+    w0_w1_w0 above_1861 1155
+    w0_w1_w0 above_1862 1093
+    w0_w1_w0 above_1863 1209
+    w0_w1_w0 above_1864 1196
+    w0_w1_w0 above_1865 1211
+    w0_w1_w0 above_1866 1360
+    w0_w1_w0 above_1867 1214
+    w0_w1_w0 above_1868 1306
+    w0_w1_w0 above_1869 1190
+    w0_w1_w0 above_1870 1227
+    w0_w1_w0 above_1871 1260
+    w0_w1_w0 above_1872 1338
+    w0_w1_w0 above_1873 1390
+    w0_w1_w0 above_1874 1220
+    w0_w1_w0 above_1875 1400
+    w0_w1_w0 above_1876 1396
+    w0_w1_w0 above_1877 1304
+    w0_w1_w0 above_1878 1243
+    w0_w1_w0 above_1879 1323
+    w0_w1_w0 above_1880 1507
+    w0_w1_w0 above_1881 1424
+    w0_w1_w0 above_1882 1342
+    w0_w1_w0 above_1883 1484
+    w0_w1_w0 above_1884 1543
+    w0_w1_w0 above_1885 1410
+    w0_w1_w0 above_1886 1453
+    w0_w1_w0 above_1887 1455
+    w0_w1_w0 above_1888 1353
+    w0_w1_w0 above_1889 1513
+    w0_w1_w0 above_1890 1476
+    w0_w1_w0 above_1891 1620
+    w0_w1_w0 above_1892 1475
+    w0_w1_w0 above_1893 1376
+    w0_w1_w0 above_1894 1423
+    w0_w1_w0 above_1895 1503
+    w0_w1_w0 above_1896 1539
+    w0_w1_w0 above_1897 1461
+    w0_w1_w0 above_1898 1716
+    w0_w1_w0 above_1899 1570
+    w0_w1_w0 above_1900 1537
+    w0_w1_w0 above_1901 1467
+    w0_w1_w0 above_1902 1536
+    w0_w1_w0 above_1903 1628
+    w0_w1_w0 above_1904 1588
+    w0_w1_w0 above_1905 1663
+    w0_w1_w0 above_1906 1589
+    w0_w1_w0 above_1907 1607
+    w0_w1_w0 above_1908 1482
+    w0_w1_w0 above_1909 1661
+    w0_w1_w0 above_1910 1581
+    w0_w1_w0 above_1911 1575
+    w0_w1_w0 above_1912 1649
+    w0_w1_w0 above_1913 1495
+    w0_w1_w0 above_1914 1560
+    w0_w1_w0 above_1915 1654
+    w0_w1_w0 above_1916 1840
+    w0_w1_w0 above_1917 1534
+    w0_w1_w0 above_1918 1807
+    w0_w1_w0 above_1919 1550
+    w0_w1_w0 above_1920 1792
+    w0_w1_w0 above_1921 1706
+    w0_w1_w0 above_1922 1696
+    w0_w1_w0 above_1923 1582
+    w0_w1_w0 above_1924 1764
+    w0_w1_w0 above_1925 1532
+    w0_w1_w0 above_1926 1624
+    w0_w1_w0 above_1927 1708
+    w0_w1_w0 above_1928 1830
+    w0_w1_w0 above_1929 1734
+    w0_w1_w0 above_1930 1795
+    w0_w1_w0 above_1931 1793
+    w0_w1_w0 above_1932 1683
+    w0_w1_w0 above_1933 1888
+    w0_w1_w0 above_1934 1734
+    w0_w1_w0 above_1935 1587
+    w0_w1_w0 above_1936 1859
+    w0_w1_w0 above_1937 1837
+    w0_w1_w0 above_1938 1790
+    w0_w1_w0 above_1939 1765
+    w0_w1_w0 above_1940 1759
+    w0_w1_w0 above_1941 1808
+    w0_w1_w0 above_1942 1830
+    w0_w1_w0 above_1943 1652
+    w0_w1_w0 above_1944 1641
+    w0_w1_w0 above_1945 1735
+    w0_w1_w0 above_1946 1749
+    w0_w1_w0 above_1947 1814
+    w0_w1_w0 above_1948 1844
+    w0_w1_w0 above_1949 1873
+    w0_w1_w0 above_1950 1846
+    w0_w1_w0 above_1951 1835
+    w0_w1_w0 above_1952 1796
+    w0_w1_w0 above_1953 1776
+    w0_w1_w0 above_1954 1711
+    w0_w1_w0 above_1955 1866
+    w0_w1_w0 above_1956 1930
+    w0_w1_w0 above_1957 1812
+    w0_w1_w0 above_1958 1861
+    w0_w1_w0 above_1959 1883
+    w0_w1_w0 above_1960 1871
+    w0_w1_w0 above_1961 1922
+    w0_w1_w0 above_1962 2015
+    w0_w1_w0 above_1963 1845
+    w0_w1_w0 above_1964 1886
+    w0_w1_w0 above_1965 1805
+    w0_w1_w0 above_1966 1801
+    w0_w1_w0 above_1967 1655
+    w0_w1_w0 above_1968 1852
+    w0_w1_w0 above_1969 1884
+    w0_w1_w0 above_1970 1851
+    w0_w1_w0 above_1971 1910
+    w0_w1_w0 above_1972 1944
+    w0_w1_w0 above_1973 1870
+    w0_w1_w0 above_1974 1902
+    w0_w1_w0 above_1975 1745
+    w0_w1_w0 above_1976 1942
+    w0_w1_w0 above_1977 1715
+    w0_w1_w0 above_1978 1942
+    w0_w1_w0 above_1979 1878
+    w0_w1_w0 above_1980 1838
+    w0_w1_w0 above_1981 1789
+    w0_w1_w0 above_1982 1980
+    w0_w1_w0 above_1983 1969
+    w0_w1_w0 above_1984 1948
+    w0_w1_w0 above_1985 1885
+    w0_w1_w0 above_1986 2007
+    w0_w1_w0 above_1987 1750
+    w0_w1_w0 above_1988 2050
+    w0_w1_w0 above_1989 2040
+    w0_w1_w0 above_1990 1995
+    w0_w1_w0 above_1991 1873
+    w0_w1_w0 above_1992 1831
+    w0_w1_w0 above_1993 2199
+    w0_w1_w0 above_1994 1817
+    w0_w1_w0 above_1995 2082
+    w0_w1_w0 above_1996 2058
+    w0_w1_w0 above_1997 2049
+    w0_w1_w0 above_1998 2126
+    w0_w1_w0 above_1999 1985
+    w0_w1_w0 above_2000 2118
+    w0_w1_w0 below_1800 -310
+    w0_w1_w0 below_1801 -364
+    w0_w1_w0 below_1802 -416
+    w0_w1_w0 below_1803 -377
+    w0_w1_w0 below_1804 -424
+    w0_w1_w0 below_1805 -414
+    w0_w1_w0 below_1806 -390
+    w0_w1_w0 below_1807 -416
+    w0_w1_w0 below_1808 -460
+    w0_w1_w0 below_1809 -464
+    w0_w1_w0 below_1810 -479
+    w0_w1_w0 below_1811 -468
+    w0_w1_w0 below_1812 -512
+    w0_w1_w0 below_1813 -514
+    w0_w1_w0 below_1814 -495
+    w0_w1_w0 below_1815 -527
+    w0_w1_w0 below_1816 -529
+    w0_w1_w0 below_1817 -525
+    w0_w1_w0 below_1818 -550
+    w0_w1_w0 below_1819 -605
+    w0_w1_w0 below_1820 -561
+    w0_w1_w0 below_1821 -573
+    w0_w1_w0 below_1822 -605
+    w0_w1_w0 below_1823 -585
+    w0_w1_w0 below_1824 -627
+    w0_w1_w0 below_1825 -677
+    w0_w1_w0 below_1826 -621
+    w0_w1_w0 below_1827 -680
+    w0_w1_w0 below_1828 -747
+    w0_w1_w0 below_1829 -694
+    w0_w1_w0 below_1830 -735
+    w0_w1_w0 below_1831 -698
+    w0_w1_w0 below_1832 -763
+    w0_w1_w0 below_1833 -733
+    w0_w1_w0 below_1834 -738
+    w0_w1_w0 below_1835 -833
+    w0_w1_w0 below_1836 -770
+    w0_w1_w0 below_1837 -729
+    w0_w1_w0 below_1838 -835
+    w0_w1_w0 below_1839 -841
+    w0_w1_w0 below_1840 -840
+    w0_w1_w0 below_1841 -809
+    w0_w1_w0 below_1842 -891
+    w0_w1_w0 below_1843 -901
+    w0_w1_w0 below_1844 -915
+    w0_w1_w0 below_1845 -965
+    w0_w1_w0 below_1846 -933
+    w0_w1_w0 below_1847 -928
+    w0_w1_w0 below_1848 -969
+    w0_w1_w0 below_1849 -950
+    w0_w1_w0 below_1850 -969
+    w0_w1_w0 below_1851 -997
+    w0_w1_w0 below_1852 -1035
+    w0_w1_w0 below_1853 -1071
+    w0_w1_w0 below_1854 -965
+    w0_w1_w0 below_1855 -1124
+    w0_w1_w0 below_1856 -1054
+    w0_w1_w0 below_1857 -1096
+    w0_w1_w0 below_1858 -1237
+    w0_w1_w0 below_1859 -1193
+    w0_w1_w0 below_1860 -1209
+    w0_w1_w0 below_1861 -1155
+    w0_w1_w0 below_1862 -1093
+    w0_w1_w0 below_1863 -1209
+    w0_w1_w0 below_1864 -1196
+    w0_w1_w0 below_1865 -1211
+    w0_w1_w0 below_1866 -1360
+    w0_w1_w0 below_1867 -1214
+    w0_w1_w0 below_1868 -1306
+    w0_w1_w0 below_1869 -1190
+    w0_w1_w0 below_1870 -1227
+    w0_w1_w0 below_1871 -1260
+    w0_w1_w0 below_1872 -1338
+    w0_w1_w0 below_1873 -1390
+    w0_w1_w0 below_1874 -1220
+    w0_w1_w0 below_1875 -1400
+    w0_w1_w0 below_1876 -1396
+    w0_w1_w0 below_1877 -1304
+    w0_w1_w0 below_1878 -1243
+    w0_w1_w0 below_1879 -1323
+    w0_w1_w0 below_1880 -1507
+    w0_w1_w0 below_1881 -1424
+    w0_w1_w0 below_1882 -1342
+    w0_w1_w0 below_1883 -1484
+    w0_w1_w0 below_1884 -1543
+    w0_w1_w0 below_1885 -1410
+    w0_w1_w0 below_1886 -1453
+    w0_w1_w0 below_1887 -1455
+    w0_w1_w0 below_1888 -1353
+    w0_w1_w0 below_1889 -1513
+    w0_w1_w0 below_1890 -1476
+    w0_w1_w0 below_1891 -1620
+    w0_w1_w0 below_1892 -1475
+    w0_w1_w0 below_1893 -1376
+    w0_w1_w0 below_1894 -1423
+    w0_w1_w0 below_1895 -1503
+    w0_w1_w0 below_1896 -1539
+    w0_w1_w0 below_1897 -1461
+    w0_w1_w0 below_1898 -1716
+    w0_w1_w0 below_1899 -1570
+    w0_w1_w0 below_1900 -1537
+    w0_w1_w0 below_1901 -1467
+    w0_w1_w0 below_1902 -1536
+    w0_w1_w0 below_1903 -1628
+    w0_w1_w0 below_1904 -1588
+    w0_w1_w0 below_1905 -1663
+    w0_w1_w0 below_1906 -1589
+    w0_w1_w0 below_1907 -1607
+    w0_w1_w0 below_1908 -1482
+    w0_w1_w0 below_1909 -1661
+    w0_w1_w0 below_1910 -1581
+    w0_w1_w0 below_1911 -1575
+    w0_w1_w0 below_1912 -1649
+    w0_w1_w0 below_1913 -1495
+    w0_w1_w0 below_1914 -1560
+    w0_w1_w0 below_1915 -1654
+    w0_w1_w0 below_1916 -1840
+    w0_w1_w0 below_1917 -1534
+    w0_w1_w0 below_1918 -1807
+    w0_w1_w0 below_1919 -1550
+    w0_w1_w0 below_1920 -1792
+    w0_w1_w0 below_1921 -1706
+    w0_w1_w0 below_1922 -1696
+    w0_w1_w0 below_1923 -1582
+    w0_w1_w0 below_1924 -1764
+    w0_w1_w0 below_1925 -1532
+    w0_w1_w0 below_1926 -1624
+    w0_w1_w0 below_1927 -1708
+    w0_w1_w0 below_1928 -1830
+    w0_w1_w0 below_1929 -1734
+    w0_w1_w0 below_1930 -1795
+    w0_w1_w0 below_1931 -1793
+    w0_w1_w0 below_1932 -1683
+    w0_w1_w0 below_1933 -1888
+    w0_w1_w0 below_1934 -1734
+    w0_w1_w0 below_1935 -1587
+    w0_w1_w0 below_1936 -1859
+    w0_w1_w0 below_1937 -1837
+    w0_w1_w0 below_1938 -1790
+    w0_w1_w0 below_1939 -1765
+    w0_w1_w0 below_1940 -1759
+    w0_w1_w0 below_1941 -1808
+    w0_w1_w0 below_1942 -1830
+    w0_w1_w0 below_1943 -1652
+    w0_w1_w0 below_1944 -1641
+    w0_w1_w0 below_1945 -1735
+    w0_w1_w0 below_1946 -1749
+    w0_w1_w0 below_1947 -1814
+    w0_w1_w0 below_1948 -1844
+    w0_w1_w0 below_1949 -1873
+    w0_w1_w0 below_1950 -1846
+    w0_w1_w0 below_1951 -1835
+    w0_w1_w0 below_1952 -1796
+    w0_w1_w0 below_1953 -1776
+    w0_w1_w0 below_1954 -1711
+    w0_w1_w0 below_1955 -1866
+    w0_w1_w0 below_1956 -1930
+    w0_w1_w0 below_1957 -1812
+    w0_w1_w0 below_1958 -1861
+    w0_w1_w0 below_1959 -1883
+    w0_w1_w0 below_1960 -1871
+    w0_w1_w0 below_1961 -1922
+    w0_w1_w0 below_1962 -2015
+    w0_w1_w0 below_1963 -1845
+    w0_w1_w0 below_1964 -1886
+    w0_w1_w0 below_1965 -1805
+    w0_w1_w0 below_1966 -1801
+    w0_w1_w0 below_1967 -1655
+    w0_w1_w0 below_1968 -1852
+    w0_w1_w0 below_1969 -1884
+    w0_w1_w0 below_1970 -1851
+    w0_w1_w0 below_1971 -1910
+    w0_w1_w0 below_1972 -1944
+    w0_w1_w0 below_1973 -1870
+    w0_w1_w0 below_1974 -1902
+    w0_w1_w0 below_1975 -1745
+    w0_w1_w0 below_1976 -1942
+    w0_w1_w0 below_1977 -1715
+    w0_w1_w0 below_1978 -1942
+    w0_w1_w0 below_1979 -1878
+    w0_w1_w0 below_1980 -1838
+    w0_w1_w0 below_1981 -1789
+    w0_w1_w0 below_1982 -1980
+    w0_w1_w0 below_1983 -1969
+    w0_w1_w0 below_1984 -1948
+    w0_w1_w0 below_1985 -1885
+    w0_w1_w0 below_1986 -2007
+    w0_w1_w0 below_1987 -1750
+    w0_w1_w0 below_1988 -2050
+    w0_w1_w0 below_1989 -2040
+    w0_w1_w0 below_1990 -1995
+    w0_w1_w0 below_1991 -1873
+    w0_w1_w0 below_1992 -1831
+    w0_w1_w0 below_1993 -2199
+    w0_w1_w0 below_1994 -1817
+    w0_w1_w0 below_1995 -2082
+    w0_w1_w0 below_1996 -2058
+    w0_w1_w0 below_1997 -2049
+    w0_w1_w0 below_1998 -2126
+    w0_w1_w0 below_1999 -1985
+    w0_w1_w0 below_2000 -2118
+    __t__ obj 1
+    __t__ above_1800 -1
+    __t__ above_1801 -1
+    __t__ above_1802 -1
+    __t__ above_1803 -1
+    __t__ above_1804 -1
+    __t__ above_1805 -1
+    __t__ above_1806 -1
+    __t__ above_1807 -1
+    __t__ above_1808 -1
+    __t__ above_1809 -1
+    __t__ above_1810 -1
+    __t__ above_1811 -1
+    __t__ above_1812 -1
+    __t__ above_1813 -1
+    __t__ above_1814 -1
+    __t__ above_1815 -1
+    __t__ above_1816 -1
+    __t__ above_1817 -1
+    __t__ above_1818 -1
+    __t__ above_1819 -1
+    __t__ above_1820 -1
+    __t__ above_1821 -1
+    __t__ above_1822 -1
+    __t__ above_1823 -1
+    __t__ above_1824 -1
+    __t__ above_1825 -1
+    __t__ above_1826 -1
+    __t__ above_1827 -1
+    __t__ above_1828 -1
+    __t__ above_1829 -1
+    __t__ above_1830 -1
+    __t__ above_1831 -1
+    __t__ above_1832 -1
+    __t__ above_1833 -1
+    __t__ above_1834 -1
+    __t__ above_1835 -1
+    __t__ above_1836 -1
+    __t__ above_1837 -1
+    __t__ above_1838 -1
+    __t__ above_1839 -1
+    __t__ above_1840 -1
+    __t__ above_1841 -1
+    __t__ above_1842 -1
+    __t__ above_1843 -1
+    __t__ above_1844 -1
+    __t__ above_1845 -1
+    __t__ above_1846 -1
+    __t__ above_1847 -1
+    __t__ above_1848 -1
+    __t__ above_1849 -1
+    __t__ above_1850 -1
+    __t__ above_1851 -1
+    __t__ above_1852 -1
+    __t__ above_1853 -1
+    __t__ above_1854 -1
+    __t__ above_1855 -1
+    __t__ above_1856 -1
+    __t__ above_1857 -1
+    __t__ above_1858 -1
+    __t__ above_1859 -1
+    __t__ above_1860 -1
+    __t__ above_1861 -1
+    __t__ above_1862 -1
+    __t__ above_1863 -1
+    __t__ above_1864 -1
+    __t__ above_1865 -1
+    __t__ above_1866 -1
+    __t__ above_1867 -1
+    __t__ above_1868 -1
+    __t__ above_1869 -1
+    __t__ above_1870 -1
+    __t__ above_1871 -1
+    __t__ above_1872 -1
+    __t__ above_1873 -1
+    __t__ above_1874 -1
+    __t__ above_1875 -1
+    __t__ above_1876 -1
+    __t__ above_1877 -1
+    __t__ above_1878 -1
+    __t__ above_1879 -1
+    __t__ above_1880 -1
+    __t__ above_1881 -1
+    __t__ above_1882 -1
+    __t__ above_1883 -1
+    __t__ above_1884 -1
+    __t__ above_1885 -1
+    __t__ above_1886 -1
+    __t__ above_1887 -1
+    __t__ above_1888 -1
+    __t__ above_1889 -1
+    __t__ above_1890 -1
+    __t__ above_1891 -1
+    __t__ above_1892 -1
+    __t__ above_1893 -1
+    __t__ above_1894 -1
+    __t__ above_1895 -1
+    __t__ above_1896 -1
+    __t__ above_1897 -1
+    __t__ above_1898 -1
+    __t__ above_1899 -1
+    __t__ above_1900 -1
+    __t__ above_1901 -1
+    __t__ above_1902 -1
+    __t__ above_1903 -1
+    __t__ above_1904 -1
+    __t__ above_1905 -1
+    __t__ above_1906 -1
+    __t__ above_1907 -1
+    __t__ above_1908 -1
+    __t__ above_1909 -1
+    __t__ above_1910 -1
+    __t__ above_1911 -1
+    __t__ above_1912 -1
+    __t__ above_1913 -1
+    __t__ above_1914 -1
+    __t__ above_1915 -1
+    __t__ above_1916 -1
+    __t__ above_1917 -1
+    __t__ above_1918 -1
+    __t__ above_1919 -1
+    __t__ above_1920 -1
+    __t__ above_1921 -1
+    __t__ above_1922 -1
+    __t__ above_1923 -1
+    __t__ above_1924 -1
+    __t__ above_1925 -1
+    __t__ above_1926 -1
+    __t__ above_1927 -1
+    __t__ above_1928 -1
+    __t__ above_1929 -1
+    __t__ above_1930 -1
+    __t__ above_1931 -1
+    __t__ above_1932 -1
+    __t__ above_1933 -1
+    __t__ above_1934 -1
+    __t__ above_1935 -1
+    __t__ above_1936 -1
+    __t__ above_1937 -1
+    __t__ above_1938 -1
+    __t__ above_1939 -1
+    __t__ above_1940 -1
+    __t__ above_1941 -1
+    __t__ above_1942 -1
+    __t__ above_1943 -1
+    __t__ above_1944 -1
+    __t__ above_1945 -1
+    __t__ above_1946 -1
+    __t__ above_1947 -1
+    __t__ above_1948 -1
+    __t__ above_1949 -1
+    __t__ above_1950 -1
+    __t__ above_1951 -1
+    __t__ above_1952 -1
+    __t__ above_1953 -1
+    __t__ above_1954 -1
+    __t__ above_1955 -1
+    __t__ above_1956 -1
+    __t__ above_1957 -1
+    __t__ above_1958 -1
+    __t__ above_1959 -1
+    __t__ above_1960 -1
+    __t__ above_1961 -1
+    __t__ above_1962 -1
+    __t__ above_1963 -1
+    __t__ above_1964 -1
+    __t__ above_1965 -1
+    __t__ above_1966 -1
+    __t__ above_1967 -1
+    __t__ above_1968 -1
+    __t__ above_1969 -1
+    __t__ above_1970 -1
+    __t__ above_1971 -1
+    __t__ above_1972 -1
+    __t__ above_1973 -1
+    __t__ above_1974 -1
+    __t__ above_1975 -1
+    __t__ above_1976 -1
+    __t__ above_1977 -1
+    __t__ above_1978 -1
+    __t__ above_1979 -1
+    __t__ above_1980 -1
+    __t__ above_1981 -1
+    __t__ above_1982 -1
+    __t__ above_1983 -1
+    __t__ above_1984 -1
+    __t__ above_1985 -1
+    __t__ above_1986 -1
+    __t__ above_1987 -1
+    __t__ above_1988 -1
+    __t__ above_1989 -1
+    __t__ above_1990 -1
+    __t__ above_1991 -1
+    __t__ above_1992 -1
+    __t__ above_1993 -1
+    __t__ above_1994 -1
+    __t__ above_1995 -1
+    __t__ above_1996 -1
+    __t__ above_1997 -1
+    __t__ above_1998 -1
+    __t__ above_1999 -1
+    __t__ above_2000 -1
+    __t__ below_1800 -1
+    __t__ below_1801 -1
+    __t__ below_1802 -1
+    __t__ below_1803 -1
+    __t__ below_1804 -1
+    __t__ below_1805 -1
+    __t__ below_1806 -1
+    __t__ below_1807 -1
+    __t__ below_1808 -1
+    __t__ below_1809 -1
+    __t__ below_1810 -1
+    __t__ below_1811 -1
+    __t__ below_1812 -1
+    __t__ below_1813 -1
+    __t__ below_1814 -1
+    __t__ below_1815 -1
+    __t__ below_1816 -1
+    __t__ below_1817 -1
+    __t__ below_1818 -1
+    __t__ below_1819 -1
+    __t__ below_1820 -1
+    __t__ below_1821 -1
+    __t__ below_1822 -1
+    __t__ below_1823 -1
+    __t__ below_1824 -1
+    __t__ below_1825 -1
+    __t__ below_1826 -1
+    __t__ below_1827 -1
+    __t__ below_1828 -1
+    __t__ below_1829 -1
+    __t__ below_1830 -1
+    __t__ below_1831 -1
+    __t__ below_1832 -1
+    __t__ below_1833 -1
+    __t__ below_1834 -1
+    __t__ below_1835 -1
+    __t__ below_1836 -1
+    __t__ below_1837 -1
+    __t__ below_1838 -1
+    __t__ below_1839 -1
+    __t__ below_1840 -1
+    __t__ below_1841 -1
+    __t__ below_1842 -1
+    __t__ below_1843 -1
+    __t__ below_1844 -1
+    __t__ below_1845 -1
+    __t__ below_1846 -1
+    __t__ below_1847 -1
+    __t__ below_1848 -1
+    __t__ below_1849 -1
+    __t__ below_1850 -1
+    __t__ below_1851 -1
+    __t__ below_1852 -1
+    __t__ below_1853 -1
+    __t__ below_1854 -1
+    __t__ below_1855 -1
+    __t__ below_1856 -1
+    __t__ below_1857 -1
+    __t__ below_1858 -1
+    __t__ below_1859 -1
+    __t__ below_1860 -1
+    __t__ below_1861 -1
+    __t__ below_1862 -1
+    __t__ below_1863 -1
+    __t__ below_1864 -1
+    __t__ below_1865 -1
+    __t__ below_1866 -1
+    __t__ below_1867 -1
+    __t__ below_1868 -1
+    __t__ below_1869 -1
+    __t__ below_1870 -1
+    __t__ below_1871 -1
+    __t__ below_1872 -1
+    __t__ below_1873 -1
+    __t__ below_1874 -1
+    __t__ below_1875 -1
+    __t__ below_1876 -1
+    __t__ below_1877 -1
+    __t__ below_1878 -1
+    __t__ below_1879 -1
+    __t__ below_1880 -1
+    __t__ below_1881 -1
+    __t__ below_1882 -1
+    __t__ below_1883 -1
+    __t__ below_1884 -1
+    __t__ below_1885 -1
+    __t__ below_1886 -1
+    __t__ below_1887 -1
+    __t__ below_1888 -1
+    __t__ below_1889 -1
+    __t__ below_1890 -1
+    __t__ below_1891 -1
+    __t__ below_1892 -1
+    __t__ below_1893 -1
+    __t__ below_1894 -1
+    __t__ below_1895 -1
+    __t__ below_1896 -1
+    __t__ below_1897 -1
+    __t__ below_1898 -1
+    __t__ below_1899 -1
+    __t__ below_1900 -1
+    __t__ below_1901 -1
+    __t__ below_1902 -1
+    __t__ below_1903 -1
+    __t__ below_1904 -1
+    __t__ below_1905 -1
+    __t__ below_1906 -1
+    __t__ below_1907 -1
+    __t__ below_1908 -1
+    __t__ below_1909 -1
+    __t__ below_1910 -1
+    __t__ below_1911 -1
+    __t__ below_1912 -1
+    __t__ below_1913 -1
+    __t__ below_1914 -1
+    __t__ below_1915 -1
+    __t__ below_1916 -1
+    __t__ below_1917 -1
+    __t__ below_1918 -1
+    __t__ below_1919 -1
+    __t__ below_1920 -1
+    __t__ below_1921 -1
+    __t__ below_1922 -1
+    __t__ below_1923 -1
+    __t__ below_1924 -1
+    __t__ below_1925 -1
+    __t__ below_1926 -1
+    __t__ below_1927 -1
+    __t__ below_1928 -1
+    __t__ below_1929 -1
+    __t__ below_1930 -1
+    __t__ below_1931 -1
+    __t__ below_1932 -1
+    __t__ below_1933 -1
+    __t__ below_1934 -1
+    __t__ below_1935 -1
+    __t__ below_1936 -1
+    __t__ below_1937 -1
+    __t__ below_1938 -1
+    __t__ below_1939 -1
+    __t__ below_1940 -1
+    __t__ below_1941 -1
+    __t__ below_1942 -1
+    __t__ below_1943 -1
+    __t__ below_1944 -1
+    __t__ below_1945 -1
+    __t__ below_1946 -1
+    __t__ below_1947 -1
+    __t__ below_1948 -1
+    __t__ below_1949 -1
+    __t__ below_1950 -1
+    __t__ below_1951 -1
+    __t__ below_1952 -1
+    __t__ below_1953 -1
+    __t__ below_1954 -1
+    __t__ below_1955 -1
+    __t__ below_1956 -1
+    __t__ below_1957 -1
+    __t__ below_1958 -1
+    __t__ below_1959 -1
+    __t__ below_1960 -1
+    __t__ below_1961 -1
+    __t__ below_1962 -1
+    __t__ below_1963 -1
+    __t__ below_1964 -1
+    __t__ below_1965 -1
+    __t__ below_1966 -1
+    __t__ below_1967 -1
+    __t__ below_1968 -1
+    __t__ below_1969 -1
+    __t__ below_1970 -1
+    __t__ below_1971 -1
+    __t__ below_1972 -1
+    __t__ below_1973 -1
+    __t__ below_1974 -1
+    __t__ below_1975 -1
+    __t__ below_1976 -1
+    __t__ below_1977 -1
+    __t__ below_1978 -1
+    __t__ below_1979 -1
+    __t__ below_1980 -1
+    __t__ below_1981 -1
+    __t__ below_1982 -1
+    __t__ below_1983 -1
+    __t__ below_1984 -1
+    __t__ below_1985 -1
+    __t__ below_1986 -1
+    __t__ below_1987 -1
+    __t__ below_1988 -1
+    __t__ below_1989 -1
+    __t__ below_1990 -1
+    __t__ below_1991 -1
+    __t__ below_1992 -1
+    __t__ below_1993 -1
+    __t__ below_1994 -1
+    __t__ below_1995 -1
+    __t__ below_1996 -1
+    __t__ below_1997 -1
+    __t__ below_1998 -1
+    __t__ below_1999 -1
+    __t__ below_2000 -1
+RHS
+    RHS above_1813 1
+    RHS above_1814 1
+    RHS above_1815 1
+    RHS above_1816 1
+    RHS above_1817 1
+    RHS above_1818 1
+    RHS above_1819 1
+    RHS above_1820 1
+    RHS above_1821 2
+    RHS above_1822 2
+    RHS above_1823 2
+    RHS above_1824 2
+    RHS above_1825 3
+    RHS above_1826 3
+    RHS above_1827 3
+    RHS above_1828 4
+    RHS above_1829 5
+    RHS above_1830 5
+    RHS above_1831 6
+    RHS above_1832 6
+    RHS above_1833 8
+    RHS above_1834 8
+    RHS above_1835 9
+    RHS above_1836 10
+    RHS above_1837 11
+    RHS above_1838 13
+    RHS above_1839 15
+    RHS above_1840 17
+    RHS above_1841 18
+    RHS above_1842 20
+    RHS above_1843 24
+    RHS above_1844 24
+    RHS above_1845 30
+    RHS above_1846 33
+    RHS above_1847 36
+    RHS above_1848 37
+    RHS above_1849 46
+    RHS above_1850 51
+    RHS above_1851 48
+    RHS above_1852 61
+    RHS above_1853 64
+    RHS above_1854 68
+    RHS above_1855 73
+    RHS above_1856 76
+    RHS above_1857 91
+    RHS above_1858 90
+    RHS above_1859 93
+    RHS above_1860 105
+    RHS above_1861 113
+    RHS above_1862 121
+    RHS above_1863 121
+    RHS above_1864 133
+    RHS above_1865 140
+    RHS above_1866 139
+    RHS above_1867 140
+    RHS above_1868 143
+    RHS above_1869 138
+    RHS above_1870 144
+    RHS above_1871 137
+    RHS above_1872 144
+    RHS above_1873 140
+    RHS above_1874 153
+    RHS above_1875 148
+    RHS above_1876 154
+    RHS above_1877 171
+    RHS above_1878 153
+    RHS above_1879 146
+    RHS above_1880 169
+    RHS above_1881 157
+    RHS above_1882 168
+    RHS above_1883 163
+    RHS above_1884 146
+    RHS above_1885 161
+    RHS above_1886 151
+    RHS above_1887 159
+    RHS above_1888 159
+    RHS above_1889 145
+    RHS above_1890 152
+    RHS above_1891 166
+    RHS above_1892 167
+    RHS above_1893 158
+    RHS above_1894 159
+    RHS above_1895 156
+    RHS above_1896 160
+    RHS above_1897 175
+    RHS above_1898 168
+    RHS above_1899 166
+    RHS above_1900 147
+    RHS above_1901 171
+    RHS above_1902 147
+    RHS above_1903 140
+    RHS above_1904 149
+    RHS above_1905 137
+    RHS above_1906 148
+    RHS above_1907 156
+    RHS above_1908 140
+    RHS above_1909 136
+    RHS above_1910 150
+    RHS above_1911 145
+    RHS above_1912 142
+    RHS above_1913 137
+    RHS above_1914 142
+    RHS above_1915 152
+    RHS above_1916 151
+    RHS above_1917 144
+    RHS above_1918 154
+    RHS above_1919 149
+    RHS above_1920 146
+    RHS above_1921 141
+    RHS above_1922 139
+    RHS above_1923 149
+    RHS above_1924 147
+    RHS above_1925 130
+    RHS above_1926 143
+    RHS above_1927 127
+    RHS above_1928 147
+    RHS above_1929 139
+    RHS above_1930 126
+    RHS above_1931 146
+    RHS above_1932 124
+    RHS above_1933 137
+    RHS above_1934 137
+    RHS above_1935 147
+    RHS above_1936 139
+    RHS above_1937 149
+    RHS above_1938 144
+    RHS above_1939 143
+    RHS above_1940 154
+    RHS above_1941 148
+    RHS above_1942 157
+    RHS above_1943 138
+    RHS above_1944 143
+    RHS above_1945 141
+    RHS above_1946 146
+    RHS above_1947 141
+    RHS above_1948 149
+    RHS above_1949 136
+    RHS above_1950 137
+    RHS above_1951 158
+    RHS above_1952 148
+    RHS above_1953 159
+    RHS above_1954 137
+    RHS above_1955 146
+    RHS above_1956 158
+    RHS above_1957 146
+    RHS above_1958 163
+    RHS above_1959 152
+    RHS above_1960 154
+    RHS above_1961 147
+    RHS above_1962 161
+    RHS above_1963 164
+    RHS above_1964 144
+    RHS above_1965 163
+    RHS above_1966 129
+    RHS above_1967 143
+    RHS above_1968 139
+    RHS above_1969 159
+    RHS above_1970 149
+    RHS above_1971 157
+    RHS above_1972 140
+    RHS above_1973 160
+    RHS above_1974 158
+    RHS above_1975 164
+    RHS above_1976 148
+    RHS above_1977 160
+    RHS above_1978 147
+    RHS above_1979 152
+    RHS above_1980 145
+    RHS above_1981 159
+    RHS above_1982 144
+    RHS above_1983 146
+    RHS above_1984 166
+    RHS above_1985 158
+    RHS above_1986 163
+    RHS above_1987 155
+    RHS above_1988 165
+    RHS above_1989 154
+    RHS above_1990 156
+    RHS above_1991 160
+    RHS above_1992 167
+    RHS above_1993 160
+    RHS above_1994 170
+    RHS above_1995 158
+    RHS above_1996 145
+    RHS above_1997 151
+    RHS above_1998 154
+    RHS above_1999 158
+    RHS above_2000 171
+    RHS below_1813 -1
+    RHS below_1814 -1
+    RHS below_1815 -1
+    RHS below_1816 -1
+    RHS below_1817 -1
+    RHS below_1818 -1
+    RHS below_1819 -1
+    RHS below_1820 -1
+    RHS below_1821 -2
+    RHS below_1822 -2
+    RHS below_1823 -2
+    RHS below_1824 -2
+    RHS below_1825 -3
+    RHS below_1826 -3
+    RHS below_1827 -3
+    RHS below_1828 -4
+    RHS below_1829 -5
+    RHS below_1830 -5
+    RHS below_1831 -6
+    RHS below_1832 -6
+    RHS below_1833 -8
+    RHS below_1834 -8
+    RHS below_1835 -9
+    RHS below_1836 -10
+    RHS below_1837 -11
+    RHS below_1838 -13
+    RHS below_1839 -15
+    RHS below_1840 -17
+    RHS below_1841 -18
+    RHS below_1842 -20
+    RHS below_1843 -24
+    RHS below_1844 -24
+    RHS below_1845 -30
+    RHS below_1846 -33
+    RHS below_1847 -36
+    RHS below_1848 -37
+    RHS below_1849 -46
+    RHS below_1850 -51
+    RHS below_1851 -48
+    RHS below_1852 -61
+    RHS below_1853 -64
+    RHS below_1854 -68
+    RHS below_1855 -73
+    RHS below_1856 -76
+    RHS below_1857 -91
+    RHS below_1858 -90
+    RHS below_1859 -93
+    RHS below_1860 -105
+    RHS below_1861 -113
+    RHS below_1862 -121
+    RHS below_1863 -121
+    RHS below_1864 -133
+    RHS below_1865 -140
+    RHS below_1866 -139
+    RHS below_1867 -140
+    RHS below_1868 -143
+    RHS below_1869 -138
+    RHS below_1870 -144
+    RHS below_1871 -137
+    RHS below_1872 -144
+    RHS below_1873 -140
+    RHS below_1874 -153
+    RHS below_1875 -148
+    RHS below_1876 -154
+    RHS below_1877 -171
+    RHS below_1878 -153
+    RHS below_1879 -146
+    RHS below_1880 -169
+    RHS below_1881 -157
+    RHS below_1882 -168
+    RHS below_1883 -163
+    RHS below_1884 -146
+    RHS below_1885 -161
+    RHS below_1886 -151
+    RHS below_1887 -159
+    RHS below_1888 -159
+    RHS below_1889 -145
+    RHS below_1890 -152
+    RHS below_1891 -166
+    RHS below_1892 -167
+    RHS below_1893 -158
+    RHS below_1894 -159
+    RHS below_1895 -156
+    RHS below_1896 -160
+    RHS below_1897 -175
+    RHS below_1898 -168
+    RHS below_1899 -166
+    RHS below_1900 -147
+    RHS below_1901 -171
+    RHS below_1902 -147
+    RHS below_1903 -140
+    RHS below_1904 -149
+    RHS below_1905 -137
+    RHS below_1906 -148
+    RHS below_1907 -156
+    RHS below_1908 -140
+    RHS below_1909 -136
+    RHS below_1910 -150
+    RHS below_1911 -145
+    RHS below_1912 -142
+    RHS below_1913 -137
+    RHS below_1914 -142
+    RHS below_1915 -152
+    RHS below_1916 -151
+    RHS below_1917 -144
+    RHS below_1918 -154
+    RHS below_1919 -149
+    RHS below_1920 -146
+    RHS below_1921 -141
+    RHS below_1922 -139
+    RHS below_1923 -149
+    RHS below_1924 -147
+    RHS below_1925 -130
+    RHS below_1926 -143
+    RHS below_1927 -127
+    RHS below_1928 -147
+    RHS below_1929 -139
+    RHS below_1930 -126
+    RHS below_1931 -146
+    RHS below_1932 -124
+    RHS below_1933 -137
+    RHS below_1934 -137
+    RHS below_1935 -147
+    RHS below_1936 -139
+    RHS below_1937 -149
+    RHS below_1938 -144
+    RHS below_1939 -143
+    RHS below_1940 -154
+    RHS below_1941 -148
+    RHS below_1942 -157
+    RHS below_1943 -138
+    RHS below_1944 -143
+    RHS below_1945 -141
+    RHS below_1946 -146
+    RHS below_1947 -141
+    RHS below_1948 -149
+    RHS below_1949 -136
+    RHS below_1950 -137
+    RHS below_1951 -158
+    RHS below_1952 -148
+    RHS below_1953 -159
+    RHS below_1954 -137
+    RHS below_1955 -146
+    RHS below_1956 -158
+    RHS below_1957 -146
+    RHS below_1958 -163
+    RHS below_1959 -152
+    RHS below_1960 -154
+    RHS below_1961 -147
+    RHS below_1962 -161
+    RHS below_1963 -164
+    RHS below_1964 -144
+    RHS below_1965 -163
+    RHS below_1966 -129
+    RHS below_1967 -143
+    RHS below_1968 -139
+    RHS below_1969 -159
+    RHS below_1970 -149
+    RHS below_1971 -157
+    RHS below_1972 -140
+    RHS below_1973 -160
+    RHS below_1974 -158
+    RHS below_1975 -164
+    RHS below_1976 -148
+    RHS below_1977 -160
+    RHS below_1978 -147
+    RHS below_1979 -152
+    RHS below_1980 -145
+    RHS below_1981 -159
+    RHS below_1982 -144
+    RHS below_1983 -146
+    RHS below_1984 -166
+    RHS below_1985 -158
+    RHS below_1986 -163
+    RHS below_1987 -155
+    RHS below_1988 -165
+    RHS below_1989 -154
+    RHS below_1990 -156
+    RHS below_1991 -160
+    RHS below_1992 -167
+    RHS below_1993 -160
+    RHS below_1994 -170
+    RHS below_1995 -158
+    RHS below_1996 -145
+    RHS below_1997 -151
+    RHS below_1998 -154
+    RHS below_1999 -158
+    RHS below_2000 -171
+BOUNDS
+ENDATA
@@ -0,0 +1,2704 @@
+NAME w0_w1_w4_w1-1cdb42361f0c0759
+ROWS
+ N obj
+ L above_1800
+ L above_1801
+ L above_1802
+ L above_1803
+ L above_1804
+ L above_1805
+ L above_1806
+ L above_1807
+ L above_1808
+ L above_1809
+ L above_1810
+ L above_1811
+ L above_1812
+ L above_1813
+ L above_1814
+ L above_1815
+ L above_1816
+ L above_1817
+ L above_1818
+ L above_1819
+ L above_1820
+ L above_1821
+ L above_1822
+ L above_1823
+ L above_1824
+ L above_1825
+ L above_1826
+ L above_1827
+ L above_1828
+ L above_1829
+ L above_1830
+ L above_1831
+ L above_1832
+ L above_1833
+ L above_1834
+ L above_1835
+ L above_1836
+ L above_1837
+ L above_1838
+ L above_1839
+ L above_1840
+ L above_1841
+ L above_1842
+ L above_1843
+ L above_1844
+ L above_1845
+ L above_1846
+ L above_1847
+ L above_1848
+ L above_1849
+ L above_1850
+ L above_1851
+ L above_1852
+ L above_1853
+ L above_1854
+ L above_1855
+ L above_1856
+ L above_1857
+ L above_1858
+ L above_1859
+ L above_1860
+ L above_1861
+ L above_1862
+ L above_1863
+ L above_1864
+ L above_1865
+ L above_1866
+ L above_1867
+ L above_1868
+ L above_1869
+ L above_1870
+ L above_1871
+ L above_1872
+ L above_1873
+ L above_1874
+ L above_1875
+ L above_1876
+ L above_1877
+ L above_1878
+ L above_1879
+ L above_1880
+ L above_1881
+ L above_1882
+ L above_1883
+ L above_1884
+ L above_1885
+ L above_1886
+ L above_1887
+ L above_1888
+ L above_1889
+ L above_1890
+ L above_1891
+ L above_1892
+ L above_1893
+ L above_1894
+ L above_1895
+ L above_1896
+ L above_1897
+ L above_1898
+ L above_1899
+ L above_1900
+ L above_1901
+ L above_1902
+ L above_1903
+ L above_1904
+ L above_1905
+ L above_1906
+ L above_1907
+ L above_1908
+ L above_1909
+ L above_1910
+ L above_1911
+ L above_1912
+ L above_1913
+ L above_1914
+ L above_1915
+ L above_1916
+ L above_1917
+ L above_1918
+ L above_1919
+ L above_1920
+ L above_1921
+ L above_1922
+ L above_1923
+ L above_1924
+ L above_1925
+ L above_1926
+ L above_1927
+ L above_1928
+ L above_1929
+ L above_1930
+ L above_1931
+ L above_1932
+ L above_1933
+ L above_1934
+ L above_1935
+ L above_1936
+ L above_1937
+ L above_1938
+ L above_1939
+ L above_1940
+ L above_1941
+ L above_1942
+ L above_1943
+ L above_1944
+ L above_1945
+ L above_1946
+ L above_1947
+ L above_1948
+ L above_1949
+ L above_1950
+ L above_1951
+ L above_1952
+ L above_1953
+ L above_1954
+ L above_1955
+ L above_1956
+ L above_1957
+ L above_1958
+ L above_1959
+ L above_1960
+ L above_1961
+ L above_1962
+ L above_1963
+ L above_1964
+ L above_1965
+ L above_1966
+ L above_1967
+ L above_1968
+ L above_1969
+ L above_1970
+ L above_1971
+ L above_1972
+ L above_1973
+ L above_1974
+ L above_1975
+ L above_1976
+ L above_1977
+ L above_1978
+ L above_1979
+ L above_1980
+ L above_1981
+ L above_1982
+ L above_1983
+ L above_1984
+ L above_1985
+ L above_1986
+ L above_1987
+ L above_1988
+ L above_1989
+ L above_1990
+ L above_1991
+ L above_1992
+ L above_1993
+ L above_1994
+ L above_1995
+ L above_1996
+ L above_1997
+ L above_1998
+ L above_1999
+ L above_2000
+ L below_1800
+ L below_1801
+ L below_1802
+ L below_1803
+ L below_1804
+ L below_1805
+ L below_1806
+ L below_1807
+ L below_1808
+ L below_1809
+ L below_1810
+ L below_1811
+ L below_1812
+ L below_1813
+ L below_1814
+ L below_1815
+ L below_1816
+ L below_1817
+ L below_1818
+ L below_1819
+ L below_1820
+ L below_1821
+ L below_1822
+ L below_1823
+ L below_1824
+ L below_1825
+ L below_1826
+ L below_1827
+ L below_1828
+ L below_1829
+ L below_1830
+ L below_1831
+ L below_1832
+ L below_1833
+ L below_1834
+ L below_1835
+ L below_1836
+ L below_1837
+ L below_1838
+ L below_1839
+ L below_1840
+ L below_1841
+ L below_1842
+ L below_1843
+ L below_1844
+ L below_1845
+ L below_1846
+ L below_1847
+ L below_1848
+ L below_1849
+ L below_1850
+ L below_1851
+ L below_1852
+ L below_1853
+ L below_1854
+ L below_1855
+ L below_1856
+ L below_1857
+ L below_1858
+ L below_1859
+ L below_1860
+ L below_1861
+ L below_1862
+ L below_1863
+ L below_1864
+ L below_1865
+ L below_1866
+ L below_1867
+ L below_1868
+ L below_1869
+ L below_1870
+ L below_1871
+ L below_1872
+ L below_1873
+ L below_1874
+ L below_1875
+ L below_1876
+ L below_1877
+ L below_1878
+ L below_1879
+ L below_1880
+ L below_1881
+ L below_1882
+ L below_1883
+ L below_1884
+ L below_1885
+ L below_1886
+ L below_1887
+ L below_1888
+ L below_1889
+ L below_1890
+ L below_1891
+ L below_1892
+ L below_1893
+ L below_1894
+ L below_1895
+ L below_1896
+ L below_1897
+ L below_1898
+ L below_1899
+ L below_1900
+ L below_1901
+ L below_1902
+ L below_1903
+ L below_1904
+ L below_1905
+ L below_1906
+ L below_1907
+ L below_1908
+ L below_1909
+ L below_1910
+ L below_1911
+ L below_1912
+ L below_1913
+ L below_1914
+ L below_1915
+ L below_1916
+ L below_1917
+ L below_1918
+ L below_1919
+ L below_1920
+ L below_1921
+ L below_1922
+ L below_1923
+ L below_1924
+ L below_1925
+ L below_1926
+ L below_1927
+ L below_1928
+ L below_1929
+ L below_1930
+ L below_1931
+ L below_1932
+ L below_1933
+ L below_1934
+ L below_1935
+ L below_1936
+ L below_1937
+ L below_1938
+ L below_1939
+ L below_1940
+ L below_1941
+ L below_1942
+ L below_1943
+ L below_1944
+ L below_1945
+ L below_1946
+ L below_1947
+ L below_1948
+ L below_1949
+ L below_1950
+ L below_1951
+ L below_1952
+ L below_1953
+ L below_1954
+ L below_1955
+ L below_1956
+ L below_1957
+ L below_1958
+ L below_1959
+ L below_1960
+ L below_1961
+ L below_1962
+ L below_1963
+ L below_1964
+ L below_1965
+ L below_1966
+ L below_1967
+ L below_1968
+ L below_1969
+ L below_1970
+ L below_1971
+ L below_1972
+ L below_1973
+ L below_1974
+ L below_1975
+ L below_1976
+ L below_1977
+ L below_1978
+ L below_1979
+ L below_1980
+ L below_1981
+ L below_1982
+ L below_1983
+ L below_1984
+ L below_1985
+ L below_1986
+ L below_1987
+ L below_1988
+ L below_1989
+ L below_1990
+ L below_1991
+ L below_1992
+ L below_1993
+ L below_1994
+ L below_1995
+ L below_1996
+ L below_1997
+ L below_1998
+ L below_1999
+ L below_2000
+COLUMNS
+    w0 above_1800 51422
+    w0 above_1801 49243
+    w0 above_1802 51632
+    w0 above_1803 56497
+    w0 above_1804 62163
+    w0 above_1805 54862
+    w0 above_1806 60946
+    w0 above_1807 57189
+    w0 above_1808 62041
+    w0 above_1809 60099
+    w0 above_1810 67048
+    w0 above_1811 66630
+    w0 above_1812 68430
+    w0 above_1813 66835
+    w0 above_1814 68792
+    w0 above_1815 73721
+    w0 above_1816 72991
+    w0 above_1817 74461
+    w0 above_1818 70588
+    w0 above_1819 72598
+    w0 above_1820 70313
+    w0 above_1821 80058
+    w0 above_1822 79982
+    w0 above_1823 83562
+    w0 above_1824 78857
+    w0 above_1825 84733
+    w0 above_1826 84533
+    w0 above_1827 86684
+    w0 above_1828 92440
+    w0 above_1829 90404
+    w0 above_1830 99023
+    w0 above_1831 95010
+    w0 above_1832 89504
+    w0 above_1833 96055
+    w0 above_1834 101847
+    w0 above_1835 99440
+    w0 above_1836 97118
+    w0 above_1837 98861
+    w0 above_1838 107170
+    w0 above_1839 117222
+    w0 above_1840 100027
+    w0 above_1841 104022
+    w0 above_1842 110583
+    w0 above_1843 120174
+    w0 above_1844 123051
+    w0 above_1845 114845
+    w0 above_1846 115882
+    w0 above_1847 118138
+    w0 above_1848 126206
+    w0 above_1849 132716
+    w0 above_1850 126038
+    w0 above_1851 125001
+    w0 above_1852 119535
+    w0 above_1853 136287
+    w0 above_1854 123481
+    w0 above_1855 129220
+    w0 above_1856 132467
+    w0 above_1857 129788
+    w0 above_1858 137362
+    w0 above_1859 142732
+    w0 above_1860 138160
+    w0 above_1861 131516
+    w0 above_1862 150514
+    w0 above_1863 143334
+    w0 above_1864 143985
+    w0 above_1865 141525
+    w0 above_1866 167136
+    w0 above_1867 147658
+    w0 above_1868 156346
+    w0 above_1869 160966
+    w0 above_1870 146669
+    w0 above_1871 159061
+    w0 above_1872 160795
+    w0 above_1873 157895
+    w0 above_1874 167156
+    w0 above_1875 177177
+    w0 above_1876 168586
+    w0 above_1877 161651
+    w0 above_1878 153783
+    w0 above_1879 164740
+    w0 above_1880 170504
+    w0 above_1881 193250
+    w0 above_1882 168093
+    w0 above_1883 175926
+    w0 above_1884 170076
+    w0 above_1885 174629
+    w0 above_1886 173507
+    w0 above_1887 190112
+    w0 above_1888 200848
+    w0 above_1889 185130
+    w0 above_1890 187402
+    w0 above_1891 199916
+    w0 above_1892 197980
+    w0 above_1893 186324
+    w0 above_1894 189516
+    w0 above_1895 208523
+    w0 above_1896 207543
+    w0 above_1897 208976
+    w0 above_1898 199778
+    w0 above_1899 199030
+    w0 above_1900 220860
+    w0 above_1901 208072
+    w0 above_1902 232937
+    w0 above_1903 212698
+    w0 above_1904 219674
+    w0 above_1905 197633
+    w0 above_1906 220700
+    w0 above_1907 202257
+    w0 above_1908 210581
+    w0 above_1909 212066
+    w0 above_1910 219758
+    w0 above_1911 203996
+    w0 above_1912 214462
+    w0 above_1913 235181
+    w0 above_1914 234427
+    w0 above_1915 216230
+    w0 above_1916 209333
+    w0 above_1917 242237
+    w0 above_1918 250679
+    w0 above_1919 232106
+    w0 above_1920 221059
+    w0 above_1921 240767
+    w0 above_1922 224484
+    w0 above_1923 227616
+    w0 above_1924 243338
+    w0 above_1925 243747
+    w0 above_1926 247575
+    w0 above_1927 246773
+    w0 above_1928 224087
+    w0 above_1929 253617
+    w0 above_1930 249345
+    w0 above_1931 254756
+    w0 above_1932 246453
+    w0 above_1933 285284
+    w0 above_1934 262248
+    w0 above_1935 246708
+    w0 above_1936 257176
+    w0 above_1937 269376
+    w0 above_1938 251680
+    w0 above_1939 254913
+    w0 above_1940 253498
+    w0 above_1941 280108
+    w0 above_1942 264013
+    w0 above_1943 260556
+    w0 above_1944 237400
+    w0 above_1945 268362
+    w0 above_1946 256755
+    w0 above_1947 266028
+    w0 above_1948 277912
+    w0 above_1949 292117
+    w0 above_1950 268199
+    w0 above_1951 261424
+    w0 above_1952 259563
+    w0 above_1953 256153
+    w0 above_1954 283437
+    w0 above_1955 285567
+    w0 above_1956 289467
+    w0 above_1957 298888
+    w0 above_1958 301542
+    w0 above_1959 306129
+    w0 above_1960 290193
+    w0 above_1961 258337
+    w0 above_1962 289324
+    w0 above_1963 243394
+    w0 above_1964 291174
+    w0 above_1965 297056
+    w0 above_1966 281350
+    w0 above_1967 297824
+    w0 above_1968 292376
+    w0 above_1969 284537
+    w0 above_1970 279026
+    w0 above_1971 306382
+    w0 above_1972 290453
+    w0 above_1973 291454
+    w0 above_1974 315356
+    w0 above_1975 319830
+    w0 above_1976 308533
+    w0 above_1977 303503
+    w0 above_1978 293547
+    w0 above_1979 296333
+    w0 above_1980 305086
+    w0 above_1981 279551
+    w0 above_1982 296835
+    w0 above_1983 319476
+    w0 above_1984 286300
+    w0 above_1985 324819
+    w0 above_1986 331124
+    w0 above_1987 318146
+    w0 above_1988 277741
+    w0 above_1989 302499
+    w0 above_1990 294102
+    w0 above_1991 298467
+    w0 above_1992 320441
+    w0 above_1993 336698
+    w0 above_1994 317784
+    w0 above_1995 320671
+    w0 above_1996 348847
+    w0 above_1997 292322
+    w0 above_1998 308121
+    w0 above_1999 325227
+    w0 above_2000 298136
+    w0 below_1800 -51422
+    w0 below_1801 -49243
+    w0 below_1802 -51632
+    w0 below_1803 -56497
+    w0 below_1804 -62163
+    w0 below_1805 -54862
+    w0 below_1806 -60946
+    w0 below_1807 -57189
+    w0 below_1808 -62041
+    w0 below_1809 -60099
+    w0 below_1810 -67048
+    w0 below_1811 -66630
+    w0 below_1812 -68430
+    w0 below_1813 -66835
+    w0 below_1814 -68792
+    w0 below_1815 -73721
+    w0 below_1816 -72991
+    w0 below_1817 -74461
+    w0 below_1818 -70588
+    w0 below_1819 -72598
+    w0 below_1820 -70313
+    w0 below_1821 -80058
+    w0 below_1822 -79982
+    w0 below_1823 -83562
+    w0 below_1824 -78857
+    w0 below_1825 -84733
+    w0 below_1826 -84533
+    w0 below_1827 -86684
+    w0 below_1828 -92440
+    w0 below_1829 -90404
+    w0 below_1830 -99023
+    w0 below_1831 -95010
+    w0 below_1832 -89504
+    w0 below_1833 -96055
+    w0 below_1834 -101847
+    w0 below_1835 -99440
+    w0 below_1836 -97118
+    w0 below_1837 -98861
+    w0 below_1838 -107170
+    w0 below_1839 -117222
+    w0 below_1840 -100027
+    w0 below_1841 -104022
+    w0 below_1842 -110583
+    w0 below_1843 -120174
+    w0 below_1844 -123051
+    w0 below_1845 -114845
+    w0 below_1846 -115882
+    w0 below_1847 -118138
+    w0 below_1848 -126206
+    w0 below_1849 -132716
+    w0 below_1850 -126038
+    w0 below_1851 -125001
+    w0 below_1852 -119535
+    w0 below_1853 -136287
+    w0 below_1854 -123481
+    w0 below_1855 -129220
+    w0 below_1856 -132467
+    w0 below_1857 -129788
+    w0 below_1858 -137362
+    w0 below_1859 -142732
+    w0 below_1860 -138160
+    w0 below_1861 -131516
+    w0 below_1862 -150514
+    w0 below_1863 -143334
+    w0 below_1864 -143985
+    w0 below_1865 -141525
+    w0 below_1866 -167136
+    w0 below_1867 -147658
+    w0 below_1868 -156346
+    w0 below_1869 -160966
+    w0 below_1870 -146669
+    w0 below_1871 -159061
+    w0 below_1872 -160795
+    w0 below_1873 -157895
+    w0 below_1874 -167156
+    w0 below_1875 -177177
+    w0 below_1876 -168586
+    w0 below_1877 -161651
+    w0 below_1878 -153783
+    w0 below_1879 -164740
+    w0 below_1880 -170504
+    w0 below_1881 -193250
+    w0 below_1882 -168093
+    w0 below_1883 -175926
+    w0 below_1884 -170076
+    w0 below_1885 -174629
+    w0 below_1886 -173507
+    w0 below_1887 -190112
+    w0 below_1888 -200848
+    w0 below_1889 -185130
+    w0 below_1890 -187402
+    w0 below_1891 -199916
+    w0 below_1892 -197980
+    w0 below_1893 -186324
+    w0 below_1894 -189516
+    w0 below_1895 -208523
+    w0 below_1896 -207543
+    w0 below_1897 -208976
+    w0 below_1898 -199778
+    w0 below_1899 -199030
+    w0 below_1900 -220860
+    w0 below_1901 -208072
+    w0 below_1902 -232937
+    w0 below_1903 -212698
+    w0 below_1904 -219674
+    w0 below_1905 -197633
+    w0 below_1906 -220700
+    w0 below_1907 -202257
+    w0 below_1908 -210581
+    w0 below_1909 -212066
+    w0 below_1910 -219758
+    w0 below_1911 -203996
+    w0 below_1912 -214462
+    w0 below_1913 -235181
+    w0 below_1914 -234427
+    w0 below_1915 -216230
+    w0 below_1916 -209333
+    w0 below_1917 -242237
+    w0 below_1918 -250679
+    w0 below_1919 -232106
+    w0 below_1920 -221059
+    w0 below_1921 -240767
+    w0 below_1922 -224484
+    w0 below_1923 -227616
+    w0 below_1924 -243338
+    w0 below_1925 -243747
+    w0 below_1926 -247575
+    w0 below_1927 -246773
+    w0 below_1928 -224087
+    w0 below_1929 -253617
+    w0 below_1930 -249345
+    w0 below_1931 -254756
+    w0 below_1932 -246453
+    w0 below_1933 -285284
+    w0 below_1934 -262248
+    w0 below_1935 -246708
+    w0 below_1936 -257176
+    w0 below_1937 -269376
+    w0 below_1938 -251680
+    w0 below_1939 -254913
+    w0 below_1940 -253498
+    w0 below_1941 -280108
+    w0 below_1942 -264013
+    w0 below_1943 -260556
+    w0 below_1944 -237400
+    w0 below_1945 -268362
+    w0 below_1946 -256755
+    w0 below_1947 -266028
+    w0 below_1948 -277912
+    w0 below_1949 -292117
+    w0 below_1950 -268199
+    w0 below_1951 -261424
+    w0 below_1952 -259563
+    w0 below_1953 -256153
+    w0 below_1954 -283437
+    w0 below_1955 -285567
+    w0 below_1956 -289467
+    w0 below_1957 -298888
+    w0 below_1958 -301542
+    w0 below_1959 -306129
+    w0 below_1960 -290193
+    w0 below_1961 -258337
+    w0 below_1962 -289324
+    w0 below_1963 -243394
+    w0 below_1964 -291174
+    w0 below_1965 -297056
+    w0 below_1966 -281350
+    w0 below_1967 -297824
+    w0 below_1968 -292376
+    w0 below_1969 -284537
+    w0 below_1970 -279026
+    w0 below_1971 -306382
+    w0 below_1972 -290453
+    w0 below_1973 -291454
+    w0 below_1974 -315356
+    w0 below_1975 -319830
+    w0 below_1976 -308533
+    w0 below_1977 -303503
+    w0 below_1978 -293547
+    w0 below_1979 -296333
+    w0 below_1980 -305086
+    w0 below_1981 -279551
+    w0 below_1982 -296835
+    w0 below_1983 -319476
+    w0 below_1984 -286300
+    w0 below_1985 -324819
+    w0 below_1986 -331124
+    w0 below_1987 -318146
+    w0 below_1988 -277741
+    w0 below_1989 -302499
+    w0 below_1990 -294102
+    w0 below_1991 -298467
+    w0 below_1992 -320441
+    w0 below_1993 -336698
+    w0 below_1994 -317784
+    w0 below_1995 -320671
+    w0 below_1996 -348847
+    w0 below_1997 -292322
+    w0 below_1998 -308121
+    w0 below_1999 -325227
+    w0 below_2000 -298136
+    w1 above_1800 18573
+    w1 above_1801 23843
+    w1 above_1802 23680
+    w1 above_1803 24216
+    w1 above_1804 26037
+    w1 above_1805 24163
+    w1 above_1806 25417
+    w1 above_1807 25789
+    w1 above_1808 26248
+    w1 above_1809 27208
+    w1 above_1810 28015
+    w1 above_1811 28483
+    w1 above_1812 30553
+    w1 above_1813 29107
+    w1 above_1814 31440
+    w1 above_1815 33059
+    w1 above_1816 32795
+    w1 above_1817 33165
+    w1 above_1818 34089
+    w1 above_1819 33683
+    w1 above_1820 33696
+    w1 above_1821 37417
+    w1 above_1822 37931
+    w1 above_1823 35437
+    w1 above_1824 36625
+    w1 above_1825 34328
+    w1 above_1826 39452
+    w1 above_1827 41328
+    w1 above_1828 41333
+    w1 above_1829 39587
+    w1 above_1830 45154
+    w1 above_1831 45095
+    w1 above_1832 44953
+    w1 above_1833 43461
+    w1 above_1834 43872
+    w1 above_1835 43903
+    w1 above_1836 44845
+    w1 above_1837 44906
+    w1 above_1838 46449
+    w1 above_1839 50510
+    w1 above_1840 47757
+    w1 above_1841 53188
+    w1 above_1842 51636
+    w1 above_1843 49766
+    w1 above_1844 51779
+    w1 above_1845 57332
+    w1 above_1846 53842
+    w1 above_1847 52609
+    w1 above_1848 56220
+    w1 above_1849 54749
+    w1 above_1850 60368
+    w1 above_1851 51947
+    w1 above_1852 57871
+    w1 above_1853 57712
+    w1 above_1854 58859
+    w1 above_1855 56566
+    w1 above_1856 62003
+    w1 above_1857 58965
+    w1 above_1858 56141
+    w1 above_1859 66735
+    w1 above_1860 69203
+    w1 above_1861 64844
+    w1 above_1862 68314
+    w1 above_1863 66125
+    w1 above_1864 65977
+    w1 above_1865 66237
+    w1 above_1866 66797
+    w1 above_1867 69841
+    w1 above_1868 72806
+    w1 above_1869 71832
+    w1 above_1870 70728
+    w1 above_1871 79707
+    w1 above_1872 71025
+    w1 above_1873 79031
+    w1 above_1874 73147
+    w1 above_1875 74002
+    w1 above_1876 72090
+    w1 above_1877 81331
+    w1 above_1878 85583
+    w1 above_1879 79118
+    w1 above_1880 81301
+    w1 above_1881 76450
+    w1 above_1882 86013
+    w1 above_1883 79334
+    w1 above_1884 84676
+    w1 above_1885 82792
+    w1 above_1886 88960
+    w1 above_1887 84220
+    w1 above_1888 89382
+    w1 above_1889 76778
+    w1 above_1890 85069
+    w1 above_1891 89501
+    w1 above_1892 95595
+    w1 above_1893 84795
+    w1 above_1894 87560
+    w1 above_1895 100089
+    w1 above_1896 90528
+    w1 above_1897 98766
+    w1 above_1898 98910
+    w1 above_1899 93928
+    w1 above_1900 96872
+    w1 above_1901 93455
+    w1 above_1902 102189
+    w1 above_1903 102786
+    w1 above_1904 92315
+    w1 above_1905 100359
+    w1 above_1906 102932
+    w1 above_1907 101873
+    w1 above_1908 103073
+    w1 above_1909 96325
+    w1 above_1910 106794
+    w1 above_1911 106138
+    w1 above_1912 97065
+    w1 above_1913 101876
+    w1 above_1914 107538
+    w1 above_1915 107953
+    w1 above_1916 107097
+    w1 above_1917 119403
+    w1 above_1918 111938
+    w1 above_1919 114722
+    w1 above_1920 110430
+    w1 above_1921 111681
+    w1 above_1922 110089
+    w1 above_1923 122225
+    w1 above_1924 107572
+    w1 above_1925 108554
+    w1 above_1926 123676
+    w1 above_1927 115506
+    w1 above_1928 107329
+    w1 above_1929 109388
+    w1 above_1930 108633
+    w1 above_1931 107658
+    w1 above_1932 124095
+    w1 above_1933 119031
+    w1 above_1934 123444
+    w1 above_1935 125005
+    w1 above_1936 129170
+    w1 above_1937 117234
+    w1 above_1938 126603
+    w1 above_1939 115190
+    w1 above_1940 128216
+    w1 above_1941 125533
+    w1 above_1942 127632
+    w1 above_1943 123244
+    w1 above_1944 143695
+    w1 above_1945 137559
+    w1 above_1946 126615
+    w1 above_1947 129508
+    w1 above_1948 132512
+    w1 above_1949 121267
+    w1 above_1950 124892
+    w1 above_1951 112248
+    w1 above_1952 133135
+    w1 above_1953 124883
+    w1 above_1954 130014
+    w1 above_1955 126647
+    w1 above_1956 125160
+    w1 above_1957 138794
+    w1 above_1958 125060
+    w1 above_1959 138959
+    w1 above_1960 149001
+    w1 above_1961 144451
+    w1 above_1962 134026
+    w1 above_1963 140081
+    w1 above_1964 141412
+    w1 above_1965 153613
+    w1 above_1966 142228
+    w1 above_1967 158361
+    w1 above_1968 137044
+    w1 above_1969 148331
+    w1 above_1970 141996
+    w1 above_1971 131085
+    w1 above_1972 130959
+    w1 above_1973 144780
+    w1 above_1974 144577
+    w1 above_1975 151063
+    w1 above_1976 148136
+    w1 above_1977 145868
+    w1 above_1978 142767
+    w1 above_1979 147677
+    w1 above_1980 146118
+    w1 above_1981 149150
+    w1 above_1982 155668
+    w1 above_1983 144145
+    w1 above_1984 137361
+    w1 above_1985 154411
+    w1 above_1986 159868
+    w1 above_1987 143458
+    w1 above_1988 126539
+    w1 above_1989 159581
+    w1 above_1990 149498
+    w1 above_1991 159957
+    w1 above_1992 156712
+    w1 above_1993 157550
+    w1 above_1994 160998
+    w1 above_1995 164759
+    w1 above_1996 139715
+    w1 above_1997 173516
+    w1 above_1998 158642
+    w1 above_1999 153635
+    w1 above_2000 143841
+    w1 below_1800 -18573
+    w1 below_1801 -23843
+    w1 below_1802 -23680
+    w1 below_1803 -24216
+    w1 below_1804 -26037
+    w1 below_1805 -24163
+    w1 below_1806 -25417
+    w1 below_1807 -25789
+    w1 below_1808 -26248
+    w1 below_1809 -27208
+    w1 below_1810 -28015
+    w1 below_1811 -28483
+    w1 below_1812 -30553
+    w1 below_1813 -29107
+    w1 below_1814 -31440
+    w1 below_1815 -33059
+    w1 below_1816 -32795
+    w1 below_1817 -33165
+    w1 below_1818 -34089
+    w1 below_1819 -33683
+    w1 below_1820 -33696
+    w1 below_1821 -37417
+    w1 below_1822 -37931
+    w1 below_1823 -35437
+    w1 below_1824 -36625
+    w1 below_1825 -34328
+    w1 below_1826 -39452
+    w1 below_1827 -41328
+    w1 below_1828 -41333
+    w1 below_1829 -39587
+    w1 below_1830 -45154
+    w1 below_1831 -45095
+    w1 below_1832 -44953
+    w1 below_1833 -43461
+    w1 below_1834 -43872
+    w1 below_1835 -43903
+    w1 below_1836 -44845
+    w1 below_1837 -44906
+    w1 below_1838 -46449
+    w1 below_1839 -50510
+    w1 below_1840 -47757
+    w1 below_1841 -53188
+    w1 below_1842 -51636
+    w1 below_1843 -49766
+    w1 below_1844 -51779
+    w1 below_1845 -57332
+    w1 below_1846 -53842
+    w1 below_1847 -52609
+    w1 below_1848 -56220
+    w1 below_1849 -54749
+    w1 below_1850 -60368
+    w1 below_1851 -51947
+    w1 below_1852 -57871
+    w1 below_1853 -57712
+    w1 below_1854 -58859
+    w1 below_1855 -56566
+    w1 below_1856 -62003
+    w1 below_1857 -58965
+    w1 below_1858 -56141
+    w1 below_1859 -66735
+    w1 below_1860 -69203
+    w1 below_1861 -64844
+    w1 below_1862 -68314
+    w1 below_1863 -66125
+    w1 below_1864 -65977
+    w1 below_1865 -66237
+    w1 below_1866 -66797
+    w1 below_1867 -69841
+    w1 below_1868 -72806
+    w1 below_1869 -71832
+    w1 below_1870 -70728
+    w1 below_1871 -79707
+    w1 below_1872 -71025
+    w1 below_1873 -79031
+    w1 below_1874 -73147
+    w1 below_1875 -74002
+    w1 below_1876 -72090
+    w1 below_1877 -81331
+    w1 below_1878 -85583
+    w1 below_1879 -79118
+    w1 below_1880 -81301
+    w1 below_1881 -76450
+    w1 below_1882 -86013
+    w1 below_1883 -79334
+    w1 below_1884 -84676
+    w1 below_1885 -82792
+    w1 below_1886 -88960
+    w1 below_1887 -84220
+    w1 below_1888 -89382
+    w1 below_1889 -76778
+    w1 below_1890 -85069
+    w1 below_1891 -89501
+    w1 below_1892 -95595
+    w1 below_1893 -84795
+    w1 below_1894 -87560
+    w1 below_1895 -100089
+    w1 below_1896 -90528
+    w1 below_1897 -98766
+    w1 below_1898 -98910
+    w1 below_1899 -93928
+    w1 below_1900 -96872
+    w1 below_1901 -93455
+    w1 below_1902 -102189
+    w1 below_1903 -102786
+    w1 below_1904 -92315
+    w1 below_1905 -100359
+    w1 below_1906 -102932
+    w1 below_1907 -101873
+    w1 below_1908 -103073
+    w1 below_1909 -96325
+    w1 below_1910 -106794
+    w1 below_1911 -106138
+    w1 below_1912 -97065
+    w1 below_1913 -101876
+    w1 below_1914 -107538
+    w1 below_1915 -107953
+    w1 below_1916 -107097
+    w1 below_1917 -119403
+    w1 below_1918 -111938
+    w1 below_1919 -114722
+    w1 below_1920 -110430
+    w1 below_1921 -111681
+    w1 below_1922 -110089
+    w1 below_1923 -122225
+    w1 below_1924 -107572
+    w1 below_1925 -108554
+    w1 below_1926 -123676
+    w1 below_1927 -115506
+    w1 below_1928 -107329
+    w1 below_1929 -109388
+    w1 below_1930 -108633
+    w1 below_1931 -107658
+    w1 below_1932 -124095
+    w1 below_1933 -119031
+    w1 below_1934 -123444
+    w1 below_1935 -125005
+    w1 below_1936 -129170
+    w1 below_1937 -117234
+    w1 below_1938 -126603
+    w1 below_1939 -115190
+    w1 below_1940 -128216
+    w1 below_1941 -125533
+    w1 below_1942 -127632
+    w1 below_1943 -123244
+    w1 below_1944 -143695
+    w1 below_1945 -137559
+    w1 below_1946 -126615
+    w1 below_1947 -129508
+    w1 below_1948 -132512
+    w1 below_1949 -121267
+    w1 below_1950 -124892
+    w1 below_1951 -112248
+    w1 below_1952 -133135
+    w1 below_1953 -124883
+    w1 below_1954 -130014
+    w1 below_1955 -126647
+    w1 below_1956 -125160
+    w1 below_1957 -138794
+    w1 below_1958 -125060
+    w1 below_1959 -138959
+    w1 below_1960 -149001
+    w1 below_1961 -144451
+    w1 below_1962 -134026
+    w1 below_1963 -140081
+    w1 below_1964 -141412
+    w1 below_1965 -153613
+    w1 below_1966 -142228
+    w1 below_1967 -158361
+    w1 below_1968 -137044
+    w1 below_1969 -148331
+    w1 below_1970 -141996
+    w1 below_1971 -131085
+    w1 below_1972 -130959
+    w1 below_1973 -144780
+    w1 below_1974 -144577
+    w1 below_1975 -151063
+    w1 below_1976 -148136
+    w1 below_1977 -145868
+    w1 below_1978 -142767
+    w1 below_1979 -147677
+    w1 below_1980 -146118
+    w1 below_1981 -149150
+    w1 below_1982 -155668
+    w1 below_1983 -144145
+    w1 below_1984 -137361
+    w1 below_1985 -154411
+    w1 below_1986 -159868
+    w1 below_1987 -143458
+    w1 below_1988 -126539
+    w1 below_1989 -159581
+    w1 below_1990 -149498
+    w1 below_1991 -159957
+    w1 below_1992 -156712
+    w1 below_1993 -157550
+    w1 below_1994 -160998
+    w1 below_1995 -164759
+    w1 below_1996 -139715
+    w1 below_1997 -173516
+    w1 below_1998 -158642
+    w1 below_1999 -153635
+    w1 below_2000 -143841
+    w4 above_1800 7917
+    w4 above_1801 9065
+    w4 above_1802 9377
+    w4 above_1803 9551
+    w4 above_1804 10053
+    w4 above_1805 10440
+    w4 above_1806 10359
+    w4 above_1807 10094
+    w4 above_1808 10343
+    w4 above_1809 9469
+    w4 above_1810 10863
+    w4 above_1811 10903
+    w4 above_1812 12443
+    w4 above_1813 11467
+    w4 above_1814 13009
+    w4 above_1815 13730
+    w4 above_1816 12985
+    w4 above_1817 12691
+    w4 above_1818 14656
+    w4 above_1819 14156
+    w4 above_1820 13338
+    w4 above_1821 14187
+    w4 above_1822 16343
+    w4 above_1823 16099
+    w4 above_1824 16634
+    w4 above_1825 18067
+    w4 above_1826 16033
+    w4 above_1827 15320
+    w4 above_1828 17366
+    w4 above_1829 18575
+    w4 above_1830 17984
+    w4 above_1831 19469
+    w4 above_1832 17239
+    w4 above_1833 19741
+    w4 above_1834 17845
+    w4 above_1835 20037
+    w4 above_1836 21758
+    w4 above_1837 20052
+    w4 above_1838 22439
+    w4 above_1839 21583
+    w4 above_1840 21849
+    w4 above_1841 21746
+    w4 above_1842 23795
+    w4 above_1843 20466
+    w4 above_1844 24024
+    w4 above_1845 23710
+    w4 above_1846 23009
+    w4 above_1847 23362
+    w4 above_1848 26296
+    w4 above_1849 26699
+    w4 above_1850 26001
+    w4 above_1851 23392
+    w4 above_1852 26634
+    w4 above_1853 26152
+    w4 above_1854 27913
+    w4 above_1855 26370
+    w4 above_1856 28855
+    w4 above_1857 27514
+    w4 above_1858 29440
+    w4 above_1859 28833
+    w4 above_1860 31461
+    w4 above_1861 30257
+    w4 above_1862 30281
+    w4 above_1863 29902
+    w4 above_1864 31293
+    w4 above_1865 32930
+    w4 above_1866 33948
+    w4 above_1867 32716
+    w4 above_1868 35323
+    w4 above_1869 31724
+    w4 above_1870 33468
+    w4 above_1871 33275
+    w4 above_1872 34624
+    w4 above_1873 34540
+    w4 above_1874 34820
+    w4 above_1875 39673
+    w4 above_1876 35286
+    w4 above_1877 35648
+    w4 above_1878 35242
+    w4 above_1879 38505
+    w4 above_1880 38822
+    w4 above_1881 39710
+    w4 above_1882 38641
+    w4 above_1883 42035
+    w4 above_1884 40831
+    w4 above_1885 40115
+    w4 above_1886 37734
+    w4 above_1887 39591
+    w4 above_1888 42433
+    w4 above_1889 41108
+    w4 above_1890 46949
+    w4 above_1891 43900
+    w4 above_1892 45152
+    w4 above_1893 44633
+    w4 above_1894 46796
+    w4 above_1895 42417
+    w4 above_1896 47046
+    w4 above_1897 46724
+    w4 above_1898 42486
+    w4 above_1899 47996
+    w4 above_1900 46711
+    w4 above_1901 46386
+    w4 above_1902 43882
+    w4 above_1903 47971
+    w4 above_1904 46358
+    w4 above_1905 46617
+    w4 above_1906 48314
+    w4 above_1907 48604
+    w4 above_1908 49544
+    w4 above_1909 49817
+    w4 above_1910 51785
+    w4 above_1911 45980
+    w4 above_1912 49511
+    w4 above_1913 52273
+    w4 above_1914 53023
+    w4 above_1915 49269
+    w4 above_1916 48807
+    w4 above_1917 50586
+    w4 above_1918 52719
+    w4 above_1919 50524
+    w4 above_1920 50009
+    w4 above_1921 53414
+    w4 above_1922 56076
+    w4 above_1923 53550
+    w4 above_1924 51678
+    w4 above_1925 56605
+    w4 above_1926 55356
+    w4 above_1927 60709
+    w4 above_1928 58814
+    w4 above_1929 56992
+    w4 above_1930 51475
+    w4 above_1931 56522
+    w4 above_1932 56596
+    w4 above_1933 55317
+    w4 above_1934 60639
+    w4 above_1935 58705
+    w4 above_1936 56243
+    w4 above_1937 62470
+    w4 above_1938 54535
+    w4 above_1939 61040
+    w4 above_1940 62927
+    w4 above_1941 59535
+    w4 above_1942 60127
+    w4 above_1943 65629
+    w4 above_1944 65256
+    w4 above_1945 60881
+    w4 above_1946 59047
+    w4 above_1947 62327
+    w4 above_1948 59374
+    w4 above_1949 66892
+    w4 above_1950 58216
+    w4 above_1951 69079
+    w4 above_1952 60453
+    w4 above_1953 56358
+    w4 above_1954 55532
+    w4 above_1955 60489
+    w4 above_1956 58010
+    w4 above_1957 61622
+    w4 above_1958 62600
+    w4 above_1959 64008
+    w4 above_1960 68203
+    w4 above_1961 64892
+    w4 above_1962 65766
+    w4 above_1963 63878
+    w4 above_1964 67223
+    w4 above_1965 68984
+    w4 above_1966 68156
+    w4 above_1967 63009
+    w4 above_1968 72838
+    w4 above_1969 61614
+    w4 above_1970 68336
+    w4 above_1971 64893
+    w4 above_1972 70715
+    w4 above_1973 68317
+    w4 above_1974 69678
+    w4 above_1975 66842
+    w4 above_1976 73173
+    w4 above_1977 68075
+    w4 above_1978 73109
+    w4 above_1979 65032
+    w4 above_1980 72301
+    w4 above_1981 66514
+    w4 above_1982 71141
+    w4 above_1983 64256
+    w4 above_1984 64574
+    w4 above_1985 65796
+    w4 above_1986 67171
+    w4 above_1987 67300
+    w4 above_1988 69657
+    w4 above_1989 69079
+    w4 above_1990 69377
+    w4 above_1991 65907
+    w4 above_1992 70537
+    w4 above_1993 66157
+    w4 above_1994 70212
+    w4 above_1995 71963
+    w4 above_1996 67711
+    w4 above_1997 66615
+    w4 above_1998 77656
+    w4 above_1999 69271
+    w4 above_2000 74697
+    w4 below_1800 -7917
+    w4 below_1801 -9065
+    w4 below_1802 -9377
+    w4 below_1803 -9551
+    w4 below_1804 -10053
+    w4 below_1805 -10440
+    w4 below_1806 -10359
+    w4 below_1807 -10094
+    w4 below_1808 -10343
+    w4 below_1809 -9469
+    w4 below_1810 -10863
+    w4 below_1811 -10903
+    w4 below_1812 -12443
+    w4 below_1813 -11467
+    w4 below_1814 -13009
+    w4 below_1815 -13730
+    w4 below_1816 -12985
+    w4 below_1817 -12691
+    w4 below_1818 -14656
+    w4 below_1819 -14156
+    w4 below_1820 -13338
+    w4 below_1821 -14187
+    w4 below_1822 -16343
+    w4 below_1823 -16099
+    w4 below_1824 -16634
+    w4 below_1825 -18067
+    w4 below_1826 -16033
+    w4 below_1827 -15320
+    w4 below_1828 -17366
+    w4 below_1829 -18575
+    w4 below_1830 -17984
+    w4 below_1831 -19469
+    w4 below_1832 -17239
+    w4 below_1833 -19741
+    w4 below_1834 -17845
+    w4 below_1835 -20037
+    w4 below_1836 -21758
+    w4 below_1837 -20052
+    w4 below_1838 -22439
+    w4 below_1839 -21583
+    w4 below_1840 -21849
+    w4 below_1841 -21746
+    w4 below_1842 -23795
+    w4 below_1843 -20466
+    w4 below_1844 -24024
+    w4 below_1845 -23710
+    w4 below_1846 -23009
+    w4 below_1847 -23362
+    w4 below_1848 -26296
+    w4 below_1849 -26699
+    w4 below_1850 -26001
+    w4 below_1851 -23392
+    w4 below_1852 -26634
+    w4 below_1853 -26152
+    w4 below_1854 -27913
+    w4 below_1855 -26370
+    w4 below_1856 -28855
+    w4 below_1857 -27514
+    w4 below_1858 -29440
+    w4 below_1859 -28833
+    w4 below_1860 -31461
+    w4 below_1861 -30257
+    w4 below_1862 -30281
+    w4 below_1863 -29902
+    w4 below_1864 -31293
+    w4 below_1865 -32930
+    w4 below_1866 -33948
+    w4 below_1867 -32716
+    w4 below_1868 -35323
+    w4 below_1869 -31724
+    w4 below_1870 -33468
+    w4 below_1871 -33275
+    w4 below_1872 -34624
+    w4 below_1873 -34540
+    w4 below_1874 -34820
+    w4 below_1875 -39673
+    w4 below_1876 -35286
+    w4 below_1877 -35648
+    w4 below_1878 -35242
+    w4 below_1879 -38505
+    w4 below_1880 -38822
+    w4 below_1881 -39710
+    w4 below_1882 -38641
+    w4 below_1883 -42035
+    w4 below_1884 -40831
+    w4 below_1885 -40115
+    w4 below_1886 -37734
+    w4 below_1887 -39591
+    w4 below_1888 -42433
+    w4 below_1889 -41108
+    w4 below_1890 -46949
+    w4 below_1891 -43900
+    w4 below_1892 -45152
+    w4 below_1893 -44633
+    w4 below_1894 -46796
+    w4 below_1895 -42417
+    w4 below_1896 -47046
+    w4 below_1897 -46724
+    w4 below_1898 -42486
+    w4 below_1899 -47996
+    w4 below_1900 -46711
+    w4 below_1901 -46386
+    w4 below_1902 -43882
+    w4 below_1903 -47971
+    w4 below_1904 -46358
+    w4 below_1905 -46617
+    w4 below_1906 -48314
+    w4 below_1907 -48604
+    w4 below_1908 -49544
+    w4 below_1909 -49817
+    w4 below_1910 -51785
+    w4 below_1911 -45980
+    w4 below_1912 -49511
+    w4 below_1913 -52273
+    w4 below_1914 -53023
+    w4 below_1915 -49269
+    w4 below_1916 -48807
+    w4 below_1917 -50586
+    w4 below_1918 -52719
+    w4 below_1919 -50524
+    w4 below_1920 -50009
+    w4 below_1921 -53414
+    w4 below_1922 -56076
+    w4 below_1923 -53550
+    w4 below_1924 -51678
+    w4 below_1925 -56605
+    w4 below_1926 -55356
+    w4 below_1927 -60709
+    w4 below_1928 -58814
+    w4 below_1929 -56992
+    w4 below_1930 -51475
+    w4 below_1931 -56522
+    w4 below_1932 -56596
+    w4 below_1933 -55317
+    w4 below_1934 -60639
+    w4 below_1935 -58705
+    w4 below_1936 -56243
+    w4 below_1937 -62470
+    w4 below_1938 -54535
+    w4 below_1939 -61040
+    w4 below_1940 -62927
+    w4 below_1941 -59535
+    w4 below_1942 -60127
+    w4 below_1943 -65629
+    w4 below_1944 -65256
+    w4 below_1945 -60881
+    w4 below_1946 -59047
+    w4 below_1947 -62327
+    w4 below_1948 -59374
+    w4 below_1949 -66892
+    w4 below_1950 -58216
+    w4 below_1951 -69079
+    w4 below_1952 -60453
+    w4 below_1953 -56358
+    w4 below_1954 -55532
+    w4 below_1955 -60489
+    w4 below_1956 -58010
+    w4 below_1957 -61622
+    w4 below_1958 -62600
+    w4 below_1959 -64008
+    w4 below_1960 -68203
+    w4 below_1961 -64892
+    w4 below_1962 -65766
+    w4 below_1963 -63878
+    w4 below_1964 -67223
+    w4 below_1965 -68984
+    w4 below_1966 -68156
+    w4 below_1967 -63009
+    w4 below_1968 -72838
+    w4 below_1969 -61614
+    w4 below_1970 -68336
+    w4 below_1971 -64893
+    w4 below_1972 -70715
+    w4 below_1973 -68317
+    w4 below_1974 -69678
+    w4 below_1975 -66842
+    w4 below_1976 -73173
+    w4 below_1977 -68075
+    w4 below_1978 -73109
+    w4 below_1979 -65032
+    w4 below_1980 -72301
+    w4 below_1981 -66514
+    w4 below_1982 -71141
+    w4 below_1983 -64256
+    w4 below_1984 -64574
+    w4 below_1985 -65796
+    w4 below_1986 -67171
+    w4 below_1987 -67300
+    w4 below_1988 -69657
+    w4 below_1989 -69079
+    w4 below_1990 -69377
+    w4 below_1991 -65907
+    w4 below_1992 -70537
+    w4 below_1993 -66157
+    w4 below_1994 -70212
+    w4 below_1995 -71963
+    w4 below_1996 -67711
+    w4 below_1997 -66615
+    w4 below_1998 -77656
+    w4 below_1999 -69271
+    w4 below_2000 -74697
+    w1_w4 above_1800 271
+    w1_w4 above_1801 265
+    w1_w4 above_1802 259
+    w1_w4 above_1803 277
+    w1_w4 above_1804 278
+    w1_w4 above_1805 304
+    w1_w4 above_1806 314
+    w1_w4 above_1807 303
+    w1_w4 above_1808 321
+    w1_w4 above_1809 327
+    w1_w4 above_1810 332
+    w1_w4 above_1811 328
+    w1_w4 above_1812 370
+    w1_w4 above_1813 333
+    w1_w4 above_1814 324
+    w1_w4 above_1815 338
+    w1_w4 above_1816 381
+    w1_w4 above_1817 402
+    w1_w4 above_1818 415
+    w1_w4 above_1819 410
+    w1_w4 above_1820 445
+    w1_w4 above_1821 446
+    w1_w4 above_1822 475
+    w1_w4 above_1823 450
+    w1_w4 above_1824 447
+    w1_w4 above_1825 487
+    w1_w4 above_1826 472
+    w1_w4 above_1827 416
+    w1_w4 above_1828 506
+    w1_w4 above_1829 484
+    w1_w4 above_1830 512
+    w1_w4 above_1831 563
+    w1_w4 above_1832 517
+    w1_w4 above_1833 576
+    w1_w4 above_1834 548
+    w1_w4 above_1835 566
+    w1_w4 above_1836 640
+    w1_w4 above_1837 642
+    w1_w4 above_1838 603
+    w1_w4 above_1839 658
+    w1_w4 above_1840 650
+    w1_w4 above_1841 599
+    w1_w4 above_1842 623
+    w1_w4 above_1843 699
+    w1_w4 above_1844 693
+    w1_w4 above_1845 737
+    w1_w4 above_1846 715
+    w1_w4 above_1847 717
+    w1_w4 above_1848 783
+    w1_w4 above_1849 727
+    w1_w4 above_1850 718
+    w1_w4 above_1851 778
+    w1_w4 above_1852 739
+    w1_w4 above_1853 817
+    w1_w4 above_1854 775
+    w1_w4 above_1855 820
+    w1_w4 above_1856 871
+    w1_w4 above_1857 807
+    w1_w4 above_1858 824
+    w1_w4 above_1859 898
+    w1_w4 above_1860 923
+    w1_w4 above_1861 950
+    w1_w4 above_1862 898
+    w1_w4 above_1863 934
+    w1_w4 above_1864 930
+    w1_w4 above_1865 978
+    w1_w4 above_1866 954
+    w1_w4 above_1867 1007
+    w1_w4 above_1868 990
+    w1_w4 above_1869 919
+    w1_w4 above_1870 927
+    w1_w4 above_1871 1078
+    w1_w4 above_1872 1024
+    w1_w4 above_1873 1049
+    w1_w4 above_1874 979
+    w1_w4 above_1875 1148
+    w1_w4 above_1876 1131
+    w1_w4 above_1877 1154
+    w1_w4 above_1878 1072
+    w1_w4 above_1879 1091
+    w1_w4 above_1880 1185
+    w1_w4 above_1881 1134
+    w1_w4 above_1882 1205
+    w1_w4 above_1883 1220
+    w1_w4 above_1884 1231
+    w1_w4 above_1885 1223
+    w1_w4 above_1886 1005
+    w1_w4 above_1887 1178
+    w1_w4 above_1888 1251
+    w1_w4 above_1889 1137
+    w1_w4 above_1890 1309
+    w1_w4 above_1891 1374
+    w1_w4 above_1892 1231
+    w1_w4 above_1893 1307
+    w1_w4 above_1894 1316
+    w1_w4 above_1895 1315
+    w1_w4 above_1896 1314
+    w1_w4 above_1897 1449
+    w1_w4 above_1898 1362
+    w1_w4 above_1899 1287
+    w1_w4 above_1900 1328
+    w1_w4 above_1901 1391
+    w1_w4 above_1902 1449
+    w1_w4 above_1903 1566
+    w1_w4 above_1904 1442
+    w1_w4 above_1905 1460
+    w1_w4 above_1906 1607
+    w1_w4 above_1907 1568
+    w1_w4 above_1908 1551
+    w1_w4 above_1909 1511
+    w1_w4 above_1910 1559
+    w1_w4 above_1911 1588
+    w1_w4 above_1912 1616
+    w1_w4 above_1913 1701
+    w1_w4 above_1914 1695
+    w1_w4 above_1915 1728
+    w1_w4 above_1916 1862
+    w1_w4 above_1917 1775
+    w1_w4 above_1918 1930
+    w1_w4 above_1919 1773
+    w1_w4 above_1920 1834
+    w1_w4 above_1921 1875
+    w1_w4 above_1922 1760
+    w1_w4 above_1923 1761
+    w1_w4 above_1924 1873
+    w1_w4 above_1925 1697
+    w1_w4 above_1926 1894
+    w1_w4 above_1927 1928
+    w1_w4 above_1928 1977
+    w1_w4 above_1929 1783
+    w1_w4 above_1930 1797
+    w1_w4 above_1931 1985
+    w1_w4 above_1932 1958
+    w1_w4 above_1933 1939
+    w1_w4 above_1934 1919
+    w1_w4 above_1935 2177
+    w1_w4 above_1936 2055
+    w1_w4 above_1937 2138
+    w1_w4 above_1938 2023
+    w1_w4 above_1939 2006
+    w1_w4 above_1940 2162
+    w1_w4 above_1941 2176
+    w1_w4 above_1942 2112
+    w1_w4 above_1943 1950
+    w1_w4 above_1944 2107
+    w1_w4 above_1945 2011
+    w1_w4 above_1946 2257
+    w1_w4 above_1947 2077
+    w1_w4 above_1948 2042
+    w1_w4 above_1949 2134
+    w1_w4 above_1950 2073
+    w1_w4 above_1951 2171
+    w1_w4 above_1952 2122
+    w1_w4 above_1953 2461
+    w1_w4 above_1954 2360
+    w1_w4 above_1955 2435
+    w1_w4 above_1956 2277
+    w1_w4 above_1957 2275
+    w1_w4 above_1958 2434
+    w1_w4 above_1959 2348
+    w1_w4 above_1960 2312
+    w1_w4 above_1961 2169
+    w1_w4 above_1962 2469
+    w1_w4 above_1963 2415
+    w1_w4 above_1964 2320
+    w1_w4 above_1965 2089
+    w1_w4 above_1966 2370
+    w1_w4 above_1967 2358
+    w1_w4 above_1968 2416
+    w1_w4 above_1969 2277
+    w1_w4 above_1970 2295
+    w1_w4 above_1971 2432
+    w1_w4 above_1972 2252
+    w1_w4 above_1973 2490
+    w1_w4 above_1974 2152
+    w1_w4 above_1975 2355
+    w1_w4 above_1976 2426
+    w1_w4 above_1977 2608
+    w1_w4 above_1978 2352
+    w1_w4 above_1979 2429
+    w1_w4 above_1980 2443
+    w1_w4 above_1981 2449
+    w1_w4 above_1982 2433
+    w1_w4 above_1983 2496
+    w1_w4 above_1984 2543
+    w1_w4 above_1985 2398
+    w1_w4 above_1986 2814
+    w1_w4 above_1987 2434
+    w1_w4 above_1988 2795
+    w1_w4 above_1989 2562
+    w1_w4 above_1990 2632
+    w1_w4 above_1991 2525
+    w1_w4 above_1992 2864
+    w1_w4 above_1993 2606
+    w1_w4 above_1994 2451
+    w1_w4 above_1995 2605
+    w1_w4 above_1996 2838
+    w1_w4 above_1997 2507
+    w1_w4 above_1998 2703
+    w1_w4 above_1999 2703
+    w1_w4 above_2000 2775
+    w1_w4 below_1800 -271
+    w1_w4 below_1801 -265
+    w1_w4 below_1802 -259
+    w1_w4 below_1803 -277
+    w1_w4 below_1804 -278
+    w1_w4 below_1805 -304
+    w1_w4 below_1806 -314
+    w1_w4 below_1807 -303
+    w1_w4 below_1808 -321
+    w1_w4 below_1809 -327
+    w1_w4 below_1810 -332
+    w1_w4 below_1811 -328
+    w1_w4 below_1812 -370
+    w1_w4 below_1813 -333
+    w1_w4 below_1814 -324
+    w1_w4 below_1815 -338
+    w1_w4 below_1816 -381
+    w1_w4 below_1817 -402
+    w1_w4 below_1818 -415
+    w1_w4 below_1819 -410
+    w1_w4 below_1820 -445
+    w1_w4 below_1821 -446
+    w1_w4 below_1822 -475
+    w1_w4 below_1823 -450
+    w1_w4 below_1824 -447
+    w1_w4 below_1825 -487
+    w1_w4 below_1826 -472
+    w1_w4 below_1827 -416
+    w1_w4 below_1828 -506
+    w1_w4 below_1829 -484
+    w1_w4 below_1830 -512
+    w1_w4 below_1831 -563
+    w1_w4 below_1832 -517
+    w1_w4 below_1833 -576
+    w1_w4 below_1834 -548
+    w1_w4 below_1835 -566
+    w1_w4 below_1836 -640
+    w1_w4 below_1837 -642
+    w1_w4 below_1838 -603
+    w1_w4 below_1839 -658
+    w1_w4 below_1840 -650
+    w1_w4 below_1841 -599
+    w1_w4 below_1842 -623
+    w1_w4 below_1843 -699
+    w1_w4 below_1844 -693
+    w1_w4 below_1845 -737
+    w1_w4 below_1846 -715
+    w1_w4 below_1847 -717
+    w1_w4 below_1848 -783
+    w1_w4 below_1849 -727
+    w1_w4 below_1850 -718
+    w1_w4 below_1851 -778
+    w1_w4 below_1852 -739
+    w1_w4 below_1853 -817
+    w1_w4 below_1854 -775
+    w1_w4 below_1855 -820
+    w1_w4 below_1856 -871
+    w1_w4 below_1857 -807
+    w1_w4 below_1858 -824
+    w1_w4 below_1859 -898
+    w1_w4 below_1860 -923
+    w1_w4 below_1861 -950
+    w1_w4 below_1862 -898
+    w1_w4 below_1863 -934
+    w1_w4 below_1864 -930
+    w1_w4 below_1865 -978
+    w1_w4 below_1866 -954
+    w1_w4 below_1867 -1007
+    w1_w4 below_1868 -990
+    w1_w4 below_1869 -919
+    w1_w4 below_1870 -927
+    w1_w4 below_1871 -1078
+    w1_w4 below_1872 -1024
+    w1_w4 below_1873 -1049
+    w1_w4 below_1874 -979
+    w1_w4 below_1875 -1148
+    w1_w4 below_1876 -1131
+    w1_w4 below_1877 -1154
+    w1_w4 below_1878 -1072
+    w1_w4 below_1879 -1091
+    w1_w4 below_1880 -1185
+    w1_w4 below_1881 -1134
+    w1_w4 below_1882 -1205
+    w1_w4 below_1883 -1220
+    w1_w4 below_1884 -1231
+    w1_w4 below_1885 -1223
+    w1_w4 below_1886 -1005
+    w1_w4 below_1887 -1178
+    w1_w4 below_1888 -1251
+    w1_w4 below_1889 -1137
+    w1_w4 below_1890 -1309
+    w1_w4 below_1891 -1374
+    w1_w4 below_1892 -1231
+    w1_w4 below_1893 -1307
+    w1_w4 below_1894 -1316
+    w1_w4 below_1895 -1315
+    w1_w4 below_1896 -1314
+    w1_w4 below_1897 -1449
+    w1_w4 below_1898 -1362
+    w1_w4 below_1899 -1287
+    w1_w4 below_1900 -1328
+    w1_w4 below_1901 -1391
+    w1_w4 below_1902 -1449
+    w1_w4 below_1903 -1566
+    w1_w4 below_1904 -1442
+    w1_w4 below_1905 -1460
+    w1_w4 below_1906 -1607
+    w1_w4 below_1907 -1568
+    w1_w4 below_1908 -1551
+    w1_w4 below_1909 -1511
+    w1_w4 below_1910 -1559
+    w1_w4 below_1911 -1588
+    w1_w4 below_1912 -1616
+    w1_w4 below_1913 -1701
+    w1_w4 below_1914 -1695
+    w1_w4 below_1915 -1728
+    w1_w4 below_1916 -1862
+    w1_w4 below_1917 -1775
+    w1_w4 below_1918 -1930
+    w1_w4 below_1919 -1773
+    w1_w4 below_1920 -1834
+    w1_w4 below_1921 -1875
+    w1_w4 below_1922 -1760
+    w1_w4 below_1923 -1761
+    w1_w4 below_1924 -1873
+    w1_w4 below_1925 -1697
+    w1_w4 below_1926 -1894
+    w1_w4 below_1927 -1928
+    w1_w4 below_1928 -1977
+    w1_w4 below_1929 -1783
+    w1_w4 below_1930 -1797
+    w1_w4 below_1931 -1985
+    w1_w4 below_1932 -1958
+    w1_w4 below_1933 -1939
+    w1_w4 below_1934 -1919
+    w1_w4 below_1935 -2177
+    w1_w4 below_1936 -2055
+    w1_w4 below_1937 -2138
+    w1_w4 below_1938 -2023
+    w1_w4 below_1939 -2006
+    w1_w4 below_1940 -2162
+    w1_w4 below_1941 -2176
+    w1_w4 below_1942 -2112
+    w1_w4 below_1943 -1950
+    w1_w4 below_1944 -2107
+    w1_w4 below_1945 -2011
+    w1_w4 below_1946 -2257
+    w1_w4 below_1947 -2077
+    w1_w4 below_1948 -2042
+    w1_w4 below_1949 -2134
+    w1_w4 below_1950 -2073
+    w1_w4 below_1951 -2171
+    w1_w4 below_1952 -2122
+    w1_w4 below_1953 -2461
+    w1_w4 below_1954 -2360
+    w1_w4 below_1955 -2435
+    w1_w4 below_1956 -2277
+    w1_w4 below_1957 -2275
+    w1_w4 below_1958 -2434
+    w1_w4 below_1959 -2348
+    w1_w4 below_1960 -2312
+    w1_w4 below_1961 -2169
+    w1_w4 below_1962 -2469
+    w1_w4 below_1963 -2415
+    w1_w4 below_1964 -2320
+    w1_w4 below_1965 -2089
+    w1_w4 below_1966 -2370
+    w1_w4 below_1967 -2358
+    w1_w4 below_1968 -2416
+    w1_w4 below_1969 -2277
+    w1_w4 below_1970 -2295
+    w1_w4 below_1971 -2432
+    w1_w4 below_1972 -2252
+    w1_w4 below_1973 -2490
+    w1_w4 below_1974 -2152
+    w1_w4 below_1975 -2355
+    w1_w4 below_1976 -2426
+    w1_w4 below_1977 -2608
+    w1_w4 below_1978 -2352
+    w1_w4 below_1979 -2429
+    w1_w4 below_1980 -2443
+    w1_w4 below_1981 -2449
+    w1_w4 below_1982 -2433
+    w1_w4 below_1983 -2496
+    w1_w4 below_1984 -2543
+    w1_w4 below_1985 -2398
+    w1_w4 below_1986 -2814
+    w1_w4 below_1987 -2434
+    w1_w4 below_1988 -2795
+    w1_w4 below_1989 -2562
+    w1_w4 below_1990 -2632
+    w1_w4 below_1991 -2525
+    w1_w4 below_1992 -2864
+    w1_w4 below_1993 -2606
+    w1_w4 below_1994 -2451
+    w1_w4 below_1995 -2605
+    w1_w4 below_1996 -2838
+    w1_w4 below_1997 -2507
+    w1_w4 below_1998 -2703
+    w1_w4 below_1999 -2703
+    w1_w4 below_2000 -2775
+    __t__ obj 1
+    __t__ above_1800 -1
+    __t__ above_1801 -1
+    __t__ above_1802 -1
+    __t__ above_1803 -1
+    __t__ above_1804 -1
+    __t__ above_1805 -1
+    __t__ above_1806 -1
+    __t__ above_1807 -1
+    __t__ above_1808 -1
+    __t__ above_1809 -1
+    __t__ above_1810 -1
+    __t__ above_1811 -1
+    __t__ above_1812 -1
+    __t__ above_1813 -1
+    __t__ above_1814 -1
+    __t__ above_1815 -1
+    __t__ above_1816 -1
+    __t__ above_1817 -1
+    __t__ above_1818 -1
+    __t__ above_1819 -1
+    __t__ above_1820 -1
+    __t__ above_1821 -1
+    __t__ above_1822 -1
+    __t__ above_1823 -1
+    __t__ above_1824 -1
+    __t__ above_1825 -1
+    __t__ above_1826 -1
+    __t__ above_1827 -1
+    __t__ above_1828 -1
+    __t__ above_1829 -1
+    __t__ above_1830 -1
+    __t__ above_1831 -1
+    __t__ above_1832 -1
+    __t__ above_1833 -1
+    __t__ above_1834 -1
+    __t__ above_1835 -1
+    __t__ above_1836 -1
+    __t__ above_1837 -1
+    __t__ above_1838 -1
+    __t__ above_1839 -1
+    __t__ above_1840 -1
+    __t__ above_1841 -1
+    __t__ above_1842 -1
+    __t__ above_1843 -1
+    __t__ above_1844 -1
+    __t__ above_1845 -1
+    __t__ above_1846 -1
+    __t__ above_1847 -1
+    __t__ above_1848 -1
+    __t__ above_1849 -1
+    __t__ above_1850 -1
+    __t__ above_1851 -1
+    __t__ above_1852 -1
+    __t__ above_1853 -1
+    __t__ above_1854 -1
+    __t__ above_1855 -1
+    __t__ above_1856 -1
+    __t__ above_1857 -1
+    __t__ above_1858 -1
+    __t__ above_1859 -1
+    __t__ above_1860 -1
+    __t__ above_1861 -1
+    __t__ above_1862 -1
+    __t__ above_1863 -1
+    __t__ above_1864 -1
+    __t__ above_1865 -1
+    __t__ above_1866 -1
+    __t__ above_1867 -1
+    __t__ above_1868 -1
+    __t__ above_1869 -1
+    __t__ above_1870 -1
+    __t__ above_1871 -1
+    __t__ above_1872 -1
+    __t__ above_1873 -1
+    __t__ above_1874 -1
+    __t__ above_1875 -1
+    __t__ above_1876 -1
+    __t__ above_1877 -1
+    __t__ above_1878 -1
+    __t__ above_1879 -1
+    __t__ above_1880 -1
+    __t__ above_1881 -1
+    __t__ above_1882 -1
+    __t__ above_1883 -1
+    __t__ above_1884 -1
+    __t__ above_1885 -1
+    __t__ above_1886 -1
+    __t__ above_1887 -1
+    __t__ above_1888 -1
+    __t__ above_1889 -1
+    __t__ above_1890 -1
+    __t__ above_1891 -1
+    __t__ above_1892 -1
+    __t__ above_1893 -1
+    __t__ above_1894 -1
+    __t__ above_1895 -1
+    __t__ above_1896 -1
+    __t__ above_1897 -1
+    __t__ above_1898 -1
+    __t__ above_1899 -1
+    __t__ above_1900 -1
+    __t__ above_1901 -1
+    __t__ above_1902 -1
+    __t__ above_1903 -1
+    __t__ above_1904 -1
+    __t__ above_1905 -1
+    __t__ above_1906 -1
+    __t__ above_1907 -1
+    __t__ above_1908 -1
+    __t__ above_1909 -1
+    __t__ above_1910 -1
+    __t__ above_1911 -1
+    __t__ above_1912 -1
+    __t__ above_1913 -1
+    __t__ above_1914 -1
+    __t__ above_1915 -1
+    __t__ above_1916 -1
+    __t__ above_1917 -1
+    __t__ above_1918 -1
+    __t__ above_1919 -1
+    __t__ above_1920 -1
+    __t__ above_1921 -1
+    __t__ above_1922 -1
+    __t__ above_1923 -1
+    __t__ above_1924 -1
+    __t__ above_1925 -1
+    __t__ above_1926 -1
+    __t__ above_1927 -1
+    __t__ above_1928 -1
+    __t__ above_1929 -1
+    __t__ above_1930 -1
+    __t__ above_1931 -1
+    __t__ above_1932 -1
+    __t__ above_1933 -1
+    __t__ above_1934 -1
+    __t__ above_1935 -1
+    __t__ above_1936 -1
+    __t__ above_1937 -1
+    __t__ above_1938 -1
+    __t__ above_1939 -1
+    __t__ above_1940 -1
+    __t__ above_1941 -1
+    __t__ above_1942 -1
+    __t__ above_1943 -1
+    __t__ above_1944 -1
+    __t__ above_1945 -1
+    __t__ above_1946 -1
+    __t__ above_1947 -1
+    __t__ above_1948 -1
+    __t__ above_1949 -1
+    __t__ above_1950 -1
+    __t__ above_1951 -1
+    __t__ above_1952 -1
+    __t__ above_1953 -1
+    __t__ above_1954 -1
+    __t__ above_1955 -1
+    __t__ above_1956 -1
+    __t__ above_1957 -1
+    __t__ above_1958 -1
+    __t__ above_1959 -1
+    __t__ above_1960 -1
+    __t__ above_1961 -1
+    __t__ above_1962 -1
+    __t__ above_1963 -1
+    __t__ above_1964 -1
+    __t__ above_1965 -1
+    __t__ above_1966 -1
+    __t__ above_1967 -1
+    __t__ above_1968 -1
+    __t__ above_1969 -1
+    __t__ above_1970 -1
+    __t__ above_1971 -1
+    __t__ above_1972 -1
+    __t__ above_1973 -1
+    __t__ above_1974 -1
+    __t__ above_1975 -1
+    __t__ above_1976 -1
+    __t__ above_1977 -1
+    __t__ above_1978 -1
+    __t__ above_1979 -1
+    __t__ above_1980 -1
+    __t__ above_1981 -1
+    __t__ above_1982 -1
+    __t__ above_1983 -1
+    __t__ above_1984 -1
+    __t__ above_1985 -1
+    __t__ above_1986 -1
+    __t__ above_1987 -1
+    __t__ above_1988 -1
+    __t__ above_1989 -1
+    __t__ above_1990 -1
+    __t__ above_1991 -1
+    __t__ above_1992 -1
+    __t__ above_1993 -1
+    __t__ above_1994 -1
+    __t__ above_1995 -1
+    __t__ above_1996 -1
+    __t__ above_1997 -1
+    __t__ above_1998 -1
+    __t__ above_1999 -1
+    __t__ above_2000 -1
+    __t__ below_1800 -1
+    __t__ below_1801 -1
+    __t__ below_1802 -1
+    __t__ below_1803 -1
+    __t__ below_1804 -1
+    __t__ below_1805 -1
+    __t__ below_1806 -1
+    __t__ below_1807 -1
+    __t__ below_1808 -1
+    __t__ below_1809 -1
+    __t__ below_1810 -1
+    __t__ below_1811 -1
+    __t__ below_1812 -1
+    __t__ below_1813 -1
+    __t__ below_1814 -1
+    __t__ below_1815 -1
+    __t__ below_1816 -1
+    __t__ below_1817 -1
+    __t__ below_1818 -1
+    __t__ below_1819 -1
+    __t__ below_1820 -1
+    __t__ below_1821 -1
+    __t__ below_1822 -1
+    __t__ below_1823 -1
+    __t__ below_1824 -1
+    __t__ below_1825 -1
+    __t__ below_1826 -1
+    __t__ below_1827 -1
+    __t__ below_1828 -1
+    __t__ below_1829 -1
+    __t__ below_1830 -1
+    __t__ below_1831 -1
+    __t__ below_1832 -1
+    __t__ below_1833 -1
+    __t__ below_1834 -1
+    __t__ below_1835 -1
+    __t__ below_1836 -1
+    __t__ below_1837 -1
+    __t__ below_1838 -1
+    __t__ below_1839 -1
+    __t__ below_1840 -1
+    __t__ below_1841 -1
+    __t__ below_1842 -1
+    __t__ below_1843 -1
+    __t__ below_1844 -1
+    __t__ below_1845 -1
+    __t__ below_1846 -1
+    __t__ below_1847 -1
+    __t__ below_1848 -1
+    __t__ below_1849 -1
+    __t__ below_1850 -1
+    __t__ below_1851 -1
+    __t__ below_1852 -1
+    __t__ below_1853 -1
+    __t__ below_1854 -1
+    __t__ below_1855 -1
+    __t__ below_1856 -1
+    __t__ below_1857 -1
+    __t__ below_1858 -1
+    __t__ below_1859 -1
+    __t__ below_1860 -1
+    __t__ below_1861 -1
+    __t__ below_1862 -1
+    __t__ below_1863 -1
+    __t__ below_1864 -1
+    __t__ below_1865 -1
+    __t__ below_1866 -1
+    __t__ below_1867 -1
+    __t__ below_1868 -1
+    __t__ below_1869 -1
+    __t__ below_1870 -1
+    __t__ below_1871 -1
+    __t__ below_1872 -1
+    __t__ below_1873 -1
+    __t__ below_1874 -1
+    __t__ below_1875 -1
+    __t__ below_1876 -1
+    __t__ below_1877 -1
+    __t__ below_1878 -1
+    __t__ below_1879 -1
+    __t__ below_1880 -1
+    __t__ below_1881 -1
+    __t__ below_1882 -1
+    __t__ below_1883 -1
+    __t__ below_1884 -1
+    __t__ below_1885 -1
+    __t__ below_1886 -1
+    __t__ below_1887 -1
+    __t__ below_1888 -1
+    __t__ below_1889 -1
+    __t__ below_1890 -1
+    __t__ below_1891 -1
+    __t__ below_1892 -1
+    __t__ below_1893 -1
+    __t__ below_1894 -1
+    __t__ below_1895 -1
+    __t__ below_1896 -1
+    __t__ below_1897 -1
+    __t__ below_1898 -1
+    __t__ below_1899 -1
+    __t__ below_1900 -1
+    __t__ below_1901 -1
+    __t__ below_1902 -1
+    __t__ below_1903 -1
+    __t__ below_1904 -1
+    __t__ below_1905 -1
+    __t__ below_1906 -1
+    __t__ below_1907 -1
+    __t__ below_1908 -1
+    __t__ below_1909 -1
+    __t__ below_1910 -1
+    __t__ below_1911 -1
+    __t__ below_1912 -1
+    __t__ below_1913 -1
+    __t__ below_1914 -1
+    __t__ below_1915 -1
+    __t__ below_1916 -1
+    __t__ below_1917 -1
+    __t__ below_1918 -1
+    __t__ below_1919 -1
+    __t__ below_1920 -1
+    __t__ below_1921 -1
+    __t__ below_1922 -1
+    __t__ below_1923 -1
+    __t__ below_1924 -1
+    __t__ below_1925 -1
+    __t__ below_1926 -1
+    __t__ below_1927 -1
+    __t__ below_1928 -1
+    __t__ below_1929 -1
+    __t__ below_1930 -1
+    __t__ below_1931 -1
+    __t__ below_1932 -1
+    __t__ below_1933 -1
+    __t__ below_1934 -1
+    __t__ below_1935 -1
+    __t__ below_1936 -1
+    __t__ below_1937 -1
+    __t__ below_1938 -1
+    __t__ below_1939 -1
+    __t__ below_1940 -1
+    __t__ below_1941 -1
+    __t__ below_1942 -1
+    __t__ below_1943 -1
+    __t__ below_1944 -1
+    __t__ below_1945 -1
+    __t__ below_1946 -1
+    __t__ below_1947 -1
+    __t__ below_1948 -1
+    __t__ below_1949 -1
+    __t__ below_1950 -1
+    __t__ below_1951 -1
+    __t__ below_1952 -1
+    __t__ below_1953 -1
+    __t__ below_1954 -1
+    __t__ below_1955 -1
+    __t__ below_1956 -1
+    __t__ below_1957 -1
+    __t__ below_1958 -1
+    __t__ below_1959 -1
+    __t__ below_1960 -1
+    __t__ below_1961 -1
+    __t__ below_1962 -1
+    __t__ below_1963 -1
+    __t__ below_1964 -1
+    __t__ below_1965 -1
+    __t__ below_1966 -1
+    __t__ below_1967 -1
+    __t__ below_1968 -1
+    __t__ below_1969 -1
+    __t__ below_1970 -1
+    __t__ below_1971 -1
+    __t__ below_1972 -1
+    __t__ below_1973 -1
+    __t__ below_1974 -1
+    __t__ below_1975 -1
+    __t__ below_1976 -1
+    __t__ below_1977 -1
+    __t__ below_1978 -1
+    __t__ below_1979 -1
+    __t__ below_1980 -1
+    __t__ below_1981 -1
+    __t__ below_1982 -1
+    __t__ below_1983 -1
+    __t__ below_1984 -1
+    __t__ below_1985 -1
+    __t__ below_1986 -1
+    __t__ below_1987 -1
+    __t__ below_1988 -1
+    __t__ below_1989 -1
+    __t__ below_1990 -1
+    __t__ below_1991 -1
+    __t__ below_1992 -1
+    __t__ below_1993 -1
+    __t__ below_1994 -1
+    __t__ below_1995 -1
+    __t__ below_1996 -1
+    __t__ below_1997 -1
+    __t__ below_1998 -1
+    __t__ below_1999 -1
+    __t__ below_2000 -1
+RHS
+    RHS above_1859 1
+    RHS above_1860 1
+    RHS above_1861 1
+    RHS above_1862 1
+    RHS above_1863 1
+    RHS above_1864 1
+    RHS above_1865 1
+    RHS above_1866 1
+    RHS above_1867 2
+    RHS above_1868 2
+    RHS above_1869 2
+    RHS above_1870 2
+    RHS above_1871 3
+    RHS above_1872 3
+    RHS above_1873 3
+    RHS above_1874 4
+    RHS above_1875 4
+    RHS above_1876 5
+    RHS above_1877 5
+    RHS above_1878 6
+    RHS above_1879 7
+    RHS above_1880 7
+    RHS above_1881 8
+    RHS above_1882 9
+    RHS above_1883 10
+    RHS above_1884 11
+    RHS above_1885 12
+    RHS above_1886 14
+    RHS above_1887 14
+    RHS above_1888 15
+    RHS above_1889 16
+    RHS above_1890 17
+    RHS above_1891 17
+    RHS above_1892 20
+    RHS above_1893 19
+    RHS above_1894 21
+    RHS above_1895 22
+    RHS above_1896 25
+    RHS above_1897 23
+    RHS above_1898 24
+    RHS above_1899 25
+    RHS above_1900 25
+    RHS above_1901 26
+    RHS above_1902 28
+    RHS above_1903 28
+    RHS above_1904 28
+    RHS above_1905 29
+    RHS above_1906 29
+    RHS above_1907 27
+    RHS above_1908 30
+    RHS above_1909 29
+    RHS above_1910 30
+    RHS above_1911 28
+    RHS above_1912 29
+    RHS above_1913 29
+    RHS above_1914 32
+    RHS above_1915 31
+    RHS above_1916 30
+    RHS above_1917 31
+    RHS above_1918 31
+    RHS above_1919 31
+    RHS above_1920 32
+    RHS above_1921 30
+    RHS above_1922 29
+    RHS above_1923 33
+    RHS above_1924 34
+    RHS above_1925 30
+    RHS above_1926 32
+    RHS above_1927 33
+    RHS above_1928 33
+    RHS above_1929 30
+    RHS above_1930 32
+    RHS above_1931 33
+    RHS above_1932 29
+    RHS above_1933 31
+    RHS above_1934 32
+    RHS above_1935 32
+    RHS above_1936 30
+    RHS above_1937 33
+    RHS above_1938 30
+    RHS above_1939 31
+    RHS above_1940 31
+    RHS above_1941 33
+    RHS above_1942 34
+    RHS above_1943 34
+    RHS above_1944 29
+    RHS above_1945 33
+    RHS above_1946 34
+    RHS above_1947 32
+    RHS above_1948 31
+    RHS above_1949 31
+    RHS above_1950 34
+    RHS above_1951 33
+    RHS above_1952 32
+    RHS above_1953 28
+    RHS above_1954 33
+    RHS above_1955 31
+    RHS above_1956 32
+    RHS above_1957 29
+    RHS above_1958 31
+    RHS above_1959 34
+    RHS above_1960 32
+    RHS above_1961 33
+    RHS above_1962 34
+    RHS above_1963 34
+    RHS above_1964 32
+    RHS above_1965 31
+    RHS above_1966 34
+    RHS above_1967 34
+    RHS above_1968 32
+    RHS above_1969 35
+    RHS above_1970 32
+    RHS above_1971 32
+    RHS above_1972 33
+    RHS above_1973 33
+    RHS above_1974 30
+    RHS above_1975 31
+    RHS above_1976 30
+    RHS above_1977 34
+    RHS above_1978 32
+    RHS above_1979 34
+    RHS above_1980 33
+    RHS above_1981 32
+    RHS above_1982 32
+    RHS above_1983 33
+    RHS above_1984 33
+    RHS above_1985 33
+    RHS above_1986 31
+    RHS above_1987 31
+    RHS above_1988 32
+    RHS above_1989 30
+    RHS above_1990 32
+    RHS above_1991 31
+    RHS above_1992 33
+    RHS above_1993 35
+    RHS above_1994 31
+    RHS above_1995 32
+    RHS above_1996 34
+    RHS above_1997 33
+    RHS above_1998 34
+    RHS above_1999 32
+    RHS above_2000 32
+    RHS below_1859 -1
+    RHS below_1860 -1
+    RHS below_1861 -1
+    RHS below_1862 -1
+    RHS below_1863 -1
+    RHS below_1864 -1
+    RHS below_1865 -1
+    RHS below_1866 -1
+    RHS below_1867 -2
+    RHS below_1868 -2
+    RHS below_1869 -2
+    RHS below_1870 -2
+    RHS below_1871 -3
+    RHS below_1872 -3
+    RHS below_1873 -3
+    RHS below_1874 -4
+    RHS below_1875 -4
+    RHS below_1876 -5
+    RHS below_1877 -5
+    RHS below_1878 -6
+    RHS below_1879 -7
+    RHS below_1880 -7
+    RHS below_1881 -8
+    RHS below_1882 -9
+    RHS below_1883 -10
+    RHS below_1884 -11
+    RHS below_1885 -12
+    RHS below_1886 -14
+    RHS below_1887 -14
+    RHS below_1888 -15
+    RHS below_1889 -16
+    RHS below_1890 -17
+    RHS below_1891 -17
+    RHS below_1892 -20
+    RHS below_1893 -19
+    RHS below_1894 -21
+    RHS below_1895 -22
+    RHS below_1896 -25
+    RHS below_1897 -23
+    RHS below_1898 -24
+    RHS below_1899 -25
+    RHS below_1900 -25
+    RHS below_1901 -26
+    RHS below_1902 -28
+    RHS below_1903 -28
+    RHS below_1904 -28
+    RHS below_1905 -29
+    RHS below_1906 -29
+    RHS below_1907 -27
+    RHS below_1908 -30
+    RHS below_1909 -29
+    RHS below_1910 -30
+    RHS below_1911 -28
+    RHS below_1912 -29
+    RHS below_1913 -29
+    RHS below_1914 -32
+    RHS below_1915 -31
+    RHS below_1916 -30
+    RHS below_1917 -31
+    RHS below_1918 -31
+    RHS below_1919 -31
+    RHS below_1920 -32
+    RHS below_1921 -30
+    RHS below_1922 -29
+    RHS below_1923 -33
+    RHS below_1924 -34
+    RHS below_1925 -30
+    RHS below_1926 -32
+    RHS below_1927 -33
+    RHS below_1928 -33
+    RHS below_1929 -30
+    RHS below_1930 -32
+    RHS below_1931 -33
+    RHS below_1932 -29
+    RHS below_1933 -31
+    RHS below_1934 -32
+    RHS below_1935 -32
+    RHS below_1936 -30
+    RHS below_1937 -33
+    RHS below_1938 -30
+    RHS below_1939 -31
+    RHS below_1940 -31
+    RHS below_1941 -33
+    RHS below_1942 -34
+    RHS below_1943 -34
+    RHS below_1944 -29
+    RHS below_1945 -33
+    RHS below_1946 -34
+    RHS below_1947 -32
+    RHS below_1948 -31
+    RHS below_1949 -31
+    RHS below_1950 -34
+    RHS below_1951 -33
+    RHS below_1952 -32
+    RHS below_1953 -28
+    RHS below_1954 -33
+    RHS below_1955 -31
+    RHS below_1956 -32
+    RHS below_1957 -29
+    RHS below_1958 -31
+    RHS below_1959 -34
+    RHS below_1960 -32
+    RHS below_1961 -33
+    RHS below_1962 -34
+    RHS below_1963 -34
+    RHS below_1964 -32
+    RHS below_1965 -31
+    RHS below_1966 -34
+    RHS below_1967 -34
+    RHS below_1968 -32
+    RHS below_1969 -35
+    RHS below_1970 -32
+    RHS below_1971 -32
+    RHS below_1972 -33
+    RHS below_1973 -33
+    RHS below_1974 -30
+    RHS below_1975 -31
+    RHS below_1976 -30
+    RHS below_1977 -34
+    RHS below_1978 -32
+    RHS below_1979 -34
+    RHS below_1980 -33
+    RHS below_1981 -32
+    RHS below_1982 -32
+    RHS below_1983 -33
+    RHS below_1984 -33
+    RHS below_1985 -33
+    RHS below_1986 -31
+    RHS below_1987 -31
+    RHS below_1988 -32
+    RHS below_1989 -30
+    RHS below_1990 -32
+    RHS below_1991 -31
+    RHS below_1992 -33
+    RHS below_1993 -35
+    RHS below_1994 -31
+    RHS below_1995 -32
+    RHS below_1996 -34
+    RHS below_1997 -33
+    RHS below_1998 -34
+    RHS below_1999 -32
+    RHS below_2000 -32
+BOUNDS
+ENDATA
@@ -0,0 +1,2420 @@
+NAME w0_w1_w1-2072e79c079ad65e
+ROWS
+ N obj
+ L above_1800
+ L above_1801
+ L above_1802
+ L above_1803
+ L above_1804
+ L above_1805
+ L above_1806
+ L above_1807
+ L above_1808
+ L above_1809
+ L above_1810
+ L above_1811
+ L above_1812
+ L above_1813
+ L above_1814
+ L above_1815
+ L above_1816
+ L above_1817
+ L above_1818
+ L above_1819
+ L above_1820
+ L above_1821
+ L above_1822
+ L above_1823
+ L above_1824
+ L above_1825
+ L above_1826
+ L above_1827
+ L above_1828
+ L above_1829
+ L above_1830
+ L above_1831
+ L above_1832
+ L above_1833
+ L above_1834
+ L above_1835
+ L above_1836
+ L above_1837
+ L above_1838
+ L above_1839
+ L above_1840
+ L above_1841
+ L above_1842
+ L above_1843
+ L above_1844
+ L above_1845
+ L above_1846
+ L above_1847
+ L above_1848
+ L above_1849
+ L above_1850
+ L above_1851
+ L above_1852
+ L above_1853
+ L above_1854
+ L above_1855
+ L above_1856
+ L above_1857
+ L above_1858
+ L above_1859
+ L above_1860
+ L above_1861
+ L above_1862
+ L above_1863
+ L above_1864
+ L above_1865
+ L above_1866
+ L above_1867
+ L above_1868
+ L above_1869
+ L above_1870
+ L above_1871
+ L above_1872
+ L above_1873
+ L above_1874
+ L above_1875
+ L above_1876
+ L above_1877
+ L above_1878
+ L above_1879
+ L above_1880
+ L above_1881
+ L above_1882
+ L above_1883
+ L above_1884
+ L above_1885
+ L above_1886
+ L above_1887
+ L above_1888
+ L above_1889
+ L above_1890
+ L above_1891
+ L above_1892
+ L above_1893
+ L above_1894
+ L above_1895
+ L above_1896
+ L above_1897
+ L above_1898
+ L above_1899
+ L above_1900
+ L above_1901
+ L above_1902
+ L above_1903
+ L above_1904
+ L above_1905
+ L above_1906
+ L above_1907
+ L above_1908
+ L above_1909
+ L above_1910
+ L above_1911
+ L above_1912
+ L above_1913
+ L above_1914
+ L above_1915
+ L above_1916
+ L above_1917
+ L above_1918
+ L above_1919
+ L above_1920
+ L above_1921
+ L above_1922
+ L above_1923
+ L above_1924
+ L above_1925
+ L above_1926
+ L above_1927
+ L above_1928
+ L above_1929
+ L above_1930
+ L above_1931
+ L above_1932
+ L above_1933
+ L above_1934
+ L above_1935
+ L above_1936
+ L above_1937
+ L above_1938
+ L above_1939
+ L above_1940
+ L above_1941
+ L above_1942
+ L above_1943
+ L above_1944
+ L above_1945
+ L above_1946
+ L above_1947
+ L above_1948
+ L above_1949
+ L above_1950
+ L above_1951
+ L above_1952
+ L above_1953
+ L above_1954
+ L above_1955
+ L above_1956
+ L above_1957
+ L above_1958
+ L above_1959
+ L above_1960
+ L above_1961
+ L above_1962
+ L above_1963
+ L above_1964
+ L above_1965
+ L above_1966
+ L above_1967
+ L above_1968
+ L above_1969
+ L above_1970
+ L above_1971
+ L above_1972
+ L above_1973
+ L above_1974
+ L above_1975
+ L above_1976
+ L above_1977
+ L above_1978
+ L above_1979
+ L above_1980
+ L above_1981
+ L above_1982
+ L above_1983
+ L above_1984
+ L above_1985
+ L above_1986
+ L above_1987
+ L above_1988
+ L above_1989
+ L above_1990
+ L above_1991
+ L above_1992
+ L above_1993
+ L above_1994
+ L above_1995
+ L above_1996
+ L above_1997
+ L above_1998
+ L above_1999
+ L above_2000
+ L below_1800
+ L below_1801
+ L below_1802
+ L below_1803
+ L below_1804
+ L below_1805
+ L below_1806
+ L below_1807
+ L below_1808
+ L below_1809
+ L below_1810
+ L below_1811
+ L below_1812
+ L below_1813
+ L below_1814
+ L below_1815
+ L below_1816
+ L below_1817
+ L below_1818
+ L below_1819
+ L below_1820
+ L below_1821
+ L below_1822
+ L below_1823
+ L below_1824
+ L below_1825
+ L below_1826
+ L below_1827
+ L below_1828
+ L below_1829
+ L below_1830
+ L below_1831
+ L below_1832
+ L below_1833
+ L below_1834
+ L below_1835
+ L below_1836
+ L below_1837
+ L below_1838
+ L below_1839
+ L below_1840
+ L below_1841
+ L below_1842
+ L below_1843
+ L below_1844
+ L below_1845
+ L below_1846
+ L below_1847
+ L below_1848
+ L below_1849
+ L below_1850
+ L below_1851
+ L below_1852
+ L below_1853
+ L below_1854
+ L below_1855
+ L below_1856
+ L below_1857
+ L below_1858
+ L below_1859
+ L below_1860
+ L below_1861
+ L below_1862
+ L below_1863
+ L below_1864
+ L below_1865
+ L below_1866
+ L below_1867
+ L below_1868
+ L below_1869
+ L below_1870
+ L below_1871
+ L below_1872
+ L below_1873
+ L below_1874
+ L below_1875
+ L below_1876
+ L below_1877
+ L below_1878
+ L below_1879
+ L below_1880
+ L below_1881
+ L below_1882
+ L below_1883
+ L below_1884
+ L below_1885
+ L below_1886
+ L below_1887
+ L below_1888
+ L below_1889
+ L below_1890
+ L below_1891
+ L below_1892
+ L below_1893
+ L below_1894
+ L below_1895
+ L below_1896
+ L below_1897
+ L below_1898
+ L below_1899
+ L below_1900
+ L below_1901
+ L below_1902
+ L below_1903
+ L below_1904
+ L below_1905
+ L below_1906
+ L below_1907
+ L below_1908
+ L below_1909
+ L below_1910
+ L below_1911
+ L below_1912
+ L below_1913
+ L below_1914
+ L below_1915
+ L below_1916
+ L below_1917
+ L below_1918
+ L below_1919
+ L below_1920
+ L below_1921
+ L below_1922
+ L below_1923
+ L below_1924
+ L below_1925
+ L below_1926
+ L below_1927
+ L below_1928
+ L below_1929
+ L below_1930
+ L below_1931
+ L below_1932
+ L below_1933
+ L below_1934
+ L below_1935
+ L below_1936
+ L below_1937
+ L below_1938
+ L below_1939
+ L below_1940
+ L below_1941
+ L below_1942
+ L below_1943
+ L below_1944
+ L below_1945
+ L below_1946
+ L below_1947
+ L below_1948
+ L below_1949
+ L below_1950
+ L below_1951
+ L below_1952
+ L below_1953
+ L below_1954
+ L below_1955
+ L below_1956
+ L below_1957
+ L below_1958
+ L below_1959
+ L below_1960
+ L below_1961
+ L below_1962
+ L below_1963
+ L below_1964
+ L below_1965
+ L below_1966
+ L below_1967
+ L below_1968
+ L below_1969
+ L below_1970
+ L below_1971
+ L below_1972
+ L below_1973
+ L below_1974
+ L below_1975
+ L below_1976
+ L below_1977
+ L below_1978
+ L below_1979
+ L below_1980
+ L below_1981
+ L below_1982
+ L below_1983
+ L below_1984
+ L below_1985
+ L below_1986
+ L below_1987
+ L below_1988
+ L below_1989
+ L below_1990
+ L below_1991
+ L below_1992
+ L below_1993
+ L below_1994
+ L below_1995
+ L below_1996
+ L below_1997
+ L below_1998
+ L below_1999
+ L below_2000
+COLUMNS
+    w0 above_1800 51422
+    w0 above_1801 49243
+    w0 above_1802 51632
+    w0 above_1803 56497
+    w0 above_1804 62163
+    w0 above_1805 54862
+    w0 above_1806 60946
+    w0 above_1807 57189
+    w0 above_1808 62041
+    w0 above_1809 60099
+    w0 above_1810 67048
+    w0 above_1811 66630
+    w0 above_1812 68430
+    w0 above_1813 66835
+    w0 above_1814 68792
+    w0 above_1815 73721
+    w0 above_1816 72991
+    w0 above_1817 74461
+    w0 above_1818 70588
+    w0 above_1819 72598
+    w0 above_1820 70313
+    w0 above_1821 80058
+    w0 above_1822 79982
+    w0 above_1823 83562
+    w0 above_1824 78857
+    w0 above_1825 84733
+    w0 above_1826 84533
+    w0 above_1827 86684
+    w0 above_1828 92440
+    w0 above_1829 90404
+    w0 above_1830 99023
+    w0 above_1831 95010
+    w0 above_1832 89504
+    w0 above_1833 96055
+    w0 above_1834 101847
+    w0 above_1835 99440
+    w0 above_1836 97118
+    w0 above_1837 98861
+    w0 above_1838 107170
+    w0 above_1839 117222
+    w0 above_1840 100027
+    w0 above_1841 104022
+    w0 above_1842 110583
+    w0 above_1843 120174
+    w0 above_1844 123051
+    w0 above_1845 114845
+    w0 above_1846 115882
+    w0 above_1847 118138
+    w0 above_1848 126206
+    w0 above_1849 132716
+    w0 above_1850 126038
+    w0 above_1851 125001
+    w0 above_1852 119535
+    w0 above_1853 136287
+    w0 above_1854 123481
+    w0 above_1855 129220
+    w0 above_1856 132467
+    w0 above_1857 129788
+    w0 above_1858 137362
+    w0 above_1859 142732
+    w0 above_1860 138160
+    w0 above_1861 131516
+    w0 above_1862 150514
+    w0 above_1863 143334
+    w0 above_1864 143985
+    w0 above_1865 141525
+    w0 above_1866 167136
+    w0 above_1867 147658
+    w0 above_1868 156346
+    w0 above_1869 160966
+    w0 above_1870 146669
+    w0 above_1871 159061
+    w0 above_1872 160795
+    w0 above_1873 157895
+    w0 above_1874 167156
+    w0 above_1875 177177
+    w0 above_1876 168586
+    w0 above_1877 161651
+    w0 above_1878 153783
+    w0 above_1879 164740
+    w0 above_1880 170504
+    w0 above_1881 193250
+    w0 above_1882 168093
+    w0 above_1883 175926
+    w0 above_1884 170076
+    w0 above_1885 174629
+    w0 above_1886 173507
+    w0 above_1887 190112
+    w0 above_1888 200848
+    w0 above_1889 185130
+    w0 above_1890 187402
+    w0 above_1891 199916
+    w0 above_1892 197980
+    w0 above_1893 186324
+    w0 above_1894 189516
+    w0 above_1895 208523
+    w0 above_1896 207543
+    w0 above_1897 208976
+    w0 above_1898 199778
+    w0 above_1899 199030
+    w0 above_1900 220860
+    w0 above_1901 208072
+    w0 above_1902 232937
+    w0 above_1903 212698
+    w0 above_1904 219674
+    w0 above_1905 197633
+    w0 above_1906 220700
+    w0 above_1907 202257
+    w0 above_1908 210581
+    w0 above_1909 212066
+    w0 above_1910 219758
+    w0 above_1911 203996
+    w0 above_1912 214462
+    w0 above_1913 235181
+    w0 above_1914 234427
+    w0 above_1915 216230
+    w0 above_1916 209333
+    w0 above_1917 242237
+    w0 above_1918 250679
+    w0 above_1919 232106
+    w0 above_1920 221059
+    w0 above_1921 240767
+    w0 above_1922 224484
+    w0 above_1923 227616
+    w0 above_1924 243338
+    w0 above_1925 243747
+    w0 above_1926 247575
+    w0 above_1927 246773
+    w0 above_1928 224087
+    w0 above_1929 253617
+    w0 above_1930 249345
+    w0 above_1931 254756
+    w0 above_1932 246453
+    w0 above_1933 285284
+    w0 above_1934 262248
+    w0 above_1935 246708
+    w0 above_1936 257176
+    w0 above_1937 269376
+    w0 above_1938 251680
+    w0 above_1939 254913
+    w0 above_1940 253498
+    w0 above_1941 280108
+    w0 above_1942 264013
+    w0 above_1943 260556
+    w0 above_1944 237400
+    w0 above_1945 268362
+    w0 above_1946 256755
+    w0 above_1947 266028
+    w0 above_1948 277912
+    w0 above_1949 292117
+    w0 above_1950 268199
+    w0 above_1951 261424
+    w0 above_1952 259563
+    w0 above_1953 256153
+    w0 above_1954 283437
+    w0 above_1955 285567
+    w0 above_1956 289467
+    w0 above_1957 298888
+    w0 above_1958 301542
+    w0 above_1959 306129
+    w0 above_1960 290193
+    w0 above_1961 258337
+    w0 above_1962 289324
+    w0 above_1963 243394
+    w0 above_1964 291174
+    w0 above_1965 297056
+    w0 above_1966 281350
+    w0 above_1967 297824
+    w0 above_1968 292376
+    w0 above_1969 284537
+    w0 above_1970 279026
+    w0 above_1971 306382
+    w0 above_1972 290453
+    w0 above_1973 291454
+    w0 above_1974 315356
+    w0 above_1975 319830
+    w0 above_1976 308533
+    w0 above_1977 303503
+    w0 above_1978 293547
+    w0 above_1979 296333
+    w0 above_1980 305086
+    w0 above_1981 279551
+    w0 above_1982 296835
+    w0 above_1983 319476
+    w0 above_1984 286300
+    w0 above_1985 324819
+    w0 above_1986 331124
+    w0 above_1987 318146
+    w0 above_1988 277741
+    w0 above_1989 302499
+    w0 above_1990 294102
+    w0 above_1991 298467
+    w0 above_1992 320441
+    w0 above_1993 336698
+    w0 above_1994 317784
+    w0 above_1995 320671
+    w0 above_1996 348847
+    w0 above_1997 292322
+    w0 above_1998 308121
+    w0 above_1999 325227
+    w0 above_2000 298136
+    w0 below_1800 -51422
+    w0 below_1801 -49243
+    w0 below_1802 -51632
+    w0 below_1803 -56497
+    w0 below_1804 -62163
+    w0 below_1805 -54862
+    w0 below_1806 -60946
+    w0 below_1807 -57189
+    w0 below_1808 -62041
+    w0 below_1809 -60099
+    w0 below_1810 -67048
+    w0 below_1811 -66630
+    w0 below_1812 -68430
+    w0 below_1813 -66835
+    w0 below_1814 -68792
+    w0 below_1815 -73721
+    w0 below_1816 -72991
+    w0 below_1817 -74461
+    w0 below_1818 -70588
+    w0 below_1819 -72598
+    w0 below_1820 -70313
+    w0 below_1821 -80058
+    w0 below_1822 -79982
+    w0 below_1823 -83562
+    w0 below_1824 -78857
+    w0 below_1825 -84733
+    w0 below_1826 -84533
+    w0 below_1827 -86684
+    w0 below_1828 -92440
+    w0 below_1829 -90404
+    w0 below_1830 -99023
+    w0 below_1831 -95010
+    w0 below_1832 -89504
+    w0 below_1833 -96055
+    w0 below_1834 -101847
+    w0 below_1835 -99440
+    w0 below_1836 -97118
+    w0 below_1837 -98861
+    w0 below_1838 -107170
+    w0 below_1839 -117222
+    w0 below_1840 -100027
+    w0 below_1841 -104022
+    w0 below_1842 -110583
+    w0 below_1843 -120174
+    w0 below_1844 -123051
+    w0 below_1845 -114845
+    w0 below_1846 -115882
+    w0 below_1847 -118138
+    w0 below_1848 -126206
+    w0 below_1849 -132716
+    w0 below_1850 -126038
+    w0 below_1851 -125001
+    w0 below_1852 -119535
+    w0 below_1853 -136287
+    w0 below_1854 -123481
+    w0 below_1855 -129220
+    w0 below_1856 -132467
+    w0 below_1857 -129788
+    w0 below_1858 -137362
+    w0 below_1859 -142732
+    w0 below_1860 -138160
+    w0 below_1861 -131516
+    w0 below_1862 -150514
+    w0 below_1863 -143334
+    w0 below_1864 -143985
+    w0 below_1865 -141525
+    w0 below_1866 -167136
+    w0 below_1867 -147658
+    w0 below_1868 -156346
+    w0 below_1869 -160966
+    w0 below_1870 -146669
+    w0 below_1871 -159061
+    w0 below_1872 -160795
+    w0 below_1873 -157895
+    w0 below_1874 -167156
+    w0 below_1875 -177177
+    w0 below_1876 -168586
+    w0 below_1877 -161651
+    w0 below_1878 -153783
+    w0 below_1879 -164740
+    w0 below_1880 -170504
+    w0 below_1881 -193250
+    w0 below_1882 -168093
+    w0 below_1883 -175926
+    w0 below_1884 -170076
+    w0 below_1885 -174629
+    w0 below_1886 -173507
+    w0 below_1887 -190112
+    w0 below_1888 -200848
+    w0 below_1889 -185130
+    w0 below_1890 -187402
+    w0 below_1891 -199916
+    w0 below_1892 -197980
+    w0 below_1893 -186324
+    w0 below_1894 -189516
+    w0 below_1895 -208523
+    w0 below_1896 -207543
+    w0 below_1897 -208976
+    w0 below_1898 -199778
+    w0 below_1899 -199030
+    w0 below_1900 -220860
+    w0 below_1901 -208072
+    w0 below_1902 -232937
+    w0 below_1903 -212698
+    w0 below_1904 -219674
+    w0 below_1905 -197633
+    w0 below_1906 -220700
+    w0 below_1907 -202257
+    w0 below_1908 -210581
+    w0 below_1909 -212066
+    w0 below_1910 -219758
+    w0 below_1911 -203996
+    w0 below_1912 -214462
+    w0 below_1913 -235181
+    w0 below_1914 -234427
+    w0 below_1915 -216230
+    w0 below_1916 -209333
+    w0 below_1917 -242237
+    w0 below_1918 -250679
+    w0 below_1919 -232106
+    w0 below_1920 -221059
+    w0 below_1921 -240767
+    w0 below_1922 -224484
+    w0 below_1923 -227616
+    w0 below_1924 -243338
+    w0 below_1925 -243747
+    w0 below_1926 -247575
+    w0 below_1927 -246773
+    w0 below_1928 -224087
+    w0 below_1929 -253617
+    w0 below_1930 -249345
+    w0 below_1931 -254756
+    w0 below_1932 -246453
+    w0 below_1933 -285284
+    w0 below_1934 -262248
+    w0 below_1935 -246708
+    w0 below_1936 -257176
+    w0 below_1937 -269376
+    w0 below_1938 -251680
+    w0 below_1939 -254913
+    w0 below_1940 -253498
+    w0 below_1941 -280108
+    w0 below_1942 -264013
+    w0 below_1943 -260556
+    w0 below_1944 -237400
+    w0 below_1945 -268362
+    w0 below_1946 -256755
+    w0 below_1947 -266028
+    w0 below_1948 -277912
+    w0 below_1949 -292117
+    w0 below_1950 -268199
+    w0 below_1951 -261424
+    w0 below_1952 -259563
+    w0 below_1953 -256153
+    w0 below_1954 -283437
+    w0 below_1955 -285567
+    w0 below_1956 -289467
+    w0 below_1957 -298888
+    w0 below_1958 -301542
+    w0 below_1959 -306129
+    w0 below_1960 -290193
+    w0 below_1961 -258337
+    w0 below_1962 -289324
+    w0 below_1963 -243394
+    w0 below_1964 -291174
+    w0 below_1965 -297056
+    w0 below_1966 -281350
+    w0 below_1967 -297824
+    w0 below_1968 -292376
+    w0 below_1969 -284537
+    w0 below_1970 -279026
+    w0 below_1971 -306382
+    w0 below_1972 -290453
+    w0 below_1973 -291454
+    w0 below_1974 -315356
+    w0 below_1975 -319830
+    w0 below_1976 -308533
+    w0 below_1977 -303503
+    w0 below_1978 -293547
+    w0 below_1979 -296333
+    w0 below_1980 -305086
+    w0 below_1981 -279551
+    w0 below_1982 -296835
+    w0 below_1983 -319476
+    w0 below_1984 -286300
+    w0 below_1985 -324819
+    w0 below_1986 -331124
+    w0 below_1987 -318146
+    w0 below_1988 -277741
+    w0 below_1989 -302499
+    w0 below_1990 -294102
+    w0 below_1991 -298467
+    w0 below_1992 -320441
+    w0 below_1993 -336698
+    w0 below_1994 -317784
+    w0 below_1995 -320671
+    w0 below_1996 -348847
+    w0 below_1997 -292322
+    w0 below_1998 -308121
+    w0 below_1999 -325227
+    w0 below_2000 -298136
+    w1 above_1800 18573
+    w1 above_1801 23843
+    w1 above_1802 23680
+    w1 above_1803 24216
+    w1 above_1804 26037
+    w1 above_1805 24163
+    w1 above_1806 25417
+    w1 above_1807 25789
+    w1 above_1808 26248
+    w1 above_1809 27208
+    w1 above_1810 28015
+    w1 above_1811 28483
+    w1 above_1812 30553
+    w1 above_1813 29107
+    w1 above_1814 31440
+    w1 above_1815 33059
+    w1 above_1816 32795
+    w1 above_1817 33165
+    w1 above_1818 34089
+    w1 above_1819 33683
+    w1 above_1820 33696
+    w1 above_1821 37417
+    w1 above_1822 37931
+    w1 above_1823 35437
+    w1 above_1824 36625
+    w1 above_1825 34328
+    w1 above_1826 39452
+    w1 above_1827 41328
+    w1 above_1828 41333
+    w1 above_1829 39587
+    w1 above_1830 45154
+    w1 above_1831 45095
+    w1 above_1832 44953
+    w1 above_1833 43461
+    w1 above_1834 43872
+    w1 above_1835 43903
+    w1 above_1836 44845
+    w1 above_1837 44906
+    w1 above_1838 46449
+    w1 above_1839 50510
+    w1 above_1840 47757
+    w1 above_1841 53188
+    w1 above_1842 51636
+    w1 above_1843 49766
+    w1 above_1844 51779
+    w1 above_1845 57332
+    w1 above_1846 53842
+    w1 above_1847 52609
+    w1 above_1848 56220
+    w1 above_1849 54749
+    w1 above_1850 60368
+    w1 above_1851 51947
+    w1 above_1852 57871
+    w1 above_1853 57712
+    w1 above_1854 58859
+    w1 above_1855 56566
+    w1 above_1856 62003
+    w1 above_1857 58965
+    w1 above_1858 56141
+    w1 above_1859 66735
+    w1 above_1860 69203
+    w1 above_1861 64844
+    w1 above_1862 68314
+    w1 above_1863 66125
+    w1 above_1864 65977
+    w1 above_1865 66237
+    w1 above_1866 66797
+    w1 above_1867 69841
+    w1 above_1868 72806
+    w1 above_1869 71832
+    w1 above_1870 70728
+    w1 above_1871 79707
+    w1 above_1872 71025
+    w1 above_1873 79031
+    w1 above_1874 73147
+    w1 above_1875 74002
+    w1 above_1876 72090
+    w1 above_1877 81331
+    w1 above_1878 85583
+    w1 above_1879 79118
+    w1 above_1880 81301
+    w1 above_1881 76450
+    w1 above_1882 86013
+    w1 above_1883 79334
+    w1 above_1884 84676
+    w1 above_1885 82792
+    w1 above_1886 88960
+    w1 above_1887 84220
+    w1 above_1888 89382
+    w1 above_1889 76778
+    w1 above_1890 85069
+    w1 above_1891 89501
+    w1 above_1892 95595
+    w1 above_1893 84795
+    w1 above_1894 87560
+    w1 above_1895 100089
+    w1 above_1896 90528
+    w1 above_1897 98766
+    w1 above_1898 98910
+    w1 above_1899 93928
+    w1 above_1900 96872
+    w1 above_1901 93455
+    w1 above_1902 102189
+    w1 above_1903 102786
+    w1 above_1904 92315
+    w1 above_1905 100359
+    w1 above_1906 102932
+    w1 above_1907 101873
+    w1 above_1908 103073
+    w1 above_1909 96325
+    w1 above_1910 106794
+    w1 above_1911 106138
+    w1 above_1912 97065
+    w1 above_1913 101876
+    w1 above_1914 107538
+    w1 above_1915 107953
+    w1 above_1916 107097
+    w1 above_1917 119403
+    w1 above_1918 111938
+    w1 above_1919 114722
+    w1 above_1920 110430
+    w1 above_1921 111681
+    w1 above_1922 110089
+    w1 above_1923 122225
+    w1 above_1924 107572
+    w1 above_1925 108554
+    w1 above_1926 123676
+    w1 above_1927 115506
+    w1 above_1928 107329
+    w1 above_1929 109388
+    w1 above_1930 108633
+    w1 above_1931 107658
+    w1 above_1932 124095
+    w1 above_1933 119031
+    w1 above_1934 123444
+    w1 above_1935 125005
+    w1 above_1936 129170
+    w1 above_1937 117234
+    w1 above_1938 126603
+    w1 above_1939 115190
+    w1 above_1940 128216
+    w1 above_1941 125533
+    w1 above_1942 127632
+    w1 above_1943 123244
+    w1 above_1944 143695
+    w1 above_1945 137559
+    w1 above_1946 126615
+    w1 above_1947 129508
+    w1 above_1948 132512
+    w1 above_1949 121267
+    w1 above_1950 124892
+    w1 above_1951 112248
+    w1 above_1952 133135
+    w1 above_1953 124883
+    w1 above_1954 130014
+    w1 above_1955 126647
+    w1 above_1956 125160
+    w1 above_1957 138794
+    w1 above_1958 125060
+    w1 above_1959 138959
+    w1 above_1960 149001
+    w1 above_1961 144451
+    w1 above_1962 134026
+    w1 above_1963 140081
+    w1 above_1964 141412
+    w1 above_1965 153613
+    w1 above_1966 142228
+    w1 above_1967 158361
+    w1 above_1968 137044
+    w1 above_1969 148331
+    w1 above_1970 141996
+    w1 above_1971 131085
+    w1 above_1972 130959
+    w1 above_1973 144780
+    w1 above_1974 144577
+    w1 above_1975 151063
+    w1 above_1976 148136
+    w1 above_1977 145868
+    w1 above_1978 142767
+    w1 above_1979 147677
+    w1 above_1980 146118
+    w1 above_1981 149150
+    w1 above_1982 155668
+    w1 above_1983 144145
+    w1 above_1984 137361
+    w1 above_1985 154411
+    w1 above_1986 159868
+    w1 above_1987 143458
+    w1 above_1988 126539
+    w1 above_1989 159581
+    w1 above_1990 149498
+    w1 above_1991 159957
+    w1 above_1992 156712
+    w1 above_1993 157550
+    w1 above_1994 160998
+    w1 above_1995 164759
+    w1 above_1996 139715
+    w1 above_1997 173516
+    w1 above_1998 158642
+    w1 above_1999 153635
+    w1 above_2000 143841
+    w1 below_1800 -18573
+    w1 below_1801 -23843
+    w1 below_1802 -23680
+    w1 below_1803 -24216
+    w1 below_1804 -26037
+    w1 below_1805 -24163
+    w1 below_1806 -25417
+    w1 below_1807 -25789
+    w1 below_1808 -26248
+    w1 below_1809 -27208
+    w1 below_1810 -28015
+    w1 below_1811 -28483
+    w1 below_1812 -30553
+    w1 below_1813 -29107
+    w1 below_1814 -31440
+    w1 below_1815 -33059
+    w1 below_1816 -32795
+    w1 below_1817 -33165
+    w1 below_1818 -34089
+    w1 below_1819 -33683
+    w1 below_1820 -33696
+    w1 below_1821 -37417
+    w1 below_1822 -37931
+    w1 below_1823 -35437
+    w1 below_1824 -36625
+    w1 below_1825 -34328
+    w1 below_1826 -39452
+    w1 below_1827 -41328
+    w1 below_1828 -41333
+    w1 below_1829 -39587
+    w1 below_1830 -45154
+    w1 below_1831 -45095
+    w1 below_1832 -44953
+    w1 below_1833 -43461
+    w1 below_1834 -43872
+    w1 below_1835 -43903
+    w1 below_1836 -44845
+    w1 below_1837 -44906
+    w1 below_1838 -46449
+    w1 below_1839 -50510
+    w1 below_1840 -47757
+    w1 below_1841 -53188
+    w1 below_1842 -51636
+    w1 below_1843 -49766
+    w1 below_1844 -51779
+    w1 below_1845 -57332
+    w1 below_1846 -53842
+    w1 below_1847 -52609
+    w1 below_1848 -56220
+    w1 below_1849 -54749
+    w1 below_1850 -60368
+    w1 below_1851 -51947
+    w1 below_1852 -57871
+    w1 below_1853 -57712
+    w1 below_1854 -58859
+    w1 below_1855 -56566
+    w1 below_1856 -62003
+    w1 below_1857 -58965
+    w1 below_1858 -56141
+    w1 below_1859 -66735
+    w1 below_1860 -69203
+    w1 below_1861 -64844
+    w1 below_1862 -68314
+    w1 below_1863 -66125
+    w1 below_1864 -65977
+    w1 below_1865 -66237
+    w1 below_1866 -66797
+    w1 below_1867 -69841
+    w1 below_1868 -72806
+    w1 below_1869 -71832
+    w1 below_1870 -70728
+    w1 below_1871 -79707
+    w1 below_1872 -71025
+    w1 below_1873 -79031
+    w1 below_1874 -73147
+    w1 below_1875 -74002
+    w1 below_1876 -72090
+    w1 below_1877 -81331
+    w1 below_1878 -85583
+    w1 below_1879 -79118
+    w1 below_1880 -81301
+    w1 below_1881 -76450
+    w1 below_1882 -86013
+    w1 below_1883 -79334
+    w1 below_1884 -84676
+    w1 below_1885 -82792
+    w1 below_1886 -88960
+    w1 below_1887 -84220
+    w1 below_1888 -89382
+    w1 below_1889 -76778
+    w1 below_1890 -85069
+    w1 below_1891 -89501
+    w1 below_1892 -95595
+    w1 below_1893 -84795
+    w1 below_1894 -87560
+    w1 below_1895 -100089
+    w1 below_1896 -90528
+    w1 below_1897 -98766
+    w1 below_1898 -98910
+    w1 below_1899 -93928
+    w1 below_1900 -96872
+    w1 below_1901 -93455
+    w1 below_1902 -102189
+    w1 below_1903 -102786
+    w1 below_1904 -92315
+    w1 below_1905 -100359
+    w1 below_1906 -102932
+    w1 below_1907 -101873
+    w1 below_1908 -103073
+    w1 below_1909 -96325
+    w1 below_1910 -106794
+    w1 below_1911 -106138
+    w1 below_1912 -97065
+    w1 below_1913 -101876
+    w1 below_1914 -107538
+    w1 below_1915 -107953
+    w1 below_1916 -107097
+    w1 below_1917 -119403
+    w1 below_1918 -111938
+    w1 below_1919 -114722
+    w1 below_1920 -110430
+    w1 below_1921 -111681
+    w1 below_1922 -110089
+    w1 below_1923 -122225
+    w1 below_1924 -107572
+    w1 below_1925 -108554
+    w1 below_1926 -123676
+    w1 below_1927 -115506
+    w1 below_1928 -107329
+    w1 below_1929 -109388
+    w1 below_1930 -108633
+    w1 below_1931 -107658
+    w1 below_1932 -124095
+    w1 below_1933 -119031
+    w1 below_1934 -123444
+    w1 below_1935 -125005
+    w1 below_1936 -129170
+    w1 below_1937 -117234
+    w1 below_1938 -126603
+    w1 below_1939 -115190
+    w1 below_1940 -128216
+    w1 below_1941 -125533
+    w1 below_1942 -127632
+    w1 below_1943 -123244
+    w1 below_1944 -143695
+    w1 below_1945 -137559
+    w1 below_1946 -126615
+    w1 below_1947 -129508
+    w1 below_1948 -132512
+    w1 below_1949 -121267
+    w1 below_1950 -124892
+    w1 below_1951 -112248
+    w1 below_1952 -133135
+    w1 below_1953 -124883
+    w1 below_1954 -130014
+    w1 below_1955 -126647
+    w1 below_1956 -125160
+    w1 below_1957 -138794
+    w1 below_1958 -125060
+    w1 below_1959 -138959
+    w1 below_1960 -149001
+    w1 below_1961 -144451
+    w1 below_1962 -134026
+    w1 below_1963 -140081
+    w1 below_1964 -141412
+    w1 below_1965 -153613
+    w1 below_1966 -142228
+    w1 below_1967 -158361
+    w1 below_1968 -137044
+    w1 below_1969 -148331
+    w1 below_1970 -141996
+    w1 below_1971 -131085
+    w1 below_1972 -130959
+    w1 below_1973 -144780
+    w1 below_1974 -144577
+    w1 below_1975 -151063
+    w1 below_1976 -148136
+    w1 below_1977 -145868
+    w1 below_1978 -142767
+    w1 below_1979 -147677
+    w1 below_1980 -146118
+    w1 below_1981 -149150
+    w1 below_1982 -155668
+    w1 below_1983 -144145
+    w1 below_1984 -137361
+    w1 below_1985 -154411
+    w1 below_1986 -159868
+    w1 below_1987 -143458
+    w1 below_1988 -126539
+    w1 below_1989 -159581
+    w1 below_1990 -149498
+    w1 below_1991 -159957
+    w1 below_1992 -156712
+    w1 below_1993 -157550
+    w1 below_1994 -160998
+    w1 below_1995 -164759
+    w1 below_1996 -139715
+    w1 below_1997 -173516
+    w1 below_1998 -158642
+    w1 below_1999 -153635
+    w1 below_2000 -143841
+    w1_w1 above_1800 727
+    w1_w1 above_1801 709
+    w1_w1 above_1802 721
+    w1_w1 above_1803 765
+    w1_w1 above_1804 776
+    w1_w1 above_1805 865
+    w1_w1 above_1806 801
+    w1_w1 above_1807 792
+    w1_w1 above_1808 877
+    w1_w1 above_1809 917
+    w1_w1 above_1810 936
+    w1_w1 above_1811 960
+    w1_w1 above_1812 972
+    w1_w1 above_1813 1074
+    w1_w1 above_1814 1006
+    w1_w1 above_1815 1034
+    w1_w1 above_1816 1097
+    w1_w1 above_1817 1087
+    w1_w1 above_1818 1116
+    w1_w1 above_1819 1060
+    w1_w1 above_1820 1076
+    w1_w1 above_1821 1209
+    w1_w1 above_1822 1172
+    w1_w1 above_1823 1300
+    w1_w1 above_1824 1311
+    w1_w1 above_1825 1134
+    w1_w1 above_1826 1348
+    w1_w1 above_1827 1354
+    w1_w1 above_1828 1370
+    w1_w1 above_1829 1442
+    w1_w1 above_1830 1452
+    w1_w1 above_1831 1479
+    w1_w1 above_1832 1508
+    w1_w1 above_1833 1449
+    w1_w1 above_1834 1495
+    w1_w1 above_1835 1417
+    w1_w1 above_1836 1564
+    w1_w1 above_1837 1650
+    w1_w1 above_1838 1652
+    w1_w1 above_1839 1726
+    w1_w1 above_1840 1806
+    w1_w1 above_1841 1828
+    w1_w1 above_1842 1728
+    w1_w1 above_1843 1662
+    w1_w1 above_1844 1824
+    w1_w1 above_1845 1793
+    w1_w1 above_1846 1837
+    w1_w1 above_1847 1900
+    w1_w1 above_1848 1688
+    w1_w1 above_1849 2085
+    w1_w1 above_1850 1755
+    w1_w1 above_1851 1943
+    w1_w1 above_1852 1642
+    w1_w1 above_1853 2045
+    w1_w1 above_1854 2101
+    w1_w1 above_1855 2041
+    w1_w1 above_1856 1976
+    w1_w1 above_1857 2059
+    w1_w1 above_1858 2016
+    w1_w1 above_1859 2106
+    w1_w1 above_1860 2167
+    w1_w1 above_1861 2280
+    w1_w1 above_1862 2591
+    w1_w1 above_1863 2349
+    w1_w1 above_1864 2316
+    w1_w1 above_1865 2542
+    w1_w1 above_1866 2287
+    w1_w1 above_1867 2410
+    w1_w1 above_1868 2340
+    w1_w1 above_1869 2231
+    w1_w1 above_1870 2341
+    w1_w1 above_1871 2562
+    w1_w1 above_1872 2675
+    w1_w1 above_1873 2613
+    w1_w1 above_1874 2558
+    w1_w1 above_1875 2446
+    w1_w1 above_1876 2561
+    w1_w1 above_1877 2762
+    w1_w1 above_1878 2680
+    w1_w1 above_1879 2893
+    w1_w1 above_1880 2912
+    w1_w1 above_1881 3008
+    w1_w1 above_1882 2801
+    w1_w1 above_1883 2736
+    w1_w1 above_1884 2991
+    w1_w1 above_1885 2942
+    w1_w1 above_1886 3056
+    w1_w1 above_1887 2750
+    w1_w1 above_1888 3064
+    w1_w1 above_1889 2904
+    w1_w1 above_1890 3230
+    w1_w1 above_1891 2827
+    w1_w1 above_1892 3096
+    w1_w1 above_1893 3264
+    w1_w1 above_1894 3264
+    w1_w1 above_1895 3208
+    w1_w1 above_1896 3145
+    w1_w1 above_1897 3529
+    w1_w1 above_1898 3541
+    w1_w1 above_1899 3828
+    w1_w1 above_1900 3414
+    w1_w1 above_1901 3602
+    w1_w1 above_1902 3468
+    w1_w1 above_1903 3605
+    w1_w1 above_1904 3665
+    w1_w1 above_1905 3773
+    w1_w1 above_1906 3512
+    w1_w1 above_1907 3800
+    w1_w1 above_1908 3714
+    w1_w1 above_1909 3980
+    w1_w1 above_1910 4182
+    w1_w1 above_1911 4052
+    w1_w1 above_1912 4093
+    w1_w1 above_1913 3605
+    w1_w1 above_1914 3947
+    w1_w1 above_1915 4365
+    w1_w1 above_1916 3975
+    w1_w1 above_1917 3958
+    w1_w1 above_1918 4037
+    w1_w1 above_1919 4267
+    w1_w1 above_1920 3957
+    w1_w1 above_1921 4167
+    w1_w1 above_1922 4167
+    w1_w1 above_1923 4616
+    w1_w1 above_1924 4394
+    w1_w1 above_1925 4273
+    w1_w1 above_1926 4583
+    w1_w1 above_1927 4324
+    w1_w1 above_1928 4807
+    w1_w1 above_1929 4864
+    w1_w1 above_1930 4736
+    w1_w1 above_1931 4927
+    w1_w1 above_1932 4803
+    w1_w1 above_1933 4357
+    w1_w1 above_1934 4566
+    w1_w1 above_1935 4983
+    w1_w1 above_1936 4745
+    w1_w1 above_1937 4857
+    w1_w1 above_1938 4752
+    w1_w1 above_1939 5011
+    w1_w1 above_1940 4696
+    w1_w1 above_1941 4910
+    w1_w1 above_1942 4448
+    w1_w1 above_1943 5167
+    w1_w1 above_1944 5194
+    w1_w1 above_1945 4772
+    w1_w1 above_1946 5010
+    w1_w1 above_1947 5128
+    w1_w1 above_1948 5045
+    w1_w1 above_1949 4843
+    w1_w1 above_1950 4978
+    w1_w1 above_1951 5548
+    w1_w1 above_1952 5311
+    w1_w1 above_1953 5467
+    w1_w1 above_1954 5405
+    w1_w1 above_1955 5579
+    w1_w1 above_1956 5107
+    w1_w1 above_1957 5451
+    w1_w1 above_1958 5796
+    w1_w1 above_1959 5353
+    w1_w1 above_1960 5448
+    w1_w1 above_1961 5688
+    w1_w1 above_1962 5826
+    w1_w1 above_1963 5519
+    w1_w1 above_1964 5492
+    w1_w1 above_1965 6296
+    w1_w1 above_1966 5672
+    w1_w1 above_1967 5371
+    w1_w1 above_1968 5930
+    w1_w1 above_1969 5555
+    w1_w1 above_1970 6262
+    w1_w1 above_1971 5724
+    w1_w1 above_1972 5616
+    w1_w1 above_1973 5631
+    w1_w1 above_1974 6606
+    w1_w1 above_1975 5869
+    w1_w1 above_1976 5809
+    w1_w1 above_1977 6427
+    w1_w1 above_1978 6534
+    w1_w1 above_1979 5924
+    w1_w1 above_1980 6266
+    w1_w1 above_1981 6588
+    w1_w1 above_1982 6337
+    w1_w1 above_1983 6305
+    w1_w1 above_1984 6319
+    w1_w1 above_1985 6140
+    w1_w1 above_1986 5842
+    w1_w1 above_1987 6389
+    w1_w1 above_1988 6652
+    w1_w1 above_1989 6589
+    w1_w1 above_1990 6622
+    w1_w1 above_1991 5588
+    w1_w1 above_1992 6593
+    w1_w1 above_1993 7126
+    w1_w1 above_1994 7054
+    w1_w1 above_1995 6982
+    w1_w1 above_1996 6507
+    w1_w1 above_1997 7433
+    w1_w1 above_1998 6735
+    w1_w1 above_1999 6516
+    w1_w1 above_2000 7105
+    w1_w1 below_1800 -727
+    w1_w1 below_1801 -709
+    w1_w1 below_1802 -721
+    w1_w1 below_1803 -765
+    w1_w1 below_1804 -776
+    w1_w1 below_1805 -865
+    w1_w1 below_1806 -801
+    w1_w1 below_1807 -792
+    w1_w1 below_1808 -877
+    w1_w1 below_1809 -917
+    w1_w1 below_1810 -936
+    w1_w1 below_1811 -960
+    w1_w1 below_1812 -972
+    w1_w1 below_1813 -1074
+    w1_w1 below_1814 -1006
+    w1_w1 below_1815 -1034
+    w1_w1 below_1816 -1097
+    w1_w1 below_1817 -1087
+    w1_w1 below_1818 -1116
+    w1_w1 below_1819 -1060
+    w1_w1 below_1820 -1076
+    w1_w1 below_1821 -1209
+    w1_w1 below_1822 -1172
+    w1_w1 below_1823 -1300
+    w1_w1 below_1824 -1311
+    w1_w1 below_1825 -1134
+    w1_w1 below_1826 -1348
+    w1_w1 below_1827 -1354
+    w1_w1 below_1828 -1370
+    w1_w1 below_1829 -1442
+    w1_w1 below_1830 -1452
+    w1_w1 below_1831 -1479
+    w1_w1 below_1832 -1508
+    w1_w1 below_1833 -1449
+    w1_w1 below_1834 -1495
+    w1_w1 below_1835 -1417
+    w1_w1 below_1836 -1564
+    w1_w1 below_1837 -1650
+    w1_w1 below_1838 -1652
+    w1_w1 below_1839 -1726
+    w1_w1 below_1840 -1806
+    w1_w1 below_1841 -1828
+    w1_w1 below_1842 -1728
+    w1_w1 below_1843 -1662
+    w1_w1 below_1844 -1824
+    w1_w1 below_1845 -1793
+    w1_w1 below_1846 -1837
+    w1_w1 below_1847 -1900
+    w1_w1 below_1848 -1688
+    w1_w1 below_1849 -2085
+    w1_w1 below_1850 -1755
+    w1_w1 below_1851 -1943
+    w1_w1 below_1852 -1642
+    w1_w1 below_1853 -2045
+    w1_w1 below_1854 -2101
+    w1_w1 below_1855 -2041
+    w1_w1 below_1856 -1976
+    w1_w1 below_1857 -2059
+    w1_w1 below_1858 -2016
+    w1_w1 below_1859 -2106
+    w1_w1 below_1860 -2167
+    w1_w1 below_1861 -2280
+    w1_w1 below_1862 -2591
+    w1_w1 below_1863 -2349
+    w1_w1 below_1864 -2316
+    w1_w1 below_1865 -2542
+    w1_w1 below_1866 -2287
+    w1_w1 below_1867 -2410
+    w1_w1 below_1868 -2340
+    w1_w1 below_1869 -2231
+    w1_w1 below_1870 -2341
+    w1_w1 below_1871 -2562
+    w1_w1 below_1872 -2675
+    w1_w1 below_1873 -2613
+    w1_w1 below_1874 -2558
+    w1_w1 below_1875 -2446
+    w1_w1 below_1876 -2561
+    w1_w1 below_1877 -2762
+    w1_w1 below_1878 -2680
+    w1_w1 below_1879 -2893
+    w1_w1 below_1880 -2912
+    w1_w1 below_1881 -3008
+    w1_w1 below_1882 -2801
+    w1_w1 below_1883 -2736
+    w1_w1 below_1884 -2991
+    w1_w1 below_1885 -2942
+    w1_w1 below_1886 -3056
+    w1_w1 below_1887 -2750
+    w1_w1 below_1888 -3064
+    w1_w1 below_1889 -2904
+    w1_w1 below_1890 -3230
+    w1_w1 below_1891 -2827
+    w1_w1 below_1892 -3096
+    w1_w1 below_1893 -3264
+    w1_w1 below_1894 -3264
+    w1_w1 below_1895 -3208
+    w1_w1 below_1896 -3145
+    w1_w1 below_1897 -3529
+    w1_w1 below_1898 -3541
+    w1_w1 below_1899 -3828
+    w1_w1 below_1900 -3414
+    w1_w1 below_1901 -3602
+    w1_w1 below_1902 -3468
+    w1_w1 below_1903 -3605
+    w1_w1 below_1904 -3665
+    w1_w1 below_1905 -3773
+    w1_w1 below_1906 -3512
+    w1_w1 below_1907 -3800
+    w1_w1 below_1908 -3714
+    w1_w1 below_1909 -3980
+    w1_w1 below_1910 -4182
+    w1_w1 below_1911 -4052
+    w1_w1 below_1912 -4093
+    w1_w1 below_1913 -3605
+    w1_w1 below_1914 -3947
+    w1_w1 below_1915 -4365
+    w1_w1 below_1916 -3975
+    w1_w1 below_1917 -3958
+    w1_w1 below_1918 -4037
+    w1_w1 below_1919 -4267
+    w1_w1 below_1920 -3957
+    w1_w1 below_1921 -4167
+    w1_w1 below_1922 -4167
+    w1_w1 below_1923 -4616
+    w1_w1 below_1924 -4394
+    w1_w1 below_1925 -4273
+    w1_w1 below_1926 -4583
+    w1_w1 below_1927 -4324
+    w1_w1 below_1928 -4807
+    w1_w1 below_1929 -4864
+    w1_w1 below_1930 -4736
+    w1_w1 below_1931 -4927
+    w1_w1 below_1932 -4803
+    w1_w1 below_1933 -4357
+    w1_w1 below_1934 -4566
+    w1_w1 below_1935 -4983
+    w1_w1 below_1936 -4745
+    w1_w1 below_1937 -4857
+    w1_w1 below_1938 -4752
+    w1_w1 below_1939 -5011
+    w1_w1 below_1940 -4696
+    w1_w1 below_1941 -4910
+    w1_w1 below_1942 -4448
+    w1_w1 below_1943 -5167
+    w1_w1 below_1944 -5194
+    w1_w1 below_1945 -4772
+    w1_w1 below_1946 -5010
+    w1_w1 below_1947 -5128
+    w1_w1 below_1948 -5045
+    w1_w1 below_1949 -4843
+    w1_w1 below_1950 -4978
+    w1_w1 below_1951 -5548
+    w1_w1 below_1952 -5311
+    w1_w1 below_1953 -5467
+    w1_w1 below_1954 -5405
+    w1_w1 below_1955 -5579
+    w1_w1 below_1956 -5107
+    w1_w1 below_1957 -5451
+    w1_w1 below_1958 -5796
+    w1_w1 below_1959 -5353
+    w1_w1 below_1960 -5448
+    w1_w1 below_1961 -5688
+    w1_w1 below_1962 -5826
+    w1_w1 below_1963 -5519
+    w1_w1 below_1964 -5492
+    w1_w1 below_1965 -6296
+    w1_w1 below_1966 -5672
+    w1_w1 below_1967 -5371
+    w1_w1 below_1968 -5930
+    w1_w1 below_1969 -5555
+    w1_w1 below_1970 -6262
+    w1_w1 below_1971 -5724
+    w1_w1 below_1972 -5616
+    w1_w1 below_1973 -5631
+    w1_w1 below_1974 -6606
+    w1_w1 below_1975 -5869
+    w1_w1 below_1976 -5809
+    w1_w1 below_1977 -6427
+    w1_w1 below_1978 -6534
+    w1_w1 below_1979 -5924
+    w1_w1 below_1980 -6266
+    w1_w1 below_1981 -6588
+    w1_w1 below_1982 -6337
+    w1_w1 below_1983 -6305
+    w1_w1 below_1984 -6319
+    w1_w1 below_1985 -6140
+    w1_w1 below_1986 -5842
+    w1_w1 below_1987 -6389
+    w1_w1 below_1988 -6652
+    w1_w1 below_1989 -6589
+    w1_w1 below_1990 -6622
+    w1_w1 below_1991 -5588
+    w1_w1 below_1992 -6593
+    w1_w1 below_1993 -7126
+    w1_w1 below_1994 -7054
+    w1_w1 below_1995 -6982
+    w1_w1 below_1996 -6507
+    w1_w1 below_1997 -7433
+    w1_w1 below_1998 -6735
+    w1_w1 below_1999 -6516
+    w1_w1 below_2000 -7105
+    __t__ obj 1
+    __t__ above_1800 -1
+    __t__ above_1801 -1
+    __t__ above_1802 -1
+    __t__ above_1803 -1
+    __t__ above_1804 -1
+    __t__ above_1805 -1
+    __t__ above_1806 -1
+    __t__ above_1807 -1
+    __t__ above_1808 -1
+    __t__ above_1809 -1
+    __t__ above_1810 -1
+    __t__ above_1811 -1
+    __t__ above_1812 -1
+    __t__ above_1813 -1
+    __t__ above_1814 -1
+    __t__ above_1815 -1
+    __t__ above_1816 -1
+    __t__ above_1817 -1
+    __t__ above_1818 -1
+    __t__ above_1819 -1
+    __t__ above_1820 -1
+    __t__ above_1821 -1
+    __t__ above_1822 -1
+    __t__ above_1823 -1
+    __t__ above_1824 -1
+    __t__ above_1825 -1
+    __t__ above_1826 -1
+    __t__ above_1827 -1
+    __t__ above_1828 -1
+    __t__ above_1829 -1
+    __t__ above_1830 -1
+    __t__ above_1831 -1
+    __t__ above_1832 -1
+    __t__ above_1833 -1
+    __t__ above_1834 -1
+    __t__ above_1835 -1
+    __t__ above_1836 -1
+    __t__ above_1837 -1
+    __t__ above_1838 -1
+    __t__ above_1839 -1
+    __t__ above_1840 -1
+    __t__ above_1841 -1
+    __t__ above_1842 -1
+    __t__ above_1843 -1
+    __t__ above_1844 -1
+    __t__ above_1845 -1
+    __t__ above_1846 -1
+    __t__ above_1847 -1
+    __t__ above_1848 -1
+    __t__ above_1849 -1
+    __t__ above_1850 -1
+    __t__ above_1851 -1
+    __t__ above_1852 -1
+    __t__ above_1853 -1
+    __t__ above_1854 -1
+    __t__ above_1855 -1
+    __t__ above_1856 -1
+    __t__ above_1857 -1
+    __t__ above_1858 -1
+    __t__ above_1859 -1
+    __t__ above_1860 -1
+    __t__ above_1861 -1
+    __t__ above_1862 -1
+    __t__ above_1863 -1
+    __t__ above_1864 -1
+    __t__ above_1865 -1
+    __t__ above_1866 -1
+    __t__ above_1867 -1
+    __t__ above_1868 -1
+    __t__ above_1869 -1
+    __t__ above_1870 -1
+    __t__ above_1871 -1
+    __t__ above_1872 -1
+    __t__ above_1873 -1
+    __t__ above_1874 -1
+    __t__ above_1875 -1
+    __t__ above_1876 -1
+    __t__ above_1877 -1
+    __t__ above_1878 -1
+    __t__ above_1879 -1
+    __t__ above_1880 -1
+    __t__ above_1881 -1
+    __t__ above_1882 -1
+    __t__ above_1883 -1
+    __t__ above_1884 -1
+    __t__ above_1885 -1
+    __t__ above_1886 -1
+    __t__ above_1887 -1
+    __t__ above_1888 -1
+    __t__ above_1889 -1
+    __t__ above_1890 -1
+    __t__ above_1891 -1
+    __t__ above_1892 -1
+    __t__ above_1893 -1
+    __t__ above_1894 -1
+    __t__ above_1895 -1
+    __t__ above_1896 -1
+    __t__ above_1897 -1
+    __t__ above_1898 -1
+    __t__ above_1899 -1
+    __t__ above_1900 -1
+    __t__ above_1901 -1
+    __t__ above_1902 -1
+    __t__ above_1903 -1
+    __t__ above_1904 -1
+    __t__ above_1905 -1
+    __t__ above_1906 -1
+    __t__ above_1907 -1
+    __t__ above_1908 -1
+    __t__ above_1909 -1
+    __t__ above_1910 -1
+    __t__ above_1911 -1
+    __t__ above_1912 -1
+    __t__ above_1913 -1
+    __t__ above_1914 -1
+    __t__ above_1915 -1
+    __t__ above_1916 -1
+    __t__ above_1917 -1
+    __t__ above_1918 -1
+    __t__ above_1919 -1
+    __t__ above_1920 -1
+    __t__ above_1921 -1
+    __t__ above_1922 -1
+    __t__ above_1923 -1
+    __t__ above_1924 -1
+    __t__ above_1925 -1
+    __t__ above_1926 -1
+    __t__ above_1927 -1
+    __t__ above_1928 -1
+    __t__ above_1929 -1
+    __t__ above_1930 -1
+    __t__ above_1931 -1
+    __t__ above_1932 -1
+    __t__ above_1933 -1
+    __t__ above_1934 -1
+    __t__ above_1935 -1
+    __t__ above_1936 -1
+    __t__ above_1937 -1
+    __t__ above_1938 -1
+    __t__ above_1939 -1
+    __t__ above_1940 -1
+    __t__ above_1941 -1
+    __t__ above_1942 -1
+    __t__ above_1943 -1
+    __t__ above_1944 -1
+    __t__ above_1945 -1
+    __t__ above_1946 -1
+    __t__ above_1947 -1
+    __t__ above_1948 -1
+    __t__ above_1949 -1
+    __t__ above_1950 -1
+    __t__ above_1951 -1
+    __t__ above_1952 -1
+    __t__ above_1953 -1
+    __t__ above_1954 -1
+    __t__ above_1955 -1
+    __t__ above_1956 -1
+    __t__ above_1957 -1
+    __t__ above_1958 -1
+    __t__ above_1959 -1
+    __t__ above_1960 -1
+    __t__ above_1961 -1
+    __t__ above_1962 -1
+    __t__ above_1963 -1
+    __t__ above_1964 -1
+    __t__ above_1965 -1
+    __t__ above_1966 -1
+    __t__ above_1967 -1
+    __t__ above_1968 -1
+    __t__ above_1969 -1
+    __t__ above_1970 -1
+    __t__ above_1971 -1
+    __t__ above_1972 -1
+    __t__ above_1973 -1
+    __t__ above_1974 -1
+    __t__ above_1975 -1
+    __t__ above_1976 -1
+    __t__ above_1977 -1
+    __t__ above_1978 -1
+    __t__ above_1979 -1
+    __t__ above_1980 -1
+    __t__ above_1981 -1
+    __t__ above_1982 -1
+    __t__ above_1983 -1
+    __t__ above_1984 -1
+    __t__ above_1985 -1
+    __t__ above_1986 -1
+    __t__ above_1987 -1
+    __t__ above_1988 -1
+    __t__ above_1989 -1
+    __t__ above_1990 -1
+    __t__ above_1991 -1
+    __t__ above_1992 -1
+    __t__ above_1993 -1
+    __t__ above_1994 -1
+    __t__ above_1995 -1
+    __t__ above_1996 -1
+    __t__ above_1997 -1
+    __t__ above_1998 -1
+    __t__ above_1999 -1
+    __t__ above_2000 -1
+    __t__ below_1800 -1
+    __t__ below_1801 -1
+    __t__ below_1802 -1
+    __t__ below_1803 -1
+    __t__ below_1804 -1
+    __t__ below_1805 -1
+    __t__ below_1806 -1
+    __t__ below_1807 -1
+    __t__ below_1808 -1
+    __t__ below_1809 -1
+    __t__ below_1810 -1
+    __t__ below_1811 -1
+    __t__ below_1812 -1
+    __t__ below_1813 -1
+    __t__ below_1814 -1
+    __t__ below_1815 -1
+    __t__ below_1816 -1
+    __t__ below_1817 -1
+    __t__ below_1818 -1
+    __t__ below_1819 -1
+    __t__ below_1820 -1
+    __t__ below_1821 -1
+    __t__ below_1822 -1
+    __t__ below_1823 -1
+    __t__ below_1824 -1
+    __t__ below_1825 -1
+    __t__ below_1826 -1
+    __t__ below_1827 -1
+    __t__ below_1828 -1
+    __t__ below_1829 -1
+    __t__ below_1830 -1
+    __t__ below_1831 -1
+    __t__ below_1832 -1
+    __t__ below_1833 -1
+    __t__ below_1834 -1
+    __t__ below_1835 -1
+    __t__ below_1836 -1
+    __t__ below_1837 -1
+    __t__ below_1838 -1
+    __t__ below_1839 -1
+    __t__ below_1840 -1
+    __t__ below_1841 -1
+    __t__ below_1842 -1
+    __t__ below_1843 -1
+    __t__ below_1844 -1
+    __t__ below_1845 -1
+    __t__ below_1846 -1
+    __t__ below_1847 -1
+    __t__ below_1848 -1
+    __t__ below_1849 -1
+    __t__ below_1850 -1
+    __t__ below_1851 -1
+    __t__ below_1852 -1
+    __t__ below_1853 -1
+    __t__ below_1854 -1
+    __t__ below_1855 -1
+    __t__ below_1856 -1
+    __t__ below_1857 -1
+    __t__ below_1858 -1
+    __t__ below_1859 -1
+    __t__ below_1860 -1
+    __t__ below_1861 -1
+    __t__ below_1862 -1
+    __t__ below_1863 -1
+    __t__ below_1864 -1
+    __t__ below_1865 -1
+    __t__ below_1866 -1
+    __t__ below_1867 -1
+    __t__ below_1868 -1
+    __t__ below_1869 -1
+    __t__ below_1870 -1
+    __t__ below_1871 -1
+    __t__ below_1872 -1
+    __t__ below_1873 -1
+    __t__ below_1874 -1
+    __t__ below_1875 -1
+    __t__ below_1876 -1
+    __t__ below_1877 -1
+    __t__ below_1878 -1
+    __t__ below_1879 -1
+    __t__ below_1880 -1
+    __t__ below_1881 -1
+    __t__ below_1882 -1
+    __t__ below_1883 -1
+    __t__ below_1884 -1
+    __t__ below_1885 -1
+    __t__ below_1886 -1
+    __t__ below_1887 -1
+    __t__ below_1888 -1
+    __t__ below_1889 -1
+    __t__ below_1890 -1
+    __t__ below_1891 -1
+    __t__ below_1892 -1
+    __t__ below_1893 -1
+    __t__ below_1894 -1
+    __t__ below_1895 -1
+    __t__ below_1896 -1
+    __t__ below_1897 -1
+    __t__ below_1898 -1
+    __t__ below_1899 -1
+    __t__ below_1900 -1
+    __t__ below_1901 -1
+    __t__ below_1902 -1
+    __t__ below_1903 -1
+    __t__ below_1904 -1
+    __t__ below_1905 -1
+    __t__ below_1906 -1
+    __t__ below_1907 -1
+    __t__ below_1908 -1
+    __t__ below_1909 -1
+    __t__ below_1910 -1
+    __t__ below_1911 -1
+    __t__ below_1912 -1
+    __t__ below_1913 -1
+    __t__ below_1914 -1
+    __t__ below_1915 -1
+    __t__ below_1916 -1
+    __t__ below_1917 -1
+    __t__ below_1918 -1
+    __t__ below_1919 -1
+    __t__ below_1920 -1
+    __t__ below_1921 -1
+    __t__ below_1922 -1
+    __t__ below_1923 -1
+    __t__ below_1924 -1
+    __t__ below_1925 -1
+    __t__ below_1926 -1
+    __t__ below_1927 -1
+    __t__ below_1928 -1
+    __t__ below_1929 -1
+    __t__ below_1930 -1
+    __t__ below_1931 -1
+    __t__ below_1932 -1
+    __t__ below_1933 -1
+    __t__ below_1934 -1
+    __t__ below_1935 -1
+    __t__ below_1936 -1
+    __t__ below_1937 -1
+    __t__ below_1938 -1
+    __t__ below_1939 -1
+    __t__ below_1940 -1
+    __t__ below_1941 -1
+    __t__ below_1942 -1
+    __t__ below_1943 -1
+    __t__ below_1944 -1
+    __t__ below_1945 -1
+    __t__ below_1946 -1
+    __t__ below_1947 -1
+    __t__ below_1948 -1
+    __t__ below_1949 -1
+    __t__ below_1950 -1
+    __t__ below_1951 -1
+    __t__ below_1952 -1
+    __t__ below_1953 -1
+    __t__ below_1954 -1
+    __t__ below_1955 -1
+    __t__ below_1956 -1
+    __t__ below_1957 -1
+    __t__ below_1958 -1
+    __t__ below_1959 -1
+    __t__ below_1960 -1
+    __t__ below_1961 -1
+    __t__ below_1962 -1
+    __t__ below_1963 -1
+    __t__ below_1964 -1
+    __t__ below_1965 -1
+    __t__ below_1966 -1
+    __t__ below_1967 -1
+    __t__ below_1968 -1
+    __t__ below_1969 -1
+    __t__ below_1970 -1
+    __t__ below_1971 -1
+    __t__ below_1972 -1
+    __t__ below_1973 -1
+    __t__ below_1974 -1
+    __t__ below_1975 -1
+    __t__ below_1976 -1
+    __t__ below_1977 -1
+    __t__ below_1978 -1
+    __t__ below_1979 -1
+    __t__ below_1980 -1
+    __t__ below_1981 -1
+    __t__ below_1982 -1
+    __t__ below_1983 -1
+    __t__ below_1984 -1
+    __t__ below_1985 -1
+    __t__ below_1986 -1
+    __t__ below_1987 -1
+    __t__ below_1988 -1
+    __t__ below_1989 -1
+    __t__ below_1990 -1
+    __t__ below_1991 -1
+    __t__ below_1992 -1
+    __t__ below_1993 -1
+    __t__ below_1994 -1
+    __t__ below_1995 -1
+    __t__ below_1996 -1
+    __t__ below_1997 -1
+    __t__ below_1998 -1
+    __t__ below_1999 -1
+    __t__ below_2000 -1
+RHS
+    RHS above_1800 52
+    RHS above_1801 60
+    RHS above_1802 62
+    RHS above_1803 66
+    RHS above_1804 62
+    RHS above_1805 62
+    RHS above_1806 64
+    RHS above_1807 70
+    RHS above_1808 68
+    RHS above_1809 70
+    RHS above_1810 78
+    RHS above_1811 75
+    RHS above_1812 81
+    RHS above_1813 76
+    RHS above_1814 86
+    RHS above_1815 86
+    RHS above_1816 85
+    RHS above_1817 81
+    RHS above_1818 100
+    RHS above_1819 97
+    RHS above_1820 108
+    RHS above_1821 101
+    RHS above_1822 97
+    RHS above_1823 111
+    RHS above_1824 108
+    RHS above_1825 100
+    RHS above_1826 105
+    RHS above_1827 117
+    RHS above_1828 116
+    RHS above_1829 123
+    RHS above_1830 121
+    RHS above_1831 120
+    RHS above_1832 118
+    RHS above_1833 131
+    RHS above_1834 132
+    RHS above_1835 140
+    RHS above_1836 139
+    RHS above_1837 139
+    RHS above_1838 142
+    RHS above_1839 149
+    RHS above_1840 151
+    RHS above_1841 167
+    RHS above_1842 160
+    RHS above_1843 159
+    RHS above_1844 152
+    RHS above_1845 174
+    RHS above_1846 174
+    RHS above_1847 162
+    RHS above_1848 167
+    RHS above_1849 175
+    RHS above_1850 183
+    RHS above_1851 197
+    RHS above_1852 189
+    RHS above_1853 179
+    RHS above_1854 201
+    RHS above_1855 207
+    RHS above_1856 206
+    RHS above_1857 188
+    RHS above_1858 233
+    RHS above_1859 218
+    RHS above_1860 208
+    RHS above_1861 223
+    RHS above_1862 228
+    RHS above_1863 227
+    RHS above_1864 244
+    RHS above_1865 240
+    RHS above_1866 226
+    RHS above_1867 249
+    RHS above_1868 242
+    RHS above_1869 244
+    RHS above_1870 258
+    RHS above_1871 282
+    RHS above_1872 258
+    RHS above_1873 281
+    RHS above_1874 241
+    RHS above_1875 267
+    RHS above_1876 281
+    RHS above_1877 274
+    RHS above_1878 286
+    RHS above_1879 291
+    RHS above_1880 277
+    RHS above_1881 286
+    RHS above_1882 285
+    RHS above_1883 284
+    RHS above_1884 290
+    RHS above_1885 277
+    RHS above_1886 299
+    RHS above_1887 332
+    RHS above_1888 323
+    RHS above_1889 310
+    RHS above_1890 328
+    RHS above_1891 340
+    RHS above_1892 334
+    RHS above_1893 322
+    RHS above_1894 316
+    RHS above_1895 329
+    RHS above_1896 333
+    RHS above_1897 354
+    RHS above_1898 338
+    RHS above_1899 333
+    RHS above_1900 341
+    RHS above_1901 346
+    RHS above_1902 324
+    RHS above_1903 356
+    RHS above_1904 369
+    RHS above_1905 342
+    RHS above_1906 410
+    RHS above_1907 361
+    RHS above_1908 355
+    RHS above_1909 349
+    RHS above_1910 315
+    RHS above_1911 405
+    RHS above_1912 352
+    RHS above_1913 349
+    RHS above_1914 388
+    RHS above_1915 379
+    RHS above_1916 393
+    RHS above_1917 389
+    RHS above_1918 388
+    RHS above_1919 381
+    RHS above_1920 409
+    RHS above_1921 407
+    RHS above_1922 380
+    RHS above_1923 392
+    RHS above_1924 400
+    RHS above_1925 397
+    RHS above_1926 414
+    RHS above_1927 417
+    RHS above_1928 448
+    RHS above_1929 410
+    RHS above_1930 449
+    RHS above_1931 441
+    RHS above_1932 455
+    RHS above_1933 426
+    RHS above_1934 426
+    RHS above_1935 412
+    RHS above_1936 432
+    RHS above_1937 481
+    RHS above_1938 465
+    RHS above_1939 437
+    RHS above_1940 475
+    RHS above_1941 518
+    RHS above_1942 488
+    RHS above_1943 522
+    RHS above_1944 459
+    RHS above_1945 478
+    RHS above_1946 515
+    RHS above_1947 518
+    RHS above_1948 464
+    RHS above_1949 497
+    RHS above_1950 502
+    RHS above_1951 520
+    RHS above_1952 538
+    RHS above_1953 491
+    RHS above_1954 540
+    RHS above_1955 542
+    RHS above_1956 521
+    RHS above_1957 492
+    RHS above_1958 481
+    RHS above_1959 553
+    RHS above_1960 582
+    RHS above_1961 571
+    RHS above_1962 511
+    RHS above_1963 604
+    RHS above_1964 553
+    RHS above_1965 577
+    RHS above_1966 593
+    RHS above_1967 566
+    RHS above_1968 514
+    RHS above_1969 624
+    RHS above_1970 520
+    RHS above_1971 561
+    RHS above_1972 592
+    RHS above_1973 606
+    RHS above_1974 565
+    RHS above_1975 566
+    RHS above_1976 601
+    RHS above_1977 564
+    RHS above_1978 588
+    RHS above_1979 610
+    RHS above_1980 656
+    RHS above_1981 599
+    RHS above_1982 653
+    RHS above_1983 564
+    RHS above_1984 566
+    RHS above_1985 601
+    RHS above_1986 659
+    RHS above_1987 558
+    RHS above_1988 628
+    RHS above_1989 622
+    RHS above_1990 641
+    RHS above_1991 599
+    RHS above_1992 688
+    RHS above_1993 634
+    RHS above_1994 620
+    RHS above_1995 698
+    RHS above_1996 583
+    RHS above_1997 692
+    RHS above_1998 600
+    RHS above_1999 635
+    RHS above_2000 677
+    RHS below_1800 -52
+    RHS below_1801 -60
+    RHS below_1802 -62
+    RHS below_1803 -66
+    RHS below_1804 -62
+    RHS below_1805 -62
+    RHS below_1806 -64
+    RHS below_1807 -70
+    RHS below_1808 -68
+    RHS below_1809 -70
+    RHS below_1810 -78
+    RHS below_1811 -75
+    RHS below_1812 -81
+    RHS below_1813 -76
+    RHS below_1814 -86
+    RHS below_1815 -86
+    RHS below_1816 -85
+    RHS below_1817 -81
+    RHS below_1818 -100
+    RHS below_1819 -97
+    RHS below_1820 -108
+    RHS below_1821 -101
+    RHS below_1822 -97
+    RHS below_1823 -111
+    RHS below_1824 -108
+    RHS below_1825 -100
+    RHS below_1826 -105
+    RHS below_1827 -117
+    RHS below_1828 -116
+    RHS below_1829 -123
+    RHS below_1830 -121
+    RHS below_1831 -120
+    RHS below_1832 -118
+    RHS below_1833 -131
+    RHS below_1834 -132
+    RHS below_1835 -140
+    RHS below_1836 -139
+    RHS below_1837 -139
+    RHS below_1838 -142
+    RHS below_1839 -149
+    RHS below_1840 -151
+    RHS below_1841 -167
+    RHS below_1842 -160
+    RHS below_1843 -159
+    RHS below_1844 -152
+    RHS below_1845 -174
+    RHS below_1846 -174
+    RHS below_1847 -162
+    RHS below_1848 -167
+    RHS below_1849 -175
+    RHS below_1850 -183
+    RHS below_1851 -197
+    RHS below_1852 -189
+    RHS below_1853 -179
+    RHS below_1854 -201
+    RHS below_1855 -207
+    RHS below_1856 -206
+    RHS below_1857 -188
+    RHS below_1858 -233
+    RHS below_1859 -218
+    RHS below_1860 -208
+    RHS below_1861 -223
+    RHS below_1862 -228
+    RHS below_1863 -227
+    RHS below_1864 -244
+    RHS below_1865 -240
+    RHS below_1866 -226
+    RHS below_1867 -249
+    RHS below_1868 -242
+    RHS below_1869 -244
+    RHS below_1870 -258
+    RHS below_1871 -282
+    RHS below_1872 -258
+    RHS below_1873 -281
+    RHS below_1874 -241
+    RHS below_1875 -267
+    RHS below_1876 -281
+    RHS below_1877 -274
+    RHS below_1878 -286
+    RHS below_1879 -291
+    RHS below_1880 -277
+    RHS below_1881 -286
+    RHS below_1882 -285
+    RHS below_1883 -284
+    RHS below_1884 -290
+    RHS below_1885 -277
+    RHS below_1886 -299
+    RHS below_1887 -332
+    RHS below_1888 -323
+    RHS below_1889 -310
+    RHS below_1890 -328
+    RHS below_1891 -340
+    RHS below_1892 -334
+    RHS below_1893 -322
+    RHS below_1894 -316
+    RHS below_1895 -329
+    RHS below_1896 -333
+    RHS below_1897 -354
+    RHS below_1898 -338
+    RHS below_1899 -333
+    RHS below_1900 -341
+    RHS below_1901 -346
+    RHS below_1902 -324
+    RHS below_1903 -356
+    RHS below_1904 -369
+    RHS below_1905 -342
+    RHS below_1906 -410
+    RHS below_1907 -361
+    RHS below_1908 -355
+    RHS below_1909 -349
+    RHS below_1910 -315
+    RHS below_1911 -405
+    RHS below_1912 -352
+    RHS below_1913 -349
+    RHS below_1914 -388
+    RHS below_1915 -379
+    RHS below_1916 -393
+    RHS below_1917 -389
+    RHS below_1918 -388
+    RHS below_1919 -381
+    RHS below_1920 -409
+    RHS below_1921 -407
+    RHS below_1922 -380
+    RHS below_1923 -392
+    RHS below_1924 -400
+    RHS below_1925 -397
+    RHS below_1926 -414
+    RHS below_1927 -417
+    RHS below_1928 -448
+    RHS below_1929 -410
+    RHS below_1930 -449
+    RHS below_1931 -441
+    RHS below_1932 -455
+    RHS below_1933 -426
+    RHS below_1934 -426
+    RHS below_1935 -412
+    RHS below_1936 -432
+    RHS below_1937 -481
+    RHS below_1938 -465
+    RHS below_1939 -437
+    RHS below_1940 -475
+    RHS below_1941 -518
+    RHS below_1942 -488
+    RHS below_1943 -522
+    RHS below_1944 -459
+    RHS below_1945 -478
+    RHS below_1946 -515
+    RHS below_1947 -518
+    RHS below_1948 -464
+    RHS below_1949 -497
+    RHS below_1950 -502
+    RHS below_1951 -520
+    RHS below_1952 -538
+    RHS below_1953 -491
+    RHS below_1954 -540
+    RHS below_1955 -542
+    RHS below_1956 -521
+    RHS below_1957 -492
+    RHS below_1958 -481
+    RHS below_1959 -553
+    RHS below_1960 -582
+    RHS below_1961 -571
+    RHS below_1962 -511
+    RHS below_1963 -604
+    RHS below_1964 -553
+    RHS below_1965 -577
+    RHS below_1966 -593
+    RHS below_1967 -566
+    RHS below_1968 -514
+    RHS below_1969 -624
+    RHS below_1970 -520
+    RHS below_1971 -561
+    RHS below_1972 -592
+    RHS below_1973 -606
+    RHS below_1974 -565
+    RHS below_1975 -566
+    RHS below_1976 -601
+    RHS below_1977 -564
+    RHS below_1978 -588
+    RHS below_1979 -610
+    RHS below_1980 -656
+    RHS below_1981 -599
+    RHS below_1982 -653
+    RHS below_1983 -564
+    RHS below_1984 -566
+    RHS below_1985 -601
+    RHS below_1986 -659
+    RHS below_1987 -558
+    RHS below_1988 -628
+    RHS below_1989 -622
+    RHS below_1990 -641
+    RHS below_1991 -599
+    RHS below_1992 -688
+    RHS below_1993 -634
+    RHS below_1994 -620
+    RHS below_1995 -698
+    RHS below_1996 -583
+    RHS below_1997 -692
+    RHS below_1998 -600
+    RHS below_1999 -635
+    RHS below_2000 -677
+BOUNDS
+ENDATA
@@ -0,0 +1,2822 @@
+NAME w0_w3_w0-b960be3e88fd0586
+ROWS
+ N obj
+ L above_1800
+ L above_1801
+ L above_1802
+ L above_1803
+ L above_1804
+ L above_1805
+ L above_1806
+ L above_1807
+ L above_1808
+ L above_1809
+ L above_1810
+ L above_1811
+ L above_1812
+ L above_1813
+ L above_1814
+ L above_1815
+ L above_1816
+ L above_1817
+ L above_1818
+ L above_1819
+ L above_1820
+ L above_1821
+ L above_1822
+ L above_1823
+ L above_1824
+ L above_1825
+ L above_1826
+ L above_1827
+ L above_1828
+ L above_1829
+ L above_1830
+ L above_1831
+ L above_1832
+ L above_1833
+ L above_1834
+ L above_1835
+ L above_1836
+ L above_1837
+ L above_1838
+ L above_1839
+ L above_1840
+ L above_1841
+ L above_1842
+ L above_1843
+ L above_1844
+ L above_1845
+ L above_1846
+ L above_1847
+ L above_1848
+ L above_1849
+ L above_1850
+ L above_1851
+ L above_1852
+ L above_1853
+ L above_1854
+ L above_1855
+ L above_1856
+ L above_1857
+ L above_1858
+ L above_1859
+ L above_1860
+ L above_1861
+ L above_1862
+ L above_1863
+ L above_1864
+ L above_1865
+ L above_1866
+ L above_1867
+ L above_1868
+ L above_1869
+ L above_1870
+ L above_1871
+ L above_1872
+ L above_1873
+ L above_1874
+ L above_1875
+ L above_1876
+ L above_1877
+ L above_1878
+ L above_1879
+ L above_1880
+ L above_1881
+ L above_1882
+ L above_1883
+ L above_1884
+ L above_1885
+ L above_1886
+ L above_1887
+ L above_1888
+ L above_1889
+ L above_1890
+ L above_1891
+ L above_1892
+ L above_1893
+ L above_1894
+ L above_1895
+ L above_1896
+ L above_1897
+ L above_1898
+ L above_1899
+ L above_1900
+ L above_1901
+ L above_1902
+ L above_1903
+ L above_1904
+ L above_1905
+ L above_1906
+ L above_1907
+ L above_1908
+ L above_1909
+ L above_1910
+ L above_1911
+ L above_1912
+ L above_1913
+ L above_1914
+ L above_1915
+ L above_1916
+ L above_1917
+ L above_1918
+ L above_1919
+ L above_1920
+ L above_1921
+ L above_1922
+ L above_1923
+ L above_1924
+ L above_1925
+ L above_1926
+ L above_1927
+ L above_1928
+ L above_1929
+ L above_1930
+ L above_1931
+ L above_1932
+ L above_1933
+ L above_1934
+ L above_1935
+ L above_1936
+ L above_1937
+ L above_1938
+ L above_1939
+ L above_1940
+ L above_1941
+ L above_1942
+ L above_1943
+ L above_1944
+ L above_1945
+ L above_1946
+ L above_1947
+ L above_1948
+ L above_1949
+ L above_1950
+ L above_1951
+ L above_1952
+ L above_1953
+ L above_1954
+ L above_1955
+ L above_1956
+ L above_1957
+ L above_1958
+ L above_1959
+ L above_1960
+ L above_1961
+ L above_1962
+ L above_1963
+ L above_1964
+ L above_1965
+ L above_1966
+ L above_1967
+ L above_1968
+ L above_1969
+ L above_1970
+ L above_1971
+ L above_1972
+ L above_1973
+ L above_1974
+ L above_1975
+ L above_1976
+ L above_1977
+ L above_1978
+ L above_1979
+ L above_1980
+ L above_1981
+ L above_1982
+ L above_1983
+ L above_1984
+ L above_1985
+ L above_1986
+ L above_1987
+ L above_1988
+ L above_1989
+ L above_1990
+ L above_1991
+ L above_1992
+ L above_1993
+ L above_1994
+ L above_1995
+ L above_1996
+ L above_1997
+ L above_1998
+ L above_1999
+ L above_2000
+ L below_1800
+ L below_1801
+ L below_1802
+ L below_1803
+ L below_1804
+ L below_1805
+ L below_1806
+ L below_1807
+ L below_1808
+ L below_1809
+ L below_1810
+ L below_1811
+ L below_1812
+ L below_1813
+ L below_1814
+ L below_1815
+ L below_1816
+ L below_1817
+ L below_1818
+ L below_1819
+ L below_1820
+ L below_1821
+ L below_1822
+ L below_1823
+ L below_1824
+ L below_1825
+ L below_1826
+ L below_1827
+ L below_1828
+ L below_1829
+ L below_1830
+ L below_1831
+ L below_1832
+ L below_1833
+ L below_1834
+ L below_1835
+ L below_1836
+ L below_1837
+ L below_1838
+ L below_1839
+ L below_1840
+ L below_1841
+ L below_1842
+ L below_1843
+ L below_1844
+ L below_1845
+ L below_1846
+ L below_1847
+ L below_1848
+ L below_1849
+ L below_1850
+ L below_1851
+ L below_1852
+ L below_1853
+ L below_1854
+ L below_1855
+ L below_1856
+ L below_1857
+ L below_1858
+ L below_1859
+ L below_1860
+ L below_1861
+ L below_1862
+ L below_1863
+ L below_1864
+ L below_1865
+ L below_1866
+ L below_1867
+ L below_1868
+ L below_1869
+ L below_1870
+ L below_1871
+ L below_1872
+ L below_1873
+ L below_1874
+ L below_1875
+ L below_1876
+ L below_1877
+ L below_1878
+ L below_1879
+ L below_1880
+ L below_1881
+ L below_1882
+ L below_1883
+ L below_1884
+ L below_1885
+ L below_1886
+ L below_1887
+ L below_1888
+ L below_1889
+ L below_1890
+ L below_1891
+ L below_1892
+ L below_1893
+ L below_1894
+ L below_1895
+ L below_1896
+ L below_1897
+ L below_1898
+ L below_1899
+ L below_1900
+ L below_1901
+ L below_1902
+ L below_1903
+ L below_1904
+ L below_1905
+ L below_1906
+ L below_1907
+ L below_1908
+ L below_1909
+ L below_1910
+ L below_1911
+ L below_1912
+ L below_1913
+ L below_1914
+ L below_1915
+ L below_1916
+ L below_1917
+ L below_1918
+ L below_1919
+ L below_1920
+ L below_1921
+ L below_1922
+ L below_1923
+ L below_1924
+ L below_1925
+ L below_1926
+ L below_1927
+ L below_1928
+ L below_1929
+ L below_1930
+ L below_1931
+ L below_1932
+ L below_1933
+ L below_1934
+ L below_1935
+ L below_1936
+ L below_1937
+ L below_1938
+ L below_1939
+ L below_1940
+ L below_1941
+ L below_1942
+ L below_1943
+ L below_1944
+ L below_1945
+ L below_1946
+ L below_1947
+ L below_1948
+ L below_1949
+ L below_1950
+ L below_1951
+ L below_1952
+ L below_1953
+ L below_1954
+ L below_1955
+ L below_1956
+ L below_1957
+ L below_1958
+ L below_1959
+ L below_1960
+ L below_1961
+ L below_1962
+ L below_1963
+ L below_1964
+ L below_1965
+ L below_1966
+ L below_1967
+ L below_1968
+ L below_1969
+ L below_1970
+ L below_1971
+ L below_1972
+ L below_1973
+ L below_1974
+ L below_1975
+ L below_1976
+ L below_1977
+ L below_1978
+ L below_1979
+ L below_1980
+ L below_1981
+ L below_1982
+ L below_1983
+ L below_1984
+ L below_1985
+ L below_1986
+ L below_1987
+ L below_1988
+ L below_1989
+ L below_1990
+ L below_1991
+ L below_1992
+ L below_1993
+ L below_1994
+ L below_1995
+ L below_1996
+ L below_1997
+ L below_1998
+ L below_1999
+ L below_2000
+COLUMNS
+    w0 above_1800 51422
+    w0 above_1801 49243
+    w0 above_1802 51632
+    w0 above_1803 56497
+    w0 above_1804 62163
+    w0 above_1805 54862
+    w0 above_1806 60946
+    w0 above_1807 57189
+    w0 above_1808 62041
+    w0 above_1809 60099
+    w0 above_1810 67048
+    w0 above_1811 66630
+    w0 above_1812 68430
+    w0 above_1813 66835
+    w0 above_1814 68792
+    w0 above_1815 73721
+    w0 above_1816 72991
+    w0 above_1817 74461
+    w0 above_1818 70588
+    w0 above_1819 72598
+    w0 above_1820 70313
+    w0 above_1821 80058
+    w0 above_1822 79982
+    w0 above_1823 83562
+    w0 above_1824 78857
+    w0 above_1825 84733
+    w0 above_1826 84533
+    w0 above_1827 86684
+    w0 above_1828 92440
+    w0 above_1829 90404
+    w0 above_1830 99023
+    w0 above_1831 95010
+    w0 above_1832 89504
+    w0 above_1833 96055
+    w0 above_1834 101847
+    w0 above_1835 99440
+    w0 above_1836 97118
+    w0 above_1837 98861
+    w0 above_1838 107170
+    w0 above_1839 117222
+    w0 above_1840 100027
+    w0 above_1841 104022
+    w0 above_1842 110583
+    w0 above_1843 120174
+    w0 above_1844 123051
+    w0 above_1845 114845
+    w0 above_1846 115882
+    w0 above_1847 118138
+    w0 above_1848 126206
+    w0 above_1849 132716
+    w0 above_1850 126038
+    w0 above_1851 125001
+    w0 above_1852 119535
+    w0 above_1853 136287
+    w0 above_1854 123481
+    w0 above_1855 129220
+    w0 above_1856 132467
+    w0 above_1857 129788
+    w0 above_1858 137362
+    w0 above_1859 142732
+    w0 above_1860 138160
+    w0 above_1861 131516
+    w0 above_1862 150514
+    w0 above_1863 143334
+    w0 above_1864 143985
+    w0 above_1865 141525
+    w0 above_1866 167136
+    w0 above_1867 147658
+    w0 above_1868 156346
+    w0 above_1869 160966
+    w0 above_1870 146669
+    w0 above_1871 159061
+    w0 above_1872 160795
+    w0 above_1873 157895
+    w0 above_1874 167156
+    w0 above_1875 177177
+    w0 above_1876 168586
+    w0 above_1877 161651
+    w0 above_1878 153783
+    w0 above_1879 164740
+    w0 above_1880 170504
+    w0 above_1881 193250
+    w0 above_1882 168093
+    w0 above_1883 175926
+    w0 above_1884 170076
+    w0 above_1885 174629
+    w0 above_1886 173507
+    w0 above_1887 190112
+    w0 above_1888 200848
+    w0 above_1889 185130
+    w0 above_1890 187402
+    w0 above_1891 199916
+    w0 above_1892 197980
+    w0 above_1893 186324
+    w0 above_1894 189516
+    w0 above_1895 208523
+    w0 above_1896 207543
+    w0 above_1897 208976
+    w0 above_1898 199778
+    w0 above_1899 199030
+    w0 above_1900 220860
+    w0 above_1901 208072
+    w0 above_1902 232937
+    w0 above_1903 212698
+    w0 above_1904 219674
+    w0 above_1905 197633
+    w0 above_1906 220700
+    w0 above_1907 202257
+    w0 above_1908 210581
+    w0 above_1909 212066
+    w0 above_1910 219758
+    w0 above_1911 203996
+    w0 above_1912 214462
+    w0 above_1913 235181
+    w0 above_1914 234427
+    w0 above_1915 216230
+    w0 above_1916 209333
+    w0 above_1917 242237
+    w0 above_1918 250679
+    w0 above_1919 232106
+    w0 above_1920 221059
+    w0 above_1921 240767
+    w0 above_1922 224484
+    w0 above_1923 227616
+    w0 above_1924 243338
+    w0 above_1925 243747
+    w0 above_1926 247575
+    w0 above_1927 246773
+    w0 above_1928 224087
+    w0 above_1929 253617
+    w0 above_1930 249345
+    w0 above_1931 254756
+    w0 above_1932 246453
+    w0 above_1933 285284
+    w0 above_1934 262248
+    w0 above_1935 246708
+    w0 above_1936 257176
+    w0 above_1937 269376
+    w0 above_1938 251680
+    w0 above_1939 254913
+    w0 above_1940 253498
+    w0 above_1941 280108
+    w0 above_1942 264013
+    w0 above_1943 260556
+    w0 above_1944 237400
+    w0 above_1945 268362
+    w0 above_1946 256755
+    w0 above_1947 266028
+    w0 above_1948 277912
+    w0 above_1949 292117
+    w0 above_1950 268199
+    w0 above_1951 261424
+    w0 above_1952 259563
+    w0 above_1953 256153
+    w0 above_1954 283437
+    w0 above_1955 285567
+    w0 above_1956 289467
+    w0 above_1957 298888
+    w0 above_1958 301542
+    w0 above_1959 306129
+    w0 above_1960 290193
+    w0 above_1961 258337
+    w0 above_1962 289324
+    w0 above_1963 243394
+    w0 above_1964 291174
+    w0 above_1965 297056
+    w0 above_1966 281350
+    w0 above_1967 297824
+    w0 above_1968 292376
+    w0 above_1969 284537
+    w0 above_1970 279026
+    w0 above_1971 306382
+    w0 above_1972 290453
+    w0 above_1973 291454
+    w0 above_1974 315356
+    w0 above_1975 319830
+    w0 above_1976 308533
+    w0 above_1977 303503
+    w0 above_1978 293547
+    w0 above_1979 296333
+    w0 above_1980 305086
+    w0 above_1981 279551
+    w0 above_1982 296835
+    w0 above_1983 319476
+    w0 above_1984 286300
+    w0 above_1985 324819
+    w0 above_1986 331124
+    w0 above_1987 318146
+    w0 above_1988 277741
+    w0 above_1989 302499
+    w0 above_1990 294102
+    w0 above_1991 298467
+    w0 above_1992 320441
+    w0 above_1993 336698
+    w0 above_1994 317784
+    w0 above_1995 320671
+    w0 above_1996 348847
+    w0 above_1997 292322
+    w0 above_1998 308121
+    w0 above_1999 325227
+    w0 above_2000 298136
+    w0 below_1800 -51422
+    w0 below_1801 -49243
+    w0 below_1802 -51632
+    w0 below_1803 -56497
+    w0 below_1804 -62163
+    w0 below_1805 -54862
+    w0 below_1806 -60946
+    w0 below_1807 -57189
+    w0 below_1808 -62041
+    w0 below_1809 -60099
+    w0 below_1810 -67048
+    w0 below_1811 -66630
+    w0 below_1812 -68430
+    w0 below_1813 -66835
+    w0 below_1814 -68792
+    w0 below_1815 -73721
+    w0 below_1816 -72991
+    w0 below_1817 -74461
+    w0 below_1818 -70588
+    w0 below_1819 -72598
+    w0 below_1820 -70313
+    w0 below_1821 -80058
+    w0 below_1822 -79982
+    w0 below_1823 -83562
+    w0 below_1824 -78857
+    w0 below_1825 -84733
+    w0 below_1826 -84533
+    w0 below_1827 -86684
+    w0 below_1828 -92440
+    w0 below_1829 -90404
+    w0 below_1830 -99023
+    w0 below_1831 -95010
+    w0 below_1832 -89504
+    w0 below_1833 -96055
+    w0 below_1834 -101847
+    w0 below_1835 -99440
+    w0 below_1836 -97118
+    w0 below_1837 -98861
+    w0 below_1838 -107170
+    w0 below_1839 -117222
+    w0 below_1840 -100027
+    w0 below_1841 -104022
+    w0 below_1842 -110583
+    w0 below_1843 -120174
+    w0 below_1844 -123051
+    w0 below_1845 -114845
+    w0 below_1846 -115882
+    w0 below_1847 -118138
+    w0 below_1848 -126206
+    w0 below_1849 -132716
+    w0 below_1850 -126038
+    w0 below_1851 -125001
+    w0 below_1852 -119535
+    w0 below_1853 -136287
+    w0 below_1854 -123481
+    w0 below_1855 -129220
+    w0 below_1856 -132467
+    w0 below_1857 -129788
+    w0 below_1858 -137362
+    w0 below_1859 -142732
+    w0 below_1860 -138160
+    w0 below_1861 -131516
+    w0 below_1862 -150514
+    w0 below_1863 -143334
+    w0 below_1864 -143985
+    w0 below_1865 -141525
+    w0 below_1866 -167136
+    w0 below_1867 -147658
+    w0 below_1868 -156346
+    w0 below_1869 -160966
+    w0 below_1870 -146669
+    w0 below_1871 -159061
+    w0 below_1872 -160795
+    w0 below_1873 -157895
+    w0 below_1874 -167156
+    w0 below_1875 -177177
+    w0 below_1876 -168586
+    w0 below_1877 -161651
+    w0 below_1878 -153783
+    w0 below_1879 -164740
+    w0 below_1880 -170504
+    w0 below_1881 -193250
+    w0 below_1882 -168093
+    w0 below_1883 -175926
+    w0 below_1884 -170076
+    w0 below_1885 -174629
+    w0 below_1886 -173507
+    w0 below_1887 -190112
+    w0 below_1888 -200848
+    w0 below_1889 -185130
+    w0 below_1890 -187402
+    w0 below_1891 -199916
+    w0 below_1892 -197980
+    w0 below_1893 -186324
+    w0 below_1894 -189516
+    w0 below_1895 -208523
+    w0 below_1896 -207543
+    w0 below_1897 -208976
+    w0 below_1898 -199778
+    w0 below_1899 -199030
+    w0 below_1900 -220860
+    w0 below_1901 -208072
+    w0 below_1902 -232937
+    w0 below_1903 -212698
+    w0 below_1904 -219674
+    w0 below_1905 -197633
+    w0 below_1906 -220700
+    w0 below_1907 -202257
+    w0 below_1908 -210581
+    w0 below_1909 -212066
+    w0 below_1910 -219758
+    w0 below_1911 -203996
+    w0 below_1912 -214462
+    w0 below_1913 -235181
+    w0 below_1914 -234427
+    w0 below_1915 -216230
+    w0 below_1916 -209333
+    w0 below_1917 -242237
+    w0 below_1918 -250679
+    w0 below_1919 -232106
+    w0 below_1920 -221059
+    w0 below_1921 -240767
+    w0 below_1922 -224484
+    w0 below_1923 -227616
+    w0 below_1924 -243338
+    w0 below_1925 -243747
+    w0 below_1926 -247575
+    w0 below_1927 -246773
+    w0 below_1928 -224087
+    w0 below_1929 -253617
+    w0 below_1930 -249345
+    w0 below_1931 -254756
+    w0 below_1932 -246453
+    w0 below_1933 -285284
+    w0 below_1934 -262248
+    w0 below_1935 -246708
+    w0 below_1936 -257176
+    w0 below_1937 -269376
+    w0 below_1938 -251680
+    w0 below_1939 -254913
+    w0 below_1940 -253498
+    w0 below_1941 -280108
+    w0 below_1942 -264013
+    w0 below_1943 -260556
+    w0 below_1944 -237400
+    w0 below_1945 -268362
+    w0 below_1946 -256755
+    w0 below_1947 -266028
+    w0 below_1948 -277912
+    w0 below_1949 -292117
+    w0 below_1950 -268199
+    w0 below_1951 -261424
+    w0 below_1952 -259563
+    w0 below_1953 -256153
+    w0 below_1954 -283437
+    w0 below_1955 -285567
+    w0 below_1956 -289467
+    w0 below_1957 -298888
+    w0 below_1958 -301542
+    w0 below_1959 -306129
+    w0 below_1960 -290193
+    w0 below_1961 -258337
+    w0 below_1962 -289324
+    w0 below_1963 -243394
+    w0 below_1964 -291174
+    w0 below_1965 -297056
+    w0 below_1966 -281350
+    w0 below_1967 -297824
+    w0 below_1968 -292376
+    w0 below_1969 -284537
+    w0 below_1970 -279026
+    w0 below_1971 -306382
+    w0 below_1972 -290453
+    w0 below_1973 -291454
+    w0 below_1974 -315356
+    w0 below_1975 -319830
+    w0 below_1976 -308533
+    w0 below_1977 -303503
+    w0 below_1978 -293547
+    w0 below_1979 -296333
+    w0 below_1980 -305086
+    w0 below_1981 -279551
+    w0 below_1982 -296835
+    w0 below_1983 -319476
+    w0 below_1984 -286300
+    w0 below_1985 -324819
+    w0 below_1986 -331124
+    w0 below_1987 -318146
+    w0 below_1988 -277741
+    w0 below_1989 -302499
+    w0 below_1990 -294102
+    w0 below_1991 -298467
+    w0 below_1992 -320441
+    w0 below_1993 -336698
+    w0 below_1994 -317784
+    w0 below_1995 -320671
+    w0 below_1996 -348847
+    w0 below_1997 -292322
+    w0 below_1998 -308121
+    w0 below_1999 -325227
+    w0 below_2000 -298136
+    w3 above_1800 14316
+    w3 above_1801 13407
+    w3 above_1802 14381
+    w3 above_1803 14131
+    w3 above_1804 13880
+    w3 above_1805 13884
+    w3 above_1806 14769
+    w3 above_1807 15917
+    w3 above_1808 15949
+    w3 above_1809 18426
+    w3 above_1810 18853
+    w3 above_1811 18302
+    w3 above_1812 17670
+    w3 above_1813 18073
+    w3 above_1814 17361
+    w3 above_1815 19205
+    w3 above_1816 18917
+    w3 above_1817 19295
+    w3 above_1818 20697
+    w3 above_1819 22529
+    w3 above_1820 23114
+    w3 above_1821 20750
+    w3 above_1822 19752
+    w3 above_1823 23081
+    w3 above_1824 22598
+    w3 above_1825 21875
+    w3 above_1826 23057
+    w3 above_1827 24048
+    w3 above_1828 25112
+    w3 above_1829 25891
+    w3 above_1830 27407
+    w3 above_1831 26871
+    w3 above_1832 29588
+    w3 above_1833 22603
+    w3 above_1834 28662
+    w3 above_1835 29452
+    w3 above_1836 28749
+    w3 above_1837 28120
+    w3 above_1838 30973
+    w3 above_1839 29632
+    w3 above_1840 27725
+    w3 above_1841 31229
+    w3 above_1842 30283
+    w3 above_1843 33436
+    w3 above_1844 32951
+    w3 above_1845 32777
+    w3 above_1846 35403
+    w3 above_1847 37634
+    w3 above_1848 36107
+    w3 above_1849 36750
+    w3 above_1850 35508
+    w3 above_1851 39270
+    w3 above_1852 37827
+    w3 above_1853 36471
+    w3 above_1854 38510
+    w3 above_1855 40491
+    w3 above_1856 38878
+    w3 above_1857 41519
+    w3 above_1858 40123
+    w3 above_1859 41896
+    w3 above_1860 37803
+    w3 above_1861 39748
+    w3 above_1862 43916
+    w3 above_1863 43762
+    w3 above_1864 44829
+    w3 above_1865 42606
+    w3 above_1866 46872
+    w3 above_1867 43029
+    w3 above_1868 45465
+    w3 above_1869 43732
+    w3 above_1870 48580
+    w3 above_1871 47650
+    w3 above_1872 47540
+    w3 above_1873 49581
+    w3 above_1874 47533
+    w3 above_1875 50620
+    w3 above_1876 51193
+    w3 above_1877 52359
+    w3 above_1878 53318
+    w3 above_1879 49850
+    w3 above_1880 50019
+    w3 above_1881 53945
+    w3 above_1882 53139
+    w3 above_1883 54610
+    w3 above_1884 57990
+    w3 above_1885 55110
+    w3 above_1886 57883
+    w3 above_1887 51034
+    w3 above_1888 52407
+    w3 above_1889 58642
+    w3 above_1890 57998
+    w3 above_1891 59802
+    w3 above_1892 58280
+    w3 above_1893 55499
+    w3 above_1894 55839
+    w3 above_1895 61323
+    w3 above_1896 58404
+    w3 above_1897 64198
+    w3 above_1898 66863
+    w3 above_1899 57866
+    w3 above_1900 61499
+    w3 above_1901 59126
+    w3 above_1902 64222
+    w3 above_1903 62034
+    w3 above_1904 62437
+    w3 above_1905 63867
+    w3 above_1906 64471
+    w3 above_1907 68096
+    w3 above_1908 65408
+    w3 above_1909 64898
+    w3 above_1910 64844
+    w3 above_1911 73523
+    w3 above_1912 72454
+    w3 above_1913 66957
+    w3 above_1914 68450
+    w3 above_1915 69092
+    w3 above_1916 68418
+    w3 above_1917 69794
+    w3 above_1918 66719
+    w3 above_1919 67895
+    w3 above_1920 70320
+    w3 above_1921 69888
+    w3 above_1922 76086
+    w3 above_1923 78735
+    w3 above_1924 78221
+    w3 above_1925 69956
+    w3 above_1926 73745
+    w3 above_1927 78499
+    w3 above_1928 76916
+    w3 above_1929 77258
+    w3 above_1930 71852
+    w3 above_1931 77665
+    w3 above_1932 79954
+    w3 above_1933 76570
+    w3 above_1934 78678
+    w3 above_1935 79478
+    w3 above_1936 75418
+    w3 above_1937 72255
+    w3 above_1938 71568
+    w3 above_1939 88628
+    w3 above_1940 82182
+    w3 above_1941 80830
+    w3 above_1942 88442
+    w3 above_1943 77801
+    w3 above_1944 80214
+    w3 above_1945 81476
+    w3 above_1946 82551
+    w3 above_1947 89148
+    w3 above_1948 86396
+    w3 above_1949 88409
+    w3 above_1950 84111
+    w3 above_1951 78253
+    w3 above_1952 83040
+    w3 above_1953 89434
+    w3 above_1954 82328
+    w3 above_1955 82790
+    w3 above_1956 87529
+    w3 above_1957 90132
+    w3 above_1958 86925
+    w3 above_1959 80587
+    w3 above_1960 83352
+    w3 above_1961 84003
+    w3 above_1962 92689
+    w3 above_1963 86989
+    w3 above_1964 84466
+    w3 above_1965 89647
+    w3 above_1966 92231
+    w3 above_1967 89271
+    w3 above_1968 89750
+    w3 above_1969 81258
+    w3 above_1970 88627
+    w3 above_1971 88573
+    w3 above_1972 91967
+    w3 above_1973 94888
+    w3 above_1974 107140
+    w3 above_1975 84686
+    w3 above_1976 88640
+    w3 above_1977 99836
+    w3 above_1978 95159
+    w3 above_1979 88051
+    w3 above_1980 94166
+    w3 above_1981 95329
+    w3 above_1982 96631
+    w3 above_1983 96544
+    w3 above_1984 96725
+    w3 above_1985 90644
+    w3 above_1986 92603
+    w3 above_1987 104216
+    w3 above_1988 100382
+    w3 above_1989 96287
+    w3 above_1990 97278
+    w3 above_1991 92309
+    w3 above_1992 91911
+    w3 above_1993 89139
+    w3 above_1994 93469
+    w3 above_1995 96587
+    w3 above_1996 96422
+    w3 above_1997 95196
+    w3 above_1998 94612
+    w3 above_1999 103450
+    w3 above_2000 99627
+    w3 below_1800 -14316
+    w3 below_1801 -13407
+    w3 below_1802 -14381
+    w3 below_1803 -14131
+    w3 below_1804 -13880
+    w3 below_1805 -13884
+    w3 below_1806 -14769
+    w3 below_1807 -15917
+    w3 below_1808 -15949
+    w3 below_1809 -18426
+    w3 below_1810 -18853
+    w3 below_1811 -18302
+    w3 below_1812 -17670
+    w3 below_1813 -18073
+    w3 below_1814 -17361
+    w3 below_1815 -19205
+    w3 below_1816 -18917
+    w3 below_1817 -19295
+    w3 below_1818 -20697
+    w3 below_1819 -22529
+    w3 below_1820 -23114
+    w3 below_1821 -20750
+    w3 below_1822 -19752
+    w3 below_1823 -23081
+    w3 below_1824 -22598
+    w3 below_1825 -21875
+    w3 below_1826 -23057
+    w3 below_1827 -24048
+    w3 below_1828 -25112
+    w3 below_1829 -25891
+    w3 below_1830 -27407
+    w3 below_1831 -26871
+    w3 below_1832 -29588
+    w3 below_1833 -22603
+    w3 below_1834 -28662
+    w3 below_1835 -29452
+    w3 below_1836 -28749
+    w3 below_1837 -28120
+    w3 below_1838 -30973
+    w3 below_1839 -29632
+    w3 below_1840 -27725
+    w3 below_1841 -31229
+    w3 below_1842 -30283
+    w3 below_1843 -33436
+    w3 below_1844 -32951
+    w3 below_1845 -32777
+    w3 below_1846 -35403
+    w3 below_1847 -37634
+    w3 below_1848 -36107
+    w3 below_1849 -36750
+    w3 below_1850 -35508
+    w3 below_1851 -39270
+    w3 below_1852 -37827
+    w3 below_1853 -36471
+    w3 below_1854 -38510
+    w3 below_1855 -40491
+    w3 below_1856 -38878
+    w3 below_1857 -41519
+    w3 below_1858 -40123
+    w3 below_1859 -41896
+    w3 below_1860 -37803
+    w3 below_1861 -39748
+    w3 below_1862 -43916
+    w3 below_1863 -43762
+    w3 below_1864 -44829
+    w3 below_1865 -42606
+    w3 below_1866 -46872
+    w3 below_1867 -43029
+    w3 below_1868 -45465
+    w3 below_1869 -43732
+    w3 below_1870 -48580
+    w3 below_1871 -47650
+    w3 below_1872 -47540
+    w3 below_1873 -49581
+    w3 below_1874 -47533
+    w3 below_1875 -50620
+    w3 below_1876 -51193
+    w3 below_1877 -52359
+    w3 below_1878 -53318
+    w3 below_1879 -49850
+    w3 below_1880 -50019
+    w3 below_1881 -53945
+    w3 below_1882 -53139
+    w3 below_1883 -54610
+    w3 below_1884 -57990
+    w3 below_1885 -55110
+    w3 below_1886 -57883
+    w3 below_1887 -51034
+    w3 below_1888 -52407
+    w3 below_1889 -58642
+    w3 below_1890 -57998
+    w3 below_1891 -59802
+    w3 below_1892 -58280
+    w3 below_1893 -55499
+    w3 below_1894 -55839
+    w3 below_1895 -61323
+    w3 below_1896 -58404
+    w3 below_1897 -64198
+    w3 below_1898 -66863
+    w3 below_1899 -57866
+    w3 below_1900 -61499
+    w3 below_1901 -59126
+    w3 below_1902 -64222
+    w3 below_1903 -62034
+    w3 below_1904 -62437
+    w3 below_1905 -63867
+    w3 below_1906 -64471
+    w3 below_1907 -68096
+    w3 below_1908 -65408
+    w3 below_1909 -64898
+    w3 below_1910 -64844
+    w3 below_1911 -73523
+    w3 below_1912 -72454
+    w3 below_1913 -66957
+    w3 below_1914 -68450
+    w3 below_1915 -69092
+    w3 below_1916 -68418
+    w3 below_1917 -69794
+    w3 below_1918 -66719
+    w3 below_1919 -67895
+    w3 below_1920 -70320
+    w3 below_1921 -69888
+    w3 below_1922 -76086
+    w3 below_1923 -78735
+    w3 below_1924 -78221
+    w3 below_1925 -69956
+    w3 below_1926 -73745
+    w3 below_1927 -78499
+    w3 below_1928 -76916
+    w3 below_1929 -77258
+    w3 below_1930 -71852
+    w3 below_1931 -77665
+    w3 below_1932 -79954
+    w3 below_1933 -76570
+    w3 below_1934 -78678
+    w3 below_1935 -79478
+    w3 below_1936 -75418
+    w3 below_1937 -72255
+    w3 below_1938 -71568
+    w3 below_1939 -88628
+    w3 below_1940 -82182
+    w3 below_1941 -80830
+    w3 below_1942 -88442
+    w3 below_1943 -77801
+    w3 below_1944 -80214
+    w3 below_1945 -81476
+    w3 below_1946 -82551
+    w3 below_1947 -89148
+    w3 below_1948 -86396
+    w3 below_1949 -88409
+    w3 below_1950 -84111
+    w3 below_1951 -78253
+    w3 below_1952 -83040
+    w3 below_1953 -89434
+    w3 below_1954 -82328
+    w3 below_1955 -82790
+    w3 below_1956 -87529
+    w3 below_1957 -90132
+    w3 below_1958 -86925
+    w3 below_1959 -80587
+    w3 below_1960 -83352
+    w3 below_1961 -84003
+    w3 below_1962 -92689
+    w3 below_1963 -86989
+    w3 below_1964 -84466
+    w3 below_1965 -89647
+    w3 below_1966 -92231
+    w3 below_1967 -89271
+    w3 below_1968 -89750
+    w3 below_1969 -81258
+    w3 below_1970 -88627
+    w3 below_1971 -88573
+    w3 below_1972 -91967
+    w3 below_1973 -94888
+    w3 below_1974 -107140
+    w3 below_1975 -84686
+    w3 below_1976 -88640
+    w3 below_1977 -99836
+    w3 below_1978 -95159
+    w3 below_1979 -88051
+    w3 below_1980 -94166
+    w3 below_1981 -95329
+    w3 below_1982 -96631
+    w3 below_1983 -96544
+    w3 below_1984 -96725
+    w3 below_1985 -90644
+    w3 below_1986 -92603
+    w3 below_1987 -104216
+    w3 below_1988 -100382
+    w3 below_1989 -96287
+    w3 below_1990 -97278
+    w3 below_1991 -92309
+    w3 below_1992 -91911
+    w3 below_1993 -89139
+    w3 below_1994 -93469
+    w3 below_1995 -96587
+    w3 below_1996 -96422
+    w3 below_1997 -95196
+    w3 below_1998 -94612
+    w3 below_1999 -103450
+    w3 below_2000 -99627
+    __intercept__ above_1800 1
+    __intercept__ above_1801 1
+    __intercept__ above_1802 1
+    __intercept__ above_1803 1
+    __intercept__ above_1804 1
+    __intercept__ above_1805 1
+    __intercept__ above_1806 1
+    __intercept__ above_1807 1
+    __intercept__ above_1808 1
+    __intercept__ above_1809 1
+    __intercept__ above_1810 1
+    __intercept__ above_1811 1
+    __intercept__ above_1812 1
+    __intercept__ above_1813 1
+    __intercept__ above_1814 1
+    __intercept__ above_1815 1
+    __intercept__ above_1816 1
+    __intercept__ above_1817 1
+    __intercept__ above_1818 1
+    __intercept__ above_1819 1
+    __intercept__ above_1820 1
+    __intercept__ above_1821 1
+    __intercept__ above_1822 1
+    __intercept__ above_1823 1
+    __intercept__ above_1824 1
+    __intercept__ above_1825 1
+    __intercept__ above_1826 1
+    __intercept__ above_1827 1
+    __intercept__ above_1828 1
+    __intercept__ above_1829 1
+    __intercept__ above_1830 1
+    __intercept__ above_1831 1
+    __intercept__ above_1832 1
+    __intercept__ above_1833 1
+    __intercept__ above_1834 1
+    __intercept__ above_1835 1
+    __intercept__ above_1836 1
+    __intercept__ above_1837 1
+    __intercept__ above_1838 1
+    __intercept__ above_1839 1
+    __intercept__ above_1840 1
+    __intercept__ above_1841 1
+    __intercept__ above_1842 1
+    __intercept__ above_1843 1
+    __intercept__ above_1844 1
+    __intercept__ above_1845 1
+    __intercept__ above_1846 1
+    __intercept__ above_1847 1
+    __intercept__ above_1848 1
+    __intercept__ above_1849 1
+    __intercept__ above_1850 1
+    __intercept__ above_1851 1
+    __intercept__ above_1852 1
+    __intercept__ above_1853 1
+    __intercept__ above_1854 1
+    __intercept__ above_1855 1
+    __intercept__ above_1856 1
+    __intercept__ above_1857 1
+    __intercept__ above_1858 1
+    __intercept__ above_1859 1
+    __intercept__ above_1860 1
+    __intercept__ above_1861 1
+    __intercept__ above_1862 1
+    __intercept__ above_1863 1
+    __intercept__ above_1864 1
+    __intercept__ above_1865 1
+    __intercept__ above_1866 1
+    __intercept__ above_1867 1
+    __intercept__ above_1868 1
+    __intercept__ above_1869 1
+    __intercept__ above_1870 1
+    __intercept__ above_1871 1
+    __intercept__ above_1872 1
+    __intercept__ above_1873 1
+    __intercept__ above_1874 1
+    __intercept__ above_1875 1
+    __intercept__ above_1876 1
+    __intercept__ above_1877 1
+    __intercept__ above_1878 1
+    __intercept__ above_1879 1
+    __intercept__ above_1880 1
+    __intercept__ above_1881 1
+    __intercept__ above_1882 1
+    __intercept__ above_1883 1
+    __intercept__ above_1884 1
+    __intercept__ above_1885 1
+    __intercept__ above_1886 1
+    __intercept__ above_1887 1
+    __intercept__ above_1888 1
+    __intercept__ above_1889 1
+    __intercept__ above_1890 1
+    __intercept__ above_1891 1
+    __intercept__ above_1892 1
+    __intercept__ above_1893 1
+    __intercept__ above_1894 1
+    __intercept__ above_1895 1
+    __intercept__ above_1896 1
+    __intercept__ above_1897 1
+    __intercept__ above_1898 1
+    __intercept__ above_1899 1
+    __intercept__ above_1900 1
+    __intercept__ above_1901 1
+    __intercept__ above_1902 1
+    __intercept__ above_1903 1
+    __intercept__ above_1904 1
+    __intercept__ above_1905 1
+    __intercept__ above_1906 1
+    __intercept__ above_1907 1
+    __intercept__ above_1908 1
+    __intercept__ above_1909 1
+    __intercept__ above_1910 1
+    __intercept__ above_1911 1
+    __intercept__ above_1912 1
+    __intercept__ above_1913 1
+    __intercept__ above_1914 1
+    __intercept__ above_1915 1
+    __intercept__ above_1916 1
+    __intercept__ above_1917 1
+    __intercept__ above_1918 1
+    __intercept__ above_1919 1
+    __intercept__ above_1920 1
+    __intercept__ above_1921 1
+    __intercept__ above_1922 1
+    __intercept__ above_1923 1
+    __intercept__ above_1924 1
+    __intercept__ above_1925 1
+    __intercept__ above_1926 1
+    __intercept__ above_1927 1
+    __intercept__ above_1928 1
+    __intercept__ above_1929 1
+    __intercept__ above_1930 1
+    __intercept__ above_1931 1
+    __intercept__ above_1932 1
+    __intercept__ above_1933 1
+    __intercept__ above_1934 1
+    __intercept__ above_1935 1
+    __intercept__ above_1936 1
+    __intercept__ above_1937 1
+    __intercept__ above_1938 1
+    __intercept__ above_1939 1
+    __intercept__ above_1940 1
+    __intercept__ above_1941 1
+    __intercept__ above_1942 1
+    __intercept__ above_1943 1
+    __intercept__ above_1944 1
+    __intercept__ above_1945 1
+    __intercept__ above_1946 1
+    __intercept__ above_1947 1
+    __intercept__ above_1948 1
+    __intercept__ above_1949 1
+    __intercept__ above_1950 1
+    __intercept__ above_1951 1
+    __intercept__ above_1952 1
+    __intercept__ above_1953 1
+    __intercept__ above_1954 1
+    __intercept__ above_1955 1
+    __intercept__ above_1956 1
+    __intercept__ above_1957 1
+    __intercept__ above_1958 1
+    __intercept__ above_1959 1
+    __intercept__ above_1960 1
+    __intercept__ above_1961 1
+    __intercept__ above_1962 1
+    __intercept__ above_1963 1
+    __intercept__ above_1964 1
+    __intercept__ above_1965 1
+    __intercept__ above_1966 1
+    __intercept__ above_1967 1
+    __intercept__ above_1968 1
+    __intercept__ above_1969 1
+    __intercept__ above_1970 1
+    __intercept__ above_1971 1
+    __intercept__ above_1972 1
+    __intercept__ above_1973 1
+    __intercept__ above_1974 1
+    __intercept__ above_1975 1
+    __intercept__ above_1976 1
+    __intercept__ above_1977 1
+    __intercept__ above_1978 1
+    __intercept__ above_1979 1
+    __intercept__ above_1980 1
+    __intercept__ above_1981 1
+    __intercept__ above_1982 1
+    __intercept__ above_1983 1
+    __intercept__ above_1984 1
+    __intercept__ above_1985 1
+    __intercept__ above_1986 1
+    __intercept__ above_1987 1
+    __intercept__ above_1988 1
+    __intercept__ above_1989 1
+    __intercept__ above_1990 1
+    __intercept__ above_1991 1
+    __intercept__ above_1992 1
+    __intercept__ above_1993 1
+    __intercept__ above_1994 1
+    __intercept__ above_1995 1
+    __intercept__ above_1996 1
+    __intercept__ above_1997 1
+    __intercept__ above_1998 1
+    __intercept__ above_1999 1
+    __intercept__ above_2000 1
+    __intercept__ below_1800 -1
+    __intercept__ below_1801 -1
+    __intercept__ below_1802 -1
+    __intercept__ below_1803 -1
+    __intercept__ below_1804 -1
+    __intercept__ below_1805 -1
+    __intercept__ below_1806 -1
+    __intercept__ below_1807 -1
+    __intercept__ below_1808 -1
+    __intercept__ below_1809 -1
+    __intercept__ below_1810 -1
+    __intercept__ below_1811 -1
+    __intercept__ below_1812 -1
+    __intercept__ below_1813 -1
+    __intercept__ below_1814 -1
+    __intercept__ below_1815 -1
+    __intercept__ below_1816 -1
+    __intercept__ below_1817 -1
+    __intercept__ below_1818 -1
+    __intercept__ below_1819 -1
+    __intercept__ below_1820 -1
+    __intercept__ below_1821 -1
+    __intercept__ below_1822 -1
+    __intercept__ below_1823 -1
+    __intercept__ below_1824 -1
+    __intercept__ below_1825 -1
+    __intercept__ below_1826 -1
+    __intercept__ below_1827 -1
+    __intercept__ below_1828 -1
+    __intercept__ below_1829 -1
+    __intercept__ below_1830 -1
+    __intercept__ below_1831 -1
+    __intercept__ below_1832 -1
+    __intercept__ below_1833 -1
+    __intercept__ below_1834 -1
+    __intercept__ below_1835 -1
+    __intercept__ below_1836 -1
+    __intercept__ below_1837 -1
+    __intercept__ below_1838 -1
+    __intercept__ below_1839 -1
+    __intercept__ below_1840 -1
+    __intercept__ below_1841 -1
+    __intercept__ below_1842 -1
+    __intercept__ below_1843 -1
+    __intercept__ below_1844 -1
+    __intercept__ below_1845 -1
+    __intercept__ below_1846 -1
+    __intercept__ below_1847 -1
+    __intercept__ below_1848 -1
+    __intercept__ below_1849 -1
+    __intercept__ below_1850 -1
+    __intercept__ below_1851 -1
+    __intercept__ below_1852 -1
+    __intercept__ below_1853 -1
+    __intercept__ below_1854 -1
+    __intercept__ below_1855 -1
+    __intercept__ below_1856 -1
+    __intercept__ below_1857 -1
+    __intercept__ below_1858 -1
+    __intercept__ below_1859 -1
+    __intercept__ below_1860 -1
+    __intercept__ below_1861 -1
+    __intercept__ below_1862 -1
+    __intercept__ below_1863 -1
+    __intercept__ below_1864 -1
+    __intercept__ below_1865 -1
+    __intercept__ below_1866 -1
+    __intercept__ below_1867 -1
+    __intercept__ below_1868 -1
+    __intercept__ below_1869 -1
+    __intercept__ below_1870 -1
+    __intercept__ below_1871 -1
+    __intercept__ below_1872 -1
+    __intercept__ below_1873 -1
+    __intercept__ below_1874 -1
+    __intercept__ below_1875 -1
+    __intercept__ below_1876 -1
+    __intercept__ below_1877 -1
+    __intercept__ below_1878 -1
+    __intercept__ below_1879 -1
+    __intercept__ below_1880 -1
+    __intercept__ below_1881 -1
+    __intercept__ below_1882 -1
+    __intercept__ below_1883 -1
+    __intercept__ below_1884 -1
+    __intercept__ below_1885 -1
+    __intercept__ below_1886 -1
+    __intercept__ below_1887 -1
+    __intercept__ below_1888 -1
+    __intercept__ below_1889 -1
+    __intercept__ below_1890 -1
+    __intercept__ below_1891 -1
+    __intercept__ below_1892 -1
+    __intercept__ below_1893 -1
+    __intercept__ below_1894 -1
+    __intercept__ below_1895 -1
+    __intercept__ below_1896 -1
+    __intercept__ below_1897 -1
+    __intercept__ below_1898 -1
+    __intercept__ below_1899 -1
+    __intercept__ below_1900 -1
+    __intercept__ below_1901 -1
+    __intercept__ below_1902 -1
+    __intercept__ below_1903 -1
+    __intercept__ below_1904 -1
+    __intercept__ below_1905 -1
+    __intercept__ below_1906 -1
+    __intercept__ below_1907 -1
+    __intercept__ below_1908 -1
+    __intercept__ below_1909 -1
+    __intercept__ below_1910 -1
+    __intercept__ below_1911 -1
+    __intercept__ below_1912 -1
+    __intercept__ below_1913 -1
+    __intercept__ below_1914 -1
+    __intercept__ below_1915 -1
+    __intercept__ below_1916 -1
+    __intercept__ below_1917 -1
+    __intercept__ below_1918 -1
+    __intercept__ below_1919 -1
+    __intercept__ below_1920 -1
+    __intercept__ below_1921 -1
+    __intercept__ below_1922 -1
+    __intercept__ below_1923 -1
+    __intercept__ below_1924 -1
+    __intercept__ below_1925 -1
+    __intercept__ below_1926 -1
+    __intercept__ below_1927 -1
+    __intercept__ below_1928 -1
+    __intercept__ below_1929 -1
+    __intercept__ below_1930 -1
+    __intercept__ below_1931 -1
+    __intercept__ below_1932 -1
+    __intercept__ below_1933 -1
+    __intercept__ below_1934 -1
+    __intercept__ below_1935 -1
+    __intercept__ below_1936 -1
+    __intercept__ below_1937 -1
+    __intercept__ below_1938 -1
+    __intercept__ below_1939 -1
+    __intercept__ below_1940 -1
+    __intercept__ below_1941 -1
+    __intercept__ below_1942 -1
+    __intercept__ below_1943 -1
+    __intercept__ below_1944 -1
+    __intercept__ below_1945 -1
+    __intercept__ below_1946 -1
+    __intercept__ below_1947 -1
+    __intercept__ below_1948 -1
+    __intercept__ below_1949 -1
+    __intercept__ below_1950 -1
+    __intercept__ below_1951 -1
+    __intercept__ below_1952 -1
+    __intercept__ below_1953 -1
+    __intercept__ below_1954 -1
+    __intercept__ below_1955 -1
+    __intercept__ below_1956 -1
+    __intercept__ below_1957 -1
+    __intercept__ below_1958 -1
+    __intercept__ below_1959 -1
+    __intercept__ below_1960 -1
+    __intercept__ below_1961 -1
+    __intercept__ below_1962 -1
+    __intercept__ below_1963 -1
+    __intercept__ below_1964 -1
+    __intercept__ below_1965 -1
+    __intercept__ below_1966 -1
+    __intercept__ below_1967 -1
+    __intercept__ below_1968 -1
+    __intercept__ below_1969 -1
+    __intercept__ below_1970 -1
+    __intercept__ below_1971 -1
+    __intercept__ below_1972 -1
+    __intercept__ below_1973 -1
+    __intercept__ below_1974 -1
+    __intercept__ below_1975 -1
+    __intercept__ below_1976 -1
+    __intercept__ below_1977 -1
+    __intercept__ below_1978 -1
+    __intercept__ below_1979 -1
+    __intercept__ below_1980 -1
+    __intercept__ below_1981 -1
+    __intercept__ below_1982 -1
+    __intercept__ below_1983 -1
+    __intercept__ below_1984 -1
+    __intercept__ below_1985 -1
+    __intercept__ below_1986 -1
+    __intercept__ below_1987 -1
+    __intercept__ below_1988 -1
+    __intercept__ below_1989 -1
+    __intercept__ below_1990 -1
+    __intercept__ below_1991 -1
+    __intercept__ below_1992 -1
+    __intercept__ below_1993 -1
+    __intercept__ below_1994 -1
+    __intercept__ below_1995 -1
+    __intercept__ below_1996 -1
+    __intercept__ below_1997 -1
+    __intercept__ below_1998 -1
+    __intercept__ below_1999 -1
+    __intercept__ below_2000 -1
+    __trend__ above_1801 1
+    __trend__ above_1802 2
+    __trend__ above_1803 3
+    __trend__ above_1804 4
+    __trend__ above_1805 5
+    __trend__ above_1806 6
+    __trend__ above_1807 7
+    __trend__ above_1808 8
+    __trend__ above_1809 9
+    __trend__ above_1810 10
+    __trend__ above_1811 11
+    __trend__ above_1812 12
+    __trend__ above_1813 13
+    __trend__ above_1814 14
+    __trend__ above_1815 15
+    __trend__ above_1816 16
+    __trend__ above_1817 17
+    __trend__ above_1818 18
+    __trend__ above_1819 19
+    __trend__ above_1820 20
+    __trend__ above_1821 21
+    __trend__ above_1822 22
+    __trend__ above_1823 23
+    __trend__ above_1824 24
+    __trend__ above_1825 25
+    __trend__ above_1826 26
+    __trend__ above_1827 27
+    __trend__ above_1828 28
+    __trend__ above_1829 29
+    __trend__ above_1830 30
+    __trend__ above_1831 31
+    __trend__ above_1832 32
+    __trend__ above_1833 33
+    __trend__ above_1834 34
+    __trend__ above_1835 35
+    __trend__ above_1836 36
+    __trend__ above_1837 37
+    __trend__ above_1838 38
+    __trend__ above_1839 39
+    __trend__ above_1840 40
+    __trend__ above_1841 41
+    __trend__ above_1842 42
+    __trend__ above_1843 43
+    __trend__ above_1844 44
+    __trend__ above_1845 45
+    __trend__ above_1846 46
+    __trend__ above_1847 47
+    __trend__ above_1848 48
+    __trend__ above_1849 49
+    __trend__ above_1850 50
+    __trend__ above_1851 51
+    __trend__ above_1852 52
+    __trend__ above_1853 53
+    __trend__ above_1854 54
+    __trend__ above_1855 55
+    __trend__ above_1856 56
+    __trend__ above_1857 57
+    __trend__ above_1858 58
+    __trend__ above_1859 59
+    __trend__ above_1860 60
+    __trend__ above_1861 61
+    __trend__ above_1862 62
+    __trend__ above_1863 63
+    __trend__ above_1864 64
+    __trend__ above_1865 65
+    __trend__ above_1866 66
+    __trend__ above_1867 67
+    __trend__ above_1868 68
+    __trend__ above_1869 69
+    __trend__ above_1870 70
+    __trend__ above_1871 71
+    __trend__ above_1872 72
+    __trend__ above_1873 73
+    __trend__ above_1874 74
+    __trend__ above_1875 75
+    __trend__ above_1876 76
+    __trend__ above_1877 77
+    __trend__ above_1878 78
+    __trend__ above_1879 79
+    __trend__ above_1880 80
+    __trend__ above_1881 81
+    __trend__ above_1882 82
+    __trend__ above_1883 83
+    __trend__ above_1884 84
+    __trend__ above_1885 85
+    __trend__ above_1886 86
+    __trend__ above_1887 87
+    __trend__ above_1888 88
+    __trend__ above_1889 89
+    __trend__ above_1890 90
+    __trend__ above_1891 91
+    __trend__ above_1892 92
+    __trend__ above_1893 93
+    __trend__ above_1894 94
+    __trend__ above_1895 95
+    __trend__ above_1896 96
+    __trend__ above_1897 97
+    __trend__ above_1898 98
+    __trend__ above_1899 99
+    __trend__ above_1900 100
+    __trend__ above_1901 101
+    __trend__ above_1902 102
+    __trend__ above_1903 103
+    __trend__ above_1904 104
+    __trend__ above_1905 105
+    __trend__ above_1906 106
+    __trend__ above_1907 107
+    __trend__ above_1908 108
+    __trend__ above_1909 109
+    __trend__ above_1910 110
+    __trend__ above_1911 111
+    __trend__ above_1912 112
+    __trend__ above_1913 113
+    __trend__ above_1914 114
+    __trend__ above_1915 115
+    __trend__ above_1916 116
+    __trend__ above_1917 117
+    __trend__ above_1918 118
+    __trend__ above_1919 119
+    __trend__ above_1920 120
+    __trend__ above_1921 121
+    __trend__ above_1922 122
+    __trend__ above_1923 123
+    __trend__ above_1924 124
+    __trend__ above_1925 125
+    __trend__ above_1926 126
+    __trend__ above_1927 127
+    __trend__ above_1928 128
+    __trend__ above_1929 129
+    __trend__ above_1930 130
+    __trend__ above_1931 131
+    __trend__ above_1932 132
+    __trend__ above_1933 133
+    __trend__ above_1934 134
+    __trend__ above_1935 135
+    __trend__ above_1936 136
+    __trend__ above_1937 137
+    __trend__ above_1938 138
+    __trend__ above_1939 139
+    __trend__ above_1940 140
+    __trend__ above_1941 141
+    __trend__ above_1942 142
+    __trend__ above_1943 143
+    __trend__ above_1944 144
+    __trend__ above_1945 145
+    __trend__ above_1946 146
+    __trend__ above_1947 147
+    __trend__ above_1948 148
+    __trend__ above_1949 149
+    __trend__ above_1950 150
+    __trend__ above_1951 151
+    __trend__ above_1952 152
+    __trend__ above_1953 153
+    __trend__ above_1954 154
+    __trend__ above_1955 155
+    __trend__ above_1956 156
+    __trend__ above_1957 157
+    __trend__ above_1958 158
+    __trend__ above_1959 159
+    __trend__ above_1960 160
+    __trend__ above_1961 161
+    __trend__ above_1962 162
+    __trend__ above_1963 163
+    __trend__ above_1964 164
+    __trend__ above_1965 165
+    __trend__ above_1966 166
+    __trend__ above_1967 167
+    __trend__ above_1968 168
+    __trend__ above_1969 169
+    __trend__ above_1970 170
+    __trend__ above_1971 171
+    __trend__ above_1972 172
+    __trend__ above_1973 173
+    __trend__ above_1974 174
+    __trend__ above_1975 175
+    __trend__ above_1976 176
+    __trend__ above_1977 177
+    __trend__ above_1978 178
+    __trend__ above_1979 179
+    __trend__ above_1980 180
+    __trend__ above_1981 181
+    __trend__ above_1982 182
+    __trend__ above_1983 183
+    __trend__ above_1984 184
+    __trend__ above_1985 185
+    __trend__ above_1986 186
+    __trend__ above_1987 187
+    __trend__ above_1988 188
+    __trend__ above_1989 189
+    __trend__ above_1990 190
+    __trend__ above_1991 191
+    __trend__ above_1992 192
+    __trend__ above_1993 193
+    __trend__ above_1994 194
+    __trend__ above_1995 195
+    __trend__ above_1996 196
+    __trend__ above_1997 197
+    __trend__ above_1998 198
+    __trend__ above_1999 199
+    __trend__ above_2000 200
+    __trend__ below_1801 -1
+    __trend__ below_1802 -2
+    __trend__ below_1803 -3
+    __trend__ below_1804 -4
+    __trend__ below_1805 -5
+    __trend__ below_1806 -6
+    __trend__ below_1807 -7
+    __trend__ below_1808 -8
+    __trend__ below_1809 -9
+    __trend__ below_1810 -10
+    __trend__ below_1811 -11
+    __trend__ below_1812 -12
+    __trend__ below_1813 -13
+    __trend__ below_1814 -14
+    __trend__ below_1815 -15
+    __trend__ below_1816 -16
+    __trend__ below_1817 -17
+    __trend__ below_1818 -18
+    __trend__ below_1819 -19
+    __trend__ below_1820 -20
+    __trend__ below_1821 -21
+    __trend__ below_1822 -22
+    __trend__ below_1823 -23
+    __trend__ below_1824 -24
+    __trend__ below_1825 -25
+    __trend__ below_1826 -26
+    __trend__ below_1827 -27
+    __trend__ below_1828 -28
+    __trend__ below_1829 -29
+    __trend__ below_1830 -30
+    __trend__ below_1831 -31
+    __trend__ below_1832 -32
+    __trend__ below_1833 -33
+    __trend__ below_1834 -34
+    __trend__ below_1835 -35
+    __trend__ below_1836 -36
+    __trend__ below_1837 -37
+    __trend__ below_1838 -38
+    __trend__ below_1839 -39
+    __trend__ below_1840 -40
+    __trend__ below_1841 -41
+    __trend__ below_1842 -42
+    __trend__ below_1843 -43
+    __trend__ below_1844 -44
+    __trend__ below_1845 -45
+    __trend__ below_1846 -46
+    __trend__ below_1847 -47
+    __trend__ below_1848 -48
+    __trend__ below_1849 -49
+    __trend__ below_1850 -50
+    __trend__ below_1851 -51
+    __trend__ below_1852 -52
+    __trend__ below_1853 -53
+    __trend__ below_1854 -54
+    __trend__ below_1855 -55
+    __trend__ below_1856 -56
+    __trend__ below_1857 -57
+    __trend__ below_1858 -58
+    __trend__ below_1859 -59
+    __trend__ below_1860 -60
+    __trend__ below_1861 -61
+    __trend__ below_1862 -62
+    __trend__ below_1863 -63
+    __trend__ below_1864 -64
+    __trend__ below_1865 -65
+    __trend__ below_1866 -66
+    __trend__ below_1867 -67
+    __trend__ below_1868 -68
+    __trend__ below_1869 -69
+    __trend__ below_1870 -70
+    __trend__ below_1871 -71
+    __trend__ below_1872 -72
+    __trend__ below_1873 -73
+    __trend__ below_1874 -74
+    __trend__ below_1875 -75
+    __trend__ below_1876 -76
+    __trend__ below_1877 -77
+    __trend__ below_1878 -78
+    __trend__ below_1879 -79
+    __trend__ below_1880 -80
+    __trend__ below_1881 -81
+    __trend__ below_1882 -82
+    __trend__ below_1883 -83
+    __trend__ below_1884 -84
+    __trend__ below_1885 -85
+    __trend__ below_1886 -86
+    __trend__ below_1887 -87
+    __trend__ below_1888 -88
+    __trend__ below_1889 -89
+    __trend__ below_1890 -90
+    __trend__ below_1891 -91
+    __trend__ below_1892 -92
+    __trend__ below_1893 -93
+    __trend__ below_1894 -94
+    __trend__ below_1895 -95
+    __trend__ below_1896 -96
+    __trend__ below_1897 -97
+    __trend__ below_1898 -98
+    __trend__ below_1899 -99
+    __trend__ below_1900 -100
+    __trend__ below_1901 -101
+    __trend__ below_1902 -102
+    __trend__ below_1903 -103
+    __trend__ below_1904 -104
+    __trend__ below_1905 -105
+    __trend__ below_1906 -106
+    __trend__ below_1907 -107
+    __trend__ below_1908 -108
+    __trend__ below_1909 -109
+    __trend__ below_1910 -110
+    __trend__ below_1911 -111
+    __trend__ below_1912 -112
+    __trend__ below_1913 -113
+    __trend__ below_1914 -114
+    __trend__ below_1915 -115
+    __trend__ below_1916 -116
+    __trend__ below_1917 -117
+    __trend__ below_1918 -118
+    __trend__ below_1919 -119
+    __trend__ below_1920 -120
+    __trend__ below_1921 -121
+    __trend__ below_1922 -122
+    __trend__ below_1923 -123
+    __trend__ below_1924 -124
+    __trend__ below_1925 -125
+    __trend__ below_1926 -126
+    __trend__ below_1927 -127
+    __trend__ below_1928 -128
+    __trend__ below_1929 -129
+    __trend__ below_1930 -130
+    __trend__ below_1931 -131
+    __trend__ below_1932 -132
+    __trend__ below_1933 -133
+    __trend__ below_1934 -134
+    __trend__ below_1935 -135
+    __trend__ below_1936 -136
+    __trend__ below_1937 -137
+    __trend__ below_1938 -138
+    __trend__ below_1939 -139
+    __trend__ below_1940 -140
+    __trend__ below_1941 -141
+    __trend__ below_1942 -142
+    __trend__ below_1943 -143
+    __trend__ below_1944 -144
+    __trend__ below_1945 -145
+    __trend__ below_1946 -146
+    __trend__ below_1947 -147
+    __trend__ below_1948 -148
+    __trend__ below_1949 -149
+    __trend__ below_1950 -150
+    __trend__ below_1951 -151
+    __trend__ below_1952 -152
+    __trend__ below_1953 -153
+    __trend__ below_1954 -154
+    __trend__ below_1955 -155
+    __trend__ below_1956 -156
+    __trend__ below_1957 -157
+    __trend__ below_1958 -158
+    __trend__ below_1959 -159
+    __trend__ below_1960 -160
+    __trend__ below_1961 -161
+    __trend__ below_1962 -162
+    __trend__ below_1963 -163
+    __trend__ below_1964 -164
+    __trend__ below_1965 -165
+    __trend__ below_1966 -166
+    __trend__ below_1967 -167
+    __trend__ below_1968 -168
+    __trend__ below_1969 -169
+    __trend__ below_1970 -170
+    __trend__ below_1971 -171
+    __trend__ below_1972 -172
+    __trend__ below_1973 -173
+    __trend__ below_1974 -174
+    __trend__ below_1975 -175
+    __trend__ below_1976 -176
+    __trend__ below_1977 -177
+    __trend__ below_1978 -178
+    __trend__ below_1979 -179
+    __trend__ below_1980 -180
+    __trend__ below_1981 -181
+    __trend__ below_1982 -182
+    __trend__ below_1983 -183
+    __trend__ below_1984 -184
+    __trend__ below_1985 -185
+    __trend__ below_1986 -186
+    __trend__ below_1987 -187
+    __trend__ below_1988 -188
+    __trend__ below_1989 -189
+    __trend__ below_1990 -190
+    __trend__ below_1991 -191
+    __trend__ below_1992 -192
+    __trend__ below_1993 -193
+    __trend__ below_1994 -194
+    __trend__ below_1995 -195
+    __trend__ below_1996 -196
+    __trend__ below_1997 -197
+    __trend__ below_1998 -198
+    __trend__ below_1999 -199
+    __trend__ below_2000 -200
+    __t__ obj 1
+    __t__ above_1800 -1
+    __t__ above_1801 -1
+    __t__ above_1802 -1
+    __t__ above_1803 -1
+    __t__ above_1804 -1
+    __t__ above_1805 -1
+    __t__ above_1806 -1
+    __t__ above_1807 -1
+    __t__ above_1808 -1
+    __t__ above_1809 -1
+    __t__ above_1810 -1
+    __t__ above_1811 -1
+    __t__ above_1812 -1
+    __t__ above_1813 -1
+    __t__ above_1814 -1
+    __t__ above_1815 -1
+    __t__ above_1816 -1
+    __t__ above_1817 -1
+    __t__ above_1818 -1
+    __t__ above_1819 -1
+    __t__ above_1820 -1
+    __t__ above_1821 -1
+    __t__ above_1822 -1
+    __t__ above_1823 -1
+    __t__ above_1824 -1
+    __t__ above_1825 -1
+    __t__ above_1826 -1
+    __t__ above_1827 -1
+    __t__ above_1828 -1
+    __t__ above_1829 -1
+    __t__ above_1830 -1
+    __t__ above_1831 -1
+    __t__ above_1832 -1
+    __t__ above_1833 -1
+    __t__ above_1834 -1
+    __t__ above_1835 -1
+    __t__ above_1836 -1
+    __t__ above_1837 -1
+    __t__ above_1838 -1
+    __t__ above_1839 -1
+    __t__ above_1840 -1
+    __t__ above_1841 -1
+    __t__ above_1842 -1
+    __t__ above_1843 -1
+    __t__ above_1844 -1
+    __t__ above_1845 -1
+    __t__ above_1846 -1
+    __t__ above_1847 -1
+    __t__ above_1848 -1
+    __t__ above_1849 -1
+    __t__ above_1850 -1
+    __t__ above_1851 -1
+    __t__ above_1852 -1
+    __t__ above_1853 -1
+    __t__ above_1854 -1
+    __t__ above_1855 -1
+    __t__ above_1856 -1
+    __t__ above_1857 -1
+    __t__ above_1858 -1
+    __t__ above_1859 -1
+    __t__ above_1860 -1
+    __t__ above_1861 -1
+    __t__ above_1862 -1
+    __t__ above_1863 -1
+    __t__ above_1864 -1
+    __t__ above_1865 -1
+    __t__ above_1866 -1
+    __t__ above_1867 -1
+    __t__ above_1868 -1
+    __t__ above_1869 -1
+    __t__ above_1870 -1
+    __t__ above_1871 -1
+    __t__ above_1872 -1
+    __t__ above_1873 -1
+    __t__ above_1874 -1
+    __t__ above_1875 -1
+    __t__ above_1876 -1
+    __t__ above_1877 -1
+    __t__ above_1878 -1
+    __t__ above_1879 -1
+    __t__ above_1880 -1
+    __t__ above_1881 -1
+    __t__ above_1882 -1
+    __t__ above_1883 -1
+    __t__ above_1884 -1
+    __t__ above_1885 -1
+    __t__ above_1886 -1
+    __t__ above_1887 -1
+    __t__ above_1888 -1
+    __t__ above_1889 -1
+    __t__ above_1890 -1
+    __t__ above_1891 -1
+    __t__ above_1892 -1
+    __t__ above_1893 -1
+    __t__ above_1894 -1
+    __t__ above_1895 -1
+    __t__ above_1896 -1
+    __t__ above_1897 -1
+    __t__ above_1898 -1
+    __t__ above_1899 -1
+    __t__ above_1900 -1
+    __t__ above_1901 -1
+    __t__ above_1902 -1
+    __t__ above_1903 -1
+    __t__ above_1904 -1
+    __t__ above_1905 -1
+    __t__ above_1906 -1
+    __t__ above_1907 -1
+    __t__ above_1908 -1
+    __t__ above_1909 -1
+    __t__ above_1910 -1
+    __t__ above_1911 -1
+    __t__ above_1912 -1
+    __t__ above_1913 -1
+    __t__ above_1914 -1
+    __t__ above_1915 -1
+    __t__ above_1916 -1
+    __t__ above_1917 -1
+    __t__ above_1918 -1
+    __t__ above_1919 -1
+    __t__ above_1920 -1
+    __t__ above_1921 -1
+    __t__ above_1922 -1
+    __t__ above_1923 -1
+    __t__ above_1924 -1
+    __t__ above_1925 -1
+    __t__ above_1926 -1
+    __t__ above_1927 -1
+    __t__ above_1928 -1
+    __t__ above_1929 -1
+    __t__ above_1930 -1
+    __t__ above_1931 -1
+    __t__ above_1932 -1
+    __t__ above_1933 -1
+    __t__ above_1934 -1
+    __t__ above_1935 -1
+    __t__ above_1936 -1
+    __t__ above_1937 -1
+    __t__ above_1938 -1
+    __t__ above_1939 -1
+    __t__ above_1940 -1
+    __t__ above_1941 -1
+    __t__ above_1942 -1
+    __t__ above_1943 -1
+    __t__ above_1944 -1
+    __t__ above_1945 -1
+    __t__ above_1946 -1
+    __t__ above_1947 -1
+    __t__ above_1948 -1
+    __t__ above_1949 -1
+    __t__ above_1950 -1
+    __t__ above_1951 -1
+    __t__ above_1952 -1
+    __t__ above_1953 -1
+    __t__ above_1954 -1
+    __t__ above_1955 -1
+    __t__ above_1956 -1
+    __t__ above_1957 -1
+    __t__ above_1958 -1
+    __t__ above_1959 -1
+    __t__ above_1960 -1
+    __t__ above_1961 -1
+    __t__ above_1962 -1
+    __t__ above_1963 -1
+    __t__ above_1964 -1
+    __t__ above_1965 -1
+    __t__ above_1966 -1
+    __t__ above_1967 -1
+    __t__ above_1968 -1
+    __t__ above_1969 -1
+    __t__ above_1970 -1
+    __t__ above_1971 -1
+    __t__ above_1972 -1
+    __t__ above_1973 -1
+    __t__ above_1974 -1
+    __t__ above_1975 -1
+    __t__ above_1976 -1
+    __t__ above_1977 -1
+    __t__ above_1978 -1
+    __t__ above_1979 -1
+    __t__ above_1980 -1
+    __t__ above_1981 -1
+    __t__ above_1982 -1
+    __t__ above_1983 -1
+    __t__ above_1984 -1
+    __t__ above_1985 -1
+    __t__ above_1986 -1
+    __t__ above_1987 -1
+    __t__ above_1988 -1
+    __t__ above_1989 -1
+    __t__ above_1990 -1
+    __t__ above_1991 -1
+    __t__ above_1992 -1
+    __t__ above_1993 -1
+    __t__ above_1994 -1
+    __t__ above_1995 -1
+    __t__ above_1996 -1
+    __t__ above_1997 -1
+    __t__ above_1998 -1
+    __t__ above_1999 -1
+    __t__ above_2000 -1
+    __t__ below_1800 -1
+    __t__ below_1801 -1
+    __t__ below_1802 -1
+    __t__ below_1803 -1
+    __t__ below_1804 -1
+    __t__ below_1805 -1
+    __t__ below_1806 -1
+    __t__ below_1807 -1
+    __t__ below_1808 -1
+    __t__ below_1809 -1
+    __t__ below_1810 -1
+    __t__ below_1811 -1
+    __t__ below_1812 -1
+    __t__ below_1813 -1
+    __t__ below_1814 -1
+    __t__ below_1815 -1
+    __t__ below_1816 -1
+    __t__ below_1817 -1
+    __t__ below_1818 -1
+    __t__ below_1819 -1
+    __t__ below_1820 -1
+    __t__ below_1821 -1
+    __t__ below_1822 -1
+    __t__ below_1823 -1
+    __t__ below_1824 -1
+    __t__ below_1825 -1
+    __t__ below_1826 -1
+    __t__ below_1827 -1
+    __t__ below_1828 -1
+    __t__ below_1829 -1
+    __t__ below_1830 -1
+    __t__ below_1831 -1
+    __t__ below_1832 -1
+    __t__ below_1833 -1
+    __t__ below_1834 -1
+    __t__ below_1835 -1
+    __t__ below_1836 -1
+    __t__ below_1837 -1
+    __t__ below_1838 -1
+    __t__ below_1839 -1
+    __t__ below_1840 -1
+    __t__ below_1841 -1
+    __t__ below_1842 -1
+    __t__ below_1843 -1
+    __t__ below_1844 -1
+    __t__ below_1845 -1
+    __t__ below_1846 -1
+    __t__ below_1847 -1
+    __t__ below_1848 -1
+    __t__ below_1849 -1
+    __t__ below_1850 -1
+    __t__ below_1851 -1
+    __t__ below_1852 -1
+    __t__ below_1853 -1
+    __t__ below_1854 -1
+    __t__ below_1855 -1
+    __t__ below_1856 -1
+    __t__ below_1857 -1
+    __t__ below_1858 -1
+    __t__ below_1859 -1
+    __t__ below_1860 -1
+    __t__ below_1861 -1
+    __t__ below_1862 -1
+    __t__ below_1863 -1
+    __t__ below_1864 -1
+    __t__ below_1865 -1
+    __t__ below_1866 -1
+    __t__ below_1867 -1
+    __t__ below_1868 -1
+    __t__ below_1869 -1
+    __t__ below_1870 -1
+    __t__ below_1871 -1
+    __t__ below_1872 -1
+    __t__ below_1873 -1
+    __t__ below_1874 -1
+    __t__ below_1875 -1
+    __t__ below_1876 -1
+    __t__ below_1877 -1
+    __t__ below_1878 -1
+    __t__ below_1879 -1
+    __t__ below_1880 -1
+    __t__ below_1881 -1
+    __t__ below_1882 -1
+    __t__ below_1883 -1
+    __t__ below_1884 -1
+    __t__ below_1885 -1
+    __t__ below_1886 -1
+    __t__ below_1887 -1
+    __t__ below_1888 -1
+    __t__ below_1889 -1
+    __t__ below_1890 -1
+    __t__ below_1891 -1
+    __t__ below_1892 -1
+    __t__ below_1893 -1
+    __t__ below_1894 -1
+    __t__ below_1895 -1
+    __t__ below_1896 -1
+    __t__ below_1897 -1
+    __t__ below_1898 -1
+    __t__ below_1899 -1
+    __t__ below_1900 -1
+    __t__ below_1901 -1
+    __t__ below_1902 -1
+    __t__ below_1903 -1
+    __t__ below_1904 -1
+    __t__ below_1905 -1
+    __t__ below_1906 -1
+    __t__ below_1907 -1
+    __t__ below_1908 -1
+    __t__ below_1909 -1
+    __t__ below_1910 -1
+    __t__ below_1911 -1
+    __t__ below_1912 -1
+    __t__ below_1913 -1
+    __t__ below_1914 -1
+    __t__ below_1915 -1
+    __t__ below_1916 -1
+    __t__ below_1917 -1
+    __t__ below_1918 -1
+    __t__ below_1919 -1
+    __t__ below_1920 -1
+    __t__ below_1921 -1
+    __t__ below_1922 -1
+    __t__ below_1923 -1
+    __t__ below_1924 -1
+    __t__ below_1925 -1
+    __t__ below_1926 -1
+    __t__ below_1927 -1
+    __t__ below_1928 -1
+    __t__ below_1929 -1
+    __t__ below_1930 -1
+    __t__ below_1931 -1
+    __t__ below_1932 -1
+    __t__ below_1933 -1
+    __t__ below_1934 -1
+    __t__ below_1935 -1
+    __t__ below_1936 -1
+    __t__ below_1937 -1
+    __t__ below_1938 -1
+    __t__ below_1939 -1
+    __t__ below_1940 -1
+    __t__ below_1941 -1
+    __t__ below_1942 -1
+    __t__ below_1943 -1
+    __t__ below_1944 -1
+    __t__ below_1945 -1
+    __t__ below_1946 -1
+    __t__ below_1947 -1
+    __t__ below_1948 -1
+    __t__ below_1949 -1
+    __t__ below_1950 -1
+    __t__ below_1951 -1
+    __t__ below_1952 -1
+    __t__ below_1953 -1
+    __t__ below_1954 -1
+    __t__ below_1955 -1
+    __t__ below_1956 -1
+    __t__ below_1957 -1
+    __t__ below_1958 -1
+    __t__ below_1959 -1
+    __t__ below_1960 -1
+    __t__ below_1961 -1
+    __t__ below_1962 -1
+    __t__ below_1963 -1
+    __t__ below_1964 -1
+    __t__ below_1965 -1
+    __t__ below_1966 -1
+    __t__ below_1967 -1
+    __t__ below_1968 -1
+    __t__ below_1969 -1
+    __t__ below_1970 -1
+    __t__ below_1971 -1
+    __t__ below_1972 -1
+    __t__ below_1973 -1
+    __t__ below_1974 -1
+    __t__ below_1975 -1
+    __t__ below_1976 -1
+    __t__ below_1977 -1
+    __t__ below_1978 -1
+    __t__ below_1979 -1
+    __t__ below_1980 -1
+    __t__ below_1981 -1
+    __t__ below_1982 -1
+    __t__ below_1983 -1
+    __t__ below_1984 -1
+    __t__ below_1985 -1
+    __t__ below_1986 -1
+    __t__ below_1987 -1
+    __t__ below_1988 -1
+    __t__ below_1989 -1
+    __t__ below_1990 -1
+    __t__ below_1991 -1
+    __t__ below_1992 -1
+    __t__ below_1993 -1
+    __t__ below_1994 -1
+    __t__ below_1995 -1
+    __t__ below_1996 -1
+    __t__ below_1997 -1
+    __t__ below_1998 -1
+    __t__ below_1999 -1
+    __t__ below_2000 -1
+RHS
+    RHS above_1800 225
+    RHS above_1801 282
+    RHS above_1802 274
+    RHS above_1803 300
+    RHS above_1804 294
+    RHS above_1805 308
+    RHS above_1806 300
+    RHS above_1807 334
+    RHS above_1808 347
+    RHS above_1809 366
+    RHS above_1810 382
+    RHS above_1811 379
+    RHS above_1812 425
+    RHS above_1813 409
+    RHS above_1814 441
+    RHS above_1815 474
+    RHS above_1816 472
+    RHS above_1817 490
+    RHS above_1818 495
+    RHS above_1819 514
+    RHS above_1820 598
+    RHS above_1821 592
+    RHS above_1822 545
+    RHS above_1823 573
+    RHS above_1824 606
+    RHS above_1825 665
+    RHS above_1826 670
+    RHS above_1827 686
+    RHS above_1828 718
+    RHS above_1829 679
+    RHS above_1830 723
+    RHS above_1831 681
+    RHS above_1832 840
+    RHS above_1833 760
+    RHS above_1834 840
+    RHS above_1835 867
+    RHS above_1836 875
+    RHS above_1837 819
+    RHS above_1838 930
+    RHS above_1839 911
+    RHS above_1840 974
+    RHS above_1841 1045
+    RHS above_1842 907
+    RHS above_1843 963
+    RHS above_1844 1059
+    RHS above_1845 903
+    RHS above_1846 1125
+    RHS above_1847 1029
+    RHS above_1848 1031
+    RHS above_1849 1128
+    RHS above_1850 1103
+    RHS above_1851 1001
+    RHS above_1852 1084
+    RHS above_1853 1154
+    RHS above_1854 1175
+    RHS above_1855 1107
+    RHS above_1856 1112
+    RHS above_1857 1207
+    RHS above_1858 1141
+    RHS above_1859 1152
+    RHS above_1860 1275
+    RHS above_1861 1252
+    RHS above_1862 1174
+    RHS above_1863 1269
+    RHS above_1864 1253
+    RHS above_1865 1242
+    RHS above_1866 1215
+    RHS above_1867 1345
+    RHS above_1868 1356
+    RHS above_1869 1291
+    RHS above_1870 1198
+    RHS above_1871 1405
+    RHS above_1872 1311
+    RHS above_1873 1251
+    RHS above_1874 1298
+    RHS above_1875 1225
+    RHS above_1876 1291
+    RHS above_1877 1281
+    RHS above_1878 1262
+    RHS above_1879 1317
+    RHS above_1880 1329
+    RHS above_1881 1337
+    RHS above_1882 1396
+    RHS above_1883 1360
+    RHS above_1884 1413
+    RHS above_1885 1319
+    RHS above_1886 1445
+    RHS above_1887 1508
+    RHS above_1888 1304
+    RHS above_1889 1378
+    RHS above_1890 1372
+    RHS above_1891 1464
+    RHS above_1892 1311
+    RHS above_1893 1446
+    RHS above_1894 1467
+    RHS above_1895 1538
+    RHS above_1896 1508
+    RHS above_1897 1425
+    RHS above_1898 1505
+    RHS above_1899 1496
+    RHS above_1900 1509
+    RHS above_1901 1484
+    RHS above_1902 1552
+    RHS above_1903 1447
+    RHS above_1904 1486
+    RHS above_1905 1420
+    RHS above_1906 1519
+    RHS above_1907 1523
+    RHS above_1908 1535
+    RHS above_1909 1555
+    RHS above_1910 1630
+    RHS above_1911 1644
+    RHS above_1912 1614
+    RHS above_1913 1620
+    RHS above_1914 1649
+    RHS above_1915 1586
+    RHS above_1916 1642
+    RHS above_1917 1672
+    RHS above_1918 1639
+    RHS above_1919 1742
+    RHS above_1920 1621
+    RHS above_1921 1754
+    RHS above_1922 1669
+    RHS above_1923 1654
+    RHS above_1924 1849
+    RHS above_1925 1788
+    RHS above_1926 1705
+    RHS above_1927 1677
+    RHS above_1928 1751
+    RHS above_1929 1797
+    RHS above_1930 1754
+    RHS above_1931 1693
+    RHS above_1932 1760
+    RHS above_1933 1806
+    RHS above_1934 1669
+    RHS above_1935 1740
+    RHS above_1936 1818
+    RHS above_1937 1935
+    RHS above_1938 1920
+    RHS above_1939 1903
+    RHS above_1940 1843
+    RHS above_1941 1873
+    RHS above_1942 1846
+    RHS above_1943 2063
+    RHS above_1944 1923
+    RHS above_1945 1974
+    RHS above_1946 2005
+    RHS above_1947 1891
+    RHS above_1948 1999
+    RHS above_1949 1864
+    RHS above_1950 2163
+    RHS above_1951 2025
+    RHS above_1952 1909
+    RHS above_1953 2067
+    RHS above_1954 2049
+    RHS above_1955 1963
+    RHS above_1956 2055
+    RHS above_1957 1970
+    RHS above_1958 1966
+    RHS above_1959 1975
+    RHS above_1960 1863
+    RHS above_1961 1836
+    RHS above_1962 2090
+    RHS above_1963 2030
+    RHS above_1964 2044
+    RHS above_1965 2111
+    RHS above_1966 2219
+    RHS above_1967 2103
+    RHS above_1968 1953
+    RHS above_1969 1980
+    RHS above_1970 2146
+    RHS above_1971 2039
+    RHS above_1972 2084
+    RHS above_1973 2099
+    RHS above_1974 1987
+    RHS above_1975 2085
+    RHS above_1976 2083
+    RHS above_1977 2176
+    RHS above_1978 2228
+    RHS above_1979 2072
+    RHS above_1980 1974
+    RHS above_1981 2239
+    RHS above_1982 2176
+    RHS above_1983 2282
+    RHS above_1984 2305
+    RHS above_1985 2127
+    RHS above_1986 2083
+    RHS above_1987 2254
+    RHS above_1988 2309
+    RHS above_1989 2160
+    RHS above_1990 2066
+    RHS above_1991 2266
+    RHS above_1992 2245
+    RHS above_1993 2033
+    RHS above_1994 2164
+    RHS above_1995 2275
+    RHS above_1996 2287
+    RHS above_1997 2088
+    RHS above_1998 2180
+    RHS above_1999 2202
+    RHS above_2000 2009
+    RHS below_1800 -225
+    RHS below_1801 -282
+    RHS below_1802 -274
+    RHS below_1803 -300
+    RHS below_1804 -294
+    RHS below_1805 -308
+    RHS below_1806 -300
+    RHS below_1807 -334
+    RHS below_1808 -347
+    RHS below_1809 -366
+    RHS below_1810 -382
+    RHS below_1811 -379
+    RHS below_1812 -425
+    RHS below_1813 -409
+    RHS below_1814 -441
+    RHS below_1815 -474
+    RHS below_1816 -472
+    RHS below_1817 -490
+    RHS below_1818 -495
+    RHS below_1819 -514
+    RHS below_1820 -598
+    RHS below_1821 -592
+    RHS below_1822 -545
+    RHS below_1823 -573
+    RHS below_1824 -606
+    RHS below_1825 -665
+    RHS below_1826 -670
+    RHS below_1827 -686
+    RHS below_1828 -718
+    RHS below_1829 -679
+    RHS below_1830 -723
+    RHS below_1831 -681
+    RHS below_1832 -840
+    RHS below_1833 -760
+    RHS below_1834 -840
+    RHS below_1835 -867
+    RHS below_1836 -875
+    RHS below_1837 -819
+    RHS below_1838 -930
+    RHS below_1839 -911
+    RHS below_1840 -974
+    RHS below_1841 -1045
+    RHS below_1842 -907
+    RHS below_1843 -963
+    RHS below_1844 -1059
+    RHS below_1845 -903
+    RHS below_1846 -1125
+    RHS below_1847 -1029
+    RHS below_1848 -1031
+    RHS below_1849 -1128
+    RHS below_1850 -1103
+    RHS below_1851 -1001
+    RHS below_1852 -1084
+    RHS below_1853 -1154
+    RHS below_1854 -1175
+    RHS below_1855 -1107
+    RHS below_1856 -1112
+    RHS below_1857 -1207
+    RHS below_1858 -1141
+    RHS below_1859 -1152
+    RHS below_1860 -1275
+    RHS below_1861 -1252
+    RHS below_1862 -1174
+    RHS below_1863 -1269
+    RHS below_1864 -1253
+    RHS below_1865 -1242
+    RHS below_1866 -1215
+    RHS below_1867 -1345
+    RHS below_1868 -1356
+    RHS below_1869 -1291
+    RHS below_1870 -1198
+    RHS below_1871 -1405
+    RHS below_1872 -1311
+    RHS below_1873 -1251
+    RHS below_1874 -1298
+    RHS below_1875 -1225
+    RHS below_1876 -1291
+    RHS below_1877 -1281
+    RHS below_1878 -1262
+    RHS below_1879 -1317
+    RHS below_1880 -1329
+    RHS below_1881 -1337
+    RHS below_1882 -1396
+    RHS below_1883 -1360
+    RHS below_1884 -1413
+    RHS below_1885 -1319
+    RHS below_1886 -1445
+    RHS below_1887 -1508
+    RHS below_1888 -1304
+    RHS below_1889 -1378
+    RHS below_1890 -1372
+    RHS below_1891 -1464
+    RHS below_1892 -1311
+    RHS below_1893 -1446
+    RHS below_1894 -1467
+    RHS below_1895 -1538
+    RHS below_1896 -1508
+    RHS below_1897 -1425
+    RHS below_1898 -1505
+    RHS below_1899 -1496
+    RHS below_1900 -1509
+    RHS below_1901 -1484
+    RHS below_1902 -1552
+    RHS below_1903 -1447
+    RHS below_1904 -1486
+    RHS below_1905 -1420
+    RHS below_1906 -1519
+    RHS below_1907 -1523
+    RHS below_1908 -1535
+    RHS below_1909 -1555
+    RHS below_1910 -1630
+    RHS below_1911 -1644
+    RHS below_1912 -1614
+    RHS below_1913 -1620
+    RHS below_1914 -1649
+    RHS below_1915 -1586
+    RHS below_1916 -1642
+    RHS below_1917 -1672
+    RHS below_1918 -1639
+    RHS below_1919 -1742
+    RHS below_1920 -1621
+    RHS below_1921 -1754
+    RHS below_1922 -1669
+    RHS below_1923 -1654
+    RHS below_1924 -1849
+    RHS below_1925 -1788
+    RHS below_1926 -1705
+    RHS below_1927 -1677
+    RHS below_1928 -1751
+    RHS below_1929 -1797
+    RHS below_1930 -1754
+    RHS below_1931 -1693
+    RHS below_1932 -1760
+    RHS below_1933 -1806
+    RHS below_1934 -1669
+    RHS below_1935 -1740
+    RHS below_1936 -1818
+    RHS below_1937 -1935
+    RHS below_1938 -1920
+    RHS below_1939 -1903
+    RHS below_1940 -1843
+    RHS below_1941 -1873
+    RHS below_1942 -1846
+    RHS below_1943 -2063
+    RHS below_1944 -1923
+    RHS below_1945 -1974
+    RHS below_1946 -2005
+    RHS below_1947 -1891
+    RHS below_1948 -1999
+    RHS below_1949 -1864
+    RHS below_1950 -2163
+    RHS below_1951 -2025
+    RHS below_1952 -1909
+    RHS below_1953 -2067
+    RHS below_1954 -2049
+    RHS below_1955 -1963
+    RHS below_1956 -2055
+    RHS below_1957 -1970
+    RHS below_1958 -1966
+    RHS below_1959 -1975
+    RHS below_1960 -1863
+    RHS below_1961 -1836
+    RHS below_1962 -2090
+    RHS below_1963 -2030
+    RHS below_1964 -2044
+    RHS below_1965 -2111
+    RHS below_1966 -2219
+    RHS below_1967 -2103
+    RHS below_1968 -1953
+    RHS below_1969 -1980
+    RHS below_1970 -2146
+    RHS below_1971 -2039
+    RHS below_1972 -2084
+    RHS below_1973 -2099
+    RHS below_1974 -1987
+    RHS below_1975 -2085
+    RHS below_1976 -2083
+    RHS below_1977 -2176
+    RHS below_1978 -2228
+    RHS below_1979 -2072
+    RHS below_1980 -1974
+    RHS below_1981 -2239
+    RHS below_1982 -2176
+    RHS below_1983 -2282
+    RHS below_1984 -2305
+    RHS below_1985 -2127
+    RHS below_1986 -2083
+    RHS below_1987 -2254
+    RHS below_1988 -2309
+    RHS below_1989 -2160
+    RHS below_1990 -2066
+    RHS below_1991 -2266
+    RHS below_1992 -2245
+    RHS below_1993 -2033
+    RHS below_1994 -2164
+    RHS below_1995 -2275
+    RHS below_1996 -2287
+    RHS below_1997 -2088
+    RHS below_1998 -2180
+    RHS below_1999 -2202
+    RHS below_2000 -2009
+BOUNDS
+ FR BND __intercept__
+ FR BND __trend__
+ENDATA
@@ -0,0 +1,2420 @@
+NAME w0_w4_w3-f7eeafc452822725
+ROWS
+ N obj
+ L above_1800
+ L above_1801
+ L above_1802
+ L above_1803
+ L above_1804
+ L above_1805
+ L above_1806
+ L above_1807
+ L above_1808
+ L above_1809
+ L above_1810
+ L above_1811
+ L above_1812
+ L above_1813
+ L above_1814
+ L above_1815
+ L above_1816
+ L above_1817
+ L above_1818
+ L above_1819
+ L above_1820
+ L above_1821
+ L above_1822
+ L above_1823
+ L above_1824
+ L above_1825
+ L above_1826
+ L above_1827
+ L above_1828
+ L above_1829
+ L above_1830
+ L above_1831
+ L above_1832
+ L above_1833
+ L above_1834
+ L above_1835
+ L above_1836
+ L above_1837
+ L above_1838
+ L above_1839
+ L above_1840
+ L above_1841
+ L above_1842
+ L above_1843
+ L above_1844
+ L above_1845
+ L above_1846
+ L above_1847
+ L above_1848
+ L above_1849
+ L above_1850
+ L above_1851
+ L above_1852
+ L above_1853
+ L above_1854
+ L above_1855
+ L above_1856
+ L above_1857
+ L above_1858
+ L above_1859
+ L above_1860
+ L above_1861
+ L above_1862
+ L above_1863
+ L above_1864
+ L above_1865
+ L above_1866
+ L above_1867
+ L above_1868
+ L above_1869
+ L above_1870
+ L above_1871
+ L above_1872
+ L above_1873
+ L above_1874
+ L above_1875
+ L above_1876
+ L above_1877
+ L above_1878
+ L above_1879
+ L above_1880
+ L above_1881
+ L above_1882
+ L above_1883
+ L above_1884
+ L above_1885
+ L above_1886
+ L above_1887
+ L above_1888
+ L above_1889
+ L above_1890
+ L above_1891
+ L above_1892
+ L above_1893
+ L above_1894
+ L above_1895
+ L above_1896
+ L above_1897
+ L above_1898
+ L above_1899
+ L above_1900
+ L above_1901
+ L above_1902
+ L above_1903
+ L above_1904
+ L above_1905
+ L above_1906
+ L above_1907
+ L above_1908
+ L above_1909
+ L above_1910
+ L above_1911
+ L above_1912
+ L above_1913
+ L above_1914
+ L above_1915
+ L above_1916
+ L above_1917
+ L above_1918
+ L above_1919
+ L above_1920
+ L above_1921
+ L above_1922
+ L above_1923
+ L above_1924
+ L above_1925
+ L above_1926
+ L above_1927
+ L above_1928
+ L above_1929
+ L above_1930
+ L above_1931
+ L above_1932
+ L above_1933
+ L above_1934
+ L above_1935
+ L above_1936
+ L above_1937
+ L above_1938
+ L above_1939
+ L above_1940
+ L above_1941
+ L above_1942
+ L above_1943
+ L above_1944
+ L above_1945
+ L above_1946
+ L above_1947
+ L above_1948
+ L above_1949
+ L above_1950
+ L above_1951
+ L above_1952
+ L above_1953
+ L above_1954
+ L above_1955
+ L above_1956
+ L above_1957
+ L above_1958
+ L above_1959
+ L above_1960
+ L above_1961
+ L above_1962
+ L above_1963
+ L above_1964
+ L above_1965
+ L above_1966
+ L above_1967
+ L above_1968
+ L above_1969
+ L above_1970
+ L above_1971
+ L above_1972
+ L above_1973
+ L above_1974
+ L above_1975
+ L above_1976
+ L above_1977
+ L above_1978
+ L above_1979
+ L above_1980
+ L above_1981
+ L above_1982
+ L above_1983
+ L above_1984
+ L above_1985
+ L above_1986
+ L above_1987
+ L above_1988
+ L above_1989
+ L above_1990
+ L above_1991
+ L above_1992
+ L above_1993
+ L above_1994
+ L above_1995
+ L above_1996
+ L above_1997
+ L above_1998
+ L above_1999
+ L above_2000
+ L below_1800
+ L below_1801
+ L below_1802
+ L below_1803
+ L below_1804
+ L below_1805
+ L below_1806
+ L below_1807
+ L below_1808
+ L below_1809
+ L below_1810
+ L below_1811
+ L below_1812
+ L below_1813
+ L below_1814
+ L below_1815
+ L below_1816
+ L below_1817
+ L below_1818
+ L below_1819
+ L below_1820
+ L below_1821
+ L below_1822
+ L below_1823
+ L below_1824
+ L below_1825
+ L below_1826
+ L below_1827
+ L below_1828
+ L below_1829
+ L below_1830
+ L below_1831
+ L below_1832
+ L below_1833
+ L below_1834
+ L below_1835
+ L below_1836
+ L below_1837
+ L below_1838
+ L below_1839
+ L below_1840
+ L below_1841
+ L below_1842
+ L below_1843
+ L below_1844
+ L below_1845
+ L below_1846
+ L below_1847
+ L below_1848
+ L below_1849
+ L below_1850
+ L below_1851
+ L below_1852
+ L below_1853
+ L below_1854
+ L below_1855
+ L below_1856
+ L below_1857
+ L below_1858
+ L below_1859
+ L below_1860
+ L below_1861
+ L below_1862
+ L below_1863
+ L below_1864
+ L below_1865
+ L below_1866
+ L below_1867
+ L below_1868
+ L below_1869
+ L below_1870
+ L below_1871
+ L below_1872
+ L below_1873
+ L below_1874
+ L below_1875
+ L below_1876
+ L below_1877
+ L below_1878
+ L below_1879
+ L below_1880
+ L below_1881
+ L below_1882
+ L below_1883
+ L below_1884
+ L below_1885
+ L below_1886
+ L below_1887
+ L below_1888
+ L below_1889
+ L below_1890
+ L below_1891
+ L below_1892
+ L below_1893
+ L below_1894
+ L below_1895
+ L below_1896
+ L below_1897
+ L below_1898
+ L below_1899
+ L below_1900
+ L below_1901
+ L below_1902
+ L below_1903
+ L below_1904
+ L below_1905
+ L below_1906
+ L below_1907
+ L below_1908
+ L below_1909
+ L below_1910
+ L below_1911
+ L below_1912
+ L below_1913
+ L below_1914
+ L below_1915
+ L below_1916
+ L below_1917
+ L below_1918
+ L below_1919
+ L below_1920
+ L below_1921
+ L below_1922
+ L below_1923
+ L below_1924
+ L below_1925
+ L below_1926
+ L below_1927
+ L below_1928
+ L below_1929
+ L below_1930
+ L below_1931
+ L below_1932
+ L below_1933
+ L below_1934
+ L below_1935
+ L below_1936
+ L below_1937
+ L below_1938
+ L below_1939
+ L below_1940
+ L below_1941
+ L below_1942
+ L below_1943
+ L below_1944
+ L below_1945
+ L below_1946
+ L below_1947
+ L below_1948
+ L below_1949
+ L below_1950
+ L below_1951
+ L below_1952
+ L below_1953
+ L below_1954
+ L below_1955
+ L below_1956
+ L below_1957
+ L below_1958
+ L below_1959
+ L below_1960
+ L below_1961
+ L below_1962
+ L below_1963
+ L below_1964
+ L below_1965
+ L below_1966
+ L below_1967
+ L below_1968
+ L below_1969
+ L below_1970
+ L below_1971
+ L below_1972
+ L below_1973
+ L below_1974
+ L below_1975
+ L below_1976
+ L below_1977
+ L below_1978
+ L below_1979
+ L below_1980
+ L below_1981
+ L below_1982
+ L below_1983
+ L below_1984
+ L below_1985
+ L below_1986
+ L below_1987
+ L below_1988
+ L below_1989
+ L below_1990
+ L below_1991
+ L below_1992
+ L below_1993
+ L below_1994
+ L below_1995
+ L below_1996
+ L below_1997
+ L below_1998
+ L below_1999
+ L below_2000
+COLUMNS
+    w0 above_1800 255.83084577114428
+    w0 above_1801 489.9800995024876
+    w0 above_1802 770.6268656716418
+    w0 above_1803 1124.318407960199
+    w0 above_1804 1546.3432835820897
+    w0 above_1805 1637.6716417910447
+    w0 above_1806 2122.497512437811
+    w0 above_1807 2276.179104477612
+    w0 above_1808 2777.955223880597
+    w0 above_1809 2990
+    w0 above_1810 3669.2935323383085
+    w0 above_1811 3977.910447761194
+    w0 above_1812 4425.820895522388
+    w0 above_1813 4655.174129353234
+    w0 above_1814 5133.731343283582
+    w0 above_1815 5868.338308457712
+    w0 above_1816 6173.36815920398
+    w0 above_1817 6668.1492537313425
+    w0 above_1818 6672.497512437811
+    w0 above_1819 7223.681592039801
+    w0 above_1820 7346.134328358209
+    w0 above_1821 8762.567164179105
+    w0 above_1822 9152.169154228855
+    w0 above_1823 9977.55223880597
+    w0 above_1824 9808.084577114429
+    w0 above_1825 10960.487562189055
+    w0 above_1826 11355.179104477611
+    w0 above_1827 12075.383084577115
+    w0 above_1828 13337.114427860697
+    w0 above_1829 13493.134328358208
+    w0 above_1830 15272.203980099503
+    w0 above_1831 15125.970149253732
+    w0 above_1832 14694.686567164179
+    w0 above_1833 16248.109452736318
+    w0 above_1834 17734.552238805973
+    w0 above_1835 17810.149253731342
+    w0 above_1836 17877.442786069652
+    w0 above_1837 18690.13930348259
+    w0 above_1838 20794.17910447761
+    w0 above_1839 23327.761194029852
+    w0 above_1840 20403.51741293532
+    w0 above_1841 21735.940298507463
+    w0 above_1842 23657.059701492537
+    w0 above_1843 26306.746268656716
+    w0 above_1844 27548.731343283584
+    w0 above_1845 26282.935323383084
+    w0 above_1846 27096.786069651742
+    w0 above_1847 28212.059701492537
+    w0 above_1848 30766.6368159204
+    w0 above_1849 33013.930348258706
+    w0 above_1850 31979.79104477612
+    w0 above_1851 32338.5671641791
+    w0 above_1852 31519.179104477615
+    w0 above_1853 36614.41791044776
+    w0 above_1854 33788.33333333333
+    w0 above_1855 36001.592039801
+    w0 above_1856 37565.26865671641
+    w0 above_1857 37451.26368159204
+    w0 above_1858 40320.18905472637
+    w0 above_1859 42606.5671641791
+    w0 above_1860 41929.154228855725
+    w0 above_1861 40567.12437810945
+    w0 above_1862 47176.02985074627
+    w0 above_1863 45638.68656716418
+    w0 above_1864 46562.31343283583
+    w0 above_1865 46470.89552238806
+    w0 above_1866 55712
+    w0 above_1867 49953.95024875622
+    w0 above_1868 53671.014925373136
+    w0 above_1869 56057.81094527364
+    w0 above_1870 51808.45273631841
+    w0 above_1871 56977.074626865666
+    w0 above_1872 58398.18407960199
+    w0 above_1873 58130.49751243781
+    w0 above_1874 62371.64179104478
+    w0 above_1875 66992.29850746269
+    w0 above_1876 64582.69651741293
+    w0 above_1877 62730.23880597015
+    w0 above_1878 60442.07462686567
+    w0 above_1879 65568.1592039801
+    w0 above_1880 68710.5671641791
+    w0 above_1881 78838.30845771144
+    w0 above_1882 69411.53731343284
+    w0 above_1883 73521.31343283581
+    w0 above_1884 71922.68656716419
+    w0 above_1885 74716.8855721393
+    w0 above_1886 75100.0447761194
+    w0 above_1887 83233.1144278607
+    w0 above_1888 88932.69651741293
+    w0 above_1889 82894.02985074627
+    w0 above_1890 84843.69154228855
+    w0 above_1891 91503.84079601991
+    w0 above_1892 91602.68656716419
+    w0 above_1893 87136.59701492537
+    w0 above_1894 89572.23880597015
+    w0 above_1895 99593.07462686567
+    w0 above_1896 100157.56716417911
+    w0 above_1897 101888.7960199005
+    w0 above_1898 98398.11940298507
+    w0 above_1899 99019.90049751244
+    w0 above_1900 110979.40298507463
+    w0 above_1901 105588.77611940299
+    w0 above_1902 119365.7263681592
+    w0 above_1903 110052.69651741293
+    w0 above_1904 114755.07462686567
+    w0 above_1905 104224.368159204
+    w0 above_1906 117487.06467661692
+    w0 above_1907 108675.40298507463
+    w0 above_1908 114195.66666666666
+    w0 above_1909 116056.0199004975
+    w0 above_1910 121358.89552238806
+    w0 above_1911 113669.41293532339
+    w0 above_1912 120568.18905472636
+    w0 above_1913 133386.23880597015
+    w0 above_1914 134124.90049751243
+    w0 above_1915 124789.45273631842
+    w0 above_1916 121850.55223880598
+    w0 above_1917 142208.78606965175
+    w0 above_1918 148411.94527363183
+    w0 above_1919 138570.74626865672
+    w0 above_1920 133075.3184079602
+    w0 above_1921 146137.18407960198
+    w0 above_1922 137370.80597014926
+    w0 above_1923 140419.82089552237
+    w0 above_1924 151329.60199004973
+    w0 above_1925 152796.62686567166
+    w0 above_1926 156427.98507462686
+    w0 above_1927 157148.9751243781
+    w0 above_1928 143817.02985074627
+    w0 above_1929 164030.89552238808
+    w0 above_1930 162508.43283582092
+    w0 above_1931 167302.44776119402
+    w0 above_1932 163075.86567164178
+    w0 above_1933 190189.3333333333
+    w0 above_1934 176136.71641791047
+    w0 above_1935 166926.80597014926
+    w0 above_1936 175289.1144278607
+    w0 above_1937 184944.71641791044
+    w0 above_1938 174047.3631840796
+    w0 above_1939 177551.3432835821
+    w0 above_1940 177826.9552238806
+    w0 above_1941 197887.24378109453
+    w0 above_1942 187830.14427860695
+    w0 above_1943 186666.98507462686
+    w0 above_1944 171258.70646766172
+    w0 above_1945 194929.61194029852
+    w0 above_1946 187776.0447761194
+    w0 above_1947 195881.31343283583
+    w0 above_1948 206014.36815920396
+    w0 above_1949 217997.76119402985
+    w0 above_1950 201482.83084577115
+    w0 above_1951 197693.7711442786
+    w0 above_1952 197577.80597014926
+    w0 above_1953 196256.52736318405
+    w0 above_1954 218570.8208955224
+    w0 above_1955 221634.08955223882
+    w0 above_1956 226101.08955223882
+    w0 above_1957 234946.78606965175
+    w0 above_1958 238533.223880597
+    w0 above_1959 243684.776119403
+    w0 above_1960 232443.14925373136
+    w0 above_1961 208211.91044776118
+    w0 above_1962 234625.9303482587
+    w0 above_1963 198590.12935323382
+    w0 above_1964 239023.43283582092
+    w0 above_1965 245329.83084577115
+    w0 above_1966 233758.45771144278
+    w0 above_1967 248927.5223880597
+    w0 above_1968 245828.57711442784
+    w0 above_1969 240653.184079602
+    w0 above_1970 237380.32835820896
+    w0 above_1971 262177.63184079604
+    w0 above_1972 249991.8855721393
+    w0 above_1973 252303.46268656716
+    w0 above_1974 274563.6815920398
+    w0 above_1975 280050.14925373136
+    w0 above_1976 271693.2388059701
+    w0 above_1977 268773.80099502485
+    w0 above_1978 261417.47761194033
+    w0 above_1979 265372.8358208955
+    w0 above_1980 274729.184079602
+    w0 above_1981 253125.78109452737
+    w0 above_1982 270252.7611940298
+    w0 above_1983 292455.6417910448
+    w0 above_1984 263509.9502487562
+    w0 above_1985 300578.77611940296
+    w0 above_1986 308060.6368159204
+    w0 above_1987 297569.39303482586
+    w0 above_1988 261159.44776119405
+    w0 above_1989 285944.32835820894
+    w0 above_1990 279470.05970149254
+    w0 above_1991 285102.80597014923
+    w0 above_1992 307687.12935323385
+    w0 above_1993 324972.19900497515
+    w0 above_1994 308297.9104477612
+    w0 above_1995 312694.10945273633
+    w0 above_1996 341904.7711442786
+    w0 above_1997 287958.98507462686
+    w0 above_1998 305055.1194029851
+    w0 above_1999 323608.9552238806
+    w0 above_2000 298136
+    w0 below_1800 -255.83084577114428
+    w0 below_1801 -489.9800995024876
+    w0 below_1802 -770.6268656716418
+    w0 below_1803 -1124.318407960199
+    w0 below_1804 -1546.3432835820897
+    w0 below_1805 -1637.6716417910447
+    w0 below_1806 -2122.497512437811
+    w0 below_1807 -2276.179104477612
+    w0 below_1808 -2777.955223880597
+    w0 below_1809 -2990
+    w0 below_1810 -3669.2935323383085
+    w0 below_1811 -3977.910447761194
+    w0 below_1812 -4425.820895522388
+    w0 below_1813 -4655.174129353234
+    w0 below_1814 -5133.731343283582
+    w0 below_1815 -5868.338308457712
+    w0 below_1816 -6173.36815920398
+    w0 below_1817 -6668.1492537313425
+    w0 below_1818 -6672.497512437811
+    w0 below_1819 -7223.681592039801
+    w0 below_1820 -7346.134328358209
+    w0 below_1821 -8762.567164179105
+    w0 below_1822 -9152.169154228855
+    w0 below_1823 -9977.55223880597
+    w0 below_1824 -9808.084577114429
+    w0 below_1825 -10960.487562189055
+    w0 below_1826 -11355.179104477611
+    w0 below_1827 -12075.383084577115
+    w0 below_1828 -13337.114427860697
+    w0 below_1829 -13493.134328358208
+    w0 below_1830 -15272.203980099503
+    w0 below_1831 -15125.970149253732
+    w0 below_1832 -14694.686567164179
+    w0 below_1833 -16248.109452736318
+    w0 below_1834 -17734.552238805973
+    w0 below_1835 -17810.149253731342
+    w0 below_1836 -17877.442786069652
+    w0 below_1837 -18690.13930348259
+    w0 below_1838 -20794.17910447761
+    w0 below_1839 -23327.761194029852
+    w0 below_1840 -20403.51741293532
+    w0 below_1841 -21735.940298507463
+    w0 below_1842 -23657.059701492537
+    w0 below_1843 -26306.746268656716
+    w0 below_1844 -27548.731343283584
+    w0 below_1845 -26282.935323383084
+    w0 below_1846 -27096.786069651742
+    w0 below_1847 -28212.059701492537
+    w0 below_1848 -30766.6368159204
+    w0 below_1849 -33013.930348258706
+    w0 below_1850 -31979.79104477612
+    w0 below_1851 -32338.5671641791
+    w0 below_1852 -31519.179104477615
+    w0 below_1853 -36614.41791044776
+    w0 below_1854 -33788.33333333333
+    w0 below_1855 -36001.592039801
+    w0 below_1856 -37565.26865671641
+    w0 below_1857 -37451.26368159204
+    w0 below_1858 -40320.18905472637
+    w0 below_1859 -42606.5671641791
+    w0 below_1860 -41929.154228855725
+    w0 below_1861 -40567.12437810945
+    w0 below_1862 -47176.02985074627
+    w0 below_1863 -45638.68656716418
+    w0 below_1864 -46562.31343283583
+    w0 below_1865 -46470.89552238806
+    w0 below_1866 -55712
+    w0 below_1867 -49953.95024875622
+    w0 below_1868 -53671.014925373136
+    w0 below_1869 -56057.81094527364
+    w0 below_1870 -51808.45273631841
+    w0 below_1871 -56977.074626865666
+    w0 below_1872 -58398.18407960199
+    w0 below_1873 -58130.49751243781
+    w0 below_1874 -62371.64179104478
+    w0 below_1875 -66992.29850746269
+    w0 below_1876 -64582.69651741293
+    w0 below_1877 -62730.23880597015
+    w0 below_1878 -60442.07462686567
+    w0 below_1879 -65568.1592039801
+    w0 below_1880 -68710.5671641791
+    w0 below_1881 -78838.30845771144
+    w0 below_1882 -69411.53731343284
+    w0 below_1883 -73521.31343283581
+    w0 below_1884 -71922.68656716419
+    w0 below_1885 -74716.8855721393
+    w0 below_1886 -75100.0447761194
+    w0 below_1887 -83233.1144278607
+    w0 below_1888 -88932.69651741293
+    w0 below_1889 -82894.02985074627
+    w0 below_1890 -84843.69154228855
+    w0 below_1891 -91503.84079601991
+    w0 below_1892 -91602.68656716419
+    w0 below_1893 -87136.59701492537
+    w0 below_1894 -89572.23880597015
+    w0 below_1895 -99593.07462686567
+    w0 below_1896 -100157.56716417911
+    w0 below_1897 -101888.7960199005
+    w0 below_1898 -98398.11940298507
+    w0 below_1899 -99019.90049751244
+    w0 below_1900 -110979.40298507463
+    w0 below_1901 -105588.77611940299
+    w0 below_1902 -119365.7263681592
+    w0 below_1903 -110052.69651741293
+    w0 below_1904 -114755.07462686567
+    w0 below_1905 -104224.368159204
+    w0 below_1906 -117487.06467661692
+    w0 below_1907 -108675.40298507463
+    w0 below_1908 -114195.66666666666
+    w0 below_1909 -116056.0199004975
+    w0 below_1910 -121358.89552238806
+    w0 below_1911 -113669.41293532339
+    w0 below_1912 -120568.18905472636
+    w0 below_1913 -133386.23880597015
+    w0 below_1914 -134124.90049751243
+    w0 below_1915 -124789.45273631842
+    w0 below_1916 -121850.55223880598
+    w0 below_1917 -142208.78606965175
+    w0 below_1918 -148411.94527363183
+    w0 below_1919 -138570.74626865672
+    w0 below_1920 -133075.3184079602
+    w0 below_1921 -146137.18407960198
+    w0 below_1922 -137370.80597014926
+    w0 below_1923 -140419.82089552237
+    w0 below_1924 -151329.60199004973
+    w0 below_1925 -152796.62686567166
+    w0 below_1926 -156427.98507462686
+    w0 below_1927 -157148.9751243781
+    w0 below_1928 -143817.02985074627
+    w0 below_1929 -164030.89552238808
+    w0 below_1930 -162508.43283582092
+    w0 below_1931 -167302.44776119402
+    w0 below_1932 -163075.86567164178
+    w0 below_1933 -190189.3333333333
+    w0 below_1934 -176136.71641791047
+    w0 below_1935 -166926.80597014926
+    w0 below_1936 -175289.1144278607
+    w0 below_1937 -184944.71641791044
+    w0 below_1938 -174047.3631840796
+    w0 below_1939 -177551.3432835821
+    w0 below_1940 -177826.9552238806
+    w0 below_1941 -197887.24378109453
+    w0 below_1942 -187830.14427860695
+    w0 below_1943 -186666.98507462686
+    w0 below_1944 -171258.70646766172
+    w0 below_1945 -194929.61194029852
+    w0 below_1946 -187776.0447761194
+    w0 below_1947 -195881.31343283583
+    w0 below_1948 -206014.36815920396
+    w0 below_1949 -217997.76119402985
+    w0 below_1950 -201482.83084577115
+    w0 below_1951 -197693.7711442786
+    w0 below_1952 -197577.80597014926
+    w0 below_1953 -196256.52736318405
+    w0 below_1954 -218570.8208955224
+    w0 below_1955 -221634.08955223882
+    w0 below_1956 -226101.08955223882
+    w0 below_1957 -234946.78606965175
+    w0 below_1958 -238533.223880597
+    w0 below_1959 -243684.776119403
+    w0 below_1960 -232443.14925373136
+    w0 below_1961 -208211.91044776118
+    w0 below_1962 -234625.9303482587
+    w0 below_1963 -198590.12935323382
+    w0 below_1964 -239023.43283582092
+    w0 below_1965 -245329.83084577115
+    w0 below_1966 -233758.45771144278
+    w0 below_1967 -248927.5223880597
+    w0 below_1968 -245828.57711442784
+    w0 below_1969 -240653.184079602
+    w0 below_1970 -237380.32835820896
+    w0 below_1971 -262177.63184079604
+    w0 below_1972 -249991.8855721393
+    w0 below_1973 -252303.46268656716
+    w0 below_1974 -274563.6815920398
+    w0 below_1975 -280050.14925373136
+    w0 below_1976 -271693.2388059701
+    w0 below_1977 -268773.80099502485
+    w0 below_1978 -261417.47761194033
+    w0 below_1979 -265372.8358208955
+    w0 below_1980 -274729.184079602
+    w0 below_1981 -253125.78109452737
+    w0 below_1982 -270252.7611940298
+    w0 below_1983 -292455.6417910448
+    w0 below_1984 -263509.9502487562
+    w0 below_1985 -300578.77611940296
+    w0 below_1986 -308060.6368159204
+    w0 below_1987 -297569.39303482586
+    w0 below_1988 -261159.44776119405
+    w0 below_1989 -285944.32835820894
+    w0 below_1990 -279470.05970149254
+    w0 below_1991 -285102.80597014923
+    w0 below_1992 -307687.12935323385
+    w0 below_1993 -324972.19900497515
+    w0 below_1994 -308297.9104477612
+    w0 below_1995 -312694.10945273633
+    w0 below_1996 -341904.7711442786
+    w0 below_1997 -287958.98507462686
+    w0 below_1998 -305055.1194029851
+    w0 below_1999 -323608.9552238806
+    w0 below_2000 -298136
+    w4 above_1800 39.38805970149254
+    w4 above_1801 90.19900497512438
+    w4 above_1802 139.955223880597
+    w4 above_1803 190.06965174129354
+    w4 above_1804 250.07462686567166
+    w4 above_1805 311.64179104477614
+    w4 above_1806 360.76119402985074
+    w4 above_1807 401.7512437810945
+    w4 above_1808 463.1194029850746
+    w4 above_1809 471.0945273631841
+    w4 above_1810 594.4925373134329
+    w4 above_1811 650.9253731343283
+    w4 above_1812 804.7711442786069
+    w4 above_1813 798.6965174129353
+    w4 above_1814 970.820895522388
+    w4 above_1815 1092.9353233830846
+    w4 above_1816 1098.233830845771
+    w4 above_1817 1136.5074626865671
+    w4 above_1818 1385.3930348258707
+    w4 above_1819 1408.5572139303483
+    w4 above_1820 1393.5223880597014
+    w4 above_1821 1552.8059701492537
+    w4 above_1822 1870.0945273631842
+    w4 above_1823 1922.2686567164178
+    w4 above_1824 2068.905472636816
+    w4 above_1825 2337.0248756218903
+    w4 above_1826 2153.686567164179
+    w4 above_1827 2134.129353233831
+    w4 above_1828 2505.5422885572143
+    w4 above_1829 2772.3880597014922
+    w4 above_1830 2773.6517412935323
+    w4 above_1831 3099.542288557214
+    w4 above_1832 2830.283582089552
+    w4 above_1833 3339.273631840796
+    w4 above_1834 3107.3383084577117
+    w4 above_1835 3588.7164179104475
+    w4 above_1836 4005.2039800995026
+    w4 above_1837 3790.9253731343283
+    w4 above_1838 4353.835820895522
+    w4 above_1839 4295.124378109453
+    w4 above_1840 4456.76119402985
+    w4 above_1841 4543.940298507462
+    w4 above_1842 5090.47263681592
+    w4 above_1843 4480.119402985075
+    w4 above_1844 5378.507462686567
+    w4 above_1845 5426.169154228856
+    w4 above_1846 5380.213930348258
+    w4 above_1847 5578.985074626866
+    w4 above_1848 6410.467661691542
+    w4 above_1849 6641.542288557214
+    w4 above_1850 6597.268656716418
+    w4 above_1851 6051.661691542288
+    w4 above_1852 7022.89552238806
+    w4 above_1853 7025.910447761194
+    w4 above_1854 7637.885572139303
+    w4 above_1855 7346.865671641791
+    w4 above_1856 8182.76119402985
+    w4 above_1857 7939.363184079602
+    w4 above_1858 8641.592039800995
+    w4 above_1859 8606.86567164179
+    w4 above_1860 9547.865671641792
+    w4 above_1861 9333.004975124377
+    w4 above_1862 9491.059701492537
+    w4 above_1863 9521.034825870647
+    w4 above_1864 10119.626865671642
+    w4 above_1865 10812.835820895523
+    w4 above_1866 11316
+    w4 above_1867 11068.099502487563
+    w4 above_1868 12125.805970149253
+    w4 above_1869 11048.1592039801
+    w4 above_1870 11822.029850746268
+    w4 above_1871 11919.402985074626
+    w4 above_1872 12574.885572139305
+    w4 above_1873 12716.218905472637
+    w4 above_1874 12992.537313432837
+    w4 above_1875 15000.73631840796
+    w4 above_1876 13517.5223880597
+    w4 above_1877 13833.55223880597
+    w4 above_1878 13851.333333333332
+    w4 above_1879 15325.37313432836
+    w4 above_1880 15644.686567164179
+    w4 above_1881 16200.099502487561
+    w4 above_1882 15956.233830845771
+    w4 above_1883 17566.86567164179
+    w4 above_1884 17266.8407960199
+    w4 above_1885 17163.63184079602
+    w4 above_1886 16332.62686567164
+    w4 above_1887 17333.373134328358
+    w4 above_1888 18788.741293532337
+    w4 above_1889 18406.567164179105
+    w4 above_1890 21255.51741293532
+    w4 above_1891 20093.532338308458
+    w4 above_1892 20891.223880597016
+    w4 above_1893 20873.144278606964
+    w4 above_1894 22117.512437810947
+    w4 above_1895 20258.86567164179
+    w4 above_1896 22703.79104477612
+    w4 above_1897 22780.855721393036
+    w4 above_1898 20925.940298507463
+    w4 above_1899 23878.60696517413
+    w4 above_1900 23471.696517412936
+    w4 above_1901 23539.16417910448
+    w4 above_1902 22486.7960199005
+    w4 above_1903 24820.81592039801
+    w4 above_1904 24216.86567164179
+    w4 above_1905 24584.08955223881
+    w4 above_1906 25719.393034825873
+    w4 above_1907 26115.582089552237
+    w4 above_1908 26867.144278606964
+    w4 above_1909 27263.034825870644
+    w4 above_1910 28597.686567164183
+    w4 above_1911 25620.696517412936
+    w4 above_1912 27834.542288557215
+    w4 above_1913 29647.373134328358
+    w4 above_1914 30336.54228855721
+    w4 above_1915 28433.850746268658
+    w4 above_1916 28410.044776119405
+    w4 above_1917 29697.253731343284
+    w4 above_1918 31211.746268656716
+    w4 above_1919 30163.582089552237
+    w4 above_1920 30104.920398009952
+    w4 above_1921 32420.437810945274
+    w4 above_1922 34315.164179104475
+    w4 above_1923 33035.82089552239
+    w4 above_1924 32138.059701492533
+    w4 above_1925 35483.73134328359
+    w4 above_1926 34976.17910447761
+    w4 above_1927 38660.45771144279
+    w4 above_1928 37746.298507462685
+    w4 above_1929 36860.49751243781
+    w4 above_1930 33548.383084577115
+    w4 above_1931 37118.92537313433
+    w4 above_1932 37449.09452736318
+    w4 above_1933 36878
+    w4 above_1934 40727.68656716418
+    w4 above_1935 39720.796019900496
+    w4 above_1936 38334.78109452737
+    w4 above_1937 42889.850746268654
+    w4 above_1938 37713.25870646766
+    w4 above_1939 42515.422885572145
+    w4 above_1940 44142.82089552239
+    w4 above_1941 42059.55223880597
+    w4 above_1942 42776.92039800995
+    w4 above_1943 47017.79104477612
+    w4 above_1944 47075.22388059702
+    w4 above_1945 44222.019900497515
+    w4 above_1946 43183.62686567164
+    w4 above_1947 45892.517412935325
+    w4 above_1948 44013.562189054726
+    w4 above_1949 49919.40298507463
+    w4 above_1950 43734.40796019901
+    w4 above_1951 52238.845771144275
+    w4 above_1952 46016.46268656716
+    w4 above_1953 43179.761194029845
+    w4 above_1954 42823.18407960199
+    w4 above_1955 46946.68656716418
+    w4 above_1956 45311.293532338306
+    w4 above_1957 48439.18407960199
+    w4 above_1958 49519.40298507462
+    w4 above_1959 50951.64179104478
+    w4 above_1960 54630.26368159204
+    w4 above_1961 52301.014925373136
+    w4 above_1962 53332.62686567164
+    w4 above_1963 52119.3631840796
+    w4 above_1964 55183.05970149254
+    w4 above_1965 56971.86069651741
+    w4 above_1966 56627.12437810945
+    w4 above_1967 52664.23880597015
+    w4 above_1968 61241.90049751243
+    w4 above_1969 52111.34328358209
+    w4 above_1970 58136.59701492538
+    w4 above_1971 55530.32835820896
+    w4 above_1972 60864.15422885572
+    w4 above_1973 59140.0895522388
+    w4 above_1974 60664.925373134334
+    w4 above_1975 58528.3184079602
+    w4 above_1976 64435.92537313433
+    w4 above_1977 60285.32338308458
+    w4 above_1978 65107.019900497515
+    w4 above_1979 58237.611940298506
+    w4 above_1980 65106.87064676617
+    w4 above_1981 60226.60696517413
+    w4 above_1982 64770.164179104475
+    w4 above_1983 58821.41293532339
+    w4 above_1984 59433.78109452737
+    w4 above_1985 60885.850746268654
+    w4 above_1986 62492.42288557214
+    w4 above_1987 62947.263681592034
+    w4 above_1988 65498.373134328365
+    w4 above_1989 65298.55721393035
+    w4 above_1990 65925.40796019901
+    w4 above_1991 62955.94029850746
+    w4 above_1992 67729.55721393034
+    w4 above_1993 63853.02487562189
+    w4 above_1994 68116.11940298507
+    w4 above_1995 70172.87562189055
+    w4 above_1996 66363.51741293533
+    w4 above_1997 65620.74626865672
+    w4 above_1998 76883.30348258707
+    w4 above_1999 68926.36815920398
+    w4 above_2000 74697
+    w4 below_1800 -39.38805970149254
+    w4 below_1801 -90.19900497512438
+    w4 below_1802 -139.955223880597
+    w4 below_1803 -190.06965174129354
+    w4 below_1804 -250.07462686567166
+    w4 below_1805 -311.64179104477614
+    w4 below_1806 -360.76119402985074
+    w4 below_1807 -401.7512437810945
+    w4 below_1808 -463.1194029850746
+    w4 below_1809 -471.0945273631841
+    w4 below_1810 -594.4925373134329
+    w4 below_1811 -650.9253731343283
+    w4 below_1812 -804.7711442786069
+    w4 below_1813 -798.6965174129353
+    w4 below_1814 -970.820895522388
+    w4 below_1815 -1092.9353233830846
+    w4 below_1816 -1098.233830845771
+    w4 below_1817 -1136.5074626865671
+    w4 below_1818 -1385.3930348258707
+    w4 below_1819 -1408.5572139303483
+    w4 below_1820 -1393.5223880597014
+    w4 below_1821 -1552.8059701492537
+    w4 below_1822 -1870.0945273631842
+    w4 below_1823 -1922.2686567164178
+    w4 below_1824 -2068.905472636816
+    w4 below_1825 -2337.0248756218903
+    w4 below_1826 -2153.686567164179
+    w4 below_1827 -2134.129353233831
+    w4 below_1828 -2505.5422885572143
+    w4 below_1829 -2772.3880597014922
+    w4 below_1830 -2773.6517412935323
+    w4 below_1831 -3099.542288557214
+    w4 below_1832 -2830.283582089552
+    w4 below_1833 -3339.273631840796
+    w4 below_1834 -3107.3383084577117
+    w4 below_1835 -3588.7164179104475
+    w4 below_1836 -4005.2039800995026
+    w4 below_1837 -3790.9253731343283
+    w4 below_1838 -4353.835820895522
+    w4 below_1839 -4295.124378109453
+    w4 below_1840 -4456.76119402985
+    w4 below_1841 -4543.940298507462
+    w4 below_1842 -5090.47263681592
+    w4 below_1843 -4480.119402985075
+    w4 below_1844 -5378.507462686567
+    w4 below_1845 -5426.169154228856
+    w4 below_1846 -5380.213930348258
+    w4 below_1847 -5578.985074626866
+    w4 below_1848 -6410.467661691542
+    w4 below_1849 -6641.542288557214
+    w4 below_1850 -6597.268656716418
+    w4 below_1851 -6051.661691542288
+    w4 below_1852 -7022.89552238806
+    w4 below_1853 -7025.910447761194
+    w4 below_1854 -7637.885572139303
+    w4 below_1855 -7346.865671641791
+    w4 below_1856 -8182.76119402985
+    w4 below_1857 -7939.363184079602
+    w4 below_1858 -8641.592039800995
+    w4 below_1859 -8606.86567164179
+    w4 below_1860 -9547.865671641792
+    w4 below_1861 -9333.004975124377
+    w4 below_1862 -9491.059701492537
+    w4 below_1863 -9521.034825870647
+    w4 below_1864 -10119.626865671642
+    w4 below_1865 -10812.835820895523
+    w4 below_1866 -11316
+    w4 below_1867 -11068.099502487563
+    w4 below_1868 -12125.805970149253
+    w4 below_1869 -11048.1592039801
+    w4 below_1870 -11822.029850746268
+    w4 below_1871 -11919.402985074626
+    w4 below_1872 -12574.885572139305
+    w4 below_1873 -12716.218905472637
+    w4 below_1874 -12992.537313432837
+    w4 below_1875 -15000.73631840796
+    w4 below_1876 -13517.5223880597
+    w4 below_1877 -13833.55223880597
+    w4 below_1878 -13851.333333333332
+    w4 below_1879 -15325.37313432836
+    w4 below_1880 -15644.686567164179
+    w4 below_1881 -16200.099502487561
+    w4 below_1882 -15956.233830845771
+    w4 below_1883 -17566.86567164179
+    w4 below_1884 -17266.8407960199
+    w4 below_1885 -17163.63184079602
+    w4 below_1886 -16332.62686567164
+    w4 below_1887 -17333.373134328358
+    w4 below_1888 -18788.741293532337
+    w4 below_1889 -18406.567164179105
+    w4 below_1890 -21255.51741293532
+    w4 below_1891 -20093.532338308458
+    w4 below_1892 -20891.223880597016
+    w4 below_1893 -20873.144278606964
+    w4 below_1894 -22117.512437810947
+    w4 below_1895 -20258.86567164179
+    w4 below_1896 -22703.79104477612
+    w4 below_1897 -22780.855721393036
+    w4 below_1898 -20925.940298507463
+    w4 below_1899 -23878.60696517413
+    w4 below_1900 -23471.696517412936
+    w4 below_1901 -23539.16417910448
+    w4 below_1902 -22486.7960199005
+    w4 below_1903 -24820.81592039801
+    w4 below_1904 -24216.86567164179
+    w4 below_1905 -24584.08955223881
+    w4 below_1906 -25719.393034825873
+    w4 below_1907 -26115.582089552237
+    w4 below_1908 -26867.144278606964
+    w4 below_1909 -27263.034825870644
+    w4 below_1910 -28597.686567164183
+    w4 below_1911 -25620.696517412936
+    w4 below_1912 -27834.542288557215
+    w4 below_1913 -29647.373134328358
+    w4 below_1914 -30336.54228855721
+    w4 below_1915 -28433.850746268658
+    w4 below_1916 -28410.044776119405
+    w4 below_1917 -29697.253731343284
+    w4 below_1918 -31211.746268656716
+    w4 below_1919 -30163.582089552237
+    w4 below_1920 -30104.920398009952
+    w4 below_1921 -32420.437810945274
+    w4 below_1922 -34315.164179104475
+    w4 below_1923 -33035.82089552239
+    w4 below_1924 -32138.059701492533
+    w4 below_1925 -35483.73134328359
+    w4 below_1926 -34976.17910447761
+    w4 below_1927 -38660.45771144279
+    w4 below_1928 -37746.298507462685
+    w4 below_1929 -36860.49751243781
+    w4 below_1930 -33548.383084577115
+    w4 below_1931 -37118.92537313433
+    w4 below_1932 -37449.09452736318
+    w4 below_1933 -36878
+    w4 below_1934 -40727.68656716418
+    w4 below_1935 -39720.796019900496
+    w4 below_1936 -38334.78109452737
+    w4 below_1937 -42889.850746268654
+    w4 below_1938 -37713.25870646766
+    w4 below_1939 -42515.422885572145
+    w4 below_1940 -44142.82089552239
+    w4 below_1941 -42059.55223880597
+    w4 below_1942 -42776.92039800995
+    w4 below_1943 -47017.79104477612
+    w4 below_1944 -47075.22388059702
+    w4 below_1945 -44222.019900497515
+    w4 below_1946 -43183.62686567164
+    w4 below_1947 -45892.517412935325
+    w4 below_1948 -44013.562189054726
+    w4 below_1949 -49919.40298507463
+    w4 below_1950 -43734.40796019901
+    w4 below_1951 -52238.845771144275
+    w4 below_1952 -46016.46268656716
+    w4 below_1953 -43179.761194029845
+    w4 below_1954 -42823.18407960199
+    w4 below_1955 -46946.68656716418
+    w4 below_1956 -45311.293532338306
+    w4 below_1957 -48439.18407960199
+    w4 below_1958 -49519.40298507462
+    w4 below_1959 -50951.64179104478
+    w4 below_1960 -54630.26368159204
+    w4 below_1961 -52301.014925373136
+    w4 below_1962 -53332.62686567164
+    w4 below_1963 -52119.3631840796
+    w4 below_1964 -55183.05970149254
+    w4 below_1965 -56971.86069651741
+    w4 below_1966 -56627.12437810945
+    w4 below_1967 -52664.23880597015
+    w4 below_1968 -61241.90049751243
+    w4 below_1969 -52111.34328358209
+    w4 below_1970 -58136.59701492538
+    w4 below_1971 -55530.32835820896
+    w4 below_1972 -60864.15422885572
+    w4 below_1973 -59140.0895522388
+    w4 below_1974 -60664.925373134334
+    w4 below_1975 -58528.3184079602
+    w4 below_1976 -64435.92537313433
+    w4 below_1977 -60285.32338308458
+    w4 below_1978 -65107.019900497515
+    w4 below_1979 -58237.611940298506
+    w4 below_1980 -65106.87064676617
+    w4 below_1981 -60226.60696517413
+    w4 below_1982 -64770.164179104475
+    w4 below_1983 -58821.41293532339
+    w4 below_1984 -59433.78109452737
+    w4 below_1985 -60885.850746268654
+    w4 below_1986 -62492.42288557214
+    w4 below_1987 -62947.263681592034
+    w4 below_1988 -65498.373134328365
+    w4 below_1989 -65298.55721393035
+    w4 below_1990 -65925.40796019901
+    w4 below_1991 -62955.94029850746
+    w4 below_1992 -67729.55721393034
+    w4 below_1993 -63853.02487562189
+    w4 below_1994 -68116.11940298507
+    w4 below_1995 -70172.87562189055
+    w4 below_1996 -66363.51741293533
+    w4 below_1997 -65620.74626865672
+    w4 below_1998 -76883.30348258707
+    w4 below_1999 -68926.36815920398
+    w4 below_2000 -74697
+    w3 above_1800 71.22388059701493
+    w3 above_1801 133.40298507462686
+    w3 above_1802 214.6417910447761
+    w3 above_1803 281.2139303482587
+    w3 above_1804 345.27363184079604
+    w3 above_1805 414.44776119402985
+    w3 above_1806 514.3432835820896
+    w3 above_1807 633.5124378109452
+    w3 above_1808 714.1343283582089
+    w3 above_1809 916.7164179104478
+    w3 above_1810 1031.7562189054727
+    w3 above_1811 1092.6567164179105
+    w3 above_1812 1142.8358208955224
+    w3 above_1813 1258.81592039801
+    w3 above_1814 1295.597014925373
+    w3 above_1815 1528.7562189054727
+    w3 above_1816 1599.9452736318408
+    w3 above_1817 1727.9104477611938
+    w3 above_1818 1956.4328358208954
+    w3 above_1819 2241.6915422885572
+    w3 above_1820 2414.89552238806
+    w3 above_1821 2271.144278606965
+    w3 above_1822 2260.179104477612
+    w3 above_1823 2755.9402985074626
+    w3 above_1824 2810.6965174129355
+    w3 above_1825 2829.6019900497513
+    w3 above_1826 3097.2089552238804
+    w3 above_1827 3349.9701492537315
+    w3 above_1828 3623.124378109453
+    w3 above_1829 3864.328358208955
+    w3 above_1830 4226.950248756219
+    w3 above_1831 4277.9701492537315
+    w3 above_1832 4857.731343283582
+    w3 above_1833 3823.393034825871
+    w3 above_1834 4990.89552238806
+    w3 above_1835 5274.985074626866
+    w3 above_1836 5292.10447761194
+    w3 above_1837 5316.218905472637
+    w3 above_1838 6009.686567164179
+    w3 above_1839 5896.915422885572
+    w3 above_1840 5655.348258706467
+    w3 above_1841 6525.462686567164
+    w3 above_1842 6478.452736318408
+    w3 above_1843 7319.3233830845775
+    w3 above_1844 7377.089552238806
+    w3 above_1845 7501.203980099503
+    w3 above_1846 8278.313432835821
+    w3 above_1847 8987.223880597016
+    w3 above_1848 8802.203980099503
+    w3 above_1849 9141.791044776119
+    w3 above_1850 9009.492537313434
+    w3 above_1851 10159.402985074626
+    w3 above_1852 9974.283582089553
+    w3 above_1853 9798.179104477613
+    w3 above_1854 10537.562189054726
+    w3 above_1855 11281.074626865671
+    w3 above_1856 11025.10447761194
+    w3 above_1857 11980.60696517413
+    w3 above_1858 11777.398009950248
+    w3 above_1859 12506.268656716416
+    w3 above_1860 11472.55223880597
+    w3 above_1861 12260.57711442786
+    w3 above_1862 13764.71641791045
+    w3 above_1863 13934.169154228855
+    w3 above_1864 14496.940298507463
+    w3 above_1865 13990.029850746268
+    w3 above_1866 15624
+    w3 above_1867 14557.074626865673
+    w3 above_1868 15607.388059701492
+    w3 above_1869 15230.049751243781
+    w3 above_1870 17160.099502487563
+    w3 above_1871 17068.65671641791
+    w3 above_1872 17265.771144278606
+    w3 above_1873 18253.70149253731
+    w3 above_1874 17736.194029850747
+    w3 above_1875 19139.900497512437
+    w3 above_1876 19611.248756218905
+    w3 above_1877 20318.417910447763
+    w3 above_1878 20955.830845771143
+    w3 above_1879 19840.7960199005
+    w3 above_1880 20156.910447761195
+    w3 above_1881 22007.41293532338
+    w3 above_1882 21942.97014925373
+    w3 above_1883 22822.089552238805
+    w3 above_1884 24523.13432835821
+    w3 above_1885 23579.402985074626
+    w3 above_1886 25053.83582089552
+    w3 above_1887 22343.243781094527
+    w3 above_1888 23205.089552238805
+    w3 above_1889 26257.61194029851
+    w3 above_1890 26257.800995024874
+    w3 above_1891 27372.05970149254
+    w3 above_1892 26965.373134328358
+    w3 above_1893 25954.756218905473
+    w3 above_1894 26391.567164179105
+    w3 above_1895 29288.597014925374
+    w3 above_1896 28185.014925373136
+    w3 above_1897 31300.51741293532
+    w3 above_1898 32932.5223880597
+    w3 above_1899 28789.05472636816
+    w3 above_1900 30902.48258706468
+    w3 above_1901 30004.23880597015
+    w3 above_1902 32909.78109452736
+    w3 above_1903 32097.194029850743
+    w3 above_1904 32616.343283582086
+    w3 above_1905 33681.104477611945
+    w3 above_1906 34320.383084577115
+    w3 above_1907 36588.89552238806
+    w3 above_1908 35470.00995024876
+    w3 above_1909 35516.31840796019
+    w3 above_1910 35809.37313432836
+    w3 above_1911 40968.03980099503
+    w3 above_1912 40732.845771144275
+    w3 above_1913 37975.611940298506
+    w3 above_1914 39162.935323383084
+    w3 above_1915 39873.99004975125
+    w3 above_1916 39825.40298507463
+    w3 above_1917 40973.59203980099
+    w3 above_1918 39500.30348258706
+    w3 above_1919 40534.32835820895
+    w3 above_1920 42331.94029850746
+    w3 above_1921 42419.58208955224
+    w3 above_1922 46560.0895522388
+    w3 above_1923 48572.83582089552
+    w3 above_1924 48644.90049751243
+    w3 above_1925 43853.014925373136
+    w3 above_1926 46595.09950248757
+    w3 above_1927 49989.41293532338
+    w3 above_1928 49364
+    w3 above_1929 49967.86069651742
+    w3 above_1930 46828.91542288558
+    w3 above_1931 51003.880597014926
+    w3 above_1932 52904.885572139305
+    w3 above_1933 51046.666666666664
+    w3 above_1934 52843.4328358209
+    w3 above_1935 53776.1592039801
+    w3 above_1936 51404.30845771144
+    w3 above_1937 49607.91044776119
+    w3 above_1938 49492.298507462685
+    w3 above_1939 61730.94527363184
+    w3 above_1940 57650.05970149254
+    w3 above_1941 57103.78109452737
+    w3 above_1942 62921.42288557214
+    w3 above_1943 55738.029850746265
+    w3 above_1944 57865.82089552239
+    w3 above_1945 59181.572139303484
+    w3 above_1946 60373.119402985074
+    w3 above_1947 65641.31343283581
+    w3 above_1948 64044.796019900496
+    w3 above_1949 65976.86567164179
+    w3 above_1950 63187.86567164179
+    w3 above_1951 59176.39800995025
+    w3 above_1952 63209.55223880597
+    w3 above_1953 68521.57213930348
+    w3 above_1954 63486.76616915423
+    w3 above_1955 64254.92537313433
+    w3 above_1956 68368.42288557214
+    w3 above_1957 70850.02985074627
+    w3 above_1958 68761.5671641791
+    w3 above_1959 64148.85572139304
+    w3 above_1960 66764.53731343284
+    w3 above_1961 67703.9104477612
+    w3 above_1962 75165.70646766169
+    w3 above_1963 70976.09950248756
+    w3 above_1964 69337.76119402985
+    w3 above_1965 74036.82587064676
+    w3 above_1966 76629.73631840796
+    w3 above_1967 74614.5671641791
+    w3 above_1968 75461.44278606965
+    w3 above_1969 68725.67164179105
+    w3 above_1970 75399.0895522388
+    w3 above_1971 75793.81094527362
+    w3 above_1972 79155.67661691541
+    w3 above_1973 82141.85074626865
+    w3 above_1974 93281.09452736318
+    w3 above_1975 74152.91542288558
+    w3 above_1976 78056.11940298507
+    w3 above_1977 88411.98009950249
+    w3 above_1978 84743.58706467663
+    w3 above_1979 78851.64179104478
+    w3 above_1980 84796.2487562189
+    w3 above_1981 86317.80099502488
+    w3 above_1982 87977.4776119403
+    w3 above_1983 88378.58706467663
+    w3 above_1984 89025.49751243781
+    w3 above_1985 83879.5223880597
+    w3 above_1986 86153.03980099503
+    w3 above_1987 97475.66169154228
+    w3 above_1988 94389.04477611941
+    w3 above_1989 91017.56218905473
+    w3 above_1990 92438.29850746269
+    w3 above_1991 88175.76119402985
+    w3 above_1992 88252.85074626865
+    w3 above_1993 86034.65671641791
+    w3 above_1994 90678.88059701493
+    w3 above_1995 94184.3383084577
+    w3 above_1996 94503.15422885572
+    w3 above_1997 93775.16417910447
+    w3 above_1998 93670.58706467663
+    w3 above_1999 102935.32338308459
+    w3 above_2000 99627
+    w3 below_1800 -71.22388059701493
+    w3 below_1801 -133.40298507462686
+    w3 below_1802 -214.6417910447761
+    w3 below_1803 -281.2139303482587
+    w3 below_1804 -345.27363184079604
+    w3 below_1805 -414.44776119402985
+    w3 below_1806 -514.3432835820896
+    w3 below_1807 -633.5124378109452
+    w3 below_1808 -714.1343283582089
+    w3 below_1809 -916.7164179104478
+    w3 below_1810 -1031.7562189054727
+    w3 below_1811 -1092.6567164179105
+    w3 below_1812 -1142.8358208955224
+    w3 below_1813 -1258.81592039801
+    w3 below_1814 -1295.597014925373
+    w3 below_1815 -1528.7562189054727
+    w3 below_1816 -1599.9452736318408
+    w3 below_1817 -1727.9104477611938
+    w3 below_1818 -1956.4328358208954
+    w3 below_1819 -2241.6915422885572
+    w3 below_1820 -2414.89552238806
+    w3 below_1821 -2271.144278606965
+    w3 below_1822 -2260.179104477612
+    w3 below_1823 -2755.9402985074626
+    w3 below_1824 -2810.6965174129355
+    w3 below_1825 -2829.6019900497513
+    w3 below_1826 -3097.2089552238804
+    w3 below_1827 -3349.9701492537315
+    w3 below_1828 -3623.124378109453
+    w3 below_1829 -3864.328358208955
+    w3 below_1830 -4226.950248756219
+    w3 below_1831 -4277.9701492537315
+    w3 below_1832 -4857.731343283582
+    w3 below_1833 -3823.393034825871
+    w3 below_1834 -4990.89552238806
+    w3 below_1835 -5274.985074626866
+    w3 below_1836 -5292.10447761194
+    w3 below_1837 -5316.218905472637
+    w3 below_1838 -6009.686567164179
+    w3 below_1839 -5896.915422885572
+    w3 below_1840 -5655.348258706467
+    w3 below_1841 -6525.462686567164
+    w3 below_1842 -6478.452736318408
+    w3 below_1843 -7319.3233830845775
+    w3 below_1844 -7377.089552238806
+    w3 below_1845 -7501.203980099503
+    w3 below_1846 -8278.313432835821
+    w3 below_1847 -8987.223880597016
+    w3 below_1848 -8802.203980099503
+    w3 below_1849 -9141.791044776119
+    w3 below_1850 -9009.492537313434
+    w3 below_1851 -10159.402985074626
+    w3 below_1852 -9974.283582089553
+    w3 below_1853 -9798.179104477613
+    w3 below_1854 -10537.562189054726
+    w3 below_1855 -11281.074626865671
+    w3 below_1856 -11025.10447761194
+    w3 below_1857 -11980.60696517413
+    w3 below_1858 -11777.398009950248
+    w3 below_1859 -12506.268656716416
+    w3 below_1860 -11472.55223880597
+    w3 below_1861 -12260.57711442786
+    w3 below_1862 -13764.71641791045
+    w3 below_1863 -13934.169154228855
+    w3 below_1864 -14496.940298507463
+    w3 below_1865 -13990.029850746268
+    w3 below_1866 -15624
+    w3 below_1867 -14557.074626865673
+    w3 below_1868 -15607.388059701492
+    w3 below_1869 -15230.049751243781
+    w3 below_1870 -17160.099502487563
+    w3 below_1871 -17068.65671641791
+    w3 below_1872 -17265.771144278606
+    w3 below_1873 -18253.70149253731
+    w3 below_1874 -17736.194029850747
+    w3 below_1875 -19139.900497512437
+    w3 below_1876 -19611.248756218905
+    w3 below_1877 -20318.417910447763
+    w3 below_1878 -20955.830845771143
+    w3 below_1879 -19840.7960199005
+    w3 below_1880 -20156.910447761195
+    w3 below_1881 -22007.41293532338
+    w3 below_1882 -21942.97014925373
+    w3 below_1883 -22822.089552238805
+    w3 below_1884 -24523.13432835821
+    w3 below_1885 -23579.402985074626
+    w3 below_1886 -25053.83582089552
+    w3 below_1887 -22343.243781094527
+    w3 below_1888 -23205.089552238805
+    w3 below_1889 -26257.61194029851
+    w3 below_1890 -26257.800995024874
+    w3 below_1891 -27372.05970149254
+    w3 below_1892 -26965.373134328358
+    w3 below_1893 -25954.756218905473
+    w3 below_1894 -26391.567164179105
+    w3 below_1895 -29288.597014925374
+    w3 below_1896 -28185.014925373136
+    w3 below_1897 -31300.51741293532
+    w3 below_1898 -32932.5223880597
+    w3 below_1899 -28789.05472636816
+    w3 below_1900 -30902.48258706468
+    w3 below_1901 -30004.23880597015
+    w3 below_1902 -32909.78109452736
+    w3 below_1903 -32097.194029850743
+    w3 below_1904 -32616.343283582086
+    w3 below_1905 -33681.104477611945
+    w3 below_1906 -34320.383084577115
+    w3 below_1907 -36588.89552238806
+    w3 below_1908 -35470.00995024876
+    w3 below_1909 -35516.31840796019
+    w3 below_1910 -35809.37313432836
+    w3 below_1911 -40968.03980099503
+    w3 below_1912 -40732.845771144275
+    w3 below_1913 -37975.611940298506
+    w3 below_1914 -39162.935323383084
+    w3 below_1915 -39873.99004975125
+    w3 below_1916 -39825.40298507463
+    w3 below_1917 -40973.59203980099
+    w3 below_1918 -39500.30348258706
+    w3 below_1919 -40534.32835820895
+    w3 below_1920 -42331.94029850746
+    w3 below_1921 -42419.58208955224
+    w3 below_1922 -46560.0895522388
+    w3 below_1923 -48572.83582089552
+    w3 below_1924 -48644.90049751243
+    w3 below_1925 -43853.014925373136
+    w3 below_1926 -46595.09950248757
+    w3 below_1927 -49989.41293532338
+    w3 below_1928 -49364
+    w3 below_1929 -49967.86069651742
+    w3 below_1930 -46828.91542288558
+    w3 below_1931 -51003.880597014926
+    w3 below_1932 -52904.885572139305
+    w3 below_1933 -51046.666666666664
+    w3 below_1934 -52843.4328358209
+    w3 below_1935 -53776.1592039801
+    w3 below_1936 -51404.30845771144
+    w3 below_1937 -49607.91044776119
+    w3 below_1938 -49492.298507462685
+    w3 below_1939 -61730.94527363184
+    w3 below_1940 -57650.05970149254
+    w3 below_1941 -57103.78109452737
+    w3 below_1942 -62921.42288557214
+    w3 below_1943 -55738.029850746265
+    w3 below_1944 -57865.82089552239
+    w3 below_1945 -59181.572139303484
+    w3 below_1946 -60373.119402985074
+    w3 below_1947 -65641.31343283581
+    w3 below_1948 -64044.796019900496
+    w3 below_1949 -65976.86567164179
+    w3 below_1950 -63187.86567164179
+    w3 below_1951 -59176.39800995025
+    w3 below_1952 -63209.55223880597
+    w3 below_1953 -68521.57213930348
+    w3 below_1954 -63486.76616915423
+    w3 below_1955 -64254.92537313433
+    w3 below_1956 -68368.42288557214
+    w3 below_1957 -70850.02985074627
+    w3 below_1958 -68761.5671641791
+    w3 below_1959 -64148.85572139304
+    w3 below_1960 -66764.53731343284
+    w3 below_1961 -67703.9104477612
+    w3 below_1962 -75165.70646766169
+    w3 below_1963 -70976.09950248756
+    w3 below_1964 -69337.76119402985
+    w3 below_1965 -74036.82587064676
+    w3 below_1966 -76629.73631840796
+    w3 below_1967 -74614.5671641791
+    w3 below_1968 -75461.44278606965
+    w3 below_1969 -68725.67164179105
+    w3 below_1970 -75399.0895522388
+    w3 below_1971 -75793.81094527362
+    w3 below_1972 -79155.67661691541
+    w3 below_1973 -82141.85074626865
+    w3 below_1974 -93281.09452736318
+    w3 below_1975 -74152.91542288558
+    w3 below_1976 -78056.11940298507
+    w3 below_1977 -88411.98009950249
+    w3 below_1978 -84743.58706467663
+    w3 below_1979 -78851.64179104478
+    w3 below_1980 -84796.2487562189
+    w3 below_1981 -86317.80099502488
+    w3 below_1982 -87977.4776119403
+    w3 below_1983 -88378.58706467663
+    w3 below_1984 -89025.49751243781
+    w3 below_1985 -83879.5223880597
+    w3 below_1986 -86153.03980099503
+    w3 below_1987 -97475.66169154228
+    w3 below_1988 -94389.04477611941
+    w3 below_1989 -91017.56218905473
+    w3 below_1990 -92438.29850746269
+    w3 below_1991 -88175.76119402985
+    w3 below_1992 -88252.85074626865
+    w3 below_1993 -86034.65671641791
+    w3 below_1994 -90678.88059701493
+    w3 below_1995 -94184.3383084577
+    w3 below_1996 -94503.15422885572
+    w3 below_1997 -93775.16417910447
+    w3 below_1998 -93670.58706467663
+    w3 below_1999 -102935.32338308459
+    w3 below_2000 -99627
+    __t__ obj 1
+    __t__ above_1800 -1
+    __t__ above_1801 -1
+    __t__ above_1802 -1
+    __t__ above_1803 -1
+    __t__ above_1804 -1
+    __t__ above_1805 -1
+    __t__ above_1806 -1
+    __t__ above_1807 -1
+    __t__ above_1808 -1
+    __t__ above_1809 -1
+    __t__ above_1810 -1
+    __t__ above_1811 -1
+    __t__ above_1812 -1
+    __t__ above_1813 -1
+    __t__ above_1814 -1
+    __t__ above_1815 -1
+    __t__ above_1816 -1
+    __t__ above_1817 -1
+    __t__ above_1818 -1
+    __t__ above_1819 -1
+    __t__ above_1820 -1
+    __t__ above_1821 -1
+    __t__ above_1822 -1
+    __t__ above_1823 -1
+    __t__ above_1824 -1
+    __t__ above_1825 -1
+    __t__ above_1826 -1
+    __t__ above_1827 -1
+    __t__ above_1828 -1
+    __t__ above_1829 -1
+    __t__ above_1830 -1
+    __t__ above_1831 -1
+    __t__ above_1832 -1
+    __t__ above_1833 -1
+    __t__ above_1834 -1
+    __t__ above_1835 -1
+    __t__ above_1836 -1
+    __t__ above_1837 -1
+    __t__ above_1838 -1
+    __t__ above_1839 -1
+    __t__ above_1840 -1
+    __t__ above_1841 -1
+    __t__ above_1842 -1
+    __t__ above_1843 -1
+    __t__ above_1844 -1
+    __t__ above_1845 -1
+    __t__ above_1846 -1
+    __t__ above_1847 -1
+    __t__ above_1848 -1
+    __t__ above_1849 -1
+    __t__ above_1850 -1
+    __t__ above_1851 -1
+    __t__ above_1852 -1
+    __t__ above_1853 -1
+    __t__ above_1854 -1
+    __t__ above_1855 -1
+    __t__ above_1856 -1
+    __t__ above_1857 -1
+    __t__ above_1858 -1
+    __t__ above_1859 -1
+    __t__ above_1860 -1
+    __t__ above_1861 -1
+    __t__ above_1862 -1
+    __t__ above_1863 -1
+    __t__ above_1864 -1
+    __t__ above_1865 -1
+    __t__ above_1866 -1
+    __t__ above_1867 -1
+    __t__ above_1868 -1
+    __t__ above_1869 -1
+    __t__ above_1870 -1
+    __t__ above_1871 -1
+    __t__ above_1872 -1
+    __t__ above_1873 -1
+    __t__ above_1874 -1
+    __t__ above_1875 -1
+    __t__ above_1876 -1
+    __t__ above_1877 -1
+    __t__ above_1878 -1
+    __t__ above_1879 -1
+    __t__ above_1880 -1
+    __t__ above_1881 -1
+    __t__ above_1882 -1
+    __t__ above_1883 -1
+    __t__ above_1884 -1
+    __t__ above_1885 -1
+    __t__ above_1886 -1
+    __t__ above_1887 -1
+    __t__ above_1888 -1
+    __t__ above_1889 -1
+    __t__ above_1890 -1
+    __t__ above_1891 -1
+    __t__ above_1892 -1
+    __t__ above_1893 -1
+    __t__ above_1894 -1
+    __t__ above_1895 -1
+    __t__ above_1896 -1
+    __t__ above_1897 -1
+    __t__ above_1898 -1
+    __t__ above_1899 -1
+    __t__ above_1900 -1
+    __t__ above_1901 -1
+    __t__ above_1902 -1
+    __t__ above_1903 -1
+    __t__ above_1904 -1
+    __t__ above_1905 -1
+    __t__ above_1906 -1
+    __t__ above_1907 -1
+    __t__ above_1908 -1
+    __t__ above_1909 -1
+    __t__ above_1910 -1
+    __t__ above_1911 -1
+    __t__ above_1912 -1
+    __t__ above_1913 -1
+    __t__ above_1914 -1
+    __t__ above_1915 -1
+    __t__ above_1916 -1
+    __t__ above_1917 -1
+    __t__ above_1918 -1
+    __t__ above_1919 -1
+    __t__ above_1920 -1
+    __t__ above_1921 -1
+    __t__ above_1922 -1
+    __t__ above_1923 -1
+    __t__ above_1924 -1
+    __t__ above_1925 -1
+    __t__ above_1926 -1
+    __t__ above_1927 -1
+    __t__ above_1928 -1
+    __t__ above_1929 -1
+    __t__ above_1930 -1
+    __t__ above_1931 -1
+    __t__ above_1932 -1
+    __t__ above_1933 -1
+    __t__ above_1934 -1
+    __t__ above_1935 -1
+    __t__ above_1936 -1
+    __t__ above_1937 -1
+    __t__ above_1938 -1
+    __t__ above_1939 -1
+    __t__ above_1940 -1
+    __t__ above_1941 -1
+    __t__ above_1942 -1
+    __t__ above_1943 -1
+    __t__ above_1944 -1
+    __t__ above_1945 -1
+    __t__ above_1946 -1
+    __t__ above_1947 -1
+    __t__ above_1948 -1
+    __t__ above_1949 -1
+    __t__ above_1950 -1
+    __t__ above_1951 -1
+    __t__ above_1952 -1
+    __t__ above_1953 -1
+    __t__ above_1954 -1
+    __t__ above_1955 -1
+    __t__ above_1956 -1
+    __t__ above_1957 -1
+    __t__ above_1958 -1
+    __t__ above_1959 -1
+    __t__ above_1960 -1
+    __t__ above_1961 -1
+    __t__ above_1962 -1
+    __t__ above_1963 -1
+    __t__ above_1964 -1
+    __t__ above_1965 -1
+    __t__ above_1966 -1
+    __t__ above_1967 -1
+    __t__ above_1968 -1
+    __t__ above_1969 -1
+    __t__ above_1970 -1
+    __t__ above_1971 -1
+    __t__ above_1972 -1
+    __t__ above_1973 -1
+    __t__ above_1974 -1
+    __t__ above_1975 -1
+    __t__ above_1976 -1
+    __t__ above_1977 -1
+    __t__ above_1978 -1
+    __t__ above_1979 -1
+    __t__ above_1980 -1
+    __t__ above_1981 -1
+    __t__ above_1982 -1
+    __t__ above_1983 -1
+    __t__ above_1984 -1
+    __t__ above_1985 -1
+    __t__ above_1986 -1
+    __t__ above_1987 -1
+    __t__ above_1988 -1
+    __t__ above_1989 -1
+    __t__ above_1990 -1
+    __t__ above_1991 -1
+    __t__ above_1992 -1
+    __t__ above_1993 -1
+    __t__ above_1994 -1
+    __t__ above_1995 -1
+    __t__ above_1996 -1
+    __t__ above_1997 -1
+    __t__ above_1998 -1
+    __t__ above_1999 -1
+    __t__ above_2000 -1
+    __t__ below_1800 -1
+    __t__ below_1801 -1
+    __t__ below_1802 -1
+    __t__ below_1803 -1
+    __t__ below_1804 -1
+    __t__ below_1805 -1
+    __t__ below_1806 -1
+    __t__ below_1807 -1
+    __t__ below_1808 -1
+    __t__ below_1809 -1
+    __t__ below_1810 -1
+    __t__ below_1811 -1
+    __t__ below_1812 -1
+    __t__ below_1813 -1
+    __t__ below_1814 -1
+    __t__ below_1815 -1
+    __t__ below_1816 -1
+    __t__ below_1817 -1
+    __t__ below_1818 -1
+    __t__ below_1819 -1
+    __t__ below_1820 -1
+    __t__ below_1821 -1
+    __t__ below_1822 -1
+    __t__ below_1823 -1
+    __t__ below_1824 -1
+    __t__ below_1825 -1
+    __t__ below_1826 -1
+    __t__ below_1827 -1
+    __t__ below_1828 -1
+    __t__ below_1829 -1
+    __t__ below_1830 -1
+    __t__ below_1831 -1
+    __t__ below_1832 -1
+    __t__ below_1833 -1
+    __t__ below_1834 -1
+    __t__ below_1835 -1
+    __t__ below_1836 -1
+    __t__ below_1837 -1
+    __t__ below_1838 -1
+    __t__ below_1839 -1
+    __t__ below_1840 -1
+    __t__ below_1841 -1
+    __t__ below_1842 -1
+    __t__ below_1843 -1
+    __t__ below_1844 -1
+    __t__ below_1845 -1
+    __t__ below_1846 -1
+    __t__ below_1847 -1
+    __t__ below_1848 -1
+    __t__ below_1849 -1
+    __t__ below_1850 -1
+    __t__ below_1851 -1
+    __t__ below_1852 -1
+    __t__ below_1853 -1
+    __t__ below_1854 -1
+    __t__ below_1855 -1
+    __t__ below_1856 -1
+    __t__ below_1857 -1
+    __t__ below_1858 -1
+    __t__ below_1859 -1
+    __t__ below_1860 -1
+    __t__ below_1861 -1
+    __t__ below_1862 -1
+    __t__ below_1863 -1
+    __t__ below_1864 -1
+    __t__ below_1865 -1
+    __t__ below_1866 -1
+    __t__ below_1867 -1
+    __t__ below_1868 -1
+    __t__ below_1869 -1
+    __t__ below_1870 -1
+    __t__ below_1871 -1
+    __t__ below_1872 -1
+    __t__ below_1873 -1
+    __t__ below_1874 -1
+    __t__ below_1875 -1
+    __t__ below_1876 -1
+    __t__ below_1877 -1
+    __t__ below_1878 -1
+    __t__ below_1879 -1
+    __t__ below_1880 -1
+    __t__ below_1881 -1
+    __t__ below_1882 -1
+    __t__ below_1883 -1
+    __t__ below_1884 -1
+    __t__ below_1885 -1
+    __t__ below_1886 -1
+    __t__ below_1887 -1
+    __t__ below_1888 -1
+    __t__ below_1889 -1
+    __t__ below_1890 -1
+    __t__ below_1891 -1
+    __t__ below_1892 -1
+    __t__ below_1893 -1
+    __t__ below_1894 -1
+    __t__ below_1895 -1
+    __t__ below_1896 -1
+    __t__ below_1897 -1
+    __t__ below_1898 -1
+    __t__ below_1899 -1
+    __t__ below_1900 -1
+    __t__ below_1901 -1
+    __t__ below_1902 -1
+    __t__ below_1903 -1
+    __t__ below_1904 -1
+    __t__ below_1905 -1
+    __t__ below_1906 -1
+    __t__ below_1907 -1
+    __t__ below_1908 -1
+    __t__ below_1909 -1
+    __t__ below_1910 -1
+    __t__ below_1911 -1
+    __t__ below_1912 -1
+    __t__ below_1913 -1
+    __t__ below_1914 -1
+    __t__ below_1915 -1
+    __t__ below_1916 -1
+    __t__ below_1917 -1
+    __t__ below_1918 -1
+    __t__ below_1919 -1
+    __t__ below_1920 -1
+    __t__ below_1921 -1
+    __t__ below_1922 -1
+    __t__ below_1923 -1
+    __t__ below_1924 -1
+    __t__ below_1925 -1
+    __t__ below_1926 -1
+    __t__ below_1927 -1
+    __t__ below_1928 -1
+    __t__ below_1929 -1
+    __t__ below_1930 -1
+    __t__ below_1931 -1
+    __t__ below_1932 -1
+    __t__ below_1933 -1
+    __t__ below_1934 -1
+    __t__ below_1935 -1
+    __t__ below_1936 -1
+    __t__ below_1937 -1
+    __t__ below_1938 -1
+    __t__ below_1939 -1
+    __t__ below_1940 -1
+    __t__ below_1941 -1
+    __t__ below_1942 -1
+    __t__ below_1943 -1
+    __t__ below_1944 -1
+    __t__ below_1945 -1
+    __t__ below_1946 -1
+    __t__ below_1947 -1
+    __t__ below_1948 -1
+    __t__ below_1949 -1
+    __t__ below_1950 -1
+    __t__ below_1951 -1
+    __t__ below_1952 -1
+    __t__ below_1953 -1
+    __t__ below_1954 -1
+    __t__ below_1955 -1
+    __t__ below_1956 -1
+    __t__ below_1957 -1
+    __t__ below_1958 -1
+    __t__ below_1959 -1
+    __t__ below_1960 -1
+    __t__ below_1961 -1
+    __t__ below_1962 -1
+    __t__ below_1963 -1
+    __t__ below_1964 -1
+    __t__ below_1965 -1
+    __t__ below_1966 -1
+    __t__ below_1967 -1
+    __t__ below_1968 -1
+    __t__ below_1969 -1
+    __t__ below_1970 -1
+    __t__ below_1971 -1
+    __t__ below_1972 -1
+    __t__ below_1973 -1
+    __t__ below_1974 -1
+    __t__ below_1975 -1
+    __t__ below_1976 -1
+    __t__ below_1977 -1
+    __t__ below_1978 -1
+    __t__ below_1979 -1
+    __t__ below_1980 -1
+    __t__ below_1981 -1
+    __t__ below_1982 -1
+    __t__ below_1983 -1
+    __t__ below_1984 -1
+    __t__ below_1985 -1
+    __t__ below_1986 -1
+    __t__ below_1987 -1
+    __t__ below_1988 -1
+    __t__ below_1989 -1
+    __t__ below_1990 -1
+    __t__ below_1991 -1
+    __t__ below_1992 -1
+    __t__ below_1993 -1
+    __t__ below_1994 -1
+    __t__ below_1995 -1
+    __t__ below_1996 -1
+    __t__ below_1997 -1
+    __t__ below_1998 -1
+    __t__ below_1999 -1
+    __t__ below_2000 -1
+RHS
+    RHS above_1800 0.0845771144278607
+    RHS above_1801 0.1791044776119403
+    RHS above_1802 0.23880597014925373
+    RHS above_1803 0.3582089552238806
+    RHS above_1804 0.44776119402985076
+    RHS above_1805 0.5373134328358209
+    RHS above_1806 0.5920398009950248
+    RHS above_1807 0.6766169154228856
+    RHS above_1808 0.8507462686567163
+    RHS above_1809 1.0447761194029852
+    RHS above_1810 1.1492537313432836
+    RHS above_1811 1.2537313432835822
+    RHS above_1812 1.2288557213930347
+    RHS above_1813 1.462686567164179
+    RHS above_1814 1.6417910447761193
+    RHS above_1815 1.8308457711442785
+    RHS above_1816 2.029850746268657
+    RHS above_1817 2.059701492537313
+    RHS above_1818 2.45771144278607
+    RHS above_1819 2.6865671641791047
+    RHS above_1820 2.5074626865671643
+    RHS above_1821 2.955223880597015
+    RHS above_1822 3.2039800995024876
+    RHS above_1823 3.2238805970149254
+    RHS above_1824 3.482587064676617
+    RHS above_1825 4.1393034825870645
+    RHS above_1826 4.432835820895522
+    RHS above_1827 4.875621890547264
+    RHS above_1828 5.0497512437810945
+    RHS above_1829 5.074626865671641
+    RHS above_1830 5.860696517412935
+    RHS above_1831 5.572139303482587
+    RHS above_1832 6.2388059701492535
+    RHS above_1833 6.258706467661692
+    RHS above_1834 6.965174129353234
+    RHS above_1835 7.522388059701492
+    RHS above_1836 7.915422885572139
+    RHS above_1837 8.507462686567164
+    RHS above_1838 8.149253731343284
+    RHS above_1839 9.154228855721394
+    RHS above_1840 8.975124378109452
+    RHS above_1841 10.656716417910447
+    RHS above_1842 11.124378109452737
+    RHS above_1843 12.039800995024876
+    RHS above_1844 12.537313432835822
+    RHS above_1845 12.587064676616917
+    RHS above_1846 12.62686567164179
+    RHS above_1847 14.328358208955224
+    RHS above_1848 14.626865671641792
+    RHS above_1849 13.930348258706468
+    RHS above_1850 14.716417910447761
+    RHS above_1851 16.557213930348258
+    RHS above_1852 16.084577114427862
+    RHS above_1853 17.46268656716418
+    RHS above_1854 20.796019900497512
+    RHS above_1855 21.17412935323383
+    RHS above_1856 21.55223880597015
+    RHS above_1857 20.776119402985074
+    RHS above_1858 21.427860696517413
+    RHS above_1859 23.88059701492537
+    RHS above_1860 24.27860696517413
+    RHS above_1861 28.99502487562189
+    RHS above_1862 28.522388059701495
+    RHS above_1863 28.33830845771144
+    RHS above_1864 28.134328358208958
+    RHS above_1865 31.52238805970149
+    RHS above_1866 34.33333333333333
+    RHS above_1867 29.771144278606968
+    RHS above_1868 34.67164179104478
+    RHS above_1869 34.82587064676617
+    RHS above_1870 37.44278606965174
+    RHS above_1871 40.83582089552239
+    RHS above_1872 37.77114427860697
+    RHS above_1873 43.44278606965174
+    RHS above_1874 42.16417910447761
+    RHS above_1875 44.61691542288557
+    RHS above_1876 47.88557213930348
+    RHS above_1877 48.8955223880597
+    RHS above_1878 46.77114427860696
+    RHS above_1879 45.37313432835821
+    RHS above_1880 53.59701492537313
+    RHS above_1881 50.58706467661691
+    RHS above_1882 49.13930348258707
+    RHS above_1883 52.23880597014925
+    RHS above_1884 54.55223880597015
+    RHS above_1885 54.33830845771144
+    RHS above_1886 56.26865671641791
+    RHS above_1887 60.85572139303483
+    RHS above_1888 62.87562189054726
+    RHS above_1889 60.8955223880597
+    RHS above_1890 70.17412935323382
+    RHS above_1891 70.48756218905473
+    RHS above_1892 74.49253731343283
+    RHS above_1893 81.3731343283582
+    RHS above_1894 82.71144278606965
+    RHS above_1895 80.71641791044776
+    RHS above_1896 80.59203980099502
+    RHS above_1897 89.71144278606965
+    RHS above_1898 88.65671641791045
+    RHS above_1899 87.56218905472637
+    RHS above_1900 90.44776119402985
+    RHS above_1901 99.46268656716418
+    RHS above_1902 100.43781094527363
+    RHS above_1903 99.34328358208955
+    RHS above_1904 108.13432835820895
+    RHS above_1905 117.60199004975125
+    RHS above_1906 127.76119402985076
+    RHS above_1907 133.7910447761194
+    RHS above_1908 125.81094527363183
+    RHS above_1909 126.41791044776119
+    RHS above_1910 134.1940298507463
+    RHS above_1911 142.64676616915423
+    RHS above_1912 155.726368159204
+    RHS above_1913 142.3582089552239
+    RHS above_1914 157.910447761194
+    RHS above_1915 171.98009950248758
+    RHS above_1916 166.47761194029852
+    RHS above_1917 194.318407960199
+    RHS above_1918 177.01990049751242
+    RHS above_1919 160
+    RHS above_1920 193.23880597014926
+    RHS above_1921 208.79601990049753
+    RHS above_1922 201.32835820895522
+    RHS above_1923 203.58208955223878
+    RHS above_1924 188.43283582089552
+    RHS above_1925 199.97014925373136
+    RHS above_1926 204.08457711442787
+    RHS above_1927 208.23880597014926
+    RHS above_1928 212.43283582089552
+    RHS above_1929 227.01492537313436
+    RHS above_1930 220.28855721393035
+    RHS above_1931 233.7910447761194
+    RHS above_1932 236.8855721393035
+    RHS above_1933 226.66666666666666
+    RHS above_1934 243.80597014925374
+    RHS above_1935 219.90049751243782
+    RHS above_1936 241.28358208955225
+    RHS above_1937 219.01492537313433
+    RHS above_1938 219.910447761194
+    RHS above_1939 224.27860696517413
+    RHS above_1940 201.32835820895522
+    RHS above_1941 226.06965174129354
+    RHS above_1942 232.6417910447761
+    RHS above_1943 221.3731343283582
+    RHS above_1944 234.45273631840797
+    RHS above_1945 219.363184079602
+    RHS above_1946 235.49253731343285
+    RHS above_1947 223.1044776119403
+    RHS above_1948 241.66169154228854
+    RHS above_1949 216.41791044776122
+    RHS above_1950 208.84577114427861
+    RHS above_1951 220.81592039800995
+    RHS above_1952 216.17910447761193
+    RHS above_1953 199.20398009950247
+    RHS above_1954 196.64179104477614
+    RHS above_1955 201.01492537313433
+    RHS above_1956 203.86567164179104
+    RHS above_1957 191.80099502487562
+    RHS above_1958 202.50746268656715
+    RHS above_1959 193.43283582089552
+    RHS above_1960 197.044776119403
+    RHS above_1961 217.61194029850745
+    RHS above_1962 197.87064676616916
+    RHS above_1963 210.50746268656715
+    RHS above_1964 185.5223880597015
+    RHS above_1965 179.2139303482587
+    RHS above_1966 180.29353233830847
+    RHS above_1967 195.5820895522388
+    RHS above_1968 189.17910447761193
+    RHS above_1969 200.44776119402985
+    RHS above_1970 183.76119402985074
+    RHS above_1971 202.80597014925374
+    RHS above_1972 186.77114427860695
+    RHS above_1973 194.77611940298507
+    RHS above_1974 198.50746268656718
+    RHS above_1975 197.89054726368158
+    RHS above_1976 205.17910447761193
+    RHS above_1977 204.56716417910448
+    RHS above_1978 201.2636815920398
+    RHS above_1979 192.53731343283582
+    RHS above_1980 202.61194029850748
+    RHS above_1981 201.01492537313433
+    RHS above_1982 203.02985074626864
+    RHS above_1983 183.08457711442787
+    RHS above_1984 207.08955223880596
+    RHS above_1985 213.76119402985074
+    RHS above_1986 202.81592039800995
+    RHS above_1987 222.60696517412936
+    RHS above_1988 216.26865671641792
+    RHS above_1989 199.45273631840797
+    RHS above_1990 222.3582089552239
+    RHS above_1991 212.0597014925373
+    RHS above_1992 210.28358208955223
+    RHS above_1993 212.33830845771146
+    RHS above_1994 195.97014925373134
+    RHS above_1995 213.55223880597015
+    RHS above_1996 228.36318407960198
+    RHS above_1997 234.44776119402985
+    RHS above_1998 225.7313432835821
+    RHS above_1999 215.92039800995025
+    RHS above_2000 219
+    RHS below_1800 -0.0845771144278607
+    RHS below_1801 -0.1791044776119403
+    RHS below_1802 -0.23880597014925373
+    RHS below_1803 -0.3582089552238806
+    RHS below_1804 -0.44776119402985076
+    RHS below_1805 -0.5373134328358209
+    RHS below_1806 -0.5920398009950248
+    RHS below_1807 -0.6766169154228856
+    RHS below_1808 -0.8507462686567163
+    RHS below_1809 -1.0447761194029852
+    RHS below_1810 -1.1492537313432836
+    RHS below_1811 -1.2537313432835822
+    RHS below_1812 -1.2288557213930347
+    RHS below_1813 -1.462686567164179
+    RHS below_1814 -1.6417910447761193
+    RHS below_1815 -1.8308457711442785
+    RHS below_1816 -2.029850746268657
+    RHS below_1817 -2.059701492537313
+    RHS below_1818 -2.45771144278607
+    RHS below_1819 -2.6865671641791047
+    RHS below_1820 -2.5074626865671643
+    RHS below_1821 -2.955223880597015
+    RHS below_1822 -3.2039800995024876
+    RHS below_1823 -3.2238805970149254
+    RHS below_1824 -3.482587064676617
+    RHS below_1825 -4.1393034825870645
+    RHS below_1826 -4.432835820895522
+    RHS below_1827 -4.875621890547264
+    RHS below_1828 -5.0497512437810945
+    RHS below_1829 -5.074626865671641
+    RHS below_1830 -5.860696517412935
+    RHS below_1831 -5.572139303482587
+    RHS below_1832 -6.2388059701492535
+    RHS below_1833 -6.258706467661692
+    RHS below_1834 -6.965174129353234
+    RHS below_1835 -7.522388059701492
+    RHS below_1836 -7.915422885572139
+    RHS below_1837 -8.507462686567164
+    RHS below_1838 -8.149253731343284
+    RHS below_1839 -9.154228855721394
+    RHS below_1840 -8.975124378109452
+    RHS below_1841 -10.656716417910447
+    RHS below_1842 -11.124378109452737
+    RHS below_1843 -12.039800995024876
+    RHS below_1844 -12.537313432835822
+    RHS below_1845 -12.587064676616917
+    RHS below_1846 -12.62686567164179
+    RHS below_1847 -14.328358208955224
+    RHS below_1848 -14.626865671641792
+    RHS below_1849 -13.930348258706468
+    RHS below_1850 -14.716417910447761
+    RHS below_1851 -16.557213930348258
+    RHS below_1852 -16.084577114427862
+    RHS below_1853 -17.46268656716418
+    RHS below_1854 -20.796019900497512
+    RHS below_1855 -21.17412935323383
+    RHS below_1856 -21.55223880597015
+    RHS below_1857 -20.776119402985074
+    RHS below_1858 -21.427860696517413
+    RHS below_1859 -23.88059701492537
+    RHS below_1860 -24.27860696517413
+    RHS below_1861 -28.99502487562189
+    RHS below_1862 -28.522388059701495
+    RHS below_1863 -28.33830845771144
+    RHS below_1864 -28.134328358208958
+    RHS below_1865 -31.52238805970149
+    RHS below_1866 -34.33333333333333
+    RHS below_1867 -29.771144278606968
+    RHS below_1868 -34.67164179104478
+    RHS below_1869 -34.82587064676617
+    RHS below_1870 -37.44278606965174
+    RHS below_1871 -40.83582089552239
+    RHS below_1872 -37.77114427860697
+    RHS below_1873 -43.44278606965174
+    RHS below_1874 -42.16417910447761
+    RHS below_1875 -44.61691542288557
+    RHS below_1876 -47.88557213930348
+    RHS below_1877 -48.8955223880597
+    RHS below_1878 -46.77114427860696
+    RHS below_1879 -45.37313432835821
+    RHS below_1880 -53.59701492537313
+    RHS below_1881 -50.58706467661691
+    RHS below_1882 -49.13930348258707
+    RHS below_1883 -52.23880597014925
+    RHS below_1884 -54.55223880597015
+    RHS below_1885 -54.33830845771144
+    RHS below_1886 -56.26865671641791
+    RHS below_1887 -60.85572139303483
+    RHS below_1888 -62.87562189054726
+    RHS below_1889 -60.8955223880597
+    RHS below_1890 -70.17412935323382
+    RHS below_1891 -70.48756218905473
+    RHS below_1892 -74.49253731343283
+    RHS below_1893 -81.3731343283582
+    RHS below_1894 -82.71144278606965
+    RHS below_1895 -80.71641791044776
+    RHS below_1896 -80.59203980099502
+    RHS below_1897 -89.71144278606965
+    RHS below_1898 -88.65671641791045
+    RHS below_1899 -87.56218905472637
+    RHS below_1900 -90.44776119402985
+    RHS below_1901 -99.46268656716418
+    RHS below_1902 -100.43781094527363
+    RHS below_1903 -99.34328358208955
+    RHS below_1904 -108.13432835820895
+    RHS below_1905 -117.60199004975125
+    RHS below_1906 -127.76119402985076
+    RHS below_1907 -133.7910447761194
+    RHS below_1908 -125.81094527363183
+    RHS below_1909 -126.41791044776119
+    RHS below_1910 -134.1940298507463
+    RHS below_1911 -142.64676616915423
+    RHS below_1912 -155.726368159204
+    RHS below_1913 -142.3582089552239
+    RHS below_1914 -157.910447761194
+    RHS below_1915 -171.98009950248758
+    RHS below_1916 -166.47761194029852
+    RHS below_1917 -194.318407960199
+    RHS below_1918 -177.01990049751242
+    RHS below_1919 -160
+    RHS below_1920 -193.23880597014926
+    RHS below_1921 -208.79601990049753
+    RHS below_1922 -201.32835820895522
+    RHS below_1923 -203.58208955223878
+    RHS below_1924 -188.43283582089552
+    RHS below_1925 -199.97014925373136
+    RHS below_1926 -204.08457711442787
+    RHS below_1927 -208.23880597014926
+    RHS below_1928 -212.43283582089552
+    RHS below_1929 -227.01492537313436
+    RHS below_1930 -220.28855721393035
+    RHS below_1931 -233.7910447761194
+    RHS below_1932 -236.8855721393035
+    RHS below_1933 -226.66666666666666
+    RHS below_1934 -243.80597014925374
+    RHS below_1935 -219.90049751243782
+    RHS below_1936 -241.28358208955225
+    RHS below_1937 -219.01492537313433
+    RHS below_1938 -219.910447761194
+    RHS below_1939 -224.27860696517413
+    RHS below_1940 -201.32835820895522
+    RHS below_1941 -226.06965174129354
+    RHS below_1942 -232.6417910447761
+    RHS below_1943 -221.3731343283582
+    RHS below_1944 -234.45273631840797
+    RHS below_1945 -219.363184079602
+    RHS below_1946 -235.49253731343285
+    RHS below_1947 -223.1044776119403
+    RHS below_1948 -241.66169154228854
+    RHS below_1949 -216.41791044776122
+    RHS below_1950 -208.84577114427861
+    RHS below_1951 -220.81592039800995
+    RHS below_1952 -216.17910447761193
+    RHS below_1953 -199.20398009950247
+    RHS below_1954 -196.64179104477614
+    RHS below_1955 -201.01492537313433
+    RHS below_1956 -203.86567164179104
+    RHS below_1957 -191.80099502487562
+    RHS below_1958 -202.50746268656715
+    RHS below_1959 -193.43283582089552
+    RHS below_1960 -197.044776119403
+    RHS below_1961 -217.61194029850745
+    RHS below_1962 -197.87064676616916
+    RHS below_1963 -210.50746268656715
+    RHS below_1964 -185.5223880597015
+    RHS below_1965 -179.2139303482587
+    RHS below_1966 -180.29353233830847
+    RHS below_1967 -195.5820895522388
+    RHS below_1968 -189.17910447761193
+    RHS below_1969 -200.44776119402985
+    RHS below_1970 -183.76119402985074
+    RHS below_1971 -202.80597014925374
+    RHS below_1972 -186.77114427860695
+    RHS below_1973 -194.77611940298507
+    RHS below_1974 -198.50746268656718
+    RHS below_1975 -197.89054726368158
+    RHS below_1976 -205.17910447761193
+    RHS below_1977 -204.56716417910448
+    RHS below_1978 -201.2636815920398
+    RHS below_1979 -192.53731343283582
+    RHS below_1980 -202.61194029850748
+    RHS below_1981 -201.01492537313433
+    RHS below_1982 -203.02985074626864
+    RHS below_1983 -183.08457711442787
+    RHS below_1984 -207.08955223880596
+    RHS below_1985 -213.76119402985074
+    RHS below_1986 -202.81592039800995
+    RHS below_1987 -222.60696517412936
+    RHS below_1988 -216.26865671641792
+    RHS below_1989 -199.45273631840797
+    RHS below_1990 -222.3582089552239
+    RHS below_1991 -212.0597014925373
+    RHS below_1992 -210.28358208955223
+    RHS below_1993 -212.33830845771146
+    RHS below_1994 -195.97014925373134
+    RHS below_1995 -213.55223880597015
+    RHS below_1996 -228.36318407960198
+    RHS below_1997 -234.44776119402985
+    RHS below_1998 -225.7313432835821
+    RHS below_1999 -215.92039800995025
+    RHS below_2000 -219
+BOUNDS
+ENDATA
@@ -0,0 +1,2420 @@
+NAME w0_w4_w8-bad21a3afa86af12
+ROWS
+ N obj
+ L above_1800
+ L above_1801
+ L above_1802
+ L above_1803
+ L above_1804
+ L above_1805
+ L above_1806
+ L above_1807
+ L above_1808
+ L above_1809
+ L above_1810
+ L above_1811
+ L above_1812
+ L above_1813
+ L above_1814
+ L above_1815
+ L above_1816
+ L above_1817
+ L above_1818
+ L above_1819
+ L above_1820
+ L above_1821
+ L above_1822
+ L above_1823
+ L above_1824
+ L above_1825
+ L above_1826
+ L above_1827
+ L above_1828
+ L above_1829
+ L above_1830
+ L above_1831
+ L above_1832
+ L above_1833
+ L above_1834
+ L above_1835
+ L above_1836
+ L above_1837
+ L above_1838
+ L above_1839
+ L above_1840
+ L above_1841
+ L above_1842
+ L above_1843
+ L above_1844
+ L above_1845
+ L above_1846
+ L above_1847
+ L above_1848
+ L above_1849
+ L above_1850
+ L above_1851
+ L above_1852
+ L above_1853
+ L above_1854
+ L above_1855
+ L above_1856
+ L above_1857
+ L above_1858
+ L above_1859
+ L above_1860
+ L above_1861
+ L above_1862
+ L above_1863
+ L above_1864
+ L above_1865
+ L above_1866
+ L above_1867
+ L above_1868
+ L above_1869
+ L above_1870
+ L above_1871
+ L above_1872
+ L above_1873
+ L above_1874
+ L above_1875
+ L above_1876
+ L above_1877
+ L above_1878
+ L above_1879
+ L above_1880
+ L above_1881
+ L above_1882
+ L above_1883
+ L above_1884
+ L above_1885
+ L above_1886
+ L above_1887
+ L above_1888
+ L above_1889
+ L above_1890
+ L above_1891
+ L above_1892
+ L above_1893
+ L above_1894
+ L above_1895
+ L above_1896
+ L above_1897
+ L above_1898
+ L above_1899
+ L above_1900
+ L above_1901
+ L above_1902
+ L above_1903
+ L above_1904
+ L above_1905
+ L above_1906
+ L above_1907
+ L above_1908
+ L above_1909
+ L above_1910
+ L above_1911
+ L above_1912
+ L above_1913
+ L above_1914
+ L above_1915
+ L above_1916
+ L above_1917
+ L above_1918
+ L above_1919
+ L above_1920
+ L above_1921
+ L above_1922
+ L above_1923
+ L above_1924
+ L above_1925
+ L above_1926
+ L above_1927
+ L above_1928
+ L above_1929
+ L above_1930
+ L above_1931
+ L above_1932
+ L above_1933
+ L above_1934
+ L above_1935
+ L above_1936
+ L above_1937
+ L above_1938
+ L above_1939
+ L above_1940
+ L above_1941
+ L above_1942
+ L above_1943
+ L above_1944
+ L above_1945
+ L above_1946
+ L above_1947
+ L above_1948
+ L above_1949
+ L above_1950
+ L above_1951
+ L above_1952
+ L above_1953
+ L above_1954
+ L above_1955
+ L above_1956
+ L above_1957
+ L above_1958
+ L above_1959
+ L above_1960
+ L above_1961
+ L above_1962
+ L above_1963
+ L above_1964
+ L above_1965
+ L above_1966
+ L above_1967
+ L above_1968
+ L above_1969
+ L above_1970
+ L above_1971
+ L above_1972
+ L above_1973
+ L above_1974
+ L above_1975
+ L above_1976
+ L above_1977
+ L above_1978
+ L above_1979
+ L above_1980
+ L above_1981
+ L above_1982
+ L above_1983
+ L above_1984
+ L above_1985
+ L above_1986
+ L above_1987
+ L above_1988
+ L above_1989
+ L above_1990
+ L above_1991
+ L above_1992
+ L above_1993
+ L above_1994
+ L above_1995
+ L above_1996
+ L above_1997
+ L above_1998
+ L above_1999
+ L above_2000
+ L below_1800
+ L below_1801
+ L below_1802
+ L below_1803
+ L below_1804
+ L below_1805
+ L below_1806
+ L below_1807
+ L below_1808
+ L below_1809
+ L below_1810
+ L below_1811
+ L below_1812
+ L below_1813
+ L below_1814
+ L below_1815
+ L below_1816
+ L below_1817
+ L below_1818
+ L below_1819
+ L below_1820
+ L below_1821
+ L below_1822
+ L below_1823
+ L below_1824
+ L below_1825
+ L below_1826
+ L below_1827
+ L below_1828
+ L below_1829
+ L below_1830
+ L below_1831
+ L below_1832
+ L below_1833
+ L below_1834
+ L below_1835
+ L below_1836
+ L below_1837
+ L below_1838
+ L below_1839
+ L below_1840
+ L below_1841
+ L below_1842
+ L below_1843
+ L below_1844
+ L below_1845
+ L below_1846
+ L below_1847
+ L below_1848
+ L below_1849
+ L below_1850
+ L below_1851
+ L below_1852
+ L below_1853
+ L below_1854
+ L below_1855
+ L below_1856
+ L below_1857
+ L below_1858
+ L below_1859
+ L below_1860
+ L below_1861
+ L below_1862
+ L below_1863
+ L below_1864
+ L below_1865
+ L below_1866
+ L below_1867
+ L below_1868
+ L below_1869
+ L below_1870
+ L below_1871
+ L below_1872
+ L below_1873
+ L below_1874
+ L below_1875
+ L below_1876
+ L below_1877
+ L below_1878
+ L below_1879
+ L below_1880
+ L below_1881
+ L below_1882
+ L below_1883
+ L below_1884
+ L below_1885
+ L below_1886
+ L below_1887
+ L below_1888
+ L below_1889
+ L below_1890
+ L below_1891
+ L below_1892
+ L below_1893
+ L below_1894
+ L below_1895
+ L below_1896
+ L below_1897
+ L below_1898
+ L below_1899
+ L below_1900
+ L below_1901
+ L below_1902
+ L below_1903
+ L below_1904
+ L below_1905
+ L below_1906
+ L below_1907
+ L below_1908
+ L below_1909
+ L below_1910
+ L below_1911
+ L below_1912
+ L below_1913
+ L below_1914
+ L below_1915
+ L below_1916
+ L below_1917
+ L below_1918
+ L below_1919
+ L below_1920
+ L below_1921
+ L below_1922
+ L below_1923
+ L below_1924
+ L below_1925
+ L below_1926
+ L below_1927
+ L below_1928
+ L below_1929
+ L below_1930
+ L below_1931
+ L below_1932
+ L below_1933
+ L below_1934
+ L below_1935
+ L below_1936
+ L below_1937
+ L below_1938
+ L below_1939
+ L below_1940
+ L below_1941
+ L below_1942
+ L below_1943
+ L below_1944
+ L below_1945
+ L below_1946
+ L below_1947
+ L below_1948
+ L below_1949
+ L below_1950
+ L below_1951
+ L below_1952
+ L below_1953
+ L below_1954
+ L below_1955
+ L below_1956
+ L below_1957
+ L below_1958
+ L below_1959
+ L below_1960
+ L below_1961
+ L below_1962
+ L below_1963
+ L below_1964
+ L below_1965
+ L below_1966
+ L below_1967
+ L below_1968
+ L below_1969
+ L below_1970
+ L below_1971
+ L below_1972
+ L below_1973
+ L below_1974
+ L below_1975
+ L below_1976
+ L below_1977
+ L below_1978
+ L below_1979
+ L below_1980
+ L below_1981
+ L below_1982
+ L below_1983
+ L below_1984
+ L below_1985
+ L below_1986
+ L below_1987
+ L below_1988
+ L below_1989
+ L below_1990
+ L below_1991
+ L below_1992
+ L below_1993
+ L below_1994
+ L below_1995
+ L below_1996
+ L below_1997
+ L below_1998
+ L below_1999
+ L below_2000
+COLUMNS
+    w0 above_1800 255.83084577114428
+    w0 above_1801 489.9800995024876
+    w0 above_1802 770.6268656716418
+    w0 above_1803 1124.318407960199
+    w0 above_1804 1546.3432835820897
+    w0 above_1805 1637.6716417910447
+    w0 above_1806 2122.497512437811
+    w0 above_1807 2276.179104477612
+    w0 above_1808 2777.955223880597
+    w0 above_1809 2990
+    w0 above_1810 3669.2935323383085
+    w0 above_1811 3977.910447761194
+    w0 above_1812 4425.820895522388
+    w0 above_1813 4655.174129353234
+    w0 above_1814 5133.731343283582
+    w0 above_1815 5868.338308457712
+    w0 above_1816 6173.36815920398
+    w0 above_1817 6668.1492537313425
+    w0 above_1818 6672.497512437811
+    w0 above_1819 7223.681592039801
+    w0 above_1820 7346.134328358209
+    w0 above_1821 8762.567164179105
+    w0 above_1822 9152.169154228855
+    w0 above_1823 9977.55223880597
+    w0 above_1824 9808.084577114429
+    w0 above_1825 10960.487562189055
+    w0 above_1826 11355.179104477611
+    w0 above_1827 12075.383084577115
+    w0 above_1828 13337.114427860697
+    w0 above_1829 13493.134328358208
+    w0 above_1830 15272.203980099503
+    w0 above_1831 15125.970149253732
+    w0 above_1832 14694.686567164179
+    w0 above_1833 16248.109452736318
+    w0 above_1834 17734.552238805973
+    w0 above_1835 17810.149253731342
+    w0 above_1836 17877.442786069652
+    w0 above_1837 18690.13930348259
+    w0 above_1838 20794.17910447761
+    w0 above_1839 23327.761194029852
+    w0 above_1840 20403.51741293532
+    w0 above_1841 21735.940298507463
+    w0 above_1842 23657.059701492537
+    w0 above_1843 26306.746268656716
+    w0 above_1844 27548.731343283584
+    w0 above_1845 26282.935323383084
+    w0 above_1846 27096.786069651742
+    w0 above_1847 28212.059701492537
+    w0 above_1848 30766.6368159204
+    w0 above_1849 33013.930348258706
+    w0 above_1850 31979.79104477612
+    w0 above_1851 32338.5671641791
+    w0 above_1852 31519.179104477615
+    w0 above_1853 36614.41791044776
+    w0 above_1854 33788.33333333333
+    w0 above_1855 36001.592039801
+    w0 above_1856 37565.26865671641
+    w0 above_1857 37451.26368159204
+    w0 above_1858 40320.18905472637
+    w0 above_1859 42606.5671641791
+    w0 above_1860 41929.154228855725
+    w0 above_1861 40567.12437810945
+    w0 above_1862 47176.02985074627
+    w0 above_1863 45638.68656716418
+    w0 above_1864 46562.31343283583
+    w0 above_1865 46470.89552238806
+    w0 above_1866 55712
+    w0 above_1867 49953.95024875622
+    w0 above_1868 53671.014925373136
+    w0 above_1869 56057.81094527364
+    w0 above_1870 51808.45273631841
+    w0 above_1871 56977.074626865666
+    w0 above_1872 58398.18407960199
+    w0 above_1873 58130.49751243781
+    w0 above_1874 62371.64179104478
+    w0 above_1875 66992.29850746269
+    w0 above_1876 64582.69651741293
+    w0 above_1877 62730.23880597015
+    w0 above_1878 60442.07462686567
+    w0 above_1879 65568.1592039801
+    w0 above_1880 68710.5671641791
+    w0 above_1881 78838.30845771144
+    w0 above_1882 69411.53731343284
+    w0 above_1883 73521.31343283581
+    w0 above_1884 71922.68656716419
+    w0 above_1885 74716.8855721393
+    w0 above_1886 75100.0447761194
+    w0 above_1887 83233.1144278607
+    w0 above_1888 88932.69651741293
+    w0 above_1889 82894.02985074627
+    w0 above_1890 84843.69154228855
+    w0 above_1891 91503.84079601991
+    w0 above_1892 91602.68656716419
+    w0 above_1893 87136.59701492537
+    w0 above_1894 89572.23880597015
+    w0 above_1895 99593.07462686567
+    w0 above_1896 100157.56716417911
+    w0 above_1897 101888.7960199005
+    w0 above_1898 98398.11940298507
+    w0 above_1899 99019.90049751244
+    w0 above_1900 110979.40298507463
+    w0 above_1901 105588.77611940299
+    w0 above_1902 119365.7263681592
+    w0 above_1903 110052.69651741293
+    w0 above_1904 114755.07462686567
+    w0 above_1905 104224.368159204
+    w0 above_1906 117487.06467661692
+    w0 above_1907 108675.40298507463
+    w0 above_1908 114195.66666666666
+    w0 above_1909 116056.0199004975
+    w0 above_1910 121358.89552238806
+    w0 above_1911 113669.41293532339
+    w0 above_1912 120568.18905472636
+    w0 above_1913 133386.23880597015
+    w0 above_1914 134124.90049751243
+    w0 above_1915 124789.45273631842
+    w0 above_1916 121850.55223880598
+    w0 above_1917 142208.78606965175
+    w0 above_1918 148411.94527363183
+    w0 above_1919 138570.74626865672
+    w0 above_1920 133075.3184079602
+    w0 above_1921 146137.18407960198
+    w0 above_1922 137370.80597014926
+    w0 above_1923 140419.82089552237
+    w0 above_1924 151329.60199004973
+    w0 above_1925 152796.62686567166
+    w0 above_1926 156427.98507462686
+    w0 above_1927 157148.9751243781
+    w0 above_1928 143817.02985074627
+    w0 above_1929 164030.89552238808
+    w0 above_1930 162508.43283582092
+    w0 above_1931 167302.44776119402
+    w0 above_1932 163075.86567164178
+    w0 above_1933 190189.3333333333
+    w0 above_1934 176136.71641791047
+    w0 above_1935 166926.80597014926
+    w0 above_1936 175289.1144278607
+    w0 above_1937 184944.71641791044
+    w0 above_1938 174047.3631840796
+    w0 above_1939 177551.3432835821
+    w0 above_1940 177826.9552238806
+    w0 above_1941 197887.24378109453
+    w0 above_1942 187830.14427860695
+    w0 above_1943 186666.98507462686
+    w0 above_1944 171258.70646766172
+    w0 above_1945 194929.61194029852
+    w0 above_1946 187776.0447761194
+    w0 above_1947 195881.31343283583
+    w0 above_1948 206014.36815920396
+    w0 above_1949 217997.76119402985
+    w0 above_1950 201482.83084577115
+    w0 above_1951 197693.7711442786
+    w0 above_1952 197577.80597014926
+    w0 above_1953 196256.52736318405
+    w0 above_1954 218570.8208955224
+    w0 above_1955 221634.08955223882
+    w0 above_1956 226101.08955223882
+    w0 above_1957 234946.78606965175
+    w0 above_1958 238533.223880597
+    w0 above_1959 243684.776119403
+    w0 above_1960 232443.14925373136
+    w0 above_1961 208211.91044776118
+    w0 above_1962 234625.9303482587
+    w0 above_1963 198590.12935323382
+    w0 above_1964 239023.43283582092
+    w0 above_1965 245329.83084577115
+    w0 above_1966 233758.45771144278
+    w0 above_1967 248927.5223880597
+    w0 above_1968 245828.57711442784
+    w0 above_1969 240653.184079602
+    w0 above_1970 237380.32835820896
+    w0 above_1971 262177.63184079604
+    w0 above_1972 249991.8855721393
+    w0 above_1973 252303.46268656716
+    w0 above_1974 274563.6815920398
+    w0 above_1975 280050.14925373136
+    w0 above_1976 271693.2388059701
+    w0 above_1977 268773.80099502485
+    w0 above_1978 261417.47761194033
+    w0 above_1979 265372.8358208955
+    w0 above_1980 274729.184079602
+    w0 above_1981 253125.78109452737
+    w0 above_1982 270252.7611940298
+    w0 above_1983 292455.6417910448
+    w0 above_1984 263509.9502487562
+    w0 above_1985 300578.77611940296
+    w0 above_1986 308060.6368159204
+    w0 above_1987 297569.39303482586
+    w0 above_1988 261159.44776119405
+    w0 above_1989 285944.32835820894
+    w0 above_1990 279470.05970149254
+    w0 above_1991 285102.80597014923
+    w0 above_1992 307687.12935323385
+    w0 above_1993 324972.19900497515
+    w0 above_1994 308297.9104477612
+    w0 above_1995 312694.10945273633
+    w0 above_1996 341904.7711442786
+    w0 above_1997 287958.98507462686
+    w0 above_1998 305055.1194029851
+    w0 above_1999 323608.9552238806
+    w0 above_2000 298136
+    w0 below_1800 -255.83084577114428
+    w0 below_1801 -489.9800995024876
+    w0 below_1802 -770.6268656716418
+    w0 below_1803 -1124.318407960199
+    w0 below_1804 -1546.3432835820897
+    w0 below_1805 -1637.6716417910447
+    w0 below_1806 -2122.497512437811
+    w0 below_1807 -2276.179104477612
+    w0 below_1808 -2777.955223880597
+    w0 below_1809 -2990
+    w0 below_1810 -3669.2935323383085
+    w0 below_1811 -3977.910447761194
+    w0 below_1812 -4425.820895522388
+    w0 below_1813 -4655.174129353234
+    w0 below_1814 -5133.731343283582
+    w0 below_1815 -5868.338308457712
+    w0 below_1816 -6173.36815920398
+    w0 below_1817 -6668.1492537313425
+    w0 below_1818 -6672.497512437811
+    w0 below_1819 -7223.681592039801
+    w0 below_1820 -7346.134328358209
+    w0 below_1821 -8762.567164179105
+    w0 below_1822 -9152.169154228855
+    w0 below_1823 -9977.55223880597
+    w0 below_1824 -9808.084577114429
+    w0 below_1825 -10960.487562189055
+    w0 below_1826 -11355.179104477611
+    w0 below_1827 -12075.383084577115
+    w0 below_1828 -13337.114427860697
+    w0 below_1829 -13493.134328358208
+    w0 below_1830 -15272.203980099503
+    w0 below_1831 -15125.970149253732
+    w0 below_1832 -14694.686567164179
+    w0 below_1833 -16248.109452736318
+    w0 below_1834 -17734.552238805973
+    w0 below_1835 -17810.149253731342
+    w0 below_1836 -17877.442786069652
+    w0 below_1837 -18690.13930348259
+    w0 below_1838 -20794.17910447761
+    w0 below_1839 -23327.761194029852
+    w0 below_1840 -20403.51741293532
+    w0 below_1841 -21735.940298507463
+    w0 below_1842 -23657.059701492537
+    w0 below_1843 -26306.746268656716
+    w0 below_1844 -27548.731343283584
+    w0 below_1845 -26282.935323383084
+    w0 below_1846 -27096.786069651742
+    w0 below_1847 -28212.059701492537
+    w0 below_1848 -30766.6368159204
+    w0 below_1849 -33013.930348258706
+    w0 below_1850 -31979.79104477612
+    w0 below_1851 -32338.5671641791
+    w0 below_1852 -31519.179104477615
+    w0 below_1853 -36614.41791044776
+    w0 below_1854 -33788.33333333333
+    w0 below_1855 -36001.592039801
+    w0 below_1856 -37565.26865671641
+    w0 below_1857 -37451.26368159204
+    w0 below_1858 -40320.18905472637
+    w0 below_1859 -42606.5671641791
+    w0 below_1860 -41929.154228855725
+    w0 below_1861 -40567.12437810945
+    w0 below_1862 -47176.02985074627
+    w0 below_1863 -45638.68656716418
+    w0 below_1864 -46562.31343283583
+    w0 below_1865 -46470.89552238806
+    w0 below_1866 -55712
+    w0 below_1867 -49953.95024875622
+    w0 below_1868 -53671.014925373136
+    w0 below_1869 -56057.81094527364
+    w0 below_1870 -51808.45273631841
+    w0 below_1871 -56977.074626865666
+    w0 below_1872 -58398.18407960199
+    w0 below_1873 -58130.49751243781
+    w0 below_1874 -62371.64179104478
+    w0 below_1875 -66992.29850746269
+    w0 below_1876 -64582.69651741293
+    w0 below_1877 -62730.23880597015
+    w0 below_1878 -60442.07462686567
+    w0 below_1879 -65568.1592039801
+    w0 below_1880 -68710.5671641791
+    w0 below_1881 -78838.30845771144
+    w0 below_1882 -69411.53731343284
+    w0 below_1883 -73521.31343283581
+    w0 below_1884 -71922.68656716419
+    w0 below_1885 -74716.8855721393
+    w0 below_1886 -75100.0447761194
+    w0 below_1887 -83233.1144278607
+    w0 below_1888 -88932.69651741293
+    w0 below_1889 -82894.02985074627
+    w0 below_1890 -84843.69154228855
+    w0 below_1891 -91503.84079601991
+    w0 below_1892 -91602.68656716419
+    w0 below_1893 -87136.59701492537
+    w0 below_1894 -89572.23880597015
+    w0 below_1895 -99593.07462686567
+    w0 below_1896 -100157.56716417911
+    w0 below_1897 -101888.7960199005
+    w0 below_1898 -98398.11940298507
+    w0 below_1899 -99019.90049751244
+    w0 below_1900 -110979.40298507463
+    w0 below_1901 -105588.77611940299
+    w0 below_1902 -119365.7263681592
+    w0 below_1903 -110052.69651741293
+    w0 below_1904 -114755.07462686567
+    w0 below_1905 -104224.368159204
+    w0 below_1906 -117487.06467661692
+    w0 below_1907 -108675.40298507463
+    w0 below_1908 -114195.66666666666
+    w0 below_1909 -116056.0199004975
+    w0 below_1910 -121358.89552238806
+    w0 below_1911 -113669.41293532339
+    w0 below_1912 -120568.18905472636
+    w0 below_1913 -133386.23880597015
+    w0 below_1914 -134124.90049751243
+    w0 below_1915 -124789.45273631842
+    w0 below_1916 -121850.55223880598
+    w0 below_1917 -142208.78606965175
+    w0 below_1918 -148411.94527363183
+    w0 below_1919 -138570.74626865672
+    w0 below_1920 -133075.3184079602
+    w0 below_1921 -146137.18407960198
+    w0 below_1922 -137370.80597014926
+    w0 below_1923 -140419.82089552237
+    w0 below_1924 -151329.60199004973
+    w0 below_1925 -152796.62686567166
+    w0 below_1926 -156427.98507462686
+    w0 below_1927 -157148.9751243781
+    w0 below_1928 -143817.02985074627
+    w0 below_1929 -164030.89552238808
+    w0 below_1930 -162508.43283582092
+    w0 below_1931 -167302.44776119402
+    w0 below_1932 -163075.86567164178
+    w0 below_1933 -190189.3333333333
+    w0 below_1934 -176136.71641791047
+    w0 below_1935 -166926.80597014926
+    w0 below_1936 -175289.1144278607
+    w0 below_1937 -184944.71641791044
+    w0 below_1938 -174047.3631840796
+    w0 below_1939 -177551.3432835821
+    w0 below_1940 -177826.9552238806
+    w0 below_1941 -197887.24378109453
+    w0 below_1942 -187830.14427860695
+    w0 below_1943 -186666.98507462686
+    w0 below_1944 -171258.70646766172
+    w0 below_1945 -194929.61194029852
+    w0 below_1946 -187776.0447761194
+    w0 below_1947 -195881.31343283583
+    w0 below_1948 -206014.36815920396
+    w0 below_1949 -217997.76119402985
+    w0 below_1950 -201482.83084577115
+    w0 below_1951 -197693.7711442786
+    w0 below_1952 -197577.80597014926
+    w0 below_1953 -196256.52736318405
+    w0 below_1954 -218570.8208955224
+    w0 below_1955 -221634.08955223882
+    w0 below_1956 -226101.08955223882
+    w0 below_1957 -234946.78606965175
+    w0 below_1958 -238533.223880597
+    w0 below_1959 -243684.776119403
+    w0 below_1960 -232443.14925373136
+    w0 below_1961 -208211.91044776118
+    w0 below_1962 -234625.9303482587
+    w0 below_1963 -198590.12935323382
+    w0 below_1964 -239023.43283582092
+    w0 below_1965 -245329.83084577115
+    w0 below_1966 -233758.45771144278
+    w0 below_1967 -248927.5223880597
+    w0 below_1968 -245828.57711442784
+    w0 below_1969 -240653.184079602
+    w0 below_1970 -237380.32835820896
+    w0 below_1971 -262177.63184079604
+    w0 below_1972 -249991.8855721393
+    w0 below_1973 -252303.46268656716
+    w0 below_1974 -274563.6815920398
+    w0 below_1975 -280050.14925373136
+    w0 below_1976 -271693.2388059701
+    w0 below_1977 -268773.80099502485
+    w0 below_1978 -261417.47761194033
+    w0 below_1979 -265372.8358208955
+    w0 below_1980 -274729.184079602
+    w0 below_1981 -253125.78109452737
+    w0 below_1982 -270252.7611940298
+    w0 below_1983 -292455.6417910448
+    w0 below_1984 -263509.9502487562
+    w0 below_1985 -300578.77611940296
+    w0 below_1986 -308060.6368159204
+    w0 below_1987 -297569.39303482586
+    w0 below_1988 -261159.44776119405
+    w0 below_1989 -285944.32835820894
+    w0 below_1990 -279470.05970149254
+    w0 below_1991 -285102.80597014923
+    w0 below_1992 -307687.12935323385
+    w0 below_1993 -324972.19900497515
+    w0 below_1994 -308297.9104477612
+    w0 below_1995 -312694.10945273633
+    w0 below_1996 -341904.7711442786
+    w0 below_1997 -287958.98507462686
+    w0 below_1998 -305055.1194029851
+    w0 below_1999 -323608.9552238806
+    w0 below_2000 -298136
+    w4 above_1800 39.38805970149254
+    w4 above_1801 90.19900497512438
+    w4 above_1802 139.955223880597
+    w4 above_1803 190.06965174129354
+    w4 above_1804 250.07462686567166
+    w4 above_1805 311.64179104477614
+    w4 above_1806 360.76119402985074
+    w4 above_1807 401.7512437810945
+    w4 above_1808 463.1194029850746
+    w4 above_1809 471.0945273631841
+    w4 above_1810 594.4925373134329
+    w4 above_1811 650.9253731343283
+    w4 above_1812 804.7711442786069
+    w4 above_1813 798.6965174129353
+    w4 above_1814 970.820895522388
+    w4 above_1815 1092.9353233830846
+    w4 above_1816 1098.233830845771
+    w4 above_1817 1136.5074626865671
+    w4 above_1818 1385.3930348258707
+    w4 above_1819 1408.5572139303483
+    w4 above_1820 1393.5223880597014
+    w4 above_1821 1552.8059701492537
+    w4 above_1822 1870.0945273631842
+    w4 above_1823 1922.2686567164178
+    w4 above_1824 2068.905472636816
+    w4 above_1825 2337.0248756218903
+    w4 above_1826 2153.686567164179
+    w4 above_1827 2134.129353233831
+    w4 above_1828 2505.5422885572143
+    w4 above_1829 2772.3880597014922
+    w4 above_1830 2773.6517412935323
+    w4 above_1831 3099.542288557214
+    w4 above_1832 2830.283582089552
+    w4 above_1833 3339.273631840796
+    w4 above_1834 3107.3383084577117
+    w4 above_1835 3588.7164179104475
+    w4 above_1836 4005.2039800995026
+    w4 above_1837 3790.9253731343283
+    w4 above_1838 4353.835820895522
+    w4 above_1839 4295.124378109453
+    w4 above_1840 4456.76119402985
+    w4 above_1841 4543.940298507462
+    w4 above_1842 5090.47263681592
+    w4 above_1843 4480.119402985075
+    w4 above_1844 5378.507462686567
+    w4 above_1845 5426.169154228856
+    w4 above_1846 5380.213930348258
+    w4 above_1847 5578.985074626866
+    w4 above_1848 6410.467661691542
+    w4 above_1849 6641.542288557214
+    w4 above_1850 6597.268656716418
+    w4 above_1851 6051.661691542288
+    w4 above_1852 7022.89552238806
+    w4 above_1853 7025.910447761194
+    w4 above_1854 7637.885572139303
+    w4 above_1855 7346.865671641791
+    w4 above_1856 8182.76119402985
+    w4 above_1857 7939.363184079602
+    w4 above_1858 8641.592039800995
+    w4 above_1859 8606.86567164179
+    w4 above_1860 9547.865671641792
+    w4 above_1861 9333.004975124377
+    w4 above_1862 9491.059701492537
+    w4 above_1863 9521.034825870647
+    w4 above_1864 10119.626865671642
+    w4 above_1865 10812.835820895523
+    w4 above_1866 11316
+    w4 above_1867 11068.099502487563
+    w4 above_1868 12125.805970149253
+    w4 above_1869 11048.1592039801
+    w4 above_1870 11822.029850746268
+    w4 above_1871 11919.402985074626
+    w4 above_1872 12574.885572139305
+    w4 above_1873 12716.218905472637
+    w4 above_1874 12992.537313432837
+    w4 above_1875 15000.73631840796
+    w4 above_1876 13517.5223880597
+    w4 above_1877 13833.55223880597
+    w4 above_1878 13851.333333333332
+    w4 above_1879 15325.37313432836
+    w4 above_1880 15644.686567164179
+    w4 above_1881 16200.099502487561
+    w4 above_1882 15956.233830845771
+    w4 above_1883 17566.86567164179
+    w4 above_1884 17266.8407960199
+    w4 above_1885 17163.63184079602
+    w4 above_1886 16332.62686567164
+    w4 above_1887 17333.373134328358
+    w4 above_1888 18788.741293532337
+    w4 above_1889 18406.567164179105
+    w4 above_1890 21255.51741293532
+    w4 above_1891 20093.532338308458
+    w4 above_1892 20891.223880597016
+    w4 above_1893 20873.144278606964
+    w4 above_1894 22117.512437810947
+    w4 above_1895 20258.86567164179
+    w4 above_1896 22703.79104477612
+    w4 above_1897 22780.855721393036
+    w4 above_1898 20925.940298507463
+    w4 above_1899 23878.60696517413
+    w4 above_1900 23471.696517412936
+    w4 above_1901 23539.16417910448
+    w4 above_1902 22486.7960199005
+    w4 above_1903 24820.81592039801
+    w4 above_1904 24216.86567164179
+    w4 above_1905 24584.08955223881
+    w4 above_1906 25719.393034825873
+    w4 above_1907 26115.582089552237
+    w4 above_1908 26867.144278606964
+    w4 above_1909 27263.034825870644
+    w4 above_1910 28597.686567164183
+    w4 above_1911 25620.696517412936
+    w4 above_1912 27834.542288557215
+    w4 above_1913 29647.373134328358
+    w4 above_1914 30336.54228855721
+    w4 above_1915 28433.850746268658
+    w4 above_1916 28410.044776119405
+    w4 above_1917 29697.253731343284
+    w4 above_1918 31211.746268656716
+    w4 above_1919 30163.582089552237
+    w4 above_1920 30104.920398009952
+    w4 above_1921 32420.437810945274
+    w4 above_1922 34315.164179104475
+    w4 above_1923 33035.82089552239
+    w4 above_1924 32138.059701492533
+    w4 above_1925 35483.73134328359
+    w4 above_1926 34976.17910447761
+    w4 above_1927 38660.45771144279
+    w4 above_1928 37746.298507462685
+    w4 above_1929 36860.49751243781
+    w4 above_1930 33548.383084577115
+    w4 above_1931 37118.92537313433
+    w4 above_1932 37449.09452736318
+    w4 above_1933 36878
+    w4 above_1934 40727.68656716418
+    w4 above_1935 39720.796019900496
+    w4 above_1936 38334.78109452737
+    w4 above_1937 42889.850746268654
+    w4 above_1938 37713.25870646766
+    w4 above_1939 42515.422885572145
+    w4 above_1940 44142.82089552239
+    w4 above_1941 42059.55223880597
+    w4 above_1942 42776.92039800995
+    w4 above_1943 47017.79104477612
+    w4 above_1944 47075.22388059702
+    w4 above_1945 44222.019900497515
+    w4 above_1946 43183.62686567164
+    w4 above_1947 45892.517412935325
+    w4 above_1948 44013.562189054726
+    w4 above_1949 49919.40298507463
+    w4 above_1950 43734.40796019901
+    w4 above_1951 52238.845771144275
+    w4 above_1952 46016.46268656716
+    w4 above_1953 43179.761194029845
+    w4 above_1954 42823.18407960199
+    w4 above_1955 46946.68656716418
+    w4 above_1956 45311.293532338306
+    w4 above_1957 48439.18407960199
+    w4 above_1958 49519.40298507462
+    w4 above_1959 50951.64179104478
+    w4 above_1960 54630.26368159204
+    w4 above_1961 52301.014925373136
+    w4 above_1962 53332.62686567164
+    w4 above_1963 52119.3631840796
+    w4 above_1964 55183.05970149254
+    w4 above_1965 56971.86069651741
+    w4 above_1966 56627.12437810945
+    w4 above_1967 52664.23880597015
+    w4 above_1968 61241.90049751243
+    w4 above_1969 52111.34328358209
+    w4 above_1970 58136.59701492538
+    w4 above_1971 55530.32835820896
+    w4 above_1972 60864.15422885572
+    w4 above_1973 59140.0895522388
+    w4 above_1974 60664.925373134334
+    w4 above_1975 58528.3184079602
+    w4 above_1976 64435.92537313433
+    w4 above_1977 60285.32338308458
+    w4 above_1978 65107.019900497515
+    w4 above_1979 58237.611940298506
+    w4 above_1980 65106.87064676617
+    w4 above_1981 60226.60696517413
+    w4 above_1982 64770.164179104475
+    w4 above_1983 58821.41293532339
+    w4 above_1984 59433.78109452737
+    w4 above_1985 60885.850746268654
+    w4 above_1986 62492.42288557214
+    w4 above_1987 62947.263681592034
+    w4 above_1988 65498.373134328365
+    w4 above_1989 65298.55721393035
+    w4 above_1990 65925.40796019901
+    w4 above_1991 62955.94029850746
+    w4 above_1992 67729.55721393034
+    w4 above_1993 63853.02487562189
+    w4 above_1994 68116.11940298507
+    w4 above_1995 70172.87562189055
+    w4 above_1996 66363.51741293533
+    w4 above_1997 65620.74626865672
+    w4 above_1998 76883.30348258707
+    w4 above_1999 68926.36815920398
+    w4 above_2000 74697
+    w4 below_1800 -39.38805970149254
+    w4 below_1801 -90.19900497512438
+    w4 below_1802 -139.955223880597
+    w4 below_1803 -190.06965174129354
+    w4 below_1804 -250.07462686567166
+    w4 below_1805 -311.64179104477614
+    w4 below_1806 -360.76119402985074
+    w4 below_1807 -401.7512437810945
+    w4 below_1808 -463.1194029850746
+    w4 below_1809 -471.0945273631841
+    w4 below_1810 -594.4925373134329
+    w4 below_1811 -650.9253731343283
+    w4 below_1812 -804.7711442786069
+    w4 below_1813 -798.6965174129353
+    w4 below_1814 -970.820895522388
+    w4 below_1815 -1092.9353233830846
+    w4 below_1816 -1098.233830845771
+    w4 below_1817 -1136.5074626865671
+    w4 below_1818 -1385.3930348258707
+    w4 below_1819 -1408.5572139303483
+    w4 below_1820 -1393.5223880597014
+    w4 below_1821 -1552.8059701492537
+    w4 below_1822 -1870.0945273631842
+    w4 below_1823 -1922.2686567164178
+    w4 below_1824 -2068.905472636816
+    w4 below_1825 -2337.0248756218903
+    w4 below_1826 -2153.686567164179
+    w4 below_1827 -2134.129353233831
+    w4 below_1828 -2505.5422885572143
+    w4 below_1829 -2772.3880597014922
+    w4 below_1830 -2773.6517412935323
+    w4 below_1831 -3099.542288557214
+    w4 below_1832 -2830.283582089552
+    w4 below_1833 -3339.273631840796
+    w4 below_1834 -3107.3383084577117
+    w4 below_1835 -3588.7164179104475
+    w4 below_1836 -4005.2039800995026
+    w4 below_1837 -3790.9253731343283
+    w4 below_1838 -4353.835820895522
+    w4 below_1839 -4295.124378109453
+    w4 below_1840 -4456.76119402985
+    w4 below_1841 -4543.940298507462
+    w4 below_1842 -5090.47263681592
+    w4 below_1843 -4480.119402985075
+    w4 below_1844 -5378.507462686567
+    w4 below_1845 -5426.169154228856
+    w4 below_1846 -5380.213930348258
+    w4 below_1847 -5578.985074626866
+    w4 below_1848 -6410.467661691542
+    w4 below_1849 -6641.542288557214
+    w4 below_1850 -6597.268656716418
+    w4 below_1851 -6051.661691542288
+    w4 below_1852 -7022.89552238806
+    w4 below_1853 -7025.910447761194
+    w4 below_1854 -7637.885572139303
+    w4 below_1855 -7346.865671641791
+    w4 below_1856 -8182.76119402985
+    w4 below_1857 -7939.363184079602
+    w4 below_1858 -8641.592039800995
+    w4 below_1859 -8606.86567164179
+    w4 below_1860 -9547.865671641792
+    w4 below_1861 -9333.004975124377
+    w4 below_1862 -9491.059701492537
+    w4 below_1863 -9521.034825870647
+    w4 below_1864 -10119.626865671642
+    w4 below_1865 -10812.835820895523
+    w4 below_1866 -11316
+    w4 below_1867 -11068.099502487563
+    w4 below_1868 -12125.805970149253
+    w4 below_1869 -11048.1592039801
+    w4 below_1870 -11822.029850746268
+    w4 below_1871 -11919.402985074626
+    w4 below_1872 -12574.885572139305
+    w4 below_1873 -12716.218905472637
+    w4 below_1874 -12992.537313432837
+    w4 below_1875 -15000.73631840796
+    w4 below_1876 -13517.5223880597
+    w4 below_1877 -13833.55223880597
+    w4 below_1878 -13851.333333333332
+    w4 below_1879 -15325.37313432836
+    w4 below_1880 -15644.686567164179
+    w4 below_1881 -16200.099502487561
+    w4 below_1882 -15956.233830845771
+    w4 below_1883 -17566.86567164179
+    w4 below_1884 -17266.8407960199
+    w4 below_1885 -17163.63184079602
+    w4 below_1886 -16332.62686567164
+    w4 below_1887 -17333.373134328358
+    w4 below_1888 -18788.741293532337
+    w4 below_1889 -18406.567164179105
+    w4 below_1890 -21255.51741293532
+    w4 below_1891 -20093.532338308458
+    w4 below_1892 -20891.223880597016
+    w4 below_1893 -20873.144278606964
+    w4 below_1894 -22117.512437810947
+    w4 below_1895 -20258.86567164179
+    w4 below_1896 -22703.79104477612
+    w4 below_1897 -22780.855721393036
+    w4 below_1898 -20925.940298507463
+    w4 below_1899 -23878.60696517413
+    w4 below_1900 -23471.696517412936
+    w4 below_1901 -23539.16417910448
+    w4 below_1902 -22486.7960199005
+    w4 below_1903 -24820.81592039801
+    w4 below_1904 -24216.86567164179
+    w4 below_1905 -24584.08955223881
+    w4 below_1906 -25719.393034825873
+    w4 below_1907 -26115.582089552237
+    w4 below_1908 -26867.144278606964
+    w4 below_1909 -27263.034825870644
+    w4 below_1910 -28597.686567164183
+    w4 below_1911 -25620.696517412936
+    w4 below_1912 -27834.542288557215
+    w4 below_1913 -29647.373134328358
+    w4 below_1914 -30336.54228855721
+    w4 below_1915 -28433.850746268658
+    w4 below_1916 -28410.044776119405
+    w4 below_1917 -29697.253731343284
+    w4 below_1918 -31211.746268656716
+    w4 below_1919 -30163.582089552237
+    w4 below_1920 -30104.920398009952
+    w4 below_1921 -32420.437810945274
+    w4 below_1922 -34315.164179104475
+    w4 below_1923 -33035.82089552239
+    w4 below_1924 -32138.059701492533
+    w4 below_1925 -35483.73134328359
+    w4 below_1926 -34976.17910447761
+    w4 below_1927 -38660.45771144279
+    w4 below_1928 -37746.298507462685
+    w4 below_1929 -36860.49751243781
+    w4 below_1930 -33548.383084577115
+    w4 below_1931 -37118.92537313433
+    w4 below_1932 -37449.09452736318
+    w4 below_1933 -36878
+    w4 below_1934 -40727.68656716418
+    w4 below_1935 -39720.796019900496
+    w4 below_1936 -38334.78109452737
+    w4 below_1937 -42889.850746268654
+    w4 below_1938 -37713.25870646766
+    w4 below_1939 -42515.422885572145
+    w4 below_1940 -44142.82089552239
+    w4 below_1941 -42059.55223880597
+    w4 below_1942 -42776.92039800995
+    w4 below_1943 -47017.79104477612
+    w4 below_1944 -47075.22388059702
+    w4 below_1945 -44222.019900497515
+    w4 below_1946 -43183.62686567164
+    w4 below_1947 -45892.517412935325
+    w4 below_1948 -44013.562189054726
+    w4 below_1949 -49919.40298507463
+    w4 below_1950 -43734.40796019901
+    w4 below_1951 -52238.845771144275
+    w4 below_1952 -46016.46268656716
+    w4 below_1953 -43179.761194029845
+    w4 below_1954 -42823.18407960199
+    w4 below_1955 -46946.68656716418
+    w4 below_1956 -45311.293532338306
+    w4 below_1957 -48439.18407960199
+    w4 below_1958 -49519.40298507462
+    w4 below_1959 -50951.64179104478
+    w4 below_1960 -54630.26368159204
+    w4 below_1961 -52301.014925373136
+    w4 below_1962 -53332.62686567164
+    w4 below_1963 -52119.3631840796
+    w4 below_1964 -55183.05970149254
+    w4 below_1965 -56971.86069651741
+    w4 below_1966 -56627.12437810945
+    w4 below_1967 -52664.23880597015
+    w4 below_1968 -61241.90049751243
+    w4 below_1969 -52111.34328358209
+    w4 below_1970 -58136.59701492538
+    w4 below_1971 -55530.32835820896
+    w4 below_1972 -60864.15422885572
+    w4 below_1973 -59140.0895522388
+    w4 below_1974 -60664.925373134334
+    w4 below_1975 -58528.3184079602
+    w4 below_1976 -64435.92537313433
+    w4 below_1977 -60285.32338308458
+    w4 below_1978 -65107.019900497515
+    w4 below_1979 -58237.611940298506
+    w4 below_1980 -65106.87064676617
+    w4 below_1981 -60226.60696517413
+    w4 below_1982 -64770.164179104475
+    w4 below_1983 -58821.41293532339
+    w4 below_1984 -59433.78109452737
+    w4 below_1985 -60885.850746268654
+    w4 below_1986 -62492.42288557214
+    w4 below_1987 -62947.263681592034
+    w4 below_1988 -65498.373134328365
+    w4 below_1989 -65298.55721393035
+    w4 below_1990 -65925.40796019901
+    w4 below_1991 -62955.94029850746
+    w4 below_1992 -67729.55721393034
+    w4 below_1993 -63853.02487562189
+    w4 below_1994 -68116.11940298507
+    w4 below_1995 -70172.87562189055
+    w4 below_1996 -66363.51741293533
+    w4 below_1997 -65620.74626865672
+    w4 below_1998 -76883.30348258707
+    w4 below_1999 -68926.36815920398
+    w4 below_2000 -74697
+    w8 above_1800 24.18905472636816
+    w8 above_1801 45.960199004975124
+    w8 above_1802 69.1044776119403
+    w8 above_1803 102.5273631840796
+    w8 above_1804 120.19900497512438
+    w8 above_1805 144.11940298507463
+    w8 above_1806 190.53233830845772
+    w8 above_1807 219.6218905472637
+    w8 above_1808 254.77611940298505
+    w8 above_1809 296.9651741293533
+    w8 above_1810 344.33830845771143
+    w8 above_1811 319.1044776119403
+    w8 above_1812 403.97014925373134
+    w8 above_1813 442.1492537313433
+    w8 above_1814 457.0149253731343
+    w8 above_1815 493.0547263681592
+    w8 above_1816 578.8457711442786
+    w8 above_1817 567.044776119403
+    w8 above_1818 703.7562189054727
+    w8 above_1819 801.6915422885572
+    w8 above_1820 723.0895522388059
+    w8 above_1821 826.3681592039801
+    w8 above_1822 934.5323383084577
+    w8 above_1823 945.3134328358209
+    w8 above_1824 937.6865671641791
+    w8 above_1825 965.4925373134328
+    w8 above_1826 1081.4776119402984
+    w8 above_1827 1225.1741293532339
+    w8 above_1828 1264.7462686567164
+    w8 above_1829 1313.283582089552
+    w8 above_1830 1381.8905472636816
+    w8 above_1831 1557.4925373134329
+    w8 above_1832 1629.8059701492537
+    w8 above_1833 1537.273631840796
+    w8 above_1834 1730.671641791045
+    w8 above_1835 1832.9552238805968
+    w8 above_1836 1965.6019900497513
+    w8 above_1837 2085.273631840796
+    w8 above_1838 2220.8656716417913
+    w8 above_1839 2200.7960199004974
+    w8 above_1840 2182.791044776119
+    w8 above_1841 2116.2985074626863
+    w8 above_1842 2458.273631840796
+    w8 above_1843 2415.621890547264
+    w8 above_1844 2428.6567164179105
+    w8 above_1845 2813.5522388059703
+    w8 above_1846 2604.1741293532336
+    w8 above_1847 3009.194029850746
+    w8 above_1848 3076.7611940298507
+    w8 above_1849 2929.353233830846
+    w8 above_1850 3463.6865671641795
+    w8 above_1851 3641.0348258706467
+    w8 above_1852 3410.1940298507466
+    w8 above_1853 3892.8358208955224
+    w8 above_1854 3929.0796019900495
+    w8 above_1855 3668.6965174129355
+    w8 above_1856 3817.582089552239
+    w8 above_1857 4536.985074626866
+    w8 above_1858 4565.014925373134
+    w8 above_1859 3977.3134328358205
+    w8 above_1860 4237.52736318408
+    w8 above_1861 4900.159203980099
+    w8 above_1862 4689.268656716418
+    w8 above_1863 4883.741293532338
+    w8 above_1864 4642.810945273633
+    w8 above_1865 5261.2835820895525
+    w8 above_1866 5253
+    w8 above_1867 5043.502487562189
+    w8 above_1868 5437.268656716417
+    w8 above_1869 6087.213930348259
+    w8 above_1870 6120.12935323383
+    w8 above_1871 5585.194029850746
+    w8 above_1872 6072.074626865672
+    w8 above_1873 6287.422885572139
+    w8 above_1874 6497.761194029851
+    w8 above_1875 6297.79104477612
+    w8 above_1876 6967.542288557213
+    w8 above_1877 6698.686567164179
+    w8 above_1878 7139.084577114428
+    w8 above_1879 6514.626865671642
+    w8 above_1880 7973.059701492537
+    w8 above_1881 7613.353233830845
+    w8 above_1882 7963.457711442787
+    w8 above_1883 7814.0895522388055
+    w8 above_1884 8059.353233830846
+    w8 above_1885 7842.258706467662
+    w8 above_1886 7730.014925373134
+    w8 above_1887 8898.069651741294
+    w8 above_1888 8531.60199004975
+    w8 above_1889 8999.55223880597
+    w8 above_1890 8982.741293532337
+    w8 above_1891 8784.39800995025
+    w8 above_1892 9460.089552238805
+    w8 above_1893 9276.537313432835
+    w8 above_1894 9745.298507462687
+    w8 above_1895 8988.65671641791
+    w8 above_1896 10392.995024875623
+    w8 above_1897 10889.701492537313
+    w8 above_1898 9974.373134328358
+    w8 above_1899 9979.601990049752
+    w8 above_1900 10863.781094527365
+    w8 above_1901 10773.432835820897
+    w8 above_1902 11324.875621890547
+    w8 above_1903 12164.895522388058
+    w8 above_1904 12311.641791044774
+    w8 above_1905 11951.63184079602
+    w8 above_1906 12930.49751243781
+    w8 above_1907 11798.328358208955
+    w8 above_1908 13064.273631840795
+    w8 above_1909 12979.999999999998
+    w8 above_1910 12633.567164179105
+    w8 above_1911 12861.05472636816
+    w8 above_1912 13698.860696517413
+    w8 above_1913 13328.358208955224
+    w8 above_1914 13818.308457711442
+    w8 above_1915 12992.577114427862
+    w8 above_1916 14041.746268656716
+    w8 above_1917 14243.363184079602
+    w8 above_1918 14961.437810945274
+    w8 above_1919 14653.134328358208
+    w8 above_1920 15823.910447761195
+    w8 above_1921 16811.114427860695
+    w8 above_1922 15048.835820895521
+    w8 above_1923 16894.22885572139
+    w8 above_1924 15816.542288557213
+    w8 above_1925 15709.880597014926
+    w8 above_1926 16842.34825870647
+    w8 above_1927 17955.66169154229
+    w8 above_1928 18219.805970149253
+    w8 above_1929 17437.462686567167
+    w8 above_1930 17681.74129353234
+    w8 above_1931 18675.044776119405
+    w8 above_1932 17545.412935323384
+    w8 above_1933 18676
+    w8 above_1934 18871.119402985078
+    w8 above_1935 20379.701492537315
+    w8 above_1936 19935.885572139305
+    w8 above_1937 17682.537313432837
+    w8 above_1938 18586.582089552237
+    w8 above_1939 18760
+    w8 above_1940 19483.253731343284
+    w8 above_1941 20399.960199004974
+    w8 above_1942 21025.268656716416
+    w8 above_1943 20367.0447761194
+    w8 above_1944 20368.532338308458
+    w8 above_1945 21984.258706467663
+    w8 above_1946 21353.02985074627
+    w8 above_1947 24357.412935323384
+    w8 above_1948 20682.830845771143
+    w8 above_1949 21914.179104477615
+    w8 above_1950 21518.626865671642
+    w8 above_1951 20646.28855721393
+    w8 above_1952 24725.86567164179
+    w8 above_1953 23884.557213930348
+    w8 above_1954 24166.119402985078
+    w8 above_1955 24179.223880597016
+    w8 above_1956 26421.303482587064
+    w8 above_1957 25874.268656716416
+    w8 above_1958 23574.716417910447
+    w8 above_1959 22997.81094527363
+    w8 above_1960 24299.786069651742
+    w8 above_1961 25450.119402985074
+    w8 above_1962 25890.238805970148
+    w8 above_1963 24884.75621890547
+    w8 above_1964 27160.97014925373
+    w8 above_1965 26956.417910447763
+    w8 above_1966 26628.60696517413
+    w8 above_1967 26912.597014925374
+    w8 above_1968 29094.905472636816
+    w8 above_1969 28996.417910447763
+    w8 above_1970 26263.388059701494
+    w8 above_1971 31113.174129353232
+    w8 above_1972 25912.990049751243
+    w8 above_1973 28200.985074626864
+    w8 above_1974 30218.407960199005
+    w8 above_1975 28531.26368159204
+    w8 above_1976 28448.567164179105
+    w8 above_1977 32612.079601990048
+    w8 above_1978 32561.07960199005
+    w8 above_1979 32404.4776119403
+    w8 above_1980 31234.65671641791
+    w8 above_1981 31777.562189054726
+    w8 above_1982 32189.791044776117
+    w8 above_1983 31746.865671641794
+    w8 above_1984 38149.57711442786
+    w8 above_1985 31606.119402985074
+    w8 above_1986 33291.58208955224
+    w8 above_1987 33388.23880597015
+    w8 above_1988 34905.76119402985
+    w8 above_1989 38050.09950248757
+    w8 above_1990 35902.298507462685
+    w8 above_1991 37337.79104477612
+    w8 above_1992 31845.960199004974
+    w8 above_1993 37601.253731343284
+    w8 above_1994 39558.80597014925
+    w8 above_1995 39226.32835820896
+    w8 above_1996 39775.37810945274
+    w8 above_1997 38296.746268656716
+    w8 above_1998 40743.517412935325
+    w8 above_1999 38139.30348258706
+    w8 above_2000 40094
+    w8 below_1800 -24.18905472636816
+    w8 below_1801 -45.960199004975124
+    w8 below_1802 -69.1044776119403
+    w8 below_1803 -102.5273631840796
+    w8 below_1804 -120.19900497512438
+    w8 below_1805 -144.11940298507463
+    w8 below_1806 -190.53233830845772
+    w8 below_1807 -219.6218905472637
+    w8 below_1808 -254.77611940298505
+    w8 below_1809 -296.9651741293533
+    w8 below_1810 -344.33830845771143
+    w8 below_1811 -319.1044776119403
+    w8 below_1812 -403.97014925373134
+    w8 below_1813 -442.1492537313433
+    w8 below_1814 -457.0149253731343
+    w8 below_1815 -493.0547263681592
+    w8 below_1816 -578.8457711442786
+    w8 below_1817 -567.044776119403
+    w8 below_1818 -703.7562189054727
+    w8 below_1819 -801.6915422885572
+    w8 below_1820 -723.0895522388059
+    w8 below_1821 -826.3681592039801
+    w8 below_1822 -934.5323383084577
+    w8 below_1823 -945.3134328358209
+    w8 below_1824 -937.6865671641791
+    w8 below_1825 -965.4925373134328
+    w8 below_1826 -1081.4776119402984
+    w8 below_1827 -1225.1741293532339
+    w8 below_1828 -1264.7462686567164
+    w8 below_1829 -1313.283582089552
+    w8 below_1830 -1381.8905472636816
+    w8 below_1831 -1557.4925373134329
+    w8 below_1832 -1629.8059701492537
+    w8 below_1833 -1537.273631840796
+    w8 below_1834 -1730.671641791045
+    w8 below_1835 -1832.9552238805968
+    w8 below_1836 -1965.6019900497513
+    w8 below_1837 -2085.273631840796
+    w8 below_1838 -2220.8656716417913
+    w8 below_1839 -2200.7960199004974
+    w8 below_1840 -2182.791044776119
+    w8 below_1841 -2116.2985074626863
+    w8 below_1842 -2458.273631840796
+    w8 below_1843 -2415.621890547264
+    w8 below_1844 -2428.6567164179105
+    w8 below_1845 -2813.5522388059703
+    w8 below_1846 -2604.1741293532336
+    w8 below_1847 -3009.194029850746
+    w8 below_1848 -3076.7611940298507
+    w8 below_1849 -2929.353233830846
+    w8 below_1850 -3463.6865671641795
+    w8 below_1851 -3641.0348258706467
+    w8 below_1852 -3410.1940298507466
+    w8 below_1853 -3892.8358208955224
+    w8 below_1854 -3929.0796019900495
+    w8 below_1855 -3668.6965174129355
+    w8 below_1856 -3817.582089552239
+    w8 below_1857 -4536.985074626866
+    w8 below_1858 -4565.014925373134
+    w8 below_1859 -3977.3134328358205
+    w8 below_1860 -4237.52736318408
+    w8 below_1861 -4900.159203980099
+    w8 below_1862 -4689.268656716418
+    w8 below_1863 -4883.741293532338
+    w8 below_1864 -4642.810945273633
+    w8 below_1865 -5261.2835820895525
+    w8 below_1866 -5253
+    w8 below_1867 -5043.502487562189
+    w8 below_1868 -5437.268656716417
+    w8 below_1869 -6087.213930348259
+    w8 below_1870 -6120.12935323383
+    w8 below_1871 -5585.194029850746
+    w8 below_1872 -6072.074626865672
+    w8 below_1873 -6287.422885572139
+    w8 below_1874 -6497.761194029851
+    w8 below_1875 -6297.79104477612
+    w8 below_1876 -6967.542288557213
+    w8 below_1877 -6698.686567164179
+    w8 below_1878 -7139.084577114428
+    w8 below_1879 -6514.626865671642
+    w8 below_1880 -7973.059701492537
+    w8 below_1881 -7613.353233830845
+    w8 below_1882 -7963.457711442787
+    w8 below_1883 -7814.0895522388055
+    w8 below_1884 -8059.353233830846
+    w8 below_1885 -7842.258706467662
+    w8 below_1886 -7730.014925373134
+    w8 below_1887 -8898.069651741294
+    w8 below_1888 -8531.60199004975
+    w8 below_1889 -8999.55223880597
+    w8 below_1890 -8982.741293532337
+    w8 below_1891 -8784.39800995025
+    w8 below_1892 -9460.089552238805
+    w8 below_1893 -9276.537313432835
+    w8 below_1894 -9745.298507462687
+    w8 below_1895 -8988.65671641791
+    w8 below_1896 -10392.995024875623
+    w8 below_1897 -10889.701492537313
+    w8 below_1898 -9974.373134328358
+    w8 below_1899 -9979.601990049752
+    w8 below_1900 -10863.781094527365
+    w8 below_1901 -10773.432835820897
+    w8 below_1902 -11324.875621890547
+    w8 below_1903 -12164.895522388058
+    w8 below_1904 -12311.641791044774
+    w8 below_1905 -11951.63184079602
+    w8 below_1906 -12930.49751243781
+    w8 below_1907 -11798.328358208955
+    w8 below_1908 -13064.273631840795
+    w8 below_1909 -12979.999999999998
+    w8 below_1910 -12633.567164179105
+    w8 below_1911 -12861.05472636816
+    w8 below_1912 -13698.860696517413
+    w8 below_1913 -13328.358208955224
+    w8 below_1914 -13818.308457711442
+    w8 below_1915 -12992.577114427862
+    w8 below_1916 -14041.746268656716
+    w8 below_1917 -14243.363184079602
+    w8 below_1918 -14961.437810945274
+    w8 below_1919 -14653.134328358208
+    w8 below_1920 -15823.910447761195
+    w8 below_1921 -16811.114427860695
+    w8 below_1922 -15048.835820895521
+    w8 below_1923 -16894.22885572139
+    w8 below_1924 -15816.542288557213
+    w8 below_1925 -15709.880597014926
+    w8 below_1926 -16842.34825870647
+    w8 below_1927 -17955.66169154229
+    w8 below_1928 -18219.805970149253
+    w8 below_1929 -17437.462686567167
+    w8 below_1930 -17681.74129353234
+    w8 below_1931 -18675.044776119405
+    w8 below_1932 -17545.412935323384
+    w8 below_1933 -18676
+    w8 below_1934 -18871.119402985078
+    w8 below_1935 -20379.701492537315
+    w8 below_1936 -19935.885572139305
+    w8 below_1937 -17682.537313432837
+    w8 below_1938 -18586.582089552237
+    w8 below_1939 -18760
+    w8 below_1940 -19483.253731343284
+    w8 below_1941 -20399.960199004974
+    w8 below_1942 -21025.268656716416
+    w8 below_1943 -20367.0447761194
+    w8 below_1944 -20368.532338308458
+    w8 below_1945 -21984.258706467663
+    w8 below_1946 -21353.02985074627
+    w8 below_1947 -24357.412935323384
+    w8 below_1948 -20682.830845771143
+    w8 below_1949 -21914.179104477615
+    w8 below_1950 -21518.626865671642
+    w8 below_1951 -20646.28855721393
+    w8 below_1952 -24725.86567164179
+    w8 below_1953 -23884.557213930348
+    w8 below_1954 -24166.119402985078
+    w8 below_1955 -24179.223880597016
+    w8 below_1956 -26421.303482587064
+    w8 below_1957 -25874.268656716416
+    w8 below_1958 -23574.716417910447
+    w8 below_1959 -22997.81094527363
+    w8 below_1960 -24299.786069651742
+    w8 below_1961 -25450.119402985074
+    w8 below_1962 -25890.238805970148
+    w8 below_1963 -24884.75621890547
+    w8 below_1964 -27160.97014925373
+    w8 below_1965 -26956.417910447763
+    w8 below_1966 -26628.60696517413
+    w8 below_1967 -26912.597014925374
+    w8 below_1968 -29094.905472636816
+    w8 below_1969 -28996.417910447763
+    w8 below_1970 -26263.388059701494
+    w8 below_1971 -31113.174129353232
+    w8 below_1972 -25912.990049751243
+    w8 below_1973 -28200.985074626864
+    w8 below_1974 -30218.407960199005
+    w8 below_1975 -28531.26368159204
+    w8 below_1976 -28448.567164179105
+    w8 below_1977 -32612.079601990048
+    w8 below_1978 -32561.07960199005
+    w8 below_1979 -32404.4776119403
+    w8 below_1980 -31234.65671641791
+    w8 below_1981 -31777.562189054726
+    w8 below_1982 -32189.791044776117
+    w8 below_1983 -31746.865671641794
+    w8 below_1984 -38149.57711442786
+    w8 below_1985 -31606.119402985074
+    w8 below_1986 -33291.58208955224
+    w8 below_1987 -33388.23880597015
+    w8 below_1988 -34905.76119402985
+    w8 below_1989 -38050.09950248757
+    w8 below_1990 -35902.298507462685
+    w8 below_1991 -37337.79104477612
+    w8 below_1992 -31845.960199004974
+    w8 below_1993 -37601.253731343284
+    w8 below_1994 -39558.80597014925
+    w8 below_1995 -39226.32835820896
+    w8 below_1996 -39775.37810945274
+    w8 below_1997 -38296.746268656716
+    w8 below_1998 -40743.517412935325
+    w8 below_1999 -38139.30348258706
+    w8 below_2000 -40094
+    __t__ obj 1
+    __t__ above_1800 -1
+    __t__ above_1801 -1
+    __t__ above_1802 -1
+    __t__ above_1803 -1
+    __t__ above_1804 -1
+    __t__ above_1805 -1
+    __t__ above_1806 -1
+    __t__ above_1807 -1
+    __t__ above_1808 -1
+    __t__ above_1809 -1
+    __t__ above_1810 -1
+    __t__ above_1811 -1
+    __t__ above_1812 -1
+    __t__ above_1813 -1
+    __t__ above_1814 -1
+    __t__ above_1815 -1
+    __t__ above_1816 -1
+    __t__ above_1817 -1
+    __t__ above_1818 -1
+    __t__ above_1819 -1
+    __t__ above_1820 -1
+    __t__ above_1821 -1
+    __t__ above_1822 -1
+    __t__ above_1823 -1
+    __t__ above_1824 -1
+    __t__ above_1825 -1
+    __t__ above_1826 -1
+    __t__ above_1827 -1
+    __t__ above_1828 -1
+    __t__ above_1829 -1
+    __t__ above_1830 -1
+    __t__ above_1831 -1
+    __t__ above_1832 -1
+    __t__ above_1833 -1
+    __t__ above_1834 -1
+    __t__ above_1835 -1
+    __t__ above_1836 -1
+    __t__ above_1837 -1
+    __t__ above_1838 -1
+    __t__ above_1839 -1
+    __t__ above_1840 -1
+    __t__ above_1841 -1
+    __t__ above_1842 -1
+    __t__ above_1843 -1
+    __t__ above_1844 -1
+    __t__ above_1845 -1
+    __t__ above_1846 -1
+    __t__ above_1847 -1
+    __t__ above_1848 -1
+    __t__ above_1849 -1
+    __t__ above_1850 -1
+    __t__ above_1851 -1
+    __t__ above_1852 -1
+    __t__ above_1853 -1
+    __t__ above_1854 -1
+    __t__ above_1855 -1
+    __t__ above_1856 -1
+    __t__ above_1857 -1
+    __t__ above_1858 -1
+    __t__ above_1859 -1
+    __t__ above_1860 -1
+    __t__ above_1861 -1
+    __t__ above_1862 -1
+    __t__ above_1863 -1
+    __t__ above_1864 -1
+    __t__ above_1865 -1
+    __t__ above_1866 -1
+    __t__ above_1867 -1
+    __t__ above_1868 -1
+    __t__ above_1869 -1
+    __t__ above_1870 -1
+    __t__ above_1871 -1
+    __t__ above_1872 -1
+    __t__ above_1873 -1
+    __t__ above_1874 -1
+    __t__ above_1875 -1
+    __t__ above_1876 -1
+    __t__ above_1877 -1
+    __t__ above_1878 -1
+    __t__ above_1879 -1
+    __t__ above_1880 -1
+    __t__ above_1881 -1
+    __t__ above_1882 -1
+    __t__ above_1883 -1
+    __t__ above_1884 -1
+    __t__ above_1885 -1
+    __t__ above_1886 -1
+    __t__ above_1887 -1
+    __t__ above_1888 -1
+    __t__ above_1889 -1
+    __t__ above_1890 -1
+    __t__ above_1891 -1
+    __t__ above_1892 -1
+    __t__ above_1893 -1
+    __t__ above_1894 -1
+    __t__ above_1895 -1
+    __t__ above_1896 -1
+    __t__ above_1897 -1
+    __t__ above_1898 -1
+    __t__ above_1899 -1
+    __t__ above_1900 -1
+    __t__ above_1901 -1
+    __t__ above_1902 -1
+    __t__ above_1903 -1
+    __t__ above_1904 -1
+    __t__ above_1905 -1
+    __t__ above_1906 -1
+    __t__ above_1907 -1
+    __t__ above_1908 -1
+    __t__ above_1909 -1
+    __t__ above_1910 -1
+    __t__ above_1911 -1
+    __t__ above_1912 -1
+    __t__ above_1913 -1
+    __t__ above_1914 -1
+    __t__ above_1915 -1
+    __t__ above_1916 -1
+    __t__ above_1917 -1
+    __t__ above_1918 -1
+    __t__ above_1919 -1
+    __t__ above_1920 -1
+    __t__ above_1921 -1
+    __t__ above_1922 -1
+    __t__ above_1923 -1
+    __t__ above_1924 -1
+    __t__ above_1925 -1
+    __t__ above_1926 -1
+    __t__ above_1927 -1
+    __t__ above_1928 -1
+    __t__ above_1929 -1
+    __t__ above_1930 -1
+    __t__ above_1931 -1
+    __t__ above_1932 -1
+    __t__ above_1933 -1
+    __t__ above_1934 -1
+    __t__ above_1935 -1
+    __t__ above_1936 -1
+    __t__ above_1937 -1
+    __t__ above_1938 -1
+    __t__ above_1939 -1
+    __t__ above_1940 -1
+    __t__ above_1941 -1
+    __t__ above_1942 -1
+    __t__ above_1943 -1
+    __t__ above_1944 -1
+    __t__ above_1945 -1
+    __t__ above_1946 -1
+    __t__ above_1947 -1
+    __t__ above_1948 -1
+    __t__ above_1949 -1
+    __t__ above_1950 -1
+    __t__ above_1951 -1
+    __t__ above_1952 -1
+    __t__ above_1953 -1
+    __t__ above_1954 -1
+    __t__ above_1955 -1
+    __t__ above_1956 -1
+    __t__ above_1957 -1
+    __t__ above_1958 -1
+    __t__ above_1959 -1
+    __t__ above_1960 -1
+    __t__ above_1961 -1
+    __t__ above_1962 -1
+    __t__ above_1963 -1
+    __t__ above_1964 -1
+    __t__ above_1965 -1
+    __t__ above_1966 -1
+    __t__ above_1967 -1
+    __t__ above_1968 -1
+    __t__ above_1969 -1
+    __t__ above_1970 -1
+    __t__ above_1971 -1
+    __t__ above_1972 -1
+    __t__ above_1973 -1
+    __t__ above_1974 -1
+    __t__ above_1975 -1
+    __t__ above_1976 -1
+    __t__ above_1977 -1
+    __t__ above_1978 -1
+    __t__ above_1979 -1
+    __t__ above_1980 -1
+    __t__ above_1981 -1
+    __t__ above_1982 -1
+    __t__ above_1983 -1
+    __t__ above_1984 -1
+    __t__ above_1985 -1
+    __t__ above_1986 -1
+    __t__ above_1987 -1
+    __t__ above_1988 -1
+    __t__ above_1989 -1
+    __t__ above_1990 -1
+    __t__ above_1991 -1
+    __t__ above_1992 -1
+    __t__ above_1993 -1
+    __t__ above_1994 -1
+    __t__ above_1995 -1
+    __t__ above_1996 -1
+    __t__ above_1997 -1
+    __t__ above_1998 -1
+    __t__ above_1999 -1
+    __t__ above_2000 -1
+    __t__ below_1800 -1
+    __t__ below_1801 -1
+    __t__ below_1802 -1
+    __t__ below_1803 -1
+    __t__ below_1804 -1
+    __t__ below_1805 -1
+    __t__ below_1806 -1
+    __t__ below_1807 -1
+    __t__ below_1808 -1
+    __t__ below_1809 -1
+    __t__ below_1810 -1
+    __t__ below_1811 -1
+    __t__ below_1812 -1
+    __t__ below_1813 -1
+    __t__ below_1814 -1
+    __t__ below_1815 -1
+    __t__ below_1816 -1
+    __t__ below_1817 -1
+    __t__ below_1818 -1
+    __t__ below_1819 -1
+    __t__ below_1820 -1
+    __t__ below_1821 -1
+    __t__ below_1822 -1
+    __t__ below_1823 -1
+    __t__ below_1824 -1
+    __t__ below_1825 -1
+    __t__ below_1826 -1
+    __t__ below_1827 -1
+    __t__ below_1828 -1
+    __t__ below_1829 -1
+    __t__ below_1830 -1
+    __t__ below_1831 -1
+    __t__ below_1832 -1
+    __t__ below_1833 -1
+    __t__ below_1834 -1
+    __t__ below_1835 -1
+    __t__ below_1836 -1
+    __t__ below_1837 -1
+    __t__ below_1838 -1
+    __t__ below_1839 -1
+    __t__ below_1840 -1
+    __t__ below_1841 -1
+    __t__ below_1842 -1
+    __t__ below_1843 -1
+    __t__ below_1844 -1
+    __t__ below_1845 -1
+    __t__ below_1846 -1
+    __t__ below_1847 -1
+    __t__ below_1848 -1
+    __t__ below_1849 -1
+    __t__ below_1850 -1
+    __t__ below_1851 -1
+    __t__ below_1852 -1
+    __t__ below_1853 -1
+    __t__ below_1854 -1
+    __t__ below_1855 -1
+    __t__ below_1856 -1
+    __t__ below_1857 -1
+    __t__ below_1858 -1
+    __t__ below_1859 -1
+    __t__ below_1860 -1
+    __t__ below_1861 -1
+    __t__ below_1862 -1
+    __t__ below_1863 -1
+    __t__ below_1864 -1
+    __t__ below_1865 -1
+    __t__ below_1866 -1
+    __t__ below_1867 -1
+    __t__ below_1868 -1
+    __t__ below_1869 -1
+    __t__ below_1870 -1
+    __t__ below_1871 -1
+    __t__ below_1872 -1
+    __t__ below_1873 -1
+    __t__ below_1874 -1
+    __t__ below_1875 -1
+    __t__ below_1876 -1
+    __t__ below_1877 -1
+    __t__ below_1878 -1
+    __t__ below_1879 -1
+    __t__ below_1880 -1
+    __t__ below_1881 -1
+    __t__ below_1882 -1
+    __t__ below_1883 -1
+    __t__ below_1884 -1
+    __t__ below_1885 -1
+    __t__ below_1886 -1
+    __t__ below_1887 -1
+    __t__ below_1888 -1
+    __t__ below_1889 -1
+    __t__ below_1890 -1
+    __t__ below_1891 -1
+    __t__ below_1892 -1
+    __t__ below_1893 -1
+    __t__ below_1894 -1
+    __t__ below_1895 -1
+    __t__ below_1896 -1
+    __t__ below_1897 -1
+    __t__ below_1898 -1
+    __t__ below_1899 -1
+    __t__ below_1900 -1
+    __t__ below_1901 -1
+    __t__ below_1902 -1
+    __t__ below_1903 -1
+    __t__ below_1904 -1
+    __t__ below_1905 -1
+    __t__ below_1906 -1
+    __t__ below_1907 -1
+    __t__ below_1908 -1
+    __t__ below_1909 -1
+    __t__ below_1910 -1
+    __t__ below_1911 -1
+    __t__ below_1912 -1
+    __t__ below_1913 -1
+    __t__ below_1914 -1
+    __t__ below_1915 -1
+    __t__ below_1916 -1
+    __t__ below_1917 -1
+    __t__ below_1918 -1
+    __t__ below_1919 -1
+    __t__ below_1920 -1
+    __t__ below_1921 -1
+    __t__ below_1922 -1
+    __t__ below_1923 -1
+    __t__ below_1924 -1
+    __t__ below_1925 -1
+    __t__ below_1926 -1
+    __t__ below_1927 -1
+    __t__ below_1928 -1
+    __t__ below_1929 -1
+    __t__ below_1930 -1
+    __t__ below_1931 -1
+    __t__ below_1932 -1
+    __t__ below_1933 -1
+    __t__ below_1934 -1
+    __t__ below_1935 -1
+    __t__ below_1936 -1
+    __t__ below_1937 -1
+    __t__ below_1938 -1
+    __t__ below_1939 -1
+    __t__ below_1940 -1
+    __t__ below_1941 -1
+    __t__ below_1942 -1
+    __t__ below_1943 -1
+    __t__ below_1944 -1
+    __t__ below_1945 -1
+    __t__ below_1946 -1
+    __t__ below_1947 -1
+    __t__ below_1948 -1
+    __t__ below_1949 -1
+    __t__ below_1950 -1
+    __t__ below_1951 -1
+    __t__ below_1952 -1
+    __t__ below_1953 -1
+    __t__ below_1954 -1
+    __t__ below_1955 -1
+    __t__ below_1956 -1
+    __t__ below_1957 -1
+    __t__ below_1958 -1
+    __t__ below_1959 -1
+    __t__ below_1960 -1
+    __t__ below_1961 -1
+    __t__ below_1962 -1
+    __t__ below_1963 -1
+    __t__ below_1964 -1
+    __t__ below_1965 -1
+    __t__ below_1966 -1
+    __t__ below_1967 -1
+    __t__ below_1968 -1
+    __t__ below_1969 -1
+    __t__ below_1970 -1
+    __t__ below_1971 -1
+    __t__ below_1972 -1
+    __t__ below_1973 -1
+    __t__ below_1974 -1
+    __t__ below_1975 -1
+    __t__ below_1976 -1
+    __t__ below_1977 -1
+    __t__ below_1978 -1
+    __t__ below_1979 -1
+    __t__ below_1980 -1
+    __t__ below_1981 -1
+    __t__ below_1982 -1
+    __t__ below_1983 -1
+    __t__ below_1984 -1
+    __t__ below_1985 -1
+    __t__ below_1986 -1
+    __t__ below_1987 -1
+    __t__ below_1988 -1
+    __t__ below_1989 -1
+    __t__ below_1990 -1
+    __t__ below_1991 -1
+    __t__ below_1992 -1
+    __t__ below_1993 -1
+    __t__ below_1994 -1
+    __t__ below_1995 -1
+    __t__ below_1996 -1
+    __t__ below_1997 -1
+    __t__ below_1998 -1
+    __t__ below_1999 -1
+    __t__ below_2000 -1
+RHS
+    RHS above_1800 0.05472636815920398
+    RHS above_1801 0.11940298507462686
+    RHS above_1802 0.19402985074626866
+    RHS above_1803 0.25870646766169153
+    RHS above_1804 0.32338308457711445
+    RHS above_1805 0.3880597014925373
+    RHS above_1806 0.5223880597014926
+    RHS above_1807 0.5970149253731343
+    RHS above_1808 0.6716417910447761
+    RHS above_1809 0.845771144278607
+    RHS above_1810 0.9303482587064676
+    RHS above_1811 1.0746268656716418
+    RHS above_1812 1.2288557213930347
+    RHS above_1813 1.1840796019900497
+    RHS above_1814 1.417910447761194
+    RHS above_1815 1.5920398009950247
+    RHS above_1816 1.8606965174129355
+    RHS above_1817 2.059701492537313
+    RHS above_1818 2.0796019900497513
+    RHS above_1819 2.1890547263681595
+    RHS above_1820 2.5074626865671643
+    RHS above_1821 2.845771144278607
+    RHS above_1822 2.9751243781094527
+    RHS above_1823 3.2238805970149254
+    RHS above_1824 3.3582089552238807
+    RHS above_1825 3.36318407960199
+    RHS above_1826 3.8955223880597014
+    RHS above_1827 4.45771144278607
+    RHS above_1828 4.328358208955224
+    RHS above_1829 5.223880597014925
+    RHS above_1830 4.781094527363184
+    RHS above_1831 5.731343283582089
+    RHS above_1832 6.074626865671642
+    RHS above_1833 6.258706467661692
+    RHS above_1834 7.139303482587065
+    RHS above_1835 7.701492537313432
+    RHS above_1836 8.099502487562189
+    RHS above_1837 8.696517412935323
+    RHS above_1838 8.925373134328359
+    RHS above_1839 8.756218905472638
+    RHS above_1840 9.791044776119403
+    RHS above_1841 10.656716417910447
+    RHS above_1842 11.124378109452737
+    RHS above_1843 13.353233830845772
+    RHS above_1844 12.08955223880597
+    RHS above_1845 13.502487562189055
+    RHS above_1846 14.497512437810945
+    RHS above_1847 14.08955223880597
+    RHS above_1848 15.114427860696518
+    RHS above_1849 15.671641791044776
+    RHS above_1850 17.507462686567166
+    RHS above_1851 17.07462686567164
+    RHS above_1852 17.402985074626866
+    RHS above_1853 19.34328358208955
+    RHS above_1854 19.154228855721392
+    RHS above_1855 22.28855721393035
+    RHS above_1856 24.955223880597014
+    RHS above_1857 25.1044776119403
+    RHS above_1858 23.482587064676615
+    RHS above_1859 28.656716417910445
+    RHS above_1860 25.18905472636816
+    RHS above_1861 32.07960199004975
+    RHS above_1862 32.28358208955224
+    RHS above_1863 32.47761194029851
+    RHS above_1864 36.21890547263682
+    RHS above_1865 38.74626865671642
+    RHS above_1866 35.33333333333333
+    RHS above_1867 37.55223880597015
+    RHS above_1868 38.7910447761194
+    RHS above_1869 41.791044776119406
+    RHS above_1870 44.86069651741293
+    RHS above_1871 48.35820895522388
+    RHS above_1872 49.02985074626866
+    RHS above_1873 54.11940298507462
+    RHS above_1874 50.37313432835821
+    RHS above_1875 54.82587064676617
+    RHS above_1876 57.46268656716418
+    RHS above_1877 58.59701492537314
+    RHS above_1878 56.59701492537313
+    RHS above_1879 63.28358208955224
+    RHS above_1880 67.70149253731343
+    RHS above_1881 71.39303482587064
+    RHS above_1882 63.592039800995025
+    RHS above_1883 76.05970149253731
+    RHS above_1884 76.96517412935324
+    RHS above_1885 80.86567164179104
+    RHS above_1886 82.67164179104478
+    RHS above_1887 81.87064676616916
+    RHS above_1888 83.6865671641791
+    RHS above_1889 84.6268656716418
+    RHS above_1890 86.92537313432835
+    RHS above_1891 88.33830845771145
+    RHS above_1892 99.01492537313433
+    RHS above_1893 92.12935323383084
+    RHS above_1894 91.21890547263682
+    RHS above_1895 92.65671641791045
+    RHS above_1896 96.03482587064677
+    RHS above_1897 96.04975124378109
+    RHS above_1898 110.32835820895522
+    RHS above_1899 108.45771144278608
+    RHS above_1900 107.02985074626866
+    RHS above_1901 105.044776119403
+    RHS above_1902 113.24875621890547
+    RHS above_1903 111.24378109452736
+    RHS above_1904 125.89552238805969
+    RHS above_1905 126.56716417910448
+    RHS above_1906 119.77611940298507
+    RHS above_1907 116.05970149253731
+    RHS above_1908 123.09950248756218
+    RHS above_1909 116.56716417910447
+    RHS above_1910 127.01492537313433
+    RHS above_1911 124.2587064676617
+    RHS above_1912 123.68159203980099
+    RHS above_1913 129.3134328358209
+    RHS above_1914 124.15422885572139
+    RHS above_1915 129.27363184079604
+    RHS above_1916 140.28358208955225
+    RHS above_1917 137.37313432835822
+    RHS above_1918 140.90547263681592
+    RHS above_1919 132.53731343283582
+    RHS above_1920 130.6318407960199
+    RHS above_1921 138.38805970149255
+    RHS above_1922 121.16417910447761
+    RHS above_1923 143.7412935323383
+    RHS above_1924 138.681592039801
+    RHS above_1925 133.5223880597015
+    RHS above_1926 140.90049751243782
+    RHS above_1927 137.55223880597015
+    RHS above_1928 159.1641791044776
+    RHS above_1929 137.76119402985077
+    RHS above_1930 129.69651741293532
+    RHS above_1931 147.1044776119403
+    RHS above_1932 140.94029850746267
+    RHS above_1933 138
+    RHS above_1934 137.6865671641791
+    RHS above_1935 152.23880597014926
+    RHS above_1936 127.45771144278608
+    RHS above_1937 142.80597014925374
+    RHS above_1938 136.92537313432834
+    RHS above_1939 146.26865671641792
+    RHS above_1940 144.50746268656718
+    RHS above_1941 144.11940298507463
+    RHS above_1942 151.53731343283582
+    RHS above_1943 154.02985074626864
+    RHS above_1944 143.55721393034827
+    RHS above_1945 134.3781094527363
+    RHS above_1946 138.955223880597
+    RHS above_1947 135.4825870646766
+    RHS above_1948 140.1044776119403
+    RHS above_1949 134.32835820895522
+    RHS above_1950 151
+    RHS above_1951 144.43781094527364
+    RHS above_1952 146.91044776119404
+    RHS above_1953 164.72636815920396
+    RHS above_1954 138.80597014925374
+    RHS above_1955 152.11940298507463
+    RHS above_1956 146.06467661691542
+    RHS above_1957 142.27860696517413
+    RHS above_1958 158.2089552238806
+    RHS above_1959 154.42786069651743
+    RHS above_1960 148.18407960199005
+    RHS above_1961 151.52238805970148
+    RHS above_1962 162.18905472636814
+    RHS above_1963 147.681592039801
+    RHS above_1964 151.044776119403
+    RHS above_1965 139.5721393034826
+    RHS above_1966 156.19900497512438
+    RHS above_1967 141.2537313432836
+    RHS above_1968 147.13930348258705
+    RHS above_1969 155.6218905472637
+    RHS above_1970 165.044776119403
+    RHS above_1971 162.5870646766169
+    RHS above_1972 156.64676616915423
+    RHS above_1973 154.08955223880596
+    RHS above_1974 155.84577114427861
+    RHS above_1975 163.74129353233832
+    RHS above_1976 149.70149253731344
+    RHS above_1977 154.97512437810946
+    RHS above_1978 146.9402985074627
+    RHS above_1979 164.77611940298507
+    RHS above_1980 160.28855721393035
+    RHS above_1981 168.4179104477612
+    RHS above_1982 153.86567164179104
+    RHS above_1983 164.77611940298507
+    RHS above_1984 157.38805970149255
+    RHS above_1985 175.82089552238807
+    RHS above_1986 171.18407960199005
+    RHS above_1987 175.8407960199005
+    RHS above_1988 155.1492537313433
+    RHS above_1989 162.58706467661693
+    RHS above_1990 159.6417910447761
+    RHS above_1991 167.1641791044776
+    RHS above_1992 182.4378109452736
+    RHS above_1993 182.41791044776122
+    RHS above_1994 163.95522388059703
+    RHS above_1995 176.49751243781094
+    RHS above_1996 176.4179104477612
+    RHS above_1997 162.53731343283582
+    RHS above_1998 175.23880597014926
+    RHS above_1999 178.10945273631842
+    RHS above_2000 168
+    RHS below_1800 -0.05472636815920398
+    RHS below_1801 -0.11940298507462686
+    RHS below_1802 -0.19402985074626866
+    RHS below_1803 -0.25870646766169153
+    RHS below_1804 -0.32338308457711445
+    RHS below_1805 -0.3880597014925373
+    RHS below_1806 -0.5223880597014926
+    RHS below_1807 -0.5970149253731343
+    RHS below_1808 -0.6716417910447761
+    RHS below_1809 -0.845771144278607
+    RHS below_1810 -0.9303482587064676
+    RHS below_1811 -1.0746268656716418
+    RHS below_1812 -1.2288557213930347
+    RHS below_1813 -1.1840796019900497
+    RHS below_1814 -1.417910447761194
+    RHS below_1815 -1.5920398009950247
+    RHS below_1816 -1.8606965174129355
+    RHS below_1817 -2.059701492537313
+    RHS below_1818 -2.0796019900497513
+    RHS below_1819 -2.1890547263681595
+    RHS below_1820 -2.5074626865671643
+    RHS below_1821 -2.845771144278607
+    RHS below_1822 -2.9751243781094527
+    RHS below_1823 -3.2238805970149254
+    RHS below_1824 -3.3582089552238807
+    RHS below_1825 -3.36318407960199
+    RHS below_1826 -3.8955223880597014
+    RHS below_1827 -4.45771144278607
+    RHS below_1828 -4.328358208955224
+    RHS below_1829 -5.223880597014925
+    RHS below_1830 -4.781094527363184
+    RHS below_1831 -5.731343283582089
+    RHS below_1832 -6.074626865671642
+    RHS below_1833 -6.258706467661692
+    RHS below_1834 -7.139303482587065
+    RHS below_1835 -7.701492537313432
+    RHS below_1836 -8.099502487562189
+    RHS below_1837 -8.696517412935323
+    RHS below_1838 -8.925373134328359
+    RHS below_1839 -8.756218905472638
+    RHS below_1840 -9.791044776119403
+    RHS below_1841 -10.656716417910447
+    RHS below_1842 -11.124378109452737
+    RHS below_1843 -13.353233830845772
+    RHS below_1844 -12.08955223880597
+    RHS below_1845 -13.502487562189055
+    RHS below_1846 -14.497512437810945
+    RHS below_1847 -14.08955223880597
+    RHS below_1848 -15.114427860696518
+    RHS below_1849 -15.671641791044776
+    RHS below_1850 -17.507462686567166
+    RHS below_1851 -17.07462686567164
+    RHS below_1852 -17.402985074626866
+    RHS below_1853 -19.34328358208955
+    RHS below_1854 -19.154228855721392
+    RHS below_1855 -22.28855721393035
+    RHS below_1856 -24.955223880597014
+    RHS below_1857 -25.1044776119403
+    RHS below_1858 -23.482587064676615
+    RHS below_1859 -28.656716417910445
+    RHS below_1860 -25.18905472636816
+    RHS below_1861 -32.07960199004975
+    RHS below_1862 -32.28358208955224
+    RHS below_1863 -32.47761194029851
+    RHS below_1864 -36.21890547263682
+    RHS below_1865 -38.74626865671642
+    RHS below_1866 -35.33333333333333
+    RHS below_1867 -37.55223880597015
+    RHS below_1868 -38.7910447761194
+    RHS below_1869 -41.791044776119406
+    RHS below_1870 -44.86069651741293
+    RHS below_1871 -48.35820895522388
+    RHS below_1872 -49.02985074626866
+    RHS below_1873 -54.11940298507462
+    RHS below_1874 -50.37313432835821
+    RHS below_1875 -54.82587064676617
+    RHS below_1876 -57.46268656716418
+    RHS below_1877 -58.59701492537314
+    RHS below_1878 -56.59701492537313
+    RHS below_1879 -63.28358208955224
+    RHS below_1880 -67.70149253731343
+    RHS below_1881 -71.39303482587064
+    RHS below_1882 -63.592039800995025
+    RHS below_1883 -76.05970149253731
+    RHS below_1884 -76.96517412935324
+    RHS below_1885 -80.86567164179104
+    RHS below_1886 -82.67164179104478
+    RHS below_1887 -81.87064676616916
+    RHS below_1888 -83.6865671641791
+    RHS below_1889 -84.6268656716418
+    RHS below_1890 -86.92537313432835
+    RHS below_1891 -88.33830845771145
+    RHS below_1892 -99.01492537313433
+    RHS below_1893 -92.12935323383084
+    RHS below_1894 -91.21890547263682
+    RHS below_1895 -92.65671641791045
+    RHS below_1896 -96.03482587064677
+    RHS below_1897 -96.04975124378109
+    RHS below_1898 -110.32835820895522
+    RHS below_1899 -108.45771144278608
+    RHS below_1900 -107.02985074626866
+    RHS below_1901 -105.044776119403
+    RHS below_1902 -113.24875621890547
+    RHS below_1903 -111.24378109452736
+    RHS below_1904 -125.89552238805969
+    RHS below_1905 -126.56716417910448
+    RHS below_1906 -119.77611940298507
+    RHS below_1907 -116.05970149253731
+    RHS below_1908 -123.09950248756218
+    RHS below_1909 -116.56716417910447
+    RHS below_1910 -127.01492537313433
+    RHS below_1911 -124.2587064676617
+    RHS below_1912 -123.68159203980099
+    RHS below_1913 -129.3134328358209
+    RHS below_1914 -124.15422885572139
+    RHS below_1915 -129.27363184079604
+    RHS below_1916 -140.28358208955225
+    RHS below_1917 -137.37313432835822
+    RHS below_1918 -140.90547263681592
+    RHS below_1919 -132.53731343283582
+    RHS below_1920 -130.6318407960199
+    RHS below_1921 -138.38805970149255
+    RHS below_1922 -121.16417910447761
+    RHS below_1923 -143.7412935323383
+    RHS below_1924 -138.681592039801
+    RHS below_1925 -133.5223880597015
+    RHS below_1926 -140.90049751243782
+    RHS below_1927 -137.55223880597015
+    RHS below_1928 -159.1641791044776
+    RHS below_1929 -137.76119402985077
+    RHS below_1930 -129.69651741293532
+    RHS below_1931 -147.1044776119403
+    RHS below_1932 -140.94029850746267
+    RHS below_1933 -138
+    RHS below_1934 -137.6865671641791
+    RHS below_1935 -152.23880597014926
+    RHS below_1936 -127.45771144278608
+    RHS below_1937 -142.80597014925374
+    RHS below_1938 -136.92537313432834
+    RHS below_1939 -146.26865671641792
+    RHS below_1940 -144.50746268656718
+    RHS below_1941 -144.11940298507463
+    RHS below_1942 -151.53731343283582
+    RHS below_1943 -154.02985074626864
+    RHS below_1944 -143.55721393034827
+    RHS below_1945 -134.3781094527363
+    RHS below_1946 -138.955223880597
+    RHS below_1947 -135.4825870646766
+    RHS below_1948 -140.1044776119403
+    RHS below_1949 -134.32835820895522
+    RHS below_1950 -151
+    RHS below_1951 -144.43781094527364
+    RHS below_1952 -146.91044776119404
+    RHS below_1953 -164.72636815920396
+    RHS below_1954 -138.80597014925374
+    RHS below_1955 -152.11940298507463
+    RHS below_1956 -146.06467661691542
+    RHS below_1957 -142.27860696517413
+    RHS below_1958 -158.2089552238806
+    RHS below_1959 -154.42786069651743
+    RHS below_1960 -148.18407960199005
+    RHS below_1961 -151.52238805970148
+    RHS below_1962 -162.18905472636814
+    RHS below_1963 -147.681592039801
+    RHS below_1964 -151.044776119403
+    RHS below_1965 -139.5721393034826
+    RHS below_1966 -156.19900497512438
+    RHS below_1967 -141.2537313432836
+    RHS below_1968 -147.13930348258705
+    RHS below_1969 -155.6218905472637
+    RHS below_1970 -165.044776119403
+    RHS below_1971 -162.5870646766169
+    RHS below_1972 -156.64676616915423
+    RHS below_1973 -154.08955223880596
+    RHS below_1974 -155.84577114427861
+    RHS below_1975 -163.74129353233832
+    RHS below_1976 -149.70149253731344
+    RHS below_1977 -154.97512437810946
+    RHS below_1978 -146.9402985074627
+    RHS below_1979 -164.77611940298507
+    RHS below_1980 -160.28855721393035
+    RHS below_1981 -168.4179104477612
+    RHS below_1982 -153.86567164179104
+    RHS below_1983 -164.77611940298507
+    RHS below_1984 -157.38805970149255
+    RHS below_1985 -175.82089552238807
+    RHS below_1986 -171.18407960199005
+    RHS below_1987 -175.8407960199005
+    RHS below_1988 -155.1492537313433
+    RHS below_1989 -162.58706467661693
+    RHS below_1990 -159.6417910447761
+    RHS below_1991 -167.1641791044776
+    RHS below_1992 -182.4378109452736
+    RHS below_1993 -182.41791044776122
+    RHS below_1994 -163.95522388059703
+    RHS below_1995 -176.49751243781094
+    RHS below_1996 -176.4179104477612
+    RHS below_1997 -162.53731343283582
+    RHS below_1998 -175.23880597014926
+    RHS below_1999 -178.10945273631842
+    RHS below_2000 -168
+BOUNDS
+ENDATA
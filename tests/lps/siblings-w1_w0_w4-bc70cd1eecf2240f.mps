@@ -0,0 +1,10938 @@
+NAME w1_w0_w4-bc70cd1eecf2240f
+ROWS
+ N obj
+ L above_1800
+ L above_1801
+ L above_1802
+ L above_1803
+ L above_1804
+ L above_1805
+ L above_1806
+ L above_1807
+ L above_1808
+ L above_1809
+ L above_1810
+ L above_1811
+ L above_1812
+ L above_1813
+ L above_1814
+ L above_1815
+ L above_1816
+ L above_1817
+ L above_1818
+ L above_1819
+ L above_1820
+ L above_1821
+ L above_1822
+ L above_1823
+ L above_1824
+ L above_1825
+ L above_1826
+ L above_1827
+ L above_1828
+ L above_1829
+ L above_1830
+ L above_1831
+ L above_1832
+ L above_1833
+ L above_1834
+ L above_1835
+ L above_1836
+ L above_1837
+ L above_1838
+ L above_1839
+ L above_1840
+ L above_1841
+ L above_1842
+ L above_1843
+ L above_1844
+ L above_1845
+ L above_1846
+ L above_1847
+ L above_1848
+ L above_1849
+ L above_1850
+ L above_1851
+ L above_1852
+ L above_1853
+ L above_1854
+ L above_1855
+ L above_1856
+ L above_1857
+ L above_1858
+ L above_1859
+ L above_1860
+ L above_1861
+ L above_1862
+ L above_1863
+ L above_1864
+ L above_1865
+ L above_1866
+ L above_1867
+ L above_1868
+ L above_1869
+ L above_1870
+ L above_1871
+ L above_1872
+ L above_1873
+ L above_1874
+ L above_1875
+ L above_1876
+ L above_1877
+ L above_1878
+ L above_1879
+ L above_1880
+ L above_1881
+ L above_1882
+ L above_1883
+ L above_1884
+ L above_1885
+ L above_1886
+ L above_1887
+ L above_1888
+ L above_1889
+ L above_1890
+ L above_1891
+ L above_1892
+ L above_1893
+ L above_1894
+ L above_1895
+ L above_1896
+ L above_1897
+ L above_1898
+ L above_1899
+ L above_1900
+ L above_1901
+ L above_1902
+ L above_1903
+ L above_1904
+ L above_1905
+ L above_1906
+ L above_1907
+ L above_1908
+ L above_1909
+ L above_1910
+ L above_1911
+ L above_1912
+ L above_1913
+ L above_1914
+ L above_1915
+ L above_1916
+ L above_1917
+ L above_1918
+ L above_1919
+ L above_1920
+ L above_1921
+ L above_1922
+ L above_1923
+ L above_1924
+ L above_1925
+ L above_1926
+ L above_1927
+ L above_1928
+ L above_1929
+ L above_1930
+ L above_1931
+ L above_1932
+ L above_1933
+ L above_1934
+ L above_1935
+ L above_1936
+ L above_1937
+ L above_1938
+ L above_1939
+ L above_1940
+ L above_1941
+ L above_1942
+ L above_1943
+ L above_1944
+ L above_1945
+ L above_1946
+ L above_1947
+ L above_1948
+ L above_1949
+ L above_1950
+ L above_1951
+ L above_1952
+ L above_1953
+ L above_1954
+ L above_1955
+ L above_1956
+ L above_1957
+ L above_1958
+ L above_1959
+ L above_1960
+ L above_1961
+ L above_1962
+ L above_1963
+ L above_1964
+ L above_1965
+ L above_1966
+ L above_1967
+ L above_1968
+ L above_1969
+ L above_1970
+ L above_1971
+ L above_1972
+ L above_1973
+ L above_1974
+ L above_1975
+ L above_1976
+ L above_1977
+ L above_1978
+ L above_1979
+ L above_1980
+ L above_1981
+ L above_1982
+ L above_1983
+ L above_1984
+ L above_1985
+ L above_1986
+ L above_1987
+ L above_1988
+ L above_1989
+ L above_1990
+ L above_1991
+ L above_1992
+ L above_1993
+ L above_1994
+ L above_1995
+ L above_1996
+ L above_1997
+ L above_1998
+ L above_1999
+ L above_2000
+ L below_1800
+ L below_1801
+ L below_1802
+ L below_1803
+ L below_1804
+ L below_1805
+ L below_1806
+ L below_1807
+ L below_1808
+ L below_1809
+ L below_1810
+ L below_1811
+ L below_1812
+ L below_1813
+ L below_1814
+ L below_1815
+ L below_1816
+ L below_1817
+ L below_1818
+ L below_1819
+ L below_1820
+ L below_1821
+ L below_1822
+ L below_1823
+ L below_1824
+ L below_1825
+ L below_1826
+ L below_1827
+ L below_1828
+ L below_1829
+ L below_1830
+ L below_1831
+ L below_1832
+ L below_1833
+ L below_1834
+ L below_1835
+ L below_1836
+ L below_1837
+ L below_1838
+ L below_1839
+ L below_1840
+ L below_1841
+ L below_1842
+ L below_1843
+ L below_1844
+ L below_1845
+ L below_1846
+ L below_1847
+ L below_1848
+ L below_1849
+ L below_1850
+ L below_1851
+ L below_1852
+ L below_1853
+ L below_1854
+ L below_1855
+ L below_1856
+ L below_1857
+ L below_1858
+ L below_1859
+ L below_1860
+ L below_1861
+ L below_1862
+ L below_1863
+ L below_1864
+ L below_1865
+ L below_1866
+ L below_1867
+ L below_1868
+ L below_1869
+ L below_1870
+ L below_1871
+ L below_1872
+ L below_1873
+ L below_1874
+ L below_1875
+ L below_1876
+ L below_1877
+ L below_1878
+ L below_1879
+ L below_1880
+ L below_1881
+ L below_1882
+ L below_1883
+ L below_1884
+ L below_1885
+ L below_1886
+ L below_1887
+ L below_1888
+ L below_1889
+ L below_1890
+ L below_1891
+ L below_1892
+ L below_1893
+ L below_1894
+ L below_1895
+ L below_1896
+ L below_1897
+ L below_1898
+ L below_1899
+ L below_1900
+ L below_1901
+ L below_1902
+ L below_1903
+ L below_1904
+ L below_1905
+ L below_1906
+ L below_1907
+ L below_1908
+ L below_1909
+ L below_1910
+ L below_1911
+ L below_1912
+ L below_1913
+ L below_1914
+ L below_1915
+ L below_1916
+ L below_1917
+ L below_1918
+ L below_1919
+ L below_1920
+ L below_1921
+ L below_1922
+ L below_1923
+ L below_1924
+ L below_1925
+ L below_1926
+ L below_1927
+ L below_1928
+ L below_1929
+ L below_1930
+ L below_1931
+ L below_1932
+ L below_1933
+ L below_1934
+ L below_1935
+ L below_1936
+ L below_1937
+ L below_1938
+ L below_1939
+ L below_1940
+ L below_1941
+ L below_1942
+ L below_1943
+ L below_1944
+ L below_1945
+ L below_1946
+ L below_1947
+ L below_1948
+ L below_1949
+ L below_1950
+ L below_1951
+ L below_1952
+ L below_1953
+ L below_1954
+ L below_1955
+ L below_1956
+ L below_1957
+ L below_1958
+ L below_1959
+ L below_1960
+ L below_1961
+ L below_1962
+ L below_1963
+ L below_1964
+ L below_1965
+ L below_1966
+ L below_1967
+ L below_1968
+ L below_1969
+ L below_1970
+ L below_1971
+ L below_1972
+ L below_1973
+ L below_1974
+ L below_1975
+ L below_1976
+ L below_1977
+ L below_1978
+ L below_1979
+ L below_1980
+ L below_1981
+ L below_1982
+ L below_1983
+ L below_1984
+ L below_1985
+ L below_1986
+ L below_1987
+ L below_1988
+ L below_1989
+ L below_1990
+ L below_1991
+ L below_1992
+ L below_1993
+ L below_1994
+ L below_1995
+ L below_1996
+ L below_1997
+ L below_1998
+ L below_1999
+ L below_2000
+COLUMNS
+    w1 above_1800 18573
+    w1 above_1801 23843
+    w1 above_1802 23680
+    w1 above_1803 24216
+    w1 above_1804 26037
+    w1 above_1805 24163
+    w1 above_1806 25417
+    w1 above_1807 25789
+    w1 above_1808 26248
+    w1 above_1809 27208
+    w1 above_1810 28015
+    w1 above_1811 28483
+    w1 above_1812 30553
+    w1 above_1813 29107
+    w1 above_1814 31440
+    w1 above_1815 33059
+    w1 above_1816 32795
+    w1 above_1817 33165
+    w1 above_1818 34089
+    w1 above_1819 33683
+    w1 above_1820 33696
+    w1 above_1821 37417
+    w1 above_1822 37931
+    w1 above_1823 35437
+    w1 above_1824 36625
+    w1 above_1825 34328
+    w1 above_1826 39452
+    w1 above_1827 41328
+    w1 above_1828 41333
+    w1 above_1829 39587
+    w1 above_1830 45154
+    w1 above_1831 45095
+    w1 above_1832 44953
+    w1 above_1833 43461
+    w1 above_1834 43872
+    w1 above_1835 43903
+    w1 above_1836 44845
+    w1 above_1837 44906
+    w1 above_1838 46449
+    w1 above_1839 50510
+    w1 above_1840 47757
+    w1 above_1841 53188
+    w1 above_1842 51636
+    w1 above_1843 49766
+    w1 above_1844 51779
+    w1 above_1845 57332
+    w1 above_1846 53842
+    w1 above_1847 52609
+    w1 above_1848 56220
+    w1 above_1849 54749
+    w1 above_1850 60368
+    w1 above_1851 51947
+    w1 above_1852 57871
+    w1 above_1853 57712
+    w1 above_1854 58859
+    w1 above_1855 56566
+    w1 above_1856 62003
+    w1 above_1857 58965
+    w1 above_1858 56141
+    w1 above_1859 66735
+    w1 above_1860 69203
+    w1 above_1861 64844
+    w1 above_1862 68314
+    w1 above_1863 66125
+    w1 above_1864 65977
+    w1 above_1865 66237
+    w1 above_1866 66797
+    w1 above_1867 69841
+    w1 above_1868 72806
+    w1 above_1869 71832
+    w1 above_1870 70728
+    w1 above_1871 79707
+    w1 above_1872 71025
+    w1 above_1873 79031
+    w1 above_1874 73147
+    w1 above_1875 74002
+    w1 above_1876 72090
+    w1 above_1877 81331
+    w1 above_1878 85583
+    w1 above_1879 79118
+    w1 above_1880 81301
+    w1 above_1881 76450
+    w1 above_1882 86013
+    w1 above_1883 79334
+    w1 above_1884 84676
+    w1 above_1885 82792
+    w1 above_1886 88960
+    w1 above_1887 84220
+    w1 above_1888 89382
+    w1 above_1889 76778
+    w1 above_1890 85069
+    w1 above_1891 89501
+    w1 above_1892 95595
+    w1 above_1893 84795
+    w1 above_1894 87560
+    w1 above_1895 100089
+    w1 above_1896 90528
+    w1 above_1897 98766
+    w1 above_1898 98910
+    w1 above_1899 93928
+    w1 above_1900 96872
+    w1 above_1901 93455
+    w1 above_1902 102189
+    w1 above_1903 102786
+    w1 above_1904 92315
+    w1 above_1905 100359
+    w1 above_1906 102932
+    w1 above_1907 101873
+    w1 above_1908 103073
+    w1 above_1909 96325
+    w1 above_1910 106794
+    w1 above_1911 106138
+    w1 above_1912 97065
+    w1 above_1913 101876
+    w1 above_1914 107538
+    w1 above_1915 107953
+    w1 above_1916 107097
+    w1 above_1917 119403
+    w1 above_1918 111938
+    w1 above_1919 114722
+    w1 above_1920 110430
+    w1 above_1921 111681
+    w1 above_1922 110089
+    w1 above_1923 122225
+    w1 above_1924 107572
+    w1 above_1925 108554
+    w1 above_1926 123676
+    w1 above_1927 115506
+    w1 above_1928 107329
+    w1 above_1929 109388
+    w1 above_1930 108633
+    w1 above_1931 107658
+    w1 above_1932 124095
+    w1 above_1933 119031
+    w1 above_1934 123444
+    w1 above_1935 125005
+    w1 above_1936 129170
+    w1 above_1937 117234
+    w1 above_1938 126603
+    w1 above_1939 115190
+    w1 above_1940 128216
+    w1 above_1941 125533
+    w1 above_1942 127632
+    w1 above_1943 123244
+    w1 above_1944 143695
+    w1 above_1945 137559
+    w1 above_1946 126615
+    w1 above_1947 129508
+    w1 above_1948 132512
+    w1 above_1949 121267
+    w1 above_1950 124892
+    w1 above_1951 112248
+    w1 above_1952 133135
+    w1 above_1953 124883
+    w1 above_1954 130014
+    w1 above_1955 126647
+    w1 above_1956 125160
+    w1 above_1957 138794
+    w1 above_1958 125060
+    w1 above_1959 138959
+    w1 above_1960 149001
+    w1 above_1961 144451
+    w1 above_1962 134026
+    w1 above_1963 140081
+    w1 above_1964 141412
+    w1 above_1965 153613
+    w1 above_1966 142228
+    w1 above_1967 158361
+    w1 above_1968 137044
+    w1 above_1969 148331
+    w1 above_1970 141996
+    w1 above_1971 131085
+    w1 above_1972 130959
+    w1 above_1973 144780
+    w1 above_1974 144577
+    w1 above_1975 151063
+    w1 above_1976 148136
+    w1 above_1977 145868
+    w1 above_1978 142767
+    w1 above_1979 147677
+    w1 above_1980 146118
+    w1 above_1981 149150
+    w1 above_1982 155668
+    w1 above_1983 144145
+    w1 above_1984 137361
+    w1 above_1985 154411
+    w1 above_1986 159868
+    w1 above_1987 143458
+    w1 above_1988 126539
+    w1 above_1989 159581
+    w1 above_1990 149498
+    w1 above_1991 159957
+    w1 above_1992 156712
+    w1 above_1993 157550
+    w1 above_1994 160998
+    w1 above_1995 164759
+    w1 above_1996 139715
+    w1 above_1997 173516
+    w1 above_1998 158642
+    w1 above_1999 153635
+    w1 above_2000 143841
+    w1 below_1800 -18573
+    w1 below_1801 -23843
+    w1 below_1802 -23680
+    w1 below_1803 -24216
+    w1 below_1804 -26037
+    w1 below_1805 -24163
+    w1 below_1806 -25417
+    w1 below_1807 -25789
+    w1 below_1808 -26248
+    w1 below_1809 -27208
+    w1 below_1810 -28015
+    w1 below_1811 -28483
+    w1 below_1812 -30553
+    w1 below_1813 -29107
+    w1 below_1814 -31440
+    w1 below_1815 -33059
+    w1 below_1816 -32795
+    w1 below_1817 -33165
+    w1 below_1818 -34089
+    w1 below_1819 -33683
+    w1 below_1820 -33696
+    w1 below_1821 -37417
+    w1 below_1822 -37931
+    w1 below_1823 -35437
+    w1 below_1824 -36625
+    w1 below_1825 -34328
+    w1 below_1826 -39452
+    w1 below_1827 -41328
+    w1 below_1828 -41333
+    w1 below_1829 -39587
+    w1 below_1830 -45154
+    w1 below_1831 -45095
+    w1 below_1832 -44953
+    w1 below_1833 -43461
+    w1 below_1834 -43872
+    w1 below_1835 -43903
+    w1 below_1836 -44845
+    w1 below_1837 -44906
+    w1 below_1838 -46449
+    w1 below_1839 -50510
+    w1 below_1840 -47757
+    w1 below_1841 -53188
+    w1 below_1842 -51636
+    w1 below_1843 -49766
+    w1 below_1844 -51779
+    w1 below_1845 -57332
+    w1 below_1846 -53842
+    w1 below_1847 -52609
+    w1 below_1848 -56220
+    w1 below_1849 -54749
+    w1 below_1850 -60368
+    w1 below_1851 -51947
+    w1 below_1852 -57871
+    w1 below_1853 -57712
+    w1 below_1854 -58859
+    w1 below_1855 -56566
+    w1 below_1856 -62003
+    w1 below_1857 -58965
+    w1 below_1858 -56141
+    w1 below_1859 -66735
+    w1 below_1860 -69203
+    w1 below_1861 -64844
+    w1 below_1862 -68314
+    w1 below_1863 -66125
+    w1 below_1864 -65977
+    w1 below_1865 -66237
+    w1 below_1866 -66797
+    w1 below_1867 -69841
+    w1 below_1868 -72806
+    w1 below_1869 -71832
+    w1 below_1870 -70728
+    w1 below_1871 -79707
+    w1 below_1872 -71025
+    w1 below_1873 -79031
+    w1 below_1874 -73147
+    w1 below_1875 -74002
+    w1 below_1876 -72090
+    w1 below_1877 -81331
+    w1 below_1878 -85583
+    w1 below_1879 -79118
+    w1 below_1880 -81301
+    w1 below_1881 -76450
+    w1 below_1882 -86013
+    w1 below_1883 -79334
+    w1 below_1884 -84676
+    w1 below_1885 -82792
+    w1 below_1886 -88960
+    w1 below_1887 -84220
+    w1 below_1888 -89382
+    w1 below_1889 -76778
+    w1 below_1890 -85069
+    w1 below_1891 -89501
+    w1 below_1892 -95595
+    w1 below_1893 -84795
+    w1 below_1894 -87560
+    w1 below_1895 -100089
+    w1 below_1896 -90528
+    w1 below_1897 -98766
+    w1 below_1898 -98910
+    w1 below_1899 -93928
+    w1 below_1900 -96872
+    w1 below_1901 -93455
+    w1 below_1902 -102189
+    w1 below_1903 -102786
+    w1 below_1904 -92315
+    w1 below_1905 -100359
+    w1 below_1906 -102932
+    w1 below_1907 -101873
+    w1 below_1908 -103073
+    w1 below_1909 -96325
+    w1 below_1910 -106794
+    w1 below_1911 -106138
+    w1 below_1912 -97065
+    w1 below_1913 -101876
+    w1 below_1914 -107538
+    w1 below_1915 -107953
+    w1 below_1916 -107097
+    w1 below_1917 -119403
+    w1 below_1918 -111938
+    w1 below_1919 -114722
+    w1 below_1920 -110430
+    w1 below_1921 -111681
+    w1 below_1922 -110089
+    w1 below_1923 -122225
+    w1 below_1924 -107572
+    w1 below_1925 -108554
+    w1 below_1926 -123676
+    w1 below_1927 -115506
+    w1 below_1928 -107329
+    w1 below_1929 -109388
+    w1 below_1930 -108633
+    w1 below_1931 -107658
+    w1 below_1932 -124095
+    w1 below_1933 -119031
+    w1 below_1934 -123444
+    w1 below_1935 -125005
+    w1 below_1936 -129170
+    w1 below_1937 -117234
+    w1 below_1938 -126603
+    w1 below_1939 -115190
+    w1 below_1940 -128216
+    w1 below_1941 -125533
+    w1 below_1942 -127632
+    w1 below_1943 -123244
+    w1 below_1944 -143695
+    w1 below_1945 -137559
+    w1 below_1946 -126615
+    w1 below_1947 -129508
+    w1 below_1948 -132512
+    w1 below_1949 -121267
+    w1 below_1950 -124892
+    w1 below_1951 -112248
+    w1 below_1952 -133135
+    w1 below_1953 -124883
+    w1 below_1954 -130014
+    w1 below_1955 -126647
+    w1 below_1956 -125160
+    w1 below_1957 -138794
+    w1 below_1958 -125060
+    w1 below_1959 -138959
+    w1 below_1960 -149001
+    w1 below_1961 -144451
+    w1 below_1962 -134026
+    w1 below_1963 -140081
+    w1 below_1964 -141412
+    w1 below_1965 -153613
+    w1 below_1966 -142228
+    w1 below_1967 -158361
+    w1 below_1968 -137044
+    w1 below_1969 -148331
+    w1 below_1970 -141996
+    w1 below_1971 -131085
+    w1 below_1972 -130959
+    w1 below_1973 -144780
+    w1 below_1974 -144577
+    w1 below_1975 -151063
+    w1 below_1976 -148136
+    w1 below_1977 -145868
+    w1 below_1978 -142767
+    w1 below_1979 -147677
+    w1 below_1980 -146118
+    w1 below_1981 -149150
+    w1 below_1982 -155668
+    w1 below_1983 -144145
+    w1 below_1984 -137361
+    w1 below_1985 -154411
+    w1 below_1986 -159868
+    w1 below_1987 -143458
+    w1 below_1988 -126539
+    w1 below_1989 -159581
+    w1 below_1990 -149498
+    w1 below_1991 -159957
+    w1 below_1992 -156712
+    w1 below_1993 -157550
+    w1 below_1994 -160998
+    w1 below_1995 -164759
+    w1 below_1996 -139715
+    w1 below_1997 -173516
+    w1 below_1998 -158642
+    w1 below_1999 -153635
+    w1 below_2000 -143841
+    w0 above_1800 51422
+    w0 above_1801 49243
+    w0 above_1802 51632
+    w0 above_1803 56497
+    w0 above_1804 62163
+    w0 above_1805 54862
+    w0 above_1806 60946
+    w0 above_1807 57189
+    w0 above_1808 62041
+    w0 above_1809 60099
+    w0 above_1810 67048
+    w0 above_1811 66630
+    w0 above_1812 68430
+    w0 above_1813 66835
+    w0 above_1814 68792
+    w0 above_1815 73721
+    w0 above_1816 72991
+    w0 above_1817 74461
+    w0 above_1818 70588
+    w0 above_1819 72598
+    w0 above_1820 70313
+    w0 above_1821 80058
+    w0 above_1822 79982
+    w0 above_1823 83562
+    w0 above_1824 78857
+    w0 above_1825 84733
+    w0 above_1826 84533
+    w0 above_1827 86684
+    w0 above_1828 92440
+    w0 above_1829 90404
+    w0 above_1830 99023
+    w0 above_1831 95010
+    w0 above_1832 89504
+    w0 above_1833 96055
+    w0 above_1834 101847
+    w0 above_1835 99440
+    w0 above_1836 97118
+    w0 above_1837 98861
+    w0 above_1838 107170
+    w0 above_1839 117222
+    w0 above_1840 100027
+    w0 above_1841 104022
+    w0 above_1842 110583
+    w0 above_1843 120174
+    w0 above_1844 123051
+    w0 above_1845 114845
+    w0 above_1846 115882
+    w0 above_1847 118138
+    w0 above_1848 126206
+    w0 above_1849 132716
+    w0 above_1850 126038
+    w0 above_1851 125001
+    w0 above_1852 119535
+    w0 above_1853 136287
+    w0 above_1854 123481
+    w0 above_1855 129220
+    w0 above_1856 132467
+    w0 above_1857 129788
+    w0 above_1858 137362
+    w0 above_1859 142732
+    w0 above_1860 138160
+    w0 above_1861 131516
+    w0 above_1862 150514
+    w0 above_1863 143334
+    w0 above_1864 143985
+    w0 above_1865 141525
+    w0 above_1866 167136
+    w0 above_1867 147658
+    w0 above_1868 156346
+    w0 above_1869 160966
+    w0 above_1870 146669
+    w0 above_1871 159061
+    w0 above_1872 160795
+    w0 above_1873 157895
+    w0 above_1874 167156
+    w0 above_1875 177177
+    w0 above_1876 168586
+    w0 above_1877 161651
+    w0 above_1878 153783
+    w0 above_1879 164740
+    w0 above_1880 170504
+    w0 above_1881 193250
+    w0 above_1882 168093
+    w0 above_1883 175926
+    w0 above_1884 170076
+    w0 above_1885 174629
+    w0 above_1886 173507
+    w0 above_1887 190112
+    w0 above_1888 200848
+    w0 above_1889 185130
+    w0 above_1890 187402
+    w0 above_1891 199916
+    w0 above_1892 197980
+    w0 above_1893 186324
+    w0 above_1894 189516
+    w0 above_1895 208523
+    w0 above_1896 207543
+    w0 above_1897 208976
+    w0 above_1898 199778
+    w0 above_1899 199030
+    w0 above_1900 220860
+    w0 above_1901 208072
+    w0 above_1902 232937
+    w0 above_1903 212698
+    w0 above_1904 219674
+    w0 above_1905 197633
+    w0 above_1906 220700
+    w0 above_1907 202257
+    w0 above_1908 210581
+    w0 above_1909 212066
+    w0 above_1910 219758
+    w0 above_1911 203996
+    w0 above_1912 214462
+    w0 above_1913 235181
+    w0 above_1914 234427
+    w0 above_1915 216230
+    w0 above_1916 209333
+    w0 above_1917 242237
+    w0 above_1918 250679
+    w0 above_1919 232106
+    w0 above_1920 221059
+    w0 above_1921 240767
+    w0 above_1922 224484
+    w0 above_1923 227616
+    w0 above_1924 243338
+    w0 above_1925 243747
+    w0 above_1926 247575
+    w0 above_1927 246773
+    w0 above_1928 224087
+    w0 above_1929 253617
+    w0 above_1930 249345
+    w0 above_1931 254756
+    w0 above_1932 246453
+    w0 above_1933 285284
+    w0 above_1934 262248
+    w0 above_1935 246708
+    w0 above_1936 257176
+    w0 above_1937 269376
+    w0 above_1938 251680
+    w0 above_1939 254913
+    w0 above_1940 253498
+    w0 above_1941 280108
+    w0 above_1942 264013
+    w0 above_1943 260556
+    w0 above_1944 237400
+    w0 above_1945 268362
+    w0 above_1946 256755
+    w0 above_1947 266028
+    w0 above_1948 277912
+    w0 above_1949 292117
+    w0 above_1950 268199
+    w0 above_1951 261424
+    w0 above_1952 259563
+    w0 above_1953 256153
+    w0 above_1954 283437
+    w0 above_1955 285567
+    w0 above_1956 289467
+    w0 above_1957 298888
+    w0 above_1958 301542
+    w0 above_1959 306129
+    w0 above_1960 290193
+    w0 above_1961 258337
+    w0 above_1962 289324
+    w0 above_1963 243394
+    w0 above_1964 291174
+    w0 above_1965 297056
+    w0 above_1966 281350
+    w0 above_1967 297824
+    w0 above_1968 292376
+    w0 above_1969 284537
+    w0 above_1970 279026
+    w0 above_1971 306382
+    w0 above_1972 290453
+    w0 above_1973 291454
+    w0 above_1974 315356
+    w0 above_1975 319830
+    w0 above_1976 308533
+    w0 above_1977 303503
+    w0 above_1978 293547
+    w0 above_1979 296333
+    w0 above_1980 305086
+    w0 above_1981 279551
+    w0 above_1982 296835
+    w0 above_1983 319476
+    w0 above_1984 286300
+    w0 above_1985 324819
+    w0 above_1986 331124
+    w0 above_1987 318146
+    w0 above_1988 277741
+    w0 above_1989 302499
+    w0 above_1990 294102
+    w0 above_1991 298467
+    w0 above_1992 320441
+    w0 above_1993 336698
+    w0 above_1994 317784
+    w0 above_1995 320671
+    w0 above_1996 348847
+    w0 above_1997 292322
+    w0 above_1998 308121
+    w0 above_1999 325227
+    w0 above_2000 298136
+    w0 below_1800 -51422
+    w0 below_1801 -49243
+    w0 below_1802 -51632
+    w0 below_1803 -56497
+    w0 below_1804 -62163
+    w0 below_1805 -54862
+    w0 below_1806 -60946
+    w0 below_1807 -57189
+    w0 below_1808 -62041
+    w0 below_1809 -60099
+    w0 below_1810 -67048
+    w0 below_1811 -66630
+    w0 below_1812 -68430
+    w0 below_1813 -66835
+    w0 below_1814 -68792
+    w0 below_1815 -73721
+    w0 below_1816 -72991
+    w0 below_1817 -74461
+    w0 below_1818 -70588
+    w0 below_1819 -72598
+    w0 below_1820 -70313
+    w0 below_1821 -80058
+    w0 below_1822 -79982
+    w0 below_1823 -83562
+    w0 below_1824 -78857
+    w0 below_1825 -84733
+    w0 below_1826 -84533
+    w0 below_1827 -86684
+    w0 below_1828 -92440
+    w0 below_1829 -90404
+    w0 below_1830 -99023
+    w0 below_1831 -95010
+    w0 below_1832 -89504
+    w0 below_1833 -96055
+    w0 below_1834 -101847
+    w0 below_1835 -99440
+    w0 below_1836 -97118
+    w0 below_1837 -98861
+    w0 below_1838 -107170
+    w0 below_1839 -117222
+    w0 below_1840 -100027
+    w0 below_1841 -104022
+    w0 below_1842 -110583
+    w0 below_1843 -120174
+    w0 below_1844 -123051
+    w0 below_1845 -114845
+    w0 below_1846 -115882
+    w0 below_1847 -118138
+    w0 below_1848 -126206
+    w0 below_1849 -132716
+    w0 below_1850 -126038
+    w0 below_1851 -125001
+    w0 below_1852 -119535
+    w0 below_1853 -136287
+    w0 below_1854 -123481
+    w0 below_1855 -129220
+    w0 below_1856 -132467
+    w0 below_1857 -129788
+    w0 below_1858 -137362
+    w0 below_1859 -142732
+    w0 below_1860 -138160
+    w0 below_1861 -131516
+    w0 below_1862 -150514
+    w0 below_1863 -143334
+    w0 below_1864 -143985
+    w0 below_1865 -141525
+    w0 below_1866 -167136
+    w0 below_1867 -147658
+    w0 below_1868 -156346
+    w0 below_1869 -160966
+    w0 below_1870 -146669
+    w0 below_1871 -159061
+    w0 below_1872 -160795
+    w0 below_1873 -157895
+    w0 below_1874 -167156
+    w0 below_1875 -177177
+    w0 below_1876 -168586
+    w0 below_1877 -161651
+    w0 below_1878 -153783
+    w0 below_1879 -164740
+    w0 below_1880 -170504
+    w0 below_1881 -193250
+    w0 below_1882 -168093
+    w0 below_1883 -175926
+    w0 below_1884 -170076
+    w0 below_1885 -174629
+    w0 below_1886 -173507
+    w0 below_1887 -190112
+    w0 below_1888 -200848
+    w0 below_1889 -185130
+    w0 below_1890 -187402
+    w0 below_1891 -199916
+    w0 below_1892 -197980
+    w0 below_1893 -186324
+    w0 below_1894 -189516
+    w0 below_1895 -208523
+    w0 below_1896 -207543
+    w0 below_1897 -208976
+    w0 below_1898 -199778
+    w0 below_1899 -199030
+    w0 below_1900 -220860
+    w0 below_1901 -208072
+    w0 below_1902 -232937
+    w0 below_1903 -212698
+    w0 below_1904 -219674
+    w0 below_1905 -197633
+    w0 below_1906 -220700
+    w0 below_1907 -202257
+    w0 below_1908 -210581
+    w0 below_1909 -212066
+    w0 below_1910 -219758
+    w0 below_1911 -203996
+    w0 below_1912 -214462
+    w0 below_1913 -235181
+    w0 below_1914 -234427
+    w0 below_1915 -216230
+    w0 below_1916 -209333
+    w0 below_1917 -242237
+    w0 below_1918 -250679
+    w0 below_1919 -232106
+    w0 below_1920 -221059
+    w0 below_1921 -240767
+    w0 below_1922 -224484
+    w0 below_1923 -227616
+    w0 below_1924 -243338
+    w0 below_1925 -243747
+    w0 below_1926 -247575
+    w0 below_1927 -246773
+    w0 below_1928 -224087
+    w0 below_1929 -253617
+    w0 below_1930 -249345
+    w0 below_1931 -254756
+    w0 below_1932 -246453
+    w0 below_1933 -285284
+    w0 below_1934 -262248
+    w0 below_1935 -246708
+    w0 below_1936 -257176
+    w0 below_1937 -269376
+    w0 below_1938 -251680
+    w0 below_1939 -254913
+    w0 below_1940 -253498
+    w0 below_1941 -280108
+    w0 below_1942 -264013
+    w0 below_1943 -260556
+    w0 below_1944 -237400
+    w0 below_1945 -268362
+    w0 below_1946 -256755
+    w0 below_1947 -266028
+    w0 below_1948 -277912
+    w0 below_1949 -292117
+    w0 below_1950 -268199
+    w0 below_1951 -261424
+    w0 below_1952 -259563
+    w0 below_1953 -256153
+    w0 below_1954 -283437
+    w0 below_1955 -285567
+    w0 below_1956 -289467
+    w0 below_1957 -298888
+    w0 below_1958 -301542
+    w0 below_1959 -306129
+    w0 below_1960 -290193
+    w0 below_1961 -258337
+    w0 below_1962 -289324
+    w0 below_1963 -243394
+    w0 below_1964 -291174
+    w0 below_1965 -297056
+    w0 below_1966 -281350
+    w0 below_1967 -297824
+    w0 below_1968 -292376
+    w0 below_1969 -284537
+    w0 below_1970 -279026
+    w0 below_1971 -306382
+    w0 below_1972 -290453
+    w0 below_1973 -291454
+    w0 below_1974 -315356
+    w0 below_1975 -319830
+    w0 below_1976 -308533
+    w0 below_1977 -303503
+    w0 below_1978 -293547
+    w0 below_1979 -296333
+    w0 below_1980 -305086
+    w0 below_1981 -279551
+    w0 below_1982 -296835
+    w0 below_1983 -319476
+    w0 below_1984 -286300
+    w0 below_1985 -324819
+    w0 below_1986 -331124
+    w0 below_1987 -318146
+    w0 below_1988 -277741
+    w0 below_1989 -302499
+    w0 below_1990 -294102
+    w0 below_1991 -298467
+    w0 below_1992 -320441
+    w0 below_1993 -336698
+    w0 below_1994 -317784
+    w0 below_1995 -320671
+    w0 below_1996 -348847
+    w0 below_1997 -292322
+    w0 below_1998 -308121
+    w0 below_1999 -325227
+    w0 below_2000 -298136
+    w1_w0 above_1800 2524
+    w1_w0 above_1801 2458
+    w1_w0 above_1802 2424
+    w1_w0 above_1803 2575
+    w1_w0 above_1804 2795
+    w1_w0 above_1805 2888
+    w1_w0 above_1806 2410
+    w1_w0 above_1807 2871
+    w1_w0 above_1808 2835
+    w1_w0 above_1809 3119
+    w1_w0 above_1810 3062
+    w1_w0 above_1811 3032
+    w1_w0 above_1812 2960
+    w1_w0 above_1813 3645
+    w1_w0 above_1814 3299
+    w1_w0 above_1815 3449
+    w1_w0 above_1816 3636
+    w1_w0 above_1817 3618
+    w1_w0 above_1818 3441
+    w1_w0 above_1819 3823
+    w1_w0 above_1820 3615
+    w1_w0 above_1821 4142
+    w1_w0 above_1822 3924
+    w1_w0 above_1823 4539
+    w1_w0 above_1824 4277
+    w1_w0 above_1825 4756
+    w1_w0 above_1826 4387
+    w1_w0 above_1827 4149
+    w1_w0 above_1828 4547
+    w1_w0 above_1829 4751
+    w1_w0 above_1830 4899
+    w1_w0 above_1831 4744
+    w1_w0 above_1832 4930
+    w1_w0 above_1833 5002
+    w1_w0 above_1834 5107
+    w1_w0 above_1835 5584
+    w1_w0 above_1836 5514
+    w1_w0 above_1837 5283
+    w1_w0 above_1838 5060
+    w1_w0 above_1839 5581
+    w1_w0 above_1840 5589
+    w1_w0 above_1841 5518
+    w1_w0 above_1842 5946
+    w1_w0 above_1843 6384
+    w1_w0 above_1844 6064
+    w1_w0 above_1845 6081
+    w1_w0 above_1846 5978
+    w1_w0 above_1847 6478
+    w1_w0 above_1848 6123
+    w1_w0 above_1849 6774
+    w1_w0 above_1850 6048
+    w1_w0 above_1851 6627
+    w1_w0 above_1852 6854
+    w1_w0 above_1853 7059
+    w1_w0 above_1854 6246
+    w1_w0 above_1855 6909
+    w1_w0 above_1856 6747
+    w1_w0 above_1857 7152
+    w1_w0 above_1858 6656
+    w1_w0 above_1859 6939
+    w1_w0 above_1860 7574
+    w1_w0 above_1861 7166
+    w1_w0 above_1862 7904
+    w1_w0 above_1863 7778
+    w1_w0 above_1864 7753
+    w1_w0 above_1865 6354
+    w1_w0 above_1866 8174
+    w1_w0 above_1867 7481
+    w1_w0 above_1868 8120
+    w1_w0 above_1869 7483
+    w1_w0 above_1870 8569
+    w1_w0 above_1871 7398
+    w1_w0 above_1872 8027
+    w1_w0 above_1873 8646
+    w1_w0 above_1874 8281
+    w1_w0 above_1875 9108
+    w1_w0 above_1876 9526
+    w1_w0 above_1877 8065
+    w1_w0 above_1878 8145
+    w1_w0 above_1879 8844
+    w1_w0 above_1880 8138
+    w1_w0 above_1881 8468
+    w1_w0 above_1882 9275
+    w1_w0 above_1883 9943
+    w1_w0 above_1884 8653
+    w1_w0 above_1885 8980
+    w1_w0 above_1886 9617
+    w1_w0 above_1887 9700
+    w1_w0 above_1888 8639
+    w1_w0 above_1889 9834
+    w1_w0 above_1890 8819
+    w1_w0 above_1891 10100
+    w1_w0 above_1892 10799
+    w1_w0 above_1893 9361
+    w1_w0 above_1894 9914
+    w1_w0 above_1895 9522
+    w1_w0 above_1896 9780
+    w1_w0 above_1897 9187
+    w1_w0 above_1898 10442
+    w1_w0 above_1899 11036
+    w1_w0 above_1900 10541
+    w1_w0 above_1901 9898
+    w1_w0 above_1902 10685
+    w1_w0 above_1903 10488
+    w1_w0 above_1904 10152
+    w1_w0 above_1905 10068
+    w1_w0 above_1906 11667
+    w1_w0 above_1907 11322
+    w1_w0 above_1908 10677
+    w1_w0 above_1909 11601
+    w1_w0 above_1910 10889
+    w1_w0 above_1911 10891
+    w1_w0 above_1912 10528
+    w1_w0 above_1913 10576
+    w1_w0 above_1914 10678
+    w1_w0 above_1915 10162
+    w1_w0 above_1916 11662
+    w1_w0 above_1917 10830
+    w1_w0 above_1918 11460
+    w1_w0 above_1919 11657
+    w1_w0 above_1920 11761
+    w1_w0 above_1921 11374
+    w1_w0 above_1922 11803
+    w1_w0 above_1923 12183
+    w1_w0 above_1924 11122
+    w1_w0 above_1925 12102
+    w1_w0 above_1926 10644
+    w1_w0 above_1927 13000
+    w1_w0 above_1928 12480
+    w1_w0 above_1929 11978
+    w1_w0 above_1930 12179
+    w1_w0 above_1931 12694
+    w1_w0 above_1932 12700
+    w1_w0 above_1933 12588
+    w1_w0 above_1934 12151
+    w1_w0 above_1935 11639
+    w1_w0 above_1936 12705
+    w1_w0 above_1937 12003
+    w1_w0 above_1938 12912
+    w1_w0 above_1939 13507
+    w1_w0 above_1940 12683
+    w1_w0 above_1941 14081
+    w1_w0 above_1942 12839
+    w1_w0 above_1943 12611
+    w1_w0 above_1944 13310
+    w1_w0 above_1945 13945
+    w1_w0 above_1946 14209
+    w1_w0 above_1947 12719
+    w1_w0 above_1948 13500
+    w1_w0 above_1949 14492
+    w1_w0 above_1950 13635
+    w1_w0 above_1951 12578
+    w1_w0 above_1952 13717
+    w1_w0 above_1953 15097
+    w1_w0 above_1954 15740
+    w1_w0 above_1955 12083
+    w1_w0 above_1956 15116
+    w1_w0 above_1957 14338
+    w1_w0 above_1958 14851
+    w1_w0 above_1959 14472
+    w1_w0 above_1960 13941
+    w1_w0 above_1961 14070
+    w1_w0 above_1962 15349
+    w1_w0 above_1963 14915
+    w1_w0 above_1964 13875
+    w1_w0 above_1965 14451
+    w1_w0 above_1966 14877
+    w1_w0 above_1967 14603
+    w1_w0 above_1968 14973
+    w1_w0 above_1969 15231
+    w1_w0 above_1970 14373
+    w1_w0 above_1971 15349
+    w1_w0 above_1972 15101
+    w1_w0 above_1973 15104
+    w1_w0 above_1974 14471
+    w1_w0 above_1975 15121
+    w1_w0 above_1976 14479
+    w1_w0 above_1977 14722
+    w1_w0 above_1978 14451
+    w1_w0 above_1979 16357
+    w1_w0 above_1980 14788
+    w1_w0 above_1981 16345
+    w1_w0 above_1982 13894
+    w1_w0 above_1983 14846
+    w1_w0 above_1984 14126
+    w1_w0 above_1985 14634
+    w1_w0 above_1986 17038
+    w1_w0 above_1987 14701
+    w1_w0 above_1988 14795
+    w1_w0 above_1989 14494
+    w1_w0 above_1990 16446
+    w1_w0 above_1991 16066
+    w1_w0 above_1992 14863
+    w1_w0 above_1993 16857
+    w1_w0 above_1994 15870
+    w1_w0 above_1995 14350
+    w1_w0 above_1996 16187
+    w1_w0 above_1997 17040
+    w1_w0 above_1998 15545
+    w1_w0 above_1999 15905
+    w1_w0 above_2000 18143
+    w1_w0 below_1800 -2524
+    w1_w0 below_1801 -2458
+    w1_w0 below_1802 -2424
+    w1_w0 below_1803 -2575
+    w1_w0 below_1804 -2795
+    w1_w0 below_1805 -2888
+    w1_w0 below_1806 -2410
+    w1_w0 below_1807 -2871
+    w1_w0 below_1808 -2835
+    w1_w0 below_1809 -3119
+    w1_w0 below_1810 -3062
+    w1_w0 below_1811 -3032
+    w1_w0 below_1812 -2960
+    w1_w0 below_1813 -3645
+    w1_w0 below_1814 -3299
+    w1_w0 below_1815 -3449
+    w1_w0 below_1816 -3636
+    w1_w0 below_1817 -3618
+    w1_w0 below_1818 -3441
+    w1_w0 below_1819 -3823
+    w1_w0 below_1820 -3615
+    w1_w0 below_1821 -4142
+    w1_w0 below_1822 -3924
+    w1_w0 below_1823 -4539
+    w1_w0 below_1824 -4277
+    w1_w0 below_1825 -4756
+    w1_w0 below_1826 -4387
+    w1_w0 below_1827 -4149
+    w1_w0 below_1828 -4547
+    w1_w0 below_1829 -4751
+    w1_w0 below_1830 -4899
+    w1_w0 below_1831 -4744
+    w1_w0 below_1832 -4930
+    w1_w0 below_1833 -5002
+    w1_w0 below_1834 -5107
+    w1_w0 below_1835 -5584
+    w1_w0 below_1836 -5514
+    w1_w0 below_1837 -5283
+    w1_w0 below_1838 -5060
+    w1_w0 below_1839 -5581
+    w1_w0 below_1840 -5589
+    w1_w0 below_1841 -5518
+    w1_w0 below_1842 -5946
+    w1_w0 below_1843 -6384
+    w1_w0 below_1844 -6064
+    w1_w0 below_1845 -6081
+    w1_w0 below_1846 -5978
+    w1_w0 below_1847 -6478
+    w1_w0 below_1848 -6123
+    w1_w0 below_1849 -6774
+    w1_w0 below_1850 -6048
+    w1_w0 below_1851 -6627
+    w1_w0 below_1852 -6854
+    w1_w0 below_1853 -7059
+    w1_w0 below_1854 -6246
+    w1_w0 below_1855 -6909
+    w1_w0 below_1856 -6747
+    w1_w0 below_1857 -7152
+    w1_w0 below_1858 -6656
+    w1_w0 below_1859 -6939
+    w1_w0 below_1860 -7574
+    w1_w0 below_1861 -7166
+    w1_w0 below_1862 -7904
+    w1_w0 below_1863 -7778
+    w1_w0 below_1864 -7753
+    w1_w0 below_1865 -6354
+    w1_w0 below_1866 -8174
+    w1_w0 below_1867 -7481
+    w1_w0 below_1868 -8120
+    w1_w0 below_1869 -7483
+    w1_w0 below_1870 -8569
+    w1_w0 below_1871 -7398
+    w1_w0 below_1872 -8027
+    w1_w0 below_1873 -8646
+    w1_w0 below_1874 -8281
+    w1_w0 below_1875 -9108
+    w1_w0 below_1876 -9526
+    w1_w0 below_1877 -8065
+    w1_w0 below_1878 -8145
+    w1_w0 below_1879 -8844
+    w1_w0 below_1880 -8138
+    w1_w0 below_1881 -8468
+    w1_w0 below_1882 -9275
+    w1_w0 below_1883 -9943
+    w1_w0 below_1884 -8653
+    w1_w0 below_1885 -8980
+    w1_w0 below_1886 -9617
+    w1_w0 below_1887 -9700
+    w1_w0 below_1888 -8639
+    w1_w0 below_1889 -9834
+    w1_w0 below_1890 -8819
+    w1_w0 below_1891 -10100
+    w1_w0 below_1892 -10799
+    w1_w0 below_1893 -9361
+    w1_w0 below_1894 -9914
+    w1_w0 below_1895 -9522
+    w1_w0 below_1896 -9780
+    w1_w0 below_1897 -9187
+    w1_w0 below_1898 -10442
+    w1_w0 below_1899 -11036
+    w1_w0 below_1900 -10541
+    w1_w0 below_1901 -9898
+    w1_w0 below_1902 -10685
+    w1_w0 below_1903 -10488
+    w1_w0 below_1904 -10152
+    w1_w0 below_1905 -10068
+    w1_w0 below_1906 -11667
+    w1_w0 below_1907 -11322
+    w1_w0 below_1908 -10677
+    w1_w0 below_1909 -11601
+    w1_w0 below_1910 -10889
+    w1_w0 below_1911 -10891
+    w1_w0 below_1912 -10528
+    w1_w0 below_1913 -10576
+    w1_w0 below_1914 -10678
+    w1_w0 below_1915 -10162
+    w1_w0 below_1916 -11662
+    w1_w0 below_1917 -10830
+    w1_w0 below_1918 -11460
+    w1_w0 below_1919 -11657
+    w1_w0 below_1920 -11761
+    w1_w0 below_1921 -11374
+    w1_w0 below_1922 -11803
+    w1_w0 below_1923 -12183
+    w1_w0 below_1924 -11122
+    w1_w0 below_1925 -12102
+    w1_w0 below_1926 -10644
+    w1_w0 below_1927 -13000
+    w1_w0 below_1928 -12480
+    w1_w0 below_1929 -11978
+    w1_w0 below_1930 -12179
+    w1_w0 below_1931 -12694
+    w1_w0 below_1932 -12700
+    w1_w0 below_1933 -12588
+    w1_w0 below_1934 -12151
+    w1_w0 below_1935 -11639
+    w1_w0 below_1936 -12705
+    w1_w0 below_1937 -12003
+    w1_w0 below_1938 -12912
+    w1_w0 below_1939 -13507
+    w1_w0 below_1940 -12683
+    w1_w0 below_1941 -14081
+    w1_w0 below_1942 -12839
+    w1_w0 below_1943 -12611
+    w1_w0 below_1944 -13310
+    w1_w0 below_1945 -13945
+    w1_w0 below_1946 -14209
+    w1_w0 below_1947 -12719
+    w1_w0 below_1948 -13500
+    w1_w0 below_1949 -14492
+    w1_w0 below_1950 -13635
+    w1_w0 below_1951 -12578
+    w1_w0 below_1952 -13717
+    w1_w0 below_1953 -15097
+    w1_w0 below_1954 -15740
+    w1_w0 below_1955 -12083
+    w1_w0 below_1956 -15116
+    w1_w0 below_1957 -14338
+    w1_w0 below_1958 -14851
+    w1_w0 below_1959 -14472
+    w1_w0 below_1960 -13941
+    w1_w0 below_1961 -14070
+    w1_w0 below_1962 -15349
+    w1_w0 below_1963 -14915
+    w1_w0 below_1964 -13875
+    w1_w0 below_1965 -14451
+    w1_w0 below_1966 -14877
+    w1_w0 below_1967 -14603
+    w1_w0 below_1968 -14973
+    w1_w0 below_1969 -15231
+    w1_w0 below_1970 -14373
+    w1_w0 below_1971 -15349
+    w1_w0 below_1972 -15101
+    w1_w0 below_1973 -15104
+    w1_w0 below_1974 -14471
+    w1_w0 below_1975 -15121
+    w1_w0 below_1976 -14479
+    w1_w0 below_1977 -14722
+    w1_w0 below_1978 -14451
+    w1_w0 below_1979 -16357
+    w1_w0 below_1980 -14788
+    w1_w0 below_1981 -16345
+    w1_w0 below_1982 -13894
+    w1_w0 below_1983 -14846
+    w1_w0 below_1984 -14126
+    w1_w0 below_1985 -14634
+    w1_w0 below_1986 -17038
+    w1_w0 below_1987 -14701
+    w1_w0 below_1988 -14795
+    w1_w0 below_1989 -14494
+    w1_w0 below_1990 -16446
+    w1_w0 below_1991 -16066
+    w1_w0 below_1992 -14863
+    w1_w0 below_1993 -16857
+    w1_w0 below_1994 -15870
+    w1_w0 below_1995 -14350
+    w1_w0 below_1996 -16187
+    w1_w0 below_1997 -17040
+    w1_w0 below_1998 -15545
+    w1_w0 below_1999 -15905
+    w1_w0 below_2000 -18143
+    w4 above_1800 7917
+    w4 above_1801 9065
+    w4 above_1802 9377
+    w4 above_1803 9551
+    w4 above_1804 10053
+    w4 above_1805 10440
+    w4 above_1806 10359
+    w4 above_1807 10094
+    w4 above_1808 10343
+    w4 above_1809 9469
+    w4 above_1810 10863
+    w4 above_1811 10903
+    w4 above_1812 12443
+    w4 above_1813 11467
+    w4 above_1814 13009
+    w4 above_1815 13730
+    w4 above_1816 12985
+    w4 above_1817 12691
+    w4 above_1818 14656
+    w4 above_1819 14156
+    w4 above_1820 13338
+    w4 above_1821 14187
+    w4 above_1822 16343
+    w4 above_1823 16099
+    w4 above_1824 16634
+    w4 above_1825 18067
+    w4 above_1826 16033
+    w4 above_1827 15320
+    w4 above_1828 17366
+    w4 above_1829 18575
+    w4 above_1830 17984
+    w4 above_1831 19469
+    w4 above_1832 17239
+    w4 above_1833 19741
+    w4 above_1834 17845
+    w4 above_1835 20037
+    w4 above_1836 21758
+    w4 above_1837 20052
+    w4 above_1838 22439
+    w4 above_1839 21583
+    w4 above_1840 21849
+    w4 above_1841 21746
+    w4 above_1842 23795
+    w4 above_1843 20466
+    w4 above_1844 24024
+    w4 above_1845 23710
+    w4 above_1846 23009
+    w4 above_1847 23362
+    w4 above_1848 26296
+    w4 above_1849 26699
+    w4 above_1850 26001
+    w4 above_1851 23392
+    w4 above_1852 26634
+    w4 above_1853 26152
+    w4 above_1854 27913
+    w4 above_1855 26370
+    w4 above_1856 28855
+    w4 above_1857 27514
+    w4 above_1858 29440
+    w4 above_1859 28833
+    w4 above_1860 31461
+    w4 above_1861 30257
+    w4 above_1862 30281
+    w4 above_1863 29902
+    w4 above_1864 31293
+    w4 above_1865 32930
+    w4 above_1866 33948
+    w4 above_1867 32716
+    w4 above_1868 35323
+    w4 above_1869 31724
+    w4 above_1870 33468
+    w4 above_1871 33275
+    w4 above_1872 34624
+    w4 above_1873 34540
+    w4 above_1874 34820
+    w4 above_1875 39673
+    w4 above_1876 35286
+    w4 above_1877 35648
+    w4 above_1878 35242
+    w4 above_1879 38505
+    w4 above_1880 38822
+    w4 above_1881 39710
+    w4 above_1882 38641
+    w4 above_1883 42035
+    w4 above_1884 40831
+    w4 above_1885 40115
+    w4 above_1886 37734
+    w4 above_1887 39591
+    w4 above_1888 42433
+    w4 above_1889 41108
+    w4 above_1890 46949
+    w4 above_1891 43900
+    w4 above_1892 45152
+    w4 above_1893 44633
+    w4 above_1894 46796
+    w4 above_1895 42417
+    w4 above_1896 47046
+    w4 above_1897 46724
+    w4 above_1898 42486
+    w4 above_1899 47996
+    w4 above_1900 46711
+    w4 above_1901 46386
+    w4 above_1902 43882
+    w4 above_1903 47971
+    w4 above_1904 46358
+    w4 above_1905 46617
+    w4 above_1906 48314
+    w4 above_1907 48604
+    w4 above_1908 49544
+    w4 above_1909 49817
+    w4 above_1910 51785
+    w4 above_1911 45980
+    w4 above_1912 49511
+    w4 above_1913 52273
+    w4 above_1914 53023
+    w4 above_1915 49269
+    w4 above_1916 48807
+    w4 above_1917 50586
+    w4 above_1918 52719
+    w4 above_1919 50524
+    w4 above_1920 50009
+    w4 above_1921 53414
+    w4 above_1922 56076
+    w4 above_1923 53550
+    w4 above_1924 51678
+    w4 above_1925 56605
+    w4 above_1926 55356
+    w4 above_1927 60709
+    w4 above_1928 58814
+    w4 above_1929 56992
+    w4 above_1930 51475
+    w4 above_1931 56522
+    w4 above_1932 56596
+    w4 above_1933 55317
+    w4 above_1934 60639
+    w4 above_1935 58705
+    w4 above_1936 56243
+    w4 above_1937 62470
+    w4 above_1938 54535
+    w4 above_1939 61040
+    w4 above_1940 62927
+    w4 above_1941 59535
+    w4 above_1942 60127
+    w4 above_1943 65629
+    w4 above_1944 65256
+    w4 above_1945 60881
+    w4 above_1946 59047
+    w4 above_1947 62327
+    w4 above_1948 59374
+    w4 above_1949 66892
+    w4 above_1950 58216
+    w4 above_1951 69079
+    w4 above_1952 60453
+    w4 above_1953 56358
+    w4 above_1954 55532
+    w4 above_1955 60489
+    w4 above_1956 58010
+    w4 above_1957 61622
+    w4 above_1958 62600
+    w4 above_1959 64008
+    w4 above_1960 68203
+    w4 above_1961 64892
+    w4 above_1962 65766
+    w4 above_1963 63878
+    w4 above_1964 67223
+    w4 above_1965 68984
+    w4 above_1966 68156
+    w4 above_1967 63009
+    w4 above_1968 72838
+    w4 above_1969 61614
+    w4 above_1970 68336
+    w4 above_1971 64893
+    w4 above_1972 70715
+    w4 above_1973 68317
+    w4 above_1974 69678
+    w4 above_1975 66842
+    w4 above_1976 73173
+    w4 above_1977 68075
+    w4 above_1978 73109
+    w4 above_1979 65032
+    w4 above_1980 72301
+    w4 above_1981 66514
+    w4 above_1982 71141
+    w4 above_1983 64256
+    w4 above_1984 64574
+    w4 above_1985 65796
+    w4 above_1986 67171
+    w4 above_1987 67300
+    w4 above_1988 69657
+    w4 above_1989 69079
+    w4 above_1990 69377
+    w4 above_1991 65907
+    w4 above_1992 70537
+    w4 above_1993 66157
+    w4 above_1994 70212
+    w4 above_1995 71963
+    w4 above_1996 67711
+    w4 above_1997 66615
+    w4 above_1998 77656
+    w4 above_1999 69271
+    w4 above_2000 74697
+    w4 below_1800 -7917
+    w4 below_1801 -9065
+    w4 below_1802 -9377
+    w4 below_1803 -9551
+    w4 below_1804 -10053
+    w4 below_1805 -10440
+    w4 below_1806 -10359
+    w4 below_1807 -10094
+    w4 below_1808 -10343
+    w4 below_1809 -9469
+    w4 below_1810 -10863
+    w4 below_1811 -10903
+    w4 below_1812 -12443
+    w4 below_1813 -11467
+    w4 below_1814 -13009
+    w4 below_1815 -13730
+    w4 below_1816 -12985
+    w4 below_1817 -12691
+    w4 below_1818 -14656
+    w4 below_1819 -14156
+    w4 below_1820 -13338
+    w4 below_1821 -14187
+    w4 below_1822 -16343
+    w4 below_1823 -16099
+    w4 below_1824 -16634
+    w4 below_1825 -18067
+    w4 below_1826 -16033
+    w4 below_1827 -15320
+    w4 below_1828 -17366
+    w4 below_1829 -18575
+    w4 below_1830 -17984
+    w4 below_1831 -19469
+    w4 below_1832 -17239
+    w4 below_1833 -19741
+    w4 below_1834 -17845
+    w4 below_1835 -20037
+    w4 below_1836 -21758
+    w4 below_1837 -20052
+    w4 below_1838 -22439
+    w4 below_1839 -21583
+    w4 below_1840 -21849
+    w4 below_1841 -21746
+    w4 below_1842 -23795
+    w4 below_1843 -20466
+    w4 below_1844 -24024
+    w4 below_1845 -23710
+    w4 below_1846 -23009
+    w4 below_1847 -23362
+    w4 below_1848 -26296
+    w4 below_1849 -26699
+    w4 below_1850 -26001
+    w4 below_1851 -23392
+    w4 below_1852 -26634
+    w4 below_1853 -26152
+    w4 below_1854 -27913
+    w4 below_1855 -26370
+    w4 below_1856 -28855
+    w4 below_1857 -27514
+    w4 below_1858 -29440
+    w4 below_1859 -28833
+    w4 below_1860 -31461
+    w4 below_1861 -30257
+    w4 below_1862 -30281
+    w4 below_1863 -29902
+    w4 below_1864 -31293
+    w4 below_1865 -32930
+    w4 below_1866 -33948
+    w4 below_1867 -32716
+    w4 below_1868 -35323
+    w4 below_1869 -31724
+    w4 below_1870 -33468
+    w4 below_1871 -33275
+    w4 below_1872 -34624
+    w4 below_1873 -34540
+    w4 below_1874 -34820
+    w4 below_1875 -39673
+    w4 below_1876 -35286
+    w4 below_1877 -35648
+    w4 below_1878 -35242
+    w4 below_1879 -38505
+    w4 below_1880 -38822
+    w4 below_1881 -39710
+    w4 below_1882 -38641
+    w4 below_1883 -42035
+    w4 below_1884 -40831
+    w4 below_1885 -40115
+    w4 below_1886 -37734
+    w4 below_1887 -39591
+    w4 below_1888 -42433
+    w4 below_1889 -41108
+    w4 below_1890 -46949
+    w4 below_1891 -43900
+    w4 below_1892 -45152
+    w4 below_1893 -44633
+    w4 below_1894 -46796
+    w4 below_1895 -42417
+    w4 below_1896 -47046
+    w4 below_1897 -46724
+    w4 below_1898 -42486
+    w4 below_1899 -47996
+    w4 below_1900 -46711
+    w4 below_1901 -46386
+    w4 below_1902 -43882
+    w4 below_1903 -47971
+    w4 below_1904 -46358
+    w4 below_1905 -46617
+    w4 below_1906 -48314
+    w4 below_1907 -48604
+    w4 below_1908 -49544
+    w4 below_1909 -49817
+    w4 below_1910 -51785
+    w4 below_1911 -45980
+    w4 below_1912 -49511
+    w4 below_1913 -52273
+    w4 below_1914 -53023
+    w4 below_1915 -49269
+    w4 below_1916 -48807
+    w4 below_1917 -50586
+    w4 below_1918 -52719
+    w4 below_1919 -50524
+    w4 below_1920 -50009
+    w4 below_1921 -53414
+    w4 below_1922 -56076
+    w4 below_1923 -53550
+    w4 below_1924 -51678
+    w4 below_1925 -56605
+    w4 below_1926 -55356
+    w4 below_1927 -60709
+    w4 below_1928 -58814
+    w4 below_1929 -56992
+    w4 below_1930 -51475
+    w4 below_1931 -56522
+    w4 below_1932 -56596
+    w4 below_1933 -55317
+    w4 below_1934 -60639
+    w4 below_1935 -58705
+    w4 below_1936 -56243
+    w4 below_1937 -62470
+    w4 below_1938 -54535
+    w4 below_1939 -61040
+    w4 below_1940 -62927
+    w4 below_1941 -59535
+    w4 below_1942 -60127
+    w4 below_1943 -65629
+    w4 below_1944 -65256
+    w4 below_1945 -60881
+    w4 below_1946 -59047
+    w4 below_1947 -62327
+    w4 below_1948 -59374
+    w4 below_1949 -66892
+    w4 below_1950 -58216
+    w4 below_1951 -69079
+    w4 below_1952 -60453
+    w4 below_1953 -56358
+    w4 below_1954 -55532
+    w4 below_1955 -60489
+    w4 below_1956 -58010
+    w4 below_1957 -61622
+    w4 below_1958 -62600
+    w4 below_1959 -64008
+    w4 below_1960 -68203
+    w4 below_1961 -64892
+    w4 below_1962 -65766
+    w4 below_1963 -63878
+    w4 below_1964 -67223
+    w4 below_1965 -68984
+    w4 below_1966 -68156
+    w4 below_1967 -63009
+    w4 below_1968 -72838
+    w4 below_1969 -61614
+    w4 below_1970 -68336
+    w4 below_1971 -64893
+    w4 below_1972 -70715
+    w4 below_1973 -68317
+    w4 below_1974 -69678
+    w4 below_1975 -66842
+    w4 below_1976 -73173
+    w4 below_1977 -68075
+    w4 below_1978 -73109
+    w4 below_1979 -65032
+    w4 below_1980 -72301
+    w4 below_1981 -66514
+    w4 below_1982 -71141
+    w4 below_1983 -64256
+    w4 below_1984 -64574
+    w4 below_1985 -65796
+    w4 below_1986 -67171
+    w4 below_1987 -67300
+    w4 below_1988 -69657
+    w4 below_1989 -69079
+    w4 below_1990 -69377
+    w4 below_1991 -65907
+    w4 below_1992 -70537
+    w4 below_1993 -66157
+    w4 below_1994 -70212
+    w4 below_1995 -71963
+    w4 below_1996 -67711
+    w4 below_1997 -66615
+    w4 below_1998 -77656
+    w4 below_1999 -69271
+    w4 below_2000 -74697
+    w0_w4 above_1800 507
+    w0_w4 above_1801 531
+    w0_w4 above_1802 575
+    w0_w4 above_1803 579
+    w0_w4 above_1804 566
+    w0_w4 above_1805 608
+    w0_w4 above_1806 611
+    w0_w4 above_1807 568
+    w0_w4 above_1808 590
+    w0_w4 above_1809 697
+    w0_w4 above_1810 691
+    w0_w4 above_1811 760
+    w0_w4 above_1812 726
+    w0_w4 above_1813 682
+    w0_w4 above_1814 771
+    w0_w4 above_1815 847
+    w0_w4 above_1816 798
+    w0_w4 above_1817 845
+    w0_w4 above_1818 728
+    w0_w4 above_1819 837
+    w0_w4 above_1820 960
+    w0_w4 above_1821 902
+    w0_w4 above_1822 967
+    w0_w4 above_1823 1066
+    w0_w4 above_1824 965
+    w0_w4 above_1825 1124
+    w0_w4 above_1826 1090
+    w0_w4 above_1827 1036
+    w0_w4 above_1828 1124
+    w0_w4 above_1829 1093
+    w0_w4 above_1830 1140
+    w0_w4 above_1831 1185
+    w0_w4 above_1832 1289
+    w0_w4 above_1833 1278
+    w0_w4 above_1834 1407
+    w0_w4 above_1835 1436
+    w0_w4 above_1836 1387
+    w0_w4 above_1837 1380
+    w0_w4 above_1838 1352
+    w0_w4 above_1839 1442
+    w0_w4 above_1840 1437
+    w0_w4 above_1841 1584
+    w0_w4 above_1842 1503
+    w0_w4 above_1843 1570
+    w0_w4 above_1844 1587
+    w0_w4 above_1845 1710
+    w0_w4 above_1846 1597
+    w0_w4 above_1847 1838
+    w0_w4 above_1848 1631
+    w0_w4 above_1849 1666
+    w0_w4 above_1850 1770
+    w0_w4 above_1851 1896
+    w0_w4 above_1852 1786
+    w0_w4 above_1853 1841
+    w0_w4 above_1854 1850
+    w0_w4 above_1855 1829
+    w0_w4 above_1856 1828
+    w0_w4 above_1857 1762
+    w0_w4 above_1858 2015
+    w0_w4 above_1859 1929
+    w0_w4 above_1860 2074
+    w0_w4 above_1861 2064
+    w0_w4 above_1862 1948
+    w0_w4 above_1863 1898
+    w0_w4 above_1864 2113
+    w0_w4 above_1865 1969
+    w0_w4 above_1866 2334
+    w0_w4 above_1867 2163
+    w0_w4 above_1868 2039
+    w0_w4 above_1869 2236
+    w0_w4 above_1870 2106
+    w0_w4 above_1871 2297
+    w0_w4 above_1872 2154
+    w0_w4 above_1873 2116
+    w0_w4 above_1874 2373
+    w0_w4 above_1875 2112
+    w0_w4 above_1876 2403
+    w0_w4 above_1877 2502
+    w0_w4 above_1878 2406
+    w0_w4 above_1879 2317
+    w0_w4 above_1880 2391
+    w0_w4 above_1881 2418
+    w0_w4 above_1882 2437
+    w0_w4 above_1883 2492
+    w0_w4 above_1884 2371
+    w0_w4 above_1885 2501
+    w0_w4 above_1886 2472
+    w0_w4 above_1887 2691
+    w0_w4 above_1888 2675
+    w0_w4 above_1889 2624
+    w0_w4 above_1890 2358
+    w0_w4 above_1891 2622
+    w0_w4 above_1892 2792
+    w0_w4 above_1893 2905
+    w0_w4 above_1894 2590
+    w0_w4 above_1895 2851
+    w0_w4 above_1896 2781
+    w0_w4 above_1897 2558
+    w0_w4 above_1898 2666
+    w0_w4 above_1899 2744
+    w0_w4 above_1900 2801
+    w0_w4 above_1901 2741
+    w0_w4 above_1902 2678
+    w0_w4 above_1903 2725
+    w0_w4 above_1904 2908
+    w0_w4 above_1905 2861
+    w0_w4 above_1906 2822
+    w0_w4 above_1907 2942
+    w0_w4 above_1908 2974
+    w0_w4 above_1909 3214
+    w0_w4 above_1910 2994
+    w0_w4 above_1911 2856
+    w0_w4 above_1912 3098
+    w0_w4 above_1913 3142
+    w0_w4 above_1914 3399
+    w0_w4 above_1915 3256
+    w0_w4 above_1916 3475
+    w0_w4 above_1917 3455
+    w0_w4 above_1918 3206
+    w0_w4 above_1919 3248
+    w0_w4 above_1920 3618
+    w0_w4 above_1921 3512
+    w0_w4 above_1922 3521
+    w0_w4 above_1923 3968
+    w0_w4 above_1924 3772
+    w0_w4 above_1925 3624
+    w0_w4 above_1926 3496
+    w0_w4 above_1927 3811
+    w0_w4 above_1928 4054
+    w0_w4 above_1929 4272
+    w0_w4 above_1930 3847
+    w0_w4 above_1931 3951
+    w0_w4 above_1932 4071
+    w0_w4 above_1933 3872
+    w0_w4 above_1934 4004
+    w0_w4 above_1935 3786
+    w0_w4 above_1936 3990
+    w0_w4 above_1937 4067
+    w0_w4 above_1938 3966
+    w0_w4 above_1939 4504
+    w0_w4 above_1940 4046
+    w0_w4 above_1941 4729
+    w0_w4 above_1942 4722
+    w0_w4 above_1943 4450
+    w0_w4 above_1944 4442
+    w0_w4 above_1945 4770
+    w0_w4 above_1946 4856
+    w0_w4 above_1947 4472
+    w0_w4 above_1948 5005
+    w0_w4 above_1949 4740
+    w0_w4 above_1950 4474
+    w0_w4 above_1951 4974
+    w0_w4 above_1952 4477
+    w0_w4 above_1953 4725
+    w0_w4 above_1954 4800
+    w0_w4 above_1955 5121
+    w0_w4 above_1956 4657
+    w0_w4 above_1957 4986
+    w0_w4 above_1958 5053
+    w0_w4 above_1959 5081
+    w0_w4 above_1960 5451
+    w0_w4 above_1961 4613
+    w0_w4 above_1962 5642
+    w0_w4 above_1963 5024
+    w0_w4 above_1964 5066
+    w0_w4 above_1965 4908
+    w0_w4 above_1966 6055
+    w0_w4 above_1967 5417
+    w0_w4 above_1968 5778
+    w0_w4 above_1969 5309
+    w0_w4 above_1970 4858
+    w0_w4 above_1971 5596
+    w0_w4 above_1972 6230
+    w0_w4 above_1973 5552
+    w0_w4 above_1974 5304
+    w0_w4 above_1975 6236
+    w0_w4 above_1976 6262
+    w0_w4 above_1977 6018
+    w0_w4 above_1978 6149
+    w0_w4 above_1979 6051
+    w0_w4 above_1980 5756
+    w0_w4 above_1981 5829
+    w0_w4 above_1982 5896
+    w0_w4 above_1983 6131
+    w0_w4 above_1984 6429
+    w0_w4 above_1985 5695
+    w0_w4 above_1986 6201
+    w0_w4 above_1987 5723
+    w0_w4 above_1988 5416
+    w0_w4 above_1989 5774
+    w0_w4 above_1990 6291
+    w0_w4 above_1991 5839
+    w0_w4 above_1992 5954
+    w0_w4 above_1993 5822
+    w0_w4 above_1994 6218
+    w0_w4 above_1995 6884
+    w0_w4 above_1996 6254
+    w0_w4 above_1997 6100
+    w0_w4 above_1998 5866
+    w0_w4 above_1999 6090
+    w0_w4 above_2000 6586
+    w0_w4 below_1800 -507
+    w0_w4 below_1801 -531
+    w0_w4 below_1802 -575
+    w0_w4 below_1803 -579
+    w0_w4 below_1804 -566
+    w0_w4 below_1805 -608
+    w0_w4 below_1806 -611
+    w0_w4 below_1807 -568
+    w0_w4 below_1808 -590
+    w0_w4 below_1809 -697
+    w0_w4 below_1810 -691
+    w0_w4 below_1811 -760
+    w0_w4 below_1812 -726
+    w0_w4 below_1813 -682
+    w0_w4 below_1814 -771
+    w0_w4 below_1815 -847
+    w0_w4 below_1816 -798
+    w0_w4 below_1817 -845
+    w0_w4 below_1818 -728
+    w0_w4 below_1819 -837
+    w0_w4 below_1820 -960
+    w0_w4 below_1821 -902
+    w0_w4 below_1822 -967
+    w0_w4 below_1823 -1066
+    w0_w4 below_1824 -965
+    w0_w4 below_1825 -1124
+    w0_w4 below_1826 -1090
+    w0_w4 below_1827 -1036
+    w0_w4 below_1828 -1124
+    w0_w4 below_1829 -1093
+    w0_w4 below_1830 -1140
+    w0_w4 below_1831 -1185
+    w0_w4 below_1832 -1289
+    w0_w4 below_1833 -1278
+    w0_w4 below_1834 -1407
+    w0_w4 below_1835 -1436
+    w0_w4 below_1836 -1387
+    w0_w4 below_1837 -1380
+    w0_w4 below_1838 -1352
+    w0_w4 below_1839 -1442
+    w0_w4 below_1840 -1437
+    w0_w4 below_1841 -1584
+    w0_w4 below_1842 -1503
+    w0_w4 below_1843 -1570
+    w0_w4 below_1844 -1587
+    w0_w4 below_1845 -1710
+    w0_w4 below_1846 -1597
+    w0_w4 below_1847 -1838
+    w0_w4 below_1848 -1631
+    w0_w4 below_1849 -1666
+    w0_w4 below_1850 -1770
+    w0_w4 below_1851 -1896
+    w0_w4 below_1852 -1786
+    w0_w4 below_1853 -1841
+    w0_w4 below_1854 -1850
+    w0_w4 below_1855 -1829
+    w0_w4 below_1856 -1828
+    w0_w4 below_1857 -1762
+    w0_w4 below_1858 -2015
+    w0_w4 below_1859 -1929
+    w0_w4 below_1860 -2074
+    w0_w4 below_1861 -2064
+    w0_w4 below_1862 -1948
+    w0_w4 below_1863 -1898
+    w0_w4 below_1864 -2113
+    w0_w4 below_1865 -1969
+    w0_w4 below_1866 -2334
+    w0_w4 below_1867 -2163
+    w0_w4 below_1868 -2039
+    w0_w4 below_1869 -2236
+    w0_w4 below_1870 -2106
+    w0_w4 below_1871 -2297
+    w0_w4 below_1872 -2154
+    w0_w4 below_1873 -2116
+    w0_w4 below_1874 -2373
+    w0_w4 below_1875 -2112
+    w0_w4 below_1876 -2403
+    w0_w4 below_1877 -2502
+    w0_w4 below_1878 -2406
+    w0_w4 below_1879 -2317
+    w0_w4 below_1880 -2391
+    w0_w4 below_1881 -2418
+    w0_w4 below_1882 -2437
+    w0_w4 below_1883 -2492
+    w0_w4 below_1884 -2371
+    w0_w4 below_1885 -2501
+    w0_w4 below_1886 -2472
+    w0_w4 below_1887 -2691
+    w0_w4 below_1888 -2675
+    w0_w4 below_1889 -2624
+    w0_w4 below_1890 -2358
+    w0_w4 below_1891 -2622
+    w0_w4 below_1892 -2792
+    w0_w4 below_1893 -2905
+    w0_w4 below_1894 -2590
+    w0_w4 below_1895 -2851
+    w0_w4 below_1896 -2781
+    w0_w4 below_1897 -2558
+    w0_w4 below_1898 -2666
+    w0_w4 below_1899 -2744
+    w0_w4 below_1900 -2801
+    w0_w4 below_1901 -2741
+    w0_w4 below_1902 -2678
+    w0_w4 below_1903 -2725
+    w0_w4 below_1904 -2908
+    w0_w4 below_1905 -2861
+    w0_w4 below_1906 -2822
+    w0_w4 below_1907 -2942
+    w0_w4 below_1908 -2974
+    w0_w4 below_1909 -3214
+    w0_w4 below_1910 -2994
+    w0_w4 below_1911 -2856
+    w0_w4 below_1912 -3098
+    w0_w4 below_1913 -3142
+    w0_w4 below_1914 -3399
+    w0_w4 below_1915 -3256
+    w0_w4 below_1916 -3475
+    w0_w4 below_1917 -3455
+    w0_w4 below_1918 -3206
+    w0_w4 below_1919 -3248
+    w0_w4 below_1920 -3618
+    w0_w4 below_1921 -3512
+    w0_w4 below_1922 -3521
+    w0_w4 below_1923 -3968
+    w0_w4 below_1924 -3772
+    w0_w4 below_1925 -3624
+    w0_w4 below_1926 -3496
+    w0_w4 below_1927 -3811
+    w0_w4 below_1928 -4054
+    w0_w4 below_1929 -4272
+    w0_w4 below_1930 -3847
+    w0_w4 below_1931 -3951
+    w0_w4 below_1932 -4071
+    w0_w4 below_1933 -3872
+    w0_w4 below_1934 -4004
+    w0_w4 below_1935 -3786
+    w0_w4 below_1936 -3990
+    w0_w4 below_1937 -4067
+    w0_w4 below_1938 -3966
+    w0_w4 below_1939 -4504
+    w0_w4 below_1940 -4046
+    w0_w4 below_1941 -4729
+    w0_w4 below_1942 -4722
+    w0_w4 below_1943 -4450
+    w0_w4 below_1944 -4442
+    w0_w4 below_1945 -4770
+    w0_w4 below_1946 -4856
+    w0_w4 below_1947 -4472
+    w0_w4 below_1948 -5005
+    w0_w4 below_1949 -4740
+    w0_w4 below_1950 -4474
+    w0_w4 below_1951 -4974
+    w0_w4 below_1952 -4477
+    w0_w4 below_1953 -4725
+    w0_w4 below_1954 -4800
+    w0_w4 below_1955 -5121
+    w0_w4 below_1956 -4657
+    w0_w4 below_1957 -4986
+    w0_w4 below_1958 -5053
+    w0_w4 below_1959 -5081
+    w0_w4 below_1960 -5451
+    w0_w4 below_1961 -4613
+    w0_w4 below_1962 -5642
+    w0_w4 below_1963 -5024
+    w0_w4 below_1964 -5066
+    w0_w4 below_1965 -4908
+    w0_w4 below_1966 -6055
+    w0_w4 below_1967 -5417
+    w0_w4 below_1968 -5778
+    w0_w4 below_1969 -5309
+    w0_w4 below_1970 -4858
+    w0_w4 below_1971 -5596
+    w0_w4 below_1972 -6230
+    w0_w4 below_1973 -5552
+    w0_w4 below_1974 -5304
+    w0_w4 below_1975 -6236
+    w0_w4 below_1976 -6262
+    w0_w4 below_1977 -6018
+    w0_w4 below_1978 -6149
+    w0_w4 below_1979 -6051
+    w0_w4 below_1980 -5756
+    w0_w4 below_1981 -5829
+    w0_w4 below_1982 -5896
+    w0_w4 below_1983 -6131
+    w0_w4 below_1984 -6429
+    w0_w4 below_1985 -5695
+    w0_w4 below_1986 -6201
+    w0_w4 below_1987 -5723
+    w0_w4 below_1988 -5416
+    w0_w4 below_1989 -5774
+    w0_w4 below_1990 -6291
+    w0_w4 below_1991 -5839
+    w0_w4 below_1992 -5954
+    w0_w4 below_1993 -5822
+    w0_w4 below_1994 -6218
+    w0_w4 below_1995 -6884
+    w0_w4 below_1996 -6254
+    w0_w4 below_1997 -6100
+    w0_w4 below_1998 -5866
+    w0_w4 below_1999 -6090
+    w0_w4 below_2000 -6586
+    w1_w0_w88 above_1800 906
+    w1_w0_w88 above_1801 929
+    w1_w0_w88 above_1802 985
+    w1_w0_w88 above_1803 1031
+    w1_w0_w88 above_1804 932
+    w1_w0_w88 above_1805 1019
+    w1_w0_w88 above_1806 959
+    w1_w0_w88 above_1807 1094
+    w1_w0_w88 above_1808 1002
+    w1_w0_w88 above_1809 1070
+    w1_w0_w88 above_1810 1024
+    w1_w0_w88 above_1811 1083
+    w1_w0_w88 above_1812 1074
+    w1_w0_w88 above_1813 1168
+    w1_w0_w88 above_1814 1034
+    w1_w0_w88 above_1815 1121
+    w1_w0_w88 above_1816 1161
+    w1_w0_w88 above_1817 1188
+    w1_w0_w88 above_1818 1138
+    w1_w0_w88 above_1819 1065
+    w1_w0_w88 above_1820 1132
+    w1_w0_w88 above_1821 1247
+    w1_w0_w88 above_1822 1300
+    w1_w0_w88 above_1823 1101
+    w1_w0_w88 above_1824 1183
+    w1_w0_w88 above_1825 1114
+    w1_w0_w88 above_1826 1235
+    w1_w0_w88 above_1827 1268
+    w1_w0_w88 above_1828 1206
+    w1_w0_w88 above_1829 1232
+    w1_w0_w88 above_1830 1264
+    w1_w0_w88 above_1831 1269
+    w1_w0_w88 above_1832 1250
+    w1_w0_w88 above_1833 1225
+    w1_w0_w88 above_1834 1244
+    w1_w0_w88 above_1835 1262
+    w1_w0_w88 above_1836 1233
+    w1_w0_w88 above_1837 1246
+    w1_w0_w88 above_1838 1333
+    w1_w0_w88 above_1839 1410
+    w1_w0_w88 above_1840 1333
+    w1_w0_w88 above_1841 1312
+    w1_w0_w88 above_1842 1189
+    w1_w0_w88 above_1843 1320
+    w1_w0_w88 above_1844 1351
+    w1_w0_w88 above_1845 1354
+    w1_w0_w88 above_1846 1402
+    w1_w0_w88 above_1847 1499
+    w1_w0_w88 above_1848 1296
+    w1_w0_w88 above_1849 1465
+    w1_w0_w88 above_1850 1404
+    w1_w0_w88 above_1851 1460
+    w1_w0_w88 above_1852 1487
+    w1_w0_w88 above_1853 1374
+    w1_w0_w88 above_1854 1488
+    w1_w0_w88 above_1855 1458
+    w1_w0_w88 above_1856 1397
+    w1_w0_w88 above_1857 1443
+    w1_w0_w88 above_1858 1457
+    w1_w0_w88 above_1859 1482
+    w1_w0_w88 above_1860 1458
+    w1_w0_w88 above_1861 1694
+    w1_w0_w88 above_1862 1571
+    w1_w0_w88 above_1863 1518
+    w1_w0_w88 above_1864 1419
+    w1_w0_w88 above_1865 1449
+    w1_w0_w88 above_1866 1523
+    w1_w0_w88 above_1867 1450
+    w1_w0_w88 above_1868 1403
+    w1_w0_w88 above_1869 1561
+    w1_w0_w88 above_1870 1504
+    w1_w0_w88 above_1871 1603
+    w1_w0_w88 above_1872 1594
+    w1_w0_w88 above_1873 1560
+    w1_w0_w88 above_1874 1633
+    w1_w0_w88 above_1875 1628
+    w1_w0_w88 above_1876 1461
+    w1_w0_w88 above_1877 1522
+    w1_w0_w88 above_1878 1532
+    w1_w0_w88 above_1879 1536
+    w1_w0_w88 above_1880 1465
+    w1_w0_w88 above_1881 1717
+    w1_w0_w88 above_1882 1532
+    w1_w0_w88 above_1883 1526
+    w1_w0_w88 above_1884 1555
+    w1_w0_w88 above_1885 1504
+    w1_w0_w88 above_1886 1558
+    w1_w0_w88 above_1887 1577
+    w1_w0_w88 above_1888 1529
+    w1_w0_w88 above_1889 1638
+    w1_w0_w88 above_1890 1590
+    w1_w0_w88 above_1891 1708
+    w1_w0_w88 above_1892 1595
+    w1_w0_w88 above_1893 1508
+    w1_w0_w88 above_1894 1620
+    w1_w0_w88 above_1895 1545
+    w1_w0_w88 above_1896 1644
+    w1_w0_w88 above_1897 1551
+    w1_w0_w88 above_1898 1515
+    w1_w0_w88 above_1899 1491
+    w1_w0_w88 above_1900 1484
+    w1_w0_w88 above_1901 1490
+    w1_w0_w88 above_1902 1583
+    w1_w0_w88 above_1903 1674
+    w1_w0_w88 above_1904 1601
+    w1_w0_w88 above_1905 1561
+    w1_w0_w88 above_1906 1617
+    w1_w0_w88 above_1907 1469
+    w1_w0_w88 above_1908 1653
+    w1_w0_w88 above_1909 1601
+    w1_w0_w88 above_1910 1637
+    w1_w0_w88 above_1911 1448
+    w1_w0_w88 above_1912 1576
+    w1_w0_w88 above_1913 1792
+    w1_w0_w88 above_1914 1545
+    w1_w0_w88 above_1915 1599
+    w1_w0_w88 above_1916 1753
+    w1_w0_w88 above_1917 1554
+    w1_w0_w88 above_1918 1639
+    w1_w0_w88 above_1919 1733
+    w1_w0_w88 above_1920 1596
+    w1_w0_w88 above_1921 1631
+    w1_w0_w88 above_1922 1532
+    w1_w0_w88 above_1923 1667
+    w1_w0_w88 above_1924 1785
+    w1_w0_w88 above_1925 1678
+    w1_w0_w88 above_1926 1624
+    w1_w0_w88 above_1927 1698
+    w1_w0_w88 above_1928 1591
+    w1_w0_w88 above_1929 1572
+    w1_w0_w88 above_1930 1657
+    w1_w0_w88 above_1931 1588
+    w1_w0_w88 above_1932 1582
+    w1_w0_w88 above_1933 1758
+    w1_w0_w88 above_1934 1656
+    w1_w0_w88 above_1935 1607
+    w1_w0_w88 above_1936 1712
+    w1_w0_w88 above_1937 1570
+    w1_w0_w88 above_1938 1589
+    w1_w0_w88 above_1939 1489
+    w1_w0_w88 above_1940 1598
+    w1_w0_w88 above_1941 1586
+    w1_w0_w88 above_1942 1555
+    w1_w0_w88 above_1943 1713
+    w1_w0_w88 above_1944 1692
+    w1_w0_w88 above_1945 1751
+    w1_w0_w88 above_1946 1627
+    w1_w0_w88 above_1947 1530
+    w1_w0_w88 above_1948 1573
+    w1_w0_w88 above_1949 1591
+    w1_w0_w88 above_1950 1705
+    w1_w0_w88 above_1951 1615
+    w1_w0_w88 above_1952 1740
+    w1_w0_w88 above_1953 1586
+    w1_w0_w88 above_1954 1697
+    w1_w0_w88 above_1955 1427
+    w1_w0_w88 above_1956 1658
+    w1_w0_w88 above_1957 1632
+    w1_w0_w88 above_1958 1621
+    w1_w0_w88 above_1959 1749
+    w1_w0_w88 above_1960 1637
+    w1_w0_w88 above_1961 1727
+    w1_w0_w88 above_1962 1690
+    w1_w0_w88 above_1963 1708
+    w1_w0_w88 above_1964 1685
+    w1_w0_w88 above_1965 1555
+    w1_w0_w88 above_1966 1595
+    w1_w0_w88 above_1967 1549
+    w1_w0_w88 above_1968 1681
+    w1_w0_w88 above_1969 1639
+    w1_w0_w88 above_1970 1652
+    w1_w0_w88 above_1971 1674
+    w1_w0_w88 above_1972 1718
+    w1_w0_w88 above_1973 1699
+    w1_w0_w88 above_1974 1728
+    w1_w0_w88 above_1975 1650
+    w1_w0_w88 above_1976 1766
+    w1_w0_w88 above_1977 1642
+    w1_w0_w88 above_1978 1714
+    w1_w0_w88 above_1979 1624
+    w1_w0_w88 above_1980 1471
+    w1_w0_w88 above_1981 1700
+    w1_w0_w88 above_1982 1674
+    w1_w0_w88 above_1983 1667
+    w1_w0_w88 above_1984 1643
+    w1_w0_w88 above_1985 1587
+    w1_w0_w88 above_1986 1763
+    w1_w0_w88 above_1987 1595
+    w1_w0_w88 above_1988 1599
+    w1_w0_w88 above_1989 1659
+    w1_w0_w88 above_1990 1846
+    w1_w0_w88 above_1991 1594
+    w1_w0_w88 above_1992 1680
+    w1_w0_w88 above_1993 1573
+    w1_w0_w88 above_1994 1621
+    w1_w0_w88 above_1995 1617
+    w1_w0_w88 above_1996 1696
+    w1_w0_w88 above_1997 1572
+    w1_w0_w88 above_1998 1682
+    w1_w0_w88 above_1999 1622
+    w1_w0_w88 above_2000 1669
+    w1_w0_w88 below_1800 -906
+    w1_w0_w88 below_1801 -929
+    w1_w0_w88 below_1802 -985
+    w1_w0_w88 below_1803 -1031
+    w1_w0_w88 below_1804 -932
+    w1_w0_w88 below_1805 -1019
+    w1_w0_w88 below_1806 -959
+    w1_w0_w88 below_1807 -1094
+    w1_w0_w88 below_1808 -1002
+    w1_w0_w88 below_1809 -1070
+    w1_w0_w88 below_1810 -1024
+    w1_w0_w88 below_1811 -1083
+    w1_w0_w88 below_1812 -1074
+    w1_w0_w88 below_1813 -1168
+    w1_w0_w88 below_1814 -1034
+    w1_w0_w88 below_1815 -1121
+    w1_w0_w88 below_1816 -1161
+    w1_w0_w88 below_1817 -1188
+    w1_w0_w88 below_1818 -1138
+    w1_w0_w88 below_1819 -1065
+    w1_w0_w88 below_1820 -1132
+    w1_w0_w88 below_1821 -1247
+    w1_w0_w88 below_1822 -1300
+    w1_w0_w88 below_1823 -1101
+    w1_w0_w88 below_1824 -1183
+    w1_w0_w88 below_1825 -1114
+    w1_w0_w88 below_1826 -1235
+    w1_w0_w88 below_1827 -1268
+    w1_w0_w88 below_1828 -1206
+    w1_w0_w88 below_1829 -1232
+    w1_w0_w88 below_1830 -1264
+    w1_w0_w88 below_1831 -1269
+    w1_w0_w88 below_1832 -1250
+    w1_w0_w88 below_1833 -1225
+    w1_w0_w88 below_1834 -1244
+    w1_w0_w88 below_1835 -1262
+    w1_w0_w88 below_1836 -1233
+    w1_w0_w88 below_1837 -1246
+    w1_w0_w88 below_1838 -1333
+    w1_w0_w88 below_1839 -1410
+    w1_w0_w88 below_1840 -1333
+    w1_w0_w88 below_1841 -1312
+    w1_w0_w88 below_1842 -1189
+    w1_w0_w88 below_1843 -1320
+    w1_w0_w88 below_1844 -1351
+    w1_w0_w88 below_1845 -1354
+    w1_w0_w88 below_1846 -1402
+    w1_w0_w88 below_1847 -1499
+    w1_w0_w88 below_1848 -1296
+    w1_w0_w88 below_1849 -1465
+    w1_w0_w88 below_1850 -1404
+    w1_w0_w88 below_1851 -1460
+    w1_w0_w88 below_1852 -1487
+    w1_w0_w88 below_1853 -1374
+    w1_w0_w88 below_1854 -1488
+    w1_w0_w88 below_1855 -1458
+    w1_w0_w88 below_1856 -1397
+    w1_w0_w88 below_1857 -1443
+    w1_w0_w88 below_1858 -1457
+    w1_w0_w88 below_1859 -1482
+    w1_w0_w88 below_1860 -1458
+    w1_w0_w88 below_1861 -1694
+    w1_w0_w88 below_1862 -1571
+    w1_w0_w88 below_1863 -1518
+    w1_w0_w88 below_1864 -1419
+    w1_w0_w88 below_1865 -1449
+    w1_w0_w88 below_1866 -1523
+    w1_w0_w88 below_1867 -1450
+    w1_w0_w88 below_1868 -1403
+    w1_w0_w88 below_1869 -1561
+    w1_w0_w88 below_1870 -1504
+    w1_w0_w88 below_1871 -1603
+    w1_w0_w88 below_1872 -1594
+    w1_w0_w88 below_1873 -1560
+    w1_w0_w88 below_1874 -1633
+    w1_w0_w88 below_1875 -1628
+    w1_w0_w88 below_1876 -1461
+    w1_w0_w88 below_1877 -1522
+    w1_w0_w88 below_1878 -1532
+    w1_w0_w88 below_1879 -1536
+    w1_w0_w88 below_1880 -1465
+    w1_w0_w88 below_1881 -1717
+    w1_w0_w88 below_1882 -1532
+    w1_w0_w88 below_1883 -1526
+    w1_w0_w88 below_1884 -1555
+    w1_w0_w88 below_1885 -1504
+    w1_w0_w88 below_1886 -1558
+    w1_w0_w88 below_1887 -1577
+    w1_w0_w88 below_1888 -1529
+    w1_w0_w88 below_1889 -1638
+    w1_w0_w88 below_1890 -1590
+    w1_w0_w88 below_1891 -1708
+    w1_w0_w88 below_1892 -1595
+    w1_w0_w88 below_1893 -1508
+    w1_w0_w88 below_1894 -1620
+    w1_w0_w88 below_1895 -1545
+    w1_w0_w88 below_1896 -1644
+    w1_w0_w88 below_1897 -1551
+    w1_w0_w88 below_1898 -1515
+    w1_w0_w88 below_1899 -1491
+    w1_w0_w88 below_1900 -1484
+    w1_w0_w88 below_1901 -1490
+    w1_w0_w88 below_1902 -1583
+    w1_w0_w88 below_1903 -1674
+    w1_w0_w88 below_1904 -1601
+    w1_w0_w88 below_1905 -1561
+    w1_w0_w88 below_1906 -1617
+    w1_w0_w88 below_1907 -1469
+    w1_w0_w88 below_1908 -1653
+    w1_w0_w88 below_1909 -1601
+    w1_w0_w88 below_1910 -1637
+    w1_w0_w88 below_1911 -1448
+    w1_w0_w88 below_1912 -1576
+    w1_w0_w88 below_1913 -1792
+    w1_w0_w88 below_1914 -1545
+    w1_w0_w88 below_1915 -1599
+    w1_w0_w88 below_1916 -1753
+    w1_w0_w88 below_1917 -1554
+    w1_w0_w88 below_1918 -1639
+    w1_w0_w88 below_1919 -1733
+    w1_w0_w88 below_1920 -1596
+    w1_w0_w88 below_1921 -1631
+    w1_w0_w88 below_1922 -1532
+    w1_w0_w88 below_1923 -1667
+    w1_w0_w88 below_1924 -1785
+    w1_w0_w88 below_1925 -1678
+    w1_w0_w88 below_1926 -1624
+    w1_w0_w88 below_1927 -1698
+    w1_w0_w88 below_1928 -1591
+    w1_w0_w88 below_1929 -1572
+    w1_w0_w88 below_1930 -1657
+    w1_w0_w88 below_1931 -1588
+    w1_w0_w88 below_1932 -1582
+    w1_w0_w88 below_1933 -1758
+    w1_w0_w88 below_1934 -1656
+    w1_w0_w88 below_1935 -1607
+    w1_w0_w88 below_1936 -1712
+    w1_w0_w88 below_1937 -1570
+    w1_w0_w88 below_1938 -1589
+    w1_w0_w88 below_1939 -1489
+    w1_w0_w88 below_1940 -1598
+    w1_w0_w88 below_1941 -1586
+    w1_w0_w88 below_1942 -1555
+    w1_w0_w88 below_1943 -1713
+    w1_w0_w88 below_1944 -1692
+    w1_w0_w88 below_1945 -1751
+    w1_w0_w88 below_1946 -1627
+    w1_w0_w88 below_1947 -1530
+    w1_w0_w88 below_1948 -1573
+    w1_w0_w88 below_1949 -1591
+    w1_w0_w88 below_1950 -1705
+    w1_w0_w88 below_1951 -1615
+    w1_w0_w88 below_1952 -1740
+    w1_w0_w88 below_1953 -1586
+    w1_w0_w88 below_1954 -1697
+    w1_w0_w88 below_1955 -1427
+    w1_w0_w88 below_1956 -1658
+    w1_w0_w88 below_1957 -1632
+    w1_w0_w88 below_1958 -1621
+    w1_w0_w88 below_1959 -1749
+    w1_w0_w88 below_1960 -1637
+    w1_w0_w88 below_1961 -1727
+    w1_w0_w88 below_1962 -1690
+    w1_w0_w88 below_1963 -1708
+    w1_w0_w88 below_1964 -1685
+    w1_w0_w88 below_1965 -1555
+    w1_w0_w88 below_1966 -1595
+    w1_w0_w88 below_1967 -1549
+    w1_w0_w88 below_1968 -1681
+    w1_w0_w88 below_1969 -1639
+    w1_w0_w88 below_1970 -1652
+    w1_w0_w88 below_1971 -1674
+    w1_w0_w88 below_1972 -1718
+    w1_w0_w88 below_1973 -1699
+    w1_w0_w88 below_1974 -1728
+    w1_w0_w88 below_1975 -1650
+    w1_w0_w88 below_1976 -1766
+    w1_w0_w88 below_1977 -1642
+    w1_w0_w88 below_1978 -1714
+    w1_w0_w88 below_1979 -1624
+    w1_w0_w88 below_1980 -1471
+    w1_w0_w88 below_1981 -1700
+    w1_w0_w88 below_1982 -1674
+    w1_w0_w88 below_1983 -1667
+    w1_w0_w88 below_1984 -1643
+    w1_w0_w88 below_1985 -1587
+    w1_w0_w88 below_1986 -1763
+    w1_w0_w88 below_1987 -1595
+    w1_w0_w88 below_1988 -1599
+    w1_w0_w88 below_1989 -1659
+    w1_w0_w88 below_1990 -1846
+    w1_w0_w88 below_1991 -1594
+    w1_w0_w88 below_1992 -1680
+    w1_w0_w88 below_1993 -1573
+    w1_w0_w88 below_1994 -1621
+    w1_w0_w88 below_1995 -1617
+    w1_w0_w88 below_1996 -1696
+    w1_w0_w88 below_1997 -1572
+    w1_w0_w88 below_1998 -1682
+    w1_w0_w88 below_1999 -1622
+    w1_w0_w88 below_2000 -1669
+    w1_w0_w10 above_1800 63
+    w1_w0_w10 above_1801 60
+    w1_w0_w10 above_1802 69
+    w1_w0_w10 above_1803 74
+    w1_w0_w10 above_1804 82
+    w1_w0_w10 above_1805 88
+    w1_w0_w10 above_1806 98
+    w1_w0_w10 above_1807 104
+    w1_w0_w10 above_1808 116
+    w1_w0_w10 above_1809 117
+    w1_w0_w10 above_1810 139
+    w1_w0_w10 above_1811 154
+    w1_w0_w10 above_1812 158
+    w1_w0_w10 above_1813 166
+    w1_w0_w10 above_1814 197
+    w1_w0_w10 above_1815 207
+    w1_w0_w10 above_1816 221
+    w1_w0_w10 above_1817 237
+    w1_w0_w10 above_1818 233
+    w1_w0_w10 above_1819 270
+    w1_w0_w10 above_1820 301
+    w1_w0_w10 above_1821 293
+    w1_w0_w10 above_1822 333
+    w1_w0_w10 above_1823 334
+    w1_w0_w10 above_1824 331
+    w1_w0_w10 above_1825 362
+    w1_w0_w10 above_1826 408
+    w1_w0_w10 above_1827 415
+    w1_w0_w10 above_1828 413
+    w1_w0_w10 above_1829 434
+    w1_w0_w10 above_1830 433
+    w1_w0_w10 above_1831 492
+    w1_w0_w10 above_1832 507
+    w1_w0_w10 above_1833 516
+    w1_w0_w10 above_1834 556
+    w1_w0_w10 above_1835 549
+    w1_w0_w10 above_1836 530
+    w1_w0_w10 above_1837 611
+    w1_w0_w10 above_1838 564
+    w1_w0_w10 above_1839 593
+    w1_w0_w10 above_1840 582
+    w1_w0_w10 above_1841 645
+    w1_w0_w10 above_1842 603
+    w1_w0_w10 above_1843 572
+    w1_w0_w10 above_1844 610
+    w1_w0_w10 above_1845 578
+    w1_w0_w10 above_1846 656
+    w1_w0_w10 above_1847 636
+    w1_w0_w10 above_1848 655
+    w1_w0_w10 above_1849 621
+    w1_w0_w10 above_1850 652
+    w1_w0_w10 above_1851 622
+    w1_w0_w10 above_1852 663
+    w1_w0_w10 above_1853 620
+    w1_w0_w10 above_1854 720
+    w1_w0_w10 above_1855 656
+    w1_w0_w10 above_1856 681
+    w1_w0_w10 above_1857 640
+    w1_w0_w10 above_1858 683
+    w1_w0_w10 above_1859 664
+    w1_w0_w10 above_1860 666
+    w1_w0_w10 above_1861 665
+    w1_w0_w10 above_1862 691
+    w1_w0_w10 above_1863 705
+    w1_w0_w10 above_1864 653
+    w1_w0_w10 above_1865 705
+    w1_w0_w10 above_1866 635
+    w1_w0_w10 above_1867 687
+    w1_w0_w10 above_1868 709
+    w1_w0_w10 above_1869 661
+    w1_w0_w10 above_1870 706
+    w1_w0_w10 above_1871 670
+    w1_w0_w10 above_1872 708
+    w1_w0_w10 above_1873 624
+    w1_w0_w10 above_1874 684
+    w1_w0_w10 above_1875 661
+    w1_w0_w10 above_1876 716
+    w1_w0_w10 above_1877 743
+    w1_w0_w10 above_1878 722
+    w1_w0_w10 above_1879 758
+    w1_w0_w10 above_1880 675
+    w1_w0_w10 above_1881 723
+    w1_w0_w10 above_1882 675
+    w1_w0_w10 above_1883 711
+    w1_w0_w10 above_1884 664
+    w1_w0_w10 above_1885 709
+    w1_w0_w10 above_1886 665
+    w1_w0_w10 above_1887 769
+    w1_w0_w10 above_1888 725
+    w1_w0_w10 above_1889 731
+    w1_w0_w10 above_1890 692
+    w1_w0_w10 above_1891 728
+    w1_w0_w10 above_1892 708
+    w1_w0_w10 above_1893 688
+    w1_w0_w10 above_1894 689
+    w1_w0_w10 above_1895 740
+    w1_w0_w10 above_1896 695
+    w1_w0_w10 above_1897 771
+    w1_w0_w10 above_1898 739
+    w1_w0_w10 above_1899 768
+    w1_w0_w10 above_1900 699
+    w1_w0_w10 above_1901 725
+    w1_w0_w10 above_1902 775
+    w1_w0_w10 above_1903 755
+    w1_w0_w10 above_1904 712
+    w1_w0_w10 above_1905 684
+    w1_w0_w10 above_1906 781
+    w1_w0_w10 above_1907 746
+    w1_w0_w10 above_1908 796
+    w1_w0_w10 above_1909 767
+    w1_w0_w10 above_1910 690
+    w1_w0_w10 above_1911 742
+    w1_w0_w10 above_1912 758
+    w1_w0_w10 above_1913 775
+    w1_w0_w10 above_1914 716
+    w1_w0_w10 above_1915 737
+    w1_w0_w10 above_1916 773
+    w1_w0_w10 above_1917 758
+    w1_w0_w10 above_1918 766
+    w1_w0_w10 above_1919 734
+    w1_w0_w10 above_1920 773
+    w1_w0_w10 above_1921 747
+    w1_w0_w10 above_1922 782
+    w1_w0_w10 above_1923 785
+    w1_w0_w10 above_1924 766
+    w1_w0_w10 above_1925 787
+    w1_w0_w10 above_1926 727
+    w1_w0_w10 above_1927 763
+    w1_w0_w10 above_1928 790
+    w1_w0_w10 above_1929 794
+    w1_w0_w10 above_1930 735
+    w1_w0_w10 above_1931 857
+    w1_w0_w10 above_1932 814
+    w1_w0_w10 above_1933 726
+    w1_w0_w10 above_1934 809
+    w1_w0_w10 above_1935 785
+    w1_w0_w10 above_1936 740
+    w1_w0_w10 above_1937 784
+    w1_w0_w10 above_1938 800
+    w1_w0_w10 above_1939 769
+    w1_w0_w10 above_1940 798
+    w1_w0_w10 above_1941 766
+    w1_w0_w10 above_1942 742
+    w1_w0_w10 above_1943 846
+    w1_w0_w10 above_1944 874
+    w1_w0_w10 above_1945 735
+    w1_w0_w10 above_1946 816
+    w1_w0_w10 above_1947 766
+    w1_w0_w10 above_1948 771
+    w1_w0_w10 above_1949 789
+    w1_w0_w10 above_1950 795
+    w1_w0_w10 above_1951 825
+    w1_w0_w10 above_1952 788
+    w1_w0_w10 above_1953 813
+    w1_w0_w10 above_1954 819
+    w1_w0_w10 above_1955 816
+    w1_w0_w10 above_1956 858
+    w1_w0_w10 above_1957 859
+    w1_w0_w10 above_1958 774
+    w1_w0_w10 above_1959 867
+    w1_w0_w10 above_1960 799
+    w1_w0_w10 above_1961 875
+    w1_w0_w10 above_1962 832
+    w1_w0_w10 above_1963 810
+    w1_w0_w10 above_1964 881
+    w1_w0_w10 above_1965 847
+    w1_w0_w10 above_1966 804
+    w1_w0_w10 above_1967 838
+    w1_w0_w10 above_1968 840
+    w1_w0_w10 above_1969 810
+    w1_w0_w10 above_1970 865
+    w1_w0_w10 above_1971 776
+    w1_w0_w10 above_1972 775
+    w1_w0_w10 above_1973 894
+    w1_w0_w10 above_1974 843
+    w1_w0_w10 above_1975 817
+    w1_w0_w10 above_1976 812
+    w1_w0_w10 above_1977 820
+    w1_w0_w10 above_1978 740
+    w1_w0_w10 above_1979 812
+    w1_w0_w10 above_1980 841
+    w1_w0_w10 above_1981 817
+    w1_w0_w10 above_1982 842
+    w1_w0_w10 above_1983 787
+    w1_w0_w10 above_1984 869
+    w1_w0_w10 above_1985 802
+    w1_w0_w10 above_1986 842
+    w1_w0_w10 above_1987 837
+    w1_w0_w10 above_1988 849
+    w1_w0_w10 above_1989 818
+    w1_w0_w10 above_1990 725
+    w1_w0_w10 above_1991 855
+    w1_w0_w10 above_1992 940
+    w1_w0_w10 above_1993 815
+    w1_w0_w10 above_1994 880
+    w1_w0_w10 above_1995 877
+    w1_w0_w10 above_1996 883
+    w1_w0_w10 above_1997 815
+    w1_w0_w10 above_1998 844
+    w1_w0_w10 above_1999 834
+    w1_w0_w10 above_2000 804
+    w1_w0_w10 below_1800 -63
+    w1_w0_w10 below_1801 -60
+    w1_w0_w10 below_1802 -69
+    w1_w0_w10 below_1803 -74
+    w1_w0_w10 below_1804 -82
+    w1_w0_w10 below_1805 -88
+    w1_w0_w10 below_1806 -98
+    w1_w0_w10 below_1807 -104
+    w1_w0_w10 below_1808 -116
+    w1_w0_w10 below_1809 -117
+    w1_w0_w10 below_1810 -139
+    w1_w0_w10 below_1811 -154
+    w1_w0_w10 below_1812 -158
+    w1_w0_w10 below_1813 -166
+    w1_w0_w10 below_1814 -197
+    w1_w0_w10 below_1815 -207
+    w1_w0_w10 below_1816 -221
+    w1_w0_w10 below_1817 -237
+    w1_w0_w10 below_1818 -233
+    w1_w0_w10 below_1819 -270
+    w1_w0_w10 below_1820 -301
+    w1_w0_w10 below_1821 -293
+    w1_w0_w10 below_1822 -333
+    w1_w0_w10 below_1823 -334
+    w1_w0_w10 below_1824 -331
+    w1_w0_w10 below_1825 -362
+    w1_w0_w10 below_1826 -408
+    w1_w0_w10 below_1827 -415
+    w1_w0_w10 below_1828 -413
+    w1_w0_w10 below_1829 -434
+    w1_w0_w10 below_1830 -433
+    w1_w0_w10 below_1831 -492
+    w1_w0_w10 below_1832 -507
+    w1_w0_w10 below_1833 -516
+    w1_w0_w10 below_1834 -556
+    w1_w0_w10 below_1835 -549
+    w1_w0_w10 below_1836 -530
+    w1_w0_w10 below_1837 -611
+    w1_w0_w10 below_1838 -564
+    w1_w0_w10 below_1839 -593
+    w1_w0_w10 below_1840 -582
+    w1_w0_w10 below_1841 -645
+    w1_w0_w10 below_1842 -603
+    w1_w0_w10 below_1843 -572
+    w1_w0_w10 below_1844 -610
+    w1_w0_w10 below_1845 -578
+    w1_w0_w10 below_1846 -656
+    w1_w0_w10 below_1847 -636
+    w1_w0_w10 below_1848 -655
+    w1_w0_w10 below_1849 -621
+    w1_w0_w10 below_1850 -652
+    w1_w0_w10 below_1851 -622
+    w1_w0_w10 below_1852 -663
+    w1_w0_w10 below_1853 -620
+    w1_w0_w10 below_1854 -720
+    w1_w0_w10 below_1855 -656
+    w1_w0_w10 below_1856 -681
+    w1_w0_w10 below_1857 -640
+    w1_w0_w10 below_1858 -683
+    w1_w0_w10 below_1859 -664
+    w1_w0_w10 below_1860 -666
+    w1_w0_w10 below_1861 -665
+    w1_w0_w10 below_1862 -691
+    w1_w0_w10 below_1863 -705
+    w1_w0_w10 below_1864 -653
+    w1_w0_w10 below_1865 -705
+    w1_w0_w10 below_1866 -635
+    w1_w0_w10 below_1867 -687
+    w1_w0_w10 below_1868 -709
+    w1_w0_w10 below_1869 -661
+    w1_w0_w10 below_1870 -706
+    w1_w0_w10 below_1871 -670
+    w1_w0_w10 below_1872 -708
+    w1_w0_w10 below_1873 -624
+    w1_w0_w10 below_1874 -684
+    w1_w0_w10 below_1875 -661
+    w1_w0_w10 below_1876 -716
+    w1_w0_w10 below_1877 -743
+    w1_w0_w10 below_1878 -722
+    w1_w0_w10 below_1879 -758
+    w1_w0_w10 below_1880 -675
+    w1_w0_w10 below_1881 -723
+    w1_w0_w10 below_1882 -675
+    w1_w0_w10 below_1883 -711
+    w1_w0_w10 below_1884 -664
+    w1_w0_w10 below_1885 -709
+    w1_w0_w10 below_1886 -665
+    w1_w0_w10 below_1887 -769
+    w1_w0_w10 below_1888 -725
+    w1_w0_w10 below_1889 -731
+    w1_w0_w10 below_1890 -692
+    w1_w0_w10 below_1891 -728
+    w1_w0_w10 below_1892 -708
+    w1_w0_w10 below_1893 -688
+    w1_w0_w10 below_1894 -689
+    w1_w0_w10 below_1895 -740
+    w1_w0_w10 below_1896 -695
+    w1_w0_w10 below_1897 -771
+    w1_w0_w10 below_1898 -739
+    w1_w0_w10 below_1899 -768
+    w1_w0_w10 below_1900 -699
+    w1_w0_w10 below_1901 -725
+    w1_w0_w10 below_1902 -775
+    w1_w0_w10 below_1903 -755
+    w1_w0_w10 below_1904 -712
+    w1_w0_w10 below_1905 -684
+    w1_w0_w10 below_1906 -781
+    w1_w0_w10 below_1907 -746
+    w1_w0_w10 below_1908 -796
+    w1_w0_w10 below_1909 -767
+    w1_w0_w10 below_1910 -690
+    w1_w0_w10 below_1911 -742
+    w1_w0_w10 below_1912 -758
+    w1_w0_w10 below_1913 -775
+    w1_w0_w10 below_1914 -716
+    w1_w0_w10 below_1915 -737
+    w1_w0_w10 below_1916 -773
+    w1_w0_w10 below_1917 -758
+    w1_w0_w10 below_1918 -766
+    w1_w0_w10 below_1919 -734
+    w1_w0_w10 below_1920 -773
+    w1_w0_w10 below_1921 -747
+    w1_w0_w10 below_1922 -782
+    w1_w0_w10 below_1923 -785
+    w1_w0_w10 below_1924 -766
+    w1_w0_w10 below_1925 -787
+    w1_w0_w10 below_1926 -727
+    w1_w0_w10 below_1927 -763
+    w1_w0_w10 below_1928 -790
+    w1_w0_w10 below_1929 -794
+    w1_w0_w10 below_1930 -735
+    w1_w0_w10 below_1931 -857
+    w1_w0_w10 below_1932 -814
+    w1_w0_w10 below_1933 -726
+    w1_w0_w10 below_1934 -809
+    w1_w0_w10 below_1935 -785
+    w1_w0_w10 below_1936 -740
+    w1_w0_w10 below_1937 -784
+    w1_w0_w10 below_1938 -800
+    w1_w0_w10 below_1939 -769
+    w1_w0_w10 below_1940 -798
+    w1_w0_w10 below_1941 -766
+    w1_w0_w10 below_1942 -742
+    w1_w0_w10 below_1943 -846
+    w1_w0_w10 below_1944 -874
+    w1_w0_w10 below_1945 -735
+    w1_w0_w10 below_1946 -816
+    w1_w0_w10 below_1947 -766
+    w1_w0_w10 below_1948 -771
+    w1_w0_w10 below_1949 -789
+    w1_w0_w10 below_1950 -795
+    w1_w0_w10 below_1951 -825
+    w1_w0_w10 below_1952 -788
+    w1_w0_w10 below_1953 -813
+    w1_w0_w10 below_1954 -819
+    w1_w0_w10 below_1955 -816
+    w1_w0_w10 below_1956 -858
+    w1_w0_w10 below_1957 -859
+    w1_w0_w10 below_1958 -774
+    w1_w0_w10 below_1959 -867
+    w1_w0_w10 below_1960 -799
+    w1_w0_w10 below_1961 -875
+    w1_w0_w10 below_1962 -832
+    w1_w0_w10 below_1963 -810
+    w1_w0_w10 below_1964 -881
+    w1_w0_w10 below_1965 -847
+    w1_w0_w10 below_1966 -804
+    w1_w0_w10 below_1967 -838
+    w1_w0_w10 below_1968 -840
+    w1_w0_w10 below_1969 -810
+    w1_w0_w10 below_1970 -865
+    w1_w0_w10 below_1971 -776
+    w1_w0_w10 below_1972 -775
+    w1_w0_w10 below_1973 -894
+    w1_w0_w10 below_1974 -843
+    w1_w0_w10 below_1975 -817
+    w1_w0_w10 below_1976 -812
+    w1_w0_w10 below_1977 -820
+    w1_w0_w10 below_1978 -740
+    w1_w0_w10 below_1979 -812
+    w1_w0_w10 below_1980 -841
+    w1_w0_w10 below_1981 -817
+    w1_w0_w10 below_1982 -842
+    w1_w0_w10 below_1983 -787
+    w1_w0_w10 below_1984 -869
+    w1_w0_w10 below_1985 -802
+    w1_w0_w10 below_1986 -842
+    w1_w0_w10 below_1987 -837
+    w1_w0_w10 below_1988 -849
+    w1_w0_w10 below_1989 -818
+    w1_w0_w10 below_1990 -725
+    w1_w0_w10 below_1991 -855
+    w1_w0_w10 below_1992 -940
+    w1_w0_w10 below_1993 -815
+    w1_w0_w10 below_1994 -880
+    w1_w0_w10 below_1995 -877
+    w1_w0_w10 below_1996 -883
+    w1_w0_w10 below_1997 -815
+    w1_w0_w10 below_1998 -844
+    w1_w0_w10 below_1999 -834
+    w1_w0_w10 below_2000 -804
+    w1_w0_w0 above_1800 107
+    w1_w0_w0 above_1801 118
+    w1_w0_w0 above_1802 128
+    w1_w0_w0 above_1803 123
+    w1_w0_w0 above_1804 114
+    w1_w0_w0 above_1805 132
+    w1_w0_w0 above_1806 129
+    w1_w0_w0 above_1807 135
+    w1_w0_w0 above_1808 135
+    w1_w0_w0 above_1809 138
+    w1_w0_w0 above_1810 148
+    w1_w0_w0 above_1811 140
+    w1_w0_w0 above_1812 160
+    w1_w0_w0 above_1813 148
+    w1_w0_w0 above_1814 148
+    w1_w0_w0 above_1815 158
+    w1_w0_w0 above_1816 154
+    w1_w0_w0 above_1817 160
+    w1_w0_w0 above_1818 149
+    w1_w0_w0 above_1819 167
+    w1_w0_w0 above_1820 188
+    w1_w0_w0 above_1821 175
+    w1_w0_w0 above_1822 192
+    w1_w0_w0 above_1823 197
+    w1_w0_w0 above_1824 180
+    w1_w0_w0 above_1825 191
+    w1_w0_w0 above_1826 178
+    w1_w0_w0 above_1827 182
+    w1_w0_w0 above_1828 180
+    w1_w0_w0 above_1829 181
+    w1_w0_w0 above_1830 202
+    w1_w0_w0 above_1831 191
+    w1_w0_w0 above_1832 206
+    w1_w0_w0 above_1833 205
+    w1_w0_w0 above_1834 216
+    w1_w0_w0 above_1835 193
+    w1_w0_w0 above_1836 214
+    w1_w0_w0 above_1837 231
+    w1_w0_w0 above_1838 237
+    w1_w0_w0 above_1839 237
+    w1_w0_w0 above_1840 244
+    w1_w0_w0 above_1841 250
+    w1_w0_w0 above_1842 235
+    w1_w0_w0 above_1843 255
+    w1_w0_w0 above_1844 241
+    w1_w0_w0 above_1845 265
+    w1_w0_w0 above_1846 248
+    w1_w0_w0 above_1847 256
+    w1_w0_w0 above_1848 286
+    w1_w0_w0 above_1849 273
+    w1_w0_w0 above_1850 306
+    w1_w0_w0 above_1851 269
+    w1_w0_w0 above_1852 292
+    w1_w0_w0 above_1853 330
+    w1_w0_w0 above_1854 293
+    w1_w0_w0 above_1855 306
+    w1_w0_w0 above_1856 307
+    w1_w0_w0 above_1857 329
+    w1_w0_w0 above_1858 325
+    w1_w0_w0 above_1859 317
+    w1_w0_w0 above_1860 338
+    w1_w0_w0 above_1861 320
+    w1_w0_w0 above_1862 346
+    w1_w0_w0 above_1863 342
+    w1_w0_w0 above_1864 365
+    w1_w0_w0 above_1865 389
+    w1_w0_w0 above_1866 407
+    w1_w0_w0 above_1867 395
+    w1_w0_w0 above_1868 407
+    w1_w0_w0 above_1869 438
+    w1_w0_w0 above_1870 378
+    w1_w0_w0 above_1871 399
+    w1_w0_w0 above_1872 424
+    w1_w0_w0 above_1873 434
+    w1_w0_w0 above_1874 438
+    w1_w0_w0 above_1875 470
+    w1_w0_w0 above_1876 450
+    w1_w0_w0 above_1877 449
+    w1_w0_w0 above_1878 460
+    w1_w0_w0 above_1879 446
+    w1_w0_w0 above_1880 482
+    w1_w0_w0 above_1881 463
+    w1_w0_w0 above_1882 493
+    w1_w0_w0 above_1883 481
+    w1_w0_w0 above_1884 511
+    w1_w0_w0 above_1885 492
+    w1_w0_w0 above_1886 532
+    w1_w0_w0 above_1887 518
+    w1_w0_w0 above_1888 528
+    w1_w0_w0 above_1889 505
+    w1_w0_w0 above_1890 505
+    w1_w0_w0 above_1891 512
+    w1_w0_w0 above_1892 554
+    w1_w0_w0 above_1893 581
+    w1_w0_w0 above_1894 520
+    w1_w0_w0 above_1895 546
+    w1_w0_w0 above_1896 558
+    w1_w0_w0 above_1897 605
+    w1_w0_w0 above_1898 529
+    w1_w0_w0 above_1899 595
+    w1_w0_w0 above_1900 547
+    w1_w0_w0 above_1901 647
+    w1_w0_w0 above_1902 598
+    w1_w0_w0 above_1903 584
+    w1_w0_w0 above_1904 566
+    w1_w0_w0 above_1905 567
+    w1_w0_w0 above_1906 597
+    w1_w0_w0 above_1907 576
+    w1_w0_w0 above_1908 628
+    w1_w0_w0 above_1909 615
+    w1_w0_w0 above_1910 661
+    w1_w0_w0 above_1911 624
+    w1_w0_w0 above_1912 662
+    w1_w0_w0 above_1913 648
+    w1_w0_w0 above_1914 608
+    w1_w0_w0 above_1915 657
+    w1_w0_w0 above_1916 639
+    w1_w0_w0 above_1917 672
+    w1_w0_w0 above_1918 741
+    w1_w0_w0 above_1919 705
+    w1_w0_w0 above_1920 643
+    w1_w0_w0 above_1921 634
+    w1_w0_w0 above_1922 724
+    w1_w0_w0 above_1923 741
+    w1_w0_w0 above_1924 701
+    w1_w0_w0 above_1925 736
+    w1_w0_w0 above_1926 780
+    w1_w0_w0 above_1927 728
+    w1_w0_w0 above_1928 778
+    w1_w0_w0 above_1929 776
+    w1_w0_w0 above_1930 720
+    w1_w0_w0 above_1931 731
+    w1_w0_w0 above_1932 724
+    w1_w0_w0 above_1933 764
+    w1_w0_w0 above_1934 813
+    w1_w0_w0 above_1935 748
+    w1_w0_w0 above_1936 751
+    w1_w0_w0 above_1937 748
+    w1_w0_w0 above_1938 734
+    w1_w0_w0 above_1939 768
+    w1_w0_w0 above_1940 730
+    w1_w0_w0 above_1941 855
+    w1_w0_w0 above_1942 748
+    w1_w0_w0 above_1943 747
+    w1_w0_w0 above_1944 770
+    w1_w0_w0 above_1945 826
+    w1_w0_w0 above_1946 835
+    w1_w0_w0 above_1947 791
+    w1_w0_w0 above_1948 797
+    w1_w0_w0 above_1949 808
+    w1_w0_w0 above_1950 816
+    w1_w0_w0 above_1951 872
+    w1_w0_w0 above_1952 883
+    w1_w0_w0 above_1953 852
+    w1_w0_w0 above_1954 891
+    w1_w0_w0 above_1955 948
+    w1_w0_w0 above_1956 952
+    w1_w0_w0 above_1957 874
+    w1_w0_w0 above_1958 944
+    w1_w0_w0 above_1959 908
+    w1_w0_w0 above_1960 877
+    w1_w0_w0 above_1961 779
+    w1_w0_w0 above_1962 909
+    w1_w0_w0 above_1963 919
+    w1_w0_w0 above_1964 945
+    w1_w0_w0 above_1965 932
+    w1_w0_w0 above_1966 840
+    w1_w0_w0 above_1967 921
+    w1_w0_w0 above_1968 1000
+    w1_w0_w0 above_1969 918
+    w1_w0_w0 above_1970 890
+    w1_w0_w0 above_1971 932
+    w1_w0_w0 above_1972 949
+    w1_w0_w0 above_1973 915
+    w1_w0_w0 above_1974 911
+    w1_w0_w0 above_1975 1100
+    w1_w0_w0 above_1976 941
+    w1_w0_w0 above_1977 1115
+    w1_w0_w0 above_1978 985
+    w1_w0_w0 above_1979 1017
+    w1_w0_w0 above_1980 991
+    w1_w0_w0 above_1981 966
+    w1_w0_w0 above_1982 1022
+    w1_w0_w0 above_1983 1019
+    w1_w0_w0 above_1984 1042
+    w1_w0_w0 above_1985 991
+    w1_w0_w0 above_1986 1135
+    w1_w0_w0 above_1987 1049
+    w1_w0_w0 above_1988 980
+    w1_w0_w0 above_1989 1122
+    w1_w0_w0 above_1990 1080
+    w1_w0_w0 above_1991 1103
+    w1_w0_w0 above_1992 1072
+    w1_w0_w0 above_1993 1163
+    w1_w0_w0 above_1994 1113
+    w1_w0_w0 above_1995 1181
+    w1_w0_w0 above_1996 1151
+    w1_w0_w0 above_1997 1089
+    w1_w0_w0 above_1998 1176
+    w1_w0_w0 above_1999 1055
+    w1_w0_w0 above_2000 1084
+    w1_w0_w0 below_1800 -107
+    w1_w0_w0 below_1801 -118
+    w1_w0_w0 below_1802 -128
+    w1_w0_w0 below_1803 -123
+    w1_w0_w0 below_1804 -114
+    w1_w0_w0 below_1805 -132
+    w1_w0_w0 below_1806 -129
+    w1_w0_w0 below_1807 -135
+    w1_w0_w0 below_1808 -135
+    w1_w0_w0 below_1809 -138
+    w1_w0_w0 below_1810 -148
+    w1_w0_w0 below_1811 -140
+    w1_w0_w0 below_1812 -160
+    w1_w0_w0 below_1813 -148
+    w1_w0_w0 below_1814 -148
+    w1_w0_w0 below_1815 -158
+    w1_w0_w0 below_1816 -154
+    w1_w0_w0 below_1817 -160
+    w1_w0_w0 below_1818 -149
+    w1_w0_w0 below_1819 -167
+    w1_w0_w0 below_1820 -188
+    w1_w0_w0 below_1821 -175
+    w1_w0_w0 below_1822 -192
+    w1_w0_w0 below_1823 -197
+    w1_w0_w0 below_1824 -180
+    w1_w0_w0 below_1825 -191
+    w1_w0_w0 below_1826 -178
+    w1_w0_w0 below_1827 -182
+    w1_w0_w0 below_1828 -180
+    w1_w0_w0 below_1829 -181
+    w1_w0_w0 below_1830 -202
+    w1_w0_w0 below_1831 -191
+    w1_w0_w0 below_1832 -206
+    w1_w0_w0 below_1833 -205
+    w1_w0_w0 below_1834 -216
+    w1_w0_w0 below_1835 -193
+    w1_w0_w0 below_1836 -214
+    w1_w0_w0 below_1837 -231
+    w1_w0_w0 below_1838 -237
+    w1_w0_w0 below_1839 -237
+    w1_w0_w0 below_1840 -244
+    w1_w0_w0 below_1841 -250
+    w1_w0_w0 below_1842 -235
+    w1_w0_w0 below_1843 -255
+    w1_w0_w0 below_1844 -241
+    w1_w0_w0 below_1845 -265
+    w1_w0_w0 below_1846 -248
+    w1_w0_w0 below_1847 -256
+    w1_w0_w0 below_1848 -286
+    w1_w0_w0 below_1849 -273
+    w1_w0_w0 below_1850 -306
+    w1_w0_w0 below_1851 -269
+    w1_w0_w0 below_1852 -292
+    w1_w0_w0 below_1853 -330
+    w1_w0_w0 below_1854 -293
+    w1_w0_w0 below_1855 -306
+    w1_w0_w0 below_1856 -307
+    w1_w0_w0 below_1857 -329
+    w1_w0_w0 below_1858 -325
+    w1_w0_w0 below_1859 -317
+    w1_w0_w0 below_1860 -338
+    w1_w0_w0 below_1861 -320
+    w1_w0_w0 below_1862 -346
+    w1_w0_w0 below_1863 -342
+    w1_w0_w0 below_1864 -365
+    w1_w0_w0 below_1865 -389
+    w1_w0_w0 below_1866 -407
+    w1_w0_w0 below_1867 -395
+    w1_w0_w0 below_1868 -407
+    w1_w0_w0 below_1869 -438
+    w1_w0_w0 below_1870 -378
+    w1_w0_w0 below_1871 -399
+    w1_w0_w0 below_1872 -424
+    w1_w0_w0 below_1873 -434
+    w1_w0_w0 below_1874 -438
+    w1_w0_w0 below_1875 -470
+    w1_w0_w0 below_1876 -450
+    w1_w0_w0 below_1877 -449
+    w1_w0_w0 below_1878 -460
+    w1_w0_w0 below_1879 -446
+    w1_w0_w0 below_1880 -482
+    w1_w0_w0 below_1881 -463
+    w1_w0_w0 below_1882 -493
+    w1_w0_w0 below_1883 -481
+    w1_w0_w0 below_1884 -511
+    w1_w0_w0 below_1885 -492
+    w1_w0_w0 below_1886 -532
+    w1_w0_w0 below_1887 -518
+    w1_w0_w0 below_1888 -528
+    w1_w0_w0 below_1889 -505
+    w1_w0_w0 below_1890 -505
+    w1_w0_w0 below_1891 -512
+    w1_w0_w0 below_1892 -554
+    w1_w0_w0 below_1893 -581
+    w1_w0_w0 below_1894 -520
+    w1_w0_w0 below_1895 -546
+    w1_w0_w0 below_1896 -558
+    w1_w0_w0 below_1897 -605
+    w1_w0_w0 below_1898 -529
+    w1_w0_w0 below_1899 -595
+    w1_w0_w0 below_1900 -547
+    w1_w0_w0 below_1901 -647
+    w1_w0_w0 below_1902 -598
+    w1_w0_w0 below_1903 -584
+    w1_w0_w0 below_1904 -566
+    w1_w0_w0 below_1905 -567
+    w1_w0_w0 below_1906 -597
+    w1_w0_w0 below_1907 -576
+    w1_w0_w0 below_1908 -628
+    w1_w0_w0 below_1909 -615
+    w1_w0_w0 below_1910 -661
+    w1_w0_w0 below_1911 -624
+    w1_w0_w0 below_1912 -662
+    w1_w0_w0 below_1913 -648
+    w1_w0_w0 below_1914 -608
+    w1_w0_w0 below_1915 -657
+    w1_w0_w0 below_1916 -639
+    w1_w0_w0 below_1917 -672
+    w1_w0_w0 below_1918 -741
+    w1_w0_w0 below_1919 -705
+    w1_w0_w0 below_1920 -643
+    w1_w0_w0 below_1921 -634
+    w1_w0_w0 below_1922 -724
+    w1_w0_w0 below_1923 -741
+    w1_w0_w0 below_1924 -701
+    w1_w0_w0 below_1925 -736
+    w1_w0_w0 below_1926 -780
+    w1_w0_w0 below_1927 -728
+    w1_w0_w0 below_1928 -778
+    w1_w0_w0 below_1929 -776
+    w1_w0_w0 below_1930 -720
+    w1_w0_w0 below_1931 -731
+    w1_w0_w0 below_1932 -724
+    w1_w0_w0 below_1933 -764
+    w1_w0_w0 below_1934 -813
+    w1_w0_w0 below_1935 -748
+    w1_w0_w0 below_1936 -751
+    w1_w0_w0 below_1937 -748
+    w1_w0_w0 below_1938 -734
+    w1_w0_w0 below_1939 -768
+    w1_w0_w0 below_1940 -730
+    w1_w0_w0 below_1941 -855
+    w1_w0_w0 below_1942 -748
+    w1_w0_w0 below_1943 -747
+    w1_w0_w0 below_1944 -770
+    w1_w0_w0 below_1945 -826
+    w1_w0_w0 below_1946 -835
+    w1_w0_w0 below_1947 -791
+    w1_w0_w0 below_1948 -797
+    w1_w0_w0 below_1949 -808
+    w1_w0_w0 below_1950 -816
+    w1_w0_w0 below_1951 -872
+    w1_w0_w0 below_1952 -883
+    w1_w0_w0 below_1953 -852
+    w1_w0_w0 below_1954 -891
+    w1_w0_w0 below_1955 -948
+    w1_w0_w0 below_1956 -952
+    w1_w0_w0 below_1957 -874
+    w1_w0_w0 below_1958 -944
+    w1_w0_w0 below_1959 -908
+    w1_w0_w0 below_1960 -877
+    w1_w0_w0 below_1961 -779
+    w1_w0_w0 below_1962 -909
+    w1_w0_w0 below_1963 -919
+    w1_w0_w0 below_1964 -945
+    w1_w0_w0 below_1965 -932
+    w1_w0_w0 below_1966 -840
+    w1_w0_w0 below_1967 -921
+    w1_w0_w0 below_1968 -1000
+    w1_w0_w0 below_1969 -918
+    w1_w0_w0 below_1970 -890
+    w1_w0_w0 below_1971 -932
+    w1_w0_w0 below_1972 -949
+    w1_w0_w0 below_1973 -915
+    w1_w0_w0 below_1974 -911
+    w1_w0_w0 below_1975 -1100
+    w1_w0_w0 below_1976 -941
+    w1_w0_w0 below_1977 -1115
+    w1_w0_w0 below_1978 -985
+    w1_w0_w0 below_1979 -1017
+    w1_w0_w0 below_1980 -991
+    w1_w0_w0 below_1981 -966
+    w1_w0_w0 below_1982 -1022
+    w1_w0_w0 below_1983 -1019
+    w1_w0_w0 below_1984 -1042
+    w1_w0_w0 below_1985 -991
+    w1_w0_w0 below_1986 -1135
+    w1_w0_w0 below_1987 -1049
+    w1_w0_w0 below_1988 -980
+    w1_w0_w0 below_1989 -1122
+    w1_w0_w0 below_1990 -1080
+    w1_w0_w0 below_1991 -1103
+    w1_w0_w0 below_1992 -1072
+    w1_w0_w0 below_1993 -1163
+    w1_w0_w0 below_1994 -1113
+    w1_w0_w0 below_1995 -1181
+    w1_w0_w0 below_1996 -1151
+    w1_w0_w0 below_1997 -1089
+    w1_w0_w0 below_1998 -1176
+    w1_w0_w0 below_1999 -1055
+    w1_w0_w0 below_2000 -1084
+    w1_w0_w1 above_1800 77
+    w1_w0_w1 above_1801 87
+    w1_w0_w1 above_1802 102
+    w1_w0_w1 above_1803 84
+    w1_w0_w1 above_1804 85
+    w1_w0_w1 above_1805 97
+    w1_w0_w1 above_1806 92
+    w1_w0_w1 above_1807 90
+    w1_w0_w1 above_1808 95
+    w1_w0_w1 above_1809 103
+    w1_w0_w1 above_1810 105
+    w1_w0_w1 above_1811 109
+    w1_w0_w1 above_1812 107
+    w1_w0_w1 above_1813 112
+    w1_w0_w1 above_1814 107
+    w1_w0_w1 above_1815 117
+    w1_w0_w1 above_1816 119
+    w1_w0_w1 above_1817 115
+    w1_w0_w1 above_1818 123
+    w1_w0_w1 above_1819 136
+    w1_w0_w1 above_1820 128
+    w1_w0_w1 above_1821 133
+    w1_w0_w1 above_1822 132
+    w1_w0_w1 above_1823 126
+    w1_w0_w1 above_1824 145
+    w1_w0_w1 above_1825 151
+    w1_w0_w1 above_1826 145
+    w1_w0_w1 above_1827 156
+    w1_w0_w1 above_1828 168
+    w1_w0_w1 above_1829 155
+    w1_w0_w1 above_1830 154
+    w1_w0_w1 above_1831 179
+    w1_w0_w1 above_1832 164
+    w1_w0_w1 above_1833 174
+    w1_w0_w1 above_1834 166
+    w1_w0_w1 above_1835 170
+    w1_w0_w1 above_1836 185
+    w1_w0_w1 above_1837 178
+    w1_w0_w1 above_1838 190
+    w1_w0_w1 above_1839 185
+    w1_w0_w1 above_1840 186
+    w1_w0_w1 above_1841 185
+    w1_w0_w1 above_1842 210
+    w1_w0_w1 above_1843 215
+    w1_w0_w1 above_1844 211
+    w1_w0_w1 above_1845 223
+    w1_w0_w1 above_1846 218
+    w1_w0_w1 above_1847 245
+    w1_w0_w1 above_1848 233
+    w1_w0_w1 above_1849 251
+    w1_w0_w1 above_1850 280
+    w1_w0_w1 above_1851 286
+    w1_w0_w1 above_1852 297
+    w1_w0_w1 above_1853 268
+    w1_w0_w1 above_1854 295
+    w1_w0_w1 above_1855 279
+    w1_w0_w1 above_1856 312
+    w1_w0_w1 above_1857 318
+    w1_w0_w1 above_1858 361
+    w1_w0_w1 above_1859 337
+    w1_w0_w1 above_1860 336
+    w1_w0_w1 above_1861 351
+    w1_w0_w1 above_1862 358
+    w1_w0_w1 above_1863 370
+    w1_w0_w1 above_1864 363
+    w1_w0_w1 above_1865 374
+    w1_w0_w1 above_1866 384
+    w1_w0_w1 above_1867 428
+    w1_w0_w1 above_1868 365
+    w1_w0_w1 above_1869 400
+    w1_w0_w1 above_1870 467
+    w1_w0_w1 above_1871 417
+    w1_w0_w1 above_1872 427
+    w1_w0_w1 above_1873 405
+    w1_w0_w1 above_1874 456
+    w1_w0_w1 above_1875 477
+    w1_w0_w1 above_1876 446
+    w1_w0_w1 above_1877 461
+    w1_w0_w1 above_1878 455
+    w1_w0_w1 above_1879 463
+    w1_w0_w1 above_1880 449
+    w1_w0_w1 above_1881 486
+    w1_w0_w1 above_1882 457
+    w1_w0_w1 above_1883 430
+    w1_w0_w1 above_1884 458
+    w1_w0_w1 above_1885 513
+    w1_w0_w1 above_1886 491
+    w1_w0_w1 above_1887 448
+    w1_w0_w1 above_1888 459
+    w1_w0_w1 above_1889 450
+    w1_w0_w1 above_1890 483
+    w1_w0_w1 above_1891 505
+    w1_w0_w1 above_1892 461
+    w1_w0_w1 above_1893 495
+    w1_w0_w1 above_1894 511
+    w1_w0_w1 above_1895 470
+    w1_w0_w1 above_1896 511
+    w1_w0_w1 above_1897 484
+    w1_w0_w1 above_1898 507
+    w1_w0_w1 above_1899 534
+    w1_w0_w1 above_1900 512
+    w1_w0_w1 above_1901 533
+    w1_w0_w1 above_1902 491
+    w1_w0_w1 above_1903 507
+    w1_w0_w1 above_1904 534
+    w1_w0_w1 above_1905 492
+    w1_w0_w1 above_1906 538
+    w1_w0_w1 above_1907 559
+    w1_w0_w1 above_1908 545
+    w1_w0_w1 above_1909 489
+    w1_w0_w1 above_1910 497
+    w1_w0_w1 above_1911 526
+    w1_w0_w1 above_1912 526
+    w1_w0_w1 above_1913 545
+    w1_w0_w1 above_1914 534
+    w1_w0_w1 above_1915 594
+    w1_w0_w1 above_1916 501
+    w1_w0_w1 above_1917 558
+    w1_w0_w1 above_1918 501
+    w1_w0_w1 above_1919 548
+    w1_w0_w1 above_1920 555
+    w1_w0_w1 above_1921 598
+    w1_w0_w1 above_1922 558
+    w1_w0_w1 above_1923 550
+    w1_w0_w1 above_1924 598
+    w1_w0_w1 above_1925 554
+    w1_w0_w1 above_1926 560
+    w1_w0_w1 above_1927 626
+    w1_w0_w1 above_1928 574
+    w1_w0_w1 above_1929 554
+    w1_w0_w1 above_1930 529
+    w1_w0_w1 above_1931 586
+    w1_w0_w1 above_1932 557
+    w1_w0_w1 above_1933 585
+    w1_w0_w1 above_1934 592
+    w1_w0_w1 above_1935 607
+    w1_w0_w1 above_1936 557
+    w1_w0_w1 above_1937 581
+    w1_w0_w1 above_1938 617
+    w1_w0_w1 above_1939 607
+    w1_w0_w1 above_1940 610
+    w1_w0_w1 above_1941 610
+    w1_w0_w1 above_1942 605
+    w1_w0_w1 above_1943 610
+    w1_w0_w1 above_1944 610
+    w1_w0_w1 above_1945 623
+    w1_w0_w1 above_1946 645
+    w1_w0_w1 above_1947 626
+    w1_w0_w1 above_1948 628
+    w1_w0_w1 above_1949 633
+    w1_w0_w1 above_1950 610
+    w1_w0_w1 above_1951 625
+    w1_w0_w1 above_1952 586
+    w1_w0_w1 above_1953 650
+    w1_w0_w1 above_1954 593
+    w1_w0_w1 above_1955 669
+    w1_w0_w1 above_1956 690
+    w1_w0_w1 above_1957 653
+    w1_w0_w1 above_1958 673
+    w1_w0_w1 above_1959 646
+    w1_w0_w1 above_1960 635
+    w1_w0_w1 above_1961 631
+    w1_w0_w1 above_1962 684
+    w1_w0_w1 above_1963 715
+    w1_w0_w1 above_1964 612
+    w1_w0_w1 above_1965 681
+    w1_w0_w1 above_1966 728
+    w1_w0_w1 above_1967 660
+    w1_w0_w1 above_1968 713
+    w1_w0_w1 above_1969 671
+    w1_w0_w1 above_1970 641
+    w1_w0_w1 above_1971 690
+    w1_w0_w1 above_1972 723
+    w1_w0_w1 above_1973 756
+    w1_w0_w1 above_1974 644
+    w1_w0_w1 above_1975 732
+    w1_w0_w1 above_1976 733
+    w1_w0_w1 above_1977 693
+    w1_w0_w1 above_1978 716
+    w1_w0_w1 above_1979 666
+    w1_w0_w1 above_1980 727
+    w1_w0_w1 above_1981 670
+    w1_w0_w1 above_1982 711
+    w1_w0_w1 above_1983 774
+    w1_w0_w1 above_1984 692
+    w1_w0_w1 above_1985 679
+    w1_w0_w1 above_1986 685
+    w1_w0_w1 above_1987 714
+    w1_w0_w1 above_1988 738
+    w1_w0_w1 above_1989 749
+    w1_w0_w1 above_1990 696
+    w1_w0_w1 above_1991 737
+    w1_w0_w1 above_1992 769
+    w1_w0_w1 above_1993 692
+    w1_w0_w1 above_1994 720
+    w1_w0_w1 above_1995 712
+    w1_w0_w1 above_1996 745
+    w1_w0_w1 above_1997 744
+    w1_w0_w1 above_1998 715
+    w1_w0_w1 above_1999 712
+    w1_w0_w1 above_2000 755
+    w1_w0_w1 below_1800 -77
+    w1_w0_w1 below_1801 -87
+    w1_w0_w1 below_1802 -102
+    w1_w0_w1 below_1803 -84
+    w1_w0_w1 below_1804 -85
+    w1_w0_w1 below_1805 -97
+    w1_w0_w1 below_1806 -92
+    w1_w0_w1 below_1807 -90
+    w1_w0_w1 below_1808 -95
+    w1_w0_w1 below_1809 -103
+    w1_w0_w1 below_1810 -105
+    w1_w0_w1 below_1811 -109
+    w1_w0_w1 below_1812 -107
+    w1_w0_w1 below_1813 -112
+    w1_w0_w1 below_1814 -107
+    w1_w0_w1 below_1815 -117
+    w1_w0_w1 below_1816 -119
+    w1_w0_w1 below_1817 -115
+    w1_w0_w1 below_1818 -123
+    w1_w0_w1 below_1819 -136
+    w1_w0_w1 below_1820 -128
+    w1_w0_w1 below_1821 -133
+    w1_w0_w1 below_1822 -132
+    w1_w0_w1 below_1823 -126
+    w1_w0_w1 below_1824 -145
+    w1_w0_w1 below_1825 -151
+    w1_w0_w1 below_1826 -145
+    w1_w0_w1 below_1827 -156
+    w1_w0_w1 below_1828 -168
+    w1_w0_w1 below_1829 -155
+    w1_w0_w1 below_1830 -154
+    w1_w0_w1 below_1831 -179
+    w1_w0_w1 below_1832 -164
+    w1_w0_w1 below_1833 -174
+    w1_w0_w1 below_1834 -166
+    w1_w0_w1 below_1835 -170
+    w1_w0_w1 below_1836 -185
+    w1_w0_w1 below_1837 -178
+    w1_w0_w1 below_1838 -190
+    w1_w0_w1 below_1839 -185
+    w1_w0_w1 below_1840 -186
+    w1_w0_w1 below_1841 -185
+    w1_w0_w1 below_1842 -210
+    w1_w0_w1 below_1843 -215
+    w1_w0_w1 below_1844 -211
+    w1_w0_w1 below_1845 -223
+    w1_w0_w1 below_1846 -218
+    w1_w0_w1 below_1847 -245
+    w1_w0_w1 below_1848 -233
+    w1_w0_w1 below_1849 -251
+    w1_w0_w1 below_1850 -280
+    w1_w0_w1 below_1851 -286
+    w1_w0_w1 below_1852 -297
+    w1_w0_w1 below_1853 -268
+    w1_w0_w1 below_1854 -295
+    w1_w0_w1 below_1855 -279
+    w1_w0_w1 below_1856 -312
+    w1_w0_w1 below_1857 -318
+    w1_w0_w1 below_1858 -361
+    w1_w0_w1 below_1859 -337
+    w1_w0_w1 below_1860 -336
+    w1_w0_w1 below_1861 -351
+    w1_w0_w1 below_1862 -358
+    w1_w0_w1 below_1863 -370
+    w1_w0_w1 below_1864 -363
+    w1_w0_w1 below_1865 -374
+    w1_w0_w1 below_1866 -384
+    w1_w0_w1 below_1867 -428
+    w1_w0_w1 below_1868 -365
+    w1_w0_w1 below_1869 -400
+    w1_w0_w1 below_1870 -467
+    w1_w0_w1 below_1871 -417
+    w1_w0_w1 below_1872 -427
+    w1_w0_w1 below_1873 -405
+    w1_w0_w1 below_1874 -456
+    w1_w0_w1 below_1875 -477
+    w1_w0_w1 below_1876 -446
+    w1_w0_w1 below_1877 -461
+    w1_w0_w1 below_1878 -455
+    w1_w0_w1 below_1879 -463
+    w1_w0_w1 below_1880 -449
+    w1_w0_w1 below_1881 -486
+    w1_w0_w1 below_1882 -457
+    w1_w0_w1 below_1883 -430
+    w1_w0_w1 below_1884 -458
+    w1_w0_w1 below_1885 -513
+    w1_w0_w1 below_1886 -491
+    w1_w0_w1 below_1887 -448
+    w1_w0_w1 below_1888 -459
+    w1_w0_w1 below_1889 -450
+    w1_w0_w1 below_1890 -483
+    w1_w0_w1 below_1891 -505
+    w1_w0_w1 below_1892 -461
+    w1_w0_w1 below_1893 -495
+    w1_w0_w1 below_1894 -511
+    w1_w0_w1 below_1895 -470
+    w1_w0_w1 below_1896 -511
+    w1_w0_w1 below_1897 -484
+    w1_w0_w1 below_1898 -507
+    w1_w0_w1 below_1899 -534
+    w1_w0_w1 below_1900 -512
+    w1_w0_w1 below_1901 -533
+    w1_w0_w1 below_1902 -491
+    w1_w0_w1 below_1903 -507
+    w1_w0_w1 below_1904 -534
+    w1_w0_w1 below_1905 -492
+    w1_w0_w1 below_1906 -538
+    w1_w0_w1 below_1907 -559
+    w1_w0_w1 below_1908 -545
+    w1_w0_w1 below_1909 -489
+    w1_w0_w1 below_1910 -497
+    w1_w0_w1 below_1911 -526
+    w1_w0_w1 below_1912 -526
+    w1_w0_w1 below_1913 -545
+    w1_w0_w1 below_1914 -534
+    w1_w0_w1 below_1915 -594
+    w1_w0_w1 below_1916 -501
+    w1_w0_w1 below_1917 -558
+    w1_w0_w1 below_1918 -501
+    w1_w0_w1 below_1919 -548
+    w1_w0_w1 below_1920 -555
+    w1_w0_w1 below_1921 -598
+    w1_w0_w1 below_1922 -558
+    w1_w0_w1 below_1923 -550
+    w1_w0_w1 below_1924 -598
+    w1_w0_w1 below_1925 -554
+    w1_w0_w1 below_1926 -560
+    w1_w0_w1 below_1927 -626
+    w1_w0_w1 below_1928 -574
+    w1_w0_w1 below_1929 -554
+    w1_w0_w1 below_1930 -529
+    w1_w0_w1 below_1931 -586
+    w1_w0_w1 below_1932 -557
+    w1_w0_w1 below_1933 -585
+    w1_w0_w1 below_1934 -592
+    w1_w0_w1 below_1935 -607
+    w1_w0_w1 below_1936 -557
+    w1_w0_w1 below_1937 -581
+    w1_w0_w1 below_1938 -617
+    w1_w0_w1 below_1939 -607
+    w1_w0_w1 below_1940 -610
+    w1_w0_w1 below_1941 -610
+    w1_w0_w1 below_1942 -605
+    w1_w0_w1 below_1943 -610
+    w1_w0_w1 below_1944 -610
+    w1_w0_w1 below_1945 -623
+    w1_w0_w1 below_1946 -645
+    w1_w0_w1 below_1947 -626
+    w1_w0_w1 below_1948 -628
+    w1_w0_w1 below_1949 -633
+    w1_w0_w1 below_1950 -610
+    w1_w0_w1 below_1951 -625
+    w1_w0_w1 below_1952 -586
+    w1_w0_w1 below_1953 -650
+    w1_w0_w1 below_1954 -593
+    w1_w0_w1 below_1955 -669
+    w1_w0_w1 below_1956 -690
+    w1_w0_w1 below_1957 -653
+    w1_w0_w1 below_1958 -673
+    w1_w0_w1 below_1959 -646
+    w1_w0_w1 below_1960 -635
+    w1_w0_w1 below_1961 -631
+    w1_w0_w1 below_1962 -684
+    w1_w0_w1 below_1963 -715
+    w1_w0_w1 below_1964 -612
+    w1_w0_w1 below_1965 -681
+    w1_w0_w1 below_1966 -728
+    w1_w0_w1 below_1967 -660
+    w1_w0_w1 below_1968 -713
+    w1_w0_w1 below_1969 -671
+    w1_w0_w1 below_1970 -641
+    w1_w0_w1 below_1971 -690
+    w1_w0_w1 below_1972 -723
+    w1_w0_w1 below_1973 -756
+    w1_w0_w1 below_1974 -644
+    w1_w0_w1 below_1975 -732
+    w1_w0_w1 below_1976 -733
+    w1_w0_w1 below_1977 -693
+    w1_w0_w1 below_1978 -716
+    w1_w0_w1 below_1979 -666
+    w1_w0_w1 below_1980 -727
+    w1_w0_w1 below_1981 -670
+    w1_w0_w1 below_1982 -711
+    w1_w0_w1 below_1983 -774
+    w1_w0_w1 below_1984 -692
+    w1_w0_w1 below_1985 -679
+    w1_w0_w1 below_1986 -685
+    w1_w0_w1 below_1987 -714
+    w1_w0_w1 below_1988 -738
+    w1_w0_w1 below_1989 -749
+    w1_w0_w1 below_1990 -696
+    w1_w0_w1 below_1991 -737
+    w1_w0_w1 below_1992 -769
+    w1_w0_w1 below_1993 -692
+    w1_w0_w1 below_1994 -720
+    w1_w0_w1 below_1995 -712
+    w1_w0_w1 below_1996 -745
+    w1_w0_w1 below_1997 -744
+    w1_w0_w1 below_1998 -715
+    w1_w0_w1 below_1999 -712
+    w1_w0_w1 below_2000 -755
+    w1_w0_w941 above_1800 56
+    w1_w0_w941 above_1801 51
+    w1_w0_w941 above_1802 57
+    w1_w0_w941 above_1803 60
+    w1_w0_w941 above_1804 64
+    w1_w0_w941 above_1805 65
+    w1_w0_w941 above_1806 70
+    w1_w0_w941 above_1807 68
+    w1_w0_w941 above_1808 69
+    w1_w0_w941 above_1809 74
+    w1_w0_w941 above_1810 79
+    w1_w0_w941 above_1811 88
+    w1_w0_w941 above_1812 79
+    w1_w0_w941 above_1813 86
+    w1_w0_w941 above_1814 87
+    w1_w0_w941 above_1815 91
+    w1_w0_w941 above_1816 95
+    w1_w0_w941 above_1817 95
+    w1_w0_w941 above_1818 98
+    w1_w0_w941 above_1819 101
+    w1_w0_w941 above_1820 107
+    w1_w0_w941 above_1821 98
+    w1_w0_w941 above_1822 109
+    w1_w0_w941 above_1823 107
+    w1_w0_w941 above_1824 115
+    w1_w0_w941 above_1825 114
+    w1_w0_w941 above_1826 123
+    w1_w0_w941 above_1827 122
+    w1_w0_w941 above_1828 141
+    w1_w0_w941 above_1829 134
+    w1_w0_w941 above_1830 140
+    w1_w0_w941 above_1831 137
+    w1_w0_w941 above_1832 159
+    w1_w0_w941 above_1833 146
+    w1_w0_w941 above_1834 162
+    w1_w0_w941 above_1835 163
+    w1_w0_w941 above_1836 153
+    w1_w0_w941 above_1837 171
+    w1_w0_w941 above_1838 161
+    w1_w0_w941 above_1839 169
+    w1_w0_w941 above_1840 173
+    w1_w0_w941 above_1841 186
+    w1_w0_w941 above_1842 176
+    w1_w0_w941 above_1843 188
+    w1_w0_w941 above_1844 197
+    w1_w0_w941 above_1845 198
+    w1_w0_w941 above_1846 215
+    w1_w0_w941 above_1847 216
+    w1_w0_w941 above_1848 218
+    w1_w0_w941 above_1849 234
+    w1_w0_w941 above_1850 232
+    w1_w0_w941 above_1851 220
+    w1_w0_w941 above_1852 220
+    w1_w0_w941 above_1853 226
+    w1_w0_w941 above_1854 231
+    w1_w0_w941 above_1855 230
+    w1_w0_w941 above_1856 241
+    w1_w0_w941 above_1857 209
+    w1_w0_w941 above_1858 243
+    w1_w0_w941 above_1859 233
+    w1_w0_w941 above_1860 247
+    w1_w0_w941 above_1861 257
+    w1_w0_w941 above_1862 280
+    w1_w0_w941 above_1863 261
+    w1_w0_w941 above_1864 295
+    w1_w0_w941 above_1865 260
+    w1_w0_w941 above_1866 266
+    w1_w0_w941 above_1867 257
+    w1_w0_w941 above_1868 272
+    w1_w0_w941 above_1869 271
+    w1_w0_w941 above_1870 283
+    w1_w0_w941 above_1871 297
+    w1_w0_w941 above_1872 302
+    w1_w0_w941 above_1873 272
+    w1_w0_w941 above_1874 290
+    w1_w0_w941 above_1875 302
+    w1_w0_w941 above_1876 297
+    w1_w0_w941 above_1877 292
+    w1_w0_w941 above_1878 308
+    w1_w0_w941 above_1879 287
+    w1_w0_w941 above_1880 302
+    w1_w0_w941 above_1881 286
+    w1_w0_w941 above_1882 298
+    w1_w0_w941 above_1883 325
+    w1_w0_w941 above_1884 318
+    w1_w0_w941 above_1885 325
+    w1_w0_w941 above_1886 323
+    w1_w0_w941 above_1887 325
+    w1_w0_w941 above_1888 304
+    w1_w0_w941 above_1889 310
+    w1_w0_w941 above_1890 309
+    w1_w0_w941 above_1891 328
+    w1_w0_w941 above_1892 304
+    w1_w0_w941 above_1893 321
+    w1_w0_w941 above_1894 302
+    w1_w0_w941 above_1895 300
+    w1_w0_w941 above_1896 323
+    w1_w0_w941 above_1897 332
+    w1_w0_w941 above_1898 326
+    w1_w0_w941 above_1899 312
+    w1_w0_w941 above_1900 352
+    w1_w0_w941 above_1901 285
+    w1_w0_w941 above_1902 306
+    w1_w0_w941 above_1903 337
+    w1_w0_w941 above_1904 329
+    w1_w0_w941 above_1905 349
+    w1_w0_w941 above_1906 355
+    w1_w0_w941 above_1907 357
+    w1_w0_w941 above_1908 355
+    w1_w0_w941 above_1909 369
+    w1_w0_w941 above_1910 342
+    w1_w0_w941 above_1911 357
+    w1_w0_w941 above_1912 362
+    w1_w0_w941 above_1913 335
+    w1_w0_w941 above_1914 349
+    w1_w0_w941 above_1915 349
+    w1_w0_w941 above_1916 345
+    w1_w0_w941 above_1917 369
+    w1_w0_w941 above_1918 348
+    w1_w0_w941 above_1919 363
+    w1_w0_w941 above_1920 345
+    w1_w0_w941 above_1921 341
+    w1_w0_w941 above_1922 353
+    w1_w0_w941 above_1923 368
+    w1_w0_w941 above_1924 328
+    w1_w0_w941 above_1925 387
+    w1_w0_w941 above_1926 322
+    w1_w0_w941 above_1927 340
+    w1_w0_w941 above_1928 367
+    w1_w0_w941 above_1929 343
+    w1_w0_w941 above_1930 354
+    w1_w0_w941 above_1931 320
+    w1_w0_w941 above_1932 320
+    w1_w0_w941 above_1933 338
+    w1_w0_w941 above_1934 338
+    w1_w0_w941 above_1935 374
+    w1_w0_w941 above_1936 367
+    w1_w0_w941 above_1937 359
+    w1_w0_w941 above_1938 373
+    w1_w0_w941 above_1939 358
+    w1_w0_w941 above_1940 373
+    w1_w0_w941 above_1941 393
+    w1_w0_w941 above_1942 358
+    w1_w0_w941 above_1943 350
+    w1_w0_w941 above_1944 365
+    w1_w0_w941 above_1945 359
+    w1_w0_w941 above_1946 375
+    w1_w0_w941 above_1947 398
+    w1_w0_w941 above_1948 333
+    w1_w0_w941 above_1949 318
+    w1_w0_w941 above_1950 346
+    w1_w0_w941 above_1951 372
+    w1_w0_w941 above_1952 342
+    w1_w0_w941 above_1953 344
+    w1_w0_w941 above_1954 355
+    w1_w0_w941 above_1955 363
+    w1_w0_w941 above_1956 344
+    w1_w0_w941 above_1957 360
+    w1_w0_w941 above_1958 357
+    w1_w0_w941 above_1959 364
+    w1_w0_w941 above_1960 370
+    w1_w0_w941 above_1961 345
+    w1_w0_w941 above_1962 334
+    w1_w0_w941 above_1963 369
+    w1_w0_w941 above_1964 376
+    w1_w0_w941 above_1965 345
+    w1_w0_w941 above_1966 358
+    w1_w0_w941 above_1967 352
+    w1_w0_w941 above_1968 350
+    w1_w0_w941 above_1969 367
+    w1_w0_w941 above_1970 341
+    w1_w0_w941 above_1971 369
+    w1_w0_w941 above_1972 372
+    w1_w0_w941 above_1973 346
+    w1_w0_w941 above_1974 334
+    w1_w0_w941 above_1975 370
+    w1_w0_w941 above_1976 371
+    w1_w0_w941 above_1977 355
+    w1_w0_w941 above_1978 328
+    w1_w0_w941 above_1979 380
+    w1_w0_w941 above_1980 357
+    w1_w0_w941 above_1981 369
+    w1_w0_w941 above_1982 357
+    w1_w0_w941 above_1983 348
+    w1_w0_w941 above_1984 365
+    w1_w0_w941 above_1985 354
+    w1_w0_w941 above_1986 362
+    w1_w0_w941 above_1987 369
+    w1_w0_w941 above_1988 345
+    w1_w0_w941 above_1989 353
+    w1_w0_w941 above_1990 327
+    w1_w0_w941 above_1991 335
+    w1_w0_w941 above_1992 353
+    w1_w0_w941 above_1993 344
+    w1_w0_w941 above_1994 329
+    w1_w0_w941 above_1995 337
+    w1_w0_w941 above_1996 367
+    w1_w0_w941 above_1997 342
+    w1_w0_w941 above_1998 364
+    w1_w0_w941 above_1999 350
+    w1_w0_w941 above_2000 344
+    w1_w0_w941 below_1800 -56
+    w1_w0_w941 below_1801 -51
+    w1_w0_w941 below_1802 -57
+    w1_w0_w941 below_1803 -60
+    w1_w0_w941 below_1804 -64
+    w1_w0_w941 below_1805 -65
+    w1_w0_w941 below_1806 -70
+    w1_w0_w941 below_1807 -68
+    w1_w0_w941 below_1808 -69
+    w1_w0_w941 below_1809 -74
+    w1_w0_w941 below_1810 -79
+    w1_w0_w941 below_1811 -88
+    w1_w0_w941 below_1812 -79
+    w1_w0_w941 below_1813 -86
+    w1_w0_w941 below_1814 -87
+    w1_w0_w941 below_1815 -91
+    w1_w0_w941 below_1816 -95
+    w1_w0_w941 below_1817 -95
+    w1_w0_w941 below_1818 -98
+    w1_w0_w941 below_1819 -101
+    w1_w0_w941 below_1820 -107
+    w1_w0_w941 below_1821 -98
+    w1_w0_w941 below_1822 -109
+    w1_w0_w941 below_1823 -107
+    w1_w0_w941 below_1824 -115
+    w1_w0_w941 below_1825 -114
+    w1_w0_w941 below_1826 -123
+    w1_w0_w941 below_1827 -122
+    w1_w0_w941 below_1828 -141
+    w1_w0_w941 below_1829 -134
+    w1_w0_w941 below_1830 -140
+    w1_w0_w941 below_1831 -137
+    w1_w0_w941 below_1832 -159
+    w1_w0_w941 below_1833 -146
+    w1_w0_w941 below_1834 -162
+    w1_w0_w941 below_1835 -163
+    w1_w0_w941 below_1836 -153
+    w1_w0_w941 below_1837 -171
+    w1_w0_w941 below_1838 -161
+    w1_w0_w941 below_1839 -169
+    w1_w0_w941 below_1840 -173
+    w1_w0_w941 below_1841 -186
+    w1_w0_w941 below_1842 -176
+    w1_w0_w941 below_1843 -188
+    w1_w0_w941 below_1844 -197
+    w1_w0_w941 below_1845 -198
+    w1_w0_w941 below_1846 -215
+    w1_w0_w941 below_1847 -216
+    w1_w0_w941 below_1848 -218
+    w1_w0_w941 below_1849 -234
+    w1_w0_w941 below_1850 -232
+    w1_w0_w941 below_1851 -220
+    w1_w0_w941 below_1852 -220
+    w1_w0_w941 below_1853 -226
+    w1_w0_w941 below_1854 -231
+    w1_w0_w941 below_1855 -230
+    w1_w0_w941 below_1856 -241
+    w1_w0_w941 below_1857 -209
+    w1_w0_w941 below_1858 -243
+    w1_w0_w941 below_1859 -233
+    w1_w0_w941 below_1860 -247
+    w1_w0_w941 below_1861 -257
+    w1_w0_w941 below_1862 -280
+    w1_w0_w941 below_1863 -261
+    w1_w0_w941 below_1864 -295
+    w1_w0_w941 below_1865 -260
+    w1_w0_w941 below_1866 -266
+    w1_w0_w941 below_1867 -257
+    w1_w0_w941 below_1868 -272
+    w1_w0_w941 below_1869 -271
+    w1_w0_w941 below_1870 -283
+    w1_w0_w941 below_1871 -297
+    w1_w0_w941 below_1872 -302
+    w1_w0_w941 below_1873 -272
+    w1_w0_w941 below_1874 -290
+    w1_w0_w941 below_1875 -302
+    w1_w0_w941 below_1876 -297
+    w1_w0_w941 below_1877 -292
+    w1_w0_w941 below_1878 -308
+    w1_w0_w941 below_1879 -287
+    w1_w0_w941 below_1880 -302
+    w1_w0_w941 below_1881 -286
+    w1_w0_w941 below_1882 -298
+    w1_w0_w941 below_1883 -325
+    w1_w0_w941 below_1884 -318
+    w1_w0_w941 below_1885 -325
+    w1_w0_w941 below_1886 -323
+    w1_w0_w941 below_1887 -325
+    w1_w0_w941 below_1888 -304
+    w1_w0_w941 below_1889 -310
+    w1_w0_w941 below_1890 -309
+    w1_w0_w941 below_1891 -328
+    w1_w0_w941 below_1892 -304
+    w1_w0_w941 below_1893 -321
+    w1_w0_w941 below_1894 -302
+    w1_w0_w941 below_1895 -300
+    w1_w0_w941 below_1896 -323
+    w1_w0_w941 below_1897 -332
+    w1_w0_w941 below_1898 -326
+    w1_w0_w941 below_1899 -312
+    w1_w0_w941 below_1900 -352
+    w1_w0_w941 below_1901 -285
+    w1_w0_w941 below_1902 -306
+    w1_w0_w941 below_1903 -337
+    w1_w0_w941 below_1904 -329
+    w1_w0_w941 below_1905 -349
+    w1_w0_w941 below_1906 -355
+    w1_w0_w941 below_1907 -357
+    w1_w0_w941 below_1908 -355
+    w1_w0_w941 below_1909 -369
+    w1_w0_w941 below_1910 -342
+    w1_w0_w941 below_1911 -357
+    w1_w0_w941 below_1912 -362
+    w1_w0_w941 below_1913 -335
+    w1_w0_w941 below_1914 -349
+    w1_w0_w941 below_1915 -349
+    w1_w0_w941 below_1916 -345
+    w1_w0_w941 below_1917 -369
+    w1_w0_w941 below_1918 -348
+    w1_w0_w941 below_1919 -363
+    w1_w0_w941 below_1920 -345
+    w1_w0_w941 below_1921 -341
+    w1_w0_w941 below_1922 -353
+    w1_w0_w941 below_1923 -368
+    w1_w0_w941 below_1924 -328
+    w1_w0_w941 below_1925 -387
+    w1_w0_w941 below_1926 -322
+    w1_w0_w941 below_1927 -340
+    w1_w0_w941 below_1928 -367
+    w1_w0_w941 below_1929 -343
+    w1_w0_w941 below_1930 -354
+    w1_w0_w941 below_1931 -320
+    w1_w0_w941 below_1932 -320
+    w1_w0_w941 below_1933 -338
+    w1_w0_w941 below_1934 -338
+    w1_w0_w941 below_1935 -374
+    w1_w0_w941 below_1936 -367
+    w1_w0_w941 below_1937 -359
+    w1_w0_w941 below_1938 -373
+    w1_w0_w941 below_1939 -358
+    w1_w0_w941 below_1940 -373
+    w1_w0_w941 below_1941 -393
+    w1_w0_w941 below_1942 -358
+    w1_w0_w941 below_1943 -350
+    w1_w0_w941 below_1944 -365
+    w1_w0_w941 below_1945 -359
+    w1_w0_w941 below_1946 -375
+    w1_w0_w941 below_1947 -398
+    w1_w0_w941 below_1948 -333
+    w1_w0_w941 below_1949 -318
+    w1_w0_w941 below_1950 -346
+    w1_w0_w941 below_1951 -372
+    w1_w0_w941 below_1952 -342
+    w1_w0_w941 below_1953 -344
+    w1_w0_w941 below_1954 -355
+    w1_w0_w941 below_1955 -363
+    w1_w0_w941 below_1956 -344
+    w1_w0_w941 below_1957 -360
+    w1_w0_w941 below_1958 -357
+    w1_w0_w941 below_1959 -364
+    w1_w0_w941 below_1960 -370
+    w1_w0_w941 below_1961 -345
+    w1_w0_w941 below_1962 -334
+    w1_w0_w941 below_1963 -369
+    w1_w0_w941 below_1964 -376
+    w1_w0_w941 below_1965 -345
+    w1_w0_w941 below_1966 -358
+    w1_w0_w941 below_1967 -352
+    w1_w0_w941 below_1968 -350
+    w1_w0_w941 below_1969 -367
+    w1_w0_w941 below_1970 -341
+    w1_w0_w941 below_1971 -369
+    w1_w0_w941 below_1972 -372
+    w1_w0_w941 below_1973 -346
+    w1_w0_w941 below_1974 -334
+    w1_w0_w941 below_1975 -370
+    w1_w0_w941 below_1976 -371
+    w1_w0_w941 below_1977 -355
+    w1_w0_w941 below_1978 -328
+    w1_w0_w941 below_1979 -380
+    w1_w0_w941 below_1980 -357
+    w1_w0_w941 below_1981 -369
+    w1_w0_w941 below_1982 -357
+    w1_w0_w941 below_1983 -348
+    w1_w0_w941 below_1984 -365
+    w1_w0_w941 below_1985 -354
+    w1_w0_w941 below_1986 -362
+    w1_w0_w941 below_1987 -369
+    w1_w0_w941 below_1988 -345
+    w1_w0_w941 below_1989 -353
+    w1_w0_w941 below_1990 -327
+    w1_w0_w941 below_1991 -335
+    w1_w0_w941 below_1992 -353
+    w1_w0_w941 below_1993 -344
+    w1_w0_w941 below_1994 -329
+    w1_w0_w941 below_1995 -337
+    w1_w0_w941 below_1996 -367
+    w1_w0_w941 below_1997 -342
+    w1_w0_w941 below_1998 -364
+    w1_w0_w941 below_1999 -350
+    w1_w0_w941 below_2000 -344
+    w1_w0_w1663 above_1800 111
+    w1_w0_w1663 above_1801 107
+    w1_w0_w1663 above_1802 105
+    w1_w0_w1663 above_1803 104
+    w1_w0_w1663 above_1804 112
+    w1_w0_w1663 above_1805 118
+    w1_w0_w1663 above_1806 116
+    w1_w0_w1663 above_1807 127
+    w1_w0_w1663 above_1808 139
+    w1_w0_w1663 above_1809 142
+    w1_w0_w1663 above_1810 144
+    w1_w0_w1663 above_1811 156
+    w1_w0_w1663 above_1812 142
+    w1_w0_w1663 above_1813 159
+    w1_w0_w1663 above_1814 145
+    w1_w0_w1663 above_1815 162
+    w1_w0_w1663 above_1816 179
+    w1_w0_w1663 above_1817 170
+    w1_w0_w1663 above_1818 182
+    w1_w0_w1663 above_1819 151
+    w1_w0_w1663 above_1820 187
+    w1_w0_w1663 above_1821 187
+    w1_w0_w1663 above_1822 183
+    w1_w0_w1663 above_1823 184
+    w1_w0_w1663 above_1824 185
+    w1_w0_w1663 above_1825 191
+    w1_w0_w1663 above_1826 196
+    w1_w0_w1663 above_1827 207
+    w1_w0_w1663 above_1828 196
+    w1_w0_w1663 above_1829 198
+    w1_w0_w1663 above_1830 194
+    w1_w0_w1663 above_1831 205
+    w1_w0_w1663 above_1832 210
+    w1_w0_w1663 above_1833 206
+    w1_w0_w1663 above_1834 218
+    w1_w0_w1663 above_1835 235
+    w1_w0_w1663 above_1836 209
+    w1_w0_w1663 above_1837 210
+    w1_w0_w1663 above_1838 209
+    w1_w0_w1663 above_1839 220
+    w1_w0_w1663 above_1840 247
+    w1_w0_w1663 above_1841 232
+    w1_w0_w1663 above_1842 226
+    w1_w0_w1663 above_1843 239
+    w1_w0_w1663 above_1844 226
+    w1_w0_w1663 above_1845 228
+    w1_w0_w1663 above_1846 235
+    w1_w0_w1663 above_1847 233
+    w1_w0_w1663 above_1848 244
+    w1_w0_w1663 above_1849 238
+    w1_w0_w1663 above_1850 196
+    w1_w0_w1663 above_1851 230
+    w1_w0_w1663 above_1852 240
+    w1_w0_w1663 above_1853 249
+    w1_w0_w1663 above_1854 243
+    w1_w0_w1663 above_1855 240
+    w1_w0_w1663 above_1856 246
+    w1_w0_w1663 above_1857 251
+    w1_w0_w1663 above_1858 235
+    w1_w0_w1663 above_1859 242
+    w1_w0_w1663 above_1860 253
+    w1_w0_w1663 above_1861 236
+    w1_w0_w1663 above_1862 231
+    w1_w0_w1663 above_1863 245
+    w1_w0_w1663 above_1864 224
+    w1_w0_w1663 above_1865 246
+    w1_w0_w1663 above_1866 248
+    w1_w0_w1663 above_1867 232
+    w1_w0_w1663 above_1868 214
+    w1_w0_w1663 above_1869 239
+    w1_w0_w1663 above_1870 266
+    w1_w0_w1663 above_1871 262
+    w1_w0_w1663 above_1872 234
+    w1_w0_w1663 above_1873 228
+    w1_w0_w1663 above_1874 244
+    w1_w0_w1663 above_1875 256
+    w1_w0_w1663 above_1876 223
+    w1_w0_w1663 above_1877 235
+    w1_w0_w1663 above_1878 243
+    w1_w0_w1663 above_1879 260
+    w1_w0_w1663 above_1880 246
+    w1_w0_w1663 above_1881 256
+    w1_w0_w1663 above_1882 236
+    w1_w0_w1663 above_1883 254
+    w1_w0_w1663 above_1884 235
+    w1_w0_w1663 above_1885 249
+    w1_w0_w1663 above_1886 240
+    w1_w0_w1663 above_1887 250
+    w1_w0_w1663 above_1888 271
+    w1_w0_w1663 above_1889 264
+    w1_w0_w1663 above_1890 263
+    w1_w0_w1663 above_1891 236
+    w1_w0_w1663 above_1892 262
+    w1_w0_w1663 above_1893 242
+    w1_w0_w1663 above_1894 230
+    w1_w0_w1663 above_1895 259
+    w1_w0_w1663 above_1896 240
+    w1_w0_w1663 above_1897 239
+    w1_w0_w1663 above_1898 233
+    w1_w0_w1663 above_1899 266
+    w1_w0_w1663 above_1900 245
+    w1_w0_w1663 above_1901 231
+    w1_w0_w1663 above_1902 251
+    w1_w0_w1663 above_1903 237
+    w1_w0_w1663 above_1904 248
+    w1_w0_w1663 above_1905 251
+    w1_w0_w1663 above_1906 244
+    w1_w0_w1663 above_1907 265
+    w1_w0_w1663 above_1908 253
+    w1_w0_w1663 above_1909 238
+    w1_w0_w1663 above_1910 259
+    w1_w0_w1663 above_1911 223
+    w1_w0_w1663 above_1912 254
+    w1_w0_w1663 above_1913 242
+    w1_w0_w1663 above_1914 261
+    w1_w0_w1663 above_1915 248
+    w1_w0_w1663 above_1916 271
+    w1_w0_w1663 above_1917 262
+    w1_w0_w1663 above_1918 246
+    w1_w0_w1663 above_1919 261
+    w1_w0_w1663 above_1920 230
+    w1_w0_w1663 above_1921 246
+    w1_w0_w1663 above_1922 240
+    w1_w0_w1663 above_1923 234
+    w1_w0_w1663 above_1924 246
+    w1_w0_w1663 above_1925 240
+    w1_w0_w1663 above_1926 256
+    w1_w0_w1663 above_1927 277
+    w1_w0_w1663 above_1928 245
+    w1_w0_w1663 above_1929 244
+    w1_w0_w1663 above_1930 245
+    w1_w0_w1663 above_1931 237
+    w1_w0_w1663 above_1932 267
+    w1_w0_w1663 above_1933 243
+    w1_w0_w1663 above_1934 243
+    w1_w0_w1663 above_1935 260
+    w1_w0_w1663 above_1936 245
+    w1_w0_w1663 above_1937 263
+    w1_w0_w1663 above_1938 243
+    w1_w0_w1663 above_1939 251
+    w1_w0_w1663 above_1940 242
+    w1_w0_w1663 above_1941 253
+    w1_w0_w1663 above_1942 232
+    w1_w0_w1663 above_1943 257
+    w1_w0_w1663 above_1944 258
+    w1_w0_w1663 above_1945 259
+    w1_w0_w1663 above_1946 241
+    w1_w0_w1663 above_1947 261
+    w1_w0_w1663 above_1948 216
+    w1_w0_w1663 above_1949 245
+    w1_w0_w1663 above_1950 240
+    w1_w0_w1663 above_1951 250
+    w1_w0_w1663 above_1952 249
+    w1_w0_w1663 above_1953 258
+    w1_w0_w1663 above_1954 237
+    w1_w0_w1663 above_1955 239
+    w1_w0_w1663 above_1956 258
+    w1_w0_w1663 above_1957 245
+    w1_w0_w1663 above_1958 223
+    w1_w0_w1663 above_1959 220
+    w1_w0_w1663 above_1960 217
+    w1_w0_w1663 above_1961 244
+    w1_w0_w1663 above_1962 266
+    w1_w0_w1663 above_1963 258
+    w1_w0_w1663 above_1964 239
+    w1_w0_w1663 above_1965 235
+    w1_w0_w1663 above_1966 253
+    w1_w0_w1663 above_1967 241
+    w1_w0_w1663 above_1968 251
+    w1_w0_w1663 above_1969 253
+    w1_w0_w1663 above_1970 237
+    w1_w0_w1663 above_1971 257
+    w1_w0_w1663 above_1972 258
+    w1_w0_w1663 above_1973 234
+    w1_w0_w1663 above_1974 247
+    w1_w0_w1663 above_1975 236
+    w1_w0_w1663 above_1976 254
+    w1_w0_w1663 above_1977 237
+    w1_w0_w1663 above_1978 246
+    w1_w0_w1663 above_1979 245
+    w1_w0_w1663 above_1980 236
+    w1_w0_w1663 above_1981 244
+    w1_w0_w1663 above_1982 265
+    w1_w0_w1663 above_1983 236
+    w1_w0_w1663 above_1984 241
+    w1_w0_w1663 above_1985 243
+    w1_w0_w1663 above_1986 260
+    w1_w0_w1663 above_1987 252
+    w1_w0_w1663 above_1988 237
+    w1_w0_w1663 above_1989 268
+    w1_w0_w1663 above_1990 257
+    w1_w0_w1663 above_1991 254
+    w1_w0_w1663 above_1992 244
+    w1_w0_w1663 above_1993 258
+    w1_w0_w1663 above_1994 238
+    w1_w0_w1663 above_1995 252
+    w1_w0_w1663 above_1996 234
+    w1_w0_w1663 above_1997 254
+    w1_w0_w1663 above_1998 259
+    w1_w0_w1663 above_1999 267
+    w1_w0_w1663 above_2000 245
+    w1_w0_w1663 below_1800 -111
+    w1_w0_w1663 below_1801 -107
+    w1_w0_w1663 below_1802 -105
+    w1_w0_w1663 below_1803 -104
+    w1_w0_w1663 below_1804 -112
+    w1_w0_w1663 below_1805 -118
+    w1_w0_w1663 below_1806 -116
+    w1_w0_w1663 below_1807 -127
+    w1_w0_w1663 below_1808 -139
+    w1_w0_w1663 below_1809 -142
+    w1_w0_w1663 below_1810 -144
+    w1_w0_w1663 below_1811 -156
+    w1_w0_w1663 below_1812 -142
+    w1_w0_w1663 below_1813 -159
+    w1_w0_w1663 below_1814 -145
+    w1_w0_w1663 below_1815 -162
+    w1_w0_w1663 below_1816 -179
+    w1_w0_w1663 below_1817 -170
+    w1_w0_w1663 below_1818 -182
+    w1_w0_w1663 below_1819 -151
+    w1_w0_w1663 below_1820 -187
+    w1_w0_w1663 below_1821 -187
+    w1_w0_w1663 below_1822 -183
+    w1_w0_w1663 below_1823 -184
+    w1_w0_w1663 below_1824 -185
+    w1_w0_w1663 below_1825 -191
+    w1_w0_w1663 below_1826 -196
+    w1_w0_w1663 below_1827 -207
+    w1_w0_w1663 below_1828 -196
+    w1_w0_w1663 below_1829 -198
+    w1_w0_w1663 below_1830 -194
+    w1_w0_w1663 below_1831 -205
+    w1_w0_w1663 below_1832 -210
+    w1_w0_w1663 below_1833 -206
+    w1_w0_w1663 below_1834 -218
+    w1_w0_w1663 below_1835 -235
+    w1_w0_w1663 below_1836 -209
+    w1_w0_w1663 below_1837 -210
+    w1_w0_w1663 below_1838 -209
+    w1_w0_w1663 below_1839 -220
+    w1_w0_w1663 below_1840 -247
+    w1_w0_w1663 below_1841 -232
+    w1_w0_w1663 below_1842 -226
+    w1_w0_w1663 below_1843 -239
+    w1_w0_w1663 below_1844 -226
+    w1_w0_w1663 below_1845 -228
+    w1_w0_w1663 below_1846 -235
+    w1_w0_w1663 below_1847 -233
+    w1_w0_w1663 below_1848 -244
+    w1_w0_w1663 below_1849 -238
+    w1_w0_w1663 below_1850 -196
+    w1_w0_w1663 below_1851 -230
+    w1_w0_w1663 below_1852 -240
+    w1_w0_w1663 below_1853 -249
+    w1_w0_w1663 below_1854 -243
+    w1_w0_w1663 below_1855 -240
+    w1_w0_w1663 below_1856 -246
+    w1_w0_w1663 below_1857 -251
+    w1_w0_w1663 below_1858 -235
+    w1_w0_w1663 below_1859 -242
+    w1_w0_w1663 below_1860 -253
+    w1_w0_w1663 below_1861 -236
+    w1_w0_w1663 below_1862 -231
+    w1_w0_w1663 below_1863 -245
+    w1_w0_w1663 below_1864 -224
+    w1_w0_w1663 below_1865 -246
+    w1_w0_w1663 below_1866 -248
+    w1_w0_w1663 below_1867 -232
+    w1_w0_w1663 below_1868 -214
+    w1_w0_w1663 below_1869 -239
+    w1_w0_w1663 below_1870 -266
+    w1_w0_w1663 below_1871 -262
+    w1_w0_w1663 below_1872 -234
+    w1_w0_w1663 below_1873 -228
+    w1_w0_w1663 below_1874 -244
+    w1_w0_w1663 below_1875 -256
+    w1_w0_w1663 below_1876 -223
+    w1_w0_w1663 below_1877 -235
+    w1_w0_w1663 below_1878 -243
+    w1_w0_w1663 below_1879 -260
+    w1_w0_w1663 below_1880 -246
+    w1_w0_w1663 below_1881 -256
+    w1_w0_w1663 below_1882 -236
+    w1_w0_w1663 below_1883 -254
+    w1_w0_w1663 below_1884 -235
+    w1_w0_w1663 below_1885 -249
+    w1_w0_w1663 below_1886 -240
+    w1_w0_w1663 below_1887 -250
+    w1_w0_w1663 below_1888 -271
+    w1_w0_w1663 below_1889 -264
+    w1_w0_w1663 below_1890 -263
+    w1_w0_w1663 below_1891 -236
+    w1_w0_w1663 below_1892 -262
+    w1_w0_w1663 below_1893 -242
+    w1_w0_w1663 below_1894 -230
+    w1_w0_w1663 below_1895 -259
+    w1_w0_w1663 below_1896 -240
+    w1_w0_w1663 below_1897 -239
+    w1_w0_w1663 below_1898 -233
+    w1_w0_w1663 below_1899 -266
+    w1_w0_w1663 below_1900 -245
+    w1_w0_w1663 below_1901 -231
+    w1_w0_w1663 below_1902 -251
+    w1_w0_w1663 below_1903 -237
+    w1_w0_w1663 below_1904 -248
+    w1_w0_w1663 below_1905 -251
+    w1_w0_w1663 below_1906 -244
+    w1_w0_w1663 below_1907 -265
+    w1_w0_w1663 below_1908 -253
+    w1_w0_w1663 below_1909 -238
+    w1_w0_w1663 below_1910 -259
+    w1_w0_w1663 below_1911 -223
+    w1_w0_w1663 below_1912 -254
+    w1_w0_w1663 below_1913 -242
+    w1_w0_w1663 below_1914 -261
+    w1_w0_w1663 below_1915 -248
+    w1_w0_w1663 below_1916 -271
+    w1_w0_w1663 below_1917 -262
+    w1_w0_w1663 below_1918 -246
+    w1_w0_w1663 below_1919 -261
+    w1_w0_w1663 below_1920 -230
+    w1_w0_w1663 below_1921 -246
+    w1_w0_w1663 below_1922 -240
+    w1_w0_w1663 below_1923 -234
+    w1_w0_w1663 below_1924 -246
+    w1_w0_w1663 below_1925 -240
+    w1_w0_w1663 below_1926 -256
+    w1_w0_w1663 below_1927 -277
+    w1_w0_w1663 below_1928 -245
+    w1_w0_w1663 below_1929 -244
+    w1_w0_w1663 below_1930 -245
+    w1_w0_w1663 below_1931 -237
+    w1_w0_w1663 below_1932 -267
+    w1_w0_w1663 below_1933 -243
+    w1_w0_w1663 below_1934 -243
+    w1_w0_w1663 below_1935 -260
+    w1_w0_w1663 below_1936 -245
+    w1_w0_w1663 below_1937 -263
+    w1_w0_w1663 below_1938 -243
+    w1_w0_w1663 below_1939 -251
+    w1_w0_w1663 below_1940 -242
+    w1_w0_w1663 below_1941 -253
+    w1_w0_w1663 below_1942 -232
+    w1_w0_w1663 below_1943 -257
+    w1_w0_w1663 below_1944 -258
+    w1_w0_w1663 below_1945 -259
+    w1_w0_w1663 below_1946 -241
+    w1_w0_w1663 below_1947 -261
+    w1_w0_w1663 below_1948 -216
+    w1_w0_w1663 below_1949 -245
+    w1_w0_w1663 below_1950 -240
+    w1_w0_w1663 below_1951 -250
+    w1_w0_w1663 below_1952 -249
+    w1_w0_w1663 below_1953 -258
+    w1_w0_w1663 below_1954 -237
+    w1_w0_w1663 below_1955 -239
+    w1_w0_w1663 below_1956 -258
+    w1_w0_w1663 below_1957 -245
+    w1_w0_w1663 below_1958 -223
+    w1_w0_w1663 below_1959 -220
+    w1_w0_w1663 below_1960 -217
+    w1_w0_w1663 below_1961 -244
+    w1_w0_w1663 below_1962 -266
+    w1_w0_w1663 below_1963 -258
+    w1_w0_w1663 below_1964 -239
+    w1_w0_w1663 below_1965 -235
+    w1_w0_w1663 below_1966 -253
+    w1_w0_w1663 below_1967 -241
+    w1_w0_w1663 below_1968 -251
+    w1_w0_w1663 below_1969 -253
+    w1_w0_w1663 below_1970 -237
+    w1_w0_w1663 below_1971 -257
+    w1_w0_w1663 below_1972 -258
+    w1_w0_w1663 below_1973 -234
+    w1_w0_w1663 below_1974 -247
+    w1_w0_w1663 below_1975 -236
+    w1_w0_w1663 below_1976 -254
+    w1_w0_w1663 below_1977 -237
+    w1_w0_w1663 below_1978 -246
+    w1_w0_w1663 below_1979 -245
+    w1_w0_w1663 below_1980 -236
+    w1_w0_w1663 below_1981 -244
+    w1_w0_w1663 below_1982 -265
+    w1_w0_w1663 below_1983 -236
+    w1_w0_w1663 below_1984 -241
+    w1_w0_w1663 below_1985 -243
+    w1_w0_w1663 below_1986 -260
+    w1_w0_w1663 below_1987 -252
+    w1_w0_w1663 below_1988 -237
+    w1_w0_w1663 below_1989 -268
+    w1_w0_w1663 below_1990 -257
+    w1_w0_w1663 below_1991 -254
+    w1_w0_w1663 below_1992 -244
+    w1_w0_w1663 below_1993 -258
+    w1_w0_w1663 below_1994 -238
+    w1_w0_w1663 below_1995 -252
+    w1_w0_w1663 below_1996 -234
+    w1_w0_w1663 below_1997 -254
+    w1_w0_w1663 below_1998 -259
+    w1_w0_w1663 below_1999 -267
+    w1_w0_w1663 below_2000 -245
+    w1_w0_w111 above_1800 23
+    w1_w0_w111 above_1801 25
+    w1_w0_w111 above_1802 24
+    w1_w0_w111 above_1803 27
+    w1_w0_w111 above_1804 26
+    w1_w0_w111 above_1805 28
+    w1_w0_w111 above_1806 27
+    w1_w0_w111 above_1807 27
+    w1_w0_w111 above_1808 28
+    w1_w0_w111 above_1809 29
+    w1_w0_w111 above_1810 32
+    w1_w0_w111 above_1811 33
+    w1_w0_w111 above_1812 27
+    w1_w0_w111 above_1813 33
+    w1_w0_w111 above_1814 31
+    w1_w0_w111 above_1815 35
+    w1_w0_w111 above_1816 36
+    w1_w0_w111 above_1817 36
+    w1_w0_w111 above_1818 39
+    w1_w0_w111 above_1819 38
+    w1_w0_w111 above_1820 38
+    w1_w0_w111 above_1821 40
+    w1_w0_w111 above_1822 44
+    w1_w0_w111 above_1823 43
+    w1_w0_w111 above_1824 41
+    w1_w0_w111 above_1825 42
+    w1_w0_w111 above_1826 46
+    w1_w0_w111 above_1827 50
+    w1_w0_w111 above_1828 49
+    w1_w0_w111 above_1829 50
+    w1_w0_w111 above_1830 45
+    w1_w0_w111 above_1831 49
+    w1_w0_w111 above_1832 54
+    w1_w0_w111 above_1833 52
+    w1_w0_w111 above_1834 52
+    w1_w0_w111 above_1835 50
+    w1_w0_w111 above_1836 61
+    w1_w0_w111 above_1837 56
+    w1_w0_w111 above_1838 61
+    w1_w0_w111 above_1839 59
+    w1_w0_w111 above_1840 60
+    w1_w0_w111 above_1841 64
+    w1_w0_w111 above_1842 69
+    w1_w0_w111 above_1843 71
+    w1_w0_w111 above_1844 69
+    w1_w0_w111 above_1845 65
+    w1_w0_w111 above_1846 68
+    w1_w0_w111 above_1847 72
+    w1_w0_w111 above_1848 85
+    w1_w0_w111 above_1849 75
+    w1_w0_w111 above_1850 75
+    w1_w0_w111 above_1851 81
+    w1_w0_w111 above_1852 83
+    w1_w0_w111 above_1853 85
+    w1_w0_w111 above_1854 93
+    w1_w0_w111 above_1855 89
+    w1_w0_w111 above_1856 86
+    w1_w0_w111 above_1857 92
+    w1_w0_w111 above_1858 93
+    w1_w0_w111 above_1859 97
+    w1_w0_w111 above_1860 98
+    w1_w0_w111 above_1861 101
+    w1_w0_w111 above_1862 106
+    w1_w0_w111 above_1863 97
+    w1_w0_w111 above_1864 102
+    w1_w0_w111 above_1865 104
+    w1_w0_w111 above_1866 117
+    w1_w0_w111 above_1867 106
+    w1_w0_w111 above_1868 111
+    w1_w0_w111 above_1869 110
+    w1_w0_w111 above_1870 121
+    w1_w0_w111 above_1871 116
+    w1_w0_w111 above_1872 120
+    w1_w0_w111 above_1873 114
+    w1_w0_w111 above_1874 136
+    w1_w0_w111 above_1875 131
+    w1_w0_w111 above_1876 137
+    w1_w0_w111 above_1877 125
+    w1_w0_w111 above_1878 139
+    w1_w0_w111 above_1879 140
+    w1_w0_w111 above_1880 150
+    w1_w0_w111 above_1881 139
+    w1_w0_w111 above_1882 151
+    w1_w0_w111 above_1883 143
+    w1_w0_w111 above_1884 143
+    w1_w0_w111 above_1885 160
+    w1_w0_w111 above_1886 163
+    w1_w0_w111 above_1887 161
+    w1_w0_w111 above_1888 165
+    w1_w0_w111 above_1889 163
+    w1_w0_w111 above_1890 157
+    w1_w0_w111 above_1891 181
+    w1_w0_w111 above_1892 180
+    w1_w0_w111 above_1893 176
+    w1_w0_w111 above_1894 171
+    w1_w0_w111 above_1895 188
+    w1_w0_w111 above_1896 199
+    w1_w0_w111 above_1897 187
+    w1_w0_w111 above_1898 204
+    w1_w0_w111 above_1899 193
+    w1_w0_w111 above_1900 209
+    w1_w0_w111 above_1901 195
+    w1_w0_w111 above_1902 204
+    w1_w0_w111 above_1903 231
+    w1_w0_w111 above_1904 217
+    w1_w0_w111 above_1905 205
+    w1_w0_w111 above_1906 233
+    w1_w0_w111 above_1907 223
+    w1_w0_w111 above_1908 222
+    w1_w0_w111 above_1909 237
+    w1_w0_w111 above_1910 236
+    w1_w0_w111 above_1911 226
+    w1_w0_w111 above_1912 236
+    w1_w0_w111 above_1913 225
+    w1_w0_w111 above_1914 230
+    w1_w0_w111 above_1915 256
+    w1_w0_w111 above_1916 234
+    w1_w0_w111 above_1917 226
+    w1_w0_w111 above_1918 255
+    w1_w0_w111 above_1919 260
+    w1_w0_w111 above_1920 261
+    w1_w0_w111 above_1921 250
+    w1_w0_w111 above_1922 285
+    w1_w0_w111 above_1923 266
+    w1_w0_w111 above_1924 274
+    w1_w0_w111 above_1925 283
+    w1_w0_w111 above_1926 288
+    w1_w0_w111 above_1927 279
+    w1_w0_w111 above_1928 283
+    w1_w0_w111 above_1929 289
+    w1_w0_w111 above_1930 293
+    w1_w0_w111 above_1931 280
+    w1_w0_w111 above_1932 269
+    w1_w0_w111 above_1933 290
+    w1_w0_w111 above_1934 318
+    w1_w0_w111 above_1935 293
+    w1_w0_w111 above_1936 294
+    w1_w0_w111 above_1937 312
+    w1_w0_w111 above_1938 311
+    w1_w0_w111 above_1939 321
+    w1_w0_w111 above_1940 302
+    w1_w0_w111 above_1941 311
+    w1_w0_w111 above_1942 310
+    w1_w0_w111 above_1943 358
+    w1_w0_w111 above_1944 336
+    w1_w0_w111 above_1945 325
+    w1_w0_w111 above_1946 319
+    w1_w0_w111 above_1947 327
+    w1_w0_w111 above_1948 345
+    w1_w0_w111 above_1949 326
+    w1_w0_w111 above_1950 332
+    w1_w0_w111 above_1951 303
+    w1_w0_w111 above_1952 386
+    w1_w0_w111 above_1953 342
+    w1_w0_w111 above_1954 352
+    w1_w0_w111 above_1955 349
+    w1_w0_w111 above_1956 327
+    w1_w0_w111 above_1957 359
+    w1_w0_w111 above_1958 359
+    w1_w0_w111 above_1959 347
+    w1_w0_w111 above_1960 353
+    w1_w0_w111 above_1961 361
+    w1_w0_w111 above_1962 347
+    w1_w0_w111 above_1963 387
+    w1_w0_w111 above_1964 368
+    w1_w0_w111 above_1965 385
+    w1_w0_w111 above_1966 382
+    w1_w0_w111 above_1967 382
+    w1_w0_w111 above_1968 405
+    w1_w0_w111 above_1969 401
+    w1_w0_w111 above_1970 380
+    w1_w0_w111 above_1971 387
+    w1_w0_w111 above_1972 398
+    w1_w0_w111 above_1973 376
+    w1_w0_w111 above_1974 398
+    w1_w0_w111 above_1975 434
+    w1_w0_w111 above_1976 416
+    w1_w0_w111 above_1977 402
+    w1_w0_w111 above_1978 383
+    w1_w0_w111 above_1979 371
+    w1_w0_w111 above_1980 414
+    w1_w0_w111 above_1981 417
+    w1_w0_w111 above_1982 433
+    w1_w0_w111 above_1983 400
+    w1_w0_w111 above_1984 406
+    w1_w0_w111 above_1985 436
+    w1_w0_w111 above_1986 421
+    w1_w0_w111 above_1987 441
+    w1_w0_w111 above_1988 416
+    w1_w0_w111 above_1989 426
+    w1_w0_w111 above_1990 457
+    w1_w0_w111 above_1991 406
+    w1_w0_w111 above_1992 409
+    w1_w0_w111 above_1993 429
+    w1_w0_w111 above_1994 427
+    w1_w0_w111 above_1995 420
+    w1_w0_w111 above_1996 416
+    w1_w0_w111 above_1997 439
+    w1_w0_w111 above_1998 498
+    w1_w0_w111 above_1999 481
+    w1_w0_w111 above_2000 441
+    w1_w0_w111 below_1800 -23
+    w1_w0_w111 below_1801 -25
+    w1_w0_w111 below_1802 -24
+    w1_w0_w111 below_1803 -27
+    w1_w0_w111 below_1804 -26
+    w1_w0_w111 below_1805 -28
+    w1_w0_w111 below_1806 -27
+    w1_w0_w111 below_1807 -27
+    w1_w0_w111 below_1808 -28
+    w1_w0_w111 below_1809 -29
+    w1_w0_w111 below_1810 -32
+    w1_w0_w111 below_1811 -33
+    w1_w0_w111 below_1812 -27
+    w1_w0_w111 below_1813 -33
+    w1_w0_w111 below_1814 -31
+    w1_w0_w111 below_1815 -35
+    w1_w0_w111 below_1816 -36
+    w1_w0_w111 below_1817 -36
+    w1_w0_w111 below_1818 -39
+    w1_w0_w111 below_1819 -38
+    w1_w0_w111 below_1820 -38
+    w1_w0_w111 below_1821 -40
+    w1_w0_w111 below_1822 -44
+    w1_w0_w111 below_1823 -43
+    w1_w0_w111 below_1824 -41
+    w1_w0_w111 below_1825 -42
+    w1_w0_w111 below_1826 -46
+    w1_w0_w111 below_1827 -50
+    w1_w0_w111 below_1828 -49
+    w1_w0_w111 below_1829 -50
+    w1_w0_w111 below_1830 -45
+    w1_w0_w111 below_1831 -49
+    w1_w0_w111 below_1832 -54
+    w1_w0_w111 below_1833 -52
+    w1_w0_w111 below_1834 -52
+    w1_w0_w111 below_1835 -50
+    w1_w0_w111 below_1836 -61
+    w1_w0_w111 below_1837 -56
+    w1_w0_w111 below_1838 -61
+    w1_w0_w111 below_1839 -59
+    w1_w0_w111 below_1840 -60
+    w1_w0_w111 below_1841 -64
+    w1_w0_w111 below_1842 -69
+    w1_w0_w111 below_1843 -71
+    w1_w0_w111 below_1844 -69
+    w1_w0_w111 below_1845 -65
+    w1_w0_w111 below_1846 -68
+    w1_w0_w111 below_1847 -72
+    w1_w0_w111 below_1848 -85
+    w1_w0_w111 below_1849 -75
+    w1_w0_w111 below_1850 -75
+    w1_w0_w111 below_1851 -81
+    w1_w0_w111 below_1852 -83
+    w1_w0_w111 below_1853 -85
+    w1_w0_w111 below_1854 -93
+    w1_w0_w111 below_1855 -89
+    w1_w0_w111 below_1856 -86
+    w1_w0_w111 below_1857 -92
+    w1_w0_w111 below_1858 -93
+    w1_w0_w111 below_1859 -97
+    w1_w0_w111 below_1860 -98
+    w1_w0_w111 below_1861 -101
+    w1_w0_w111 below_1862 -106
+    w1_w0_w111 below_1863 -97
+    w1_w0_w111 below_1864 -102
+    w1_w0_w111 below_1865 -104
+    w1_w0_w111 below_1866 -117
+    w1_w0_w111 below_1867 -106
+    w1_w0_w111 below_1868 -111
+    w1_w0_w111 below_1869 -110
+    w1_w0_w111 below_1870 -121
+    w1_w0_w111 below_1871 -116
+    w1_w0_w111 below_1872 -120
+    w1_w0_w111 below_1873 -114
+    w1_w0_w111 below_1874 -136
+    w1_w0_w111 below_1875 -131
+    w1_w0_w111 below_1876 -137
+    w1_w0_w111 below_1877 -125
+    w1_w0_w111 below_1878 -139
+    w1_w0_w111 below_1879 -140
+    w1_w0_w111 below_1880 -150
+    w1_w0_w111 below_1881 -139
+    w1_w0_w111 below_1882 -151
+    w1_w0_w111 below_1883 -143
+    w1_w0_w111 below_1884 -143
+    w1_w0_w111 below_1885 -160
+    w1_w0_w111 below_1886 -163
+    w1_w0_w111 below_1887 -161
+    w1_w0_w111 below_1888 -165
+    w1_w0_w111 below_1889 -163
+    w1_w0_w111 below_1890 -157
+    w1_w0_w111 below_1891 -181
+    w1_w0_w111 below_1892 -180
+    w1_w0_w111 below_1893 -176
+    w1_w0_w111 below_1894 -171
+    w1_w0_w111 below_1895 -188
+    w1_w0_w111 below_1896 -199
+    w1_w0_w111 below_1897 -187
+    w1_w0_w111 below_1898 -204
+    w1_w0_w111 below_1899 -193
+    w1_w0_w111 below_1900 -209
+    w1_w0_w111 below_1901 -195
+    w1_w0_w111 below_1902 -204
+    w1_w0_w111 below_1903 -231
+    w1_w0_w111 below_1904 -217
+    w1_w0_w111 below_1905 -205
+    w1_w0_w111 below_1906 -233
+    w1_w0_w111 below_1907 -223
+    w1_w0_w111 below_1908 -222
+    w1_w0_w111 below_1909 -237
+    w1_w0_w111 below_1910 -236
+    w1_w0_w111 below_1911 -226
+    w1_w0_w111 below_1912 -236
+    w1_w0_w111 below_1913 -225
+    w1_w0_w111 below_1914 -230
+    w1_w0_w111 below_1915 -256
+    w1_w0_w111 below_1916 -234
+    w1_w0_w111 below_1917 -226
+    w1_w0_w111 below_1918 -255
+    w1_w0_w111 below_1919 -260
+    w1_w0_w111 below_1920 -261
+    w1_w0_w111 below_1921 -250
+    w1_w0_w111 below_1922 -285
+    w1_w0_w111 below_1923 -266
+    w1_w0_w111 below_1924 -274
+    w1_w0_w111 below_1925 -283
+    w1_w0_w111 below_1926 -288
+    w1_w0_w111 below_1927 -279
+    w1_w0_w111 below_1928 -283
+    w1_w0_w111 below_1929 -289
+    w1_w0_w111 below_1930 -293
+    w1_w0_w111 below_1931 -280
+    w1_w0_w111 below_1932 -269
+    w1_w0_w111 below_1933 -290
+    w1_w0_w111 below_1934 -318
+    w1_w0_w111 below_1935 -293
+    w1_w0_w111 below_1936 -294
+    w1_w0_w111 below_1937 -312
+    w1_w0_w111 below_1938 -311
+    w1_w0_w111 below_1939 -321
+    w1_w0_w111 below_1940 -302
+    w1_w0_w111 below_1941 -311
+    w1_w0_w111 below_1942 -310
+    w1_w0_w111 below_1943 -358
+    w1_w0_w111 below_1944 -336
+    w1_w0_w111 below_1945 -325
+    w1_w0_w111 below_1946 -319
+    w1_w0_w111 below_1947 -327
+    w1_w0_w111 below_1948 -345
+    w1_w0_w111 below_1949 -326
+    w1_w0_w111 below_1950 -332
+    w1_w0_w111 below_1951 -303
+    w1_w0_w111 below_1952 -386
+    w1_w0_w111 below_1953 -342
+    w1_w0_w111 below_1954 -352
+    w1_w0_w111 below_1955 -349
+    w1_w0_w111 below_1956 -327
+    w1_w0_w111 below_1957 -359
+    w1_w0_w111 below_1958 -359
+    w1_w0_w111 below_1959 -347
+    w1_w0_w111 below_1960 -353
+    w1_w0_w111 below_1961 -361
+    w1_w0_w111 below_1962 -347
+    w1_w0_w111 below_1963 -387
+    w1_w0_w111 below_1964 -368
+    w1_w0_w111 below_1965 -385
+    w1_w0_w111 below_1966 -382
+    w1_w0_w111 below_1967 -382
+    w1_w0_w111 below_1968 -405
+    w1_w0_w111 below_1969 -401
+    w1_w0_w111 below_1970 -380
+    w1_w0_w111 below_1971 -387
+    w1_w0_w111 below_1972 -398
+    w1_w0_w111 below_1973 -376
+    w1_w0_w111 below_1974 -398
+    w1_w0_w111 below_1975 -434
+    w1_w0_w111 below_1976 -416
+    w1_w0_w111 below_1977 -402
+    w1_w0_w111 below_1978 -383
+    w1_w0_w111 below_1979 -371
+    w1_w0_w111 below_1980 -414
+    w1_w0_w111 below_1981 -417
+    w1_w0_w111 below_1982 -433
+    w1_w0_w111 below_1983 -400
+    w1_w0_w111 below_1984 -406
+    w1_w0_w111 below_1985 -436
+    w1_w0_w111 below_1986 -421
+    w1_w0_w111 below_1987 -441
+    w1_w0_w111 below_1988 -416
+    w1_w0_w111 below_1989 -426
+    w1_w0_w111 below_1990 -457
+    w1_w0_w111 below_1991 -406
+    w1_w0_w111 below_1992 -409
+    w1_w0_w111 below_1993 -429
+    w1_w0_w111 below_1994 -427
+    w1_w0_w111 below_1995 -420
+    w1_w0_w111 below_1996 -416
+    w1_w0_w111 below_1997 -439
+    w1_w0_w111 below_1998 -498
+    w1_w0_w111 below_1999 -481
+    w1_w0_w111 below_2000 -441
+    w1_w0_w119 above_1800 13
+    w1_w0_w119 above_1801 13
+    w1_w0_w119 above_1802 14
+    w1_w0_w119 above_1803 14
+    w1_w0_w119 above_1804 14
+    w1_w0_w119 above_1805 18
+    w1_w0_w119 above_1806 18
+    w1_w0_w119 above_1807 17
+    w1_w0_w119 above_1808 17
+    w1_w0_w119 above_1809 18
+    w1_w0_w119 above_1810 18
+    w1_w0_w119 above_1811 19
+    w1_w0_w119 above_1812 19
+    w1_w0_w119 above_1813 20
+    w1_w0_w119 above_1814 24
+    w1_w0_w119 above_1815 22
+    w1_w0_w119 above_1816 24
+    w1_w0_w119 above_1817 24
+    w1_w0_w119 above_1818 26
+    w1_w0_w119 above_1819 25
+    w1_w0_w119 above_1820 25
+    w1_w0_w119 above_1821 27
+    w1_w0_w119 above_1822 26
+    w1_w0_w119 above_1823 29
+    w1_w0_w119 above_1824 26
+    w1_w0_w119 above_1825 32
+    w1_w0_w119 above_1826 33
+    w1_w0_w119 above_1827 34
+    w1_w0_w119 above_1828 32
+    w1_w0_w119 above_1829 33
+    w1_w0_w119 above_1830 40
+    w1_w0_w119 above_1831 39
+    w1_w0_w119 above_1832 41
+    w1_w0_w119 above_1833 38
+    w1_w0_w119 above_1834 42
+    w1_w0_w119 above_1835 44
+    w1_w0_w119 above_1836 48
+    w1_w0_w119 above_1837 44
+    w1_w0_w119 above_1838 46
+    w1_w0_w119 above_1839 53
+    w1_w0_w119 above_1840 51
+    w1_w0_w119 above_1841 56
+    w1_w0_w119 above_1842 53
+    w1_w0_w119 above_1843 59
+    w1_w0_w119 above_1844 56
+    w1_w0_w119 above_1845 60
+    w1_w0_w119 above_1846 63
+    w1_w0_w119 above_1847 68
+    w1_w0_w119 above_1848 65
+    w1_w0_w119 above_1849 67
+    w1_w0_w119 above_1850 73
+    w1_w0_w119 above_1851 67
+    w1_w0_w119 above_1852 72
+    w1_w0_w119 above_1853 76
+    w1_w0_w119 above_1854 75
+    w1_w0_w119 above_1855 76
+    w1_w0_w119 above_1856 79
+    w1_w0_w119 above_1857 87
+    w1_w0_w119 above_1858 83
+    w1_w0_w119 above_1859 81
+    w1_w0_w119 above_1860 91
+    w1_w0_w119 above_1861 87
+    w1_w0_w119 above_1862 92
+    w1_w0_w119 above_1863 96
+    w1_w0_w119 above_1864 106
+    w1_w0_w119 above_1865 93
+    w1_w0_w119 above_1866 96
+    w1_w0_w119 above_1867 109
+    w1_w0_w119 above_1868 113
+    w1_w0_w119 above_1869 118
+    w1_w0_w119 above_1870 118
+    w1_w0_w119 above_1871 124
+    w1_w0_w119 above_1872 115
+    w1_w0_w119 above_1873 124
+    w1_w0_w119 above_1874 129
+    w1_w0_w119 above_1875 118
+    w1_w0_w119 above_1876 128
+    w1_w0_w119 above_1877 134
+    w1_w0_w119 above_1878 131
+    w1_w0_w119 above_1879 149
+    w1_w0_w119 above_1880 158
+    w1_w0_w119 above_1881 147
+    w1_w0_w119 above_1882 152
+    w1_w0_w119 above_1883 147
+    w1_w0_w119 above_1884 140
+    w1_w0_w119 above_1885 153
+    w1_w0_w119 above_1886 148
+    w1_w0_w119 above_1887 169
+    w1_w0_w119 above_1888 176
+    w1_w0_w119 above_1889 174
+    w1_w0_w119 above_1890 162
+    w1_w0_w119 above_1891 168
+    w1_w0_w119 above_1892 168
+    w1_w0_w119 above_1893 178
+    w1_w0_w119 above_1894 168
+    w1_w0_w119 above_1895 179
+    w1_w0_w119 above_1896 197
+    w1_w0_w119 above_1897 206
+    w1_w0_w119 above_1898 189
+    w1_w0_w119 above_1899 179
+    w1_w0_w119 above_1900 200
+    w1_w0_w119 above_1901 204
+    w1_w0_w119 above_1902 199
+    w1_w0_w119 above_1903 191
+    w1_w0_w119 above_1904 201
+    w1_w0_w119 above_1905 221
+    w1_w0_w119 above_1906 206
+    w1_w0_w119 above_1907 219
+    w1_w0_w119 above_1908 233
+    w1_w0_w119 above_1909 203
+    w1_w0_w119 above_1910 231
+    w1_w0_w119 above_1911 239
+    w1_w0_w119 above_1912 227
+    w1_w0_w119 above_1913 231
+    w1_w0_w119 above_1914 225
+    w1_w0_w119 above_1915 255
+    w1_w0_w119 above_1916 243
+    w1_w0_w119 above_1917 249
+    w1_w0_w119 above_1918 283
+    w1_w0_w119 above_1919 253
+    w1_w0_w119 above_1920 273
+    w1_w0_w119 above_1921 286
+    w1_w0_w119 above_1922 295
+    w1_w0_w119 above_1923 279
+    w1_w0_w119 above_1924 268
+    w1_w0_w119 above_1925 272
+    w1_w0_w119 above_1926 260
+    w1_w0_w119 above_1927 283
+    w1_w0_w119 above_1928 268
+    w1_w0_w119 above_1929 326
+    w1_w0_w119 above_1930 297
+    w1_w0_w119 above_1931 286
+    w1_w0_w119 above_1932 263
+    w1_w0_w119 above_1933 309
+    w1_w0_w119 above_1934 323
+    w1_w0_w119 above_1935 300
+    w1_w0_w119 above_1936 308
+    w1_w0_w119 above_1937 281
+    w1_w0_w119 above_1938 320
+    w1_w0_w119 above_1939 293
+    w1_w0_w119 above_1940 308
+    w1_w0_w119 above_1941 328
+    w1_w0_w119 above_1942 312
+    w1_w0_w119 above_1943 307
+    w1_w0_w119 above_1944 318
+    w1_w0_w119 above_1945 300
+    w1_w0_w119 above_1946 312
+    w1_w0_w119 above_1947 316
+    w1_w0_w119 above_1948 327
+    w1_w0_w119 above_1949 324
+    w1_w0_w119 above_1950 314
+    w1_w0_w119 above_1951 352
+    w1_w0_w119 above_1952 274
+    w1_w0_w119 above_1953 330
+    w1_w0_w119 above_1954 331
+    w1_w0_w119 above_1955 331
+    w1_w0_w119 above_1956 338
+    w1_w0_w119 above_1957 335
+    w1_w0_w119 above_1958 331
+    w1_w0_w119 above_1959 336
+    w1_w0_w119 above_1960 333
+    w1_w0_w119 above_1961 332
+    w1_w0_w119 above_1962 317
+    w1_w0_w119 above_1963 325
+    w1_w0_w119 above_1964 336
+    w1_w0_w119 above_1965 322
+    w1_w0_w119 above_1966 295
+    w1_w0_w119 above_1967 328
+    w1_w0_w119 above_1968 315
+    w1_w0_w119 above_1969 326
+    w1_w0_w119 above_1970 325
+    w1_w0_w119 above_1971 336
+    w1_w0_w119 above_1972 331
+    w1_w0_w119 above_1973 311
+    w1_w0_w119 above_1974 314
+    w1_w0_w119 above_1975 330
+    w1_w0_w119 above_1976 309
+    w1_w0_w119 above_1977 314
+    w1_w0_w119 above_1978 326
+    w1_w0_w119 above_1979 339
+    w1_w0_w119 above_1980 335
+    w1_w0_w119 above_1981 344
+    w1_w0_w119 above_1982 373
+    w1_w0_w119 above_1983 331
+    w1_w0_w119 above_1984 312
+    w1_w0_w119 above_1985 315
+    w1_w0_w119 above_1986 335
+    w1_w0_w119 above_1987 319
+    w1_w0_w119 above_1988 337
+    w1_w0_w119 above_1989 334
+    w1_w0_w119 above_1990 332
+    w1_w0_w119 above_1991 318
+    w1_w0_w119 above_1992 343
+    w1_w0_w119 above_1993 353
+    w1_w0_w119 above_1994 335
+    w1_w0_w119 above_1995 315
+    w1_w0_w119 above_1996 319
+    w1_w0_w119 above_1997 320
+    w1_w0_w119 above_1998 322
+    w1_w0_w119 above_1999 307
+    w1_w0_w119 above_2000 322
+    w1_w0_w119 below_1800 -13
+    w1_w0_w119 below_1801 -13
+    w1_w0_w119 below_1802 -14
+    w1_w0_w119 below_1803 -14
+    w1_w0_w119 below_1804 -14
+    w1_w0_w119 below_1805 -18
+    w1_w0_w119 below_1806 -18
+    w1_w0_w119 below_1807 -17
+    w1_w0_w119 below_1808 -17
+    w1_w0_w119 below_1809 -18
+    w1_w0_w119 below_1810 -18
+    w1_w0_w119 below_1811 -19
+    w1_w0_w119 below_1812 -19
+    w1_w0_w119 below_1813 -20
+    w1_w0_w119 below_1814 -24
+    w1_w0_w119 below_1815 -22
+    w1_w0_w119 below_1816 -24
+    w1_w0_w119 below_1817 -24
+    w1_w0_w119 below_1818 -26
+    w1_w0_w119 below_1819 -25
+    w1_w0_w119 below_1820 -25
+    w1_w0_w119 below_1821 -27
+    w1_w0_w119 below_1822 -26
+    w1_w0_w119 below_1823 -29
+    w1_w0_w119 below_1824 -26
+    w1_w0_w119 below_1825 -32
+    w1_w0_w119 below_1826 -33
+    w1_w0_w119 below_1827 -34
+    w1_w0_w119 below_1828 -32
+    w1_w0_w119 below_1829 -33
+    w1_w0_w119 below_1830 -40
+    w1_w0_w119 below_1831 -39
+    w1_w0_w119 below_1832 -41
+    w1_w0_w119 below_1833 -38
+    w1_w0_w119 below_1834 -42
+    w1_w0_w119 below_1835 -44
+    w1_w0_w119 below_1836 -48
+    w1_w0_w119 below_1837 -44
+    w1_w0_w119 below_1838 -46
+    w1_w0_w119 below_1839 -53
+    w1_w0_w119 below_1840 -51
+    w1_w0_w119 below_1841 -56
+    w1_w0_w119 below_1842 -53
+    w1_w0_w119 below_1843 -59
+    w1_w0_w119 below_1844 -56
+    w1_w0_w119 below_1845 -60
+    w1_w0_w119 below_1846 -63
+    w1_w0_w119 below_1847 -68
+    w1_w0_w119 below_1848 -65
+    w1_w0_w119 below_1849 -67
+    w1_w0_w119 below_1850 -73
+    w1_w0_w119 below_1851 -67
+    w1_w0_w119 below_1852 -72
+    w1_w0_w119 below_1853 -76
+    w1_w0_w119 below_1854 -75
+    w1_w0_w119 below_1855 -76
+    w1_w0_w119 below_1856 -79
+    w1_w0_w119 below_1857 -87
+    w1_w0_w119 below_1858 -83
+    w1_w0_w119 below_1859 -81
+    w1_w0_w119 below_1860 -91
+    w1_w0_w119 below_1861 -87
+    w1_w0_w119 below_1862 -92
+    w1_w0_w119 below_1863 -96
+    w1_w0_w119 below_1864 -106
+    w1_w0_w119 below_1865 -93
+    w1_w0_w119 below_1866 -96
+    w1_w0_w119 below_1867 -109
+    w1_w0_w119 below_1868 -113
+    w1_w0_w119 below_1869 -118
+    w1_w0_w119 below_1870 -118
+    w1_w0_w119 below_1871 -124
+    w1_w0_w119 below_1872 -115
+    w1_w0_w119 below_1873 -124
+    w1_w0_w119 below_1874 -129
+    w1_w0_w119 below_1875 -118
+    w1_w0_w119 below_1876 -128
+    w1_w0_w119 below_1877 -134
+    w1_w0_w119 below_1878 -131
+    w1_w0_w119 below_1879 -149
+    w1_w0_w119 below_1880 -158
+    w1_w0_w119 below_1881 -147
+    w1_w0_w119 below_1882 -152
+    w1_w0_w119 below_1883 -147
+    w1_w0_w119 below_1884 -140
+    w1_w0_w119 below_1885 -153
+    w1_w0_w119 below_1886 -148
+    w1_w0_w119 below_1887 -169
+    w1_w0_w119 below_1888 -176
+    w1_w0_w119 below_1889 -174
+    w1_w0_w119 below_1890 -162
+    w1_w0_w119 below_1891 -168
+    w1_w0_w119 below_1892 -168
+    w1_w0_w119 below_1893 -178
+    w1_w0_w119 below_1894 -168
+    w1_w0_w119 below_1895 -179
+    w1_w0_w119 below_1896 -197
+    w1_w0_w119 below_1897 -206
+    w1_w0_w119 below_1898 -189
+    w1_w0_w119 below_1899 -179
+    w1_w0_w119 below_1900 -200
+    w1_w0_w119 below_1901 -204
+    w1_w0_w119 below_1902 -199
+    w1_w0_w119 below_1903 -191
+    w1_w0_w119 below_1904 -201
+    w1_w0_w119 below_1905 -221
+    w1_w0_w119 below_1906 -206
+    w1_w0_w119 below_1907 -219
+    w1_w0_w119 below_1908 -233
+    w1_w0_w119 below_1909 -203
+    w1_w0_w119 below_1910 -231
+    w1_w0_w119 below_1911 -239
+    w1_w0_w119 below_1912 -227
+    w1_w0_w119 below_1913 -231
+    w1_w0_w119 below_1914 -225
+    w1_w0_w119 below_1915 -255
+    w1_w0_w119 below_1916 -243
+    w1_w0_w119 below_1917 -249
+    w1_w0_w119 below_1918 -283
+    w1_w0_w119 below_1919 -253
+    w1_w0_w119 below_1920 -273
+    w1_w0_w119 below_1921 -286
+    w1_w0_w119 below_1922 -295
+    w1_w0_w119 below_1923 -279
+    w1_w0_w119 below_1924 -268
+    w1_w0_w119 below_1925 -272
+    w1_w0_w119 below_1926 -260
+    w1_w0_w119 below_1927 -283
+    w1_w0_w119 below_1928 -268
+    w1_w0_w119 below_1929 -326
+    w1_w0_w119 below_1930 -297
+    w1_w0_w119 below_1931 -286
+    w1_w0_w119 below_1932 -263
+    w1_w0_w119 below_1933 -309
+    w1_w0_w119 below_1934 -323
+    w1_w0_w119 below_1935 -300
+    w1_w0_w119 below_1936 -308
+    w1_w0_w119 below_1937 -281
+    w1_w0_w119 below_1938 -320
+    w1_w0_w119 below_1939 -293
+    w1_w0_w119 below_1940 -308
+    w1_w0_w119 below_1941 -328
+    w1_w0_w119 below_1942 -312
+    w1_w0_w119 below_1943 -307
+    w1_w0_w119 below_1944 -318
+    w1_w0_w119 below_1945 -300
+    w1_w0_w119 below_1946 -312
+    w1_w0_w119 below_1947 -316
+    w1_w0_w119 below_1948 -327
+    w1_w0_w119 below_1949 -324
+    w1_w0_w119 below_1950 -314
+    w1_w0_w119 below_1951 -352
+    w1_w0_w119 below_1952 -274
+    w1_w0_w119 below_1953 -330
+    w1_w0_w119 below_1954 -331
+    w1_w0_w119 below_1955 -331
+    w1_w0_w119 below_1956 -338
+    w1_w0_w119 below_1957 -335
+    w1_w0_w119 below_1958 -331
+    w1_w0_w119 below_1959 -336
+    w1_w0_w119 below_1960 -333
+    w1_w0_w119 below_1961 -332
+    w1_w0_w119 below_1962 -317
+    w1_w0_w119 below_1963 -325
+    w1_w0_w119 below_1964 -336
+    w1_w0_w119 below_1965 -322
+    w1_w0_w119 below_1966 -295
+    w1_w0_w119 below_1967 -328
+    w1_w0_w119 below_1968 -315
+    w1_w0_w119 below_1969 -326
+    w1_w0_w119 below_1970 -325
+    w1_w0_w119 below_1971 -336
+    w1_w0_w119 below_1972 -331
+    w1_w0_w119 below_1973 -311
+    w1_w0_w119 below_1974 -314
+    w1_w0_w119 below_1975 -330
+    w1_w0_w119 below_1976 -309
+    w1_w0_w119 below_1977 -314
+    w1_w0_w119 below_1978 -326
+    w1_w0_w119 below_1979 -339
+    w1_w0_w119 below_1980 -335
+    w1_w0_w119 below_1981 -344
+    w1_w0_w119 below_1982 -373
+    w1_w0_w119 below_1983 -331
+    w1_w0_w119 below_1984 -312
+    w1_w0_w119 below_1985 -315
+    w1_w0_w119 below_1986 -335
+    w1_w0_w119 below_1987 -319
+    w1_w0_w119 below_1988 -337
+    w1_w0_w119 below_1989 -334
+    w1_w0_w119 below_1990 -332
+    w1_w0_w119 below_1991 -318
+    w1_w0_w119 below_1992 -343
+    w1_w0_w119 below_1993 -353
+    w1_w0_w119 below_1994 -335
+    w1_w0_w119 below_1995 -315
+    w1_w0_w119 below_1996 -319
+    w1_w0_w119 below_1997 -320
+    w1_w0_w119 below_1998 -322
+    w1_w0_w119 below_1999 -307
+    w1_w0_w119 below_2000 -322
+    w1_w0_w12 above_1800 6
+    w1_w0_w12 above_1801 7
+    w1_w0_w12 above_1802 8
+    w1_w0_w12 above_1803 8
+    w1_w0_w12 above_1804 8
+    w1_w0_w12 above_1805 8
+    w1_w0_w12 above_1806 8
+    w1_w0_w12 above_1807 8
+    w1_w0_w12 above_1808 9
+    w1_w0_w12 above_1809 9
+    w1_w0_w12 above_1810 10
+    w1_w0_w12 above_1811 9
+    w1_w0_w12 above_1812 10
+    w1_w0_w12 above_1813 11
+    w1_w0_w12 above_1814 11
+    w1_w0_w12 above_1815 10
+    w1_w0_w12 above_1816 11
+    w1_w0_w12 above_1817 13
+    w1_w0_w12 above_1818 12
+    w1_w0_w12 above_1819 14
+    w1_w0_w12 above_1820 14
+    w1_w0_w12 above_1821 14
+    w1_w0_w12 above_1822 15
+    w1_w0_w12 above_1823 16
+    w1_w0_w12 above_1824 16
+    w1_w0_w12 above_1825 17
+    w1_w0_w12 above_1826 20
+    w1_w0_w12 above_1827 19
+    w1_w0_w12 above_1828 19
+    w1_w0_w12 above_1829 21
+    w1_w0_w12 above_1830 22
+    w1_w0_w12 above_1831 20
+    w1_w0_w12 above_1832 23
+    w1_w0_w12 above_1833 23
+    w1_w0_w12 above_1834 24
+    w1_w0_w12 above_1835 26
+    w1_w0_w12 above_1836 28
+    w1_w0_w12 above_1837 29
+    w1_w0_w12 above_1838 27
+    w1_w0_w12 above_1839 31
+    w1_w0_w12 above_1840 33
+    w1_w0_w12 above_1841 36
+    w1_w0_w12 above_1842 34
+    w1_w0_w12 above_1843 37
+    w1_w0_w12 above_1844 37
+    w1_w0_w12 above_1845 37
+    w1_w0_w12 above_1846 39
+    w1_w0_w12 above_1847 39
+    w1_w0_w12 above_1848 45
+    w1_w0_w12 above_1849 43
+    w1_w0_w12 above_1850 42
+    w1_w0_w12 above_1851 46
+    w1_w0_w12 above_1852 47
+    w1_w0_w12 above_1853 49
+    w1_w0_w12 above_1854 49
+    w1_w0_w12 above_1855 50
+    w1_w0_w12 above_1856 52
+    w1_w0_w12 above_1857 55
+    w1_w0_w12 above_1858 58
+    w1_w0_w12 above_1859 53
+    w1_w0_w12 above_1860 54
+    w1_w0_w12 above_1861 57
+    w1_w0_w12 above_1862 58
+    w1_w0_w12 above_1863 62
+    w1_w0_w12 above_1864 62
+    w1_w0_w12 above_1865 63
+    w1_w0_w12 above_1866 68
+    w1_w0_w12 above_1867 68
+    w1_w0_w12 above_1868 64
+    w1_w0_w12 above_1869 67
+    w1_w0_w12 above_1870 66
+    w1_w0_w12 above_1871 80
+    w1_w0_w12 above_1872 70
+    w1_w0_w12 above_1873 75
+    w1_w0_w12 above_1874 80
+    w1_w0_w12 above_1875 81
+    w1_w0_w12 above_1876 85
+    w1_w0_w12 above_1877 84
+    w1_w0_w12 above_1878 87
+    w1_w0_w12 above_1879 87
+    w1_w0_w12 above_1880 87
+    w1_w0_w12 above_1881 98
+    w1_w0_w12 above_1882 92
+    w1_w0_w12 above_1883 96
+    w1_w0_w12 above_1884 95
+    w1_w0_w12 above_1885 103
+    w1_w0_w12 above_1886 99
+    w1_w0_w12 above_1887 97
+    w1_w0_w12 above_1888 106
+    w1_w0_w12 above_1889 110
+    w1_w0_w12 above_1890 103
+    w1_w0_w12 above_1891 115
+    w1_w0_w12 above_1892 103
+    w1_w0_w12 above_1893 115
+    w1_w0_w12 above_1894 114
+    w1_w0_w12 above_1895 117
+    w1_w0_w12 above_1896 117
+    w1_w0_w12 above_1897 124
+    w1_w0_w12 above_1898 126
+    w1_w0_w12 above_1899 134
+    w1_w0_w12 above_1900 137
+    w1_w0_w12 above_1901 142
+    w1_w0_w12 above_1902 131
+    w1_w0_w12 above_1903 144
+    w1_w0_w12 above_1904 144
+    w1_w0_w12 above_1905 147
+    w1_w0_w12 above_1906 145
+    w1_w0_w12 above_1907 157
+    w1_w0_w12 above_1908 167
+    w1_w0_w12 above_1909 152
+    w1_w0_w12 above_1910 157
+    w1_w0_w12 above_1911 153
+    w1_w0_w12 above_1912 170
+    w1_w0_w12 above_1913 168
+    w1_w0_w12 above_1914 170
+    w1_w0_w12 above_1915 198
+    w1_w0_w12 above_1916 157
+    w1_w0_w12 above_1917 177
+    w1_w0_w12 above_1918 202
+    w1_w0_w12 above_1919 188
+    w1_w0_w12 above_1920 193
+    w1_w0_w12 above_1921 217
+    w1_w0_w12 above_1922 198
+    w1_w0_w12 above_1923 215
+    w1_w0_w12 above_1924 203
+    w1_w0_w12 above_1925 203
+    w1_w0_w12 above_1926 220
+    w1_w0_w12 above_1927 208
+    w1_w0_w12 above_1928 240
+    w1_w0_w12 above_1929 221
+    w1_w0_w12 above_1930 256
+    w1_w0_w12 above_1931 246
+    w1_w0_w12 above_1932 255
+    w1_w0_w12 above_1933 239
+    w1_w0_w12 above_1934 242
+    w1_w0_w12 above_1935 241
+    w1_w0_w12 above_1936 270
+    w1_w0_w12 above_1937 278
+    w1_w0_w12 above_1938 272
+    w1_w0_w12 above_1939 282
+    w1_w0_w12 above_1940 276
+    w1_w0_w12 above_1941 274
+    w1_w0_w12 above_1942 273
+    w1_w0_w12 above_1943 292
+    w1_w0_w12 above_1944 258
+    w1_w0_w12 above_1945 255
+    w1_w0_w12 above_1946 309
+    w1_w0_w12 above_1947 321
+    w1_w0_w12 above_1948 325
+    w1_w0_w12 above_1949 317
+    w1_w0_w12 above_1950 326
+    w1_w0_w12 above_1951 316
+    w1_w0_w12 above_1952 324
+    w1_w0_w12 above_1953 331
+    w1_w0_w12 above_1954 344
+    w1_w0_w12 above_1955 317
+    w1_w0_w12 above_1956 311
+    w1_w0_w12 above_1957 358
+    w1_w0_w12 above_1958 369
+    w1_w0_w12 above_1959 386
+    w1_w0_w12 above_1960 340
+    w1_w0_w12 above_1961 374
+    w1_w0_w12 above_1962 404
+    w1_w0_w12 above_1963 399
+    w1_w0_w12 above_1964 366
+    w1_w0_w12 above_1965 426
+    w1_w0_w12 above_1966 402
+    w1_w0_w12 above_1967 416
+    w1_w0_w12 above_1968 449
+    w1_w0_w12 above_1969 387
+    w1_w0_w12 above_1970 419
+    w1_w0_w12 above_1971 416
+    w1_w0_w12 above_1972 412
+    w1_w0_w12 above_1973 443
+    w1_w0_w12 above_1974 433
+    w1_w0_w12 above_1975 440
+    w1_w0_w12 above_1976 434
+    w1_w0_w12 above_1977 425
+    w1_w0_w12 above_1978 449
+    w1_w0_w12 above_1979 431
+    w1_w0_w12 above_1980 409
+    w1_w0_w12 above_1981 389
+    w1_w0_w12 above_1982 433
+    w1_w0_w12 above_1983 410
+    w1_w0_w12 above_1984 446
+    w1_w0_w12 above_1985 440
+    w1_w0_w12 above_1986 395
+    w1_w0_w12 above_1987 432
+    w1_w0_w12 above_1988 417
+    w1_w0_w12 above_1989 430
+    w1_w0_w12 above_1990 407
+    w1_w0_w12 above_1991 408
+    w1_w0_w12 above_1992 383
+    w1_w0_w12 above_1993 441
+    w1_w0_w12 above_1994 389
+    w1_w0_w12 above_1995 438
+    w1_w0_w12 above_1996 461
+    w1_w0_w12 above_1997 421
+    w1_w0_w12 above_1998 439
+    w1_w0_w12 above_1999 376
+    w1_w0_w12 above_2000 441
+    w1_w0_w12 below_1800 -6
+    w1_w0_w12 below_1801 -7
+    w1_w0_w12 below_1802 -8
+    w1_w0_w12 below_1803 -8
+    w1_w0_w12 below_1804 -8
+    w1_w0_w12 below_1805 -8
+    w1_w0_w12 below_1806 -8
+    w1_w0_w12 below_1807 -8
+    w1_w0_w12 below_1808 -9
+    w1_w0_w12 below_1809 -9
+    w1_w0_w12 below_1810 -10
+    w1_w0_w12 below_1811 -9
+    w1_w0_w12 below_1812 -10
+    w1_w0_w12 below_1813 -11
+    w1_w0_w12 below_1814 -11
+    w1_w0_w12 below_1815 -10
+    w1_w0_w12 below_1816 -11
+    w1_w0_w12 below_1817 -13
+    w1_w0_w12 below_1818 -12
+    w1_w0_w12 below_1819 -14
+    w1_w0_w12 below_1820 -14
+    w1_w0_w12 below_1821 -14
+    w1_w0_w12 below_1822 -15
+    w1_w0_w12 below_1823 -16
+    w1_w0_w12 below_1824 -16
+    w1_w0_w12 below_1825 -17
+    w1_w0_w12 below_1826 -20
+    w1_w0_w12 below_1827 -19
+    w1_w0_w12 below_1828 -19
+    w1_w0_w12 below_1829 -21
+    w1_w0_w12 below_1830 -22
+    w1_w0_w12 below_1831 -20
+    w1_w0_w12 below_1832 -23
+    w1_w0_w12 below_1833 -23
+    w1_w0_w12 below_1834 -24
+    w1_w0_w12 below_1835 -26
+    w1_w0_w12 below_1836 -28
+    w1_w0_w12 below_1837 -29
+    w1_w0_w12 below_1838 -27
+    w1_w0_w12 below_1839 -31
+    w1_w0_w12 below_1840 -33
+    w1_w0_w12 below_1841 -36
+    w1_w0_w12 below_1842 -34
+    w1_w0_w12 below_1843 -37
+    w1_w0_w12 below_1844 -37
+    w1_w0_w12 below_1845 -37
+    w1_w0_w12 below_1846 -39
+    w1_w0_w12 below_1847 -39
+    w1_w0_w12 below_1848 -45
+    w1_w0_w12 below_1849 -43
+    w1_w0_w12 below_1850 -42
+    w1_w0_w12 below_1851 -46
+    w1_w0_w12 below_1852 -47
+    w1_w0_w12 below_1853 -49
+    w1_w0_w12 below_1854 -49
+    w1_w0_w12 below_1855 -50
+    w1_w0_w12 below_1856 -52
+    w1_w0_w12 below_1857 -55
+    w1_w0_w12 below_1858 -58
+    w1_w0_w12 below_1859 -53
+    w1_w0_w12 below_1860 -54
+    w1_w0_w12 below_1861 -57
+    w1_w0_w12 below_1862 -58
+    w1_w0_w12 below_1863 -62
+    w1_w0_w12 below_1864 -62
+    w1_w0_w12 below_1865 -63
+    w1_w0_w12 below_1866 -68
+    w1_w0_w12 below_1867 -68
+    w1_w0_w12 below_1868 -64
+    w1_w0_w12 below_1869 -67
+    w1_w0_w12 below_1870 -66
+    w1_w0_w12 below_1871 -80
+    w1_w0_w12 below_1872 -70
+    w1_w0_w12 below_1873 -75
+    w1_w0_w12 below_1874 -80
+    w1_w0_w12 below_1875 -81
+    w1_w0_w12 below_1876 -85
+    w1_w0_w12 below_1877 -84
+    w1_w0_w12 below_1878 -87
+    w1_w0_w12 below_1879 -87
+    w1_w0_w12 below_1880 -87
+    w1_w0_w12 below_1881 -98
+    w1_w0_w12 below_1882 -92
+    w1_w0_w12 below_1883 -96
+    w1_w0_w12 below_1884 -95
+    w1_w0_w12 below_1885 -103
+    w1_w0_w12 below_1886 -99
+    w1_w0_w12 below_1887 -97
+    w1_w0_w12 below_1888 -106
+    w1_w0_w12 below_1889 -110
+    w1_w0_w12 below_1890 -103
+    w1_w0_w12 below_1891 -115
+    w1_w0_w12 below_1892 -103
+    w1_w0_w12 below_1893 -115
+    w1_w0_w12 below_1894 -114
+    w1_w0_w12 below_1895 -117
+    w1_w0_w12 below_1896 -117
+    w1_w0_w12 below_1897 -124
+    w1_w0_w12 below_1898 -126
+    w1_w0_w12 below_1899 -134
+    w1_w0_w12 below_1900 -137
+    w1_w0_w12 below_1901 -142
+    w1_w0_w12 below_1902 -131
+    w1_w0_w12 below_1903 -144
+    w1_w0_w12 below_1904 -144
+    w1_w0_w12 below_1905 -147
+    w1_w0_w12 below_1906 -145
+    w1_w0_w12 below_1907 -157
+    w1_w0_w12 below_1908 -167
+    w1_w0_w12 below_1909 -152
+    w1_w0_w12 below_1910 -157
+    w1_w0_w12 below_1911 -153
+    w1_w0_w12 below_1912 -170
+    w1_w0_w12 below_1913 -168
+    w1_w0_w12 below_1914 -170
+    w1_w0_w12 below_1915 -198
+    w1_w0_w12 below_1916 -157
+    w1_w0_w12 below_1917 -177
+    w1_w0_w12 below_1918 -202
+    w1_w0_w12 below_1919 -188
+    w1_w0_w12 below_1920 -193
+    w1_w0_w12 below_1921 -217
+    w1_w0_w12 below_1922 -198
+    w1_w0_w12 below_1923 -215
+    w1_w0_w12 below_1924 -203
+    w1_w0_w12 below_1925 -203
+    w1_w0_w12 below_1926 -220
+    w1_w0_w12 below_1927 -208
+    w1_w0_w12 below_1928 -240
+    w1_w0_w12 below_1929 -221
+    w1_w0_w12 below_1930 -256
+    w1_w0_w12 below_1931 -246
+    w1_w0_w12 below_1932 -255
+    w1_w0_w12 below_1933 -239
+    w1_w0_w12 below_1934 -242
+    w1_w0_w12 below_1935 -241
+    w1_w0_w12 below_1936 -270
+    w1_w0_w12 below_1937 -278
+    w1_w0_w12 below_1938 -272
+    w1_w0_w12 below_1939 -282
+    w1_w0_w12 below_1940 -276
+    w1_w0_w12 below_1941 -274
+    w1_w0_w12 below_1942 -273
+    w1_w0_w12 below_1943 -292
+    w1_w0_w12 below_1944 -258
+    w1_w0_w12 below_1945 -255
+    w1_w0_w12 below_1946 -309
+    w1_w0_w12 below_1947 -321
+    w1_w0_w12 below_1948 -325
+    w1_w0_w12 below_1949 -317
+    w1_w0_w12 below_1950 -326
+    w1_w0_w12 below_1951 -316
+    w1_w0_w12 below_1952 -324
+    w1_w0_w12 below_1953 -331
+    w1_w0_w12 below_1954 -344
+    w1_w0_w12 below_1955 -317
+    w1_w0_w12 below_1956 -311
+    w1_w0_w12 below_1957 -358
+    w1_w0_w12 below_1958 -369
+    w1_w0_w12 below_1959 -386
+    w1_w0_w12 below_1960 -340
+    w1_w0_w12 below_1961 -374
+    w1_w0_w12 below_1962 -404
+    w1_w0_w12 below_1963 -399
+    w1_w0_w12 below_1964 -366
+    w1_w0_w12 below_1965 -426
+    w1_w0_w12 below_1966 -402
+    w1_w0_w12 below_1967 -416
+    w1_w0_w12 below_1968 -449
+    w1_w0_w12 below_1969 -387
+    w1_w0_w12 below_1970 -419
+    w1_w0_w12 below_1971 -416
+    w1_w0_w12 below_1972 -412
+    w1_w0_w12 below_1973 -443
+    w1_w0_w12 below_1974 -433
+    w1_w0_w12 below_1975 -440
+    w1_w0_w12 below_1976 -434
+    w1_w0_w12 below_1977 -425
+    w1_w0_w12 below_1978 -449
+    w1_w0_w12 below_1979 -431
+    w1_w0_w12 below_1980 -409
+    w1_w0_w12 below_1981 -389
+    w1_w0_w12 below_1982 -433
+    w1_w0_w12 below_1983 -410
+    w1_w0_w12 below_1984 -446
+    w1_w0_w12 below_1985 -440
+    w1_w0_w12 below_1986 -395
+    w1_w0_w12 below_1987 -432
+    w1_w0_w12 below_1988 -417
+    w1_w0_w12 below_1989 -430
+    w1_w0_w12 below_1990 -407
+    w1_w0_w12 below_1991 -408
+    w1_w0_w12 below_1992 -383
+    w1_w0_w12 below_1993 -441
+    w1_w0_w12 below_1994 -389
+    w1_w0_w12 below_1995 -438
+    w1_w0_w12 below_1996 -461
+    w1_w0_w12 below_1997 -421
+    w1_w0_w12 below_1998 -439
+    w1_w0_w12 below_1999 -376
+    w1_w0_w12 below_2000 -441
+    w1_w0_w19 above_1800 15
+    w1_w0_w19 above_1801 15
+    w1_w0_w19 above_1802 17
+    w1_w0_w19 above_1803 18
+    w1_w0_w19 above_1804 22
+    w1_w0_w19 above_1805 23
+    w1_w0_w19 above_1806 25
+    w1_w0_w19 above_1807 26
+    w1_w0_w19 above_1808 29
+    w1_w0_w19 above_1809 33
+    w1_w0_w19 above_1810 35
+    w1_w0_w19 above_1811 34
+    w1_w0_w19 above_1812 42
+    w1_w0_w19 above_1813 45
+    w1_w0_w19 above_1814 49
+    w1_w0_w19 above_1815 56
+    w1_w0_w19 above_1816 51
+    w1_w0_w19 above_1817 63
+    w1_w0_w19 above_1818 61
+    w1_w0_w19 above_1819 62
+    w1_w0_w19 above_1820 76
+    w1_w0_w19 above_1821 77
+    w1_w0_w19 above_1822 83
+    w1_w0_w19 above_1823 80
+    w1_w0_w19 above_1824 92
+    w1_w0_w19 above_1825 92
+    w1_w0_w19 above_1826 102
+    w1_w0_w19 above_1827 101
+    w1_w0_w19 above_1828 104
+    w1_w0_w19 above_1829 108
+    w1_w0_w19 above_1830 115
+    w1_w0_w19 above_1831 117
+    w1_w0_w19 above_1832 117
+    w1_w0_w19 above_1833 131
+    w1_w0_w19 above_1834 128
+    w1_w0_w19 above_1835 143
+    w1_w0_w19 above_1836 142
+    w1_w0_w19 above_1837 134
+    w1_w0_w19 above_1838 133
+    w1_w0_w19 above_1839 146
+    w1_w0_w19 above_1840 143
+    w1_w0_w19 above_1841 140
+    w1_w0_w19 above_1842 156
+    w1_w0_w19 above_1843 144
+    w1_w0_w19 above_1844 157
+    w1_w0_w19 above_1845 152
+    w1_w0_w19 above_1846 152
+    w1_w0_w19 above_1847 169
+    w1_w0_w19 above_1848 159
+    w1_w0_w19 above_1849 152
+    w1_w0_w19 above_1850 167
+    w1_w0_w19 above_1851 176
+    w1_w0_w19 above_1852 161
+    w1_w0_w19 above_1853 178
+    w1_w0_w19 above_1854 159
+    w1_w0_w19 above_1855 169
+    w1_w0_w19 above_1856 164
+    w1_w0_w19 above_1857 170
+    w1_w0_w19 above_1858 156
+    w1_w0_w19 above_1859 175
+    w1_w0_w19 above_1860 167
+    w1_w0_w19 above_1861 161
+    w1_w0_w19 above_1862 175
+    w1_w0_w19 above_1863 182
+    w1_w0_w19 above_1864 171
+    w1_w0_w19 above_1865 163
+    w1_w0_w19 above_1866 173
+    w1_w0_w19 above_1867 166
+    w1_w0_w19 above_1868 162
+    w1_w0_w19 above_1869 171
+    w1_w0_w19 above_1870 171
+    w1_w0_w19 above_1871 164
+    w1_w0_w19 above_1872 176
+    w1_w0_w19 above_1873 182
+    w1_w0_w19 above_1874 172
+    w1_w0_w19 above_1875 167
+    w1_w0_w19 above_1876 166
+    w1_w0_w19 above_1877 173
+    w1_w0_w19 above_1878 176
+    w1_w0_w19 above_1879 156
+    w1_w0_w19 above_1880 171
+    w1_w0_w19 above_1881 156
+    w1_w0_w19 above_1882 173
+    w1_w0_w19 above_1883 180
+    w1_w0_w19 above_1884 175
+    w1_w0_w19 above_1885 167
+    w1_w0_w19 above_1886 178
+    w1_w0_w19 above_1887 171
+    w1_w0_w19 above_1888 188
+    w1_w0_w19 above_1889 162
+    w1_w0_w19 above_1890 193
+    w1_w0_w19 above_1891 172
+    w1_w0_w19 above_1892 185
+    w1_w0_w19 above_1893 193
+    w1_w0_w19 above_1894 187
+    w1_w0_w19 above_1895 164
+    w1_w0_w19 above_1896 170
+    w1_w0_w19 above_1897 168
+    w1_w0_w19 above_1898 168
+    w1_w0_w19 above_1899 175
+    w1_w0_w19 above_1900 178
+    w1_w0_w19 above_1901 165
+    w1_w0_w19 above_1902 177
+    w1_w0_w19 above_1903 181
+    w1_w0_w19 above_1904 200
+    w1_w0_w19 above_1905 174
+    w1_w0_w19 above_1906 180
+    w1_w0_w19 above_1907 172
+    w1_w0_w19 above_1908 181
+    w1_w0_w19 above_1909 170
+    w1_w0_w19 above_1910 180
+    w1_w0_w19 above_1911 156
+    w1_w0_w19 above_1912 179
+    w1_w0_w19 above_1913 187
+    w1_w0_w19 above_1914 180
+    w1_w0_w19 above_1915 184
+    w1_w0_w19 above_1916 186
+    w1_w0_w19 above_1917 173
+    w1_w0_w19 above_1918 166
+    w1_w0_w19 above_1919 192
+    w1_w0_w19 above_1920 174
+    w1_w0_w19 above_1921 172
+    w1_w0_w19 above_1922 166
+    w1_w0_w19 above_1923 184
+    w1_w0_w19 above_1924 192
+    w1_w0_w19 above_1925 182
+    w1_w0_w19 above_1926 185
+    w1_w0_w19 above_1927 171
+    w1_w0_w19 above_1928 158
+    w1_w0_w19 above_1929 183
+    w1_w0_w19 above_1930 180
+    w1_w0_w19 above_1931 171
+    w1_w0_w19 above_1932 187
+    w1_w0_w19 above_1933 184
+    w1_w0_w19 above_1934 173
+    w1_w0_w19 above_1935 182
+    w1_w0_w19 above_1936 190
+    w1_w0_w19 above_1937 180
+    w1_w0_w19 above_1938 163
+    w1_w0_w19 above_1939 189
+    w1_w0_w19 above_1940 164
+    w1_w0_w19 above_1941 187
+    w1_w0_w19 above_1942 178
+    w1_w0_w19 above_1943 196
+    w1_w0_w19 above_1944 185
+    w1_w0_w19 above_1945 179
+    w1_w0_w19 above_1946 188
+    w1_w0_w19 above_1947 184
+    w1_w0_w19 above_1948 172
+    w1_w0_w19 above_1949 173
+    w1_w0_w19 above_1950 177
+    w1_w0_w19 above_1951 186
+    w1_w0_w19 above_1952 175
+    w1_w0_w19 above_1953 167
+    w1_w0_w19 above_1954 180
+    w1_w0_w19 above_1955 186
+    w1_w0_w19 above_1956 177
+    w1_w0_w19 above_1957 173
+    w1_w0_w19 above_1958 177
+    w1_w0_w19 above_1959 174
+    w1_w0_w19 above_1960 177
+    w1_w0_w19 above_1961 175
+    w1_w0_w19 above_1962 181
+    w1_w0_w19 above_1963 168
+    w1_w0_w19 above_1964 160
+    w1_w0_w19 above_1965 182
+    w1_w0_w19 above_1966 174
+    w1_w0_w19 above_1967 188
+    w1_w0_w19 above_1968 184
+    w1_w0_w19 above_1969 185
+    w1_w0_w19 above_1970 179
+    w1_w0_w19 above_1971 174
+    w1_w0_w19 above_1972 182
+    w1_w0_w19 above_1973 172
+    w1_w0_w19 above_1974 188
+    w1_w0_w19 above_1975 166
+    w1_w0_w19 above_1976 178
+    w1_w0_w19 above_1977 162
+    w1_w0_w19 above_1978 170
+    w1_w0_w19 above_1979 191
+    w1_w0_w19 above_1980 189
+    w1_w0_w19 above_1981 177
+    w1_w0_w19 above_1982 172
+    w1_w0_w19 above_1983 187
+    w1_w0_w19 above_1984 174
+    w1_w0_w19 above_1985 179
+    w1_w0_w19 above_1986 179
+    w1_w0_w19 above_1987 183
+    w1_w0_w19 above_1988 183
+    w1_w0_w19 above_1989 164
+    w1_w0_w19 above_1990 165
+    w1_w0_w19 above_1991 187
+    w1_w0_w19 above_1992 185
+    w1_w0_w19 above_1993 186
+    w1_w0_w19 above_1994 173
+    w1_w0_w19 above_1995 179
+    w1_w0_w19 above_1996 178
+    w1_w0_w19 above_1997 162
+    w1_w0_w19 above_1998 179
+    w1_w0_w19 above_1999 186
+    w1_w0_w19 above_2000 165
+    w1_w0_w19 below_1800 -15
+    w1_w0_w19 below_1801 -15
+    w1_w0_w19 below_1802 -17
+    w1_w0_w19 below_1803 -18
+    w1_w0_w19 below_1804 -22
+    w1_w0_w19 below_1805 -23
+    w1_w0_w19 below_1806 -25
+    w1_w0_w19 below_1807 -26
+    w1_w0_w19 below_1808 -29
+    w1_w0_w19 below_1809 -33
+    w1_w0_w19 below_1810 -35
+    w1_w0_w19 below_1811 -34
+    w1_w0_w19 below_1812 -42
+    w1_w0_w19 below_1813 -45
+    w1_w0_w19 below_1814 -49
+    w1_w0_w19 below_1815 -56
+    w1_w0_w19 below_1816 -51
+    w1_w0_w19 below_1817 -63
+    w1_w0_w19 below_1818 -61
+    w1_w0_w19 below_1819 -62
+    w1_w0_w19 below_1820 -76
+    w1_w0_w19 below_1821 -77
+    w1_w0_w19 below_1822 -83
+    w1_w0_w19 below_1823 -80
+    w1_w0_w19 below_1824 -92
+    w1_w0_w19 below_1825 -92
+    w1_w0_w19 below_1826 -102
+    w1_w0_w19 below_1827 -101
+    w1_w0_w19 below_1828 -104
+    w1_w0_w19 below_1829 -108
+    w1_w0_w19 below_1830 -115
+    w1_w0_w19 below_1831 -117
+    w1_w0_w19 below_1832 -117
+    w1_w0_w19 below_1833 -131
+    w1_w0_w19 below_1834 -128
+    w1_w0_w19 below_1835 -143
+    w1_w0_w19 below_1836 -142
+    w1_w0_w19 below_1837 -134
+    w1_w0_w19 below_1838 -133
+    w1_w0_w19 below_1839 -146
+    w1_w0_w19 below_1840 -143
+    w1_w0_w19 below_1841 -140
+    w1_w0_w19 below_1842 -156
+    w1_w0_w19 below_1843 -144
+    w1_w0_w19 below_1844 -157
+    w1_w0_w19 below_1845 -152
+    w1_w0_w19 below_1846 -152
+    w1_w0_w19 below_1847 -169
+    w1_w0_w19 below_1848 -159
+    w1_w0_w19 below_1849 -152
+    w1_w0_w19 below_1850 -167
+    w1_w0_w19 below_1851 -176
+    w1_w0_w19 below_1852 -161
+    w1_w0_w19 below_1853 -178
+    w1_w0_w19 below_1854 -159
+    w1_w0_w19 below_1855 -169
+    w1_w0_w19 below_1856 -164
+    w1_w0_w19 below_1857 -170
+    w1_w0_w19 below_1858 -156
+    w1_w0_w19 below_1859 -175
+    w1_w0_w19 below_1860 -167
+    w1_w0_w19 below_1861 -161
+    w1_w0_w19 below_1862 -175
+    w1_w0_w19 below_1863 -182
+    w1_w0_w19 below_1864 -171
+    w1_w0_w19 below_1865 -163
+    w1_w0_w19 below_1866 -173
+    w1_w0_w19 below_1867 -166
+    w1_w0_w19 below_1868 -162
+    w1_w0_w19 below_1869 -171
+    w1_w0_w19 below_1870 -171
+    w1_w0_w19 below_1871 -164
+    w1_w0_w19 below_1872 -176
+    w1_w0_w19 below_1873 -182
+    w1_w0_w19 below_1874 -172
+    w1_w0_w19 below_1875 -167
+    w1_w0_w19 below_1876 -166
+    w1_w0_w19 below_1877 -173
+    w1_w0_w19 below_1878 -176
+    w1_w0_w19 below_1879 -156
+    w1_w0_w19 below_1880 -171
+    w1_w0_w19 below_1881 -156
+    w1_w0_w19 below_1882 -173
+    w1_w0_w19 below_1883 -180
+    w1_w0_w19 below_1884 -175
+    w1_w0_w19 below_1885 -167
+    w1_w0_w19 below_1886 -178
+    w1_w0_w19 below_1887 -171
+    w1_w0_w19 below_1888 -188
+    w1_w0_w19 below_1889 -162
+    w1_w0_w19 below_1890 -193
+    w1_w0_w19 below_1891 -172
+    w1_w0_w19 below_1892 -185
+    w1_w0_w19 below_1893 -193
+    w1_w0_w19 below_1894 -187
+    w1_w0_w19 below_1895 -164
+    w1_w0_w19 below_1896 -170
+    w1_w0_w19 below_1897 -168
+    w1_w0_w19 below_1898 -168
+    w1_w0_w19 below_1899 -175
+    w1_w0_w19 below_1900 -178
+    w1_w0_w19 below_1901 -165
+    w1_w0_w19 below_1902 -177
+    w1_w0_w19 below_1903 -181
+    w1_w0_w19 below_1904 -200
+    w1_w0_w19 below_1905 -174
+    w1_w0_w19 below_1906 -180
+    w1_w0_w19 below_1907 -172
+    w1_w0_w19 below_1908 -181
+    w1_w0_w19 below_1909 -170
+    w1_w0_w19 below_1910 -180
+    w1_w0_w19 below_1911 -156
+    w1_w0_w19 below_1912 -179
+    w1_w0_w19 below_1913 -187
+    w1_w0_w19 below_1914 -180
+    w1_w0_w19 below_1915 -184
+    w1_w0_w19 below_1916 -186
+    w1_w0_w19 below_1917 -173
+    w1_w0_w19 below_1918 -166
+    w1_w0_w19 below_1919 -192
+    w1_w0_w19 below_1920 -174
+    w1_w0_w19 below_1921 -172
+    w1_w0_w19 below_1922 -166
+    w1_w0_w19 below_1923 -184
+    w1_w0_w19 below_1924 -192
+    w1_w0_w19 below_1925 -182
+    w1_w0_w19 below_1926 -185
+    w1_w0_w19 below_1927 -171
+    w1_w0_w19 below_1928 -158
+    w1_w0_w19 below_1929 -183
+    w1_w0_w19 below_1930 -180
+    w1_w0_w19 below_1931 -171
+    w1_w0_w19 below_1932 -187
+    w1_w0_w19 below_1933 -184
+    w1_w0_w19 below_1934 -173
+    w1_w0_w19 below_1935 -182
+    w1_w0_w19 below_1936 -190
+    w1_w0_w19 below_1937 -180
+    w1_w0_w19 below_1938 -163
+    w1_w0_w19 below_1939 -189
+    w1_w0_w19 below_1940 -164
+    w1_w0_w19 below_1941 -187
+    w1_w0_w19 below_1942 -178
+    w1_w0_w19 below_1943 -196
+    w1_w0_w19 below_1944 -185
+    w1_w0_w19 below_1945 -179
+    w1_w0_w19 below_1946 -188
+    w1_w0_w19 below_1947 -184
+    w1_w0_w19 below_1948 -172
+    w1_w0_w19 below_1949 -173
+    w1_w0_w19 below_1950 -177
+    w1_w0_w19 below_1951 -186
+    w1_w0_w19 below_1952 -175
+    w1_w0_w19 below_1953 -167
+    w1_w0_w19 below_1954 -180
+    w1_w0_w19 below_1955 -186
+    w1_w0_w19 below_1956 -177
+    w1_w0_w19 below_1957 -173
+    w1_w0_w19 below_1958 -177
+    w1_w0_w19 below_1959 -174
+    w1_w0_w19 below_1960 -177
+    w1_w0_w19 below_1961 -175
+    w1_w0_w19 below_1962 -181
+    w1_w0_w19 below_1963 -168
+    w1_w0_w19 below_1964 -160
+    w1_w0_w19 below_1965 -182
+    w1_w0_w19 below_1966 -174
+    w1_w0_w19 below_1967 -188
+    w1_w0_w19 below_1968 -184
+    w1_w0_w19 below_1969 -185
+    w1_w0_w19 below_1970 -179
+    w1_w0_w19 below_1971 -174
+    w1_w0_w19 below_1972 -182
+    w1_w0_w19 below_1973 -172
+    w1_w0_w19 below_1974 -188
+    w1_w0_w19 below_1975 -166
+    w1_w0_w19 below_1976 -178
+    w1_w0_w19 below_1977 -162
+    w1_w0_w19 below_1978 -170
+    w1_w0_w19 below_1979 -191
+    w1_w0_w19 below_1980 -189
+    w1_w0_w19 below_1981 -177
+    w1_w0_w19 below_1982 -172
+    w1_w0_w19 below_1983 -187
+    w1_w0_w19 below_1984 -174
+    w1_w0_w19 below_1985 -179
+    w1_w0_w19 below_1986 -179
+    w1_w0_w19 below_1987 -183
+    w1_w0_w19 below_1988 -183
+    w1_w0_w19 below_1989 -164
+    w1_w0_w19 below_1990 -165
+    w1_w0_w19 below_1991 -187
+    w1_w0_w19 below_1992 -185
+    w1_w0_w19 below_1993 -186
+    w1_w0_w19 below_1994 -173
+    w1_w0_w19 below_1995 -179
+    w1_w0_w19 below_1996 -178
+    w1_w0_w19 below_1997 -162
+    w1_w0_w19 below_1998 -179
+    w1_w0_w19 below_1999 -186
+    w1_w0_w19 below_2000 -165
+    w24_w0_w4 above_1800 1
+    w24_w0_w4 above_1801 1
+    w24_w0_w4 above_1802 1
+    w24_w0_w4 above_1803 1
+    w24_w0_w4 above_1804 1
+    w24_w0_w4 above_1805 1
+    w24_w0_w4 above_1806 2
+    w24_w0_w4 above_1807 2
+    w24_w0_w4 above_1808 2
+    w24_w0_w4 above_1809 2
+    w24_w0_w4 above_1810 2
+    w24_w0_w4 above_1811 2
+    w24_w0_w4 above_1812 3
+    w24_w0_w4 above_1813 3
+    w24_w0_w4 above_1814 3
+    w24_w0_w4 above_1815 3
+    w24_w0_w4 above_1816 4
+    w24_w0_w4 above_1817 4
+    w24_w0_w4 above_1818 4
+    w24_w0_w4 above_1819 5
+    w24_w0_w4 above_1820 5
+    w24_w0_w4 above_1821 5
+    w24_w0_w4 above_1822 6
+    w24_w0_w4 above_1823 6
+    w24_w0_w4 above_1824 7
+    w24_w0_w4 above_1825 8
+    w24_w0_w4 above_1826 9
+    w24_w0_w4 above_1827 9
+    w24_w0_w4 above_1828 9
+    w24_w0_w4 above_1829 10
+    w24_w0_w4 above_1830 12
+    w24_w0_w4 above_1831 12
+    w24_w0_w4 above_1832 12
+    w24_w0_w4 above_1833 14
+    w24_w0_w4 above_1834 16
+    w24_w0_w4 above_1835 16
+    w24_w0_w4 above_1836 18
+    w24_w0_w4 above_1837 17
+    w24_w0_w4 above_1838 20
+    w24_w0_w4 above_1839 21
+    w24_w0_w4 above_1840 22
+    w24_w0_w4 above_1841 27
+    w24_w0_w4 above_1842 25
+    w24_w0_w4 above_1843 30
+    w24_w0_w4 above_1844 28
+    w24_w0_w4 above_1845 33
+    w24_w0_w4 above_1846 33
+    w24_w0_w4 above_1847 38
+    w24_w0_w4 above_1848 41
+    w24_w0_w4 above_1849 42
+    w24_w0_w4 above_1850 40
+    w24_w0_w4 above_1851 44
+    w24_w0_w4 above_1852 46
+    w24_w0_w4 above_1853 52
+    w24_w0_w4 above_1854 50
+    w24_w0_w4 above_1855 51
+    w24_w0_w4 above_1856 57
+    w24_w0_w4 above_1857 63
+    w24_w0_w4 above_1858 62
+    w24_w0_w4 above_1859 60
+    w24_w0_w4 above_1860 66
+    w24_w0_w4 above_1861 73
+    w24_w0_w4 above_1862 70
+    w24_w0_w4 above_1863 73
+    w24_w0_w4 above_1864 73
+    w24_w0_w4 above_1865 84
+    w24_w0_w4 above_1866 74
+    w24_w0_w4 above_1867 88
+    w24_w0_w4 above_1868 77
+    w24_w0_w4 above_1869 92
+    w24_w0_w4 above_1870 87
+    w24_w0_w4 above_1871 93
+    w24_w0_w4 above_1872 89
+    w24_w0_w4 above_1873 98
+    w24_w0_w4 above_1874 95
+    w24_w0_w4 above_1875 93
+    w24_w0_w4 above_1876 107
+    w24_w0_w4 above_1877 109
+    w24_w0_w4 above_1878 100
+    w24_w0_w4 above_1879 108
+    w24_w0_w4 above_1880 108
+    w24_w0_w4 above_1881 106
+    w24_w0_w4 above_1882 118
+    w24_w0_w4 above_1883 114
+    w24_w0_w4 above_1884 116
+    w24_w0_w4 above_1885 116
+    w24_w0_w4 above_1886 122
+    w24_w0_w4 above_1887 107
+    w24_w0_w4 above_1888 127
+    w24_w0_w4 above_1889 122
+    w24_w0_w4 above_1890 121
+    w24_w0_w4 above_1891 131
+    w24_w0_w4 above_1892 145
+    w24_w0_w4 above_1893 141
+    w24_w0_w4 above_1894 139
+    w24_w0_w4 above_1895 131
+    w24_w0_w4 above_1896 143
+    w24_w0_w4 above_1897 162
+    w24_w0_w4 above_1898 156
+    w24_w0_w4 above_1899 158
+    w24_w0_w4 above_1900 173
+    w24_w0_w4 above_1901 158
+    w24_w0_w4 above_1902 182
+    w24_w0_w4 above_1903 186
+    w24_w0_w4 above_1904 184
+    w24_w0_w4 above_1905 200
+    w24_w0_w4 above_1906 181
+    w24_w0_w4 above_1907 214
+    w24_w0_w4 above_1908 200
+    w24_w0_w4 above_1909 227
+    w24_w0_w4 above_1910 225
+    w24_w0_w4 above_1911 225
+    w24_w0_w4 above_1912 228
+    w24_w0_w4 above_1913 257
+    w24_w0_w4 above_1914 261
+    w24_w0_w4 above_1915 275
+    w24_w0_w4 above_1916 283
+    w24_w0_w4 above_1917 296
+    w24_w0_w4 above_1918 268
+    w24_w0_w4 above_1919 302
+    w24_w0_w4 above_1920 324
+    w24_w0_w4 above_1921 349
+    w24_w0_w4 above_1922 343
+    w24_w0_w4 above_1923 365
+    w24_w0_w4 above_1924 355
+    w24_w0_w4 above_1925 366
+    w24_w0_w4 above_1926 415
+    w24_w0_w4 above_1927 404
+    w24_w0_w4 above_1928 398
+    w24_w0_w4 above_1929 472
+    w24_w0_w4 above_1930 442
+    w24_w0_w4 above_1931 510
+    w24_w0_w4 above_1932 478
+    w24_w0_w4 above_1933 540
+    w24_w0_w4 above_1934 556
+    w24_w0_w4 above_1935 541
+    w24_w0_w4 above_1936 559
+    w24_w0_w4 above_1937 577
+    w24_w0_w4 above_1938 583
+    w24_w0_w4 above_1939 672
+    w24_w0_w4 above_1940 670
+    w24_w0_w4 above_1941 617
+    w24_w0_w4 above_1942 704
+    w24_w0_w4 above_1943 678
+    w24_w0_w4 above_1944 758
+    w24_w0_w4 above_1945 816
+    w24_w0_w4 above_1946 827
+    w24_w0_w4 above_1947 833
+    w24_w0_w4 above_1948 831
+    w24_w0_w4 above_1949 860
+    w24_w0_w4 above_1950 889
+    w24_w0_w4 above_1951 902
+    w24_w0_w4 above_1952 990
+    w24_w0_w4 above_1953 993
+    w24_w0_w4 above_1954 972
+    w24_w0_w4 above_1955 1005
+    w24_w0_w4 above_1956 1043
+    w24_w0_w4 above_1957 1087
+    w24_w0_w4 above_1958 1161
+    w24_w0_w4 above_1959 1085
+    w24_w0_w4 above_1960 1211
+    w24_w0_w4 above_1961 1173
+    w24_w0_w4 above_1962 1230
+    w24_w0_w4 above_1963 1184
+    w24_w0_w4 above_1964 1155
+    w24_w0_w4 above_1965 1263
+    w24_w0_w4 above_1966 1151
+    w24_w0_w4 above_1967 1293
+    w24_w0_w4 above_1968 1257
+    w24_w0_w4 above_1969 1308
+    w24_w0_w4 above_1970 1271
+    w24_w0_w4 above_1971 1370
+    w24_w0_w4 above_1972 1428
+    w24_w0_w4 above_1973 1459
+    w24_w0_w4 above_1974 1437
+    w24_w0_w4 above_1975 1484
+    w24_w0_w4 above_1976 1374
+    w24_w0_w4 above_1977 1216
+    w24_w0_w4 above_1978 1422
+    w24_w0_w4 above_1979 1467
+    w24_w0_w4 above_1980 1425
+    w24_w0_w4 above_1981 1557
+    w24_w0_w4 above_1982 1544
+    w24_w0_w4 above_1983 1417
+    w24_w0_w4 above_1984 1425
+    w24_w0_w4 above_1985 1434
+    w24_w0_w4 above_1986 1557
+    w24_w0_w4 above_1987 1635
+    w24_w0_w4 above_1988 1517
+    w24_w0_w4 above_1989 1705
+    w24_w0_w4 above_1990 1658
+    w24_w0_w4 above_1991 1582
+    w24_w0_w4 above_1992 1529
+    w24_w0_w4 above_1993 1654
+    w24_w0_w4 above_1994 1625
+    w24_w0_w4 above_1995 1439
+    w24_w0_w4 above_1996 1760
+    w24_w0_w4 above_1997 1556
+    w24_w0_w4 above_1998 1555
+    w24_w0_w4 above_1999 1446
+    w24_w0_w4 above_2000 1648
+    w24_w0_w4 below_1800 -1
+    w24_w0_w4 below_1801 -1
+    w24_w0_w4 below_1802 -1
+    w24_w0_w4 below_1803 -1
+    w24_w0_w4 below_1804 -1
+    w24_w0_w4 below_1805 -1
+    w24_w0_w4 below_1806 -2
+    w24_w0_w4 below_1807 -2
+    w24_w0_w4 below_1808 -2
+    w24_w0_w4 below_1809 -2
+    w24_w0_w4 below_1810 -2
+    w24_w0_w4 below_1811 -2
+    w24_w0_w4 below_1812 -3
+    w24_w0_w4 below_1813 -3
+    w24_w0_w4 below_1814 -3
+    w24_w0_w4 below_1815 -3
+    w24_w0_w4 below_1816 -4
+    w24_w0_w4 below_1817 -4
+    w24_w0_w4 below_1818 -4
+    w24_w0_w4 below_1819 -5
+    w24_w0_w4 below_1820 -5
+    w24_w0_w4 below_1821 -5
+    w24_w0_w4 below_1822 -6
+    w24_w0_w4 below_1823 -6
+    w24_w0_w4 below_1824 -7
+    w24_w0_w4 below_1825 -8
+    w24_w0_w4 below_1826 -9
+    w24_w0_w4 below_1827 -9
+    w24_w0_w4 below_1828 -9
+    w24_w0_w4 below_1829 -10
+    w24_w0_w4 below_1830 -12
+    w24_w0_w4 below_1831 -12
+    w24_w0_w4 below_1832 -12
+    w24_w0_w4 below_1833 -14
+    w24_w0_w4 below_1834 -16
+    w24_w0_w4 below_1835 -16
+    w24_w0_w4 below_1836 -18
+    w24_w0_w4 below_1837 -17
+    w24_w0_w4 below_1838 -20
+    w24_w0_w4 below_1839 -21
+    w24_w0_w4 below_1840 -22
+    w24_w0_w4 below_1841 -27
+    w24_w0_w4 below_1842 -25
+    w24_w0_w4 below_1843 -30
+    w24_w0_w4 below_1844 -28
+    w24_w0_w4 below_1845 -33
+    w24_w0_w4 below_1846 -33
+    w24_w0_w4 below_1847 -38
+    w24_w0_w4 below_1848 -41
+    w24_w0_w4 below_1849 -42
+    w24_w0_w4 below_1850 -40
+    w24_w0_w4 below_1851 -44
+    w24_w0_w4 below_1852 -46
+    w24_w0_w4 below_1853 -52
+    w24_w0_w4 below_1854 -50
+    w24_w0_w4 below_1855 -51
+    w24_w0_w4 below_1856 -57
+    w24_w0_w4 below_1857 -63
+    w24_w0_w4 below_1858 -62
+    w24_w0_w4 below_1859 -60
+    w24_w0_w4 below_1860 -66
+    w24_w0_w4 below_1861 -73
+    w24_w0_w4 below_1862 -70
+    w24_w0_w4 below_1863 -73
+    w24_w0_w4 below_1864 -73
+    w24_w0_w4 below_1865 -84
+    w24_w0_w4 below_1866 -74
+    w24_w0_w4 below_1867 -88
+    w24_w0_w4 below_1868 -77
+    w24_w0_w4 below_1869 -92
+    w24_w0_w4 below_1870 -87
+    w24_w0_w4 below_1871 -93
+    w24_w0_w4 below_1872 -89
+    w24_w0_w4 below_1873 -98
+    w24_w0_w4 below_1874 -95
+    w24_w0_w4 below_1875 -93
+    w24_w0_w4 below_1876 -107
+    w24_w0_w4 below_1877 -109
+    w24_w0_w4 below_1878 -100
+    w24_w0_w4 below_1879 -108
+    w24_w0_w4 below_1880 -108
+    w24_w0_w4 below_1881 -106
+    w24_w0_w4 below_1882 -118
+    w24_w0_w4 below_1883 -114
+    w24_w0_w4 below_1884 -116
+    w24_w0_w4 below_1885 -116
+    w24_w0_w4 below_1886 -122
+    w24_w0_w4 below_1887 -107
+    w24_w0_w4 below_1888 -127
+    w24_w0_w4 below_1889 -122
+    w24_w0_w4 below_1890 -121
+    w24_w0_w4 below_1891 -131
+    w24_w0_w4 below_1892 -145
+    w24_w0_w4 below_1893 -141
+    w24_w0_w4 below_1894 -139
+    w24_w0_w4 below_1895 -131
+    w24_w0_w4 below_1896 -143
+    w24_w0_w4 below_1897 -162
+    w24_w0_w4 below_1898 -156
+    w24_w0_w4 below_1899 -158
+    w24_w0_w4 below_1900 -173
+    w24_w0_w4 below_1901 -158
+    w24_w0_w4 below_1902 -182
+    w24_w0_w4 below_1903 -186
+    w24_w0_w4 below_1904 -184
+    w24_w0_w4 below_1905 -200
+    w24_w0_w4 below_1906 -181
+    w24_w0_w4 below_1907 -214
+    w24_w0_w4 below_1908 -200
+    w24_w0_w4 below_1909 -227
+    w24_w0_w4 below_1910 -225
+    w24_w0_w4 below_1911 -225
+    w24_w0_w4 below_1912 -228
+    w24_w0_w4 below_1913 -257
+    w24_w0_w4 below_1914 -261
+    w24_w0_w4 below_1915 -275
+    w24_w0_w4 below_1916 -283
+    w24_w0_w4 below_1917 -296
+    w24_w0_w4 below_1918 -268
+    w24_w0_w4 below_1919 -302
+    w24_w0_w4 below_1920 -324
+    w24_w0_w4 below_1921 -349
+    w24_w0_w4 below_1922 -343
+    w24_w0_w4 below_1923 -365
+    w24_w0_w4 below_1924 -355
+    w24_w0_w4 below_1925 -366
+    w24_w0_w4 below_1926 -415
+    w24_w0_w4 below_1927 -404
+    w24_w0_w4 below_1928 -398
+    w24_w0_w4 below_1929 -472
+    w24_w0_w4 below_1930 -442
+    w24_w0_w4 below_1931 -510
+    w24_w0_w4 below_1932 -478
+    w24_w0_w4 below_1933 -540
+    w24_w0_w4 below_1934 -556
+    w24_w0_w4 below_1935 -541
+    w24_w0_w4 below_1936 -559
+    w24_w0_w4 below_1937 -577
+    w24_w0_w4 below_1938 -583
+    w24_w0_w4 below_1939 -672
+    w24_w0_w4 below_1940 -670
+    w24_w0_w4 below_1941 -617
+    w24_w0_w4 below_1942 -704
+    w24_w0_w4 below_1943 -678
+    w24_w0_w4 below_1944 -758
+    w24_w0_w4 below_1945 -816
+    w24_w0_w4 below_1946 -827
+    w24_w0_w4 below_1947 -833
+    w24_w0_w4 below_1948 -831
+    w24_w0_w4 below_1949 -860
+    w24_w0_w4 below_1950 -889
+    w24_w0_w4 below_1951 -902
+    w24_w0_w4 below_1952 -990
+    w24_w0_w4 below_1953 -993
+    w24_w0_w4 below_1954 -972
+    w24_w0_w4 below_1955 -1005
+    w24_w0_w4 below_1956 -1043
+    w24_w0_w4 below_1957 -1087
+    w24_w0_w4 below_1958 -1161
+    w24_w0_w4 below_1959 -1085
+    w24_w0_w4 below_1960 -1211
+    w24_w0_w4 below_1961 -1173
+    w24_w0_w4 below_1962 -1230
+    w24_w0_w4 below_1963 -1184
+    w24_w0_w4 below_1964 -1155
+    w24_w0_w4 below_1965 -1263
+    w24_w0_w4 below_1966 -1151
+    w24_w0_w4 below_1967 -1293
+    w24_w0_w4 below_1968 -1257
+    w24_w0_w4 below_1969 -1308
+    w24_w0_w4 below_1970 -1271
+    w24_w0_w4 below_1971 -1370
+    w24_w0_w4 below_1972 -1428
+    w24_w0_w4 below_1973 -1459
+    w24_w0_w4 below_1974 -1437
+    w24_w0_w4 below_1975 -1484
+    w24_w0_w4 below_1976 -1374
+    w24_w0_w4 below_1977 -1216
+    w24_w0_w4 below_1978 -1422
+    w24_w0_w4 below_1979 -1467
+    w24_w0_w4 below_1980 -1425
+    w24_w0_w4 below_1981 -1557
+    w24_w0_w4 below_1982 -1544
+    w24_w0_w4 below_1983 -1417
+    w24_w0_w4 below_1984 -1425
+    w24_w0_w4 below_1985 -1434
+    w24_w0_w4 below_1986 -1557
+    w24_w0_w4 below_1987 -1635
+    w24_w0_w4 below_1988 -1517
+    w24_w0_w4 below_1989 -1705
+    w24_w0_w4 below_1990 -1658
+    w24_w0_w4 below_1991 -1582
+    w24_w0_w4 below_1992 -1529
+    w24_w0_w4 below_1993 -1654
+    w24_w0_w4 below_1994 -1625
+    w24_w0_w4 below_1995 -1439
+    w24_w0_w4 below_1996 -1760
+    w24_w0_w4 below_1997 -1556
+    w24_w0_w4 below_1998 -1555
+    w24_w0_w4 below_1999 -1446
+    w24_w0_w4 below_2000 -1648
+    w276_w0_w4 above_1800 12
+    w276_w0_w4 above_1801 12
+    w276_w0_w4 above_1802 14
+    w276_w0_w4 above_1803 14
+    w276_w0_w4 above_1804 15
+    w276_w0_w4 above_1805 15
+    w276_w0_w4 above_1806 16
+    w276_w0_w4 above_1807 17
+    w276_w0_w4 above_1808 19
+    w276_w0_w4 above_1809 19
+    w276_w0_w4 above_1810 20
+    w276_w0_w4 above_1811 23
+    w276_w0_w4 above_1812 24
+    w276_w0_w4 above_1813 21
+    w276_w0_w4 above_1814 24
+    w276_w0_w4 above_1815 26
+    w276_w0_w4 above_1816 29
+    w276_w0_w4 above_1817 28
+    w276_w0_w4 above_1818 32
+    w276_w0_w4 above_1819 33
+    w276_w0_w4 above_1820 34
+    w276_w0_w4 above_1821 38
+    w276_w0_w4 above_1822 38
+    w276_w0_w4 above_1823 39
+    w276_w0_w4 above_1824 41
+    w276_w0_w4 above_1825 46
+    w276_w0_w4 above_1826 46
+    w276_w0_w4 above_1827 45
+    w276_w0_w4 above_1828 48
+    w276_w0_w4 above_1829 51
+    w276_w0_w4 above_1830 53
+    w276_w0_w4 above_1831 60
+    w276_w0_w4 above_1832 57
+    w276_w0_w4 above_1833 62
+    w276_w0_w4 above_1834 64
+    w276_w0_w4 above_1835 62
+    w276_w0_w4 above_1836 60
+    w276_w0_w4 above_1837 76
+    w276_w0_w4 above_1838 72
+    w276_w0_w4 above_1839 67
+    w276_w0_w4 above_1840 81
+    w276_w0_w4 above_1841 79
+    w276_w0_w4 above_1842 81
+    w276_w0_w4 above_1843 82
+    w276_w0_w4 above_1844 87
+    w276_w0_w4 above_1845 90
+    w276_w0_w4 above_1846 99
+    w276_w0_w4 above_1847 92
+    w276_w0_w4 above_1848 85
+    w276_w0_w4 above_1849 96
+    w276_w0_w4 above_1850 101
+    w276_w0_w4 above_1851 95
+    w276_w0_w4 above_1852 100
+    w276_w0_w4 above_1853 93
+    w276_w0_w4 above_1854 108
+    w276_w0_w4 above_1855 101
+    w276_w0_w4 above_1856 103
+    w276_w0_w4 above_1857 101
+    w276_w0_w4 above_1858 104
+    w276_w0_w4 above_1859 101
+    w276_w0_w4 above_1860 126
+    w276_w0_w4 above_1861 104
+    w276_w0_w4 above_1862 116
+    w276_w0_w4 above_1863 117
+    w276_w0_w4 above_1864 109
+    w276_w0_w4 above_1865 108
+    w276_w0_w4 above_1866 116
+    w276_w0_w4 above_1867 127
+    w276_w0_w4 above_1868 110
+    w276_w0_w4 above_1869 120
+    w276_w0_w4 above_1870 120
+    w276_w0_w4 above_1871 126
+    w276_w0_w4 above_1872 111
+    w276_w0_w4 above_1873 119
+    w276_w0_w4 above_1874 132
+    w276_w0_w4 above_1875 132
+    w276_w0_w4 above_1876 126
+    w276_w0_w4 above_1877 121
+    w276_w0_w4 above_1878 118
+    w276_w0_w4 above_1879 125
+    w276_w0_w4 above_1880 127
+    w276_w0_w4 above_1881 125
+    w276_w0_w4 above_1882 125
+    w276_w0_w4 above_1883 125
+    w276_w0_w4 above_1884 123
+    w276_w0_w4 above_1885 128
+    w276_w0_w4 above_1886 123
+    w276_w0_w4 above_1887 118
+    w276_w0_w4 above_1888 135
+    w276_w0_w4 above_1889 133
+    w276_w0_w4 above_1890 126
+    w276_w0_w4 above_1891 128
+    w276_w0_w4 above_1892 125
+    w276_w0_w4 above_1893 137
+    w276_w0_w4 above_1894 127
+    w276_w0_w4 above_1895 123
+    w276_w0_w4 above_1896 133
+    w276_w0_w4 above_1897 137
+    w276_w0_w4 above_1898 127
+    w276_w0_w4 above_1899 120
+    w276_w0_w4 above_1900 135
+    w276_w0_w4 above_1901 136
+    w276_w0_w4 above_1902 117
+    w276_w0_w4 above_1903 130
+    w276_w0_w4 above_1904 141
+    w276_w0_w4 above_1905 122
+    w276_w0_w4 above_1906 131
+    w276_w0_w4 above_1907 139
+    w276_w0_w4 above_1908 132
+    w276_w0_w4 above_1909 141
+    w276_w0_w4 above_1910 125
+    w276_w0_w4 above_1911 140
+    w276_w0_w4 above_1912 126
+    w276_w0_w4 above_1913 133
+    w276_w0_w4 above_1914 131
+    w276_w0_w4 above_1915 128
+    w276_w0_w4 above_1916 142
+    w276_w0_w4 above_1917 121
+    w276_w0_w4 above_1918 129
+    w276_w0_w4 above_1919 129
+    w276_w0_w4 above_1920 132
+    w276_w0_w4 above_1921 139
+    w276_w0_w4 above_1922 132
+    w276_w0_w4 above_1923 125
+    w276_w0_w4 above_1924 131
+    w276_w0_w4 above_1925 133
+    w276_w0_w4 above_1926 141
+    w276_w0_w4 above_1927 134
+    w276_w0_w4 above_1928 139
+    w276_w0_w4 above_1929 121
+    w276_w0_w4 above_1930 127
+    w276_w0_w4 above_1931 131
+    w276_w0_w4 above_1932 148
+    w276_w0_w4 above_1933 133
+    w276_w0_w4 above_1934 119
+    w276_w0_w4 above_1935 133
+    w276_w0_w4 above_1936 136
+    w276_w0_w4 above_1937 130
+    w276_w0_w4 above_1938 137
+    w276_w0_w4 above_1939 138
+    w276_w0_w4 above_1940 142
+    w276_w0_w4 above_1941 125
+    w276_w0_w4 above_1942 125
+    w276_w0_w4 above_1943 138
+    w276_w0_w4 above_1944 132
+    w276_w0_w4 above_1945 124
+    w276_w0_w4 above_1946 135
+    w276_w0_w4 above_1947 137
+    w276_w0_w4 above_1948 128
+    w276_w0_w4 above_1949 131
+    w276_w0_w4 above_1950 134
+    w276_w0_w4 above_1951 127
+    w276_w0_w4 above_1952 147
+    w276_w0_w4 above_1953 144
+    w276_w0_w4 above_1954 126
+    w276_w0_w4 above_1955 123
+    w276_w0_w4 above_1956 141
+    w276_w0_w4 above_1957 147
+    w276_w0_w4 above_1958 130
+    w276_w0_w4 above_1959 132
+    w276_w0_w4 above_1960 135
+    w276_w0_w4 above_1961 134
+    w276_w0_w4 above_1962 131
+    w276_w0_w4 above_1963 129
+    w276_w0_w4 above_1964 131
+    w276_w0_w4 above_1965 138
+    w276_w0_w4 above_1966 131
+    w276_w0_w4 above_1967 135
+    w276_w0_w4 above_1968 129
+    w276_w0_w4 above_1969 128
+    w276_w0_w4 above_1970 129
+    w276_w0_w4 above_1971 132
+    w276_w0_w4 above_1972 132
+    w276_w0_w4 above_1973 123
+    w276_w0_w4 above_1974 133
+    w276_w0_w4 above_1975 122
+    w276_w0_w4 above_1976 133
+    w276_w0_w4 above_1977 122
+    w276_w0_w4 above_1978 114
+    w276_w0_w4 above_1979 128
+    w276_w0_w4 above_1980 126
+    w276_w0_w4 above_1981 128
+    w276_w0_w4 above_1982 128
+    w276_w0_w4 above_1983 119
+    w276_w0_w4 above_1984 113
+    w276_w0_w4 above_1985 124
+    w276_w0_w4 above_1986 120
+    w276_w0_w4 above_1987 119
+    w276_w0_w4 above_1988 114
+    w276_w0_w4 above_1989 124
+    w276_w0_w4 above_1990 106
+    w276_w0_w4 above_1991 115
+    w276_w0_w4 above_1992 109
+    w276_w0_w4 above_1993 100
+    w276_w0_w4 above_1994 116
+    w276_w0_w4 above_1995 108
+    w276_w0_w4 above_1996 113
+    w276_w0_w4 above_1997 119
+    w276_w0_w4 above_1998 101
+    w276_w0_w4 above_1999 112
+    w276_w0_w4 above_2000 103
+    w276_w0_w4 below_1800 -12
+    w276_w0_w4 below_1801 -12
+    w276_w0_w4 below_1802 -14
+    w276_w0_w4 below_1803 -14
+    w276_w0_w4 below_1804 -15
+    w276_w0_w4 below_1805 -15
+    w276_w0_w4 below_1806 -16
+    w276_w0_w4 below_1807 -17
+    w276_w0_w4 below_1808 -19
+    w276_w0_w4 below_1809 -19
+    w276_w0_w4 below_1810 -20
+    w276_w0_w4 below_1811 -23
+    w276_w0_w4 below_1812 -24
+    w276_w0_w4 below_1813 -21
+    w276_w0_w4 below_1814 -24
+    w276_w0_w4 below_1815 -26
+    w276_w0_w4 below_1816 -29
+    w276_w0_w4 below_1817 -28
+    w276_w0_w4 below_1818 -32
+    w276_w0_w4 below_1819 -33
+    w276_w0_w4 below_1820 -34
+    w276_w0_w4 below_1821 -38
+    w276_w0_w4 below_1822 -38
+    w276_w0_w4 below_1823 -39
+    w276_w0_w4 below_1824 -41
+    w276_w0_w4 below_1825 -46
+    w276_w0_w4 below_1826 -46
+    w276_w0_w4 below_1827 -45
+    w276_w0_w4 below_1828 -48
+    w276_w0_w4 below_1829 -51
+    w276_w0_w4 below_1830 -53
+    w276_w0_w4 below_1831 -60
+    w276_w0_w4 below_1832 -57
+    w276_w0_w4 below_1833 -62
+    w276_w0_w4 below_1834 -64
+    w276_w0_w4 below_1835 -62
+    w276_w0_w4 below_1836 -60
+    w276_w0_w4 below_1837 -76
+    w276_w0_w4 below_1838 -72
+    w276_w0_w4 below_1839 -67
+    w276_w0_w4 below_1840 -81
+    w276_w0_w4 below_1841 -79
+    w276_w0_w4 below_1842 -81
+    w276_w0_w4 below_1843 -82
+    w276_w0_w4 below_1844 -87
+    w276_w0_w4 below_1845 -90
+    w276_w0_w4 below_1846 -99
+    w276_w0_w4 below_1847 -92
+    w276_w0_w4 below_1848 -85
+    w276_w0_w4 below_1849 -96
+    w276_w0_w4 below_1850 -101
+    w276_w0_w4 below_1851 -95
+    w276_w0_w4 below_1852 -100
+    w276_w0_w4 below_1853 -93
+    w276_w0_w4 below_1854 -108
+    w276_w0_w4 below_1855 -101
+    w276_w0_w4 below_1856 -103
+    w276_w0_w4 below_1857 -101
+    w276_w0_w4 below_1858 -104
+    w276_w0_w4 below_1859 -101
+    w276_w0_w4 below_1860 -126
+    w276_w0_w4 below_1861 -104
+    w276_w0_w4 below_1862 -116
+    w276_w0_w4 below_1863 -117
+    w276_w0_w4 below_1864 -109
+    w276_w0_w4 below_1865 -108
+    w276_w0_w4 below_1866 -116
+    w276_w0_w4 below_1867 -127
+    w276_w0_w4 below_1868 -110
+    w276_w0_w4 below_1869 -120
+    w276_w0_w4 below_1870 -120
+    w276_w0_w4 below_1871 -126
+    w276_w0_w4 below_1872 -111
+    w276_w0_w4 below_1873 -119
+    w276_w0_w4 below_1874 -132
+    w276_w0_w4 below_1875 -132
+    w276_w0_w4 below_1876 -126
+    w276_w0_w4 below_1877 -121
+    w276_w0_w4 below_1878 -118
+    w276_w0_w4 below_1879 -125
+    w276_w0_w4 below_1880 -127
+    w276_w0_w4 below_1881 -125
+    w276_w0_w4 below_1882 -125
+    w276_w0_w4 below_1883 -125
+    w276_w0_w4 below_1884 -123
+    w276_w0_w4 below_1885 -128
+    w276_w0_w4 below_1886 -123
+    w276_w0_w4 below_1887 -118
+    w276_w0_w4 below_1888 -135
+    w276_w0_w4 below_1889 -133
+    w276_w0_w4 below_1890 -126
+    w276_w0_w4 below_1891 -128
+    w276_w0_w4 below_1892 -125
+    w276_w0_w4 below_1893 -137
+    w276_w0_w4 below_1894 -127
+    w276_w0_w4 below_1895 -123
+    w276_w0_w4 below_1896 -133
+    w276_w0_w4 below_1897 -137
+    w276_w0_w4 below_1898 -127
+    w276_w0_w4 below_1899 -120
+    w276_w0_w4 below_1900 -135
+    w276_w0_w4 below_1901 -136
+    w276_w0_w4 below_1902 -117
+    w276_w0_w4 below_1903 -130
+    w276_w0_w4 below_1904 -141
+    w276_w0_w4 below_1905 -122
+    w276_w0_w4 below_1906 -131
+    w276_w0_w4 below_1907 -139
+    w276_w0_w4 below_1908 -132
+    w276_w0_w4 below_1909 -141
+    w276_w0_w4 below_1910 -125
+    w276_w0_w4 below_1911 -140
+    w276_w0_w4 below_1912 -126
+    w276_w0_w4 below_1913 -133
+    w276_w0_w4 below_1914 -131
+    w276_w0_w4 below_1915 -128
+    w276_w0_w4 below_1916 -142
+    w276_w0_w4 below_1917 -121
+    w276_w0_w4 below_1918 -129
+    w276_w0_w4 below_1919 -129
+    w276_w0_w4 below_1920 -132
+    w276_w0_w4 below_1921 -139
+    w276_w0_w4 below_1922 -132
+    w276_w0_w4 below_1923 -125
+    w276_w0_w4 below_1924 -131
+    w276_w0_w4 below_1925 -133
+    w276_w0_w4 below_1926 -141
+    w276_w0_w4 below_1927 -134
+    w276_w0_w4 below_1928 -139
+    w276_w0_w4 below_1929 -121
+    w276_w0_w4 below_1930 -127
+    w276_w0_w4 below_1931 -131
+    w276_w0_w4 below_1932 -148
+    w276_w0_w4 below_1933 -133
+    w276_w0_w4 below_1934 -119
+    w276_w0_w4 below_1935 -133
+    w276_w0_w4 below_1936 -136
+    w276_w0_w4 below_1937 -130
+    w276_w0_w4 below_1938 -137
+    w276_w0_w4 below_1939 -138
+    w276_w0_w4 below_1940 -142
+    w276_w0_w4 below_1941 -125
+    w276_w0_w4 below_1942 -125
+    w276_w0_w4 below_1943 -138
+    w276_w0_w4 below_1944 -132
+    w276_w0_w4 below_1945 -124
+    w276_w0_w4 below_1946 -135
+    w276_w0_w4 below_1947 -137
+    w276_w0_w4 below_1948 -128
+    w276_w0_w4 below_1949 -131
+    w276_w0_w4 below_1950 -134
+    w276_w0_w4 below_1951 -127
+    w276_w0_w4 below_1952 -147
+    w276_w0_w4 below_1953 -144
+    w276_w0_w4 below_1954 -126
+    w276_w0_w4 below_1955 -123
+    w276_w0_w4 below_1956 -141
+    w276_w0_w4 below_1957 -147
+    w276_w0_w4 below_1958 -130
+    w276_w0_w4 below_1959 -132
+    w276_w0_w4 below_1960 -135
+    w276_w0_w4 below_1961 -134
+    w276_w0_w4 below_1962 -131
+    w276_w0_w4 below_1963 -129
+    w276_w0_w4 below_1964 -131
+    w276_w0_w4 below_1965 -138
+    w276_w0_w4 below_1966 -131
+    w276_w0_w4 below_1967 -135
+    w276_w0_w4 below_1968 -129
+    w276_w0_w4 below_1969 -128
+    w276_w0_w4 below_1970 -129
+    w276_w0_w4 below_1971 -132
+    w276_w0_w4 below_1972 -132
+    w276_w0_w4 below_1973 -123
+    w276_w0_w4 below_1974 -133
+    w276_w0_w4 below_1975 -122
+    w276_w0_w4 below_1976 -133
+    w276_w0_w4 below_1977 -122
+    w276_w0_w4 below_1978 -114
+    w276_w0_w4 below_1979 -128
+    w276_w0_w4 below_1980 -126
+    w276_w0_w4 below_1981 -128
+    w276_w0_w4 below_1982 -128
+    w276_w0_w4 below_1983 -119
+    w276_w0_w4 below_1984 -113
+    w276_w0_w4 below_1985 -124
+    w276_w0_w4 below_1986 -120
+    w276_w0_w4 below_1987 -119
+    w276_w0_w4 below_1988 -114
+    w276_w0_w4 below_1989 -124
+    w276_w0_w4 below_1990 -106
+    w276_w0_w4 below_1991 -115
+    w276_w0_w4 below_1992 -109
+    w276_w0_w4 below_1993 -100
+    w276_w0_w4 below_1994 -116
+    w276_w0_w4 below_1995 -108
+    w276_w0_w4 below_1996 -113
+    w276_w0_w4 below_1997 -119
+    w276_w0_w4 below_1998 -101
+    w276_w0_w4 below_1999 -112
+    w276_w0_w4 below_2000 -103
+    w137_w0_w4 above_1800 4
+    w137_w0_w4 above_1801 4
+    w137_w0_w4 above_1802 5
+    w137_w0_w4 above_1803 5
+    w137_w0_w4 above_1804 5
+    w137_w0_w4 above_1805 6
+    w137_w0_w4 above_1806 6
+    w137_w0_w4 above_1807 6
+    w137_w0_w4 above_1808 7
+    w137_w0_w4 above_1809 8
+    w137_w0_w4 above_1810 8
+    w137_w0_w4 above_1811 8
+    w137_w0_w4 above_1812 8
+    w137_w0_w4 above_1813 9
+    w137_w0_w4 above_1814 10
+    w137_w0_w4 above_1815 10
+    w137_w0_w4 above_1816 10
+    w137_w0_w4 above_1817 13
+    w137_w0_w4 above_1818 13
+    w137_w0_w4 above_1819 14
+    w137_w0_w4 above_1820 13
+    w137_w0_w4 above_1821 14
+    w137_w0_w4 above_1822 15
+    w137_w0_w4 above_1823 17
+    w137_w0_w4 above_1824 16
+    w137_w0_w4 above_1825 18
+    w137_w0_w4 above_1826 20
+    w137_w0_w4 above_1827 22
+    w137_w0_w4 above_1828 22
+    w137_w0_w4 above_1829 26
+    w137_w0_w4 above_1830 23
+    w137_w0_w4 above_1831 25
+    w137_w0_w4 above_1832 26
+    w137_w0_w4 above_1833 29
+    w137_w0_w4 above_1834 29
+    w137_w0_w4 above_1835 32
+    w137_w0_w4 above_1836 34
+    w137_w0_w4 above_1837 35
+    w137_w0_w4 above_1838 39
+    w137_w0_w4 above_1839 36
+    w137_w0_w4 above_1840 42
+    w137_w0_w4 above_1841 42
+    w137_w0_w4 above_1842 42
+    w137_w0_w4 above_1843 46
+    w137_w0_w4 above_1844 50
+    w137_w0_w4 above_1845 51
+    w137_w0_w4 above_1846 49
+    w137_w0_w4 above_1847 54
+    w137_w0_w4 above_1848 55
+    w137_w0_w4 above_1849 54
+    w137_w0_w4 above_1850 62
+    w137_w0_w4 above_1851 66
+    w137_w0_w4 above_1852 62
+    w137_w0_w4 above_1853 68
+    w137_w0_w4 above_1854 63
+    w137_w0_w4 above_1855 70
+    w137_w0_w4 above_1856 79
+    w137_w0_w4 above_1857 76
+    w137_w0_w4 above_1858 71
+    w137_w0_w4 above_1859 81
+    w137_w0_w4 above_1860 76
+    w137_w0_w4 above_1861 77
+    w137_w0_w4 above_1862 86
+    w137_w0_w4 above_1863 81
+    w137_w0_w4 above_1864 93
+    w137_w0_w4 above_1865 83
+    w137_w0_w4 above_1866 89
+    w137_w0_w4 above_1867 88
+    w137_w0_w4 above_1868 91
+    w137_w0_w4 above_1869 100
+    w137_w0_w4 above_1870 106
+    w137_w0_w4 above_1871 95
+    w137_w0_w4 above_1872 99
+    w137_w0_w4 above_1873 100
+    w137_w0_w4 above_1874 109
+    w137_w0_w4 above_1875 97
+    w137_w0_w4 above_1876 101
+    w137_w0_w4 above_1877 96
+    w137_w0_w4 above_1878 113
+    w137_w0_w4 above_1879 105
+    w137_w0_w4 above_1880 113
+    w137_w0_w4 above_1881 117
+    w137_w0_w4 above_1882 104
+    w137_w0_w4 above_1883 107
+    w137_w0_w4 above_1884 101
+    w137_w0_w4 above_1885 117
+    w137_w0_w4 above_1886 106
+    w137_w0_w4 above_1887 116
+    w137_w0_w4 above_1888 109
+    w137_w0_w4 above_1889 118
+    w137_w0_w4 above_1890 119
+    w137_w0_w4 above_1891 121
+    w137_w0_w4 above_1892 111
+    w137_w0_w4 above_1893 110
+    w137_w0_w4 above_1894 109
+    w137_w0_w4 above_1895 106
+    w137_w0_w4 above_1896 122
+    w137_w0_w4 above_1897 105
+    w137_w0_w4 above_1898 111
+    w137_w0_w4 above_1899 121
+    w137_w0_w4 above_1900 116
+    w137_w0_w4 above_1901 115
+    w137_w0_w4 above_1902 122
+    w137_w0_w4 above_1903 127
+    w137_w0_w4 above_1904 114
+    w137_w0_w4 above_1905 124
+    w137_w0_w4 above_1906 126
+    w137_w0_w4 above_1907 107
+    w137_w0_w4 above_1908 120
+    w137_w0_w4 above_1909 123
+    w137_w0_w4 above_1910 128
+    w137_w0_w4 above_1911 115
+    w137_w0_w4 above_1912 122
+    w137_w0_w4 above_1913 125
+    w137_w0_w4 above_1914 123
+    w137_w0_w4 above_1915 129
+    w137_w0_w4 above_1916 121
+    w137_w0_w4 above_1917 132
+    w137_w0_w4 above_1918 123
+    w137_w0_w4 above_1919 124
+    w137_w0_w4 above_1920 120
+    w137_w0_w4 above_1921 127
+    w137_w0_w4 above_1922 121
+    w137_w0_w4 above_1923 126
+    w137_w0_w4 above_1924 123
+    w137_w0_w4 above_1925 115
+    w137_w0_w4 above_1926 138
+    w137_w0_w4 above_1927 124
+    w137_w0_w4 above_1928 117
+    w137_w0_w4 above_1929 130
+    w137_w0_w4 above_1930 120
+    w137_w0_w4 above_1931 120
+    w137_w0_w4 above_1932 122
+    w137_w0_w4 above_1933 125
+    w137_w0_w4 above_1934 130
+    w137_w0_w4 above_1935 117
+    w137_w0_w4 above_1936 113
+    w137_w0_w4 above_1937 124
+    w137_w0_w4 above_1938 129
+    w137_w0_w4 above_1939 117
+    w137_w0_w4 above_1940 127
+    w137_w0_w4 above_1941 113
+    w137_w0_w4 above_1942 113
+    w137_w0_w4 above_1943 131
+    w137_w0_w4 above_1944 124
+    w137_w0_w4 above_1945 137
+    w137_w0_w4 above_1946 119
+    w137_w0_w4 above_1947 122
+    w137_w0_w4 above_1948 135
+    w137_w0_w4 above_1949 134
+    w137_w0_w4 above_1950 121
+    w137_w0_w4 above_1951 131
+    w137_w0_w4 above_1952 134
+    w137_w0_w4 above_1953 132
+    w137_w0_w4 above_1954 131
+    w137_w0_w4 above_1955 126
+    w137_w0_w4 above_1956 121
+    w137_w0_w4 above_1957 122
+    w137_w0_w4 above_1958 113
+    w137_w0_w4 above_1959 123
+    w137_w0_w4 above_1960 126
+    w137_w0_w4 above_1961 132
+    w137_w0_w4 above_1962 126
+    w137_w0_w4 above_1963 118
+    w137_w0_w4 above_1964 134
+    w137_w0_w4 above_1965 113
+    w137_w0_w4 above_1966 127
+    w137_w0_w4 above_1967 132
+    w137_w0_w4 above_1968 129
+    w137_w0_w4 above_1969 131
+    w137_w0_w4 above_1970 124
+    w137_w0_w4 above_1971 124
+    w137_w0_w4 above_1972 121
+    w137_w0_w4 above_1973 126
+    w137_w0_w4 above_1974 128
+    w137_w0_w4 above_1975 123
+    w137_w0_w4 above_1976 129
+    w137_w0_w4 above_1977 129
+    w137_w0_w4 above_1978 110
+    w137_w0_w4 above_1979 127
+    w137_w0_w4 above_1980 131
+    w137_w0_w4 above_1981 133
+    w137_w0_w4 above_1982 109
+    w137_w0_w4 above_1983 124
+    w137_w0_w4 above_1984 130
+    w137_w0_w4 above_1985 126
+    w137_w0_w4 above_1986 120
+    w137_w0_w4 above_1987 140
+    w137_w0_w4 above_1988 114
+    w137_w0_w4 above_1989 123
+    w137_w0_w4 above_1990 129
+    w137_w0_w4 above_1991 125
+    w137_w0_w4 above_1992 128
+    w137_w0_w4 above_1993 127
+    w137_w0_w4 above_1994 127
+    w137_w0_w4 above_1995 127
+    w137_w0_w4 above_1996 136
+    w137_w0_w4 above_1997 125
+    w137_w0_w4 above_1998 126
+    w137_w0_w4 above_1999 128
+    w137_w0_w4 above_2000 115
+    w137_w0_w4 below_1800 -4
+    w137_w0_w4 below_1801 -4
+    w137_w0_w4 below_1802 -5
+    w137_w0_w4 below_1803 -5
+    w137_w0_w4 below_1804 -5
+    w137_w0_w4 below_1805 -6
+    w137_w0_w4 below_1806 -6
+    w137_w0_w4 below_1807 -6
+    w137_w0_w4 below_1808 -7
+    w137_w0_w4 below_1809 -8
+    w137_w0_w4 below_1810 -8
+    w137_w0_w4 below_1811 -8
+    w137_w0_w4 below_1812 -8
+    w137_w0_w4 below_1813 -9
+    w137_w0_w4 below_1814 -10
+    w137_w0_w4 below_1815 -10
+    w137_w0_w4 below_1816 -10
+    w137_w0_w4 below_1817 -13
+    w137_w0_w4 below_1818 -13
+    w137_w0_w4 below_1819 -14
+    w137_w0_w4 below_1820 -13
+    w137_w0_w4 below_1821 -14
+    w137_w0_w4 below_1822 -15
+    w137_w0_w4 below_1823 -17
+    w137_w0_w4 below_1824 -16
+    w137_w0_w4 below_1825 -18
+    w137_w0_w4 below_1826 -20
+    w137_w0_w4 below_1827 -22
+    w137_w0_w4 below_1828 -22
+    w137_w0_w4 below_1829 -26
+    w137_w0_w4 below_1830 -23
+    w137_w0_w4 below_1831 -25
+    w137_w0_w4 below_1832 -26
+    w137_w0_w4 below_1833 -29
+    w137_w0_w4 below_1834 -29
+    w137_w0_w4 below_1835 -32
+    w137_w0_w4 below_1836 -34
+    w137_w0_w4 below_1837 -35
+    w137_w0_w4 below_1838 -39
+    w137_w0_w4 below_1839 -36
+    w137_w0_w4 below_1840 -42
+    w137_w0_w4 below_1841 -42
+    w137_w0_w4 below_1842 -42
+    w137_w0_w4 below_1843 -46
+    w137_w0_w4 below_1844 -50
+    w137_w0_w4 below_1845 -51
+    w137_w0_w4 below_1846 -49
+    w137_w0_w4 below_1847 -54
+    w137_w0_w4 below_1848 -55
+    w137_w0_w4 below_1849 -54
+    w137_w0_w4 below_1850 -62
+    w137_w0_w4 below_1851 -66
+    w137_w0_w4 below_1852 -62
+    w137_w0_w4 below_1853 -68
+    w137_w0_w4 below_1854 -63
+    w137_w0_w4 below_1855 -70
+    w137_w0_w4 below_1856 -79
+    w137_w0_w4 below_1857 -76
+    w137_w0_w4 below_1858 -71
+    w137_w0_w4 below_1859 -81
+    w137_w0_w4 below_1860 -76
+    w137_w0_w4 below_1861 -77
+    w137_w0_w4 below_1862 -86
+    w137_w0_w4 below_1863 -81
+    w137_w0_w4 below_1864 -93
+    w137_w0_w4 below_1865 -83
+    w137_w0_w4 below_1866 -89
+    w137_w0_w4 below_1867 -88
+    w137_w0_w4 below_1868 -91
+    w137_w0_w4 below_1869 -100
+    w137_w0_w4 below_1870 -106
+    w137_w0_w4 below_1871 -95
+    w137_w0_w4 below_1872 -99
+    w137_w0_w4 below_1873 -100
+    w137_w0_w4 below_1874 -109
+    w137_w0_w4 below_1875 -97
+    w137_w0_w4 below_1876 -101
+    w137_w0_w4 below_1877 -96
+    w137_w0_w4 below_1878 -113
+    w137_w0_w4 below_1879 -105
+    w137_w0_w4 below_1880 -113
+    w137_w0_w4 below_1881 -117
+    w137_w0_w4 below_1882 -104
+    w137_w0_w4 below_1883 -107
+    w137_w0_w4 below_1884 -101
+    w137_w0_w4 below_1885 -117
+    w137_w0_w4 below_1886 -106
+    w137_w0_w4 below_1887 -116
+    w137_w0_w4 below_1888 -109
+    w137_w0_w4 below_1889 -118
+    w137_w0_w4 below_1890 -119
+    w137_w0_w4 below_1891 -121
+    w137_w0_w4 below_1892 -111
+    w137_w0_w4 below_1893 -110
+    w137_w0_w4 below_1894 -109
+    w137_w0_w4 below_1895 -106
+    w137_w0_w4 below_1896 -122
+    w137_w0_w4 below_1897 -105
+    w137_w0_w4 below_1898 -111
+    w137_w0_w4 below_1899 -121
+    w137_w0_w4 below_1900 -116
+    w137_w0_w4 below_1901 -115
+    w137_w0_w4 below_1902 -122
+    w137_w0_w4 below_1903 -127
+    w137_w0_w4 below_1904 -114
+    w137_w0_w4 below_1905 -124
+    w137_w0_w4 below_1906 -126
+    w137_w0_w4 below_1907 -107
+    w137_w0_w4 below_1908 -120
+    w137_w0_w4 below_1909 -123
+    w137_w0_w4 below_1910 -128
+    w137_w0_w4 below_1911 -115
+    w137_w0_w4 below_1912 -122
+    w137_w0_w4 below_1913 -125
+    w137_w0_w4 below_1914 -123
+    w137_w0_w4 below_1915 -129
+    w137_w0_w4 below_1916 -121
+    w137_w0_w4 below_1917 -132
+    w137_w0_w4 below_1918 -123
+    w137_w0_w4 below_1919 -124
+    w137_w0_w4 below_1920 -120
+    w137_w0_w4 below_1921 -127
+    w137_w0_w4 below_1922 -121
+    w137_w0_w4 below_1923 -126
+    w137_w0_w4 below_1924 -123
+    w137_w0_w4 below_1925 -115
+    w137_w0_w4 below_1926 -138
+    w137_w0_w4 below_1927 -124
+    w137_w0_w4 below_1928 -117
+    w137_w0_w4 below_1929 -130
+    w137_w0_w4 below_1930 -120
+    w137_w0_w4 below_1931 -120
+    w137_w0_w4 below_1932 -122
+    w137_w0_w4 below_1933 -125
+    w137_w0_w4 below_1934 -130
+    w137_w0_w4 below_1935 -117
+    w137_w0_w4 below_1936 -113
+    w137_w0_w4 below_1937 -124
+    w137_w0_w4 below_1938 -129
+    w137_w0_w4 below_1939 -117
+    w137_w0_w4 below_1940 -127
+    w137_w0_w4 below_1941 -113
+    w137_w0_w4 below_1942 -113
+    w137_w0_w4 below_1943 -131
+    w137_w0_w4 below_1944 -124
+    w137_w0_w4 below_1945 -137
+    w137_w0_w4 below_1946 -119
+    w137_w0_w4 below_1947 -122
+    w137_w0_w4 below_1948 -135
+    w137_w0_w4 below_1949 -134
+    w137_w0_w4 below_1950 -121
+    w137_w0_w4 below_1951 -131
+    w137_w0_w4 below_1952 -134
+    w137_w0_w4 below_1953 -132
+    w137_w0_w4 below_1954 -131
+    w137_w0_w4 below_1955 -126
+    w137_w0_w4 below_1956 -121
+    w137_w0_w4 below_1957 -122
+    w137_w0_w4 below_1958 -113
+    w137_w0_w4 below_1959 -123
+    w137_w0_w4 below_1960 -126
+    w137_w0_w4 below_1961 -132
+    w137_w0_w4 below_1962 -126
+    w137_w0_w4 below_1963 -118
+    w137_w0_w4 below_1964 -134
+    w137_w0_w4 below_1965 -113
+    w137_w0_w4 below_1966 -127
+    w137_w0_w4 below_1967 -132
+    w137_w0_w4 below_1968 -129
+    w137_w0_w4 below_1969 -131
+    w137_w0_w4 below_1970 -124
+    w137_w0_w4 below_1971 -124
+    w137_w0_w4 below_1972 -121
+    w137_w0_w4 below_1973 -126
+    w137_w0_w4 below_1974 -128
+    w137_w0_w4 below_1975 -123
+    w137_w0_w4 below_1976 -129
+    w137_w0_w4 below_1977 -129
+    w137_w0_w4 below_1978 -110
+    w137_w0_w4 below_1979 -127
+    w137_w0_w4 below_1980 -131
+    w137_w0_w4 below_1981 -133
+    w137_w0_w4 below_1982 -109
+    w137_w0_w4 below_1983 -124
+    w137_w0_w4 below_1984 -130
+    w137_w0_w4 below_1985 -126
+    w137_w0_w4 below_1986 -120
+    w137_w0_w4 below_1987 -140
+    w137_w0_w4 below_1988 -114
+    w137_w0_w4 below_1989 -123
+    w137_w0_w4 below_1990 -129
+    w137_w0_w4 below_1991 -125
+    w137_w0_w4 below_1992 -128
+    w137_w0_w4 below_1993 -127
+    w137_w0_w4 below_1994 -127
+    w137_w0_w4 below_1995 -127
+    w137_w0_w4 below_1996 -136
+    w137_w0_w4 below_1997 -125
+    w137_w0_w4 below_1998 -126
+    w137_w0_w4 below_1999 -128
+    w137_w0_w4 below_2000 -115
+    w22_w0_w4 above_1800 2
+    w22_w0_w4 above_1801 3
+    w22_w0_w4 above_1802 3
+    w22_w0_w4 above_1803 3
+    w22_w0_w4 above_1804 3
+    w22_w0_w4 above_1805 3
+    w22_w0_w4 above_1806 3
+    w22_w0_w4 above_1807 4
+    w22_w0_w4 above_1808 3
+    w22_w0_w4 above_1809 4
+    w22_w0_w4 above_1810 5
+    w22_w0_w4 above_1811 4
+    w22_w0_w4 above_1812 5
+    w22_w0_w4 above_1813 6
+    w22_w0_w4 above_1814 6
+    w22_w0_w4 above_1815 6
+    w22_w0_w4 above_1816 6
+    w22_w0_w4 above_1817 7
+    w22_w0_w4 above_1818 7
+    w22_w0_w4 above_1819 8
+    w22_w0_w4 above_1820 9
+    w22_w0_w4 above_1821 8
+    w22_w0_w4 above_1822 10
+    w22_w0_w4 above_1823 10
+    w22_w0_w4 above_1824 11
+    w22_w0_w4 above_1825 11
+    w22_w0_w4 above_1826 12
+    w22_w0_w4 above_1827 12
+    w22_w0_w4 above_1828 13
+    w22_w0_w4 above_1829 15
+    w22_w0_w4 above_1830 15
+    w22_w0_w4 above_1831 15
+    w22_w0_w4 above_1832 18
+    w22_w0_w4 above_1833 16
+    w22_w0_w4 above_1834 17
+    w22_w0_w4 above_1835 19
+    w22_w0_w4 above_1836 22
+    w22_w0_w4 above_1837 23
+    w22_w0_w4 above_1838 22
+    w22_w0_w4 above_1839 22
+    w22_w0_w4 above_1840 25
+    w22_w0_w4 above_1841 26
+    w22_w0_w4 above_1842 30
+    w22_w0_w4 above_1843 27
+    w22_w0_w4 above_1844 29
+    w22_w0_w4 above_1845 36
+    w22_w0_w4 above_1846 34
+    w22_w0_w4 above_1847 36
+    w22_w0_w4 above_1848 36
+    w22_w0_w4 above_1849 40
+    w22_w0_w4 above_1850 42
+    w22_w0_w4 above_1851 43
+    w22_w0_w4 above_1852 47
+    w22_w0_w4 above_1853 50
+    w22_w0_w4 above_1854 51
+    w22_w0_w4 above_1855 51
+    w22_w0_w4 above_1856 52
+    w22_w0_w4 above_1857 55
+    w22_w0_w4 above_1858 56
+    w22_w0_w4 above_1859 62
+    w22_w0_w4 above_1860 59
+    w22_w0_w4 above_1861 59
+    w22_w0_w4 above_1862 66
+    w22_w0_w4 above_1863 66
+    w22_w0_w4 above_1864 72
+    w22_w0_w4 above_1865 74
+    w22_w0_w4 above_1866 74
+    w22_w0_w4 above_1867 70
+    w22_w0_w4 above_1868 75
+    w22_w0_w4 above_1869 86
+    w22_w0_w4 above_1870 84
+    w22_w0_w4 above_1871 88
+    w22_w0_w4 above_1872 85
+    w22_w0_w4 above_1873 85
+    w22_w0_w4 above_1874 82
+    w22_w0_w4 above_1875 87
+    w22_w0_w4 above_1876 90
+    w22_w0_w4 above_1877 90
+    w22_w0_w4 above_1878 96
+    w22_w0_w4 above_1879 92
+    w22_w0_w4 above_1880 96
+    w22_w0_w4 above_1881 102
+    w22_w0_w4 above_1882 98
+    w22_w0_w4 above_1883 109
+    w22_w0_w4 above_1884 98
+    w22_w0_w4 above_1885 100
+    w22_w0_w4 above_1886 99
+    w22_w0_w4 above_1887 117
+    w22_w0_w4 above_1888 99
+    w22_w0_w4 above_1889 113
+    w22_w0_w4 above_1890 115
+    w22_w0_w4 above_1891 111
+    w22_w0_w4 above_1892 116
+    w22_w0_w4 above_1893 98
+    w22_w0_w4 above_1894 105
+    w22_w0_w4 above_1895 111
+    w22_w0_w4 above_1896 115
+    w22_w0_w4 above_1897 114
+    w22_w0_w4 above_1898 111
+    w22_w0_w4 above_1899 122
+    w22_w0_w4 above_1900 108
+    w22_w0_w4 above_1901 121
+    w22_w0_w4 above_1902 107
+    w22_w0_w4 above_1903 107
+    w22_w0_w4 above_1904 122
+    w22_w0_w4 above_1905 112
+    w22_w0_w4 above_1906 119
+    w22_w0_w4 above_1907 122
+    w22_w0_w4 above_1908 120
+    w22_w0_w4 above_1909 120
+    w22_w0_w4 above_1910 120
+    w22_w0_w4 above_1911 120
+    w22_w0_w4 above_1912 122
+    w22_w0_w4 above_1913 113
+    w22_w0_w4 above_1914 117
+    w22_w0_w4 above_1915 114
+    w22_w0_w4 above_1916 115
+    w22_w0_w4 above_1917 128
+    w22_w0_w4 above_1918 125
+    w22_w0_w4 above_1919 112
+    w22_w0_w4 above_1920 123
+    w22_w0_w4 above_1921 121
+    w22_w0_w4 above_1922 123
+    w22_w0_w4 above_1923 124
+    w22_w0_w4 above_1924 118
+    w22_w0_w4 above_1925 118
+    w22_w0_w4 above_1926 112
+    w22_w0_w4 above_1927 132
+    w22_w0_w4 above_1928 119
+    w22_w0_w4 above_1929 127
+    w22_w0_w4 above_1930 127
+    w22_w0_w4 above_1931 126
+    w22_w0_w4 above_1932 124
+    w22_w0_w4 above_1933 127
+    w22_w0_w4 above_1934 132
+    w22_w0_w4 above_1935 116
+    w22_w0_w4 above_1936 124
+    w22_w0_w4 above_1937 126
+    w22_w0_w4 above_1938 111
+    w22_w0_w4 above_1939 127
+    w22_w0_w4 above_1940 124
+    w22_w0_w4 above_1941 117
+    w22_w0_w4 above_1942 125
+    w22_w0_w4 above_1943 125
+    w22_w0_w4 above_1944 128
+    w22_w0_w4 above_1945 122
+    w22_w0_w4 above_1946 134
+    w22_w0_w4 above_1947 121
+    w22_w0_w4 above_1948 115
+    w22_w0_w4 above_1949 128
+    w22_w0_w4 above_1950 130
+    w22_w0_w4 above_1951 123
+    w22_w0_w4 above_1952 121
+    w22_w0_w4 above_1953 124
+    w22_w0_w4 above_1954 132
+    w22_w0_w4 above_1955 129
+    w22_w0_w4 above_1956 118
+    w22_w0_w4 above_1957 128
+    w22_w0_w4 above_1958 123
+    w22_w0_w4 above_1959 113
+    w22_w0_w4 above_1960 123
+    w22_w0_w4 above_1961 133
+    w22_w0_w4 above_1962 124
+    w22_w0_w4 above_1963 126
+    w22_w0_w4 above_1964 114
+    w22_w0_w4 above_1965 120
+    w22_w0_w4 above_1966 119
+    w22_w0_w4 above_1967 120
+    w22_w0_w4 above_1968 126
+    w22_w0_w4 above_1969 113
+    w22_w0_w4 above_1970 124
+    w22_w0_w4 above_1971 117
+    w22_w0_w4 above_1972 118
+    w22_w0_w4 above_1973 128
+    w22_w0_w4 above_1974 125
+    w22_w0_w4 above_1975 126
+    w22_w0_w4 above_1976 128
+    w22_w0_w4 above_1977 131
+    w22_w0_w4 above_1978 130
+    w22_w0_w4 above_1979 122
+    w22_w0_w4 above_1980 118
+    w22_w0_w4 above_1981 120
+    w22_w0_w4 above_1982 138
+    w22_w0_w4 above_1983 127
+    w22_w0_w4 above_1984 133
+    w22_w0_w4 above_1985 116
+    w22_w0_w4 above_1986 117
+    w22_w0_w4 above_1987 106
+    w22_w0_w4 above_1988 128
+    w22_w0_w4 above_1989 130
+    w22_w0_w4 above_1990 129
+    w22_w0_w4 above_1991 125
+    w22_w0_w4 above_1992 126
+    w22_w0_w4 above_1993 129
+    w22_w0_w4 above_1994 129
+    w22_w0_w4 above_1995 123
+    w22_w0_w4 above_1996 128
+    w22_w0_w4 above_1997 120
+    w22_w0_w4 above_1998 114
+    w22_w0_w4 above_1999 118
+    w22_w0_w4 above_2000 126
+    w22_w0_w4 below_1800 -2
+    w22_w0_w4 below_1801 -3
+    w22_w0_w4 below_1802 -3
+    w22_w0_w4 below_1803 -3
+    w22_w0_w4 below_1804 -3
+    w22_w0_w4 below_1805 -3
+    w22_w0_w4 below_1806 -3
+    w22_w0_w4 below_1807 -4
+    w22_w0_w4 below_1808 -3
+    w22_w0_w4 below_1809 -4
+    w22_w0_w4 below_1810 -5
+    w22_w0_w4 below_1811 -4
+    w22_w0_w4 below_1812 -5
+    w22_w0_w4 below_1813 -6
+    w22_w0_w4 below_1814 -6
+    w22_w0_w4 below_1815 -6
+    w22_w0_w4 below_1816 -6
+    w22_w0_w4 below_1817 -7
+    w22_w0_w4 below_1818 -7
+    w22_w0_w4 below_1819 -8
+    w22_w0_w4 below_1820 -9
+    w22_w0_w4 below_1821 -8
+    w22_w0_w4 below_1822 -10
+    w22_w0_w4 below_1823 -10
+    w22_w0_w4 below_1824 -11
+    w22_w0_w4 below_1825 -11
+    w22_w0_w4 below_1826 -12
+    w22_w0_w4 below_1827 -12
+    w22_w0_w4 below_1828 -13
+    w22_w0_w4 below_1829 -15
+    w22_w0_w4 below_1830 -15
+    w22_w0_w4 below_1831 -15
+    w22_w0_w4 below_1832 -18
+    w22_w0_w4 below_1833 -16
+    w22_w0_w4 below_1834 -17
+    w22_w0_w4 below_1835 -19
+    w22_w0_w4 below_1836 -22
+    w22_w0_w4 below_1837 -23
+    w22_w0_w4 below_1838 -22
+    w22_w0_w4 below_1839 -22
+    w22_w0_w4 below_1840 -25
+    w22_w0_w4 below_1841 -26
+    w22_w0_w4 below_1842 -30
+    w22_w0_w4 below_1843 -27
+    w22_w0_w4 below_1844 -29
+    w22_w0_w4 below_1845 -36
+    w22_w0_w4 below_1846 -34
+    w22_w0_w4 below_1847 -36
+    w22_w0_w4 below_1848 -36
+    w22_w0_w4 below_1849 -40
+    w22_w0_w4 below_1850 -42
+    w22_w0_w4 below_1851 -43
+    w22_w0_w4 below_1852 -47
+    w22_w0_w4 below_1853 -50
+    w22_w0_w4 below_1854 -51
+    w22_w0_w4 below_1855 -51
+    w22_w0_w4 below_1856 -52
+    w22_w0_w4 below_1857 -55
+    w22_w0_w4 below_1858 -56
+    w22_w0_w4 below_1859 -62
+    w22_w0_w4 below_1860 -59
+    w22_w0_w4 below_1861 -59
+    w22_w0_w4 below_1862 -66
+    w22_w0_w4 below_1863 -66
+    w22_w0_w4 below_1864 -72
+    w22_w0_w4 below_1865 -74
+    w22_w0_w4 below_1866 -74
+    w22_w0_w4 below_1867 -70
+    w22_w0_w4 below_1868 -75
+    w22_w0_w4 below_1869 -86
+    w22_w0_w4 below_1870 -84
+    w22_w0_w4 below_1871 -88
+    w22_w0_w4 below_1872 -85
+    w22_w0_w4 below_1873 -85
+    w22_w0_w4 below_1874 -82
+    w22_w0_w4 below_1875 -87
+    w22_w0_w4 below_1876 -90
+    w22_w0_w4 below_1877 -90
+    w22_w0_w4 below_1878 -96
+    w22_w0_w4 below_1879 -92
+    w22_w0_w4 below_1880 -96
+    w22_w0_w4 below_1881 -102
+    w22_w0_w4 below_1882 -98
+    w22_w0_w4 below_1883 -109
+    w22_w0_w4 below_1884 -98
+    w22_w0_w4 below_1885 -100
+    w22_w0_w4 below_1886 -99
+    w22_w0_w4 below_1887 -117
+    w22_w0_w4 below_1888 -99
+    w22_w0_w4 below_1889 -113
+    w22_w0_w4 below_1890 -115
+    w22_w0_w4 below_1891 -111
+    w22_w0_w4 below_1892 -116
+    w22_w0_w4 below_1893 -98
+    w22_w0_w4 below_1894 -105
+    w22_w0_w4 below_1895 -111
+    w22_w0_w4 below_1896 -115
+    w22_w0_w4 below_1897 -114
+    w22_w0_w4 below_1898 -111
+    w22_w0_w4 below_1899 -122
+    w22_w0_w4 below_1900 -108
+    w22_w0_w4 below_1901 -121
+    w22_w0_w4 below_1902 -107
+    w22_w0_w4 below_1903 -107
+    w22_w0_w4 below_1904 -122
+    w22_w0_w4 below_1905 -112
+    w22_w0_w4 below_1906 -119
+    w22_w0_w4 below_1907 -122
+    w22_w0_w4 below_1908 -120
+    w22_w0_w4 below_1909 -120
+    w22_w0_w4 below_1910 -120
+    w22_w0_w4 below_1911 -120
+    w22_w0_w4 below_1912 -122
+    w22_w0_w4 below_1913 -113
+    w22_w0_w4 below_1914 -117
+    w22_w0_w4 below_1915 -114
+    w22_w0_w4 below_1916 -115
+    w22_w0_w4 below_1917 -128
+    w22_w0_w4 below_1918 -125
+    w22_w0_w4 below_1919 -112
+    w22_w0_w4 below_1920 -123
+    w22_w0_w4 below_1921 -121
+    w22_w0_w4 below_1922 -123
+    w22_w0_w4 below_1923 -124
+    w22_w0_w4 below_1924 -118
+    w22_w0_w4 below_1925 -118
+    w22_w0_w4 below_1926 -112
+    w22_w0_w4 below_1927 -132
+    w22_w0_w4 below_1928 -119
+    w22_w0_w4 below_1929 -127
+    w22_w0_w4 below_1930 -127
+    w22_w0_w4 below_1931 -126
+    w22_w0_w4 below_1932 -124
+    w22_w0_w4 below_1933 -127
+    w22_w0_w4 below_1934 -132
+    w22_w0_w4 below_1935 -116
+    w22_w0_w4 below_1936 -124
+    w22_w0_w4 below_1937 -126
+    w22_w0_w4 below_1938 -111
+    w22_w0_w4 below_1939 -127
+    w22_w0_w4 below_1940 -124
+    w22_w0_w4 below_1941 -117
+    w22_w0_w4 below_1942 -125
+    w22_w0_w4 below_1943 -125
+    w22_w0_w4 below_1944 -128
+    w22_w0_w4 below_1945 -122
+    w22_w0_w4 below_1946 -134
+    w22_w0_w4 below_1947 -121
+    w22_w0_w4 below_1948 -115
+    w22_w0_w4 below_1949 -128
+    w22_w0_w4 below_1950 -130
+    w22_w0_w4 below_1951 -123
+    w22_w0_w4 below_1952 -121
+    w22_w0_w4 below_1953 -124
+    w22_w0_w4 below_1954 -132
+    w22_w0_w4 below_1955 -129
+    w22_w0_w4 below_1956 -118
+    w22_w0_w4 below_1957 -128
+    w22_w0_w4 below_1958 -123
+    w22_w0_w4 below_1959 -113
+    w22_w0_w4 below_1960 -123
+    w22_w0_w4 below_1961 -133
+    w22_w0_w4 below_1962 -124
+    w22_w0_w4 below_1963 -126
+    w22_w0_w4 below_1964 -114
+    w22_w0_w4 below_1965 -120
+    w22_w0_w4 below_1966 -119
+    w22_w0_w4 below_1967 -120
+    w22_w0_w4 below_1968 -126
+    w22_w0_w4 below_1969 -113
+    w22_w0_w4 below_1970 -124
+    w22_w0_w4 below_1971 -117
+    w22_w0_w4 below_1972 -118
+    w22_w0_w4 below_1973 -128
+    w22_w0_w4 below_1974 -125
+    w22_w0_w4 below_1975 -126
+    w22_w0_w4 below_1976 -128
+    w22_w0_w4 below_1977 -131
+    w22_w0_w4 below_1978 -130
+    w22_w0_w4 below_1979 -122
+    w22_w0_w4 below_1980 -118
+    w22_w0_w4 below_1981 -120
+    w22_w0_w4 below_1982 -138
+    w22_w0_w4 below_1983 -127
+    w22_w0_w4 below_1984 -133
+    w22_w0_w4 below_1985 -116
+    w22_w0_w4 below_1986 -117
+    w22_w0_w4 below_1987 -106
+    w22_w0_w4 below_1988 -128
+    w22_w0_w4 below_1989 -130
+    w22_w0_w4 below_1990 -129
+    w22_w0_w4 below_1991 -125
+    w22_w0_w4 below_1992 -126
+    w22_w0_w4 below_1993 -129
+    w22_w0_w4 below_1994 -129
+    w22_w0_w4 below_1995 -123
+    w22_w0_w4 below_1996 -128
+    w22_w0_w4 below_1997 -120
+    w22_w0_w4 below_1998 -114
+    w22_w0_w4 below_1999 -118
+    w22_w0_w4 below_2000 -126
+    w90_w0_w4 above_1801 1
+    w90_w0_w4 above_1803 1
+    w90_w0_w4 above_1804 1
+    w90_w0_w4 above_1805 1
+    w90_w0_w4 above_1806 1
+    w90_w0_w4 above_1807 1
+    w90_w0_w4 above_1808 1
+    w90_w0_w4 above_1809 1
+    w90_w0_w4 above_1810 1
+    w90_w0_w4 above_1811 1
+    w90_w0_w4 above_1812 1
+    w90_w0_w4 above_1813 1
+    w90_w0_w4 above_1814 1
+    w90_w0_w4 above_1815 1
+    w90_w0_w4 above_1816 1
+    w90_w0_w4 above_1817 1
+    w90_w0_w4 above_1818 1
+    w90_w0_w4 above_1819 1
+    w90_w0_w4 above_1820 1
+    w90_w0_w4 above_1821 1
+    w90_w0_w4 above_1822 2
+    w90_w0_w4 above_1823 2
+    w90_w0_w4 above_1824 2
+    w90_w0_w4 above_1825 2
+    w90_w0_w4 above_1826 2
+    w90_w0_w4 above_1827 2
+    w90_w0_w4 above_1828 2
+    w90_w0_w4 above_1829 2
+    w90_w0_w4 above_1830 3
+    w90_w0_w4 above_1831 3
+    w90_w0_w4 above_1832 3
+    w90_w0_w4 above_1833 3
+    w90_w0_w4 above_1834 3
+    w90_w0_w4 above_1835 3
+    w90_w0_w4 above_1836 4
+    w90_w0_w4 above_1837 4
+    w90_w0_w4 above_1838 4
+    w90_w0_w4 above_1839 4
+    w90_w0_w4 above_1840 4
+    w90_w0_w4 above_1841 5
+    w90_w0_w4 above_1842 5
+    w90_w0_w4 above_1843 5
+    w90_w0_w4 above_1844 6
+    w90_w0_w4 above_1845 6
+    w90_w0_w4 above_1846 6
+    w90_w0_w4 above_1847 7
+    w90_w0_w4 above_1848 7
+    w90_w0_w4 above_1849 7
+    w90_w0_w4 above_1850 7
+    w90_w0_w4 above_1851 8
+    w90_w0_w4 above_1852 8
+    w90_w0_w4 above_1853 9
+    w90_w0_w4 above_1854 8
+    w90_w0_w4 above_1855 11
+    w90_w0_w4 above_1856 11
+    w90_w0_w4 above_1857 11
+    w90_w0_w4 above_1858 12
+    w90_w0_w4 above_1859 13
+    w90_w0_w4 above_1860 13
+    w90_w0_w4 above_1861 14
+    w90_w0_w4 above_1862 15
+    w90_w0_w4 above_1863 15
+    w90_w0_w4 above_1864 16
+    w90_w0_w4 above_1865 18
+    w90_w0_w4 above_1866 18
+    w90_w0_w4 above_1867 18
+    w90_w0_w4 above_1868 20
+    w90_w0_w4 above_1869 21
+    w90_w0_w4 above_1870 21
+    w90_w0_w4 above_1871 23
+    w90_w0_w4 above_1872 24
+    w90_w0_w4 above_1873 27
+    w90_w0_w4 above_1874 28
+    w90_w0_w4 above_1875 29
+    w90_w0_w4 above_1876 29
+    w90_w0_w4 above_1877 31
+    w90_w0_w4 above_1878 31
+    w90_w0_w4 above_1879 34
+    w90_w0_w4 above_1880 38
+    w90_w0_w4 above_1881 39
+    w90_w0_w4 above_1882 40
+    w90_w0_w4 above_1883 42
+    w90_w0_w4 above_1884 36
+    w90_w0_w4 above_1885 45
+    w90_w0_w4 above_1886 44
+    w90_w0_w4 above_1887 48
+    w90_w0_w4 above_1888 53
+    w90_w0_w4 above_1889 54
+    w90_w0_w4 above_1890 54
+    w90_w0_w4 above_1891 51
+    w90_w0_w4 above_1892 54
+    w90_w0_w4 above_1893 57
+    w90_w0_w4 above_1894 65
+    w90_w0_w4 above_1895 66
+    w90_w0_w4 above_1896 66
+    w90_w0_w4 above_1897 70
+    w90_w0_w4 above_1898 71
+    w90_w0_w4 above_1899 72
+    w90_w0_w4 above_1900 77
+    w90_w0_w4 above_1901 76
+    w90_w0_w4 above_1902 76
+    w90_w0_w4 above_1903 85
+    w90_w0_w4 above_1904 77
+    w90_w0_w4 above_1905 88
+    w90_w0_w4 above_1906 89
+    w90_w0_w4 above_1907 101
+    w90_w0_w4 above_1908 91
+    w90_w0_w4 above_1909 95
+    w90_w0_w4 above_1910 99
+    w90_w0_w4 above_1911 102
+    w90_w0_w4 above_1912 96
+    w90_w0_w4 above_1913 105
+    w90_w0_w4 above_1914 104
+    w90_w0_w4 above_1915 101
+    w90_w0_w4 above_1916 109
+    w90_w0_w4 above_1917 109
+    w90_w0_w4 above_1918 126
+    w90_w0_w4 above_1919 128
+    w90_w0_w4 above_1920 123
+    w90_w0_w4 above_1921 115
+    w90_w0_w4 above_1922 120
+    w90_w0_w4 above_1923 115
+    w90_w0_w4 above_1924 116
+    w90_w0_w4 above_1925 126
+    w90_w0_w4 above_1926 125
+    w90_w0_w4 above_1927 124
+    w90_w0_w4 above_1928 123
+    w90_w0_w4 above_1929 125
+    w90_w0_w4 above_1930 126
+    w90_w0_w4 above_1931 126
+    w90_w0_w4 above_1932 140
+    w90_w0_w4 above_1933 132
+    w90_w0_w4 above_1934 145
+    w90_w0_w4 above_1935 143
+    w90_w0_w4 above_1936 138
+    w90_w0_w4 above_1937 136
+    w90_w0_w4 above_1938 147
+    w90_w0_w4 above_1939 136
+    w90_w0_w4 above_1940 144
+    w90_w0_w4 above_1941 149
+    w90_w0_w4 above_1942 138
+    w90_w0_w4 above_1943 146
+    w90_w0_w4 above_1944 149
+    w90_w0_w4 above_1945 151
+    w90_w0_w4 above_1946 148
+    w90_w0_w4 above_1947 143
+    w90_w0_w4 above_1948 143
+    w90_w0_w4 above_1949 151
+    w90_w0_w4 above_1950 142
+    w90_w0_w4 above_1951 133
+    w90_w0_w4 above_1952 134
+    w90_w0_w4 above_1953 150
+    w90_w0_w4 above_1954 151
+    w90_w0_w4 above_1955 142
+    w90_w0_w4 above_1956 152
+    w90_w0_w4 above_1957 145
+    w90_w0_w4 above_1958 153
+    w90_w0_w4 above_1959 157
+    w90_w0_w4 above_1960 156
+    w90_w0_w4 above_1961 155
+    w90_w0_w4 above_1962 157
+    w90_w0_w4 above_1963 167
+    w90_w0_w4 above_1964 150
+    w90_w0_w4 above_1965 167
+    w90_w0_w4 above_1966 155
+    w90_w0_w4 above_1967 153
+    w90_w0_w4 above_1968 149
+    w90_w0_w4 above_1969 162
+    w90_w0_w4 above_1970 159
+    w90_w0_w4 above_1971 147
+    w90_w0_w4 above_1972 152
+    w90_w0_w4 above_1973 160
+    w90_w0_w4 above_1974 160
+    w90_w0_w4 above_1975 147
+    w90_w0_w4 above_1976 157
+    w90_w0_w4 above_1977 161
+    w90_w0_w4 above_1978 153
+    w90_w0_w4 above_1979 153
+    w90_w0_w4 above_1980 149
+    w90_w0_w4 above_1981 146
+    w90_w0_w4 above_1982 155
+    w90_w0_w4 above_1983 157
+    w90_w0_w4 above_1984 139
+    w90_w0_w4 above_1985 149
+    w90_w0_w4 above_1986 160
+    w90_w0_w4 above_1987 159
+    w90_w0_w4 above_1988 161
+    w90_w0_w4 above_1989 155
+    w90_w0_w4 above_1990 151
+    w90_w0_w4 above_1991 158
+    w90_w0_w4 above_1992 165
+    w90_w0_w4 above_1993 156
+    w90_w0_w4 above_1994 163
+    w90_w0_w4 above_1995 146
+    w90_w0_w4 above_1996 156
+    w90_w0_w4 above_1997 153
+    w90_w0_w4 above_1998 155
+    w90_w0_w4 above_1999 158
+    w90_w0_w4 above_2000 150
+    w90_w0_w4 below_1801 -1
+    w90_w0_w4 below_1803 -1
+    w90_w0_w4 below_1804 -1
+    w90_w0_w4 below_1805 -1
+    w90_w0_w4 below_1806 -1
+    w90_w0_w4 below_1807 -1
+    w90_w0_w4 below_1808 -1
+    w90_w0_w4 below_1809 -1
+    w90_w0_w4 below_1810 -1
+    w90_w0_w4 below_1811 -1
+    w90_w0_w4 below_1812 -1
+    w90_w0_w4 below_1813 -1
+    w90_w0_w4 below_1814 -1
+    w90_w0_w4 below_1815 -1
+    w90_w0_w4 below_1816 -1
+    w90_w0_w4 below_1817 -1
+    w90_w0_w4 below_1818 -1
+    w90_w0_w4 below_1819 -1
+    w90_w0_w4 below_1820 -1
+    w90_w0_w4 below_1821 -1
+    w90_w0_w4 below_1822 -2
+    w90_w0_w4 below_1823 -2
+    w90_w0_w4 below_1824 -2
+    w90_w0_w4 below_1825 -2
+    w90_w0_w4 below_1826 -2
+    w90_w0_w4 below_1827 -2
+    w90_w0_w4 below_1828 -2
+    w90_w0_w4 below_1829 -2
+    w90_w0_w4 below_1830 -3
+    w90_w0_w4 below_1831 -3
+    w90_w0_w4 below_1832 -3
+    w90_w0_w4 below_1833 -3
+    w90_w0_w4 below_1834 -3
+    w90_w0_w4 below_1835 -3
+    w90_w0_w4 below_1836 -4
+    w90_w0_w4 below_1837 -4
+    w90_w0_w4 below_1838 -4
+    w90_w0_w4 below_1839 -4
+    w90_w0_w4 below_1840 -4
+    w90_w0_w4 below_1841 -5
+    w90_w0_w4 below_1842 -5
+    w90_w0_w4 below_1843 -5
+    w90_w0_w4 below_1844 -6
+    w90_w0_w4 below_1845 -6
+    w90_w0_w4 below_1846 -6
+    w90_w0_w4 below_1847 -7
+    w90_w0_w4 below_1848 -7
+    w90_w0_w4 below_1849 -7
+    w90_w0_w4 below_1850 -7
+    w90_w0_w4 below_1851 -8
+    w90_w0_w4 below_1852 -8
+    w90_w0_w4 below_1853 -9
+    w90_w0_w4 below_1854 -8
+    w90_w0_w4 below_1855 -11
+    w90_w0_w4 below_1856 -11
+    w90_w0_w4 below_1857 -11
+    w90_w0_w4 below_1858 -12
+    w90_w0_w4 below_1859 -13
+    w90_w0_w4 below_1860 -13
+    w90_w0_w4 below_1861 -14
+    w90_w0_w4 below_1862 -15
+    w90_w0_w4 below_1863 -15
+    w90_w0_w4 below_1864 -16
+    w90_w0_w4 below_1865 -18
+    w90_w0_w4 below_1866 -18
+    w90_w0_w4 below_1867 -18
+    w90_w0_w4 below_1868 -20
+    w90_w0_w4 below_1869 -21
+    w90_w0_w4 below_1870 -21
+    w90_w0_w4 below_1871 -23
+    w90_w0_w4 below_1872 -24
+    w90_w0_w4 below_1873 -27
+    w90_w0_w4 below_1874 -28
+    w90_w0_w4 below_1875 -29
+    w90_w0_w4 below_1876 -29
+    w90_w0_w4 below_1877 -31
+    w90_w0_w4 below_1878 -31
+    w90_w0_w4 below_1879 -34
+    w90_w0_w4 below_1880 -38
+    w90_w0_w4 below_1881 -39
+    w90_w0_w4 below_1882 -40
+    w90_w0_w4 below_1883 -42
+    w90_w0_w4 below_1884 -36
+    w90_w0_w4 below_1885 -45
+    w90_w0_w4 below_1886 -44
+    w90_w0_w4 below_1887 -48
+    w90_w0_w4 below_1888 -53
+    w90_w0_w4 below_1889 -54
+    w90_w0_w4 below_1890 -54
+    w90_w0_w4 below_1891 -51
+    w90_w0_w4 below_1892 -54
+    w90_w0_w4 below_1893 -57
+    w90_w0_w4 below_1894 -65
+    w90_w0_w4 below_1895 -66
+    w90_w0_w4 below_1896 -66
+    w90_w0_w4 below_1897 -70
+    w90_w0_w4 below_1898 -71
+    w90_w0_w4 below_1899 -72
+    w90_w0_w4 below_1900 -77
+    w90_w0_w4 below_1901 -76
+    w90_w0_w4 below_1902 -76
+    w90_w0_w4 below_1903 -85
+    w90_w0_w4 below_1904 -77
+    w90_w0_w4 below_1905 -88
+    w90_w0_w4 below_1906 -89
+    w90_w0_w4 below_1907 -101
+    w90_w0_w4 below_1908 -91
+    w90_w0_w4 below_1909 -95
+    w90_w0_w4 below_1910 -99
+    w90_w0_w4 below_1911 -102
+    w90_w0_w4 below_1912 -96
+    w90_w0_w4 below_1913 -105
+    w90_w0_w4 below_1914 -104
+    w90_w0_w4 below_1915 -101
+    w90_w0_w4 below_1916 -109
+    w90_w0_w4 below_1917 -109
+    w90_w0_w4 below_1918 -126
+    w90_w0_w4 below_1919 -128
+    w90_w0_w4 below_1920 -123
+    w90_w0_w4 below_1921 -115
+    w90_w0_w4 below_1922 -120
+    w90_w0_w4 below_1923 -115
+    w90_w0_w4 below_1924 -116
+    w90_w0_w4 below_1925 -126
+    w90_w0_w4 below_1926 -125
+    w90_w0_w4 below_1927 -124
+    w90_w0_w4 below_1928 -123
+    w90_w0_w4 below_1929 -125
+    w90_w0_w4 below_1930 -126
+    w90_w0_w4 below_1931 -126
+    w90_w0_w4 below_1932 -140
+    w90_w0_w4 below_1933 -132
+    w90_w0_w4 below_1934 -145
+    w90_w0_w4 below_1935 -143
+    w90_w0_w4 below_1936 -138
+    w90_w0_w4 below_1937 -136
+    w90_w0_w4 below_1938 -147
+    w90_w0_w4 below_1939 -136
+    w90_w0_w4 below_1940 -144
+    w90_w0_w4 below_1941 -149
+    w90_w0_w4 below_1942 -138
+    w90_w0_w4 below_1943 -146
+    w90_w0_w4 below_1944 -149
+    w90_w0_w4 below_1945 -151
+    w90_w0_w4 below_1946 -148
+    w90_w0_w4 below_1947 -143
+    w90_w0_w4 below_1948 -143
+    w90_w0_w4 below_1949 -151
+    w90_w0_w4 below_1950 -142
+    w90_w0_w4 below_1951 -133
+    w90_w0_w4 below_1952 -134
+    w90_w0_w4 below_1953 -150
+    w90_w0_w4 below_1954 -151
+    w90_w0_w4 below_1955 -142
+    w90_w0_w4 below_1956 -152
+    w90_w0_w4 below_1957 -145
+    w90_w0_w4 below_1958 -153
+    w90_w0_w4 below_1959 -157
+    w90_w0_w4 below_1960 -156
+    w90_w0_w4 below_1961 -155
+    w90_w0_w4 below_1962 -157
+    w90_w0_w4 below_1963 -167
+    w90_w0_w4 below_1964 -150
+    w90_w0_w4 below_1965 -167
+    w90_w0_w4 below_1966 -155
+    w90_w0_w4 below_1967 -153
+    w90_w0_w4 below_1968 -149
+    w90_w0_w4 below_1969 -162
+    w90_w0_w4 below_1970 -159
+    w90_w0_w4 below_1971 -147
+    w90_w0_w4 below_1972 -152
+    w90_w0_w4 below_1973 -160
+    w90_w0_w4 below_1974 -160
+    w90_w0_w4 below_1975 -147
+    w90_w0_w4 below_1976 -157
+    w90_w0_w4 below_1977 -161
+    w90_w0_w4 below_1978 -153
+    w90_w0_w4 below_1979 -153
+    w90_w0_w4 below_1980 -149
+    w90_w0_w4 below_1981 -146
+    w90_w0_w4 below_1982 -155
+    w90_w0_w4 below_1983 -157
+    w90_w0_w4 below_1984 -139
+    w90_w0_w4 below_1985 -149
+    w90_w0_w4 below_1986 -160
+    w90_w0_w4 below_1987 -159
+    w90_w0_w4 below_1988 -161
+    w90_w0_w4 below_1989 -155
+    w90_w0_w4 below_1990 -151
+    w90_w0_w4 below_1991 -158
+    w90_w0_w4 below_1992 -165
+    w90_w0_w4 below_1993 -156
+    w90_w0_w4 below_1994 -163
+    w90_w0_w4 below_1995 -146
+    w90_w0_w4 below_1996 -156
+    w90_w0_w4 below_1997 -153
+    w90_w0_w4 below_1998 -155
+    w90_w0_w4 below_1999 -158
+    w90_w0_w4 below_2000 -150
+    w4_w0_w4 above_1800 3
+    w4_w0_w4 above_1801 3
+    w4_w0_w4 above_1802 3
+    w4_w0_w4 above_1803 3
+    w4_w0_w4 above_1804 3
+    w4_w0_w4 above_1805 3
+    w4_w0_w4 above_1806 4
+    w4_w0_w4 above_1807 4
+    w4_w0_w4 above_1808 4
+    w4_w0_w4 above_1809 4
+    w4_w0_w4 above_1810 4
+    w4_w0_w4 above_1811 4
+    w4_w0_w4 above_1812 5
+    w4_w0_w4 above_1813 5
+    w4_w0_w4 above_1814 5
+    w4_w0_w4 above_1815 5
+    w4_w0_w4 above_1816 5
+    w4_w0_w4 above_1817 5
+    w4_w0_w4 above_1818 6
+    w4_w0_w4 above_1819 6
+    w4_w0_w4 above_1820 7
+    w4_w0_w4 above_1821 7
+    w4_w0_w4 above_1822 7
+    w4_w0_w4 above_1823 7
+    w4_w0_w4 above_1824 8
+    w4_w0_w4 above_1825 8
+    w4_w0_w4 above_1826 9
+    w4_w0_w4 above_1827 9
+    w4_w0_w4 above_1828 9
+    w4_w0_w4 above_1829 10
+    w4_w0_w4 above_1830 10
+    w4_w0_w4 above_1831 11
+    w4_w0_w4 above_1832 11
+    w4_w0_w4 above_1833 12
+    w4_w0_w4 above_1834 12
+    w4_w0_w4 above_1835 12
+    w4_w0_w4 above_1836 13
+    w4_w0_w4 above_1837 14
+    w4_w0_w4 above_1838 13
+    w4_w0_w4 above_1839 16
+    w4_w0_w4 above_1840 15
+    w4_w0_w4 above_1841 17
+    w4_w0_w4 above_1842 16
+    w4_w0_w4 above_1843 15
+    w4_w0_w4 above_1844 19
+    w4_w0_w4 above_1845 20
+    w4_w0_w4 above_1846 20
+    w4_w0_w4 above_1847 21
+    w4_w0_w4 above_1848 23
+    w4_w0_w4 above_1849 22
+    w4_w0_w4 above_1850 23
+    w4_w0_w4 above_1851 25
+    w4_w0_w4 above_1852 26
+    w4_w0_w4 above_1853 25
+    w4_w0_w4 above_1854 29
+    w4_w0_w4 above_1855 29
+    w4_w0_w4 above_1856 31
+    w4_w0_w4 above_1857 32
+    w4_w0_w4 above_1858 33
+    w4_w0_w4 above_1859 31
+    w4_w0_w4 above_1860 32
+    w4_w0_w4 above_1861 36
+    w4_w0_w4 above_1862 38
+    w4_w0_w4 above_1863 38
+    w4_w0_w4 above_1864 37
+    w4_w0_w4 above_1865 39
+    w4_w0_w4 above_1866 41
+    w4_w0_w4 above_1867 47
+    w4_w0_w4 above_1868 51
+    w4_w0_w4 above_1869 46
+    w4_w0_w4 above_1870 50
+    w4_w0_w4 above_1871 50
+    w4_w0_w4 above_1872 50
+    w4_w0_w4 above_1873 53
+    w4_w0_w4 above_1874 57
+    w4_w0_w4 above_1875 58
+    w4_w0_w4 above_1876 63
+    w4_w0_w4 above_1877 60
+    w4_w0_w4 above_1878 61
+    w4_w0_w4 above_1879 66
+    w4_w0_w4 above_1880 64
+    w4_w0_w4 above_1881 65
+    w4_w0_w4 above_1882 67
+    w4_w0_w4 above_1883 73
+    w4_w0_w4 above_1884 72
+    w4_w0_w4 above_1885 66
+    w4_w0_w4 above_1886 76
+    w4_w0_w4 above_1887 73
+    w4_w0_w4 above_1888 71
+    w4_w0_w4 above_1889 78
+    w4_w0_w4 above_1890 75
+    w4_w0_w4 above_1891 79
+    w4_w0_w4 above_1892 77
+    w4_w0_w4 above_1893 71
+    w4_w0_w4 above_1894 81
+    w4_w0_w4 above_1895 82
+    w4_w0_w4 above_1896 83
+    w4_w0_w4 above_1897 80
+    w4_w0_w4 above_1898 91
+    w4_w0_w4 above_1899 92
+    w4_w0_w4 above_1900 87
+    w4_w0_w4 above_1901 91
+    w4_w0_w4 above_1902 85
+    w4_w0_w4 above_1903 89
+    w4_w0_w4 above_1904 97
+    w4_w0_w4 above_1905 101
+    w4_w0_w4 above_1906 94
+    w4_w0_w4 above_1907 99
+    w4_w0_w4 above_1908 92
+    w4_w0_w4 above_1909 93
+    w4_w0_w4 above_1910 89
+    w4_w0_w4 above_1911 92
+    w4_w0_w4 above_1912 99
+    w4_w0_w4 above_1913 97
+    w4_w0_w4 above_1914 102
+    w4_w0_w4 above_1915 100
+    w4_w0_w4 above_1916 105
+    w4_w0_w4 above_1917 101
+    w4_w0_w4 above_1918 97
+    w4_w0_w4 above_1919 95
+    w4_w0_w4 above_1920 94
+    w4_w0_w4 above_1921 107
+    w4_w0_w4 above_1922 98
+    w4_w0_w4 above_1923 96
+    w4_w0_w4 above_1924 99
+    w4_w0_w4 above_1925 112
+    w4_w0_w4 above_1926 105
+    w4_w0_w4 above_1927 97
+    w4_w0_w4 above_1928 100
+    w4_w0_w4 above_1929 100
+    w4_w0_w4 above_1930 93
+    w4_w0_w4 above_1931 105
+    w4_w0_w4 above_1932 93
+    w4_w0_w4 above_1933 96
+    w4_w0_w4 above_1934 91
+    w4_w0_w4 above_1935 95
+    w4_w0_w4 above_1936 84
+    w4_w0_w4 above_1937 101
+    w4_w0_w4 above_1938 96
+    w4_w0_w4 above_1939 99
+    w4_w0_w4 above_1940 94
+    w4_w0_w4 above_1941 97
+    w4_w0_w4 above_1942 105
+    w4_w0_w4 above_1943 96
+    w4_w0_w4 above_1944 99
+    w4_w0_w4 above_1945 93
+    w4_w0_w4 above_1946 95
+    w4_w0_w4 above_1947 97
+    w4_w0_w4 above_1948 105
+    w4_w0_w4 above_1949 102
+    w4_w0_w4 above_1950 96
+    w4_w0_w4 above_1951 102
+    w4_w0_w4 above_1952 92
+    w4_w0_w4 above_1953 96
+    w4_w0_w4 above_1954 89
+    w4_w0_w4 above_1955 103
+    w4_w0_w4 above_1956 85
+    w4_w0_w4 above_1957 95
+    w4_w0_w4 above_1958 86
+    w4_w0_w4 above_1959 101
+    w4_w0_w4 above_1960 105
+    w4_w0_w4 above_1961 98
+    w4_w0_w4 above_1962 98
+    w4_w0_w4 above_1963 97
+    w4_w0_w4 above_1964 99
+    w4_w0_w4 above_1965 94
+    w4_w0_w4 above_1966 95
+    w4_w0_w4 above_1967 89
+    w4_w0_w4 above_1968 96
+    w4_w0_w4 above_1969 105
+    w4_w0_w4 above_1970 97
+    w4_w0_w4 above_1971 82
+    w4_w0_w4 above_1972 93
+    w4_w0_w4 above_1973 90
+    w4_w0_w4 above_1974 102
+    w4_w0_w4 above_1975 93
+    w4_w0_w4 above_1976 100
+    w4_w0_w4 above_1977 97
+    w4_w0_w4 above_1978 86
+    w4_w0_w4 above_1979 96
+    w4_w0_w4 above_1980 98
+    w4_w0_w4 above_1981 92
+    w4_w0_w4 above_1982 96
+    w4_w0_w4 above_1983 95
+    w4_w0_w4 above_1984 94
+    w4_w0_w4 above_1985 96
+    w4_w0_w4 above_1986 101
+    w4_w0_w4 above_1987 95
+    w4_w0_w4 above_1988 91
+    w4_w0_w4 above_1989 92
+    w4_w0_w4 above_1990 99
+    w4_w0_w4 above_1991 93
+    w4_w0_w4 above_1992 93
+    w4_w0_w4 above_1993 88
+    w4_w0_w4 above_1994 98
+    w4_w0_w4 above_1995 91
+    w4_w0_w4 above_1996 100
+    w4_w0_w4 above_1997 98
+    w4_w0_w4 above_1998 88
+    w4_w0_w4 above_1999 99
+    w4_w0_w4 above_2000 85
+    w4_w0_w4 below_1800 -3
+    w4_w0_w4 below_1801 -3
+    w4_w0_w4 below_1802 -3
+    w4_w0_w4 below_1803 -3
+    w4_w0_w4 below_1804 -3
+    w4_w0_w4 below_1805 -3
+    w4_w0_w4 below_1806 -4
+    w4_w0_w4 below_1807 -4
+    w4_w0_w4 below_1808 -4
+    w4_w0_w4 below_1809 -4
+    w4_w0_w4 below_1810 -4
+    w4_w0_w4 below_1811 -4
+    w4_w0_w4 below_1812 -5
+    w4_w0_w4 below_1813 -5
+    w4_w0_w4 below_1814 -5
+    w4_w0_w4 below_1815 -5
+    w4_w0_w4 below_1816 -5
+    w4_w0_w4 below_1817 -5
+    w4_w0_w4 below_1818 -6
+    w4_w0_w4 below_1819 -6
+    w4_w0_w4 below_1820 -7
+    w4_w0_w4 below_1821 -7
+    w4_w0_w4 below_1822 -7
+    w4_w0_w4 below_1823 -7
+    w4_w0_w4 below_1824 -8
+    w4_w0_w4 below_1825 -8
+    w4_w0_w4 below_1826 -9
+    w4_w0_w4 below_1827 -9
+    w4_w0_w4 below_1828 -9
+    w4_w0_w4 below_1829 -10
+    w4_w0_w4 below_1830 -10
+    w4_w0_w4 below_1831 -11
+    w4_w0_w4 below_1832 -11
+    w4_w0_w4 below_1833 -12
+    w4_w0_w4 below_1834 -12
+    w4_w0_w4 below_1835 -12
+    w4_w0_w4 below_1836 -13
+    w4_w0_w4 below_1837 -14
+    w4_w0_w4 below_1838 -13
+    w4_w0_w4 below_1839 -16
+    w4_w0_w4 below_1840 -15
+    w4_w0_w4 below_1841 -17
+    w4_w0_w4 below_1842 -16
+    w4_w0_w4 below_1843 -15
+    w4_w0_w4 below_1844 -19
+    w4_w0_w4 below_1845 -20
+    w4_w0_w4 below_1846 -20
+    w4_w0_w4 below_1847 -21
+    w4_w0_w4 below_1848 -23
+    w4_w0_w4 below_1849 -22
+    w4_w0_w4 below_1850 -23
+    w4_w0_w4 below_1851 -25
+    w4_w0_w4 below_1852 -26
+    w4_w0_w4 below_1853 -25
+    w4_w0_w4 below_1854 -29
+    w4_w0_w4 below_1855 -29
+    w4_w0_w4 below_1856 -31
+    w4_w0_w4 below_1857 -32
+    w4_w0_w4 below_1858 -33
+    w4_w0_w4 below_1859 -31
+    w4_w0_w4 below_1860 -32
+    w4_w0_w4 below_1861 -36
+    w4_w0_w4 below_1862 -38
+    w4_w0_w4 below_1863 -38
+    w4_w0_w4 below_1864 -37
+    w4_w0_w4 below_1865 -39
+    w4_w0_w4 below_1866 -41
+    w4_w0_w4 below_1867 -47
+    w4_w0_w4 below_1868 -51
+    w4_w0_w4 below_1869 -46
+    w4_w0_w4 below_1870 -50
+    w4_w0_w4 below_1871 -50
+    w4_w0_w4 below_1872 -50
+    w4_w0_w4 below_1873 -53
+    w4_w0_w4 below_1874 -57
+    w4_w0_w4 below_1875 -58
+    w4_w0_w4 below_1876 -63
+    w4_w0_w4 below_1877 -60
+    w4_w0_w4 below_1878 -61
+    w4_w0_w4 below_1879 -66
+    w4_w0_w4 below_1880 -64
+    w4_w0_w4 below_1881 -65
+    w4_w0_w4 below_1882 -67
+    w4_w0_w4 below_1883 -73
+    w4_w0_w4 below_1884 -72
+    w4_w0_w4 below_1885 -66
+    w4_w0_w4 below_1886 -76
+    w4_w0_w4 below_1887 -73
+    w4_w0_w4 below_1888 -71
+    w4_w0_w4 below_1889 -78
+    w4_w0_w4 below_1890 -75
+    w4_w0_w4 below_1891 -79
+    w4_w0_w4 below_1892 -77
+    w4_w0_w4 below_1893 -71
+    w4_w0_w4 below_1894 -81
+    w4_w0_w4 below_1895 -82
+    w4_w0_w4 below_1896 -83
+    w4_w0_w4 below_1897 -80
+    w4_w0_w4 below_1898 -91
+    w4_w0_w4 below_1899 -92
+    w4_w0_w4 below_1900 -87
+    w4_w0_w4 below_1901 -91
+    w4_w0_w4 below_1902 -85
+    w4_w0_w4 below_1903 -89
+    w4_w0_w4 below_1904 -97
+    w4_w0_w4 below_1905 -101
+    w4_w0_w4 below_1906 -94
+    w4_w0_w4 below_1907 -99
+    w4_w0_w4 below_1908 -92
+    w4_w0_w4 below_1909 -93
+    w4_w0_w4 below_1910 -89
+    w4_w0_w4 below_1911 -92
+    w4_w0_w4 below_1912 -99
+    w4_w0_w4 below_1913 -97
+    w4_w0_w4 below_1914 -102
+    w4_w0_w4 below_1915 -100
+    w4_w0_w4 below_1916 -105
+    w4_w0_w4 below_1917 -101
+    w4_w0_w4 below_1918 -97
+    w4_w0_w4 below_1919 -95
+    w4_w0_w4 below_1920 -94
+    w4_w0_w4 below_1921 -107
+    w4_w0_w4 below_1922 -98
+    w4_w0_w4 below_1923 -96
+    w4_w0_w4 below_1924 -99
+    w4_w0_w4 below_1925 -112
+    w4_w0_w4 below_1926 -105
+    w4_w0_w4 below_1927 -97
+    w4_w0_w4 below_1928 -100
+    w4_w0_w4 below_1929 -100
+    w4_w0_w4 below_1930 -93
+    w4_w0_w4 below_1931 -105
+    w4_w0_w4 below_1932 -93
+    w4_w0_w4 below_1933 -96
+    w4_w0_w4 below_1934 -91
+    w4_w0_w4 below_1935 -95
+    w4_w0_w4 below_1936 -84
+    w4_w0_w4 below_1937 -101
+    w4_w0_w4 below_1938 -96
+    w4_w0_w4 below_1939 -99
+    w4_w0_w4 below_1940 -94
+    w4_w0_w4 below_1941 -97
+    w4_w0_w4 below_1942 -105
+    w4_w0_w4 below_1943 -96
+    w4_w0_w4 below_1944 -99
+    w4_w0_w4 below_1945 -93
+    w4_w0_w4 below_1946 -95
+    w4_w0_w4 below_1947 -97
+    w4_w0_w4 below_1948 -105
+    w4_w0_w4 below_1949 -102
+    w4_w0_w4 below_1950 -96
+    w4_w0_w4 below_1951 -102
+    w4_w0_w4 below_1952 -92
+    w4_w0_w4 below_1953 -96
+    w4_w0_w4 below_1954 -89
+    w4_w0_w4 below_1955 -103
+    w4_w0_w4 below_1956 -85
+    w4_w0_w4 below_1957 -95
+    w4_w0_w4 below_1958 -86
+    w4_w0_w4 below_1959 -101
+    w4_w0_w4 below_1960 -105
+    w4_w0_w4 below_1961 -98
+    w4_w0_w4 below_1962 -98
+    w4_w0_w4 below_1963 -97
+    w4_w0_w4 below_1964 -99
+    w4_w0_w4 below_1965 -94
+    w4_w0_w4 below_1966 -95
+    w4_w0_w4 below_1967 -89
+    w4_w0_w4 below_1968 -96
+    w4_w0_w4 below_1969 -105
+    w4_w0_w4 below_1970 -97
+    w4_w0_w4 below_1971 -82
+    w4_w0_w4 below_1972 -93
+    w4_w0_w4 below_1973 -90
+    w4_w0_w4 below_1974 -102
+    w4_w0_w4 below_1975 -93
+    w4_w0_w4 below_1976 -100
+    w4_w0_w4 below_1977 -97
+    w4_w0_w4 below_1978 -86
+    w4_w0_w4 below_1979 -96
+    w4_w0_w4 below_1980 -98
+    w4_w0_w4 below_1981 -92
+    w4_w0_w4 below_1982 -96
+    w4_w0_w4 below_1983 -95
+    w4_w0_w4 below_1984 -94
+    w4_w0_w4 below_1985 -96
+    w4_w0_w4 below_1986 -101
+    w4_w0_w4 below_1987 -95
+    w4_w0_w4 below_1988 -91
+    w4_w0_w4 below_1989 -92
+    w4_w0_w4 below_1990 -99
+    w4_w0_w4 below_1991 -93
+    w4_w0_w4 below_1992 -93
+    w4_w0_w4 below_1993 -88
+    w4_w0_w4 below_1994 -98
+    w4_w0_w4 below_1995 -91
+    w4_w0_w4 below_1996 -100
+    w4_w0_w4 below_1997 -98
+    w4_w0_w4 below_1998 -88
+    w4_w0_w4 below_1999 -99
+    w4_w0_w4 below_2000 -85
+    w190_w0_w4 above_1898 1
+    w190_w0_w4 above_1899 1
+    w190_w0_w4 above_1900 1
+    w190_w0_w4 above_1901 1
+    w190_w0_w4 above_1902 1
+    w190_w0_w4 above_1903 1
+    w190_w0_w4 above_1904 1
+    w190_w0_w4 above_1905 2
+    w190_w0_w4 above_1906 2
+    w190_w0_w4 above_1907 2
+    w190_w0_w4 above_1908 2
+    w190_w0_w4 above_1909 3
+    w190_w0_w4 above_1910 4
+    w190_w0_w4 above_1911 4
+    w190_w0_w4 above_1912 5
+    w190_w0_w4 above_1913 5
+    w190_w0_w4 above_1914 7
+    w190_w0_w4 above_1915 7
+    w190_w0_w4 above_1916 9
+    w190_w0_w4 above_1917 11
+    w190_w0_w4 above_1918 12
+    w190_w0_w4 above_1919 14
+    w190_w0_w4 above_1920 17
+    w190_w0_w4 above_1921 18
+    w190_w0_w4 above_1922 21
+    w190_w0_w4 above_1923 27
+    w190_w0_w4 above_1924 28
+    w190_w0_w4 above_1925 30
+    w190_w0_w4 above_1926 36
+    w190_w0_w4 above_1927 42
+    w190_w0_w4 above_1928 41
+    w190_w0_w4 above_1929 52
+    w190_w0_w4 above_1930 64
+    w190_w0_w4 above_1931 68
+    w190_w0_w4 above_1932 74
+    w190_w0_w4 above_1933 75
+    w190_w0_w4 above_1934 94
+    w190_w0_w4 above_1935 91
+    w190_w0_w4 above_1936 101
+    w190_w0_w4 above_1937 115
+    w190_w0_w4 above_1938 124
+    w190_w0_w4 above_1939 120
+    w190_w0_w4 above_1940 127
+    w190_w0_w4 above_1941 120
+    w190_w0_w4 above_1942 134
+    w190_w0_w4 above_1943 143
+    w190_w0_w4 above_1944 146
+    w190_w0_w4 above_1945 157
+    w190_w0_w4 above_1946 144
+    w190_w0_w4 above_1947 144
+    w190_w0_w4 above_1948 158
+    w190_w0_w4 above_1949 167
+    w190_w0_w4 above_1950 156
+    w190_w0_w4 above_1951 159
+    w190_w0_w4 above_1952 160
+    w190_w0_w4 above_1953 154
+    w190_w0_w4 above_1954 166
+    w190_w0_w4 above_1955 173
+    w190_w0_w4 above_1956 177
+    w190_w0_w4 above_1957 179
+    w190_w0_w4 above_1958 174
+    w190_w0_w4 above_1959 174
+    w190_w0_w4 above_1960 169
+    w190_w0_w4 above_1961 183
+    w190_w0_w4 above_1962 171
+    w190_w0_w4 above_1963 174
+    w190_w0_w4 above_1964 175
+    w190_w0_w4 above_1965 173
+    w190_w0_w4 above_1966 175
+    w190_w0_w4 above_1967 170
+    w190_w0_w4 above_1968 175
+    w190_w0_w4 above_1969 170
+    w190_w0_w4 above_1970 174
+    w190_w0_w4 above_1971 170
+    w190_w0_w4 above_1972 172
+    w190_w0_w4 above_1973 166
+    w190_w0_w4 above_1974 181
+    w190_w0_w4 above_1975 180
+    w190_w0_w4 above_1976 169
+    w190_w0_w4 above_1977 164
+    w190_w0_w4 above_1978 169
+    w190_w0_w4 above_1979 163
+    w190_w0_w4 above_1980 156
+    w190_w0_w4 above_1981 165
+    w190_w0_w4 above_1982 168
+    w190_w0_w4 above_1983 177
+    w190_w0_w4 above_1984 168
+    w190_w0_w4 above_1985 172
+    w190_w0_w4 above_1986 182
+    w190_w0_w4 above_1987 184
+    w190_w0_w4 above_1988 180
+    w190_w0_w4 above_1989 176
+    w190_w0_w4 above_1990 175
+    w190_w0_w4 above_1991 161
+    w190_w0_w4 above_1992 180
+    w190_w0_w4 above_1993 175
+    w190_w0_w4 above_1994 177
+    w190_w0_w4 above_1995 178
+    w190_w0_w4 above_1996 173
+    w190_w0_w4 above_1997 174
+    w190_w0_w4 above_1998 176
+    w190_w0_w4 above_1999 163
+    w190_w0_w4 above_2000 170
+    w190_w0_w4 below_1898 -1
+    w190_w0_w4 below_1899 -1
+    w190_w0_w4 below_1900 -1
+    w190_w0_w4 below_1901 -1
+    w190_w0_w4 below_1902 -1
+    w190_w0_w4 below_1903 -1
+    w190_w0_w4 below_1904 -1
+    w190_w0_w4 below_1905 -2
+    w190_w0_w4 below_1906 -2
+    w190_w0_w4 below_1907 -2
+    w190_w0_w4 below_1908 -2
+    w190_w0_w4 below_1909 -3
+    w190_w0_w4 below_1910 -4
+    w190_w0_w4 below_1911 -4
+    w190_w0_w4 below_1912 -5
+    w190_w0_w4 below_1913 -5
+    w190_w0_w4 below_1914 -7
+    w190_w0_w4 below_1915 -7
+    w190_w0_w4 below_1916 -9
+    w190_w0_w4 below_1917 -11
+    w190_w0_w4 below_1918 -12
+    w190_w0_w4 below_1919 -14
+    w190_w0_w4 below_1920 -17
+    w190_w0_w4 below_1921 -18
+    w190_w0_w4 below_1922 -21
+    w190_w0_w4 below_1923 -27
+    w190_w0_w4 below_1924 -28
+    w190_w0_w4 below_1925 -30
+    w190_w0_w4 below_1926 -36
+    w190_w0_w4 below_1927 -42
+    w190_w0_w4 below_1928 -41
+    w190_w0_w4 below_1929 -52
+    w190_w0_w4 below_1930 -64
+    w190_w0_w4 below_1931 -68
+    w190_w0_w4 below_1932 -74
+    w190_w0_w4 below_1933 -75
+    w190_w0_w4 below_1934 -94
+    w190_w0_w4 below_1935 -91
+    w190_w0_w4 below_1936 -101
+    w190_w0_w4 below_1937 -115
+    w190_w0_w4 below_1938 -124
+    w190_w0_w4 below_1939 -120
+    w190_w0_w4 below_1940 -127
+    w190_w0_w4 below_1941 -120
+    w190_w0_w4 below_1942 -134
+    w190_w0_w4 below_1943 -143
+    w190_w0_w4 below_1944 -146
+    w190_w0_w4 below_1945 -157
+    w190_w0_w4 below_1946 -144
+    w190_w0_w4 below_1947 -144
+    w190_w0_w4 below_1948 -158
+    w190_w0_w4 below_1949 -167
+    w190_w0_w4 below_1950 -156
+    w190_w0_w4 below_1951 -159
+    w190_w0_w4 below_1952 -160
+    w190_w0_w4 below_1953 -154
+    w190_w0_w4 below_1954 -166
+    w190_w0_w4 below_1955 -173
+    w190_w0_w4 below_1956 -177
+    w190_w0_w4 below_1957 -179
+    w190_w0_w4 below_1958 -174
+    w190_w0_w4 below_1959 -174
+    w190_w0_w4 below_1960 -169
+    w190_w0_w4 below_1961 -183
+    w190_w0_w4 below_1962 -171
+    w190_w0_w4 below_1963 -174
+    w190_w0_w4 below_1964 -175
+    w190_w0_w4 below_1965 -173
+    w190_w0_w4 below_1966 -175
+    w190_w0_w4 below_1967 -170
+    w190_w0_w4 below_1968 -175
+    w190_w0_w4 below_1969 -170
+    w190_w0_w4 below_1970 -174
+    w190_w0_w4 below_1971 -170
+    w190_w0_w4 below_1972 -172
+    w190_w0_w4 below_1973 -166
+    w190_w0_w4 below_1974 -181
+    w190_w0_w4 below_1975 -180
+    w190_w0_w4 below_1976 -169
+    w190_w0_w4 below_1977 -164
+    w190_w0_w4 below_1978 -169
+    w190_w0_w4 below_1979 -163
+    w190_w0_w4 below_1980 -156
+    w190_w0_w4 below_1981 -165
+    w190_w0_w4 below_1982 -168
+    w190_w0_w4 below_1983 -177
+    w190_w0_w4 below_1984 -168
+    w190_w0_w4 below_1985 -172
+    w190_w0_w4 below_1986 -182
+    w190_w0_w4 below_1987 -184
+    w190_w0_w4 below_1988 -180
+    w190_w0_w4 below_1989 -176
+    w190_w0_w4 below_1990 -175
+    w190_w0_w4 below_1991 -161
+    w190_w0_w4 below_1992 -180
+    w190_w0_w4 below_1993 -175
+    w190_w0_w4 below_1994 -177
+    w190_w0_w4 below_1995 -178
+    w190_w0_w4 below_1996 -173
+    w190_w0_w4 below_1997 -174
+    w190_w0_w4 below_1998 -176
+    w190_w0_w4 below_1999 -163
+    w190_w0_w4 below_2000 -170
+    w2_w0_w4 above_1800 31
+    w2_w0_w4 above_1801 29
+    w2_w0_w4 above_1802 32
+    w2_w0_w4 above_1803 31
+    w2_w0_w4 above_1804 31
+    w2_w0_w4 above_1805 31
+    w2_w0_w4 above_1806 34
+    w2_w0_w4 above_1807 35
+    w2_w0_w4 above_1808 38
+    w2_w0_w4 above_1809 38
+    w2_w0_w4 above_1810 40
+    w2_w0_w4 above_1811 40
+    w2_w0_w4 above_1812 41
+    w2_w0_w4 above_1813 40
+    w2_w0_w4 above_1814 45
+    w2_w0_w4 above_1815 42
+    w2_w0_w4 above_1816 44
+    w2_w0_w4 above_1817 50
+    w2_w0_w4 above_1818 44
+    w2_w0_w4 above_1819 48
+    w2_w0_w4 above_1820 49
+    w2_w0_w4 above_1821 48
+    w2_w0_w4 above_1822 50
+    w2_w0_w4 above_1823 50
+    w2_w0_w4 above_1824 50
+    w2_w0_w4 above_1825 52
+    w2_w0_w4 above_1826 50
+    w2_w0_w4 above_1827 53
+    w2_w0_w4 above_1828 52
+    w2_w0_w4 above_1829 49
+    w2_w0_w4 above_1830 55
+    w2_w0_w4 above_1831 53
+    w2_w0_w4 above_1832 50
+    w2_w0_w4 above_1833 57
+    w2_w0_w4 above_1834 57
+    w2_w0_w4 above_1835 58
+    w2_w0_w4 above_1836 55
+    w2_w0_w4 above_1837 56
+    w2_w0_w4 above_1838 49
+    w2_w0_w4 above_1839 54
+    w2_w0_w4 above_1840 53
+    w2_w0_w4 above_1841 55
+    w2_w0_w4 above_1842 56
+    w2_w0_w4 above_1843 56
+    w2_w0_w4 above_1844 53
+    w2_w0_w4 above_1845 57
+    w2_w0_w4 above_1846 53
+    w2_w0_w4 above_1847 62
+    w2_w0_w4 above_1848 55
+    w2_w0_w4 above_1849 55
+    w2_w0_w4 above_1850 56
+    w2_w0_w4 above_1851 60
+    w2_w0_w4 above_1852 57
+    w2_w0_w4 above_1853 54
+    w2_w0_w4 above_1854 61
+    w2_w0_w4 above_1855 53
+    w2_w0_w4 above_1856 61
+    w2_w0_w4 above_1857 56
+    w2_w0_w4 above_1858 52
+    w2_w0_w4 above_1859 56
+    w2_w0_w4 above_1860 54
+    w2_w0_w4 above_1861 53
+    w2_w0_w4 above_1862 57
+    w2_w0_w4 above_1863 57
+    w2_w0_w4 above_1864 56
+    w2_w0_w4 above_1865 57
+    w2_w0_w4 above_1866 56
+    w2_w0_w4 above_1867 60
+    w2_w0_w4 above_1868 58
+    w2_w0_w4 above_1869 56
+    w2_w0_w4 above_1870 54
+    w2_w0_w4 above_1871 52
+    w2_w0_w4 above_1872 60
+    w2_w0_w4 above_1873 58
+    w2_w0_w4 above_1874 56
+    w2_w0_w4 above_1875 54
+    w2_w0_w4 above_1876 60
+    w2_w0_w4 above_1877 58
+    w2_w0_w4 above_1878 55
+    w2_w0_w4 above_1879 64
+    w2_w0_w4 above_1880 56
+    w2_w0_w4 above_1881 62
+    w2_w0_w4 above_1882 65
+    w2_w0_w4 above_1883 63
+    w2_w0_w4 above_1884 70
+    w2_w0_w4 above_1885 56
+    w2_w0_w4 above_1886 66
+    w2_w0_w4 above_1887 60
+    w2_w0_w4 above_1888 59
+    w2_w0_w4 above_1889 61
+    w2_w0_w4 above_1890 54
+    w2_w0_w4 above_1891 60
+    w2_w0_w4 above_1892 57
+    w2_w0_w4 above_1893 57
+    w2_w0_w4 above_1894 56
+    w2_w0_w4 above_1895 62
+    w2_w0_w4 above_1896 63
+    w2_w0_w4 above_1897 64
+    w2_w0_w4 above_1898 53
+    w2_w0_w4 above_1899 57
+    w2_w0_w4 above_1900 58
+    w2_w0_w4 above_1901 63
+    w2_w0_w4 above_1902 58
+    w2_w0_w4 above_1903 60
+    w2_w0_w4 above_1904 59
+    w2_w0_w4 above_1905 64
+    w2_w0_w4 above_1906 55
+    w2_w0_w4 above_1907 58
+    w2_w0_w4 above_1908 68
+    w2_w0_w4 above_1909 60
+    w2_w0_w4 above_1910 61
+    w2_w0_w4 above_1911 60
+    w2_w0_w4 above_1912 61
+    w2_w0_w4 above_1913 62
+    w2_w0_w4 above_1914 59
+    w2_w0_w4 above_1915 60
+    w2_w0_w4 above_1916 63
+    w2_w0_w4 above_1917 56
+    w2_w0_w4 above_1918 57
+    w2_w0_w4 above_1919 61
+    w2_w0_w4 above_1920 61
+    w2_w0_w4 above_1921 60
+    w2_w0_w4 above_1922 55
+    w2_w0_w4 above_1923 61
+    w2_w0_w4 above_1924 59
+    w2_w0_w4 above_1925 61
+    w2_w0_w4 above_1926 61
+    w2_w0_w4 above_1927 60
+    w2_w0_w4 above_1928 56
+    w2_w0_w4 above_1929 60
+    w2_w0_w4 above_1930 61
+    w2_w0_w4 above_1931 61
+    w2_w0_w4 above_1932 65
+    w2_w0_w4 above_1933 62
+    w2_w0_w4 above_1934 58
+    w2_w0_w4 above_1935 64
+    w2_w0_w4 above_1936 62
+    w2_w0_w4 above_1937 64
+    w2_w0_w4 above_1938 66
+    w2_w0_w4 above_1939 61
+    w2_w0_w4 above_1940 62
+    w2_w0_w4 above_1941 65
+    w2_w0_w4 above_1942 61
+    w2_w0_w4 above_1943 65
+    w2_w0_w4 above_1944 61
+    w2_w0_w4 above_1945 66
+    w2_w0_w4 above_1946 59
+    w2_w0_w4 above_1947 56
+    w2_w0_w4 above_1948 64
+    w2_w0_w4 above_1949 64
+    w2_w0_w4 above_1950 63
+    w2_w0_w4 above_1951 68
+    w2_w0_w4 above_1952 61
+    w2_w0_w4 above_1953 61
+    w2_w0_w4 above_1954 62
+    w2_w0_w4 above_1955 63
+    w2_w0_w4 above_1956 67
+    w2_w0_w4 above_1957 59
+    w2_w0_w4 above_1958 60
+    w2_w0_w4 above_1959 59
+    w2_w0_w4 above_1960 64
+    w2_w0_w4 above_1961 65
+    w2_w0_w4 above_1962 64
+    w2_w0_w4 above_1963 64
+    w2_w0_w4 above_1964 60
+    w2_w0_w4 above_1965 63
+    w2_w0_w4 above_1966 63
+    w2_w0_w4 above_1967 61
+    w2_w0_w4 above_1968 64
+    w2_w0_w4 above_1969 65
+    w2_w0_w4 above_1970 67
+    w2_w0_w4 above_1971 65
+    w2_w0_w4 above_1972 63
+    w2_w0_w4 above_1973 54
+    w2_w0_w4 above_1974 65
+    w2_w0_w4 above_1975 61
+    w2_w0_w4 above_1976 71
+    w2_w0_w4 above_1977 67
+    w2_w0_w4 above_1978 63
+    w2_w0_w4 above_1979 62
+    w2_w0_w4 above_1980 63
+    w2_w0_w4 above_1981 69
+    w2_w0_w4 above_1982 67
+    w2_w0_w4 above_1983 65
+    w2_w0_w4 above_1984 62
+    w2_w0_w4 above_1985 61
+    w2_w0_w4 above_1986 61
+    w2_w0_w4 above_1987 61
+    w2_w0_w4 above_1988 58
+    w2_w0_w4 above_1989 66
+    w2_w0_w4 above_1990 65
+    w2_w0_w4 above_1991 59
+    w2_w0_w4 above_1992 58
+    w2_w0_w4 above_1993 59
+    w2_w0_w4 above_1994 64
+    w2_w0_w4 above_1995 61
+    w2_w0_w4 above_1996 64
+    w2_w0_w4 above_1997 59
+    w2_w0_w4 above_1998 67
+    w2_w0_w4 above_1999 65
+    w2_w0_w4 above_2000 58
+    w2_w0_w4 below_1800 -31
+    w2_w0_w4 below_1801 -29
+    w2_w0_w4 below_1802 -32
+    w2_w0_w4 below_1803 -31
+    w2_w0_w4 below_1804 -31
+    w2_w0_w4 below_1805 -31
+    w2_w0_w4 below_1806 -34
+    w2_w0_w4 below_1807 -35
+    w2_w0_w4 below_1808 -38
+    w2_w0_w4 below_1809 -38
+    w2_w0_w4 below_1810 -40
+    w2_w0_w4 below_1811 -40
+    w2_w0_w4 below_1812 -41
+    w2_w0_w4 below_1813 -40
+    w2_w0_w4 below_1814 -45
+    w2_w0_w4 below_1815 -42
+    w2_w0_w4 below_1816 -44
+    w2_w0_w4 below_1817 -50
+    w2_w0_w4 below_1818 -44
+    w2_w0_w4 below_1819 -48
+    w2_w0_w4 below_1820 -49
+    w2_w0_w4 below_1821 -48
+    w2_w0_w4 below_1822 -50
+    w2_w0_w4 below_1823 -50
+    w2_w0_w4 below_1824 -50
+    w2_w0_w4 below_1825 -52
+    w2_w0_w4 below_1826 -50
+    w2_w0_w4 below_1827 -53
+    w2_w0_w4 below_1828 -52
+    w2_w0_w4 below_1829 -49
+    w2_w0_w4 below_1830 -55
+    w2_w0_w4 below_1831 -53
+    w2_w0_w4 below_1832 -50
+    w2_w0_w4 below_1833 -57
+    w2_w0_w4 below_1834 -57
+    w2_w0_w4 below_1835 -58
+    w2_w0_w4 below_1836 -55
+    w2_w0_w4 below_1837 -56
+    w2_w0_w4 below_1838 -49
+    w2_w0_w4 below_1839 -54
+    w2_w0_w4 below_1840 -53
+    w2_w0_w4 below_1841 -55
+    w2_w0_w4 below_1842 -56
+    w2_w0_w4 below_1843 -56
+    w2_w0_w4 below_1844 -53
+    w2_w0_w4 below_1845 -57
+    w2_w0_w4 below_1846 -53
+    w2_w0_w4 below_1847 -62
+    w2_w0_w4 below_1848 -55
+    w2_w0_w4 below_1849 -55
+    w2_w0_w4 below_1850 -56
+    w2_w0_w4 below_1851 -60
+    w2_w0_w4 below_1852 -57
+    w2_w0_w4 below_1853 -54
+    w2_w0_w4 below_1854 -61
+    w2_w0_w4 below_1855 -53
+    w2_w0_w4 below_1856 -61
+    w2_w0_w4 below_1857 -56
+    w2_w0_w4 below_1858 -52
+    w2_w0_w4 below_1859 -56
+    w2_w0_w4 below_1860 -54
+    w2_w0_w4 below_1861 -53
+    w2_w0_w4 below_1862 -57
+    w2_w0_w4 below_1863 -57
+    w2_w0_w4 below_1864 -56
+    w2_w0_w4 below_1865 -57
+    w2_w0_w4 below_1866 -56
+    w2_w0_w4 below_1867 -60
+    w2_w0_w4 below_1868 -58
+    w2_w0_w4 below_1869 -56
+    w2_w0_w4 below_1870 -54
+    w2_w0_w4 below_1871 -52
+    w2_w0_w4 below_1872 -60
+    w2_w0_w4 below_1873 -58
+    w2_w0_w4 below_1874 -56
+    w2_w0_w4 below_1875 -54
+    w2_w0_w4 below_1876 -60
+    w2_w0_w4 below_1877 -58
+    w2_w0_w4 below_1878 -55
+    w2_w0_w4 below_1879 -64
+    w2_w0_w4 below_1880 -56
+    w2_w0_w4 below_1881 -62
+    w2_w0_w4 below_1882 -65
+    w2_w0_w4 below_1883 -63
+    w2_w0_w4 below_1884 -70
+    w2_w0_w4 below_1885 -56
+    w2_w0_w4 below_1886 -66
+    w2_w0_w4 below_1887 -60
+    w2_w0_w4 below_1888 -59
+    w2_w0_w4 below_1889 -61
+    w2_w0_w4 below_1890 -54
+    w2_w0_w4 below_1891 -60
+    w2_w0_w4 below_1892 -57
+    w2_w0_w4 below_1893 -57
+    w2_w0_w4 below_1894 -56
+    w2_w0_w4 below_1895 -62
+    w2_w0_w4 below_1896 -63
+    w2_w0_w4 below_1897 -64
+    w2_w0_w4 below_1898 -53
+    w2_w0_w4 below_1899 -57
+    w2_w0_w4 below_1900 -58
+    w2_w0_w4 below_1901 -63
+    w2_w0_w4 below_1902 -58
+    w2_w0_w4 below_1903 -60
+    w2_w0_w4 below_1904 -59
+    w2_w0_w4 below_1905 -64
+    w2_w0_w4 below_1906 -55
+    w2_w0_w4 below_1907 -58
+    w2_w0_w4 below_1908 -68
+    w2_w0_w4 below_1909 -60
+    w2_w0_w4 below_1910 -61
+    w2_w0_w4 below_1911 -60
+    w2_w0_w4 below_1912 -61
+    w2_w0_w4 below_1913 -62
+    w2_w0_w4 below_1914 -59
+    w2_w0_w4 below_1915 -60
+    w2_w0_w4 below_1916 -63
+    w2_w0_w4 below_1917 -56
+    w2_w0_w4 below_1918 -57
+    w2_w0_w4 below_1919 -61
+    w2_w0_w4 below_1920 -61
+    w2_w0_w4 below_1921 -60
+    w2_w0_w4 below_1922 -55
+    w2_w0_w4 below_1923 -61
+    w2_w0_w4 below_1924 -59
+    w2_w0_w4 below_1925 -61
+    w2_w0_w4 below_1926 -61
+    w2_w0_w4 below_1927 -60
+    w2_w0_w4 below_1928 -56
+    w2_w0_w4 below_1929 -60
+    w2_w0_w4 below_1930 -61
+    w2_w0_w4 below_1931 -61
+    w2_w0_w4 below_1932 -65
+    w2_w0_w4 below_1933 -62
+    w2_w0_w4 below_1934 -58
+    w2_w0_w4 below_1935 -64
+    w2_w0_w4 below_1936 -62
+    w2_w0_w4 below_1937 -64
+    w2_w0_w4 below_1938 -66
+    w2_w0_w4 below_1939 -61
+    w2_w0_w4 below_1940 -62
+    w2_w0_w4 below_1941 -65
+    w2_w0_w4 below_1942 -61
+    w2_w0_w4 below_1943 -65
+    w2_w0_w4 below_1944 -61
+    w2_w0_w4 below_1945 -66
+    w2_w0_w4 below_1946 -59
+    w2_w0_w4 below_1947 -56
+    w2_w0_w4 below_1948 -64
+    w2_w0_w4 below_1949 -64
+    w2_w0_w4 below_1950 -63
+    w2_w0_w4 below_1951 -68
+    w2_w0_w4 below_1952 -61
+    w2_w0_w4 below_1953 -61
+    w2_w0_w4 below_1954 -62
+    w2_w0_w4 below_1955 -63
+    w2_w0_w4 below_1956 -67
+    w2_w0_w4 below_1957 -59
+    w2_w0_w4 below_1958 -60
+    w2_w0_w4 below_1959 -59
+    w2_w0_w4 below_1960 -64
+    w2_w0_w4 below_1961 -65
+    w2_w0_w4 below_1962 -64
+    w2_w0_w4 below_1963 -64
+    w2_w0_w4 below_1964 -60
+    w2_w0_w4 below_1965 -63
+    w2_w0_w4 below_1966 -63
+    w2_w0_w4 below_1967 -61
+    w2_w0_w4 below_1968 -64
+    w2_w0_w4 below_1969 -65
+    w2_w0_w4 below_1970 -67
+    w2_w0_w4 below_1971 -65
+    w2_w0_w4 below_1972 -63
+    w2_w0_w4 below_1973 -54
+    w2_w0_w4 below_1974 -65
+    w2_w0_w4 below_1975 -61
+    w2_w0_w4 below_1976 -71
+    w2_w0_w4 below_1977 -67
+    w2_w0_w4 below_1978 -63
+    w2_w0_w4 below_1979 -62
+    w2_w0_w4 below_1980 -63
+    w2_w0_w4 below_1981 -69
+    w2_w0_w4 below_1982 -67
+    w2_w0_w4 below_1983 -65
+    w2_w0_w4 below_1984 -62
+    w2_w0_w4 below_1985 -61
+    w2_w0_w4 below_1986 -61
+    w2_w0_w4 below_1987 -61
+    w2_w0_w4 below_1988 -58
+    w2_w0_w4 below_1989 -66
+    w2_w0_w4 below_1990 -65
+    w2_w0_w4 below_1991 -59
+    w2_w0_w4 below_1992 -58
+    w2_w0_w4 below_1993 -59
+    w2_w0_w4 below_1994 -64
+    w2_w0_w4 below_1995 -61
+    w2_w0_w4 below_1996 -64
+    w2_w0_w4 below_1997 -59
+    w2_w0_w4 below_1998 -67
+    w2_w0_w4 below_1999 -65
+    w2_w0_w4 below_2000 -58
+    w38_w0_w4 above_1800 6
+    w38_w0_w4 above_1801 6
+    w38_w0_w4 above_1802 6
+    w38_w0_w4 above_1803 7
+    w38_w0_w4 above_1804 7
+    w38_w0_w4 above_1805 8
+    w38_w0_w4 above_1806 9
+    w38_w0_w4 above_1807 9
+    w38_w0_w4 above_1808 9
+    w38_w0_w4 above_1809 9
+    w38_w0_w4 above_1810 11
+    w38_w0_w4 above_1811 11
+    w38_w0_w4 above_1812 12
+    w38_w0_w4 above_1813 13
+    w38_w0_w4 above_1814 12
+    w38_w0_w4 above_1815 12
+    w38_w0_w4 above_1816 15
+    w38_w0_w4 above_1817 14
+    w38_w0_w4 above_1818 16
+    w38_w0_w4 above_1819 17
+    w38_w0_w4 above_1820 18
+    w38_w0_w4 above_1821 17
+    w38_w0_w4 above_1822 19
+    w38_w0_w4 above_1823 19
+    w38_w0_w4 above_1824 19
+    w38_w0_w4 above_1825 22
+    w38_w0_w4 above_1826 24
+    w38_w0_w4 above_1827 25
+    w38_w0_w4 above_1828 26
+    w38_w0_w4 above_1829 26
+    w38_w0_w4 above_1830 25
+    w38_w0_w4 above_1831 28
+    w38_w0_w4 above_1832 28
+    w38_w0_w4 above_1833 30
+    w38_w0_w4 above_1834 30
+    w38_w0_w4 above_1835 31
+    w38_w0_w4 above_1836 33
+    w38_w0_w4 above_1837 34
+    w38_w0_w4 above_1838 36
+    w38_w0_w4 above_1839 34
+    w38_w0_w4 above_1840 37
+    w38_w0_w4 above_1841 37
+    w38_w0_w4 above_1842 40
+    w38_w0_w4 above_1843 42
+    w38_w0_w4 above_1844 44
+    w38_w0_w4 above_1845 40
+    w38_w0_w4 above_1846 42
+    w38_w0_w4 above_1847 43
+    w38_w0_w4 above_1848 45
+    w38_w0_w4 above_1849 45
+    w38_w0_w4 above_1850 46
+    w38_w0_w4 above_1851 49
+    w38_w0_w4 above_1852 48
+    w38_w0_w4 above_1853 49
+    w38_w0_w4 above_1854 47
+    w38_w0_w4 above_1855 52
+    w38_w0_w4 above_1856 50
+    w38_w0_w4 above_1857 49
+    w38_w0_w4 above_1858 50
+    w38_w0_w4 above_1859 55
+    w38_w0_w4 above_1860 55
+    w38_w0_w4 above_1861 53
+    w38_w0_w4 above_1862 54
+    w38_w0_w4 above_1863 55
+    w38_w0_w4 above_1864 52
+    w38_w0_w4 above_1865 51
+    w38_w0_w4 above_1866 58
+    w38_w0_w4 above_1867 56
+    w38_w0_w4 above_1868 56
+    w38_w0_w4 above_1869 57
+    w38_w0_w4 above_1870 55
+    w38_w0_w4 above_1871 54
+    w38_w0_w4 above_1872 61
+    w38_w0_w4 above_1873 56
+    w38_w0_w4 above_1874 59
+    w38_w0_w4 above_1875 60
+    w38_w0_w4 above_1876 63
+    w38_w0_w4 above_1877 60
+    w38_w0_w4 above_1878 63
+    w38_w0_w4 above_1879 63
+    w38_w0_w4 above_1880 60
+    w38_w0_w4 above_1881 58
+    w38_w0_w4 above_1882 60
+    w38_w0_w4 above_1883 59
+    w38_w0_w4 above_1884 58
+    w38_w0_w4 above_1885 62
+    w38_w0_w4 above_1886 62
+    w38_w0_w4 above_1887 63
+    w38_w0_w4 above_1888 57
+    w38_w0_w4 above_1889 68
+    w38_w0_w4 above_1890 64
+    w38_w0_w4 above_1891 59
+    w38_w0_w4 above_1892 60
+    w38_w0_w4 above_1893 65
+    w38_w0_w4 above_1894 65
+    w38_w0_w4 above_1895 65
+    w38_w0_w4 above_1896 59
+    w38_w0_w4 above_1897 60
+    w38_w0_w4 above_1898 60
+    w38_w0_w4 above_1899 63
+    w38_w0_w4 above_1900 62
+    w38_w0_w4 above_1901 68
+    w38_w0_w4 above_1902 68
+    w38_w0_w4 above_1903 65
+    w38_w0_w4 above_1904 65
+    w38_w0_w4 above_1905 64
+    w38_w0_w4 above_1906 63
+    w38_w0_w4 above_1907 64
+    w38_w0_w4 above_1908 69
+    w38_w0_w4 above_1909 63
+    w38_w0_w4 above_1910 65
+    w38_w0_w4 above_1911 59
+    w38_w0_w4 above_1912 65
+    w38_w0_w4 above_1913 62
+    w38_w0_w4 above_1914 66
+    w38_w0_w4 above_1915 60
+    w38_w0_w4 above_1916 61
+    w38_w0_w4 above_1917 63
+    w38_w0_w4 above_1918 66
+    w38_w0_w4 above_1919 63
+    w38_w0_w4 above_1920 69
+    w38_w0_w4 above_1921 61
+    w38_w0_w4 above_1922 60
+    w38_w0_w4 above_1923 63
+    w38_w0_w4 above_1924 64
+    w38_w0_w4 above_1925 66
+    w38_w0_w4 above_1926 63
+    w38_w0_w4 above_1927 67
+    w38_w0_w4 above_1928 65
+    w38_w0_w4 above_1929 63
+    w38_w0_w4 above_1930 63
+    w38_w0_w4 above_1931 62
+    w38_w0_w4 above_1932 63
+    w38_w0_w4 above_1933 67
+    w38_w0_w4 above_1934 66
+    w38_w0_w4 above_1935 61
+    w38_w0_w4 above_1936 66
+    w38_w0_w4 above_1937 71
+    w38_w0_w4 above_1938 63
+    w38_w0_w4 above_1939 65
+    w38_w0_w4 above_1940 63
+    w38_w0_w4 above_1941 69
+    w38_w0_w4 above_1942 63
+    w38_w0_w4 above_1943 68
+    w38_w0_w4 above_1944 68
+    w38_w0_w4 above_1945 64
+    w38_w0_w4 above_1946 65
+    w38_w0_w4 above_1947 68
+    w38_w0_w4 above_1948 61
+    w38_w0_w4 above_1949 68
+    w38_w0_w4 above_1950 67
+    w38_w0_w4 above_1951 58
+    w38_w0_w4 above_1952 63
+    w38_w0_w4 above_1953 67
+    w38_w0_w4 above_1954 64
+    w38_w0_w4 above_1955 68
+    w38_w0_w4 above_1956 60
+    w38_w0_w4 above_1957 65
+    w38_w0_w4 above_1958 68
+    w38_w0_w4 above_1959 64
+    w38_w0_w4 above_1960 65
+    w38_w0_w4 above_1961 68
+    w38_w0_w4 above_1962 62
+    w38_w0_w4 above_1963 63
+    w38_w0_w4 above_1964 65
+    w38_w0_w4 above_1965 63
+    w38_w0_w4 above_1966 59
+    w38_w0_w4 above_1967 67
+    w38_w0_w4 above_1968 63
+    w38_w0_w4 above_1969 60
+    w38_w0_w4 above_1970 68
+    w38_w0_w4 above_1971 71
+    w38_w0_w4 above_1972 63
+    w38_w0_w4 above_1973 66
+    w38_w0_w4 above_1974 66
+    w38_w0_w4 above_1975 69
+    w38_w0_w4 above_1976 63
+    w38_w0_w4 above_1977 62
+    w38_w0_w4 above_1978 63
+    w38_w0_w4 above_1979 65
+    w38_w0_w4 above_1980 70
+    w38_w0_w4 above_1981 59
+    w38_w0_w4 above_1982 73
+    w38_w0_w4 above_1983 62
+    w38_w0_w4 above_1984 67
+    w38_w0_w4 above_1985 67
+    w38_w0_w4 above_1986 62
+    w38_w0_w4 above_1987 64
+    w38_w0_w4 above_1988 68
+    w38_w0_w4 above_1989 64
+    w38_w0_w4 above_1990 61
+    w38_w0_w4 above_1991 62
+    w38_w0_w4 above_1992 65
+    w38_w0_w4 above_1993 66
+    w38_w0_w4 above_1994 63
+    w38_w0_w4 above_1995 66
+    w38_w0_w4 above_1996 63
+    w38_w0_w4 above_1997 69
+    w38_w0_w4 above_1998 65
+    w38_w0_w4 above_1999 72
+    w38_w0_w4 above_2000 60
+    w38_w0_w4 below_1800 -6
+    w38_w0_w4 below_1801 -6
+    w38_w0_w4 below_1802 -6
+    w38_w0_w4 below_1803 -7
+    w38_w0_w4 below_1804 -7
+    w38_w0_w4 below_1805 -8
+    w38_w0_w4 below_1806 -9
+    w38_w0_w4 below_1807 -9
+    w38_w0_w4 below_1808 -9
+    w38_w0_w4 below_1809 -9
+    w38_w0_w4 below_1810 -11
+    w38_w0_w4 below_1811 -11
+    w38_w0_w4 below_1812 -12
+    w38_w0_w4 below_1813 -13
+    w38_w0_w4 below_1814 -12
+    w38_w0_w4 below_1815 -12
+    w38_w0_w4 below_1816 -15
+    w38_w0_w4 below_1817 -14
+    w38_w0_w4 below_1818 -16
+    w38_w0_w4 below_1819 -17
+    w38_w0_w4 below_1820 -18
+    w38_w0_w4 below_1821 -17
+    w38_w0_w4 below_1822 -19
+    w38_w0_w4 below_1823 -19
+    w38_w0_w4 below_1824 -19
+    w38_w0_w4 below_1825 -22
+    w38_w0_w4 below_1826 -24
+    w38_w0_w4 below_1827 -25
+    w38_w0_w4 below_1828 -26
+    w38_w0_w4 below_1829 -26
+    w38_w0_w4 below_1830 -25
+    w38_w0_w4 below_1831 -28
+    w38_w0_w4 below_1832 -28
+    w38_w0_w4 below_1833 -30
+    w38_w0_w4 below_1834 -30
+    w38_w0_w4 below_1835 -31
+    w38_w0_w4 below_1836 -33
+    w38_w0_w4 below_1837 -34
+    w38_w0_w4 below_1838 -36
+    w38_w0_w4 below_1839 -34
+    w38_w0_w4 below_1840 -37
+    w38_w0_w4 below_1841 -37
+    w38_w0_w4 below_1842 -40
+    w38_w0_w4 below_1843 -42
+    w38_w0_w4 below_1844 -44
+    w38_w0_w4 below_1845 -40
+    w38_w0_w4 below_1846 -42
+    w38_w0_w4 below_1847 -43
+    w38_w0_w4 below_1848 -45
+    w38_w0_w4 below_1849 -45
+    w38_w0_w4 below_1850 -46
+    w38_w0_w4 below_1851 -49
+    w38_w0_w4 below_1852 -48
+    w38_w0_w4 below_1853 -49
+    w38_w0_w4 below_1854 -47
+    w38_w0_w4 below_1855 -52
+    w38_w0_w4 below_1856 -50
+    w38_w0_w4 below_1857 -49
+    w38_w0_w4 below_1858 -50
+    w38_w0_w4 below_1859 -55
+    w38_w0_w4 below_1860 -55
+    w38_w0_w4 below_1861 -53
+    w38_w0_w4 below_1862 -54
+    w38_w0_w4 below_1863 -55
+    w38_w0_w4 below_1864 -52
+    w38_w0_w4 below_1865 -51
+    w38_w0_w4 below_1866 -58
+    w38_w0_w4 below_1867 -56
+    w38_w0_w4 below_1868 -56
+    w38_w0_w4 below_1869 -57
+    w38_w0_w4 below_1870 -55
+    w38_w0_w4 below_1871 -54
+    w38_w0_w4 below_1872 -61
+    w38_w0_w4 below_1873 -56
+    w38_w0_w4 below_1874 -59
+    w38_w0_w4 below_1875 -60
+    w38_w0_w4 below_1876 -63
+    w38_w0_w4 below_1877 -60
+    w38_w0_w4 below_1878 -63
+    w38_w0_w4 below_1879 -63
+    w38_w0_w4 below_1880 -60
+    w38_w0_w4 below_1881 -58
+    w38_w0_w4 below_1882 -60
+    w38_w0_w4 below_1883 -59
+    w38_w0_w4 below_1884 -58
+    w38_w0_w4 below_1885 -62
+    w38_w0_w4 below_1886 -62
+    w38_w0_w4 below_1887 -63
+    w38_w0_w4 below_1888 -57
+    w38_w0_w4 below_1889 -68
+    w38_w0_w4 below_1890 -64
+    w38_w0_w4 below_1891 -59
+    w38_w0_w4 below_1892 -60
+    w38_w0_w4 below_1893 -65
+    w38_w0_w4 below_1894 -65
+    w38_w0_w4 below_1895 -65
+    w38_w0_w4 below_1896 -59
+    w38_w0_w4 below_1897 -60
+    w38_w0_w4 below_1898 -60
+    w38_w0_w4 below_1899 -63
+    w38_w0_w4 below_1900 -62
+    w38_w0_w4 below_1901 -68
+    w38_w0_w4 below_1902 -68
+    w38_w0_w4 below_1903 -65
+    w38_w0_w4 below_1904 -65
+    w38_w0_w4 below_1905 -64
+    w38_w0_w4 below_1906 -63
+    w38_w0_w4 below_1907 -64
+    w38_w0_w4 below_1908 -69
+    w38_w0_w4 below_1909 -63
+    w38_w0_w4 below_1910 -65
+    w38_w0_w4 below_1911 -59
+    w38_w0_w4 below_1912 -65
+    w38_w0_w4 below_1913 -62
+    w38_w0_w4 below_1914 -66
+    w38_w0_w4 below_1915 -60
+    w38_w0_w4 below_1916 -61
+    w38_w0_w4 below_1917 -63
+    w38_w0_w4 below_1918 -66
+    w38_w0_w4 below_1919 -63
+    w38_w0_w4 below_1920 -69
+    w38_w0_w4 below_1921 -61
+    w38_w0_w4 below_1922 -60
+    w38_w0_w4 below_1923 -63
+    w38_w0_w4 below_1924 -64
+    w38_w0_w4 below_1925 -66
+    w38_w0_w4 below_1926 -63
+    w38_w0_w4 below_1927 -67
+    w38_w0_w4 below_1928 -65
+    w38_w0_w4 below_1929 -63
+    w38_w0_w4 below_1930 -63
+    w38_w0_w4 below_1931 -62
+    w38_w0_w4 below_1932 -63
+    w38_w0_w4 below_1933 -67
+    w38_w0_w4 below_1934 -66
+    w38_w0_w4 below_1935 -61
+    w38_w0_w4 below_1936 -66
+    w38_w0_w4 below_1937 -71
+    w38_w0_w4 below_1938 -63
+    w38_w0_w4 below_1939 -65
+    w38_w0_w4 below_1940 -63
+    w38_w0_w4 below_1941 -69
+    w38_w0_w4 below_1942 -63
+    w38_w0_w4 below_1943 -68
+    w38_w0_w4 below_1944 -68
+    w38_w0_w4 below_1945 -64
+    w38_w0_w4 below_1946 -65
+    w38_w0_w4 below_1947 -68
+    w38_w0_w4 below_1948 -61
+    w38_w0_w4 below_1949 -68
+    w38_w0_w4 below_1950 -67
+    w38_w0_w4 below_1951 -58
+    w38_w0_w4 below_1952 -63
+    w38_w0_w4 below_1953 -67
+    w38_w0_w4 below_1954 -64
+    w38_w0_w4 below_1955 -68
+    w38_w0_w4 below_1956 -60
+    w38_w0_w4 below_1957 -65
+    w38_w0_w4 below_1958 -68
+    w38_w0_w4 below_1959 -64
+    w38_w0_w4 below_1960 -65
+    w38_w0_w4 below_1961 -68
+    w38_w0_w4 below_1962 -62
+    w38_w0_w4 below_1963 -63
+    w38_w0_w4 below_1964 -65
+    w38_w0_w4 below_1965 -63
+    w38_w0_w4 below_1966 -59
+    w38_w0_w4 below_1967 -67
+    w38_w0_w4 below_1968 -63
+    w38_w0_w4 below_1969 -60
+    w38_w0_w4 below_1970 -68
+    w38_w0_w4 below_1971 -71
+    w38_w0_w4 below_1972 -63
+    w38_w0_w4 below_1973 -66
+    w38_w0_w4 below_1974 -66
+    w38_w0_w4 below_1975 -69
+    w38_w0_w4 below_1976 -63
+    w38_w0_w4 below_1977 -62
+    w38_w0_w4 below_1978 -63
+    w38_w0_w4 below_1979 -65
+    w38_w0_w4 below_1980 -70
+    w38_w0_w4 below_1981 -59
+    w38_w0_w4 below_1982 -73
+    w38_w0_w4 below_1983 -62
+    w38_w0_w4 below_1984 -67
+    w38_w0_w4 below_1985 -67
+    w38_w0_w4 below_1986 -62
+    w38_w0_w4 below_1987 -64
+    w38_w0_w4 below_1988 -68
+    w38_w0_w4 below_1989 -64
+    w38_w0_w4 below_1990 -61
+    w38_w0_w4 below_1991 -62
+    w38_w0_w4 below_1992 -65
+    w38_w0_w4 below_1993 -66
+    w38_w0_w4 below_1994 -63
+    w38_w0_w4 below_1995 -66
+    w38_w0_w4 below_1996 -63
+    w38_w0_w4 below_1997 -69
+    w38_w0_w4 below_1998 -65
+    w38_w0_w4 below_1999 -72
+    w38_w0_w4 below_2000 -60
+    w46_w0_w4 above_1863 1
+    w46_w0_w4 above_1864 1
+    w46_w0_w4 above_1865 1
+    w46_w0_w4 above_1866 1
+    w46_w0_w4 above_1867 1
+    w46_w0_w4 above_1868 1
+    w46_w0_w4 above_1869 1
+    w46_w0_w4 above_1870 1
+    w46_w0_w4 above_1871 1
+    w46_w0_w4 above_1872 2
+    w46_w0_w4 above_1873 2
+    w46_w0_w4 above_1874 2
+    w46_w0_w4 above_1875 3
+    w46_w0_w4 above_1876 3
+    w46_w0_w4 above_1877 3
+    w46_w0_w4 above_1878 3
+    w46_w0_w4 above_1879 4
+    w46_w0_w4 above_1880 4
+    w46_w0_w4 above_1881 5
+    w46_w0_w4 above_1882 6
+    w46_w0_w4 above_1883 6
+    w46_w0_w4 above_1884 8
+    w46_w0_w4 above_1885 8
+    w46_w0_w4 above_1886 10
+    w46_w0_w4 above_1887 10
+    w46_w0_w4 above_1888 12
+    w46_w0_w4 above_1889 14
+    w46_w0_w4 above_1890 14
+    w46_w0_w4 above_1891 18
+    w46_w0_w4 above_1892 19
+    w46_w0_w4 above_1893 21
+    w46_w0_w4 above_1894 25
+    w46_w0_w4 above_1895 27
+    w46_w0_w4 above_1896 31
+    w46_w0_w4 above_1897 32
+    w46_w0_w4 above_1898 37
+    w46_w0_w4 above_1899 42
+    w46_w0_w4 above_1900 43
+    w46_w0_w4 above_1901 53
+    w46_w0_w4 above_1902 57
+    w46_w0_w4 above_1903 57
+    w46_w0_w4 above_1904 66
+    w46_w0_w4 above_1905 74
+    w46_w0_w4 above_1906 79
+    w46_w0_w4 above_1907 83
+    w46_w0_w4 above_1908 88
+    w46_w0_w4 above_1909 100
+    w46_w0_w4 above_1910 99
+    w46_w0_w4 above_1911 112
+    w46_w0_w4 above_1912 110
+    w46_w0_w4 above_1913 116
+    w46_w0_w4 above_1914 142
+    w46_w0_w4 above_1915 139
+    w46_w0_w4 above_1916 141
+    w46_w0_w4 above_1917 152
+    w46_w0_w4 above_1918 165
+    w46_w0_w4 above_1919 173
+    w46_w0_w4 above_1920 162
+    w46_w0_w4 above_1921 161
+    w46_w0_w4 above_1922 179
+    w46_w0_w4 above_1923 162
+    w46_w0_w4 above_1924 183
+    w46_w0_w4 above_1925 166
+    w46_w0_w4 above_1926 181
+    w46_w0_w4 above_1927 169
+    w46_w0_w4 above_1928 198
+    w46_w0_w4 above_1929 179
+    w46_w0_w4 above_1930 182
+    w46_w0_w4 above_1931 176
+    w46_w0_w4 above_1932 181
+    w46_w0_w4 above_1933 189
+    w46_w0_w4 above_1934 173
+    w46_w0_w4 above_1935 172
+    w46_w0_w4 above_1936 175
+    w46_w0_w4 above_1937 174
+    w46_w0_w4 above_1938 174
+    w46_w0_w4 above_1939 180
+    w46_w0_w4 above_1940 169
+    w46_w0_w4 above_1941 161
+    w46_w0_w4 above_1942 156
+    w46_w0_w4 above_1943 160
+    w46_w0_w4 above_1944 146
+    w46_w0_w4 above_1945 146
+    w46_w0_w4 above_1946 143
+    w46_w0_w4 above_1947 144
+    w46_w0_w4 above_1948 130
+    w46_w0_w4 above_1949 113
+    w46_w0_w4 above_1950 123
+    w46_w0_w4 above_1951 114
+    w46_w0_w4 above_1952 106
+    w46_w0_w4 above_1953 112
+    w46_w0_w4 above_1954 103
+    w46_w0_w4 above_1955 93
+    w46_w0_w4 above_1956 86
+    w46_w0_w4 above_1957 78
+    w46_w0_w4 above_1958 82
+    w46_w0_w4 above_1959 77
+    w46_w0_w4 above_1960 78
+    w46_w0_w4 above_1961 78
+    w46_w0_w4 above_1962 66
+    w46_w0_w4 above_1963 62
+    w46_w0_w4 above_1964 72
+    w46_w0_w4 above_1965 60
+    w46_w0_w4 above_1966 58
+    w46_w0_w4 above_1967 58
+    w46_w0_w4 above_1968 59
+    w46_w0_w4 above_1969 54
+    w46_w0_w4 above_1970 53
+    w46_w0_w4 above_1971 53
+    w46_w0_w4 above_1972 54
+    w46_w0_w4 above_1973 49
+    w46_w0_w4 above_1974 53
+    w46_w0_w4 above_1975 47
+    w46_w0_w4 above_1976 49
+    w46_w0_w4 above_1977 46
+    w46_w0_w4 above_1978 47
+    w46_w0_w4 above_1979 42
+    w46_w0_w4 above_1980 46
+    w46_w0_w4 above_1981 42
+    w46_w0_w4 above_1982 48
+    w46_w0_w4 above_1983 45
+    w46_w0_w4 above_1984 45
+    w46_w0_w4 above_1985 44
+    w46_w0_w4 above_1986 44
+    w46_w0_w4 above_1987 49
+    w46_w0_w4 above_1988 41
+    w46_w0_w4 above_1989 42
+    w46_w0_w4 above_1990 41
+    w46_w0_w4 above_1991 47
+    w46_w0_w4 above_1992 43
+    w46_w0_w4 above_1993 42
+    w46_w0_w4 above_1994 45
+    w46_w0_w4 above_1995 45
+    w46_w0_w4 above_1996 40
+    w46_w0_w4 above_1997 44
+    w46_w0_w4 above_1998 43
+    w46_w0_w4 above_1999 42
+    w46_w0_w4 above_2000 41
+    w46_w0_w4 below_1863 -1
+    w46_w0_w4 below_1864 -1
+    w46_w0_w4 below_1865 -1
+    w46_w0_w4 below_1866 -1
+    w46_w0_w4 below_1867 -1
+    w46_w0_w4 below_1868 -1
+    w46_w0_w4 below_1869 -1
+    w46_w0_w4 below_1870 -1
+    w46_w0_w4 below_1871 -1
+    w46_w0_w4 below_1872 -2
+    w46_w0_w4 below_1873 -2
+    w46_w0_w4 below_1874 -2
+    w46_w0_w4 below_1875 -3
+    w46_w0_w4 below_1876 -3
+    w46_w0_w4 below_1877 -3
+    w46_w0_w4 below_1878 -3
+    w46_w0_w4 below_1879 -4
+    w46_w0_w4 below_1880 -4
+    w46_w0_w4 below_1881 -5
+    w46_w0_w4 below_1882 -6
+    w46_w0_w4 below_1883 -6
+    w46_w0_w4 below_1884 -8
+    w46_w0_w4 below_1885 -8
+    w46_w0_w4 below_1886 -10
+    w46_w0_w4 below_1887 -10
+    w46_w0_w4 below_1888 -12
+    w46_w0_w4 below_1889 -14
+    w46_w0_w4 below_1890 -14
+    w46_w0_w4 below_1891 -18
+    w46_w0_w4 below_1892 -19
+    w46_w0_w4 below_1893 -21
+    w46_w0_w4 below_1894 -25
+    w46_w0_w4 below_1895 -27
+    w46_w0_w4 below_1896 -31
+    w46_w0_w4 below_1897 -32
+    w46_w0_w4 below_1898 -37
+    w46_w0_w4 below_1899 -42
+    w46_w0_w4 below_1900 -43
+    w46_w0_w4 below_1901 -53
+    w46_w0_w4 below_1902 -57
+    w46_w0_w4 below_1903 -57
+    w46_w0_w4 below_1904 -66
+    w46_w0_w4 below_1905 -74
+    w46_w0_w4 below_1906 -79
+    w46_w0_w4 below_1907 -83
+    w46_w0_w4 below_1908 -88
+    w46_w0_w4 below_1909 -100
+    w46_w0_w4 below_1910 -99
+    w46_w0_w4 below_1911 -112
+    w46_w0_w4 below_1912 -110
+    w46_w0_w4 below_1913 -116
+    w46_w0_w4 below_1914 -142
+    w46_w0_w4 below_1915 -139
+    w46_w0_w4 below_1916 -141
+    w46_w0_w4 below_1917 -152
+    w46_w0_w4 below_1918 -165
+    w46_w0_w4 below_1919 -173
+    w46_w0_w4 below_1920 -162
+    w46_w0_w4 below_1921 -161
+    w46_w0_w4 below_1922 -179
+    w46_w0_w4 below_1923 -162
+    w46_w0_w4 below_1924 -183
+    w46_w0_w4 below_1925 -166
+    w46_w0_w4 below_1926 -181
+    w46_w0_w4 below_1927 -169
+    w46_w0_w4 below_1928 -198
+    w46_w0_w4 below_1929 -179
+    w46_w0_w4 below_1930 -182
+    w46_w0_w4 below_1931 -176
+    w46_w0_w4 below_1932 -181
+    w46_w0_w4 below_1933 -189
+    w46_w0_w4 below_1934 -173
+    w46_w0_w4 below_1935 -172
+    w46_w0_w4 below_1936 -175
+    w46_w0_w4 below_1937 -174
+    w46_w0_w4 below_1938 -174
+    w46_w0_w4 below_1939 -180
+    w46_w0_w4 below_1940 -169
+    w46_w0_w4 below_1941 -161
+    w46_w0_w4 below_1942 -156
+    w46_w0_w4 below_1943 -160
+    w46_w0_w4 below_1944 -146
+    w46_w0_w4 below_1945 -146
+    w46_w0_w4 below_1946 -143
+    w46_w0_w4 below_1947 -144
+    w46_w0_w4 below_1948 -130
+    w46_w0_w4 below_1949 -113
+    w46_w0_w4 below_1950 -123
+    w46_w0_w4 below_1951 -114
+    w46_w0_w4 below_1952 -106
+    w46_w0_w4 below_1953 -112
+    w46_w0_w4 below_1954 -103
+    w46_w0_w4 below_1955 -93
+    w46_w0_w4 below_1956 -86
+    w46_w0_w4 below_1957 -78
+    w46_w0_w4 below_1958 -82
+    w46_w0_w4 below_1959 -77
+    w46_w0_w4 below_1960 -78
+    w46_w0_w4 below_1961 -78
+    w46_w0_w4 below_1962 -66
+    w46_w0_w4 below_1963 -62
+    w46_w0_w4 below_1964 -72
+    w46_w0_w4 below_1965 -60
+    w46_w0_w4 below_1966 -58
+    w46_w0_w4 below_1967 -58
+    w46_w0_w4 below_1968 -59
+    w46_w0_w4 below_1969 -54
+    w46_w0_w4 below_1970 -53
+    w46_w0_w4 below_1971 -53
+    w46_w0_w4 below_1972 -54
+    w46_w0_w4 below_1973 -49
+    w46_w0_w4 below_1974 -53
+    w46_w0_w4 below_1975 -47
+    w46_w0_w4 below_1976 -49
+    w46_w0_w4 below_1977 -46
+    w46_w0_w4 below_1978 -47
+    w46_w0_w4 below_1979 -42
+    w46_w0_w4 below_1980 -46
+    w46_w0_w4 below_1981 -42
+    w46_w0_w4 below_1982 -48
+    w46_w0_w4 below_1983 -45
+    w46_w0_w4 below_1984 -45
+    w46_w0_w4 below_1985 -44
+    w46_w0_w4 below_1986 -44
+    w46_w0_w4 below_1987 -49
+    w46_w0_w4 below_1988 -41
+    w46_w0_w4 below_1989 -42
+    w46_w0_w4 below_1990 -41
+    w46_w0_w4 below_1991 -47
+    w46_w0_w4 below_1992 -43
+    w46_w0_w4 below_1993 -42
+    w46_w0_w4 below_1994 -45
+    w46_w0_w4 below_1995 -45
+    w46_w0_w4 below_1996 -40
+    w46_w0_w4 below_1997 -44
+    w46_w0_w4 below_1998 -43
+    w46_w0_w4 below_1999 -42
+    w46_w0_w4 below_2000 -41
+    __t__ obj 1
+    __t__ above_1800 -1
+    __t__ above_1801 -1
+    __t__ above_1802 -1
+    __t__ above_1803 -1
+    __t__ above_1804 -1
+    __t__ above_1805 -1
+    __t__ above_1806 -1
+    __t__ above_1807 -1
+    __t__ above_1808 -1
+    __t__ above_1809 -1
+    __t__ above_1810 -1
+    __t__ above_1811 -1
+    __t__ above_1812 -1
+    __t__ above_1813 -1
+    __t__ above_1814 -1
+    __t__ above_1815 -1
+    __t__ above_1816 -1
+    __t__ above_1817 -1
+    __t__ above_1818 -1
+    __t__ above_1819 -1
+    __t__ above_1820 -1
+    __t__ above_1821 -1
+    __t__ above_1822 -1
+    __t__ above_1823 -1
+    __t__ above_1824 -1
+    __t__ above_1825 -1
+    __t__ above_1826 -1
+    __t__ above_1827 -1
+    __t__ above_1828 -1
+    __t__ above_1829 -1
+    __t__ above_1830 -1
+    __t__ above_1831 -1
+    __t__ above_1832 -1
+    __t__ above_1833 -1
+    __t__ above_1834 -1
+    __t__ above_1835 -1
+    __t__ above_1836 -1
+    __t__ above_1837 -1
+    __t__ above_1838 -1
+    __t__ above_1839 -1
+    __t__ above_1840 -1
+    __t__ above_1841 -1
+    __t__ above_1842 -1
+    __t__ above_1843 -1
+    __t__ above_1844 -1
+    __t__ above_1845 -1
+    __t__ above_1846 -1
+    __t__ above_1847 -1
+    __t__ above_1848 -1
+    __t__ above_1849 -1
+    __t__ above_1850 -1
+    __t__ above_1851 -1
+    __t__ above_1852 -1
+    __t__ above_1853 -1
+    __t__ above_1854 -1
+    __t__ above_1855 -1
+    __t__ above_1856 -1
+    __t__ above_1857 -1
+    __t__ above_1858 -1
+    __t__ above_1859 -1
+    __t__ above_1860 -1
+    __t__ above_1861 -1
+    __t__ above_1862 -1
+    __t__ above_1863 -1
+    __t__ above_1864 -1
+    __t__ above_1865 -1
+    __t__ above_1866 -1
+    __t__ above_1867 -1
+    __t__ above_1868 -1
+    __t__ above_1869 -1
+    __t__ above_1870 -1
+    __t__ above_1871 -1
+    __t__ above_1872 -1
+    __t__ above_1873 -1
+    __t__ above_1874 -1
+    __t__ above_1875 -1
+    __t__ above_1876 -1
+    __t__ above_1877 -1
+    __t__ above_1878 -1
+    __t__ above_1879 -1
+    __t__ above_1880 -1
+    __t__ above_1881 -1
+    __t__ above_1882 -1
+    __t__ above_1883 -1
+    __t__ above_1884 -1
+    __t__ above_1885 -1
+    __t__ above_1886 -1
+    __t__ above_1887 -1
+    __t__ above_1888 -1
+    __t__ above_1889 -1
+    __t__ above_1890 -1
+    __t__ above_1891 -1
+    __t__ above_1892 -1
+    __t__ above_1893 -1
+    __t__ above_1894 -1
+    __t__ above_1895 -1
+    __t__ above_1896 -1
+    __t__ above_1897 -1
+    __t__ above_1898 -1
+    __t__ above_1899 -1
+    __t__ above_1900 -1
+    __t__ above_1901 -1
+    __t__ above_1902 -1
+    __t__ above_1903 -1
+    __t__ above_1904 -1
+    __t__ above_1905 -1
+    __t__ above_1906 -1
+    __t__ above_1907 -1
+    __t__ above_1908 -1
+    __t__ above_1909 -1
+    __t__ above_1910 -1
+    __t__ above_1911 -1
+    __t__ above_1912 -1
+    __t__ above_1913 -1
+    __t__ above_1914 -1
+    __t__ above_1915 -1
+    __t__ above_1916 -1
+    __t__ above_1917 -1
+    __t__ above_1918 -1
+    __t__ above_1919 -1
+    __t__ above_1920 -1
+    __t__ above_1921 -1
+    __t__ above_1922 -1
+    __t__ above_1923 -1
+    __t__ above_1924 -1
+    __t__ above_1925 -1
+    __t__ above_1926 -1
+    __t__ above_1927 -1
+    __t__ above_1928 -1
+    __t__ above_1929 -1
+    __t__ above_1930 -1
+    __t__ above_1931 -1
+    __t__ above_1932 -1
+    __t__ above_1933 -1
+    __t__ above_1934 -1
+    __t__ above_1935 -1
+    __t__ above_1936 -1
+    __t__ above_1937 -1
+    __t__ above_1938 -1
+    __t__ above_1939 -1
+    __t__ above_1940 -1
+    __t__ above_1941 -1
+    __t__ above_1942 -1
+    __t__ above_1943 -1
+    __t__ above_1944 -1
+    __t__ above_1945 -1
+    __t__ above_1946 -1
+    __t__ above_1947 -1
+    __t__ above_1948 -1
+    __t__ above_1949 -1
+    __t__ above_1950 -1
+    __t__ above_1951 -1
+    __t__ above_1952 -1
+    __t__ above_1953 -1
+    __t__ above_1954 -1
+    __t__ above_1955 -1
+    __t__ above_1956 -1
+    __t__ above_1957 -1
+    __t__ above_1958 -1
+    __t__ above_1959 -1
+    __t__ above_1960 -1
+    __t__ above_1961 -1
+    __t__ above_1962 -1
+    __t__ above_1963 -1
+    __t__ above_1964 -1
+    __t__ above_1965 -1
+    __t__ above_1966 -1
+    __t__ above_1967 -1
+    __t__ above_1968 -1
+    __t__ above_1969 -1
+    __t__ above_1970 -1
+    __t__ above_1971 -1
+    __t__ above_1972 -1
+    __t__ above_1973 -1
+    __t__ above_1974 -1
+    __t__ above_1975 -1
+    __t__ above_1976 -1
+    __t__ above_1977 -1
+    __t__ above_1978 -1
+    __t__ above_1979 -1
+    __t__ above_1980 -1
+    __t__ above_1981 -1
+    __t__ above_1982 -1
+    __t__ above_1983 -1
+    __t__ above_1984 -1
+    __t__ above_1985 -1
+    __t__ above_1986 -1
+    __t__ above_1987 -1
+    __t__ above_1988 -1
+    __t__ above_1989 -1
+    __t__ above_1990 -1
+    __t__ above_1991 -1
+    __t__ above_1992 -1
+    __t__ above_1993 -1
+    __t__ above_1994 -1
+    __t__ above_1995 -1
+    __t__ above_1996 -1
+    __t__ above_1997 -1
+    __t__ above_1998 -1
+    __t__ above_1999 -1
+    __t__ above_2000 -1
+    __t__ below_1800 -1
+    __t__ below_1801 -1
+    __t__ below_1802 -1
+    __t__ below_1803 -1
+    __t__ below_1804 -1
+    __t__ below_1805 -1
+    __t__ below_1806 -1
+    __t__ below_1807 -1
+    __t__ below_1808 -1
+    __t__ below_1809 -1
+    __t__ below_1810 -1
+    __t__ below_1811 -1
+    __t__ below_1812 -1
+    __t__ below_1813 -1
+    __t__ below_1814 -1
+    __t__ below_1815 -1
+    __t__ below_1816 -1
+    __t__ below_1817 -1
+    __t__ below_1818 -1
+    __t__ below_1819 -1
+    __t__ below_1820 -1
+    __t__ below_1821 -1
+    __t__ below_1822 -1
+    __t__ below_1823 -1
+    __t__ below_1824 -1
+    __t__ below_1825 -1
+    __t__ below_1826 -1
+    __t__ below_1827 -1
+    __t__ below_1828 -1
+    __t__ below_1829 -1
+    __t__ below_1830 -1
+    __t__ below_1831 -1
+    __t__ below_1832 -1
+    __t__ below_1833 -1
+    __t__ below_1834 -1
+    __t__ below_1835 -1
+    __t__ below_1836 -1
+    __t__ below_1837 -1
+    __t__ below_1838 -1
+    __t__ below_1839 -1
+    __t__ below_1840 -1
+    __t__ below_1841 -1
+    __t__ below_1842 -1
+    __t__ below_1843 -1
+    __t__ below_1844 -1
+    __t__ below_1845 -1
+    __t__ below_1846 -1
+    __t__ below_1847 -1
+    __t__ below_1848 -1
+    __t__ below_1849 -1
+    __t__ below_1850 -1
+    __t__ below_1851 -1
+    __t__ below_1852 -1
+    __t__ below_1853 -1
+    __t__ below_1854 -1
+    __t__ below_1855 -1
+    __t__ below_1856 -1
+    __t__ below_1857 -1
+    __t__ below_1858 -1
+    __t__ below_1859 -1
+    __t__ below_1860 -1
+    __t__ below_1861 -1
+    __t__ below_1862 -1
+    __t__ below_1863 -1
+    __t__ below_1864 -1
+    __t__ below_1865 -1
+    __t__ below_1866 -1
+    __t__ below_1867 -1
+    __t__ below_1868 -1
+    __t__ below_1869 -1
+    __t__ below_1870 -1
+    __t__ below_1871 -1
+    __t__ below_1872 -1
+    __t__ below_1873 -1
+    __t__ below_1874 -1
+    __t__ below_1875 -1
+    __t__ below_1876 -1
+    __t__ below_1877 -1
+    __t__ below_1878 -1
+    __t__ below_1879 -1
+    __t__ below_1880 -1
+    __t__ below_1881 -1
+    __t__ below_1882 -1
+    __t__ below_1883 -1
+    __t__ below_1884 -1
+    __t__ below_1885 -1
+    __t__ below_1886 -1
+    __t__ below_1887 -1
+    __t__ below_1888 -1
+    __t__ below_1889 -1
+    __t__ below_1890 -1
+    __t__ below_1891 -1
+    __t__ below_1892 -1
+    __t__ below_1893 -1
+    __t__ below_1894 -1
+    __t__ below_1895 -1
+    __t__ below_1896 -1
+    __t__ below_1897 -1
+    __t__ below_1898 -1
+    __t__ below_1899 -1
+    __t__ below_1900 -1
+    __t__ below_1901 -1
+    __t__ below_1902 -1
+    __t__ below_1903 -1
+    __t__ below_1904 -1
+    __t__ below_1905 -1
+    __t__ below_1906 -1
+    __t__ below_1907 -1
+    __t__ below_1908 -1
+    __t__ below_1909 -1
+    __t__ below_1910 -1
+    __t__ below_1911 -1
+    __t__ below_1912 -1
+    __t__ below_1913 -1
+    __t__ below_1914 -1
+    __t__ below_1915 -1
+    __t__ below_1916 -1
+    __t__ below_1917 -1
+    __t__ below_1918 -1
+    __t__ below_1919 -1
+    __t__ below_1920 -1
+    __t__ below_1921 -1
+    __t__ below_1922 -1
+    __t__ below_1923 -1
+    __t__ below_1924 -1
+    __t__ below_1925 -1
+    __t__ below_1926 -1
+    __t__ below_1927 -1
+    __t__ below_1928 -1
+    __t__ below_1929 -1
+    __t__ below_1930 -1
+    __t__ below_1931 -1
+    __t__ below_1932 -1
+    __t__ below_1933 -1
+    __t__ below_1934 -1
+    __t__ below_1935 -1
+    __t__ below_1936 -1
+    __t__ below_1937 -1
+    __t__ below_1938 -1
+    __t__ below_1939 -1
+    __t__ below_1940 -1
+    __t__ below_1941 -1
+    __t__ below_1942 -1
+    __t__ below_1943 -1
+    __t__ below_1944 -1
+    __t__ below_1945 -1
+    __t__ below_1946 -1
+    __t__ below_1947 -1
+    __t__ below_1948 -1
+    __t__ below_1949 -1
+    __t__ below_1950 -1
+    __t__ below_1951 -1
+    __t__ below_1952 -1
+    __t__ below_1953 -1
+    __t__ below_1954 -1
+    __t__ below_1955 -1
+    __t__ below_1956 -1
+    __t__ below_1957 -1
+    __t__ below_1958 -1
+    __t__ below_1959 -1
+    __t__ below_1960 -1
+    __t__ below_1961 -1
+    __t__ below_1962 -1
+    __t__ below_1963 -1
+    __t__ below_1964 -1
+    __t__ below_1965 -1
+    __t__ below_1966 -1
+    __t__ below_1967 -1
+    __t__ below_1968 -1
+    __t__ below_1969 -1
+    __t__ below_1970 -1
+    __t__ below_1971 -1
+    __t__ below_1972 -1
+    __t__ below_1973 -1
+    __t__ below_1974 -1
+    __t__ below_1975 -1
+    __t__ below_1976 -1
+    __t__ below_1977 -1
+    __t__ below_1978 -1
+    __t__ below_1979 -1
+    __t__ below_1980 -1
+    __t__ below_1981 -1
+    __t__ below_1982 -1
+    __t__ below_1983 -1
+    __t__ below_1984 -1
+    __t__ below_1985 -1
+    __t__ below_1986 -1
+    __t__ below_1987 -1
+    __t__ below_1988 -1
+    __t__ below_1989 -1
+    __t__ below_1990 -1
+    __t__ below_1991 -1
+    __t__ below_1992 -1
+    __t__ below_1993 -1
+    __t__ below_1994 -1
+    __t__ below_1995 -1
+    __t__ below_1996 -1
+    __t__ below_1997 -1
+    __t__ below_1998 -1
+    __t__ below_1999 -1
+    __t__ below_2000 -1
+RHS
+    RHS above_1800 124
+    RHS above_1801 137
+    RHS above_1802 126
+    RHS above_1803 129
+    RHS above_1804 139
+    RHS above_1805 123
+    RHS above_1806 156
+    RHS above_1807 146
+    RHS above_1808 153
+    RHS above_1809 163
+    RHS above_1810 168
+    RHS above_1811 151
+    RHS above_1812 174
+    RHS above_1813 168
+    RHS above_1814 172
+    RHS above_1815 182
+    RHS above_1816 194
+    RHS above_1817 196
+    RHS above_1818 185
+    RHS above_1819 202
+    RHS above_1820 202
+    RHS above_1821 210
+    RHS above_1822 208
+    RHS above_1823 226
+    RHS above_1824 227
+    RHS above_1825 235
+    RHS above_1826 253
+    RHS above_1827 226
+    RHS above_1828 236
+    RHS above_1829 242
+    RHS above_1830 232
+    RHS above_1831 254
+    RHS above_1832 273
+    RHS above_1833 243
+    RHS above_1834 245
+    RHS above_1835 279
+    RHS above_1836 278
+    RHS above_1837 275
+    RHS above_1838 273
+    RHS above_1839 249
+    RHS above_1840 276
+    RHS above_1841 265
+    RHS above_1842 293
+    RHS above_1843 281
+    RHS above_1844 285
+    RHS above_1845 283
+    RHS above_1846 318
+    RHS above_1847 315
+    RHS above_1848 292
+    RHS above_1849 284
+    RHS above_1850 330
+    RHS above_1851 317
+    RHS above_1852 311
+    RHS above_1853 339
+    RHS above_1854 289
+    RHS above_1855 332
+    RHS above_1856 321
+    RHS above_1857 312
+    RHS above_1858 339
+    RHS above_1859 337
+    RHS above_1860 340
+    RHS above_1861 352
+    RHS above_1862 311
+    RHS above_1863 334
+    RHS above_1864 333
+    RHS above_1865 359
+    RHS above_1866 318
+    RHS above_1867 371
+    RHS above_1868 350
+    RHS above_1869 366
+    RHS above_1870 340
+    RHS above_1871 320
+    RHS above_1872 344
+    RHS above_1873 342
+    RHS above_1874 356
+    RHS above_1875 350
+    RHS above_1876 349
+    RHS above_1877 321
+    RHS above_1878 342
+    RHS above_1879 353
+    RHS above_1880 364
+    RHS above_1881 312
+    RHS above_1882 372
+    RHS above_1883 369
+    RHS above_1884 360
+    RHS above_1885 350
+    RHS above_1886 365
+    RHS above_1887 386
+    RHS above_1888 401
+    RHS above_1889 374
+    RHS above_1890 345
+    RHS above_1891 391
+    RHS above_1892 407
+    RHS above_1893 351
+    RHS above_1894 331
+    RHS above_1895 342
+    RHS above_1896 353
+    RHS above_1897 390
+    RHS above_1898 361
+    RHS above_1899 351
+    RHS above_1900 348
+    RHS above_1901 333
+    RHS above_1902 350
+    RHS above_1903 366
+    RHS above_1904 379
+    RHS above_1905 365
+    RHS above_1906 367
+    RHS above_1907 365
+    RHS above_1908 399
+    RHS above_1909 345
+    RHS above_1910 369
+    RHS above_1911 407
+    RHS above_1912 384
+    RHS above_1913 342
+    RHS above_1914 366
+    RHS above_1915 395
+    RHS above_1916 373
+    RHS above_1917 370
+    RHS above_1918 367
+    RHS above_1919 372
+    RHS above_1920 355
+    RHS above_1921 364
+    RHS above_1922 401
+    RHS above_1923 403
+    RHS above_1924 353
+    RHS above_1925 387
+    RHS above_1926 371
+    RHS above_1927 370
+    RHS above_1928 385
+    RHS above_1929 397
+    RHS above_1930 385
+    RHS above_1931 411
+    RHS above_1932 340
+    RHS above_1933 376
+    RHS above_1934 345
+    RHS above_1935 392
+    RHS above_1936 411
+    RHS above_1937 363
+    RHS above_1938 430
+    RHS above_1939 384
+    RHS above_1940 404
+    RHS above_1941 395
+    RHS above_1942 434
+    RHS above_1943 413
+    RHS above_1944 421
+    RHS above_1945 422
+    RHS above_1946 418
+    RHS above_1947 406
+    RHS above_1948 409
+    RHS above_1949 408
+    RHS above_1950 425
+    RHS above_1951 431
+    RHS above_1952 399
+    RHS above_1953 397
+    RHS above_1954 397
+    RHS above_1955 426
+    RHS above_1956 396
+    RHS above_1957 451
+    RHS above_1958 439
+    RHS above_1959 399
+    RHS above_1960 447
+    RHS above_1961 484
+    RHS above_1962 428
+    RHS above_1963 444
+    RHS above_1964 444
+    RHS above_1965 411
+    RHS above_1966 437
+    RHS above_1967 420
+    RHS above_1968 441
+    RHS above_1969 466
+    RHS above_1970 503
+    RHS above_1971 483
+    RHS above_1972 445
+    RHS above_1973 508
+    RHS above_1974 513
+    RHS above_1975 483
+    RHS above_1976 477
+    RHS above_1977 531
+    RHS above_1978 526
+    RHS above_1979 485
+    RHS above_1980 505
+    RHS above_1981 505
+    RHS above_1982 548
+    RHS above_1983 478
+    RHS above_1984 584
+    RHS above_1985 509
+    RHS above_1986 507
+    RHS above_1987 513
+    RHS above_1988 524
+    RHS above_1989 527
+    RHS above_1990 547
+    RHS above_1991 566
+    RHS above_1992 516
+    RHS above_1993 555
+    RHS above_1994 516
+    RHS above_1995 563
+    RHS above_1996 493
+    RHS above_1997 565
+    RHS above_1998 553
+    RHS above_1999 491
+    RHS above_2000 549
+    RHS below_1800 -124
+    RHS below_1801 -137
+    RHS below_1802 -126
+    RHS below_1803 -129
+    RHS below_1804 -139
+    RHS below_1805 -123
+    RHS below_1806 -156
+    RHS below_1807 -146
+    RHS below_1808 -153
+    RHS below_1809 -163
+    RHS below_1810 -168
+    RHS below_1811 -151
+    RHS below_1812 -174
+    RHS below_1813 -168
+    RHS below_1814 -172
+    RHS below_1815 -182
+    RHS below_1816 -194
+    RHS below_1817 -196
+    RHS below_1818 -185
+    RHS below_1819 -202
+    RHS below_1820 -202
+    RHS below_1821 -210
+    RHS below_1822 -208
+    RHS below_1823 -226
+    RHS below_1824 -227
+    RHS below_1825 -235
+    RHS below_1826 -253
+    RHS below_1827 -226
+    RHS below_1828 -236
+    RHS below_1829 -242
+    RHS below_1830 -232
+    RHS below_1831 -254
+    RHS below_1832 -273
+    RHS below_1833 -243
+    RHS below_1834 -245
+    RHS below_1835 -279
+    RHS below_1836 -278
+    RHS below_1837 -275
+    RHS below_1838 -273
+    RHS below_1839 -249
+    RHS below_1840 -276
+    RHS below_1841 -265
+    RHS below_1842 -293
+    RHS below_1843 -281
+    RHS below_1844 -285
+    RHS below_1845 -283
+    RHS below_1846 -318
+    RHS below_1847 -315
+    RHS below_1848 -292
+    RHS below_1849 -284
+    RHS below_1850 -330
+    RHS below_1851 -317
+    RHS below_1852 -311
+    RHS below_1853 -339
+    RHS below_1854 -289
+    RHS below_1855 -332
+    RHS below_1856 -321
+    RHS below_1857 -312
+    RHS below_1858 -339
+    RHS below_1859 -337
+    RHS below_1860 -340
+    RHS below_1861 -352
+    RHS below_1862 -311
+    RHS below_1863 -334
+    RHS below_1864 -333
+    RHS below_1865 -359
+    RHS below_1866 -318
+    RHS below_1867 -371
+    RHS below_1868 -350
+    RHS below_1869 -366
+    RHS below_1870 -340
+    RHS below_1871 -320
+    RHS below_1872 -344
+    RHS below_1873 -342
+    RHS below_1874 -356
+    RHS below_1875 -350
+    RHS below_1876 -349
+    RHS below_1877 -321
+    RHS below_1878 -342
+    RHS below_1879 -353
+    RHS below_1880 -364
+    RHS below_1881 -312
+    RHS below_1882 -372
+    RHS below_1883 -369
+    RHS below_1884 -360
+    RHS below_1885 -350
+    RHS below_1886 -365
+    RHS below_1887 -386
+    RHS below_1888 -401
+    RHS below_1889 -374
+    RHS below_1890 -345
+    RHS below_1891 -391
+    RHS below_1892 -407
+    RHS below_1893 -351
+    RHS below_1894 -331
+    RHS below_1895 -342
+    RHS below_1896 -353
+    RHS below_1897 -390
+    RHS below_1898 -361
+    RHS below_1899 -351
+    RHS below_1900 -348
+    RHS below_1901 -333
+    RHS below_1902 -350
+    RHS below_1903 -366
+    RHS below_1904 -379
+    RHS below_1905 -365
+    RHS below_1906 -367
+    RHS below_1907 -365
+    RHS below_1908 -399
+    RHS below_1909 -345
+    RHS below_1910 -369
+    RHS below_1911 -407
+    RHS below_1912 -384
+    RHS below_1913 -342
+    RHS below_1914 -366
+    RHS below_1915 -395
+    RHS below_1916 -373
+    RHS below_1917 -370
+    RHS below_1918 -367
+    RHS below_1919 -372
+    RHS below_1920 -355
+    RHS below_1921 -364
+    RHS below_1922 -401
+    RHS below_1923 -403
+    RHS below_1924 -353
+    RHS below_1925 -387
+    RHS below_1926 -371
+    RHS below_1927 -370
+    RHS below_1928 -385
+    RHS below_1929 -397
+    RHS below_1930 -385
+    RHS below_1931 -411
+    RHS below_1932 -340
+    RHS below_1933 -376
+    RHS below_1934 -345
+    RHS below_1935 -392
+    RHS below_1936 -411
+    RHS below_1937 -363
+    RHS below_1938 -430
+    RHS below_1939 -384
+    RHS below_1940 -404
+    RHS below_1941 -395
+    RHS below_1942 -434
+    RHS below_1943 -413
+    RHS below_1944 -421
+    RHS below_1945 -422
+    RHS below_1946 -418
+    RHS below_1947 -406
+    RHS below_1948 -409
+    RHS below_1949 -408
+    RHS below_1950 -425
+    RHS below_1951 -431
+    RHS below_1952 -399
+    RHS below_1953 -397
+    RHS below_1954 -397
+    RHS below_1955 -426
+    RHS below_1956 -396
+    RHS below_1957 -451
+    RHS below_1958 -439
+    RHS below_1959 -399
+    RHS below_1960 -447
+    RHS below_1961 -484
+    RHS below_1962 -428
+    RHS below_1963 -444
+    RHS below_1964 -444
+    RHS below_1965 -411
+    RHS below_1966 -437
+    RHS below_1967 -420
+    RHS below_1968 -441
+    RHS below_1969 -466
+    RHS below_1970 -503
+    RHS below_1971 -483
+    RHS below_1972 -445
+    RHS below_1973 -508
+    RHS below_1974 -513
+    RHS below_1975 -483
+    RHS below_1976 -477
+    RHS below_1977 -531
+    RHS below_1978 -526
+    RHS below_1979 -485
+    RHS below_1980 -505
+    RHS below_1981 -505
+    RHS below_1982 -548
+    RHS below_1983 -478
+    RHS below_1984 -584
+    RHS below_1985 -509
+    RHS below_1986 -507
+    RHS below_1987 -513
+    RHS below_1988 -524
+    RHS below_1989 -527
+    RHS below_1990 -547
+    RHS below_1991 -566
+    RHS below_1992 -516
+    RHS below_1993 -555
+    RHS below_1994 -516
+    RHS below_1995 -563
+    RHS below_1996 -493
+    RHS below_1997 -565
+    RHS below_1998 -553
+    RHS below_1999 -491
+    RHS below_2000 -549
+BOUNDS
+ENDATA
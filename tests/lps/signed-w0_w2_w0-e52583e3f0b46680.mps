@@ -0,0 +1,2423 @@
+NAME w0_w2_w0-e52583e3f0b46680
+ROWS
+ N obj
+ L above_1800
+ L above_1801
+ L above_1802
+ L above_1803
+ L above_1804
+ L above_1805
+ L above_1806
+ L above_1807
+ L above_1808
+ L above_1809
+ L above_1810
+ L above_1811
+ L above_1812
+ L above_1813
+ L above_1814
+ L above_1815
+ L above_1816
+ L above_1817
+ L above_1818
+ L above_1819
+ L above_1820
+ L above_1821
+ L above_1822
+ L above_1823
+ L above_1824
+ L above_1825
+ L above_1826
+ L above_1827
+ L above_1828
+ L above_1829
+ L above_1830
+ L above_1831
+ L above_1832
+ L above_1833
+ L above_1834
+ L above_1835
+ L above_1836
+ L above_1837
+ L above_1838
+ L above_1839
+ L above_1840
+ L above_1841
+ L above_1842
+ L above_1843
+ L above_1844
+ L above_1845
+ L above_1846
+ L above_1847
+ L above_1848
+ L above_1849
+ L above_1850
+ L above_1851
+ L above_1852
+ L above_1853
+ L above_1854
+ L above_1855
+ L above_1856
+ L above_1857
+ L above_1858
+ L above_1859
+ L above_1860
+ L above_1861
+ L above_1862
+ L above_1863
+ L above_1864
+ L above_1865
+ L above_1866
+ L above_1867
+ L above_1868
+ L above_1869
+ L above_1870
+ L above_1871
+ L above_1872
+ L above_1873
+ L above_1874
+ L above_1875
+ L above_1876
+ L above_1877
+ L above_1878
+ L above_1879
+ L above_1880
+ L above_1881
+ L above_1882
+ L above_1883
+ L above_1884
+ L above_1885
+ L above_1886
+ L above_1887
+ L above_1888
+ L above_1889
+ L above_1890
+ L above_1891
+ L above_1892
+ L above_1893
+ L above_1894
+ L above_1895
+ L above_1896
+ L above_1897
+ L above_1898
+ L above_1899
+ L above_1900
+ L above_1901
+ L above_1902
+ L above_1903
+ L above_1904
+ L above_1905
+ L above_1906
+ L above_1907
+ L above_1908
+ L above_1909
+ L above_1910
+ L above_1911
+ L above_1912
+ L above_1913
+ L above_1914
+ L above_1915
+ L above_1916
+ L above_1917
+ L above_1918
+ L above_1919
+ L above_1920
+ L above_1921
+ L above_1922
+ L above_1923
+ L above_1924
+ L above_1925
+ L above_1926
+ L above_1927
+ L above_1928
+ L above_1929
+ L above_1930
+ L above_1931
+ L above_1932
+ L above_1933
+ L above_1934
+ L above_1935
+ L above_1936
+ L above_1937
+ L above_1938
+ L above_1939
+ L above_1940
+ L above_1941
+ L above_1942
+ L above_1943
+ L above_1944
+ L above_1945
+ L above_1946
+ L above_1947
+ L above_1948
+ L above_1949
+ L above_1950
+ L above_1951
+ L above_1952
+ L above_1953
+ L above_1954
+ L above_1955
+ L above_1956
+ L above_1957
+ L above_1958
+ L above_1959
+ L above_1960
+ L above_1961
+ L above_1962
+ L above_1963
+ L above_1964
+ L above_1965
+ L above_1966
+ L above_1967
+ L above_1968
+ L above_1969
+ L above_1970
+ L above_1971
+ L above_1972
+ L above_1973
+ L above_1974
+ L above_1975
+ L above_1976
+ L above_1977
+ L above_1978
+ L above_1979
+ L above_1980
+ L above_1981
+ L above_1982
+ L above_1983
+ L above_1984
+ L above_1985
+ L above_1986
+ L above_1987
+ L above_1988
+ L above_1989
+ L above_1990
+ L above_1991
+ L above_1992
+ L above_1993
+ L above_1994
+ L above_1995
+ L above_1996
+ L above_1997
+ L above_1998
+ L above_1999
+ L above_2000
+ L below_1800
+ L below_1801
+ L below_1802
+ L below_1803
+ L below_1804
+ L below_1805
+ L below_1806
+ L below_1807
+ L below_1808
+ L below_1809
+ L below_1810
+ L below_1811
+ L below_1812
+ L below_1813
+ L below_1814
+ L below_1815
+ L below_1816
+ L below_1817
+ L below_1818
+ L below_1819
+ L below_1820
+ L below_1821
+ L below_1822
+ L below_1823
+ L below_1824
+ L below_1825
+ L below_1826
+ L below_1827
+ L below_1828
+ L below_1829
+ L below_1830
+ L below_1831
+ L below_1832
+ L below_1833
+ L below_1834
+ L below_1835
+ L below_1836
+ L below_1837
+ L below_1838
+ L below_1839
+ L below_1840
+ L below_1841
+ L below_1842
+ L below_1843
+ L below_1844
+ L below_1845
+ L below_1846
+ L below_1847
+ L below_1848
+ L below_1849
+ L below_1850
+ L below_1851
+ L below_1852
+ L below_1853
+ L below_1854
+ L below_1855
+ L below_1856
+ L below_1857
+ L below_1858
+ L below_1859
+ L below_1860
+ L below_1861
+ L below_1862
+ L below_1863
+ L below_1864
+ L below_1865
+ L below_1866
+ L below_1867
+ L below_1868
+ L below_1869
+ L below_1870
+ L below_1871
+ L below_1872
+ L below_1873
+ L below_1874
+ L below_1875
+ L below_1876
+ L below_1877
+ L below_1878
+ L below_1879
+ L below_1880
+ L below_1881
+ L below_1882
+ L below_1883
+ L below_1884
+ L below_1885
+ L below_1886
+ L below_1887
+ L below_1888
+ L below_1889
+ L below_1890
+ L below_1891
+ L below_1892
+ L below_1893
+ L below_1894
+ L below_1895
+ L below_1896
+ L below_1897
+ L below_1898
+ L below_1899
+ L below_1900
+ L below_1901
+ L below_1902
+ L below_1903
+ L below_1904
+ L below_1905
+ L below_1906
+ L below_1907
+ L below_1908
+ L below_1909
+ L below_1910
+ L below_1911
+ L below_1912
+ L below_1913
+ L below_1914
+ L below_1915
+ L below_1916
+ L below_1917
+ L below_1918
+ L below_1919
+ L below_1920
+ L below_1921
+ L below_1922
+ L below_1923
+ L below_1924
+ L below_1925
+ L below_1926
+ L below_1927
+ L below_1928
+ L below_1929
+ L below_1930
+ L below_1931
+ L below_1932
+ L below_1933
+ L below_1934
+ L below_1935
+ L below_1936
+ L below_1937
+ L below_1938
+ L below_1939
+ L below_1940
+ L below_1941
+ L below_1942
+ L below_1943
+ L below_1944
+ L below_1945
+ L below_1946
+ L below_1947
+ L below_1948
+ L below_1949
+ L below_1950
+ L below_1951
+ L below_1952
+ L below_1953
+ L below_1954
+ L below_1955
+ L below_1956
+ L below_1957
+ L below_1958
+ L below_1959
+ L below_1960
+ L below_1961
+ L below_1962
+ L below_1963
+ L below_1964
+ L below_1965
+ L below_1966
+ L below_1967
+ L below_1968
+ L below_1969
+ L below_1970
+ L below_1971
+ L below_1972
+ L below_1973
+ L below_1974
+ L below_1975
+ L below_1976
+ L below_1977
+ L below_1978
+ L below_1979
+ L below_1980
+ L below_1981
+ L below_1982
+ L below_1983
+ L below_1984
+ L below_1985
+ L below_1986
+ L below_1987
+ L below_1988
+ L below_1989
+ L below_1990
+ L below_1991
+ L below_1992
+ L below_1993
+ L below_1994
+ L below_1995
+ L below_1996
+ L below_1997
+ L below_1998
+ L below_1999
+ L below_2000
+COLUMNS
+    w0 above_1800 51422
+    w0 above_1801 49243
+    w0 above_1802 51632
+    w0 above_1803 56497
+    w0 above_1804 62163
+    w0 above_1805 54862
+    w0 above_1806 60946
+    w0 above_1807 57189
+    w0 above_1808 62041
+    w0 above_1809 60099
+    w0 above_1810 67048
+    w0 above_1811 66630
+    w0 above_1812 68430
+    w0 above_1813 66835
+    w0 above_1814 68792
+    w0 above_1815 73721
+    w0 above_1816 72991
+    w0 above_1817 74461
+    w0 above_1818 70588
+    w0 above_1819 72598
+    w0 above_1820 70313
+    w0 above_1821 80058
+    w0 above_1822 79982
+    w0 above_1823 83562
+    w0 above_1824 78857
+    w0 above_1825 84733
+    w0 above_1826 84533
+    w0 above_1827 86684
+    w0 above_1828 92440
+    w0 above_1829 90404
+    w0 above_1830 99023
+    w0 above_1831 95010
+    w0 above_1832 89504
+    w0 above_1833 96055
+    w0 above_1834 101847
+    w0 above_1835 99440
+    w0 above_1836 97118
+    w0 above_1837 98861
+    w0 above_1838 107170
+    w0 above_1839 117222
+    w0 above_1840 100027
+    w0 above_1841 104022
+    w0 above_1842 110583
+    w0 above_1843 120174
+    w0 above_1844 123051
+    w0 above_1845 114845
+    w0 above_1846 115882
+    w0 above_1847 118138
+    w0 above_1848 126206
+    w0 above_1849 132716
+    w0 above_1850 126038
+    w0 above_1851 125001
+    w0 above_1852 119535
+    w0 above_1853 136287
+    w0 above_1854 123481
+    w0 above_1855 129220
+    w0 above_1856 132467
+    w0 above_1857 129788
+    w0 above_1858 137362
+    w0 above_1859 142732
+    w0 above_1860 138160
+    w0 above_1861 131516
+    w0 above_1862 150514
+    w0 above_1863 143334
+    w0 above_1864 143985
+    w0 above_1865 141525
+    w0 above_1866 167136
+    w0 above_1867 147658
+    w0 above_1868 156346
+    w0 above_1869 160966
+    w0 above_1870 146669
+    w0 above_1871 159061
+    w0 above_1872 160795
+    w0 above_1873 157895
+    w0 above_1874 167156
+    w0 above_1875 177177
+    w0 above_1876 168586
+    w0 above_1877 161651
+    w0 above_1878 153783
+    w0 above_1879 164740
+    w0 above_1880 170504
+    w0 above_1881 193250
+    w0 above_1882 168093
+    w0 above_1883 175926
+    w0 above_1884 170076
+    w0 above_1885 174629
+    w0 above_1886 173507
+    w0 above_1887 190112
+    w0 above_1888 200848
+    w0 above_1889 185130
+    w0 above_1890 187402
+    w0 above_1891 199916
+    w0 above_1892 197980
+    w0 above_1893 186324
+    w0 above_1894 189516
+    w0 above_1895 208523
+    w0 above_1896 207543
+    w0 above_1897 208976
+    w0 above_1898 199778
+    w0 above_1899 199030
+    w0 above_1900 220860
+    w0 above_1901 208072
+    w0 above_1902 232937
+    w0 above_1903 212698
+    w0 above_1904 219674
+    w0 above_1905 197633
+    w0 above_1906 220700
+    w0 above_1907 202257
+    w0 above_1908 210581
+    w0 above_1909 212066
+    w0 above_1910 219758
+    w0 above_1911 203996
+    w0 above_1912 214462
+    w0 above_1913 235181
+    w0 above_1914 234427
+    w0 above_1915 216230
+    w0 above_1916 209333
+    w0 above_1917 242237
+    w0 above_1918 250679
+    w0 above_1919 232106
+    w0 above_1920 221059
+    w0 above_1921 240767
+    w0 above_1922 224484
+    w0 above_1923 227616
+    w0 above_1924 243338
+    w0 above_1925 243747
+    w0 above_1926 247575
+    w0 above_1927 246773
+    w0 above_1928 224087
+    w0 above_1929 253617
+    w0 above_1930 249345
+    w0 above_1931 254756
+    w0 above_1932 246453
+    w0 above_1933 285284
+    w0 above_1934 262248
+    w0 above_1935 246708
+    w0 above_1936 257176
+    w0 above_1937 269376
+    w0 above_1938 251680
+    w0 above_1939 254913
+    w0 above_1940 253498
+    w0 above_1941 280108
+    w0 above_1942 264013
+    w0 above_1943 260556
+    w0 above_1944 237400
+    w0 above_1945 268362
+    w0 above_1946 256755
+    w0 above_1947 266028
+    w0 above_1948 277912
+    w0 above_1949 292117
+    w0 above_1950 268199
+    w0 above_1951 261424
+    w0 above_1952 259563
+    w0 above_1953 256153
+    w0 above_1954 283437
+    w0 above_1955 285567
+    w0 above_1956 289467
+    w0 above_1957 298888
+    w0 above_1958 301542
+    w0 above_1959 306129
+    w0 above_1960 290193
+    w0 above_1961 258337
+    w0 above_1962 289324
+    w0 above_1963 243394
+    w0 above_1964 291174
+    w0 above_1965 297056
+    w0 above_1966 281350
+    w0 above_1967 297824
+    w0 above_1968 292376
+    w0 above_1969 284537
+    w0 above_1970 279026
+    w0 above_1971 306382
+    w0 above_1972 290453
+    w0 above_1973 291454
+    w0 above_1974 315356
+    w0 above_1975 319830
+    w0 above_1976 308533
+    w0 above_1977 303503
+    w0 above_1978 293547
+    w0 above_1979 296333
+    w0 above_1980 305086
+    w0 above_1981 279551
+    w0 above_1982 296835
+    w0 above_1983 319476
+    w0 above_1984 286300
+    w0 above_1985 324819
+    w0 above_1986 331124
+    w0 above_1987 318146
+    w0 above_1988 277741
+    w0 above_1989 302499
+    w0 above_1990 294102
+    w0 above_1991 298467
+    w0 above_1992 320441
+    w0 above_1993 336698
+    w0 above_1994 317784
+    w0 above_1995 320671
+    w0 above_1996 348847
+    w0 above_1997 292322
+    w0 above_1998 308121
+    w0 above_1999 325227
+    w0 above_2000 298136
+    w0 below_1800 -51422
+    w0 below_1801 -49243
+    w0 below_1802 -51632
+    w0 below_1803 -56497
+    w0 below_1804 -62163
+    w0 below_1805 -54862
+    w0 below_1806 -60946
+    w0 below_1807 -57189
+    w0 below_1808 -62041
+    w0 below_1809 -60099
+    w0 below_1810 -67048
+    w0 below_1811 -66630
+    w0 below_1812 -68430
+    w0 below_1813 -66835
+    w0 below_1814 -68792
+    w0 below_1815 -73721
+    w0 below_1816 -72991
+    w0 below_1817 -74461
+    w0 below_1818 -70588
+    w0 below_1819 -72598
+    w0 below_1820 -70313
+    w0 below_1821 -80058
+    w0 below_1822 -79982
+    w0 below_1823 -83562
+    w0 below_1824 -78857
+    w0 below_1825 -84733
+    w0 below_1826 -84533
+    w0 below_1827 -86684
+    w0 below_1828 -92440
+    w0 below_1829 -90404
+    w0 below_1830 -99023
+    w0 below_1831 -95010
+    w0 below_1832 -89504
+    w0 below_1833 -96055
+    w0 below_1834 -101847
+    w0 below_1835 -99440
+    w0 below_1836 -97118
+    w0 below_1837 -98861
+    w0 below_1838 -107170
+    w0 below_1839 -117222
+    w0 below_1840 -100027
+    w0 below_1841 -104022
+    w0 below_1842 -110583
+    w0 below_1843 -120174
+    w0 below_1844 -123051
+    w0 below_1845 -114845
+    w0 below_1846 -115882
+    w0 below_1847 -118138
+    w0 below_1848 -126206
+    w0 below_1849 -132716
+    w0 below_1850 -126038
+    w0 below_1851 -125001
+    w0 below_1852 -119535
+    w0 below_1853 -136287
+    w0 below_1854 -123481
+    w0 below_1855 -129220
+    w0 below_1856 -132467
+    w0 below_1857 -129788
+    w0 below_1858 -137362
+    w0 below_1859 -142732
+    w0 below_1860 -138160
+    w0 below_1861 -131516
+    w0 below_1862 -150514
+    w0 below_1863 -143334
+    w0 below_1864 -143985
+    w0 below_1865 -141525
+    w0 below_1866 -167136
+    w0 below_1867 -147658
+    w0 below_1868 -156346
+    w0 below_1869 -160966
+    w0 below_1870 -146669
+    w0 below_1871 -159061
+    w0 below_1872 -160795
+    w0 below_1873 -157895
+    w0 below_1874 -167156
+    w0 below_1875 -177177
+    w0 below_1876 -168586
+    w0 below_1877 -161651
+    w0 below_1878 -153783
+    w0 below_1879 -164740
+    w0 below_1880 -170504
+    w0 below_1881 -193250
+    w0 below_1882 -168093
+    w0 below_1883 -175926
+    w0 below_1884 -170076
+    w0 below_1885 -174629
+    w0 below_1886 -173507
+    w0 below_1887 -190112
+    w0 below_1888 -200848
+    w0 below_1889 -185130
+    w0 below_1890 -187402
+    w0 below_1891 -199916
+    w0 below_1892 -197980
+    w0 below_1893 -186324
+    w0 below_1894 -189516
+    w0 below_1895 -208523
+    w0 below_1896 -207543
+    w0 below_1897 -208976
+    w0 below_1898 -199778
+    w0 below_1899 -199030
+    w0 below_1900 -220860
+    w0 below_1901 -208072
+    w0 below_1902 -232937
+    w0 below_1903 -212698
+    w0 below_1904 -219674
+    w0 below_1905 -197633
+    w0 below_1906 -220700
+    w0 below_1907 -202257
+    w0 below_1908 -210581
+    w0 below_1909 -212066
+    w0 below_1910 -219758
+    w0 below_1911 -203996
+    w0 below_1912 -214462
+    w0 below_1913 -235181
+    w0 below_1914 -234427
+    w0 below_1915 -216230
+    w0 below_1916 -209333
+    w0 below_1917 -242237
+    w0 below_1918 -250679
+    w0 below_1919 -232106
+    w0 below_1920 -221059
+    w0 below_1921 -240767
+    w0 below_1922 -224484
+    w0 below_1923 -227616
+    w0 below_1924 -243338
+    w0 below_1925 -243747
+    w0 below_1926 -247575
+    w0 below_1927 -246773
+    w0 below_1928 -224087
+    w0 below_1929 -253617
+    w0 below_1930 -249345
+    w0 below_1931 -254756
+    w0 below_1932 -246453
+    w0 below_1933 -285284
+    w0 below_1934 -262248
+    w0 below_1935 -246708
+    w0 below_1936 -257176
+    w0 below_1937 -269376
+    w0 below_1938 -251680
+    w0 below_1939 -254913
+    w0 below_1940 -253498
+    w0 below_1941 -280108
+    w0 below_1942 -264013
+    w0 below_1943 -260556
+    w0 below_1944 -237400
+    w0 below_1945 -268362
+    w0 below_1946 -256755
+    w0 below_1947 -266028
+    w0 below_1948 -277912
+    w0 below_1949 -292117
+    w0 below_1950 -268199
+    w0 below_1951 -261424
+    w0 below_1952 -259563
+    w0 below_1953 -256153
+    w0 below_1954 -283437
+    w0 below_1955 -285567
+    w0 below_1956 -289467
+    w0 below_1957 -298888
+    w0 below_1958 -301542
+    w0 below_1959 -306129
+    w0 below_1960 -290193
+    w0 below_1961 -258337
+    w0 below_1962 -289324
+    w0 below_1963 -243394
+    w0 below_1964 -291174
+    w0 below_1965 -297056
+    w0 below_1966 -281350
+    w0 below_1967 -297824
+    w0 below_1968 -292376
+    w0 below_1969 -284537
+    w0 below_1970 -279026
+    w0 below_1971 -306382
+    w0 below_1972 -290453
+    w0 below_1973 -291454
+    w0 below_1974 -315356
+    w0 below_1975 -319830
+    w0 below_1976 -308533
+    w0 below_1977 -303503
+    w0 below_1978 -293547
+    w0 below_1979 -296333
+    w0 below_1980 -305086
+    w0 below_1981 -279551
+    w0 below_1982 -296835
+    w0 below_1983 -319476
+    w0 below_1984 -286300
+    w0 below_1985 -324819
+    w0 below_1986 -331124
+    w0 below_1987 -318146
+    w0 below_1988 -277741
+    w0 below_1989 -302499
+    w0 below_1990 -294102
+    w0 below_1991 -298467
+    w0 below_1992 -320441
+    w0 below_1993 -336698
+    w0 below_1994 -317784
+    w0 below_1995 -320671
+    w0 below_1996 -348847
+    w0 below_1997 -292322
+    w0 below_1998 -308121
+    w0 below_1999 -325227
+    w0 below_2000 -298136
+    w2 above_1800 13853
+    w2 above_1801 15982
+    w2 above_1802 15759
+    w2 above_1803 15640
+    w2 above_1804 15236
+    w2 above_1805 18045
+    w2 above_1806 20075
+    w2 above_1807 18956
+    w2 above_1808 17683
+    w2 above_1809 19143
+    w2 above_1810 18087
+    w2 above_1811 18337
+    w2 above_1812 19435
+    w2 above_1813 19389
+    w2 above_1814 22073
+    w2 above_1815 20597
+    w2 above_1816 21922
+    w2 above_1817 21949
+    w2 above_1818 22578
+    w2 above_1819 24842
+    w2 above_1820 23460
+    w2 above_1821 24048
+    w2 above_1822 26795
+    w2 above_1823 25487
+    w2 above_1824 26373
+    w2 above_1825 26842
+    w2 above_1826 25988
+    w2 above_1827 27164
+    w2 above_1828 26714
+    w2 above_1829 30294
+    w2 above_1830 32116
+    w2 above_1831 29416
+    w2 above_1832 30207
+    w2 above_1833 29370
+    w2 above_1834 32544
+    w2 above_1835 30845
+    w2 above_1836 31948
+    w2 above_1837 34197
+    w2 above_1838 34119
+    w2 above_1839 33298
+    w2 above_1840 34549
+    w2 above_1841 34396
+    w2 above_1842 34154
+    w2 above_1843 36467
+    w2 above_1844 30909
+    w2 above_1845 37359
+    w2 above_1846 37130
+    w2 above_1847 39123
+    w2 above_1848 34836
+    w2 above_1849 42054
+    w2 above_1850 38884
+    w2 above_1851 41299
+    w2 above_1852 39784
+    w2 above_1853 40614
+    w2 above_1854 44627
+    w2 above_1855 43519
+    w2 above_1856 37942
+    w2 above_1857 41910
+    w2 above_1858 44956
+    w2 above_1859 42939
+    w2 above_1860 44637
+    w2 above_1861 46333
+    w2 above_1862 46003
+    w2 above_1863 48273
+    w2 above_1864 43865
+    w2 above_1865 46329
+    w2 above_1866 39282
+    w2 above_1867 49745
+    w2 above_1868 50279
+    w2 above_1869 50593
+    w2 above_1870 54155
+    w2 above_1871 55559
+    w2 above_1872 44907
+    w2 above_1873 52763
+    w2 above_1874 52664
+    w2 above_1875 49792
+    w2 above_1876 51811
+    w2 above_1877 56449
+    w2 above_1878 52449
+    w2 above_1879 57265
+    w2 above_1880 55083
+    w2 above_1881 52029
+    w2 above_1882 54722
+    w2 above_1883 55580
+    w2 above_1884 54974
+    w2 above_1885 55173
+    w2 above_1886 59612
+    w2 above_1887 57577
+    w2 above_1888 61498
+    w2 above_1889 60045
+    w2 above_1890 62533
+    w2 above_1891 67244
+    w2 above_1892 65026
+    w2 above_1893 60706
+    w2 above_1894 61216
+    w2 above_1895 63095
+    w2 above_1896 64433
+    w2 above_1897 62533
+    w2 above_1898 63782
+    w2 above_1899 64004
+    w2 above_1900 66475
+    w2 above_1901 69948
+    w2 above_1902 72389
+    w2 above_1903 70570
+    w2 above_1904 72615
+    w2 above_1905 68629
+    w2 above_1906 73367
+    w2 above_1907 65883
+    w2 above_1908 70500
+    w2 above_1909 69231
+    w2 above_1910 72827
+    w2 above_1911 76979
+    w2 above_1912 77782
+    w2 above_1913 71993
+    w2 above_1914 78007
+    w2 above_1915 71073
+    w2 above_1916 73651
+    w2 above_1917 76479
+    w2 above_1918 76164
+    w2 above_1919 77590
+    w2 above_1920 77649
+    w2 above_1921 78734
+    w2 above_1922 71555
+    w2 above_1923 84090
+    w2 above_1924 78086
+    w2 above_1925 78883
+    w2 above_1926 85547
+    w2 above_1927 70906
+    w2 above_1928 83541
+    w2 above_1929 84162
+    w2 above_1930 82834
+    w2 above_1931 82658
+    w2 above_1932 83053
+    w2 above_1933 85684
+    w2 above_1934 91887
+    w2 above_1935 83102
+    w2 above_1936 83742
+    w2 above_1937 89388
+    w2 above_1938 86649
+    w2 above_1939 89553
+    w2 above_1940 93366
+    w2 above_1941 89699
+    w2 above_1942 88078
+    w2 above_1943 91106
+    w2 above_1944 91905
+    w2 above_1945 97969
+    w2 above_1946 87255
+    w2 above_1947 89784
+    w2 above_1948 92384
+    w2 above_1949 99105
+    w2 above_1950 93787
+    w2 above_1951 90608
+    w2 above_1952 95461
+    w2 above_1953 101991
+    w2 above_1954 87960
+    w2 above_1955 96900
+    w2 above_1956 93343
+    w2 above_1957 109947
+    w2 above_1958 91679
+    w2 above_1959 99059
+    w2 above_1960 96269
+    w2 above_1961 97985
+    w2 above_1962 96473
+    w2 above_1963 98136
+    w2 above_1964 98800
+    w2 above_1965 110786
+    w2 above_1966 99358
+    w2 above_1967 99537
+    w2 above_1968 99002
+    w2 above_1969 104761
+    w2 above_1970 94732
+    w2 above_1971 104148
+    w2 above_1972 109776
+    w2 above_1973 113500
+    w2 above_1974 106620
+    w2 above_1975 110995
+    w2 above_1976 99987
+    w2 above_1977 101987
+    w2 above_1978 94144
+    w2 above_1979 104945
+    w2 above_1980 98544
+    w2 above_1981 111215
+    w2 above_1982 102330
+    w2 above_1983 106760
+    w2 above_1984 110522
+    w2 above_1985 109949
+    w2 above_1986 109674
+    w2 above_1987 99802
+    w2 above_1988 109763
+    w2 above_1989 109579
+    w2 above_1990 100829
+    w2 above_1991 108796
+    w2 above_1992 111108
+    w2 above_1993 98905
+    w2 above_1994 110233
+    w2 above_1995 118355
+    w2 above_1996 103494
+    w2 above_1997 102473
+    w2 above_1998 105936
+    w2 above_1999 109264
+    w2 above_2000 113526
+    w2 below_1800 -13853
+    w2 below_1801 -15982
+    w2 below_1802 -15759
+    w2 below_1803 -15640
+    w2 below_1804 -15236
+    w2 below_1805 -18045
+    w2 below_1806 -20075
+    w2 below_1807 -18956
+    w2 below_1808 -17683
+    w2 below_1809 -19143
+    w2 below_1810 -18087
+    w2 below_1811 -18337
+    w2 below_1812 -19435
+    w2 below_1813 -19389
+    w2 below_1814 -22073
+    w2 below_1815 -20597
+    w2 below_1816 -21922
+    w2 below_1817 -21949
+    w2 below_1818 -22578
+    w2 below_1819 -24842
+    w2 below_1820 -23460
+    w2 below_1821 -24048
+    w2 below_1822 -26795
+    w2 below_1823 -25487
+    w2 below_1824 -26373
+    w2 below_1825 -26842
+    w2 below_1826 -25988
+    w2 below_1827 -27164
+    w2 below_1828 -26714
+    w2 below_1829 -30294
+    w2 below_1830 -32116
+    w2 below_1831 -29416
+    w2 below_1832 -30207
+    w2 below_1833 -29370
+    w2 below_1834 -32544
+    w2 below_1835 -30845
+    w2 below_1836 -31948
+    w2 below_1837 -34197
+    w2 below_1838 -34119
+    w2 below_1839 -33298
+    w2 below_1840 -34549
+    w2 below_1841 -34396
+    w2 below_1842 -34154
+    w2 below_1843 -36467
+    w2 below_1844 -30909
+    w2 below_1845 -37359
+    w2 below_1846 -37130
+    w2 below_1847 -39123
+    w2 below_1848 -34836
+    w2 below_1849 -42054
+    w2 below_1850 -38884
+    w2 below_1851 -41299
+    w2 below_1852 -39784
+    w2 below_1853 -40614
+    w2 below_1854 -44627
+    w2 below_1855 -43519
+    w2 below_1856 -37942
+    w2 below_1857 -41910
+    w2 below_1858 -44956
+    w2 below_1859 -42939
+    w2 below_1860 -44637
+    w2 below_1861 -46333
+    w2 below_1862 -46003
+    w2 below_1863 -48273
+    w2 below_1864 -43865
+    w2 below_1865 -46329
+    w2 below_1866 -39282
+    w2 below_1867 -49745
+    w2 below_1868 -50279
+    w2 below_1869 -50593
+    w2 below_1870 -54155
+    w2 below_1871 -55559
+    w2 below_1872 -44907
+    w2 below_1873 -52763
+    w2 below_1874 -52664
+    w2 below_1875 -49792
+    w2 below_1876 -51811
+    w2 below_1877 -56449
+    w2 below_1878 -52449
+    w2 below_1879 -57265
+    w2 below_1880 -55083
+    w2 below_1881 -52029
+    w2 below_1882 -54722
+    w2 below_1883 -55580
+    w2 below_1884 -54974
+    w2 below_1885 -55173
+    w2 below_1886 -59612
+    w2 below_1887 -57577
+    w2 below_1888 -61498
+    w2 below_1889 -60045
+    w2 below_1890 -62533
+    w2 below_1891 -67244
+    w2 below_1892 -65026
+    w2 below_1893 -60706
+    w2 below_1894 -61216
+    w2 below_1895 -63095
+    w2 below_1896 -64433
+    w2 below_1897 -62533
+    w2 below_1898 -63782
+    w2 below_1899 -64004
+    w2 below_1900 -66475
+    w2 below_1901 -69948
+    w2 below_1902 -72389
+    w2 below_1903 -70570
+    w2 below_1904 -72615
+    w2 below_1905 -68629
+    w2 below_1906 -73367
+    w2 below_1907 -65883
+    w2 below_1908 -70500
+    w2 below_1909 -69231
+    w2 below_1910 -72827
+    w2 below_1911 -76979
+    w2 below_1912 -77782
+    w2 below_1913 -71993
+    w2 below_1914 -78007
+    w2 below_1915 -71073
+    w2 below_1916 -73651
+    w2 below_1917 -76479
+    w2 below_1918 -76164
+    w2 below_1919 -77590
+    w2 below_1920 -77649
+    w2 below_1921 -78734
+    w2 below_1922 -71555
+    w2 below_1923 -84090
+    w2 below_1924 -78086
+    w2 below_1925 -78883
+    w2 below_1926 -85547
+    w2 below_1927 -70906
+    w2 below_1928 -83541
+    w2 below_1929 -84162
+    w2 below_1930 -82834
+    w2 below_1931 -82658
+    w2 below_1932 -83053
+    w2 below_1933 -85684
+    w2 below_1934 -91887
+    w2 below_1935 -83102
+    w2 below_1936 -83742
+    w2 below_1937 -89388
+    w2 below_1938 -86649
+    w2 below_1939 -89553
+    w2 below_1940 -93366
+    w2 below_1941 -89699
+    w2 below_1942 -88078
+    w2 below_1943 -91106
+    w2 below_1944 -91905
+    w2 below_1945 -97969
+    w2 below_1946 -87255
+    w2 below_1947 -89784
+    w2 below_1948 -92384
+    w2 below_1949 -99105
+    w2 below_1950 -93787
+    w2 below_1951 -90608
+    w2 below_1952 -95461
+    w2 below_1953 -101991
+    w2 below_1954 -87960
+    w2 below_1955 -96900
+    w2 below_1956 -93343
+    w2 below_1957 -109947
+    w2 below_1958 -91679
+    w2 below_1959 -99059
+    w2 below_1960 -96269
+    w2 below_1961 -97985
+    w2 below_1962 -96473
+    w2 below_1963 -98136
+    w2 below_1964 -98800
+    w2 below_1965 -110786
+    w2 below_1966 -99358
+    w2 below_1967 -99537
+    w2 below_1968 -99002
+    w2 below_1969 -104761
+    w2 below_1970 -94732
+    w2 below_1971 -104148
+    w2 below_1972 -109776
+    w2 below_1973 -113500
+    w2 below_1974 -106620
+    w2 below_1975 -110995
+    w2 below_1976 -99987
+    w2 below_1977 -101987
+    w2 below_1978 -94144
+    w2 below_1979 -104945
+    w2 below_1980 -98544
+    w2 below_1981 -111215
+    w2 below_1982 -102330
+    w2 below_1983 -106760
+    w2 below_1984 -110522
+    w2 below_1985 -109949
+    w2 below_1986 -109674
+    w2 below_1987 -99802
+    w2 below_1988 -109763
+    w2 below_1989 -109579
+    w2 below_1990 -100829
+    w2 below_1991 -108796
+    w2 below_1992 -111108
+    w2 below_1993 -98905
+    w2 below_1994 -110233
+    w2 below_1995 -118355
+    w2 below_1996 -103494
+    w2 below_1997 -102473
+    w2 below_1998 -105936
+    w2 below_1999 -109264
+    w2 below_2000 -113526
+    w2_w0 above_1800 1225
+    w2_w0 above_1801 1405
+    w2_w0 above_1802 1472
+    w2_w0 above_1803 1386
+    w2_w0 above_1804 1558
+    w2_w0 above_1805 1571
+    w2_w0 above_1806 1500
+    w2_w0 above_1807 1637
+    w2_w0 above_1808 1624
+    w2_w0 above_1809 1737
+    w2_w0 above_1810 1702
+    w2_w0 above_1811 1810
+    w2_w0 above_1812 1955
+    w2_w0 above_1813 1888
+    w2_w0 above_1814 1953
+    w2_w0 above_1815 1893
+    w2_w0 above_1816 1956
+    w2_w0 above_1817 2250
+    w2_w0 above_1818 2017
+    w2_w0 above_1819 2256
+    w2_w0 above_1820 2212
+    w2_w0 above_1821 2396
+    w2_w0 above_1822 2053
+    w2_w0 above_1823 2524
+    w2_w0 above_1824 2469
+    w2_w0 above_1825 2611
+    w2_w0 above_1826 2728
+    w2_w0 above_1827 2528
+    w2_w0 above_1828 2886
+    w2_w0 above_1829 2771
+    w2_w0 above_1830 2822
+    w2_w0 above_1831 3170
+    w2_w0 above_1832 2984
+    w2_w0 above_1833 3013
+    w2_w0 above_1834 3486
+    w2_w0 above_1835 3129
+    w2_w0 above_1836 3263
+    w2_w0 above_1837 3302
+    w2_w0 above_1838 3433
+    w2_w0 above_1839 3338
+    w2_w0 above_1840 3292
+    w2_w0 above_1841 3524
+    w2_w0 above_1842 3604
+    w2_w0 above_1843 3563
+    w2_w0 above_1844 3590
+    w2_w0 above_1845 3596
+    w2_w0 above_1846 3649
+    w2_w0 above_1847 4182
+    w2_w0 above_1848 3560
+    w2_w0 above_1849 3679
+    w2_w0 above_1850 4150
+    w2_w0 above_1851 3961
+    w2_w0 above_1852 3969
+    w2_w0 above_1853 3523
+    w2_w0 above_1854 4154
+    w2_w0 above_1855 4141
+    w2_w0 above_1856 4230
+    w2_w0 above_1857 4160
+    w2_w0 above_1858 4206
+    w2_w0 above_1859 3750
+    w2_w0 above_1860 4161
+    w2_w0 above_1861 4474
+    w2_w0 above_1862 4305
+    w2_w0 above_1863 4866
+    w2_w0 above_1864 4403
+    w2_w0 above_1865 4502
+    w2_w0 above_1866 4297
+    w2_w0 above_1867 4154
+    w2_w0 above_1868 4971
+    w2_w0 above_1869 4539
+    w2_w0 above_1870 4902
+    w2_w0 above_1871 5063
+    w2_w0 above_1872 4876
+    w2_w0 above_1873 4964
+    w2_w0 above_1874 4839
+    w2_w0 above_1875 4980
+    w2_w0 above_1876 5019
+    w2_w0 above_1877 5073
+    w2_w0 above_1878 5040
+    w2_w0 above_1879 4652
+    w2_w0 above_1880 5052
+    w2_w0 above_1881 4842
+    w2_w0 above_1882 4939
+    w2_w0 above_1883 4800
+    w2_w0 above_1884 5090
+    w2_w0 above_1885 5449
+    w2_w0 above_1886 5440
+    w2_w0 above_1887 6131
+    w2_w0 above_1888 4980
+    w2_w0 above_1889 5442
+    w2_w0 above_1890 5352
+    w2_w0 above_1891 5769
+    w2_w0 above_1892 5632
+    w2_w0 above_1893 6237
+    w2_w0 above_1894 5928
+    w2_w0 above_1895 5589
+    w2_w0 above_1896 5654
+    w2_w0 above_1897 6238
+    w2_w0 above_1898 5859
+    w2_w0 above_1899 5547
+    w2_w0 above_1900 6169
+    w2_w0 above_1901 5783
+    w2_w0 above_1902 6277
+    w2_w0 above_1903 6159
+    w2_w0 above_1904 5819
+    w2_w0 above_1905 5877
+    w2_w0 above_1906 6175
+    w2_w0 above_1907 6399
+    w2_w0 above_1908 6302
+    w2_w0 above_1909 6130
+    w2_w0 above_1910 6089
+    w2_w0 above_1911 6004
+    w2_w0 above_1912 6132
+    w2_w0 above_1913 6072
+    w2_w0 above_1914 5812
+    w2_w0 above_1915 6552
+    w2_w0 above_1916 6424
+    w2_w0 above_1917 7229
+    w2_w0 above_1918 6501
+    w2_w0 above_1919 6681
+    w2_w0 above_1920 6016
+    w2_w0 above_1921 6247
+    w2_w0 above_1922 6870
+    w2_w0 above_1923 6993
+    w2_w0 above_1924 6555
+    w2_w0 above_1925 6669
+    w2_w0 above_1926 6621
+    w2_w0 above_1927 7697
+    w2_w0 above_1928 6559
+    w2_w0 above_1929 7359
+    w2_w0 above_1930 7033
+    w2_w0 above_1931 7036
+    w2_w0 above_1932 6864
+    w2_w0 above_1933 7102
+    w2_w0 above_1934 7095
+    w2_w0 above_1935 7434
+    w2_w0 above_1936 6705
+    w2_w0 above_1937 7398
+    w2_w0 above_1938 7119
+    w2_w0 above_1939 7499
+    w2_w0 above_1940 7143
+    w2_w0 above_1941 7636
+    w2_w0 above_1942 7235
+    w2_w0 above_1943 8515
+    w2_w0 above_1944 8110
+    w2_w0 above_1945 7967
+    w2_w0 above_1946 7785
+    w2_w0 above_1947 8440
+    w2_w0 above_1948 7891
+    w2_w0 above_1949 8042
+    w2_w0 above_1950 7678
+    w2_w0 above_1951 7358
+    w2_w0 above_1952 8317
+    w2_w0 above_1953 8299
+    w2_w0 above_1954 8976
+    w2_w0 above_1955 8125
+    w2_w0 above_1956 7961
+    w2_w0 above_1957 8221
+    w2_w0 above_1958 8667
+    w2_w0 above_1959 8409
+    w2_w0 above_1960 9291
+    w2_w0 above_1961 7820
+    w2_w0 above_1962 8505
+    w2_w0 above_1963 8282
+    w2_w0 above_1964 8491
+    w2_w0 above_1965 9075
+    w2_w0 above_1966 8831
+    w2_w0 above_1967 9028
+    w2_w0 above_1968 8690
+    w2_w0 above_1969 8581
+    w2_w0 above_1970 8940
+    w2_w0 above_1971 8574
+    w2_w0 above_1972 8733
+    w2_w0 above_1973 9217
+    w2_w0 above_1974 8949
+    w2_w0 above_1975 8881
+    w2_w0 above_1976 9355
+    w2_w0 above_1977 9181
+    w2_w0 above_1978 8679
+    w2_w0 above_1979 9352
+    w2_w0 above_1980 9148
+    w2_w0 above_1981 9267
+    w2_w0 above_1982 9159
+    w2_w0 above_1983 9389
+    w2_w0 above_1984 9681
+    w2_w0 above_1985 8999
+    w2_w0 above_1986 9392
+    w2_w0 above_1987 9630
+    w2_w0 above_1988 9932
+    w2_w0 above_1989 9433
+    w2_w0 above_1990 9941
+    w2_w0 above_1991 10753
+    w2_w0 above_1992 10099
+    w2_w0 above_1993 10138
+    w2_w0 above_1994 9782
+    w2_w0 above_1995 11105
+    w2_w0 above_1996 9875
+    w2_w0 above_1997 9265
+    w2_w0 above_1998 9608
+    w2_w0 above_1999 10449
+    w2_w0 above_2000 9810
+    w2_w0 below_1800 -1225
+    w2_w0 below_1801 -1405
+    w2_w0 below_1802 -1472
+    w2_w0 below_1803 -1386
+    w2_w0 below_1804 -1558
+    w2_w0 below_1805 -1571
+    w2_w0 below_1806 -1500
+    w2_w0 below_1807 -1637
+    w2_w0 below_1808 -1624
+    w2_w0 below_1809 -1737
+    w2_w0 below_1810 -1702
+    w2_w0 below_1811 -1810
+    w2_w0 below_1812 -1955
+    w2_w0 below_1813 -1888
+    w2_w0 below_1814 -1953
+    w2_w0 below_1815 -1893
+    w2_w0 below_1816 -1956
+    w2_w0 below_1817 -2250
+    w2_w0 below_1818 -2017
+    w2_w0 below_1819 -2256
+    w2_w0 below_1820 -2212
+    w2_w0 below_1821 -2396
+    w2_w0 below_1822 -2053
+    w2_w0 below_1823 -2524
+    w2_w0 below_1824 -2469
+    w2_w0 below_1825 -2611
+    w2_w0 below_1826 -2728
+    w2_w0 below_1827 -2528
+    w2_w0 below_1828 -2886
+    w2_w0 below_1829 -2771
+    w2_w0 below_1830 -2822
+    w2_w0 below_1831 -3170
+    w2_w0 below_1832 -2984
+    w2_w0 below_1833 -3013
+    w2_w0 below_1834 -3486
+    w2_w0 below_1835 -3129
+    w2_w0 below_1836 -3263
+    w2_w0 below_1837 -3302
+    w2_w0 below_1838 -3433
+    w2_w0 below_1839 -3338
+    w2_w0 below_1840 -3292
+    w2_w0 below_1841 -3524
+    w2_w0 below_1842 -3604
+    w2_w0 below_1843 -3563
+    w2_w0 below_1844 -3590
+    w2_w0 below_1845 -3596
+    w2_w0 below_1846 -3649
+    w2_w0 below_1847 -4182
+    w2_w0 below_1848 -3560
+    w2_w0 below_1849 -3679
+    w2_w0 below_1850 -4150
+    w2_w0 below_1851 -3961
+    w2_w0 below_1852 -3969
+    w2_w0 below_1853 -3523
+    w2_w0 below_1854 -4154
+    w2_w0 below_1855 -4141
+    w2_w0 below_1856 -4230
+    w2_w0 below_1857 -4160
+    w2_w0 below_1858 -4206
+    w2_w0 below_1859 -3750
+    w2_w0 below_1860 -4161
+    w2_w0 below_1861 -4474
+    w2_w0 below_1862 -4305
+    w2_w0 below_1863 -4866
+    w2_w0 below_1864 -4403
+    w2_w0 below_1865 -4502
+    w2_w0 below_1866 -4297
+    w2_w0 below_1867 -4154
+    w2_w0 below_1868 -4971
+    w2_w0 below_1869 -4539
+    w2_w0 below_1870 -4902
+    w2_w0 below_1871 -5063
+    w2_w0 below_1872 -4876
+    w2_w0 below_1873 -4964
+    w2_w0 below_1874 -4839
+    w2_w0 below_1875 -4980
+    w2_w0 below_1876 -5019
+    w2_w0 below_1877 -5073
+    w2_w0 below_1878 -5040
+    w2_w0 below_1879 -4652
+    w2_w0 below_1880 -5052
+    w2_w0 below_1881 -4842
+    w2_w0 below_1882 -4939
+    w2_w0 below_1883 -4800
+    w2_w0 below_1884 -5090
+    w2_w0 below_1885 -5449
+    w2_w0 below_1886 -5440
+    w2_w0 below_1887 -6131
+    w2_w0 below_1888 -4980
+    w2_w0 below_1889 -5442
+    w2_w0 below_1890 -5352
+    w2_w0 below_1891 -5769
+    w2_w0 below_1892 -5632
+    w2_w0 below_1893 -6237
+    w2_w0 below_1894 -5928
+    w2_w0 below_1895 -5589
+    w2_w0 below_1896 -5654
+    w2_w0 below_1897 -6238
+    w2_w0 below_1898 -5859
+    w2_w0 below_1899 -5547
+    w2_w0 below_1900 -6169
+    w2_w0 below_1901 -5783
+    w2_w0 below_1902 -6277
+    w2_w0 below_1903 -6159
+    w2_w0 below_1904 -5819
+    w2_w0 below_1905 -5877
+    w2_w0 below_1906 -6175
+    w2_w0 below_1907 -6399
+    w2_w0 below_1908 -6302
+    w2_w0 below_1909 -6130
+    w2_w0 below_1910 -6089
+    w2_w0 below_1911 -6004
+    w2_w0 below_1912 -6132
+    w2_w0 below_1913 -6072
+    w2_w0 below_1914 -5812
+    w2_w0 below_1915 -6552
+    w2_w0 below_1916 -6424
+    w2_w0 below_1917 -7229
+    w2_w0 below_1918 -6501
+    w2_w0 below_1919 -6681
+    w2_w0 below_1920 -6016
+    w2_w0 below_1921 -6247
+    w2_w0 below_1922 -6870
+    w2_w0 below_1923 -6993
+    w2_w0 below_1924 -6555
+    w2_w0 below_1925 -6669
+    w2_w0 below_1926 -6621
+    w2_w0 below_1927 -7697
+    w2_w0 below_1928 -6559
+    w2_w0 below_1929 -7359
+    w2_w0 below_1930 -7033
+    w2_w0 below_1931 -7036
+    w2_w0 below_1932 -6864
+    w2_w0 below_1933 -7102
+    w2_w0 below_1934 -7095
+    w2_w0 below_1935 -7434
+    w2_w0 below_1936 -6705
+    w2_w0 below_1937 -7398
+    w2_w0 below_1938 -7119
+    w2_w0 below_1939 -7499
+    w2_w0 below_1940 -7143
+    w2_w0 below_1941 -7636
+    w2_w0 below_1942 -7235
+    w2_w0 below_1943 -8515
+    w2_w0 below_1944 -8110
+    w2_w0 below_1945 -7967
+    w2_w0 below_1946 -7785
+    w2_w0 below_1947 -8440
+    w2_w0 below_1948 -7891
+    w2_w0 below_1949 -8042
+    w2_w0 below_1950 -7678
+    w2_w0 below_1951 -7358
+    w2_w0 below_1952 -8317
+    w2_w0 below_1953 -8299
+    w2_w0 below_1954 -8976
+    w2_w0 below_1955 -8125
+    w2_w0 below_1956 -7961
+    w2_w0 below_1957 -8221
+    w2_w0 below_1958 -8667
+    w2_w0 below_1959 -8409
+    w2_w0 below_1960 -9291
+    w2_w0 below_1961 -7820
+    w2_w0 below_1962 -8505
+    w2_w0 below_1963 -8282
+    w2_w0 below_1964 -8491
+    w2_w0 below_1965 -9075
+    w2_w0 below_1966 -8831
+    w2_w0 below_1967 -9028
+    w2_w0 below_1968 -8690
+    w2_w0 below_1969 -8581
+    w2_w0 below_1970 -8940
+    w2_w0 below_1971 -8574
+    w2_w0 below_1972 -8733
+    w2_w0 below_1973 -9217
+    w2_w0 below_1974 -8949
+    w2_w0 below_1975 -8881
+    w2_w0 below_1976 -9355
+    w2_w0 below_1977 -9181
+    w2_w0 below_1978 -8679
+    w2_w0 below_1979 -9352
+    w2_w0 below_1980 -9148
+    w2_w0 below_1981 -9267
+    w2_w0 below_1982 -9159
+    w2_w0 below_1983 -9389
+    w2_w0 below_1984 -9681
+    w2_w0 below_1985 -8999
+    w2_w0 below_1986 -9392
+    w2_w0 below_1987 -9630
+    w2_w0 below_1988 -9932
+    w2_w0 below_1989 -9433
+    w2_w0 below_1990 -9941
+    w2_w0 below_1991 -10753
+    w2_w0 below_1992 -10099
+    w2_w0 below_1993 -10138
+    w2_w0 below_1994 -9782
+    w2_w0 below_1995 -11105
+    w2_w0 below_1996 -9875
+    w2_w0 below_1997 -9265
+    w2_w0 below_1998 -9608
+    w2_w0 below_1999 -10449
+    w2_w0 below_2000 -9810
+    __t__ obj 1
+    __t__ above_1800 -1
+    __t__ above_1801 -1
+    __t__ above_1802 -1
+    __t__ above_1803 -1
+    __t__ above_1804 -1
+    __t__ above_1805 -1
+    __t__ above_1806 -1
+    __t__ above_1807 -1
+    __t__ above_1808 -1
+    __t__ above_1809 -1
+    __t__ above_1810 -1
+    __t__ above_1811 -1
+    __t__ above_1812 -1
+    __t__ above_1813 -1
+    __t__ above_1814 -1
+    __t__ above_1815 -1
+    __t__ above_1816 -1
+    __t__ above_1817 -1
+    __t__ above_1818 -1
+    __t__ above_1819 -1
+    __t__ above_1820 -1
+    __t__ above_1821 -1
+    __t__ above_1822 -1
+    __t__ above_1823 -1
+    __t__ above_1824 -1
+    __t__ above_1825 -1
+    __t__ above_1826 -1
+    __t__ above_1827 -1
+    __t__ above_1828 -1
+    __t__ above_1829 -1
+    __t__ above_1830 -1
+    __t__ above_1831 -1
+    __t__ above_1832 -1
+    __t__ above_1833 -1
+    __t__ above_1834 -1
+    __t__ above_1835 -1
+    __t__ above_1836 -1
+    __t__ above_1837 -1
+    __t__ above_1838 -1
+    __t__ above_1839 -1
+    __t__ above_1840 -1
+    __t__ above_1841 -1
+    __t__ above_1842 -1
+    __t__ above_1843 -1
+    __t__ above_1844 -1
+    __t__ above_1845 -1
+    __t__ above_1846 -1
+    __t__ above_1847 -1
+    __t__ above_1848 -1
+    __t__ above_1849 -1
+    __t__ above_1850 -1
+    __t__ above_1851 -1
+    __t__ above_1852 -1
+    __t__ above_1853 -1
+    __t__ above_1854 -1
+    __t__ above_1855 -1
+    __t__ above_1856 -1
+    __t__ above_1857 -1
+    __t__ above_1858 -1
+    __t__ above_1859 -1
+    __t__ above_1860 -1
+    __t__ above_1861 -1
+    __t__ above_1862 -1
+    __t__ above_1863 -1
+    __t__ above_1864 -1
+    __t__ above_1865 -1
+    __t__ above_1866 -1
+    __t__ above_1867 -1
+    __t__ above_1868 -1
+    __t__ above_1869 -1
+    __t__ above_1870 -1
+    __t__ above_1871 -1
+    __t__ above_1872 -1
+    __t__ above_1873 -1
+    __t__ above_1874 -1
+    __t__ above_1875 -1
+    __t__ above_1876 -1
+    __t__ above_1877 -1
+    __t__ above_1878 -1
+    __t__ above_1879 -1
+    __t__ above_1880 -1
+    __t__ above_1881 -1
+    __t__ above_1882 -1
+    __t__ above_1883 -1
+    __t__ above_1884 -1
+    __t__ above_1885 -1
+    __t__ above_1886 -1
+    __t__ above_1887 -1
+    __t__ above_1888 -1
+    __t__ above_1889 -1
+    __t__ above_1890 -1
+    __t__ above_1891 -1
+    __t__ above_1892 -1
+    __t__ above_1893 -1
+    __t__ above_1894 -1
+    __t__ above_1895 -1
+    __t__ above_1896 -1
+    __t__ above_1897 -1
+    __t__ above_1898 -1
+    __t__ above_1899 -1
+    __t__ above_1900 -1
+    __t__ above_1901 -1
+    __t__ above_1902 -1
+    __t__ above_1903 -1
+    __t__ above_1904 -1
+    __t__ above_1905 -1
+    __t__ above_1906 -1
+    __t__ above_1907 -1
+    __t__ above_1908 -1
+    __t__ above_1909 -1
+    __t__ above_1910 -1
+    __t__ above_1911 -1
+    __t__ above_1912 -1
+    __t__ above_1913 -1
+    __t__ above_1914 -1
+    __t__ above_1915 -1
+    __t__ above_1916 -1
+    __t__ above_1917 -1
+    __t__ above_1918 -1
+    __t__ above_1919 -1
+    __t__ above_1920 -1
+    __t__ above_1921 -1
+    __t__ above_1922 -1
+    __t__ above_1923 -1
+    __t__ above_1924 -1
+    __t__ above_1925 -1
+    __t__ above_1926 -1
+    __t__ above_1927 -1
+    __t__ above_1928 -1
+    __t__ above_1929 -1
+    __t__ above_1930 -1
+    __t__ above_1931 -1
+    __t__ above_1932 -1
+    __t__ above_1933 -1
+    __t__ above_1934 -1
+    __t__ above_1935 -1
+    __t__ above_1936 -1
+    __t__ above_1937 -1
+    __t__ above_1938 -1
+    __t__ above_1939 -1
+    __t__ above_1940 -1
+    __t__ above_1941 -1
+    __t__ above_1942 -1
+    __t__ above_1943 -1
+    __t__ above_1944 -1
+    __t__ above_1945 -1
+    __t__ above_1946 -1
+    __t__ above_1947 -1
+    __t__ above_1948 -1
+    __t__ above_1949 -1
+    __t__ above_1950 -1
+    __t__ above_1951 -1
+    __t__ above_1952 -1
+    __t__ above_1953 -1
+    __t__ above_1954 -1
+    __t__ above_1955 -1
+    __t__ above_1956 -1
+    __t__ above_1957 -1
+    __t__ above_1958 -1
+    __t__ above_1959 -1
+    __t__ above_1960 -1
+    __t__ above_1961 -1
+    __t__ above_1962 -1
+    __t__ above_1963 -1
+    __t__ above_1964 -1
+    __t__ above_1965 -1
+    __t__ above_1966 -1
+    __t__ above_1967 -1
+    __t__ above_1968 -1
+    __t__ above_1969 -1
+    __t__ above_1970 -1
+    __t__ above_1971 -1
+    __t__ above_1972 -1
+    __t__ above_1973 -1
+    __t__ above_1974 -1
+    __t__ above_1975 -1
+    __t__ above_1976 -1
+    __t__ above_1977 -1
+    __t__ above_1978 -1
+    __t__ above_1979 -1
+    __t__ above_1980 -1
+    __t__ above_1981 -1
+    __t__ above_1982 -1
+    __t__ above_1983 -1
+    __t__ above_1984 -1
+    __t__ above_1985 -1
+    __t__ above_1986 -1
+    __t__ above_1987 -1
+    __t__ above_1988 -1
+    __t__ above_1989 -1
+    __t__ above_1990 -1
+    __t__ above_1991 -1
+    __t__ above_1992 -1
+    __t__ above_1993 -1
+    __t__ above_1994 -1
+    __t__ above_1995 -1
+    __t__ above_1996 -1
+    __t__ above_1997 -1
+    __t__ above_1998 -1
+    __t__ above_1999 -1
+    __t__ above_2000 -1
+    __t__ below_1800 -1
+    __t__ below_1801 -1
+    __t__ below_1802 -1
+    __t__ below_1803 -1
+    __t__ below_1804 -1
+    __t__ below_1805 -1
+    __t__ below_1806 -1
+    __t__ below_1807 -1
+    __t__ below_1808 -1
+    __t__ below_1809 -1
+    __t__ below_1810 -1
+    __t__ below_1811 -1
+    __t__ below_1812 -1
+    __t__ below_1813 -1
+    __t__ below_1814 -1
+    __t__ below_1815 -1
+    __t__ below_1816 -1
+    __t__ below_1817 -1
+    __t__ below_1818 -1
+    __t__ below_1819 -1
+    __t__ below_1820 -1
+    __t__ below_1821 -1
+    __t__ below_1822 -1
+    __t__ below_1823 -1
+    __t__ below_1824 -1
+    __t__ below_1825 -1
+    __t__ below_1826 -1
+    __t__ below_1827 -1
+    __t__ below_1828 -1
+    __t__ below_1829 -1
+    __t__ below_1830 -1
+    __t__ below_1831 -1
+    __t__ below_1832 -1
+    __t__ below_1833 -1
+    __t__ below_1834 -1
+    __t__ below_1835 -1
+    __t__ below_1836 -1
+    __t__ below_1837 -1
+    __t__ below_1838 -1
+    __t__ below_1839 -1
+    __t__ below_1840 -1
+    __t__ below_1841 -1
+    __t__ below_1842 -1
+    __t__ below_1843 -1
+    __t__ below_1844 -1
+    __t__ below_1845 -1
+    __t__ below_1846 -1
+    __t__ below_1847 -1
+    __t__ below_1848 -1
+    __t__ below_1849 -1
+    __t__ below_1850 -1
+    __t__ below_1851 -1
+    __t__ below_1852 -1
+    __t__ below_1853 -1
+    __t__ below_1854 -1
+    __t__ below_1855 -1
+    __t__ below_1856 -1
+    __t__ below_1857 -1
+    __t__ below_1858 -1
+    __t__ below_1859 -1
+    __t__ below_1860 -1
+    __t__ below_1861 -1
+    __t__ below_1862 -1
+    __t__ below_1863 -1
+    __t__ below_1864 -1
+    __t__ below_1865 -1
+    __t__ below_1866 -1
+    __t__ below_1867 -1
+    __t__ below_1868 -1
+    __t__ below_1869 -1
+    __t__ below_1870 -1
+    __t__ below_1871 -1
+    __t__ below_1872 -1
+    __t__ below_1873 -1
+    __t__ below_1874 -1
+    __t__ below_1875 -1
+    __t__ below_1876 -1
+    __t__ below_1877 -1
+    __t__ below_1878 -1
+    __t__ below_1879 -1
+    __t__ below_1880 -1
+    __t__ below_1881 -1
+    __t__ below_1882 -1
+    __t__ below_1883 -1
+    __t__ below_1884 -1
+    __t__ below_1885 -1
+    __t__ below_1886 -1
+    __t__ below_1887 -1
+    __t__ below_1888 -1
+    __t__ below_1889 -1
+    __t__ below_1890 -1
+    __t__ below_1891 -1
+    __t__ below_1892 -1
+    __t__ below_1893 -1
+    __t__ below_1894 -1
+    __t__ below_1895 -1
+    __t__ below_1896 -1
+    __t__ below_1897 -1
+    __t__ below_1898 -1
+    __t__ below_1899 -1
+    __t__ below_1900 -1
+    __t__ below_1901 -1
+    __t__ below_1902 -1
+    __t__ below_1903 -1
+    __t__ below_1904 -1
+    __t__ below_1905 -1
+    __t__ below_1906 -1
+    __t__ below_1907 -1
+    __t__ below_1908 -1
+    __t__ below_1909 -1
+    __t__ below_1910 -1
+    __t__ below_1911 -1
+    __t__ below_1912 -1
+    __t__ below_1913 -1
+    __t__ below_1914 -1
+    __t__ below_1915 -1
+    __t__ below_1916 -1
+    __t__ below_1917 -1
+    __t__ below_1918 -1
+    __t__ below_1919 -1
+    __t__ below_1920 -1
+    __t__ below_1921 -1
+    __t__ below_1922 -1
+    __t__ below_1923 -1
+    __t__ below_1924 -1
+    __t__ below_1925 -1
+    __t__ below_1926 -1
+    __t__ below_1927 -1
+    __t__ below_1928 -1
+    __t__ below_1929 -1
+    __t__ below_1930 -1
+    __t__ below_1931 -1
+    __t__ below_1932 -1
+    __t__ below_1933 -1
+    __t__ below_1934 -1
+    __t__ below_1935 -1
+    __t__ below_1936 -1
+    __t__ below_1937 -1
+    __t__ below_1938 -1
+    __t__ below_1939 -1
+    __t__ below_1940 -1
+    __t__ below_1941 -1
+    __t__ below_1942 -1
+    __t__ below_1943 -1
+    __t__ below_1944 -1
+    __t__ below_1945 -1
+    __t__ below_1946 -1
+    __t__ below_1947 -1
+    __t__ below_1948 -1
+    __t__ below_1949 -1
+    __t__ below_1950 -1
+    __t__ below_1951 -1
+    __t__ below_1952 -1
+    __t__ below_1953 -1
+    __t__ below_1954 -1
+    __t__ below_1955 -1
+    __t__ below_1956 -1
+    __t__ below_1957 -1
+    __t__ below_1958 -1
+    __t__ below_1959 -1
+    __t__ below_1960 -1
+    __t__ below_1961 -1
+    __t__ below_1962 -1
+    __t__ below_1963 -1
+    __t__ below_1964 -1
+    __t__ below_1965 -1
+    __t__ below_1966 -1
+    __t__ below_1967 -1
+    __t__ below_1968 -1
+    __t__ below_1969 -1
+    __t__ below_1970 -1
+    __t__ below_1971 -1
+    __t__ below_1972 -1
+    __t__ below_1973 -1
+    __t__ below_1974 -1
+    __t__ below_1975 -1
+    __t__ below_1976 -1
+    __t__ below_1977 -1
+    __t__ below_1978 -1
+    __t__ below_1979 -1
+    __t__ below_1980 -1
+    __t__ below_1981 -1
+    __t__ below_1982 -1
+    __t__ below_1983 -1
+    __t__ below_1984 -1
+    __t__ below_1985 -1
+    __t__ below_1986 -1
+    __t__ below_1987 -1
+    __t__ below_1988 -1
+    __t__ below_1989 -1
+    __t__ below_1990 -1
+    __t__ below_1991 -1
+    __t__ below_1992 -1
+    __t__ below_1993 -1
+    __t__ below_1994 -1
+    __t__ below_1995 -1
+    __t__ below_1996 -1
+    __t__ below_1997 -1
+    __t__ below_1998 -1
+    __t__ below_1999 -1
+    __t__ below_2000 -1
+RHS
+    RHS above_1800 66
+    RHS above_1801 62
+    RHS above_1802 72
+    RHS above_1803 62
+    RHS above_1804 65
+    RHS above_1805 71
+    RHS above_1806 71
+    RHS above_1807 69
+    RHS above_1808 74
+    RHS above_1809 74
+    RHS above_1810 78
+    RHS above_1811 76
+    RHS above_1812 76
+    RHS above_1813 84
+    RHS above_1814 83
+    RHS above_1815 84
+    RHS above_1816 82
+    RHS above_1817 86
+    RHS above_1818 89
+    RHS above_1819 83
+    RHS above_1820 85
+    RHS above_1821 93
+    RHS above_1822 86
+    RHS above_1823 84
+    RHS above_1824 88
+    RHS above_1825 97
+    RHS above_1826 103
+    RHS above_1827 96
+    RHS above_1828 96
+    RHS above_1829 88
+    RHS above_1830 101
+    RHS above_1831 103
+    RHS above_1832 104
+    RHS above_1833 114
+    RHS above_1834 103
+    RHS above_1835 118
+    RHS above_1836 118
+    RHS above_1837 124
+    RHS above_1838 114
+    RHS above_1839 123
+    RHS above_1840 128
+    RHS above_1841 125
+    RHS above_1842 140
+    RHS above_1843 133
+    RHS above_1844 132
+    RHS above_1845 115
+    RHS above_1846 128
+    RHS above_1847 130
+    RHS above_1848 125
+    RHS above_1849 140
+    RHS above_1850 131
+    RHS above_1851 145
+    RHS above_1852 147
+    RHS above_1853 156
+    RHS above_1854 149
+    RHS above_1855 151
+    RHS above_1856 143
+    RHS above_1857 151
+    RHS above_1858 144
+    RHS above_1859 145
+    RHS above_1860 135
+    RHS above_1861 139
+    RHS above_1862 143
+    RHS above_1863 158
+    RHS above_1864 150
+    RHS above_1865 148
+    RHS above_1866 157
+    RHS above_1867 162
+    RHS above_1868 154
+    RHS above_1869 152
+    RHS above_1870 158
+    RHS above_1871 160
+    RHS above_1872 151
+    RHS above_1873 155
+    RHS above_1874 158
+    RHS above_1875 161
+    RHS above_1876 158
+    RHS above_1877 161
+    RHS above_1878 171
+    RHS above_1879 165
+    RHS above_1880 161
+    RHS above_1881 174
+    RHS above_1882 170
+    RHS above_1883 177
+    RHS above_1884 177
+    RHS above_1885 174
+    RHS above_1886 173
+    RHS above_1887 166
+    RHS above_1888 192
+    RHS above_1889 173
+    RHS above_1890 196
+    RHS above_1891 159
+    RHS above_1892 192
+    RHS above_1893 196
+    RHS above_1894 217
+    RHS above_1895 203
+    RHS above_1896 185
+    RHS above_1897 194
+    RHS above_1898 199
+    RHS above_1899 200
+    RHS above_1900 214
+    RHS above_1901 200
+    RHS above_1902 214
+    RHS above_1903 193
+    RHS above_1904 225
+    RHS above_1905 226
+    RHS above_1906 197
+    RHS above_1907 189
+    RHS above_1908 232
+    RHS above_1909 216
+    RHS above_1910 223
+    RHS above_1911 235
+    RHS above_1912 240
+    RHS above_1913 218
+    RHS above_1914 231
+    RHS above_1915 220
+    RHS above_1916 242
+    RHS above_1917 262
+    RHS above_1918 253
+    RHS above_1919 240
+    RHS above_1920 257
+    RHS above_1921 263
+    RHS above_1922 266
+    RHS above_1923 257
+    RHS above_1924 268
+    RHS above_1925 268
+    RHS above_1926 277
+    RHS above_1927 279
+    RHS above_1928 257
+    RHS above_1929 272
+    RHS above_1930 296
+    RHS above_1931 301
+    RHS above_1932 306
+    RHS above_1933 275
+    RHS above_1934 307
+    RHS above_1935 329
+    RHS above_1936 309
+    RHS above_1937 295
+    RHS above_1938 332
+    RHS above_1939 325
+    RHS above_1940 327
+    RHS above_1941 300
+    RHS above_1942 316
+    RHS above_1943 354
+    RHS above_1944 339
+    RHS above_1945 353
+    RHS above_1946 342
+    RHS above_1947 395
+    RHS above_1948 394
+    RHS above_1949 350
+    RHS above_1950 384
+    RHS above_1951 403
+    RHS above_1952 380
+    RHS above_1953 397
+    RHS above_1954 422
+    RHS above_1955 447
+    RHS above_1956 442
+    RHS above_1957 438
+    RHS above_1958 409
+    RHS above_1959 423
+    RHS above_1960 426
+    RHS above_1961 439
+    RHS above_1962 445
+    RHS above_1963 449
+    RHS above_1964 474
+    RHS above_1965 457
+    RHS above_1966 465
+    RHS above_1967 482
+    RHS above_1968 504
+    RHS above_1969 439
+    RHS above_1970 476
+    RHS above_1971 528
+    RHS above_1972 502
+    RHS above_1973 505
+    RHS above_1974 451
+    RHS above_1975 513
+    RHS above_1976 551
+    RHS above_1977 503
+    RHS above_1978 493
+    RHS above_1979 532
+    RHS above_1980 518
+    RHS above_1981 499
+    RHS above_1982 547
+    RHS above_1983 582
+    RHS above_1984 513
+    RHS above_1985 536
+    RHS above_1986 555
+    RHS above_1987 516
+    RHS above_1988 524
+    RHS above_1989 555
+    RHS above_1990 576
+    RHS above_1991 550
+    RHS above_1992 614
+    RHS above_1993 597
+    RHS above_1994 538
+    RHS above_1995 565
+    RHS above_1996 524
+    RHS above_1997 610
+    RHS above_1998 628
+    RHS above_1999 577
+    RHS above_2000 562
+    RHS below_1800 -66
+    RHS below_1801 -62
+    RHS below_1802 -72
+    RHS below_1803 -62
+    RHS below_1804 -65
+    RHS below_1805 -71
+    RHS below_1806 -71
+    RHS below_1807 -69
+    RHS below_1808 -74
+    RHS below_1809 -74
+    RHS below_1810 -78
+    RHS below_1811 -76
+    RHS below_1812 -76
+    RHS below_1813 -84
+    RHS below_1814 -83
+    RHS below_1815 -84
+    RHS below_1816 -82
+    RHS below_1817 -86
+    RHS below_1818 -89
+    RHS below_1819 -83
+    RHS below_1820 -85
+    RHS below_1821 -93
+    RHS below_1822 -86
+    RHS below_1823 -84
+    RHS below_1824 -88
+    RHS below_1825 -97
+    RHS below_1826 -103
+    RHS below_1827 -96
+    RHS below_1828 -96
+    RHS below_1829 -88
+    RHS below_1830 -101
+    RHS below_1831 -103
+    RHS below_1832 -104
+    RHS below_1833 -114
+    RHS below_1834 -103
+    RHS below_1835 -118
+    RHS below_1836 -118
+    RHS below_1837 -124
+    RHS below_1838 -114
+    RHS below_1839 -123
+    RHS below_1840 -128
+    RHS below_1841 -125
+    RHS below_1842 -140
+    RHS below_1843 -133
+    RHS below_1844 -132
+    RHS below_1845 -115
+    RHS below_1846 -128
+    RHS below_1847 -130
+    RHS below_1848 -125
+    RHS below_1849 -140
+    RHS below_1850 -131
+    RHS below_1851 -145
+    RHS below_1852 -147
+    RHS below_1853 -156
+    RHS below_1854 -149
+    RHS below_1855 -151
+    RHS below_1856 -143
+    RHS below_1857 -151
+    RHS below_1858 -144
+    RHS below_1859 -145
+    RHS below_1860 -135
+    RHS below_1861 -139
+    RHS below_1862 -143
+    RHS below_1863 -158
+    RHS below_1864 -150
+    RHS below_1865 -148
+    RHS below_1866 -157
+    RHS below_1867 -162
+    RHS below_1868 -154
+    RHS below_1869 -152
+    RHS below_1870 -158
+    RHS below_1871 -160
+    RHS below_1872 -151
+    RHS below_1873 -155
+    RHS below_1874 -158
+    RHS below_1875 -161
+    RHS below_1876 -158
+    RHS below_1877 -161
+    RHS below_1878 -171
+    RHS below_1879 -165
+    RHS below_1880 -161
+    RHS below_1881 -174
+    RHS below_1882 -170
+    RHS below_1883 -177
+    RHS below_1884 -177
+    RHS below_1885 -174
+    RHS below_1886 -173
+    RHS below_1887 -166
+    RHS below_1888 -192
+    RHS below_1889 -173
+    RHS below_1890 -196
+    RHS below_1891 -159
+    RHS below_1892 -192
+    RHS below_1893 -196
+    RHS below_1894 -217
+    RHS below_1895 -203
+    RHS below_1896 -185
+    RHS below_1897 -194
+    RHS below_1898 -199
+    RHS below_1899 -200
+    RHS below_1900 -214
+    RHS below_1901 -200
+    RHS below_1902 -214
+    RHS below_1903 -193
+    RHS below_1904 -225
+    RHS below_1905 -226
+    RHS below_1906 -197
+    RHS below_1907 -189
+    RHS below_1908 -232
+    RHS below_1909 -216
+    RHS below_1910 -223
+    RHS below_1911 -235
+    RHS below_1912 -240
+    RHS below_1913 -218
+    RHS below_1914 -231
+    RHS below_1915 -220
+    RHS below_1916 -242
+    RHS below_1917 -262
+    RHS below_1918 -253
+    RHS below_1919 -240
+    RHS below_1920 -257
+    RHS below_1921 -263
+    RHS below_1922 -266
+    RHS below_1923 -257
+    RHS below_1924 -268
+    RHS below_1925 -268
+    RHS below_1926 -277
+    RHS below_1927 -279
+    RHS below_1928 -257
+    RHS below_1929 -272
+    RHS below_1930 -296
+    RHS below_1931 -301
+    RHS below_1932 -306
+    RHS below_1933 -275
+    RHS below_1934 -307
+    RHS below_1935 -329
+    RHS below_1936 -309
+    RHS below_1937 -295
+    RHS below_1938 -332
+    RHS below_1939 -325
+    RHS below_1940 -327
+    RHS below_1941 -300
+    RHS below_1942 -316
+    RHS below_1943 -354
+    RHS below_1944 -339
+    RHS below_1945 -353
+    RHS below_1946 -342
+    RHS below_1947 -395
+    RHS below_1948 -394
+    RHS below_1949 -350
+    RHS below_1950 -384
+    RHS below_1951 -403
+    RHS below_1952 -380
+    RHS below_1953 -397
+    RHS below_1954 -422
+    RHS below_1955 -447
+    RHS below_1956 -442
+    RHS below_1957 -438
+    RHS below_1958 -409
+    RHS below_1959 -423
+    RHS below_1960 -426
+    RHS below_1961 -439
+    RHS below_1962 -445
+    RHS below_1963 -449
+    RHS below_1964 -474
+    RHS below_1965 -457
+    RHS below_1966 -465
+    RHS below_1967 -482
+    RHS below_1968 -504
+    RHS below_1969 -439
+    RHS below_1970 -476
+    RHS below_1971 -528
+    RHS below_1972 -502
+    RHS below_1973 -505
+    RHS below_1974 -451
+    RHS below_1975 -513
+    RHS below_1976 -551
+    RHS below_1977 -503
+    RHS below_1978 -493
+    RHS below_1979 -532
+    RHS below_1980 -518
+    RHS below_1981 -499
+    RHS below_1982 -547
+    RHS below_1983 -582
+    RHS below_1984 -513
+    RHS below_1985 -536
+    RHS below_1986 -555
+    RHS below_1987 -516
+    RHS below_1988 -524
+    RHS below_1989 -555
+    RHS below_1990 -576
+    RHS below_1991 -550
+    RHS below_1992 -614
+    RHS below_1993 -597
+    RHS below_1994 -538
+    RHS below_1995 -565
+    RHS below_1996 -524
+    RHS below_1997 -610
+    RHS below_1998 -628
+    RHS below_1999 -577
+    RHS below_2000 -562
+BOUNDS
+ FR BND w0
+ FR BND w2
+ FR BND w2_w0
+ENDATA
@@ -0,0 +1,134 @@
+use duckdb::Connection;
+use nghc_rs::decompress::decompress;
+use nghc_rs::{Normalization, Optimizer, Quantization, Strategy};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Two unigrams around 1e8 and two bigrams: `w0 w1` is 1.0003 times `w0`, which neither f16
+/// nor an 8 bit grid next to 2 can hold, and `w1 w0` is exactly twice `w1`.
+fn fixture() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("nghc-rs-quantize-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("input").join("n=1")).unwrap();
+    fs::create_dir_all(dir.join("input").join("n=2")).unwrap();
+
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch(&format!(
+        "
+        CREATE TABLE unigrams AS
+            SELECT 'w' || i AS ngram,
+                list_transform(range(201), y -> (1e8 + (i + 1) * y * 7919 % 100000)::UBIGINT)
+                    AS frequency
+            FROM range(2) t(i);
+        COPY (SELECT * FROM unigrams ORDER BY ngram) TO '{0}/n=1/0.parquet';
+        COPY (
+            SELECT 'w0 w1' AS ngram, list_transform(frequency, x -> round(1.0003 * x)::UBIGINT)
+                AS frequency
+            FROM unigrams WHERE ngram == 'w0'
+            UNION ALL
+            SELECT 'w1 w0', list_transform(frequency, x -> 2 * x) FROM unigrams WHERE ngram == 'w1'
+            ORDER BY ngram
+        ) TO '{0}/n=2/0.parquet';
+        ",
+        dir.join("input").display()
+    ))
+    .unwrap();
+
+    return dir;
+}
+
+fn ngrams(conn: &Connection, path: &Path) -> Vec<String> {
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT ngram FROM read_parquet('{}') ORDER BY ngram",
+            path.display()
+        ))
+        .unwrap();
+
+    return stmt
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .map(|x| x.unwrap())
+        .collect();
+}
+
+#[test]
+fn quantization_falls_back_to_full_precision() {
+    let dir = fixture();
+    let conn = Connection::open_in_memory().unwrap();
+
+    for (quantization, scale) in [
+        (Quantization::F16, None),
+        (Quantization::Fixed(8), Some(2. / 127.)),
+    ] {
+        let optimized = dir.join(format!("optimized-{}", quantization));
+        let decompressed = dir.join(format!("decompressed-{}", quantization));
+
+        Optimizer::builder(dir.join("input"), optimized.clone())
+            .cores(1)
+            .max_n(2)
+            .strategy("direct-children".parse::<Strategy>().unwrap())
+            .normalization(Normalization::Raw)
+            .error_bound(1.)
+            .quantization(quantization)
+            .build()
+            .run()
+            .unwrap();
+
+        let compressed = optimized.join("compressed").join("n=2");
+        assert_eq!(ngrams(&conn, &compressed.join("0.parquet")), vec!["w1 w0"]);
+        assert_eq!(
+            ngrams(&conn, &compressed.join("0-full.parquet")),
+            vec!["w0 w1"],
+            "{}",
+            quantization
+        );
+
+        // The fixed-point scale is chosen for the file, so it is set by the largest coefficient.
+        let stored: Option<f64> = conn
+            .query_row(
+                &format!(
+                    "SELECT value FROM read_parquet('{}') WHERE key == 'scale:n=2/0.parquet'",
+                    optimized.join("metadata.parquet").display()
+                ),
+                [],
+                |row| row.get::<_, String>(0),
+            )
+            .ok()
+            .map(|x| x.parse().unwrap());
+        match (stored, scale) {
+            (Some(stored), Some(scale)) => assert!((stored - scale).abs() < 1e-6, "{}", stored),
+            (stored, scale) => assert_eq!(stored, scale),
+        }
+
+        decompress(optimized, decompressed.clone(), false, false);
+
+        let (worst, years): (f64, usize) = conn
+            .query_row(
+                &format!(
+                    "
+                    SELECT max(abs(o.x::DOUBLE - d.x::DOUBLE)), count(*)
+                    FROM (
+                        SELECT ngram, unnest(frequency) AS x, generate_subscripts(frequency, 1) AS y
+                        FROM read_parquet('{}/n=2/*.parquet')
+                    ) o
+                    JOIN (
+                        SELECT ngram, unnest(frequency) AS x, generate_subscripts(frequency, 1) AS y
+                        FROM read_parquet('{}/n=2/*.parquet')
+                    ) d
+                    USING (ngram, y)
+                    ",
+                    dir.join("input").display(),
+                    decompressed.display()
+                ),
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(years, 2 * 201, "{}", quantization);
+        assert!(worst <= 1.5, "{}: {}", quantization, worst);
+    }
+
+    drop(conn);
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,334 @@
+use hashbrown::HashMap;
+use nghc_rs::{LinearProgram, Optimizer, SolverBackend, SolverOption};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// An LP read back from the `.csv` and `.mps` files written by `--dump-lp`.
+struct Fixture {
+    name: String,
+    y: [f64; 201],
+    series: Vec<[f64; 201]>,
+    bounds: Vec<(f64, f64)>,
+    weights: [f64; 201],
+}
+
+impl Fixture {
+    fn read(csv: &Path) -> Self {
+        let text = fs::read_to_string(csv).unwrap();
+        // The header quotes n-grams, which may contain commas, so count the data columns.
+        let lines = text.lines().skip(1);
+        let tokens = text.lines().nth(1).unwrap().split(',').count() - 3;
+
+        let mut y = [0.; 201];
+        let mut weights = [0.; 201];
+        let mut series = vec![[0.; 201]; tokens];
+        for (i, line) in lines.enumerate() {
+            let values = line
+                .split(',')
+                .map(|x| x.parse::<f64>().unwrap())
+                .collect::<Vec<_>>();
+            weights[i] = values[1];
+            y[i] = values[2];
+            for (x, value) in series.iter_mut().zip(values[3..].iter()) {
+                x[i] = *value;
+            }
+        }
+
+        return Fixture {
+            name: csv.file_stem().unwrap().to_str().unwrap().to_string(),
+            y,
+            series,
+            bounds: read_bounds(&csv.with_extension("mps"), tokens),
+            weights,
+        };
+    }
+
+    fn lp<'a>(&'a self, series: &'a [&'a [f64; 201]]) -> LinearProgram<'a> {
+        return LinearProgram {
+            y: &self.y,
+            series,
+            bounds: &self.bounds,
+            weights: &self.weights,
+        };
+    }
+}
+
+/// Bounds of the first `columns` columns, in the order the COLUMNS section lists them.
+fn read_bounds(mps: &Path, columns: usize) -> Vec<(f64, f64)> {
+    let text = fs::read_to_string(mps).unwrap();
+    let mut names: Vec<String> = Vec::new();
+    let mut bounds: Vec<(f64, f64)> = Vec::new();
+    let mut section = "";
+
+    for line in text.lines() {
+        if !line.starts_with(' ') {
+            section = line.split_whitespace().next().unwrap_or("");
+            continue;
+        }
+
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        match section {
+            "COLUMNS" if names.last() != Some(&fields[0].to_string()) => {
+                names.push(fields[0].to_string());
+                bounds.push((0., f64::INFINITY));
+            }
+            "BOUNDS" => {
+                let j = names.iter().position(|name| name == fields[2]).unwrap();
+                let value = || fields[3].parse::<f64>().unwrap();
+                match fields[0] {
+                    "FR" => bounds[j] = (f64::NEG_INFINITY, f64::INFINITY),
+                    "MI" => bounds[j].0 = f64::NEG_INFINITY,
+                    "LO" => bounds[j].0 = value(),
+                    "UP" => bounds[j].1 = value(),
+                    kind => panic!("unexpected bound type {}", kind),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    assert_eq!(names.len(), columns + 1, "{}", mps.display());
+    bounds.pop();
+    return bounds;
+}
+
+/// The objective `t` of the LP at `c`: the largest weighted residual.
+fn objective(lp: &LinearProgram, c: &[f64]) -> f64 {
+    return (0..201)
+        .map(|i| {
+            let y_pred = lp.series.iter().zip(c).map(|(x, c)| x[i] * c).sum::<f64>();
+            return (lp.weights[i] * (lp.y[i] - y_pred)).abs();
+        })
+        .fold(0., f64::max);
+}
+
+fn within_bounds(lp: &LinearProgram, c: &[f64]) -> bool {
+    return lp
+        .bounds
+        .iter()
+        .zip(c)
+        .all(|((lo, hi), c)| *c >= lo - 1e-7 && *c <= hi + 1e-7);
+}
+
+fn lps() -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("lps");
+}
+
+/// Every LP recorded in `tests/lps`, sorted by name.
+fn recorded() -> Vec<Fixture> {
+    let dir = lps();
+    let mut paths = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|x| x == "csv"))
+        .filter(|path| path.with_extension("mps").exists())
+        .collect::<Vec<_>>();
+    paths.sort();
+    assert!(!paths.is_empty(), "no LPs in {}", dir.display());
+
+    return paths.iter().map(|path| Fixture::read(path)).collect();
+}
+
+/// Optimal `t` of each recorded LP by name, from `tests/lps/objectives.csv`.
+fn references() -> HashMap<String, f64> {
+    return fs::read_to_string(lps().join("objectives.csv"))
+        .unwrap()
+        .lines()
+        .skip(1)
+        .map(|line| {
+            let (name, t) = line.split_once(',').unwrap();
+            return (name.to_string(), t.parse::<f64>().unwrap());
+        })
+        .collect();
+}
+
+/// Every recorded LP must be solved within its bounds and reach its reference optimum with
+/// every backend that is built, the simplex as well as HiGHS, warm and cold. LPs can have
+/// several optimal coefficient vectors, so only the objective is compared. An LP without a
+/// reference is only accepted when HiGHS is built, whose result then stands in for it.
+#[test]
+fn recorded_lps() {
+    let references = references();
+
+    for fixture in recorded() {
+        let series = fixture.series.iter().collect::<Vec<_>>();
+        let lp = fixture.lp(&series);
+
+        let solvers = [
+            #[cfg(feature = "highs")]
+            SolverBackend::Highs.solver(true),
+            #[cfg(feature = "highs")]
+            SolverBackend::Highs.solver(false),
+            SolverBackend::Simplex.solver(false),
+        ];
+        let objectives = solvers
+            .iter()
+            .map(|solver| {
+                let c = solver.solve(&lp, &[]).unwrap_or_else(|status| {
+                    panic!("{} {}: {}", fixture.name, solver.name(), status)
+                });
+                assert!(within_bounds(&lp, &c), "{} {}", fixture.name, solver.name());
+                return (solver.name(), objective(&lp, &c));
+            })
+            .collect::<Vec<_>>();
+
+        let reference = match references.get(&fixture.name) {
+            Some(t) => *t,
+            None if cfg!(feature = "highs") => objectives[0].1,
+            None => panic!("{}: no reference objective in objectives.csv", fixture.name),
+        };
+        for (solver, t) in objectives.iter() {
+            assert!(
+                (t - reference).abs() <= 1e-6 * reference.abs().max(1.),
+                "{}: {} found {}, the reference is {}",
+                fixture.name,
+                solver,
+                t,
+                reference
+            );
+        }
+    }
+}
+
+/// The warm model is reused for every LP a thread solves, so `--time-limit` must restart with
+/// each of them rather than run out once their total time exceeds it.
+#[cfg(feature = "highs")]
+#[test]
+fn warm_time_limit_is_per_lp() {
+    use std::time::Instant;
+
+    let fixtures = recorded();
+    let solver = SolverBackend::Highs.solver(true);
+    let limit = 0.1;
+    let options = [SolverOption::new("time_limit", &limit.to_string())];
+
+    let start = Instant::now();
+    let mut solves = 0;
+    while start.elapsed().as_secs_f64() < 10. * limit || solves < 100 {
+        let fixture = &fixtures[solves % fixtures.len()];
+        let series = fixture.series.iter().collect::<Vec<_>>();
+        solver
+            .solve(&fixture.lp(&series), &options)
+            .unwrap_or_else(|status| {
+                panic!("{} after {} solves: {}", fixture.name, solves, status)
+            });
+        solves += 1;
+    }
+}
+
+/// Unknown options fail the run before anything is loaded, and an LP solved with them
+/// directly fails without a panic.
+#[test]
+fn invalid_options_are_errors() {
+    let fixtures = recorded();
+    let series = fixtures[0].series.iter().collect::<Vec<_>>();
+    let lp = fixtures[0].lp(&series);
+    let options = [SolverOption::new("no_such_option", "1")];
+
+    for backend in SolverBackend::VARIANTS {
+        let backend = backend.parse::<SolverBackend>().unwrap();
+        for warm in [true, false] {
+            let output =
+                std::env::temp_dir().join(format!("nghc-rs-options-{}", std::process::id()));
+            let result = Optimizer::builder(PathBuf::from("missing.db"), output.clone())
+                .solver(backend)
+                .cold_start(!warm)
+                .solver_option("no_such_option", "1")
+                .build()
+                .run();
+
+            let e = result.unwrap_err();
+            assert!(e.contains("no_such_option"), "{}", e);
+            assert!(!output.exists());
+            assert!(backend.solver(warm).solve(&lp, &options).is_err());
+        }
+    }
+}
+
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        return (self.0 % 1_000_000) as f64 / 1_000_000.;
+    }
+}
+
+/// Minimum of a convex function on `[lo, hi]`.
+fn ternary_search(lo: f64, hi: f64, f: &dyn Fn(f64) -> f64) -> f64 {
+    let (mut a, mut b) = (lo, hi);
+    for _ in 0..200 {
+        let m1 = a + (b - a) / 3.;
+        let m2 = b - (b - a) / 3.;
+        match f(m1) < f(m2) {
+            true => b = m2,
+            false => a = m1,
+        }
+    }
+
+    return f((a + b) / 2.);
+}
+
+/// With one or two candidates the objective is convex in each coefficient, so nested ternary
+/// searches find the optimum independently of any LP solver.
+#[test]
+fn simplex_matches_ternary_search() {
+    let mut rng = Rng(88172645463325252);
+    let solver = SolverBackend::Simplex.solver(false);
+
+    for trial in 0..300 {
+        let k = 1 + trial % 2;
+        let scale = [1., 1000., 1e6][trial % 3];
+        let series = (0..k)
+            .map(|_| {
+                std::array::from_fn(|i| match rng.next() < 0.1 {
+                    true => 0.,
+                    false => (rng.next() * scale).round() + i as f64,
+                })
+            })
+            .collect::<Vec<[f64; 201]>>();
+        let y: [f64; 201] = std::array::from_fn(|i| {
+            (series.iter().map(|x| x[i]).sum::<f64>() * (0.3 + rng.next())).round()
+        });
+        let weights: [f64; 201] = std::array::from_fn(|i| match trial % 4 == 0 && i < 20 {
+            true => 0.,
+            false => 1.,
+        });
+        let signed = trial % 5 == 0;
+        // The searches need a finite interval, which the optimum lies well inside.
+        let (lo, hi, lower) = match signed {
+            true => (-10., 10., f64::NEG_INFINITY),
+            false => (0., 10., 0.),
+        };
+        let bounds = vec![(lower, f64::INFINITY); k];
+        let series = series.iter().collect::<Vec<_>>();
+        let lp = LinearProgram {
+            y: &y,
+            series: &series,
+            bounds: &bounds,
+            weights: &weights,
+        };
+
+        let c = solver.solve(&lp, &[]).unwrap();
+        assert!(within_bounds(&lp, &c), "trial {}: {:?}", trial, c);
+
+        let reference = match k {
+            1 => ternary_search(lo, hi, &|a| objective(&lp, &[a])),
+            _ => ternary_search(lo, hi, &|a| {
+                ternary_search(lo, hi, &|b| objective(&lp, &[a, b]))
+            }),
+        };
+        let t = objective(&lp, &c);
+        assert!(
+            t - reference <= 1e-6 * reference.max(1.),
+            "trial {}: simplex found {}, ternary search {}",
+            trial,
+            t,
+            reference
+        );
+    }
+}